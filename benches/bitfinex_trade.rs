@@ -0,0 +1,91 @@
+//! Allocations & time per deserialised Bitfinex trade channel message.
+//!
+//! Run with `cargo bench --bench bitfinex_trade`. The "untagged" baseline deserialises the
+//! message tag as a `#[serde(untagged)]` enum of an owned `String` or snapshot, as the
+//! [`BitfinexMessage`] deserialiser previously did.
+
+use barter_data::exchange::bitfinex::{message::BitfinexMessage, trade::BitfinexTrade};
+use serde::Deserialize;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    hint::black_box,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Instant,
+};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const ITERATIONS: usize = 100_000;
+
+const TRADE: &str = r#"[420191,"te",[1225484398,1665452200022,0.08980641,19027.02807752]]"#;
+const HEARTBEAT: &str = r#"[420191,"hb"]"#;
+const SNAPSHOT: &str = r#"[420191,[[1225484398,1665452200022,0.08980641,19027.02807752],[1225484397,1665452199988,-0.0155,19027.0],[1225484396,1665452199900,0.5,19026.5]]]"#;
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+#[allow(dead_code)]
+enum UntaggedTagOrSnapshot {
+    Tag(String),
+    Snapshot(Vec<BitfinexTrade>),
+}
+
+#[derive(Deserialize)]
+#[allow(dead_code)]
+struct UntaggedMessage(u32, UntaggedTagOrSnapshot, BitfinexTrade);
+
+#[derive(Deserialize)]
+#[allow(dead_code)]
+struct UntaggedSnapshot(u32, UntaggedTagOrSnapshot);
+
+fn bench<F>(name: &str, mut deserialise: F)
+where
+    F: FnMut(),
+{
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        deserialise();
+    }
+    let elapsed = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations;
+
+    println!(
+        "{name:<24} {:>8.1} ns/msg {:>6.2} allocs/msg",
+        elapsed.as_nanos() as f64 / ITERATIONS as f64,
+        allocations as f64 / ITERATIONS as f64,
+    );
+}
+
+fn main() {
+    bench("trade", || {
+        black_box(serde_json::from_str::<BitfinexMessage>(black_box(TRADE)).unwrap());
+    });
+    bench("trade (untagged)", || {
+        black_box(serde_json::from_str::<UntaggedMessage>(black_box(TRADE)).unwrap());
+    });
+    bench("heartbeat", || {
+        black_box(serde_json::from_str::<BitfinexMessage>(black_box(HEARTBEAT)).unwrap());
+    });
+    bench("snapshot", || {
+        black_box(serde_json::from_str::<BitfinexMessage>(black_box(SNAPSHOT)).unwrap());
+    });
+    bench("snapshot (untagged)", || {
+        black_box(serde_json::from_str::<UntaggedSnapshot>(black_box(SNAPSHOT)).unwrap());
+    });
+}
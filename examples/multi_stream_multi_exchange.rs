@@ -72,7 +72,7 @@ async fn main() {
     // Join all exchange Streams into a single tokio_stream::StreamMap
     // Notes:
     //  - Use `streams.select(ExchangeId)` to interact with the individual exchange streams!
-    //  - Use `streams.join()` to join all exchange streams into a single mpsc::UnboundedReceiver!
+    //  - Use `streams.join()` to join all exchange streams into a single mpsc::Receiver!
     let mut joined_stream = streams.join_map().await;
 
     while let Some((exchange, data)) = joined_stream.next().await {
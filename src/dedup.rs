@@ -0,0 +1,226 @@
+use crate::exchange::ExchangeId;
+use barter_integration::protocol::websocket::{WsError, WsMessage, WsStream};
+use futures::Stream;
+use std::{
+    collections::{hash_map::DefaultHasher, VecDeque},
+    hash::{Hash, Hasher},
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tracing::debug;
+
+/// Sliding window of the content hashes of the most recent raw inbound data frames of a
+/// connection, used by a [`DedupStream`] to detect frames the exchange resent.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct FrameWindow {
+    pub capacity: usize,
+    hashes: VecDeque<u64>,
+}
+
+impl FrameWindow {
+    /// Construct a new [`FrameWindow`] remembering the provided number of recent frames.
+    ///
+    /// A capacity of zero is treated as one, so byte-identical consecutive frames are still
+    /// detected.
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            hashes: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Record the provided frame, returning true if it is byte-identical to a frame already in
+    /// the window.
+    pub fn is_duplicate(&mut self, frame: &[u8]) -> bool {
+        let mut hasher = DefaultHasher::new();
+        frame.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        if self.hashes.contains(&hash) {
+            return true;
+        }
+
+        if self.hashes.len() == self.capacity {
+            self.hashes.pop_front();
+        }
+        self.hashes.push_back(hash);
+        false
+    }
+}
+
+/// [`Stream`] adapter that drops raw inbound data frames which are byte-identical to one of the
+/// frames in an optional [`FrameWindow`], before they are deserialised.
+///
+/// Catches outright resends (eg/ around re-connections or under load) more cheaply than per
+/// [`SubKind`](crate::subscription::SubKind) de-duplication. Control frames are always forwarded,
+/// and no work is done per frame when no [`FrameWindow`] is set.
+#[derive(Debug)]
+pub struct DedupStream<St = WsStream> {
+    pub exchange: ExchangeId,
+    stream: St,
+    window: Option<FrameWindow>,
+}
+
+impl<St> DedupStream<St> {
+    /// Construct a new [`DedupStream`] wrapping the provided [`Stream`] of raw frames.
+    pub fn new(exchange: ExchangeId, stream: St, window: Option<FrameWindow>) -> Self {
+        Self {
+            exchange,
+            stream,
+            window,
+        }
+    }
+}
+
+impl<St> Stream for DedupStream<St>
+where
+    St: Stream<Item = Result<WsMessage, WsError>> + Unpin,
+{
+    type Item = Result<WsMessage, WsError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            let poll = Pin::new(&mut self.stream).poll_next(cx);
+
+            let Some(window) = self.window.as_mut() else {
+                return poll;
+            };
+
+            let frame = match &poll {
+                Poll::Ready(Some(Ok(WsMessage::Text(text)))) => text.as_bytes(),
+                Poll::Ready(Some(Ok(WsMessage::Binary(binary)))) => binary.as_slice(),
+                _ => return poll,
+            };
+
+            if window.is_duplicate(frame) {
+                debug!(
+                    exchange = %self.exchange,
+                    action = "dropping frame",
+                    "received duplicate raw frame",
+                );
+                continue;
+            }
+
+            return poll;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+
+    #[allow(clippy::result_large_err)]
+    fn text(frame: &str) -> Result<WsMessage, WsError> {
+        Ok(WsMessage::Text(frame.to_owned()))
+    }
+
+    #[tokio::test]
+    async fn test_dedup_stream() {
+        struct TestCase {
+            window: Option<FrameWindow>,
+            input: Vec<Result<WsMessage, WsError>>,
+            expected: usize,
+        }
+
+        let trade = r#"{"e":"trade","s":"BTCUSDT","t":1}"#;
+
+        let tests = vec![
+            TestCase {
+                // TC0: duplicated raw frame is only forwarded once
+                window: Some(FrameWindow::new(8)),
+                input: vec![text(trade), text(trade)],
+                expected: 1,
+            },
+            TestCase {
+                // TC1: distinct frames are all forwarded
+                window: Some(FrameWindow::new(8)),
+                input: vec![
+                    text(trade),
+                    text(r#"{"e":"trade","s":"BTCUSDT","t":2}"#),
+                    Ok(WsMessage::Binary(trade.as_bytes().to_vec())),
+                ],
+                expected: 2,
+            },
+            TestCase {
+                // TC2: duplicate evicted from the window is forwarded again
+                window: Some(FrameWindow::new(1)),
+                input: vec![
+                    text(trade),
+                    text(r#"{"e":"trade","s":"BTCUSDT","t":2}"#),
+                    text(trade),
+                ],
+                expected: 3,
+            },
+            TestCase {
+                // TC3: duplicate control frames are always forwarded
+                window: Some(FrameWindow::new(8)),
+                input: vec![Ok(WsMessage::Ping(vec![])), Ok(WsMessage::Ping(vec![]))],
+                expected: 2,
+            },
+            TestCase {
+                // TC4: without a FrameWindow every frame is forwarded
+                window: None,
+                input: vec![text(trade), text(trade)],
+                expected: 2,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let forwarded = DedupStream::new(
+                ExchangeId::BinanceSpot,
+                futures::stream::iter(test.input),
+                test.window,
+            )
+            .collect::<Vec<_>>()
+            .await;
+
+            assert_eq!(forwarded.len(), test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dedup_stream_emits_single_event_for_duplicated_frame() {
+        use crate::{
+            exchange::binance::{spot::BinanceSpot, trade::BinanceTrade},
+            subscription::{trade::PublicTrades, Map},
+            transformer::{stateless::StatelessTransformer, ExchangeTransformer},
+        };
+        use barter_integration::{
+            model::{
+                instrument::{kind::InstrumentKind, Instrument},
+                SubscriptionId,
+            },
+            protocol::websocket::WebSocketParser,
+            ExchangeStream,
+        };
+        use tokio::sync::mpsc;
+
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+        let transformer = <StatelessTransformer<BinanceSpot, PublicTrades, BinanceTrade> as ExchangeTransformer<BinanceSpot, PublicTrades>>::new(
+            ws_sink_tx,
+            Map(std::collections::HashMap::from([(
+                SubscriptionId::from("@trade|BTCUSDT"),
+                Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            )])),
+        )
+        .await
+        .unwrap();
+
+        let trade = r#"{"e":"trade","E":1649324825173,"s":"BTCUSDT","t":1000000000,"p":"10000.19","q":"0.239000","T":1649324825173,"m":false,"M":true}"#;
+        let frames = DedupStream::new(
+            ExchangeId::BinanceSpot,
+            futures::stream::iter(vec![text(trade), text(trade)]),
+            Some(FrameWindow::new(8)),
+        );
+
+        let events = ExchangeStream::<WebSocketParser, _, _>::new(frames, transformer)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].as_ref().unwrap().kind.id, "1000000000");
+    }
+}
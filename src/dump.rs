@@ -0,0 +1,301 @@
+use crate::{error::DataError, exchange::ExchangeId};
+use barter_integration::protocol::websocket::{WsError, WsMessage};
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// Debug mode pairing every raw inbound data frame of a connection with its parsed
+/// [`DumpOutcome`], used to diagnose whether events are lost to parsing (eg/ an exchange schema
+/// change) or to routing.
+///
+/// Configured via
+/// [`ConnectionConfig::debug_dump`](crate::subscriber::config::ConnectionConfig::debug_dump),
+/// and no work is done per frame when it is not set.
+#[derive(Clone, Debug)]
+pub enum DebugDump {
+    /// Log every [`DumpRecord`] at debug level.
+    Log,
+    /// Send every [`DumpRecord`] via the [`mpsc::UnboundedSender`].
+    Channel(mpsc::UnboundedSender<DumpRecord>),
+}
+
+impl DebugDump {
+    /// Construct a [`DebugDump::Channel`], returning the [`mpsc::UnboundedReceiver`] of its
+    /// [`DumpRecord`]s.
+    pub fn channel() -> (Self, mpsc::UnboundedReceiver<DumpRecord>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        (Self::Channel(tx), rx)
+    }
+
+    /// Deliver the provided [`DumpRecord`].
+    pub fn dump(&self, record: DumpRecord) {
+        match self {
+            Self::Log => debug!(
+                exchange = %record.exchange,
+                raw = record.raw,
+                outcome = ?record.outcome,
+                "debug dump of inbound message",
+            ),
+            Self::Channel(tx) => {
+                let _ = tx.send(record);
+            }
+        }
+    }
+}
+
+impl PartialEq for DebugDump {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Log, Self::Log) => true,
+            (Self::Channel(lhs), Self::Channel(rhs)) => lhs.same_channel(rhs),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for DebugDump {}
+
+/// Raw inbound data frame paired with its parsed [`DumpOutcome`].
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DumpRecord {
+    pub exchange: ExchangeId,
+    pub raw: String,
+    pub outcome: DumpOutcome,
+}
+
+/// Parsed outcome of a raw inbound data frame.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub enum DumpOutcome {
+    /// Frame was parsed into the number of [`MarketEvent<T>`](crate::event::MarketEvent)s.
+    Events(usize),
+    /// Frame was parsed but yielded no events (eg/ a heartbeat or an unidentifiable message).
+    Skipped,
+    /// Frame yielded an error (eg/ failed to deserialise), alongside any events yielded before it.
+    Error { events: usize, error: String },
+}
+
+/// Raw inbound frames forwarded by a [`DumpRecorder`] that have not yet been paired with their
+/// [`DumpOutcome`] by the [`DumpStream`], where control frames are recorded as `None`.
+type RecordedFrames = Arc<Mutex<VecDeque<Option<String>>>>;
+
+/// [`Stream`] adapter recording the raw inbound frames forwarded to the parser, so the outer
+/// [`DumpStream`] can pair each with its [`DumpOutcome`].
+#[derive(Debug)]
+pub struct DumpRecorder<St> {
+    stream: St,
+    frames: Option<RecordedFrames>,
+}
+
+impl<St> Stream for DumpRecorder<St>
+where
+    St: Stream<Item = Result<WsMessage, WsError>> + Unpin,
+{
+    type Item = Result<WsMessage, WsError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let poll = Pin::new(&mut self.stream).poll_next(cx);
+
+        if let (Some(frames), Poll::Ready(Some(message))) = (&self.frames, &poll) {
+            let raw = match message {
+                Ok(WsMessage::Text(text)) => Some(text.clone()),
+                Ok(WsMessage::Binary(binary)) => Some(String::from_utf8_lossy(binary).into_owned()),
+                _ => None,
+            };
+            frames.lock().unwrap().push_back(raw);
+        }
+
+        poll
+    }
+}
+
+/// Frame currently being parsed by a [`DumpStream`], & the outputs yielded from it so far.
+#[derive(Debug)]
+struct CurrentFrame {
+    raw: String,
+    events: usize,
+    error: Option<String>,
+}
+
+/// [`Stream`] adapter wrapping an [`ExchangeStream`](barter_integration::ExchangeStream) that
+/// parses the raw frames of an inner [`DumpRecorder`], sending a [`DumpRecord`] to the optional
+/// [`DebugDump`] once each raw data frame has been fully parsed.
+///
+/// Every output yielded by the [`ExchangeStream`](barter_integration::ExchangeStream) is produced
+/// by the last frame it polled, so a frame is complete once the next frame is recorded, or no
+/// further output is ready.
+#[derive(Debug)]
+pub struct DumpStream<St> {
+    pub exchange: ExchangeId,
+    pub stream: St,
+    dump: Option<(DebugDump, RecordedFrames)>,
+    current: Option<CurrentFrame>,
+}
+
+impl<St> DumpStream<St> {
+    /// Construct a new [`DumpStream`], passing the [`DumpRecorder`] wrapping the inner raw frame
+    /// [`Stream`] to the provided constructor of the parsing [`Stream`].
+    pub fn new<Inner, F>(
+        exchange: ExchangeId,
+        inner: Inner,
+        dump: Option<DebugDump>,
+        stream: F,
+    ) -> Self
+    where
+        F: FnOnce(DumpRecorder<Inner>) -> St,
+    {
+        let dump = dump.map(|dump| (dump, RecordedFrames::default()));
+        let recorder = DumpRecorder {
+            stream: inner,
+            frames: dump.as_ref().map(|(_, frames)| Arc::clone(frames)),
+        };
+
+        Self {
+            exchange,
+            stream: stream(recorder),
+            dump,
+            current: None,
+        }
+    }
+}
+
+/// Send the [`DumpRecord`] of the `current` frame (if any) to the [`DebugDump`].
+fn complete(exchange: ExchangeId, dump: &DebugDump, current: &mut Option<CurrentFrame>) {
+    let Some(frame) = current.take() else {
+        return;
+    };
+
+    let outcome = match (frame.events, frame.error) {
+        (events, Some(error)) => DumpOutcome::Error { events, error },
+        (0, None) => DumpOutcome::Skipped,
+        (events, None) => DumpOutcome::Events(events),
+    };
+
+    dump.dump(DumpRecord {
+        exchange,
+        raw: frame.raw,
+        outcome,
+    });
+}
+
+impl<St, T> Stream for DumpStream<St>
+where
+    St: Stream<Item = Result<T, DataError>> + Unpin,
+{
+    type Item = St::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let Self {
+            exchange,
+            stream,
+            dump,
+            current,
+        } = &mut *self;
+
+        let poll = Pin::new(stream).poll_next(cx);
+
+        let Some((dump, frames)) = dump.as_ref() else {
+            return poll;
+        };
+
+        // Every frame recorded before the last was parsed without yielding any output
+        for raw in frames.lock().unwrap().drain(..) {
+            complete(*exchange, dump, current);
+            *current = raw.map(|raw| CurrentFrame {
+                raw,
+                events: 0,
+                error: None,
+            });
+        }
+
+        match (&poll, current.as_mut()) {
+            (Poll::Ready(Some(Ok(_))), Some(frame)) => frame.events += 1,
+            (Poll::Ready(Some(Err(error))), Some(frame)) => {
+                frame.error.get_or_insert_with(|| error.to_string());
+            }
+            (Poll::Ready(Some(_)), None) => {}
+            (Poll::Ready(None) | Poll::Pending, _) => complete(*exchange, dump, current),
+        }
+
+        poll
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::kraken::{trade::KrakenTrades, Kraken},
+        subscription::{trade::PublicTrades, Map},
+        transformer::{stateless::StatelessTransformer, ExchangeTransformer},
+    };
+    use barter_integration::{
+        model::{
+            instrument::{kind::InstrumentKind, Instrument},
+            SubscriptionId,
+        },
+        protocol::websocket::WebSocketParser,
+        ExchangeStream,
+    };
+    use futures::StreamExt;
+
+    #[tokio::test]
+    async fn test_dump_stream_pairs_each_raw_frame_with_outcome() {
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+        let transformer =
+            <StatelessTransformer<Kraken, PublicTrades, KrakenTrades> as ExchangeTransformer<
+                Kraken,
+                PublicTrades,
+            >>::new(
+                ws_sink_tx,
+                Map(std::collections::HashMap::from([(
+                    SubscriptionId::from("trade|XBT/USD"),
+                    Instrument::from(("xbt", "usd", InstrumentKind::Spot)),
+                )])),
+            )
+            .await
+            .unwrap();
+
+        let trades = r#"[0,[["5541.20000","0.15850568","1534614057.321597","s","l",""],["6060.00000","0.02455000","1534614057.324998","b","l",""]],"trade","XBT/USD"]"#;
+        let heartbeat = r#"{"event":"heartbeat"}"#;
+        let malformed = r#"[0,"malformed"]"#;
+        let frames = vec![
+            Ok(WsMessage::Text(trades.to_owned())),
+            Ok(WsMessage::Ping(vec![])),
+            Ok(WsMessage::Text(heartbeat.to_owned())),
+            Ok(WsMessage::Text(malformed.to_owned())),
+            Ok(WsMessage::Binary(trades.as_bytes().to_vec())),
+        ];
+
+        let (dump, mut dump_rx) = DebugDump::channel();
+        let outputs = DumpStream::new(
+            ExchangeId::Kraken,
+            futures::stream::iter(frames),
+            Some(dump),
+            |recorder| ExchangeStream::<WebSocketParser, _, _>::new(recorder, transformer),
+        )
+        .collect::<Vec<_>>()
+        .await;
+        assert_eq!(outputs.len(), 5);
+
+        let mut actual = vec![];
+        while let Ok(record) = dump_rx.try_recv() {
+            assert_eq!(record.exchange, ExchangeId::Kraken);
+            actual.push((record.raw, record.outcome));
+        }
+
+        // Every raw data frame is paired with its outcome, whereas control frames are not dumped
+        assert_eq!(actual.len(), 4);
+        assert_eq!(actual[0], (trades.to_owned(), DumpOutcome::Events(2)));
+        assert_eq!(actual[1], (heartbeat.to_owned(), DumpOutcome::Skipped));
+        assert_eq!(actual[2].0, malformed);
+        assert!(matches!(actual[2].1, DumpOutcome::Error { events: 0, .. }));
+        assert_eq!(actual[3], (trades.to_owned(), DumpOutcome::Events(2)));
+    }
+}
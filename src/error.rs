@@ -1,11 +1,22 @@
-use barter_integration::error::SocketError;
+use crate::{
+    exchange::ExchangeId, streams::lifecycle::ExchangeStatus, subscription::book::BookAnomaly,
+};
+use barter_integration::{
+    error::SocketError,
+    model::instrument::{kind::InstrumentKind, Instrument},
+    protocol::websocket::WsError,
+};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use std::path::PathBuf;
 use thiserror::Error;
 
 /// All errors generated in `barter-data`.
 #[derive(Debug, Error)]
 pub enum DataError {
+    /// Boxed, since the [`SocketError`] is large relative to every other variant.
     #[error("SocketError: {0}")]
-    Socket(#[from] SocketError),
+    Socket(Box<SocketError>),
 
     #[error(
         "\
@@ -17,6 +28,44 @@ pub enum DataError {
         prev_last_update_id: u64,
         first_update_id: u64,
     },
+
+    #[error(
+        "UnsupportedSubscription: {exchange} does not support {kind} subscriptions for \
+        {instrument_kind} instruments"
+    )]
+    UnsupportedSubscription {
+        exchange: ExchangeId,
+        kind: &'static str,
+        instrument_kind: InstrumentKind,
+    },
+
+    #[error("MaxSubscriptionsExceeded: {subscriptions} subscriptions exceeds the maximum {max}")]
+    MaxSubscriptionsExceeded { subscriptions: usize, max: usize },
+
+    #[error("BookAnomaly: {instrument} OrderBook is {anomaly:?} (resync: {resync})")]
+    BookAnomaly {
+        instrument: Instrument,
+        anomaly: BookAnomaly,
+        resync: bool,
+    },
+
+    #[error("ExchangeStatus: {exchange} signalled status {status:?}")]
+    ExchangeStatus {
+        exchange: ExchangeId,
+        status: ExchangeStatus,
+    },
+
+    #[error("Persistence: failed to access {}: {error}", path.display())]
+    Persistence {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+}
+
+impl From<SocketError> for DataError {
+    fn from(error: SocketError) -> Self {
+        Self::Socket(Box::new(error))
+    }
 }
 
 impl DataError {
@@ -25,14 +74,75 @@ impl DataError {
     pub fn is_terminal(&self) -> bool {
         match self {
             DataError::InvalidSequence { .. } => true,
+            DataError::BookAnomaly { resync, .. } => *resync,
             _ => false,
         }
     }
+
+    /// Determine if the error was caused by an inbound message that failed to deserialise (eg/ a
+    /// malformed or unrecognised exchange payload).
+    pub fn is_deserialise(&self) -> bool {
+        matches!(
+            self,
+            DataError::Socket(error) if matches!(
+                **error,
+                SocketError::Deserialise { .. } | SocketError::DeserialiseBinary { .. }
+            )
+        )
+    }
+
+    /// Determine if the error is a structured signal that the exchange server is closed for
+    /// maintenance, being either:
+    /// - A [`DataError::ExchangeStatus`] of [`ExchangeStatus::Maintenance`].
+    /// - An HTTP 503 Service Unavailable response (eg/ to the WebSocket upgrade request).
+    pub fn is_maintenance(&self) -> bool {
+        match self {
+            DataError::ExchangeStatus { status, .. } => *status == ExchangeStatus::Maintenance,
+            DataError::Socket(error) => match error.as_ref() {
+                SocketError::WebSocket(WsError::Http(response)) => {
+                    response.status() == StatusCode::SERVICE_UNAVAILABLE
+                }
+                SocketError::HttpResponse(status, _) => *status == StatusCode::SERVICE_UNAVAILABLE,
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Deserialise the payload of an inbound message that failed to deserialise (see
+    /// [`Self::is_deserialise`]) as the provided type.
+    ///
+    /// Used to inspect the fields of exchange events that are not part of the expected data
+    /// format (eg/ an info event announcing a server restart).
+    pub fn unexpected_payload<T>(&self) -> Option<T>
+    where
+        T: DeserializeOwned,
+    {
+        match self {
+            DataError::Socket(error) => match error.as_ref() {
+                SocketError::Deserialise { payload, .. } => serde_json::from_str(payload).ok(),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Determine if the error message contains any of the provided signals, ignoring case.
+    ///
+    /// Used to recognise known exchange signals (eg/ maintenance notices) that are only
+    /// communicated via error messages, close frames or unexpected payloads.
+    pub fn contains_any(&self, signals: &[&str]) -> bool {
+        let message = self.to_string().to_lowercase();
+        signals
+            .iter()
+            .any(|signal| message.contains(&signal.to_lowercase()))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde::Deserialize;
 
     #[test]
     fn test_data_error_is_terminal() {
@@ -52,7 +162,25 @@ mod tests {
             },
             TestCase {
                 // TC1: is not terminal w/ DataError::Socket
-                input: DataError::Socket(SocketError::Sink),
+                input: DataError::from(SocketError::Sink),
+                expected: false,
+            },
+            TestCase {
+                // TC2: is terminal w/ DataError::BookAnomaly requiring a resync
+                input: DataError::BookAnomaly {
+                    instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                    anomaly: BookAnomaly::Locked { price: 100.0 },
+                    resync: true,
+                },
+                expected: true,
+            },
+            TestCase {
+                // TC3: is not terminal w/ DataError::BookAnomaly that is only emitted
+                input: DataError::BookAnomaly {
+                    instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                    anomaly: BookAnomaly::Locked { price: 100.0 },
+                    resync: false,
+                },
                 expected: false,
             },
         ];
@@ -62,4 +190,94 @@ mod tests {
             assert_eq!(actual, test.expected, "TC{} failed", index);
         }
     }
+
+    #[test]
+    fn test_data_error_is_maintenance() {
+        struct TestCase {
+            input: DataError,
+            expected: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: is maintenance w/ ExchangeStatus::Maintenance
+                input: DataError::ExchangeStatus {
+                    exchange: ExchangeId::Kraken,
+                    status: ExchangeStatus::Maintenance,
+                },
+                expected: true,
+            },
+            TestCase {
+                // TC1: is not maintenance w/ any other ExchangeStatus
+                input: DataError::ExchangeStatus {
+                    exchange: ExchangeId::Kraken,
+                    status: ExchangeStatus::CancelOnly,
+                },
+                expected: false,
+            },
+            TestCase {
+                // TC2: is maintenance w/ HTTP 503 Service Unavailable response
+                input: DataError::from(SocketError::HttpResponse(
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    "Service temporarily unavailable".to_string(),
+                )),
+                expected: true,
+            },
+            TestCase {
+                // TC3: is maintenance w/ HTTP 503 WebSocket upgrade response
+                input: DataError::from(SocketError::WebSocket(WsError::Http(
+                    tokio_tungstenite::tungstenite::http::Response::builder()
+                        .status(StatusCode::SERVICE_UNAVAILABLE.as_u16())
+                        .body(None)
+                        .unwrap(),
+                ))),
+                expected: true,
+            },
+            TestCase {
+                // TC4: is not maintenance w/ a message that only mentions maintenance
+                input: DataError::from(SocketError::Terminated(
+                    "Exchange Under Maintenance".to_string(),
+                )),
+                expected: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.input.is_maintenance();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_data_error_unexpected_payload() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Info {
+            code: u32,
+        }
+
+        let payload = r#"{"event":"info","code":20051}"#;
+        let error = DataError::from(SocketError::Deserialise {
+            error: serde_json::from_str::<u32>(payload).unwrap_err(),
+            payload: payload.to_string(),
+        });
+
+        assert_eq!(
+            error.unexpected_payload::<Info>(),
+            Some(Info { code: 20051 })
+        );
+        assert_eq!(
+            DataError::from(SocketError::Sink).unexpected_payload::<Info>(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_data_error_contains_any() {
+        let error = DataError::from(SocketError::Terminated(
+            "Exchange Under Maintenance".to_string(),
+        ));
+
+        assert!(error.contains_any(&["maintenance"]));
+        assert!(!error.contains_any(&["service temporarily unavailable"]));
+    }
 }
@@ -4,6 +4,7 @@ use crate::{
         book::{OrderBook, OrderBookL1},
         candle::Candle,
         liquidation::Liquidation,
+        ticker::Ticker,
         trade::PublicTrade,
     },
 };
@@ -38,11 +39,40 @@ impl<T> FromIterator<Result<MarketEvent<T>, DataError>> for MarketIter<T> {
 pub struct MarketEvent<T> {
     pub exchange_time: DateTime<Utc>,
     pub received_time: DateTime<Utc>,
+    /// Whether the `exchange_time` was synthesized from the `received_time` because the exchange
+    /// omitted a usable timestamp (see [`exchange_time_or_received`]).
+    #[serde(default)]
+    pub exchange_time_synthesized: bool,
     pub exchange: Exchange,
     pub instrument: Instrument,
     pub kind: T,
 }
 
+impl<T> MarketEvent<T> {
+    /// Determine if the `exchange_time` was synthesized from the `received_time` because the
+    /// exchange omitted a usable timestamp (see [`exchange_time_or_received`]).
+    pub fn is_exchange_time_synthesized(&self) -> bool {
+        self.exchange_time_synthesized
+    }
+}
+
+/// Fallback policy applied by every exchange conversion to determine a [`MarketEvent`]
+/// `exchange_time`.
+///
+/// Returns the exchange provided timestamp if it is usable, otherwise the `received_time`, along
+/// with whether the fallback was applied (ie/ the [`MarketEvent`] `exchange_time_synthesized`
+/// flag). A timestamp is unusable if the exchange omitted it, or if it is epoch-zero (eg/
+/// defaulted).
+pub fn exchange_time_or_received(
+    exchange_time: Option<DateTime<Utc>>,
+    received_time: DateTime<Utc>,
+) -> (DateTime<Utc>, bool) {
+    match exchange_time.filter(|time| time.timestamp_nanos_opt() != Some(0)) {
+        Some(exchange_time) => (exchange_time, false),
+        None => (received_time, true),
+    }
+}
+
 /// Available kinds of normalised Barter [`MarketEvent<T>`](MarketEvent).
 ///
 /// ### Notes
@@ -60,6 +90,7 @@ pub enum DataKind {
     OrderBook(OrderBook),
     Candle(Candle),
     Liquidation(Liquidation),
+    Ticker(Ticker),
 }
 
 impl From<MarketEvent<PublicTrade>> for MarketEvent<DataKind> {
@@ -67,6 +98,7 @@ impl From<MarketEvent<PublicTrade>> for MarketEvent<DataKind> {
         Self {
             exchange_time: event.exchange_time,
             received_time: event.received_time,
+            exchange_time_synthesized: event.exchange_time_synthesized,
             exchange: event.exchange,
             instrument: event.instrument,
             kind: DataKind::Trade(event.kind),
@@ -79,6 +111,7 @@ impl From<MarketEvent<OrderBookL1>> for MarketEvent<DataKind> {
         Self {
             exchange_time: event.exchange_time,
             received_time: event.received_time,
+            exchange_time_synthesized: event.exchange_time_synthesized,
             exchange: event.exchange,
             instrument: event.instrument,
             kind: DataKind::OrderBookL1(event.kind),
@@ -91,6 +124,7 @@ impl From<MarketEvent<OrderBook>> for MarketEvent<DataKind> {
         Self {
             exchange_time: event.exchange_time,
             received_time: event.received_time,
+            exchange_time_synthesized: event.exchange_time_synthesized,
             exchange: event.exchange,
             instrument: event.instrument,
             kind: DataKind::OrderBook(event.kind),
@@ -103,6 +137,7 @@ impl From<MarketEvent<Candle>> for MarketEvent<DataKind> {
         Self {
             exchange_time: event.exchange_time,
             received_time: event.received_time,
+            exchange_time_synthesized: event.exchange_time_synthesized,
             exchange: event.exchange,
             instrument: event.instrument,
             kind: DataKind::Candle(event.kind),
@@ -115,9 +150,93 @@ impl From<MarketEvent<Liquidation>> for MarketEvent<DataKind> {
         Self {
             exchange_time: event.exchange_time,
             received_time: event.received_time,
+            exchange_time_synthesized: event.exchange_time_synthesized,
             exchange: event.exchange,
             instrument: event.instrument,
             kind: DataKind::Liquidation(event.kind),
         }
     }
 }
+
+impl From<MarketEvent<Ticker>> for MarketEvent<DataKind> {
+    fn from(event: MarketEvent<Ticker>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange_time_synthesized: event.exchange_time_synthesized,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: DataKind::Ticker(event.kind),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_exchange_time_or_received() {
+        struct TestCase {
+            input: Option<DateTime<Utc>>,
+            expected_synthesized: bool,
+        }
+
+        let received_time = Utc.timestamp_millis_opt(1_700_000_000_123).unwrap();
+
+        let tests = vec![
+            TestCase {
+                // TC0: usable exchange timestamp is retained
+                input: Some(Utc.timestamp_millis_opt(1_700_000_000_000).unwrap()),
+                expected_synthesized: false,
+            },
+            TestCase {
+                // TC1: omitted exchange timestamp falls back to received_time
+                input: None,
+                expected_synthesized: true,
+            },
+            TestCase {
+                // TC2: epoch-zero exchange timestamp falls back to received_time
+                input: Some(DateTime::<Utc>::default()),
+                expected_synthesized: true,
+            },
+            TestCase {
+                // TC3: usable exchange timestamp equal to the received_time is not synthesized
+                input: Some(received_time),
+                expected_synthesized: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (exchange_time, exchange_time_synthesized) =
+                exchange_time_or_received(test.input, received_time);
+            let event = MarketEvent {
+                exchange_time,
+                received_time,
+                exchange_time_synthesized,
+                exchange: Exchange::from("exchange"),
+                instrument: Instrument::from((
+                    "btc",
+                    "usdt",
+                    barter_integration::model::instrument::kind::InstrumentKind::Spot,
+                )),
+                kind: (),
+            };
+
+            assert_eq!(
+                event.is_exchange_time_synthesized(),
+                test.expected_synthesized,
+                "TC{} failed",
+                index
+            );
+            if test.expected_synthesized {
+                assert_eq!(
+                    event.exchange_time, event.received_time,
+                    "TC{} failed",
+                    index
+                );
+            }
+        }
+    }
+}
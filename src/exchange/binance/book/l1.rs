@@ -67,10 +67,12 @@ impl From<(ExchangeId, Instrument, BinanceOrderBookL1)> for MarketIter<OrderBook
         Self(vec![Ok(MarketEvent {
             exchange_time: book.time,
             received_time: Utc::now(),
+            exchange_time_synthesized: false,
             exchange: Exchange::from(exchange_id),
             instrument,
             kind: OrderBookL1 {
                 last_update_time: book.time,
+                sequence: None,
                 best_bid: Level::new(book.best_bid_price, book.best_bid_amount),
                 best_ask: Level::new(book.best_ask_price, book.best_ask_amount),
             },
@@ -78,6 +80,55 @@ impl From<(ExchangeId, Instrument, BinanceOrderBookL1)> for MarketIter<OrderBook
     }
 }
 
+/// [`Binance`](super::super::Binance) all-market OrderBook Level1 (top of book) message.
+///
+/// The [`BinanceOrderBookL1`] [`SubscriptionId`] is synthesized from the all-market channel & the
+/// market (eg/ "!bookTicker|BTCUSDT"), so it can be associated with the subscribed market.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#all-book-tickers-stream>
+/// ```json
+/// {
+///     "e":"bookTicker",
+///     "u":400900217,
+///     "E":1568014460893,
+///     "T":1568014460891,
+///     "s":"BNBUSDT",
+///     "b":"25.35190000",
+///     "B":"31.21000000",
+///     "a":"25.36520000",
+///     "A":"40.66000000"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BinanceAllMarketOrderBookL1(pub BinanceOrderBookL1);
+
+impl<'de> Deserialize<'de> for BinanceAllMarketOrderBookL1 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let mut book = BinanceOrderBookL1::deserialize(deserializer)?;
+
+        // Replace per-market SubscriptionId (eg/ "@bookTicker|BTCUSDT")
+        if let Some((_, market)) = book.subscription_id.0.split_once('|') {
+            book.subscription_id =
+                ExchangeSub::from((BinanceChannel::ORDER_BOOK_L1_ALL_MARKET, market)).id();
+        }
+
+        Ok(Self(book))
+    }
+}
+
+impl IntoIterator for BinanceAllMarketOrderBookL1 {
+    type Item = BinanceOrderBookL1;
+    type IntoIter = std::iter::Once<BinanceOrderBookL1>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        std::iter::once(self.0)
+    }
+}
+
 /// Deserialize a [`BinanceOrderBookL1`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`].
 ///
 /// eg/ "@bookTicker|BTCUSDT"
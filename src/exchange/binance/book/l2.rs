@@ -1,12 +1,28 @@
-use super::{super::channel::BinanceChannel, BinanceLevel};
+use super::{
+    super::{
+        channel::BinanceChannel, futures::l2::HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_FUTURES_USD,
+        spot::l2::HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT,
+    },
+    BinanceLevel,
+};
 use crate::{
-    exchange::subscription::ExchangeSub,
+    error::DataError,
+    exchange::{subscription::ExchangeSub, ExchangeId},
+    streams::{ratelimit::RateLimitMonitor, verify::BookSnapshotSource},
+    subscriber::config::ConnectionConfig,
     subscription::book::{OrderBook, OrderBookSide},
     Identifier,
 };
-use barter_integration::model::{Side, SubscriptionId};
-use chrono::Utc;
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{instrument::Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::DateTime;
+use futures::StreamExt;
 use serde::{Deserialize, Serialize};
+use url::Url;
 
 /// [`Binance`](super::super::Binance) OrderBook Level2 snapshot HTTP message.
 ///
@@ -54,13 +70,133 @@ pub struct BinanceOrderBookL2Snapshot {
 impl From<BinanceOrderBookL2Snapshot> for OrderBook {
     fn from(snapshot: BinanceOrderBookL2Snapshot) -> Self {
         Self {
-            last_update_time: Utc::now(),
+            last_update_time: DateTime::default(),
             bids: OrderBookSide::new(Side::Buy, snapshot.bids),
             asks: OrderBookSide::new(Side::Sell, snapshot.asks),
         }
     }
 }
 
+/// Fetch a [`BinanceOrderBookL2Snapshot`] for the provided [`Instrument`] from the provided
+/// HTTP OrderBook L2 snapshot url, recording the used request weight of the exchange in the
+/// provided [`RateLimitMonitor`].
+pub async fn fetch_snapshot(
+    exchange: ExchangeId,
+    url: &str,
+    instrument: &Instrument,
+    rate_limits: Option<&RateLimitMonitor>,
+) -> Result<BinanceOrderBookL2Snapshot, DataError> {
+    // Construct OrderBook snapshot GET url
+    let snapshot_url = format!(
+        "{}?symbol={}{}&limit=100",
+        url,
+        instrument.base.as_ref().to_uppercase(),
+        instrument.quote.as_ref().to_uppercase()
+    );
+
+    // Fetch OrderBook snapshot via HTTP
+    let response = reqwest::get(snapshot_url)
+        .await
+        .map_err(SocketError::Http)?;
+
+    if let Some(rate_limits) = rate_limits {
+        rate_limits.record_headers(exchange, response.headers());
+    }
+
+    response
+        .json::<BinanceOrderBookL2Snapshot>()
+        .await
+        .map_err(SocketError::Http)
+        .map_err(DataError::from)
+}
+
+/// Fetch a [`BinanceOrderBookL2Snapshot`] for the provided [`Instrument`] from the first message
+/// of the "depth20" partial depth stream at the provided WebSocket base url, avoiding the request
+/// weight of [`fetch_snapshot`] at the cost of only seeding the top 20 levels.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#partial-book-depth-streams>
+pub async fn fetch_partial_depth_snapshot(
+    base_url: &str,
+    instrument: &Instrument,
+    config: &ConnectionConfig,
+) -> Result<BinanceOrderBookL2Snapshot, DataError> {
+    // Construct partial depth stream url (eg/ "wss://stream.binance.com:9443/ws/btcusdt@depth20@100ms")
+    let url = Url::parse(&format!(
+        "{}/{}{}@depth20@100ms",
+        base_url.trim_end_matches('/'),
+        instrument.base.as_ref().to_lowercase(),
+        instrument.quote.as_ref().to_lowercase()
+    ))
+    .map_err(SocketError::UrlParse)?;
+
+    let mut websocket = config.connect(url).await?;
+
+    // Await the first data frame, which contains the partial depth snapshot
+    let first = tokio::time::timeout(config.handshake_timeout, async {
+        while let Some(message) = websocket.next().await {
+            match message.map_err(SocketError::WebSocket)? {
+                WsMessage::Text(payload) => return Ok(payload.into_bytes()),
+                WsMessage::Binary(payload) => return Ok(payload),
+                WsMessage::Close(frame) => {
+                    return Err(SocketError::Terminated(format!("{frame:?}")));
+                }
+                _ => continue,
+            }
+        }
+        Err(SocketError::Terminated(
+            "partial depth stream ended before a snapshot".to_owned(),
+        ))
+    })
+    .await
+    .map_err(|_| {
+        SocketError::Subscribe(format!(
+            "partial depth snapshot timeout reached: {:?}",
+            config.handshake_timeout
+        ))
+    })??;
+
+    let _ = websocket.close(None).await;
+
+    serde_json::from_slice::<BinanceOrderBookL2Snapshot>(&first)
+        .map_err(|error| SocketError::Deserialise {
+            error,
+            payload: String::from_utf8_lossy(&first).into_owned(),
+        })
+        .map_err(DataError::from)
+}
+
+/// [`BookSnapshotSource`] that fetches reference [`Binance`](super::super::Binance) OrderBook
+/// Level2 snapshots via HTTP, used to verify locally maintained [`OrderBook`]s.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BinanceBookSnapshotSource {
+    pub exchange: ExchangeId,
+    pub url: &'static str,
+}
+
+impl BinanceBookSnapshotSource {
+    /// [`BinanceSpot`](super::super::spot::BinanceSpot) [`BinanceBookSnapshotSource`].
+    pub const SPOT: Self = Self {
+        exchange: ExchangeId::BinanceSpot,
+        url: HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT,
+    };
+
+    /// [`BinanceFuturesUsd`](super::super::futures::BinanceFuturesUsd)
+    /// [`BinanceBookSnapshotSource`].
+    pub const FUTURES_USD: Self = Self {
+        exchange: ExchangeId::BinanceFuturesUsd,
+        url: HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_FUTURES_USD,
+    };
+}
+
+#[async_trait]
+impl BookSnapshotSource for BinanceBookSnapshotSource {
+    async fn snapshot(&self, instrument: &Instrument) -> Result<OrderBook, DataError> {
+        fetch_snapshot(self.exchange, self.url, instrument, None)
+            .await
+            .map(OrderBook::from)
+    }
+}
+
 /// Deserialize a
 /// [`BinanceSpotOrderBookL2Delta`](super::super::spot::l2::BinanceSpotOrderBookL2Delta) or
 /// [`BinanceFuturesOrderBookL2Delta`](super::super::futures::l2::BinanceFuturesOrderBookL2Delta)
@@ -79,6 +215,45 @@ where
 mod tests {
     use super::*;
 
+    #[tokio::test]
+    async fn test_fetch_snapshot_records_used_request_weight() {
+        use barter_integration::model::instrument::kind::InstrumentKind;
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        // Mock BinanceSpot OrderBook snapshot endpoint reporting 1500 used request weight
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/api/v3/depth", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = [0u8; 1024];
+            let _ = stream.read(&mut request).await.unwrap();
+
+            let body =
+                r#"{"lastUpdateId":1027024,"bids":[["4.00000000","431.00000000"]],"asks":[]}"#;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\nx-mbx-used-weight: 1500\r\nx-mbx-used-weight-1m: 1500\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+        });
+
+        let rate_limits = RateLimitMonitor::default();
+        let snapshot = fetch_snapshot(
+            ExchangeId::BinanceSpot,
+            &url,
+            &Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            Some(&rate_limits),
+        )
+        .await
+        .unwrap();
+        assert_eq!(snapshot.last_update_id, 1027024);
+
+        let usage = rate_limits.usage(ExchangeId::BinanceSpot).unwrap();
+        assert_eq!(usage.used, 1500);
+        assert_eq!(usage.utilisation(), 0.25);
+    }
+
     mod de {
         use super::*;
 
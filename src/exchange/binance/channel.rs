@@ -1,15 +1,17 @@
-use super::{futures::BinanceFuturesUsd, Binance};
+use super::{futures::BinanceFuturesUsd, spot::BinanceSpot, Binance};
+use crate::subscription::candle::{CandleInterval, Candles, ClosedCandles, IntervalCandles};
 use crate::{
     subscription::{
-        book::{OrderBooksL1, OrderBooksL2},
-        liquidation::Liquidations,
+        book::{AllMarketOrderBooksL1, OrderBooksL1, OrderBooksL2, OrderBooksL2Speed},
+        derivatives::DerivativesStatistics,
+        liquidation::{AllMarketLiquidations, Liquidations},
+        ticker::AllMarketTickers,
         trade::PublicTrades,
         Subscription,
     },
     Identifier,
 };
 use serde::Serialize;
-use crate::subscription::candle::Candles;
 
 /// Type that defines how to translate a Barter [`Subscription`] into a [`Binance`](super::Binance)
 /// channel to be subscribed to.
@@ -43,12 +45,84 @@ impl BinanceChannel {
     /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#diff-book-depth-streams>
     pub const ORDER_BOOK_L2: Self = Self("@depth@100ms");
 
+    /// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) OrderBook Level2 channel name
+    /// (500ms delta updates).
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#diff-book-depth-streams>
+    pub const ORDER_BOOK_L2_500MS: Self = Self("@depth@500ms");
+
+    /// [`Binance`](super::Binance) OrderBook Level2 channel name at the default update speed
+    /// (1000ms for [`BinanceSpot`](super::spot::BinanceSpot), 250ms for
+    /// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd)).
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#diff-depth-stream>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#diff-book-depth-streams>
+    pub const ORDER_BOOK_L2_DEFAULT_SPEED: Self = Self("@depth");
+
+    /// Placeholder channel of an unsupported
+    /// [`OrderBooksL2Speed`](crate::subscription::book::OrderBooksL2Speed) update speed, which is
+    /// rejected by [`Connector::select_channel`](crate::exchange::Connector::select_channel)
+    /// before connecting.
+    pub const ORDER_BOOK_L2_UNSUPPORTED_SPEED: Self = Self("@depth@unsupported");
+
     /// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) liquidation orders channel name.
     ///
     /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#liquidation-order-streams>
     pub const LIQUIDATIONS: Self = Self("@forceOrder");
 
+    /// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) all-market liquidation orders
+    /// channel name.
+    ///
+    /// Note: all-market channels (ie/ prefixed with "!") are subscribed to without a market.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#all-market-liquidation-order-streams>
+    pub const LIQUIDATIONS_ALL_MARKET: Self = Self("!forceOrder@arr");
+
+    /// [`Binance`](super::Binance) all-market OrderBook Level1 (top of book) channel name.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#all-book-tickers-stream>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#all-book-tickers-stream>
+    pub const ORDER_BOOK_L1_ALL_MARKET: Self = Self("!bookTicker");
+
+    /// [`Binance`](super::Binance) all-market rolling 24hr ticker channel name.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#all-market-tickers-stream>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#all-market-tickers-streams>
+    pub const TICKERS_ALL_MARKET: Self = Self("!ticker@arr");
+
     pub const CANDLES: Self = Self("@kline_1m");
+
+    /// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) mark price channel name,
+    /// delivering the mark price, index price & funding rate every 3 seconds.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#mark-price-stream>
+    pub const MARK_PRICE: Self = Self("@markPrice");
+
+    /// [`Binance`](super::Binance) real-time Kline channel name of the provided
+    /// [`CandleInterval`].
+    ///
+    /// Note:
+    /// Only [`BinanceSpot`](super::spot::BinanceSpot) offers the [`CandleInterval::S1`]
+    /// interval.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#kline-candlestick-streams>
+    pub fn candles(interval: CandleInterval) -> Self {
+        match interval {
+            CandleInterval::S1 => Self("@kline_1s"),
+            CandleInterval::M1 => Self::CANDLES,
+            CandleInterval::M3 => Self("@kline_3m"),
+            CandleInterval::M5 => Self("@kline_5m"),
+            CandleInterval::M15 => Self("@kline_15m"),
+            CandleInterval::M30 => Self("@kline_30m"),
+            CandleInterval::H1 => Self("@kline_1h"),
+            CandleInterval::H2 => Self("@kline_2h"),
+            CandleInterval::H4 => Self("@kline_4h"),
+            CandleInterval::H6 => Self("@kline_6h"),
+            CandleInterval::H12 => Self("@kline_12h"),
+            CandleInterval::D1 => Self("@kline_1d"),
+            CandleInterval::W1 => Self("@kline_1w"),
+        }
+    }
 }
 
 impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, PublicTrades> {
@@ -63,26 +137,396 @@ impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, OrderB
     }
 }
 
+impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, AllMarketOrderBooksL1> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::ORDER_BOOK_L1_ALL_MARKET
+    }
+}
+
+impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, AllMarketTickers> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::TICKERS_ALL_MARKET
+    }
+}
+
 impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, OrderBooksL2> {
     fn id(&self) -> BinanceChannel {
         BinanceChannel::ORDER_BOOK_L2
     }
 }
 
+impl Identifier<BinanceChannel> for Subscription<BinanceSpot, OrderBooksL2Speed> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::spot_order_book_l2(self.kind.update_speed_ms)
+            .unwrap_or(BinanceChannel::ORDER_BOOK_L2_UNSUPPORTED_SPEED)
+    }
+}
+
+impl Identifier<BinanceChannel> for Subscription<BinanceFuturesUsd, OrderBooksL2Speed> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::futures_order_book_l2(self.kind.update_speed_ms)
+            .unwrap_or(BinanceChannel::ORDER_BOOK_L2_UNSUPPORTED_SPEED)
+    }
+}
+
 impl Identifier<BinanceChannel> for Subscription<BinanceFuturesUsd, Liquidations> {
     fn id(&self) -> BinanceChannel {
         BinanceChannel::LIQUIDATIONS
     }
 }
 
+impl Identifier<BinanceChannel> for Subscription<BinanceFuturesUsd, AllMarketLiquidations> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::LIQUIDATIONS_ALL_MARKET
+    }
+}
+
+impl Identifier<BinanceChannel> for Subscription<BinanceFuturesUsd, DerivativesStatistics> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::MARK_PRICE
+    }
+}
+
 impl Identifier<BinanceChannel> for Subscription<BinanceFuturesUsd, Candles> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::CANDLES
+    }
+}
 
-    fn id(&self) -> BinanceChannel { BinanceChannel::CANDLES}
+impl Identifier<BinanceChannel> for Subscription<BinanceFuturesUsd, ClosedCandles> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::CANDLES
+    }
+}
 
+impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, IntervalCandles> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::candles(self.kind.interval)
+    }
+}
+
+impl BinanceChannel {
+    /// Determine if the [`BinanceChannel`] is an all-market channel that is subscribed to
+    /// without a market (eg/ "!forceOrder@arr").
+    pub fn is_all_market(&self) -> bool {
+        self.0.starts_with('!')
+    }
+
+    /// Determine if the [`BinanceChannel`] is an OrderBook Level2 diff depth channel, at any
+    /// update speed.
+    pub fn is_order_book_l2(&self) -> bool {
+        self.0 == Self::ORDER_BOOK_L2_DEFAULT_SPEED.0 || self.0.starts_with("@depth@")
+    }
+
+    /// [`BinanceSpot`](super::spot::BinanceSpot) OrderBook Level2 channel of the provided update
+    /// speed, if supported (100ms & 1000ms).
+    pub fn spot_order_book_l2(update_speed_ms: u64) -> Option<Self> {
+        match update_speed_ms {
+            100 => Some(Self::ORDER_BOOK_L2),
+            1000 => Some(Self::ORDER_BOOK_L2_DEFAULT_SPEED),
+            _ => None,
+        }
+    }
+
+    /// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) OrderBook Level2 channel of the
+    /// provided update speed, if supported (100ms, 250ms & 500ms).
+    pub fn futures_order_book_l2(update_speed_ms: u64) -> Option<Self> {
+        match update_speed_ms {
+            100 => Some(Self::ORDER_BOOK_L2),
+            250 => Some(Self::ORDER_BOOK_L2_DEFAULT_SPEED),
+            500 => Some(Self::ORDER_BOOK_L2_500MS),
+            _ => None,
+        }
+    }
+}
+
+impl From<&'static str> for BinanceChannel {
+    fn from(channel: &'static str) -> Self {
+        Self(channel)
+    }
 }
 
 impl AsRef<str> for BinanceChannel {
     fn as_ref(&self) -> &str {
-        self.0
+        // Diff depth messages do not contain the update speed of their stream, so every Level2
+        // channel shares the SubscriptionId of the ORDER_BOOK_L2 channel (eg/ "@depth@100ms|BTCUSDT")
+        if self.is_order_book_l2() {
+            Self::ORDER_BOOK_L2.0
+        } else {
+            self.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        error::DataError,
+        exchange::{
+            binance::{futures::candle::BinanceIntervalCandle, market::BinanceMarket},
+            subscription::ExchangeSub,
+            Connector,
+        },
+        streams::builder::validate,
+        subscriber::{
+            config::ConnectionConfig,
+            mapper::{SubscriptionMapper, WebSocketSubMapper},
+        },
+    };
+    use barter_integration::{
+        model::{
+            instrument::{kind::InstrumentKind, Instrument},
+            SubscriptionId,
+        },
+        protocol::websocket::WsMessage,
+    };
+
+    #[test]
+    fn test_binance_order_book_l2_update_speed_channel() {
+        struct TestCase {
+            input: u64,
+            expected_spot: BinanceChannel,
+            expected_futures: BinanceChannel,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: 100ms update speed
+                input: 100,
+                expected_spot: BinanceChannel("@depth@100ms"),
+                expected_futures: BinanceChannel("@depth@100ms"),
+            },
+            TestCase {
+                // TC1: default 1000ms spot update speed, unsupported by futures
+                input: 1000,
+                expected_spot: BinanceChannel("@depth"),
+                expected_futures: BinanceChannel::ORDER_BOOK_L2_UNSUPPORTED_SPEED,
+            },
+            TestCase {
+                // TC2: default 250ms futures update speed, unsupported by spot
+                input: 250,
+                expected_spot: BinanceChannel::ORDER_BOOK_L2_UNSUPPORTED_SPEED,
+                expected_futures: BinanceChannel("@depth"),
+            },
+            TestCase {
+                // TC3: 500ms futures update speed, unsupported by spot
+                input: 500,
+                expected_spot: BinanceChannel::ORDER_BOOK_L2_UNSUPPORTED_SPEED,
+                expected_futures: BinanceChannel("@depth@500ms"),
+            },
+            TestCase {
+                // TC4: update speed unsupported by both
+                input: 10,
+                expected_spot: BinanceChannel::ORDER_BOOK_L2_UNSUPPORTED_SPEED,
+                expected_futures: BinanceChannel::ORDER_BOOK_L2_UNSUPPORTED_SPEED,
+            },
+        ];
+
+        let spot = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let perpetual = Instrument::from(("btc", "usdt", InstrumentKind::Perpetual));
+        for (index, test) in tests.into_iter().enumerate() {
+            let kind = OrderBooksL2Speed::new(test.input);
+
+            let actual: BinanceChannel =
+                Subscription::new(BinanceSpot::default(), spot.clone(), kind).id();
+            assert_eq!(actual, test.expected_spot, "TC{} failed", index);
+
+            let actual: BinanceChannel =
+                Subscription::new(BinanceFuturesUsd::default(), perpetual.clone(), kind).id();
+            assert_eq!(actual, test.expected_futures, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_binance_order_book_l2_update_speed_request_and_subscription_id() {
+        let market = BinanceMarket("BTCUSDT".to_string());
+        let exchange_sub = ExchangeSub::from((BinanceChannel("@depth@100ms"), market.clone()));
+        assert_eq!(
+            exchange_sub.id(),
+            SubscriptionId::from("@depth@100ms|BTCUSDT")
+        );
+
+        // Update speed is sent in the stream name, but every Level2 channel shares the
+        // SubscriptionId of the diff depth messages, which do not contain the update speed
+        let exchange_sub = ExchangeSub::from((BinanceChannel("@depth"), market));
+        assert_eq!(
+            exchange_sub.id(),
+            SubscriptionId::from("@depth@100ms|BTCUSDT")
+        );
+
+        let WsMessage::Text(request) = &BinanceSpot::requests(vec![exchange_sub])[0] else {
+            panic!("Binance subscription request is not a text WsMessage")
+        };
+        let request = serde_json::from_str::<serde_json::Value>(request).unwrap();
+        assert_eq!(request["params"], serde_json::json!(["btcusdt@depth"]));
+
+        // Other channels are unaffected
+        assert_eq!(BinanceChannel::CANDLES.as_ref(), "@kline_1m");
+        assert_eq!(
+            BinanceChannel::LIQUIDATIONS_ALL_MARKET.as_ref(),
+            "!forceOrder@arr"
+        );
+    }
+
+    #[test]
+    fn test_binance_order_book_l2_update_speed_mapping() {
+        struct TestCase {
+            input: Vec<u64>,
+            expected_ok: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: supported update speed
+                input: vec![1000],
+                expected_ok: true,
+            },
+            TestCase {
+                // TC1: unsupported update speed is rejected before connecting
+                input: vec![250],
+                expected_ok: false,
+            },
+            TestCase {
+                // TC2: distinct update speeds of the same market cannot be told apart
+                input: vec![100, 1000],
+                expected_ok: false,
+            },
+        ];
+
+        let spot = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        for (index, test) in tests.into_iter().enumerate() {
+            let subscriptions = test
+                .input
+                .into_iter()
+                .map(|speed| {
+                    Subscription::new(
+                        BinanceSpot::default(),
+                        spot.clone(),
+                        OrderBooksL2Speed::new(speed),
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            let actual = WebSocketSubMapper::map::<BinanceSpot, OrderBooksL2Speed>(
+                &subscriptions,
+                &ConnectionConfig::default(),
+            );
+            assert_eq!(actual.is_ok(), test.expected_ok, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_binance_order_book_l2_update_speed_validation() {
+        struct TestCase {
+            spot_speed: u64,
+            futures_speed: u64,
+            expected: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: supported update speeds
+                spot_speed: 1000,
+                futures_speed: 250,
+                expected: true,
+            },
+            TestCase {
+                // TC1: unsupported update speeds
+                spot_speed: 250,
+                futures_speed: 1000,
+                expected: false,
+            },
+        ];
+
+        let spot = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let perpetual = Instrument::from(("btc", "usdt", InstrumentKind::Perpetual));
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = validate(&[Subscription::new(
+                BinanceSpot::default(),
+                spot.clone(),
+                OrderBooksL2Speed::new(test.spot_speed),
+            )]);
+            assert_eq!(actual.is_ok(), test.expected, "TC{} failed", index);
+
+            let actual = validate(&[Subscription::new(
+                BinanceFuturesUsd::default(),
+                perpetual.clone(),
+                OrderBooksL2Speed::new(test.futures_speed),
+            )]);
+            assert_eq!(actual.is_ok(), test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_binance_interval_candles_validation() {
+        struct TestCase {
+            interval: CandleInterval,
+            expected_channel: BinanceChannel,
+            expected_spot: bool,
+            expected_futures: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: 1s interval is only offered by BinanceSpot
+                interval: CandleInterval::S1,
+                expected_channel: BinanceChannel("@kline_1s"),
+                expected_spot: true,
+                expected_futures: false,
+            },
+            TestCase {
+                // TC1: 1m interval is offered by both
+                interval: CandleInterval::M1,
+                expected_channel: BinanceChannel::CANDLES,
+                expected_spot: true,
+                expected_futures: true,
+            },
+            TestCase {
+                // TC2: 4h interval is offered by both
+                interval: CandleInterval::H4,
+                expected_channel: BinanceChannel("@kline_4h"),
+                expected_spot: true,
+                expected_futures: true,
+            },
+        ];
+
+        let spot = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let perpetual = Instrument::from(("btc", "usdt", InstrumentKind::Perpetual));
+        for (index, test) in tests.into_iter().enumerate() {
+            let kind = IntervalCandles::new(test.interval);
+
+            let subscription = Subscription::new(BinanceSpot::default(), spot.clone(), kind);
+            let actual: BinanceChannel = subscription.id();
+            assert_eq!(actual, test.expected_channel, "TC{} failed", index);
+            let actual = validate(&[subscription]);
+            assert_eq!(actual.is_ok(), test.expected_spot, "TC{} failed", index);
+
+            let subscription =
+                Subscription::new(BinanceFuturesUsd::default(), perpetual.clone(), kind);
+            let actual = validate(&[subscription]);
+            assert_eq!(actual.is_ok(), test.expected_futures, "TC{} failed", index);
+            if !test.expected_futures {
+                assert!(
+                    matches!(actual, Err(DataError::UnsupportedSubscription { .. })),
+                    "TC{} failed",
+                    index
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_binance_interval_candle_subscription_id() {
+        let input = r#"{"e":"kline","E":1638747660000,"s":"BTCUSDT","k":{"t":1638747660000,"T":1638747660999,"s":"BTCUSDT","i":"1s","f":100,"L":200,"o":"0.0010","c":"0.0020","h":"0.0025","l":"0.0015","v":"1000","n":100,"x":true,"q":"1.0000","V":"500","Q":"0.500","B":"123456"}}"#;
+        let candle = serde_json::from_str::<BinanceIntervalCandle>(input).unwrap();
+
+        // Kline routed by the interval channel it was subscribed to
+        let exchange_sub = ExchangeSub::from((
+            BinanceChannel::candles(CandleInterval::S1),
+            BinanceMarket("BTCUSDT".to_string()),
+        ));
+        assert_eq!(candle.id(), Some(exchange_sub.id()));
+        assert_eq!(candle.id(), Some(SubscriptionId::from("@kline_1s|BTCUSDT")));
     }
 }
@@ -0,0 +1,170 @@
+use super::{
+    futures::{candle::BinanceCandle, liquidation::BinanceLiquidation},
+    spot::l2::BinanceSpotOrderBookL2Delta,
+    trade::BinanceTrade,
+};
+use crate::{
+    event::{DataKind, MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::{candle::Candle, liquidation::Liquidation, trade::PublicTrade},
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, SubscriptionId};
+use serde::{Deserialize, Serialize};
+
+/// Binance real-time WebSocket event, dispatched to the typed message of its "e" event type.
+///
+/// Enables a single connection subscribed to a mix of channels (eg/ "@trade" & "@kline_1m") to
+/// demultiplex every message into the appropriate [`MarketEvent<DataKind>`](DataKind) kind.
+///
+/// ### Notes
+/// A "depthUpdate" delta must be applied to a locally maintained OrderBook (see
+/// [`MultiBookTransformer`](crate::transformer::book::MultiBookTransformer)), so it is
+/// identified but yields no [`MarketEvent<DataKind>`](DataKind). The
+/// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) "pu" field is ignored.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#websocket-market-streams>
+/// ```json
+/// {"e":"trade","E":1649324825173,"s":"ETHUSDT","t":1000000000,"p":"10000.19","q":"0.239000","T":1749354825200,"m":false,"M":true}
+/// ```
+///
+/// ```json
+/// {"e":"kline","E":1638747660000,"s":"BTCUSDT","k":{"t":1638747660000,"T":1638747719999,"s":"BTCUSDT","i":"1m","f":100,"L":200,"o":"0.0010","c":"0.0020","h":"0.0025","l":"0.0015","v":"1000","n":100,"x":false,"q":"1.0000","V":"500","Q":"0.500","B":"123456"}}
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(tag = "e")]
+pub enum BinanceEvent {
+    #[serde(rename = "trade")]
+    Trade(BinanceTrade),
+    #[serde(rename = "kline")]
+    Kline(BinanceCandle),
+    #[serde(rename = "depthUpdate")]
+    DepthUpdate(BinanceSpotOrderBookL2Delta),
+    #[serde(rename = "forceOrder")]
+    ForceOrder(BinanceLiquidation),
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceEvent {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            Self::Trade(trade) => trade.id(),
+            Self::Kline(candle) => candle.id(),
+            Self::DepthUpdate(delta) => delta.id(),
+            Self::ForceOrder(liquidation) => liquidation.id(),
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, BinanceEvent)> for MarketIter<DataKind> {
+    fn from((exchange_id, instrument, event): (ExchangeId, Instrument, BinanceEvent)) -> Self {
+        fn into_data_kind<T>(events: MarketIter<T>) -> MarketIter<DataKind>
+        where
+            MarketEvent<DataKind>: From<MarketEvent<T>>,
+        {
+            events
+                .0
+                .into_iter()
+                .map(|event| event.map(MarketEvent::from))
+                .collect()
+        }
+
+        match event {
+            BinanceEvent::Trade(trade) => {
+                let events: MarketIter<PublicTrade> = (exchange_id, instrument, trade).into();
+                into_data_kind(events)
+            }
+            BinanceEvent::Kline(candle) => {
+                let events: MarketIter<Candle> = (exchange_id, instrument, candle).into();
+                into_data_kind(events)
+            }
+            BinanceEvent::DepthUpdate(_) => Self(vec![]),
+            BinanceEvent::ForceOrder(liquidation) => {
+                let events: MarketIter<Liquidation> = (exchange_id, instrument, liquidation).into();
+                into_data_kind(events)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    #[test]
+    fn test_binance_event_dispatches_interleaved_messages() {
+        struct TestCase {
+            input: &'static str,
+            expected_id: &'static str,
+            expected_kind: &'static str,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: trade
+                input: r#"{"e":"trade","E":1649324825173,"s":"BTCUSDT","t":1000000000,"p":"10000.19","q":"0.239000","T":1749354825200,"m":false,"M":true}"#,
+                expected_id: "@trade|BTCUSDT",
+                expected_kind: "trade",
+            },
+            TestCase {
+                // TC1: kline interleaved between trades
+                input: r#"{"e":"kline","E":1638747660000,"s":"BTCUSDT","k":{"t":1638747660000,"T":1638747719999,"s":"BTCUSDT","i":"1m","f":100,"L":200,"o":"0.0010","c":"0.0020","h":"0.0025","l":"0.0015","v":"1000","n":100,"x":false,"q":"1.0000","V":"500","Q":"0.500","B":"123456"}}"#,
+                expected_id: "@kline_1m|BTCUSDT",
+                expected_kind: "candle",
+            },
+            TestCase {
+                // TC2: trade following a kline
+                input: r#"{"e":"trade","E":1649324825174,"s":"BTCUSDT","t":1000000001,"p":"10000.20","q":"0.100000","T":1749354825201,"m":true,"M":true}"#,
+                expected_id: "@trade|BTCUSDT",
+                expected_kind: "trade",
+            },
+            TestCase {
+                // TC3: forceOrder liquidation
+                input: r#"{"e":"forceOrder","E":1665523974222,"o":{"s":"BTCUSDT","S":"SELL","o":"LIMIT","f":"IOC","q":"0.009","p":"18917.15","ap":"18990.00","X":"FILLED","l":"0.009","z":"0.009","T":1665523974217}}"#,
+                expected_id: "@forceOrder|BTCUSDT",
+                expected_kind: "liquidation",
+            },
+            TestCase {
+                // TC4: depthUpdate is identified but yields no MarketEvent
+                input: r#"{"e":"depthUpdate","E":1671656397761,"s":"BTCUSDT","U":22611425143,"u":22611425151,"b":[["1209.67000000","85.48210000"]],"a":[]}"#,
+                expected_id: "@depth@100ms|BTCUSDT",
+                expected_kind: "none",
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let event = serde_json::from_str::<BinanceEvent>(test.input).unwrap();
+            assert_eq!(
+                event.id(),
+                Some(SubscriptionId::from(test.expected_id)),
+                "TC{} failed",
+                index
+            );
+
+            let actual = MarketIter::<DataKind>::from((
+                ExchangeId::BinanceFuturesUsd,
+                Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+                event,
+            ))
+            .0
+            .into_iter()
+            .map(|event| match event.unwrap().kind {
+                DataKind::Trade(_) => "trade",
+                DataKind::Candle(_) => "candle",
+                DataKind::Liquidation(_) => "liquidation",
+                kind => panic!("TC{index} failed: unexpected DataKind {kind:?}"),
+            })
+            .next()
+            .unwrap_or("none");
+
+            assert_eq!(actual, test.expected_kind, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_binance_event_unknown_type_fails_to_deserialise() {
+        let input = r#"{"e":"24hrTicker","E":1649324825173,"s":"BTCUSDT"}"#;
+        assert!(serde_json::from_str::<BinanceEvent>(input).is_err());
+    }
+}
@@ -1,24 +1,26 @@
-use super::super::book::{l2::BinanceOrderBookL2Snapshot, BinanceLevel};
+use super::super::book::{l2::fetch_snapshot, BinanceLevel};
 use crate::{
     error::DataError,
+    exchange::ExchangeId,
+    subscriber::config::ConnectionConfig,
     subscription::book::OrderBook,
     transformer::book::{InstrumentOrderBook, OrderBookUpdater},
     Identifier,
 };
 use async_trait::async_trait;
 use barter_integration::{
-    error::SocketError,
     model::{instrument::Instrument, SubscriptionId},
     protocol::websocket::WsMessage,
 };
-use chrono::Utc;
+use chrono::DateTime;
 use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc;
 
 /// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) HTTP OrderBook L2 snapshot url.
 ///
 /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#order-book>
-pub const HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT: &str = "https://fapi.binance.com/fapi/v1/depth";
+pub const HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_FUTURES_USD: &str =
+    "https://fapi.binance.com/fapi/v1/depth";
 
 /// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) OrderBook Level2 deltas WebSocket message.
 ///
@@ -168,21 +170,14 @@ impl OrderBookUpdater for BinanceFuturesBookUpdater {
         Exchange: Send,
         Kind: Send,
     {
-        // Construct initial OrderBook snapshot GET url
-        let snapshot_url = format!(
-            "{}?symbol={}{}&limit=100",
-            HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT,
-            instrument.base.as_ref().to_uppercase(),
-            instrument.quote.as_ref().to_uppercase()
-        );
-
         // Fetch initial OrderBook snapshot via HTTP
-        let snapshot = reqwest::get(snapshot_url)
-            .await
-            .map_err(SocketError::Http)?
-            .json::<BinanceOrderBookL2Snapshot>()
-            .await
-            .map_err(SocketError::Http)?;
+        let snapshot = fetch_snapshot(
+            ExchangeId::BinanceFuturesUsd,
+            HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_FUTURES_USD,
+            &instrument,
+            None,
+        )
+        .await?;
 
         Ok(InstrumentOrderBook {
             instrument,
@@ -191,6 +186,36 @@ impl OrderBookUpdater for BinanceFuturesBookUpdater {
         })
     }
 
+    async fn init_with_config<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+        config: &ConnectionConfig,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Fetch initial OrderBook snapshot via HTTP, recording the used request weight
+        let snapshot = fetch_snapshot(
+            ExchangeId::BinanceFuturesUsd,
+            HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_FUTURES_USD,
+            &instrument,
+            config.rate_limits.as_ref(),
+        )
+        .await?;
+
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::new(snapshot.last_update_id),
+            book: OrderBook::from(snapshot),
+        })
+    }
+
+    fn resumable() -> bool {
+        // Every update is sequence validated, so any update missed while disconnected is detected
+        true
+    }
+
     fn update(
         &mut self,
         book: &mut Self::OrderBook,
@@ -216,7 +241,9 @@ impl OrderBookUpdater for BinanceFuturesBookUpdater {
         // Update OrderBook metadata & Levels:
         // 7. The data in each event is the absolute quantity for a price level.
         // 8. If the quantity is 0, remove the price level.
-        book.last_update_time = Utc::now();
+        // Depth update event times are not deserialised, so leave the last_update_time unset
+        // for the MarketEvent exchange_time to be synthesized from the received time
+        book.last_update_time = DateTime::default();
         book.bids.upsert(update.bids);
         book.asks.upsert(update.asks);
 
@@ -285,6 +312,7 @@ mod tests {
         use super::*;
         use crate::subscription::book::{Level, OrderBookSide};
         use barter_integration::model::Side;
+        use chrono::Utc;
 
         #[test]
         fn test_is_first_update() {
@@ -406,6 +434,7 @@ mod tests {
             for (index, test) in tests.into_iter().enumerate() {
                 let actual = test.updater.validate_first_update(&test.input);
                 match (actual, test.expected) {
+                    #[allow(clippy::unit_cmp)]
                     (Ok(actual), Ok(expected)) => {
                         assert_eq!(actual, expected, "TC{} failed", index)
                     }
@@ -469,6 +498,7 @@ mod tests {
             for (index, test) in tests.into_iter().enumerate() {
                 let actual = test.updater.validate_next_update(&test.input);
                 match (actual, test.expected) {
+                    #[allow(clippy::unit_cmp)]
                     (Ok(actual), Ok(expected)) => {
                         assert_eq!(actual, expected, "TC{} failed", index)
                     }
@@ -607,5 +637,64 @@ mod tests {
                 }
             }
         }
+
+        #[test]
+        fn test_update_with_pu_chained_sequence() {
+            fn delta(first: u64, last: u64, prev_last: u64) -> BinanceFuturesOrderBookL2Delta {
+                BinanceFuturesOrderBookL2Delta {
+                    subscription_id: SubscriptionId::from("subscription_id"),
+                    first_update_id: first,
+                    last_update_id: last,
+                    prev_last_update_id: prev_last,
+                    bids: vec![BinanceLevel {
+                        price: 100.0,
+                        amount: last as f64,
+                    }],
+                    asks: vec![],
+                }
+            }
+
+            let mut updater = BinanceFuturesBookUpdater::new(100);
+            let mut book = OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, vec![Level::new(100, 1)]),
+                asks: OrderBookSide::new(Side::Sell, vec![Level::new(110, 1)]),
+            };
+
+            // Valid pu-chained sequence: each pu equals the previous event's u
+            assert!(updater
+                .update(&mut book, delta(95, 105, 90))
+                .unwrap()
+                .is_some());
+            assert!(updater
+                .update(&mut book, delta(106, 110, 105))
+                .unwrap()
+                .is_some());
+            assert!(updater
+                .update(&mut book, delta(111, 120, 110))
+                .unwrap()
+                .is_some());
+            assert_eq!(updater.last_update_id, 120);
+            let book_before_break = book.clone();
+
+            // Broken pu link: U/u are contiguous with spot rules, but pu != previous u
+            match updater.update(&mut book, delta(121, 130, 119)) {
+                Err(error) => {
+                    assert!(error.is_terminal());
+                    assert!(matches!(
+                        error,
+                        DataError::InvalidSequence {
+                            prev_last_update_id: 120,
+                            first_update_id: 121,
+                        }
+                    ));
+                }
+                Ok(book) => panic!("expected resync request, but book served: {book:?}"),
+            }
+
+            // OrderBook & OrderBookUpdater state are not mutated by the broken update
+            assert_eq!(book, book_before_break);
+            assert_eq!(updater.last_update_id, 120);
+        }
     }
 }
@@ -88,6 +88,7 @@ impl From<(ExchangeId, Instrument, BinanceLiquidation)> for MarketIter<Liquidati
         Self(vec![Ok(MarketEvent {
             exchange_time: liquidation.order.time,
             received_time: Utc::now(),
+            exchange_time_synthesized: false,
             exchange: Exchange::from(exchange_id),
             instrument,
             kind: Liquidation {
@@ -100,6 +101,76 @@ impl From<(ExchangeId, Instrument, BinanceLiquidation)> for MarketIter<Liquidati
     }
 }
 
+/// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) all-market liquidation order message,
+/// containing one or many [`BinanceLiquidation`]s for different markets.
+///
+/// Each [`BinanceLiquidationOrder`] [`SubscriptionId`] is synthesized from the all-market
+/// channel & the order market (eg/ "!forceOrder@arr|BTCUSDT"), so every [`BinanceLiquidation`]
+/// can be associated with the subscribed market.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#all-market-liquidation-order-streams>
+/// ```json
+/// [
+///     {"e":"forceOrder","E":1665523974222,"o":{"s":"BTCUSDT","S":"SELL","q":"0.009","p":"18917.15","T":1665523974217}},
+///     {"e":"forceOrder","E":1665523974223,"o":{"s":"ETHUSDT","S":"BUY","q":"1.5","p":"1290.10","T":1665523974218}}
+/// ]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BinanceAllMarketLiquidations(pub Vec<BinanceLiquidation>);
+
+impl<'de> Deserialize<'de> for BinanceAllMarketLiquidations {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        // Binance may deliver a single liquidation order or an array of liquidation orders
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum OneOrMany {
+            One(BinanceLiquidation),
+            Many(Vec<BinanceLiquidation>),
+        }
+
+        let liquidations = match OneOrMany::deserialize(deserializer)? {
+            OneOrMany::One(liquidation) => vec![liquidation],
+            OneOrMany::Many(liquidations) => liquidations,
+        };
+
+        Ok(Self(
+            liquidations
+                .into_iter()
+                .map(|mut liquidation| {
+                    // Replace per-market SubscriptionId (eg/ "@forceOrder|BTCUSDT")
+                    let market = liquidation
+                        .order
+                        .subscription_id
+                        .0
+                        .split_once('|')
+                        .map(|(_, market)| market.to_owned())
+                        .unwrap_or_default();
+
+                    liquidation.order.subscription_id = SubscriptionId::from(format!(
+                        "{}|{}",
+                        BinanceChannel::LIQUIDATIONS_ALL_MARKET.0,
+                        market
+                    ));
+                    liquidation
+                })
+                .collect(),
+        ))
+    }
+}
+
+impl IntoIterator for BinanceAllMarketLiquidations {
+    type Item = BinanceLiquidation;
+    type IntoIter = std::vec::IntoIter<BinanceLiquidation>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
 /// Deserialize a [`BinanceLiquidationOrder`] "s" (eg/ "BTCUSDT") as the associated
 /// [`SubscriptionId`].
 ///
@@ -159,5 +230,125 @@ mod tests {
                 }
             );
         }
+
+        #[test]
+        fn test_binance_all_market_liquidations() {
+            let input = r#"
+            [
+                {
+                    "e": "forceOrder",
+                    "E": 1665523974222,
+                    "o": {
+                        "s": "BTCUSDT", "S": "SELL", "o": "LIMIT", "f": "IOC", "q": "0.009",
+                        "p": "18917.15", "ap": "18990.00", "X": "FILLED", "l": "0.009",
+                        "z": "0.009", "T": 1665523974217
+                    }
+                },
+                {
+                    "e": "forceOrder",
+                    "E": 1665523974223,
+                    "o": {
+                        "s": "ETHUSDT", "S": "BUY", "o": "LIMIT", "f": "IOC", "q": "1.5",
+                        "p": "1290.10", "ap": "1289.00", "X": "FILLED", "l": "1.5",
+                        "z": "1.5", "T": 1665523974218
+                    }
+                }
+            ]
+            "#;
+
+            let actual = serde_json::from_str::<BinanceAllMarketLiquidations>(input).unwrap();
+
+            let expected = BinanceAllMarketLiquidations(vec![
+                BinanceLiquidation {
+                    order: BinanceLiquidationOrder {
+                        subscription_id: SubscriptionId::from("!forceOrder@arr|BTCUSDT"),
+                        side: Side::Sell,
+                        price: 18917.15,
+                        quantity: 0.009,
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1665523974217,
+                        )),
+                    },
+                },
+                BinanceLiquidation {
+                    order: BinanceLiquidationOrder {
+                        subscription_id: SubscriptionId::from("!forceOrder@arr|ETHUSDT"),
+                        side: Side::Buy,
+                        price: 1290.10,
+                        quantity: 1.5,
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1665523974218,
+                        )),
+                    },
+                },
+            ]);
+
+            assert_eq!(actual, expected);
+
+            // Single liquidation order payloads are also accepted
+            let single =
+                r#"{"e":"forceOrder","E":1,"o":{"s":"BTCUSDT","S":"SELL","q":"1","p":"2","T":1}}"#;
+            assert_eq!(
+                serde_json::from_str::<BinanceAllMarketLiquidations>(single)
+                    .unwrap()
+                    .0
+                    .len(),
+                1
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_all_market_liquidations_fan_out() {
+        use crate::{
+            exchange::binance::futures::BinanceFuturesUsd,
+            subscription::{liquidation::AllMarketLiquidations, Map},
+            transformer::{stateless::StatelessFanOutTransformer, ExchangeTransformer},
+        };
+        use barter_integration::{model::instrument::kind::InstrumentKind, Transformer};
+
+        let btc = Instrument::from(("btc", "usdt", InstrumentKind::Perpetual));
+        let eth = Instrument::from(("eth", "usdt", InstrumentKind::Perpetual));
+        let instrument_map = Map(vec![
+            (SubscriptionId::from("!forceOrder@arr|BTCUSDT"), btc.clone()),
+            (SubscriptionId::from("!forceOrder@arr|ETHUSDT"), eth.clone()),
+        ]
+        .into_iter()
+        .collect());
+
+        let (ws_sink_tx, _ws_sink_rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut transformer = <StatelessFanOutTransformer<
+            BinanceFuturesUsd,
+            AllMarketLiquidations,
+            BinanceAllMarketLiquidations,
+        > as ExchangeTransformer<BinanceFuturesUsd, AllMarketLiquidations>>::new(
+            ws_sink_tx,
+            instrument_map,
+        )
+        .await
+        .unwrap();
+
+        let input = r#"
+        [
+            {"e":"forceOrder","E":1,"o":{"s":"BTCUSDT","S":"SELL","q":"1","p":"100","T":1}},
+            {"e":"forceOrder","E":2,"o":{"s":"XRPUSDT","S":"BUY","q":"1","p":"0.5","T":2}},
+            {"e":"forceOrder","E":3,"o":{"s":"ETHUSDT","S":"BUY","q":"2","p":"10","T":3}}
+        ]
+        "#;
+
+        let actual = transformer
+            .transform(serde_json::from_str(input).unwrap())
+            .into_iter()
+            .map(|event| {
+                let event = event.unwrap();
+                (event.instrument, event.kind.side, event.kind.price)
+            })
+            .collect::<Vec<_>>();
+
+        // XRPUSDT is not subscribed to, so it is discarded
+        assert_eq!(
+            actual,
+            vec![(btc, Side::Sell, 100.0), (eth, Side::Buy, 10.0)]
+        );
     }
 }
@@ -0,0 +1,142 @@
+use super::super::BinanceChannel;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::derivatives::DerivativesStats,
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) mark price message, delivering the mark price,
+/// index price & funding rate of a perpetual on a single channel.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#mark-price-stream>
+/// ```json
+/// {
+///     "e": "markPriceUpdate",
+///     "E": 1562305380000,
+///     "s": "BTCUSDT",
+///     "p": "11794.15000000",
+///     "i": "11784.62659091",
+///     "P": "11784.25641265",
+///     "r": "0.00038167",
+///     "T": 1562306400000
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceMarkPrice {
+    #[serde(alias = "s", deserialize_with = "de_mark_price_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(
+        alias = "E",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
+    pub mark_price: f64,
+    #[serde(alias = "i", deserialize_with = "barter_integration::de::de_str")]
+    pub index_price: f64,
+    #[serde(alias = "r", deserialize_with = "barter_integration::de::de_str")]
+    pub funding_rate: f64,
+    #[serde(
+        alias = "T",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub next_funding_time: DateTime<Utc>,
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceMarkPrice {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl From<(ExchangeId, Instrument, BinanceMarkPrice)> for MarketIter<DerivativesStats> {
+    fn from((exchange_id, instrument, mark): (ExchangeId, Instrument, BinanceMarkPrice)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: mark.time,
+            received_time: Utc::now(),
+            exchange_time_synthesized: false,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DerivativesStats {
+                mark_price: Some(mark.mark_price),
+                index_price: Some(mark.index_price),
+                funding_rate: Some(mark.funding_rate),
+                next_funding_time: Some(mark.next_funding_time),
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`BinanceMarkPrice`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`].
+///
+/// eg/ "@markPrice|BTCUSDT"
+pub fn de_mark_price_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    Deserialize::deserialize(deserializer).map(|market: String| {
+        SubscriptionId::from(format!("{}|{}", BinanceChannel::MARK_PRICE.0, market))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::{
+        de::datetime_utc_from_epoch_duration, model::instrument::kind::InstrumentKind,
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn test_binance_mark_price_derivatives_stats() {
+        let input = r#"
+        {
+            "e": "markPriceUpdate",
+            "E": 1562305380000,
+            "s": "BTCUSDT",
+            "p": "11794.15000000",
+            "i": "11784.62659091",
+            "P": "11784.25641265",
+            "r": "0.00038167",
+            "T": 1562306400000
+        }
+        "#;
+
+        let actual = serde_json::from_str::<BinanceMarkPrice>(input).unwrap();
+        assert_eq!(
+            actual.subscription_id,
+            SubscriptionId::from("@markPrice|BTCUSDT")
+        );
+
+        // Mark price, index price & funding rate are populated from the single message
+        let event = MarketIter::<DerivativesStats>::from((
+            ExchangeId::BinanceFuturesUsd,
+            Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+            actual,
+        ))
+        .0
+        .remove(0)
+        .unwrap();
+
+        assert_eq!(
+            event.exchange_time,
+            datetime_utc_from_epoch_duration(Duration::from_millis(1562305380000))
+        );
+        assert_eq!(
+            event.kind,
+            DerivativesStats {
+                mark_price: Some(11794.15),
+                index_price: Some(11784.62659091),
+                funding_rate: Some(0.00038167),
+                next_funding_time: Some(datetime_utc_from_epoch_duration(Duration::from_millis(
+                    1562306400000
+                ))),
+            }
+        );
+    }
+}
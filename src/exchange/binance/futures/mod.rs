@@ -1,27 +1,67 @@
-use self::{l2::BinanceFuturesBookUpdater, liquidation::BinanceLiquidation};
-use super::{Binance, ExchangeServer};
+use self::{
+    l2::BinanceFuturesBookUpdater,
+    liquidation::{BinanceAllMarketLiquidations, BinanceLiquidation},
+    mark_price::BinanceMarkPrice,
+};
+use super::{
+    channel::BinanceChannel, instrument::BinanceExchangeInfo, time::BinanceServerTime,
+    trade::BinanceRecentTrade, Binance, ExchangeServer,
+};
+use crate::exchange::binance::futures::candle::{BinanceCandle, BinanceIntervalCandle};
+use crate::subscription::candle::{
+    CandleInterval, Candles, ClosedCandles, ClosedOnly, IntervalCandles,
+};
 use crate::{
     exchange::{ExchangeId, StreamSelector},
-    subscription::{book::OrderBooksL2, liquidation::Liquidations},
-    transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
+    streams::{backfill::TradeBackfill, clock::ServerTime, discovery::InstrumentDiscovery},
+    subscription::{
+        book::{OrderBooksL2, OrderBooksL2Speed},
+        derivatives::DerivativesStatistics,
+        liquidation::{AllMarketLiquidations, Liquidations},
+    },
+    transformer::{
+        book::MultiBookTransformer,
+        stateless::{StatelessFanOutTransformer, StatelessTransformer},
+    },
     ExchangeWsStream,
 };
-use crate::exchange::binance::futures::candle::BinanceCandle;
-use crate::subscription::candle::Candles;
+use barter_integration::{error::SocketError, model::instrument::Instrument};
+use chrono::{DateTime, Utc};
 
 /// Level 2 OrderBook types (top of book) and perpetual
 /// [`OrderBookUpdater`](crate::transformer::book::OrderBookUpdater) implementation.
 pub mod l2;
 
+pub mod candle;
 /// Liquidation types.
 pub mod liquidation;
-pub mod candle;
+
+/// Mark price types, delivering the [`DerivativesStats`](crate::subscription::derivatives::DerivativesStats)
+/// of a perpetual.
+pub mod mark_price;
 
 /// [`BinanceFuturesUsd`] WebSocket server base url.
 ///
 /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#websocket-market-streams>
 pub const WEBSOCKET_BASE_URL_BINANCE_FUTURES_USD: &str = "wss://fstream.binance.com/ws";
 
+/// [`BinanceFuturesUsd`] REST server-time url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#check-server-time>
+pub const HTTP_SERVER_TIME_URL_BINANCE_FUTURES_USD: &str = "https://fapi.binance.com/fapi/v1/time";
+
+/// [`BinanceFuturesUsd`] REST recent trades url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#recent-trades-list>
+pub const HTTP_RECENT_TRADES_URL_BINANCE_FUTURES_USD: &str =
+    "https://fapi.binance.com/fapi/v1/trades";
+
+/// [`BinanceFuturesUsd`] REST exchange information url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#exchange-information>
+pub const HTTP_EXCHANGE_INFO_URL_BINANCE_FUTURES_USD: &str =
+    "https://fapi.binance.com/fapi/v1/exchangeInfo";
+
 /// [`Binance`](super::Binance) perpetual usd exchange.
 pub type BinanceFuturesUsd = Binance<BinanceServerFuturesUsd>;
 
@@ -42,10 +82,72 @@ impl StreamSelector<OrderBooksL2> for BinanceFuturesUsd {
         ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, BinanceFuturesBookUpdater>>;
 }
 
+impl StreamSelector<OrderBooksL2Speed> for BinanceFuturesUsd {
+    type Stream =
+        ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2Speed, BinanceFuturesBookUpdater>>;
+
+    fn supports_kind(kind: &OrderBooksL2Speed) -> bool {
+        BinanceChannel::futures_order_book_l2(kind.update_speed_ms).is_some()
+    }
+}
+
 impl StreamSelector<Liquidations> for BinanceFuturesUsd {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, Liquidations, BinanceLiquidation>>;
 }
 
+impl StreamSelector<AllMarketLiquidations> for BinanceFuturesUsd {
+    type Stream = ExchangeWsStream<
+        StatelessFanOutTransformer<Self, AllMarketLiquidations, BinanceAllMarketLiquidations>,
+    >;
+}
+
+impl StreamSelector<DerivativesStatistics> for BinanceFuturesUsd {
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, DerivativesStatistics, BinanceMarkPrice>>;
+}
+
 impl StreamSelector<Candles> for BinanceFuturesUsd {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, Candles, BinanceCandle>>;
 }
+
+impl StreamSelector<ClosedCandles> for BinanceFuturesUsd {
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, ClosedCandles, ClosedOnly<BinanceCandle>>>;
+}
+
+impl StreamSelector<IntervalCandles> for BinanceFuturesUsd {
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, IntervalCandles, BinanceIntervalCandle>>;
+
+    fn supports_kind(kind: &IntervalCandles) -> bool {
+        // BinanceFuturesUsd does not offer 1 second Klines
+        kind.interval != CandleInterval::S1
+    }
+}
+
+impl ServerTime for BinanceFuturesUsd {
+    const SERVER_TIME_URL: &'static str = HTTP_SERVER_TIME_URL_BINANCE_FUTURES_USD;
+    type Response = BinanceServerTime;
+
+    fn server_time(response: Self::Response) -> Result<DateTime<Utc>, SocketError> {
+        Ok(response.time)
+    }
+}
+
+impl TradeBackfill for BinanceFuturesUsd {
+    const RECENT_TRADES_URL: &'static str = HTTP_RECENT_TRADES_URL_BINANCE_FUTURES_USD;
+    type Response = Vec<BinanceRecentTrade>;
+
+    fn recent_trades_url(base_url: &str, instrument: &Instrument, limit: usize) -> String {
+        super::trade::recent_trades_url(base_url, instrument, limit)
+    }
+}
+
+impl InstrumentDiscovery for BinanceFuturesUsd {
+    const INSTRUMENTS_URL: &'static str = HTTP_EXCHANGE_INFO_URL_BINANCE_FUTURES_USD;
+    type Response = BinanceExchangeInfo;
+
+    fn instruments(response: Self::Response) -> Vec<Instrument> {
+        response.perpetual_instruments()
+    }
+}
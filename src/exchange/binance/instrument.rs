@@ -0,0 +1,170 @@
+use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+use serde::{Deserialize, Serialize};
+
+/// [`Binance`](super::Binance) market "status" of an actively trading symbol.
+pub const BINANCE_SYMBOL_STATUS_TRADING: &str = "TRADING";
+
+/// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) "contractType" of a perpetual
+/// symbol.
+pub const BINANCE_CONTRACT_TYPE_PERPETUAL: &str = "PERPETUAL";
+
+/// [`Binance`](super::Binance) REST exchange information response, listing every symbol.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#exchange-information>
+/// #### Spot
+/// ```json
+/// {
+///   "timezone": "UTC",
+///   "serverTime": 1565246363776,
+///   "symbols": [
+///     {
+///       "symbol": "ETHBTC",
+///       "status": "TRADING",
+///       "baseAsset": "ETH",
+///       "quoteAsset": "BTC"
+///     }
+///   ]
+/// }
+/// ```
+///
+/// #### FuturesUsd
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#exchange-information>
+/// ```json
+/// {
+///   "symbols": [
+///     {
+///       "symbol": "BTCUSDT",
+///       "pair": "BTCUSDT",
+///       "contractType": "PERPETUAL",
+///       "status": "TRADING",
+///       "baseAsset": "BTC",
+///       "quoteAsset": "USDT"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BinanceExchangeInfo {
+    pub symbols: Vec<BinanceSymbol>,
+}
+
+/// [`Binance`](super::Binance) REST exchange information symbol.
+///
+/// See [`BinanceExchangeInfo`] for full raw payload examples.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceSymbol {
+    pub status: String,
+    pub base_asset: String,
+    pub quote_asset: String,
+    #[serde(default)]
+    pub contract_type: Option<String>,
+}
+
+impl BinanceExchangeInfo {
+    /// Actively trading spot [`Instrument`]s.
+    pub fn spot_instruments(self) -> Vec<Instrument> {
+        self.instruments(InstrumentKind::Spot, |_| true)
+    }
+
+    /// Actively trading perpetual [`Instrument`]s, skipping any delivery futures.
+    pub fn perpetual_instruments(self) -> Vec<Instrument> {
+        self.instruments(InstrumentKind::Perpetual, |symbol| {
+            symbol.contract_type.as_deref() == Some(BINANCE_CONTRACT_TYPE_PERPETUAL)
+        })
+    }
+
+    fn instruments<F>(self, kind: InstrumentKind, filter: F) -> Vec<Instrument>
+    where
+        F: Fn(&BinanceSymbol) -> bool,
+    {
+        self.symbols
+            .into_iter()
+            .filter(|symbol| symbol.status == BINANCE_SYMBOL_STATUS_TRADING && filter(symbol))
+            .map(|symbol| {
+                Instrument::from((
+                    symbol.base_asset.to_lowercase(),
+                    symbol.quote_asset.to_lowercase(),
+                    kind,
+                ))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binance_exchange_info_instruments() {
+        struct TestCase {
+            input: &'static str,
+            kind: InstrumentKind,
+            expected: Vec<Instrument>,
+        }
+
+        let cases = vec![
+            // TC0: spot instruments of every trading symbol
+            TestCase {
+                input: r#"
+                {
+                    "timezone": "UTC",
+                    "serverTime": 1565246363776,
+                    "symbols": [
+                        {"symbol": "ETHBTC", "status": "TRADING", "baseAsset": "ETH", "quoteAsset": "BTC"},
+                        {"symbol": "LUNABTC", "status": "BREAK", "baseAsset": "LUNA", "quoteAsset": "BTC"}
+                    ]
+                }
+                "#,
+                kind: InstrumentKind::Spot,
+                expected: vec![Instrument::from(("eth", "btc", InstrumentKind::Spot))],
+            },
+            // TC1: perpetual instruments skip delivery futures & halted symbols
+            TestCase {
+                input: r#"
+                {
+                    "symbols": [
+                        {
+                            "symbol": "BTCUSDT",
+                            "pair": "BTCUSDT",
+                            "contractType": "PERPETUAL",
+                            "status": "TRADING",
+                            "baseAsset": "BTC",
+                            "quoteAsset": "USDT"
+                        },
+                        {
+                            "symbol": "BTCUSDT_240329",
+                            "pair": "BTCUSDT",
+                            "contractType": "CURRENT_QUARTER",
+                            "status": "TRADING",
+                            "baseAsset": "BTC",
+                            "quoteAsset": "USDT"
+                        },
+                        {
+                            "symbol": "ETHUSDT",
+                            "pair": "ETHUSDT",
+                            "contractType": "PERPETUAL",
+                            "status": "SETTLING",
+                            "baseAsset": "ETH",
+                            "quoteAsset": "USDT"
+                        }
+                    ]
+                }
+                "#,
+                kind: InstrumentKind::Perpetual,
+                expected: vec![Instrument::from(("btc", "usdt", InstrumentKind::Perpetual))],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let response = serde_json::from_str::<BinanceExchangeInfo>(test.input).unwrap();
+            let actual = match test.kind {
+                InstrumentKind::Perpetual => response.perpetual_instruments(),
+                _ => response.spot_instruments(),
+            };
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
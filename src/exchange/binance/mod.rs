@@ -1,17 +1,28 @@
 use self::{
-    book::l1::BinanceOrderBookL1, channel::BinanceChannel, market::BinanceMarket,
-    subscription::BinanceSubResponse, trade::BinanceTrade,
+    book::l1::{BinanceAllMarketOrderBookL1, BinanceOrderBookL1},
+    channel::BinanceChannel,
+    market::BinanceMarket,
+    subscription::BinanceSubResponse,
+    ticker::BinanceAllMarketTickers,
+    trade::BinanceTrade,
 };
 use crate::{
+    error::DataError,
     exchange::{Connector, ExchangeId, ExchangeServer, ExchangeSub, StreamSelector},
-    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{book::OrderBooksL1, trade::PublicTrades, Map},
-    transformer::stateless::StatelessTransformer,
+    subscriber::{config::ConnectionConfig, validator::WebSocketSubValidator, WebSocketSubscriber},
+    subscription::{
+        book::{AllMarketOrderBooksL1, OrderBooksL1},
+        ticker::AllMarketTickers,
+        trade::PublicTrades,
+        Map,
+    },
+    transformer::stateless::{StatelessFanOutTransformer, StatelessTransformer},
     ExchangeWsStream,
 };
 use barter_integration::{
     error::SocketError, model::instrument::Instrument, protocol::websocket::WsMessage,
 };
+use serde::{Deserialize, Serialize};
 use std::{fmt::Debug, marker::PhantomData};
 use url::Url;
 
@@ -23,10 +34,18 @@ pub mod book;
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
 
+/// [`BinanceEvent`](event::BinanceEvent) dispatcher that demultiplexes the messages of mixed
+/// channels on a single connection by their "e" event type.
+pub mod event;
+
 /// [`ExchangeServer`] and [`StreamSelector`] implementations for
 /// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod futures;
 
+/// REST exchange information types, used for instrument discovery, common to both
+/// [`BinanceSpot`](spot::BinanceSpot) and [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
+pub mod instrument;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
@@ -40,10 +59,40 @@ pub mod spot;
 /// and [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod subscription;
 
+/// REST server-time types common to both [`BinanceSpot`](spot::BinanceSpot) and
+/// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
+pub mod time;
+
+/// All-market rolling 24hr ticker types common to both [`BinanceSpot`](spot::BinanceSpot) and
+/// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
+pub mod ticker;
+
 /// Public trade types common to both [`BinanceSpot`](spot::BinanceSpot) and
 /// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod trade;
 
+/// [`Binance`] maximum number of streams a single connection can listen to.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#websocket-limits>
+pub const MAX_SUBSCRIPTIONS_PER_CONNECTION_BINANCE: usize = 1024;
+
+/// [`Binance`] event sent ahead of the server closing connections for maintenance, which may be
+/// received at any time after subscribing.
+///
+/// ### Raw Payload Examples
+/// ```json
+/// {"e":"serverShutdown","E":1730000000000}
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(tag = "e")]
+pub enum BinanceServerEvent {
+    #[serde(rename = "serverShutdown")]
+    ServerShutdown {
+        #[serde(rename = "E")]
+        time: u64,
+    },
+}
+
 /// Generic [`Binance<Server>`](Binance) exchange.
 ///
 /// ### Notes
@@ -69,20 +118,37 @@ where
         Url::parse(Server::websocket_url()).map_err(SocketError::UrlParse)
     }
 
+    fn select_channel(
+        channel: Self::Channel,
+        _: &ConnectionConfig,
+    ) -> Result<Self::Channel, SocketError> {
+        if channel == BinanceChannel::ORDER_BOOK_L2_UNSUPPORTED_SPEED {
+            Err(SocketError::Subscribe(format!(
+                "{} does not support the requested OrderBooksL2Speed update speed",
+                Self::ID
+            )))
+        } else {
+            Ok(channel)
+        }
+    }
+
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
-        let stream_names = exchange_subs
-            .into_iter()
-            .map(|sub| {
-                // Note:
-                // Market must be lowercase when subscribing, but lowercase in general since
-                // Binance sends message with uppercase MARKET (eg/ BTCUSDT).
-                format!(
-                    "{}{}",
-                    sub.market.as_ref().to_lowercase(),
-                    sub.channel.as_ref()
-                )
-            })
-            .collect::<Vec<String>>();
+        let mut stream_names = Vec::<String>::with_capacity(exchange_subs.len());
+        for sub in exchange_subs {
+            // Note:
+            // Market must be lowercase when subscribing, but lowercase in general since
+            // Binance sends message with uppercase MARKET (eg/ BTCUSDT).
+            let stream_name = if sub.channel.is_all_market() {
+                sub.channel.0.to_owned()
+            } else {
+                format!("{}{}", sub.market.as_ref().to_lowercase(), sub.channel.0)
+            };
+
+            // Every market of an all-market channel shares the same stream name
+            if !stream_names.contains(&stream_name) {
+                stream_names.push(stream_name);
+            }
+        }
 
         vec![WsMessage::Text(
             serde_json::json!({
@@ -94,8 +160,16 @@ where
         )]
     }
 
-    fn expected_responses(_: &Map<Instrument>) -> usize {
-        1
+    fn max_subscriptions_per_connection() -> Option<usize> {
+        Some(MAX_SUBSCRIPTIONS_PER_CONNECTION_BINANCE)
+    }
+
+    fn expected_responses(map: &Map<Instrument>) -> usize {
+        Self::num_batched_requests(map.0.len())
+    }
+
+    fn is_maintenance(error: &DataError) -> bool {
+        error.is_maintenance() || error.unexpected_payload::<BinanceServerEvent>().is_some()
     }
 }
 
@@ -113,6 +187,24 @@ where
     type Stream = ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, BinanceOrderBookL1>>;
 }
 
+impl<Server> StreamSelector<AllMarketOrderBooksL1> for Binance<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream = ExchangeWsStream<
+        StatelessFanOutTransformer<Self, AllMarketOrderBooksL1, BinanceAllMarketOrderBookL1>,
+    >;
+}
+
+impl<Server> StreamSelector<AllMarketTickers> for Binance<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream = ExchangeWsStream<
+        StatelessFanOutTransformer<Self, AllMarketTickers, BinanceAllMarketTickers>,
+    >;
+}
+
 impl<'de, Server> serde::Deserialize<'de> for Binance<Server>
 where
     Server: ExchangeServer,
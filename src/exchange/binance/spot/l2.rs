@@ -1,17 +1,24 @@
-use super::super::book::{l2::BinanceOrderBookL2Snapshot, BinanceLevel};
+use super::{
+    super::book::{
+        l2::{fetch_partial_depth_snapshot, fetch_snapshot},
+        BinanceLevel,
+    },
+    WEBSOCKET_BASE_URL_BINANCE_SPOT,
+};
 use crate::{
     error::DataError,
+    exchange::ExchangeId,
+    subscriber::config::ConnectionConfig,
     subscription::book::OrderBook,
-    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+    transformer::book::{BookSeed, InstrumentOrderBook, OrderBookUpdater},
     Identifier,
 };
 use async_trait::async_trait;
 use barter_integration::{
-    error::SocketError,
     model::{instrument::Instrument, SubscriptionId},
     protocol::websocket::WsMessage,
 };
-use chrono::Utc;
+use chrono::DateTime;
 use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc;
 
@@ -164,21 +171,53 @@ impl OrderBookUpdater for BinanceSpotBookUpdater {
         Exchange: Send,
         Kind: Send,
     {
-        // Construct initial OrderBook snapshot GET url
-        let snapshot_url = format!(
-            "{}?symbol={}{}&limit=100",
+        // Fetch initial OrderBook snapshot via HTTP
+        let snapshot = fetch_snapshot(
+            ExchangeId::BinanceSpot,
             HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT,
-            instrument.base.as_ref().to_uppercase(),
-            instrument.quote.as_ref().to_uppercase()
-        );
+            &instrument,
+            None,
+        )
+        .await?;
 
-        // Fetch initial OrderBook snapshot via HTTP
-        let snapshot = reqwest::get(snapshot_url)
-            .await
-            .map_err(SocketError::Http)?
-            .json::<BinanceOrderBookL2Snapshot>()
-            .await
-            .map_err(SocketError::Http)?;
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::new(snapshot.last_update_id),
+            book: OrderBook::from(snapshot),
+        })
+    }
+
+    async fn init_with_config<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+        config: &ConnectionConfig,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        let snapshot = match config.book_seed {
+            BookSeed::Rest => {
+                // Fetch initial OrderBook snapshot via HTTP, recording the used request weight
+                fetch_snapshot(
+                    ExchangeId::BinanceSpot,
+                    HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT,
+                    &instrument,
+                    config.rate_limits.as_ref(),
+                )
+                .await?
+            }
+            BookSeed::PartialDepth => {
+                // Seed initial OrderBook from the partial depth stream of the same server
+                let base_url = config
+                    .url
+                    .as_ref()
+                    .map(|url| url.as_str())
+                    .unwrap_or(WEBSOCKET_BASE_URL_BINANCE_SPOT);
+
+                fetch_partial_depth_snapshot(base_url, &instrument, config).await?
+            }
+        };
 
         Ok(InstrumentOrderBook {
             instrument,
@@ -187,6 +226,11 @@ impl OrderBookUpdater for BinanceSpotBookUpdater {
         })
     }
 
+    fn resumable() -> bool {
+        // Every update is sequence validated, so any update missed while disconnected is detected
+        true
+    }
+
     fn update(
         &mut self,
         book: &mut Self::OrderBook,
@@ -212,7 +256,9 @@ impl OrderBookUpdater for BinanceSpotBookUpdater {
         // Update OrderBook metadata & Levels:
         // 7. The data in each event is the absolute quantity for a price level.
         // 8. If the quantity is 0, remove the price level.
-        book.last_update_time = Utc::now();
+        // Depth update event times are not deserialised, so leave the last_update_time unset
+        // for the MarketEvent exchange_time to be synthesized from the received time
+        book.last_update_time = DateTime::default();
         book.bids.upsert(update.bids);
         book.asks.upsert(update.asks);
 
@@ -229,6 +275,67 @@ impl OrderBookUpdater for BinanceSpotBookUpdater {
 mod tests {
     use super::*;
 
+    #[tokio::test]
+    async fn test_binance_spot_book_updater_partial_depth_seed() {
+        use crate::{
+            exchange::binance::spot::BinanceSpot,
+            subscription::book::{Level, OrderBooksL2},
+            test_util::{MockExchange, MockScript},
+        };
+        use barter_integration::model::instrument::kind::InstrumentKind;
+
+        // REST snapshots remain the default BookSeed
+        assert_eq!(ConnectionConfig::default().book_seed, BookSeed::Rest);
+
+        let exchange = MockExchange::start([MockScript::new()
+            .send_text(r#"{"lastUpdateId":160,"bids":[["0.0024","10"]],"asks":[["0.0026","100"]]}"#)
+            .close()])
+        .await
+        .unwrap();
+
+        let config = ConnectionConfig::default()
+            .url(exchange.url())
+            .book_seed(BookSeed::PartialDepth);
+
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+        let mut seeded = BinanceSpotBookUpdater::init_with_config::<BinanceSpot, OrderBooksL2>(
+            ws_sink_tx,
+            Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            &config,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(seeded.updater, BinanceSpotBookUpdater::new(160));
+        assert_eq!(seeded.book.bids.levels(), &[Level::new(0.0024, 10.0)]);
+        assert_eq!(seeded.book.asks.levels(), &[Level::new(0.0026, 100.0)]);
+
+        // Seeded OrderBook is usable by subsequent OrderBook L2 deltas
+        let book = seeded
+            .updater
+            .update(
+                &mut seeded.book,
+                BinanceSpotOrderBookL2Delta {
+                    subscription_id: SubscriptionId::from("@depth@100ms|BTCUSDT"),
+                    first_update_id: 157,
+                    last_update_id: 162,
+                    bids: vec![BinanceLevel {
+                        price: 0.0025,
+                        amount: 5.0,
+                    }],
+                    asks: vec![],
+                },
+            )
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            book.bids.levels(),
+            &[Level::new(0.0025, 5.0), Level::new(0.0024, 10.0)]
+        );
+        assert_eq!(seeded.updater.last_update_id, 162);
+    }
+
     mod de {
         use super::*;
 
@@ -275,6 +382,7 @@ mod tests {
         use super::*;
         use crate::subscription::book::{Level, OrderBookSide};
         use barter_integration::model::Side;
+        use chrono::Utc;
 
         #[test]
         fn test_is_first_update() {
@@ -397,6 +505,7 @@ mod tests {
             for (index, test) in tests.into_iter().enumerate() {
                 let actual = test.updater.validate_first_update(&test.input);
                 match (actual, test.expected) {
+                    #[allow(clippy::unit_cmp)]
                     (Ok(actual), Ok(expected)) => {
                         assert_eq!(actual, expected, "TC{} failed", index)
                     }
@@ -460,6 +569,7 @@ mod tests {
             for (index, test) in tests.into_iter().enumerate() {
                 let actual = test.updater.validate_next_update(&test.input);
                 match (actual, test.expected) {
+                    #[allow(clippy::unit_cmp)]
                     (Ok(actual), Ok(expected)) => {
                         assert_eq!(actual, expected, "TC{} failed", index)
                     }
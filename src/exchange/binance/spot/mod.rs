@@ -1,11 +1,21 @@
 use self::l2::BinanceSpotBookUpdater;
-use super::{Binance, ExchangeServer};
+use super::{
+    channel::BinanceChannel, futures::candle::BinanceIntervalCandle,
+    instrument::BinanceExchangeInfo, time::BinanceServerTime, trade::BinanceRecentTrade, Binance,
+    ExchangeServer,
+};
 use crate::{
     exchange::{ExchangeId, StreamSelector},
-    subscription::book::OrderBooksL2,
-    transformer::book::MultiBookTransformer,
+    streams::{backfill::TradeBackfill, clock::ServerTime, discovery::InstrumentDiscovery},
+    subscription::{
+        book::{OrderBooksL2, OrderBooksL2Speed},
+        candle::IntervalCandles,
+    },
+    transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
     ExchangeWsStream,
 };
+use barter_integration::{error::SocketError, model::instrument::Instrument};
+use chrono::{DateTime, Utc};
 
 /// Level 2 OrderBook types (top of book) and spot
 /// [`OrderBookUpdater`](crate::transformer::book::OrderBookUpdater) implementation.
@@ -16,6 +26,21 @@ pub mod l2;
 /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#websocket-market-streams>
 pub const WEBSOCKET_BASE_URL_BINANCE_SPOT: &str = "wss://stream.binance.com:9443/ws";
 
+/// [`BinanceSpot`] REST server-time url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#check-server-time>
+pub const HTTP_SERVER_TIME_URL_BINANCE_SPOT: &str = "https://api.binance.com/api/v3/time";
+
+/// [`BinanceSpot`] REST recent trades url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#recent-trades-list>
+pub const HTTP_RECENT_TRADES_URL_BINANCE_SPOT: &str = "https://api.binance.com/api/v3/trades";
+
+/// [`BinanceSpot`] REST exchange information url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#exchange-information>
+pub const HTTP_EXCHANGE_INFO_URL_BINANCE_SPOT: &str = "https://api.binance.com/api/v3/exchangeInfo";
+
 /// [`Binance`](super::Binance) spot exchange.
 pub type BinanceSpot = Binance<BinanceServerSpot>;
 
@@ -35,3 +60,44 @@ impl StreamSelector<OrderBooksL2> for BinanceSpot {
     type Stream =
         ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, BinanceSpotBookUpdater>>;
 }
+
+impl StreamSelector<OrderBooksL2Speed> for BinanceSpot {
+    type Stream =
+        ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2Speed, BinanceSpotBookUpdater>>;
+
+    fn supports_kind(kind: &OrderBooksL2Speed) -> bool {
+        BinanceChannel::spot_order_book_l2(kind.update_speed_ms).is_some()
+    }
+}
+
+impl StreamSelector<IntervalCandles> for BinanceSpot {
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, IntervalCandles, BinanceIntervalCandle>>;
+}
+
+impl ServerTime for BinanceSpot {
+    const SERVER_TIME_URL: &'static str = HTTP_SERVER_TIME_URL_BINANCE_SPOT;
+    type Response = BinanceServerTime;
+
+    fn server_time(response: Self::Response) -> Result<DateTime<Utc>, SocketError> {
+        Ok(response.time)
+    }
+}
+
+impl TradeBackfill for BinanceSpot {
+    const RECENT_TRADES_URL: &'static str = HTTP_RECENT_TRADES_URL_BINANCE_SPOT;
+    type Response = Vec<BinanceRecentTrade>;
+
+    fn recent_trades_url(base_url: &str, instrument: &Instrument, limit: usize) -> String {
+        super::trade::recent_trades_url(base_url, instrument, limit)
+    }
+}
+
+impl InstrumentDiscovery for BinanceSpot {
+    const INSTRUMENTS_URL: &'static str = HTTP_EXCHANGE_INFO_URL_BINANCE_SPOT;
+    type Response = BinanceExchangeInfo;
+
+    fn instruments(response: Self::Response) -> Vec<Instrument> {
+        response.spot_instruments()
+    }
+}
@@ -0,0 +1,396 @@
+use super::channel::BinanceChannel;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{subscription::ExchangeSub, ExchangeId},
+    subscription::{book::Level, ticker::Ticker},
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Binance`](super::Binance) rolling 24hr ticker.
+///
+/// The [`SubscriptionId`] is synthesized from the all-market channel & the market
+/// (eg/ "!ticker@arr|BTCUSDT"), so each ticker can be associated with the subscribed market.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#all-market-tickers-stream>
+/// ```json
+/// {
+///     "e": "24hrTicker",
+///     "E": 1672515782136,
+///     "s": "BNBBTC",
+///     "p": "0.0015",
+///     "P": "250.00",
+///     "w": "0.0018",
+///     "x": "0.0009",
+///     "c": "0.0025",
+///     "Q": "10",
+///     "b": "0.0024",
+///     "B": "10",
+///     "a": "0.0026",
+///     "A": "100",
+///     "o": "0.0010",
+///     "h": "0.0025",
+///     "l": "0.0010",
+///     "v": "10000",
+///     "q": "18",
+///     "O": 0,
+///     "C": 86400000,
+///     "F": 0,
+///     "L": 18150,
+///     "n": 18151
+/// }
+/// ```
+///
+/// Note: [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) tickers do not contain the best
+/// bid & ask, so they are left as zero.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#all-market-tickers-streams>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceTicker24h {
+    #[serde(alias = "s", deserialize_with = "de_ticker_all_market_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(
+        alias = "E",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "c", deserialize_with = "barter_integration::de::de_str")]
+    pub last_price: f64,
+    #[serde(alias = "Q", deserialize_with = "barter_integration::de::de_str")]
+    pub last_amount: f64,
+    #[serde(
+        default,
+        alias = "b",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub best_bid_price: f64,
+    #[serde(
+        default,
+        alias = "B",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub best_bid_amount: f64,
+    #[serde(
+        default,
+        alias = "a",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub best_ask_price: f64,
+    #[serde(
+        default,
+        alias = "A",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub best_ask_amount: f64,
+    #[serde(alias = "o", deserialize_with = "barter_integration::de::de_str")]
+    pub open: f64,
+    #[serde(alias = "h", deserialize_with = "barter_integration::de::de_str")]
+    pub high: f64,
+    #[serde(alias = "l", deserialize_with = "barter_integration::de::de_str")]
+    pub low: f64,
+    #[serde(alias = "v", deserialize_with = "barter_integration::de::de_str")]
+    pub volume: f64,
+    #[serde(alias = "w", deserialize_with = "barter_integration::de::de_str")]
+    pub vwap: f64,
+    #[serde(alias = "n")]
+    pub trade_count: u64,
+}
+
+/// [`Binance`](super::Binance) all-market rolling 24hr ticker message, containing a
+/// [`BinanceTicker24h`] for every market that changed.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#all-market-tickers-stream>
+/// ```json
+/// [
+///     {"e":"24hrTicker","E":1672515782136,"s":"BTCUSDT","c":"16500.00","Q":"0.1",...},
+///     {"e":"24hrTicker","E":1672515782136,"s":"ETHUSDT","c":"1200.00","Q":"1.5",...}
+/// ]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceAllMarketTickers(pub Vec<BinanceTicker24h>);
+
+impl IntoIterator for BinanceAllMarketTickers {
+    type Item = BinanceTicker24h;
+    type IntoIter = std::vec::IntoIter<BinanceTicker24h>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceTicker24h {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl From<(ExchangeId, Instrument, BinanceTicker24h)> for MarketIter<Ticker> {
+    fn from((exchange_id, instrument, ticker): (ExchangeId, Instrument, BinanceTicker24h)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: ticker.time,
+            received_time: Utc::now(),
+            exchange_time_synthesized: false,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: Ticker {
+                best_bid: Level::new(ticker.best_bid_price, ticker.best_bid_amount),
+                best_ask: Level::new(ticker.best_ask_price, ticker.best_ask_amount),
+                last_price: ticker.last_price,
+                last_amount: ticker.last_amount,
+                open_24h: ticker.open,
+                high_24h: ticker.high,
+                low_24h: ticker.low,
+                volume_24h: ticker.volume,
+                vwap_24h: Some(ticker.vwap),
+                trade_count_24h: Some(ticker.trade_count),
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`BinanceTicker24h`] "s" (eg/ "BTCUSDT") as the associated all-market
+/// [`SubscriptionId`].
+///
+/// eg/ "!ticker@arr|BTCUSDT"
+pub fn de_ticker_all_market_subscription_id<'de, D>(
+    deserializer: D,
+) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|market| ExchangeSub::from((BinanceChannel::TICKERS_ALL_MARKET, market)).id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::binance::{
+            book::l1::BinanceAllMarketOrderBookL1, futures::BinanceFuturesUsd, spot::BinanceSpot,
+        },
+        subscription::{book::AllMarketOrderBooksL1, ticker::AllMarketTickers, Map},
+        transformer::{stateless::StatelessFanOutTransformer, ExchangeTransformer},
+    };
+    use barter_integration::{
+        de::datetime_utc_from_epoch_duration, model::instrument::kind::InstrumentKind, Transformer,
+    };
+    use std::time::Duration;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_binance_all_market_tickers() {
+            struct TestCase {
+                input: &'static str,
+                expected: BinanceAllMarketTickers,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid BinanceSpot tickers array
+                    input: r#"
+                    [
+                        {
+                            "e":"24hrTicker","E":1672515782136,"s":"BTCUSDT","p":"15","P":"0.09",
+                            "w":"16490.5","x":"16485","c":"16500.00","Q":"0.1","b":"16499.99",
+                            "B":"2","a":"16500.01","A":"3","o":"16485","h":"16600","l":"16400",
+                            "v":"1000","q":"16490500","O":0,"C":86400000,"F":0,"L":18150,"n":18151
+                        },
+                        {
+                            "e":"24hrTicker","E":1672515782137,"s":"ETHUSDT","p":"1","P":"0.1",
+                            "w":"1199","x":"1199","c":"1200.00","Q":"1.5","b":"1199.99","B":"5",
+                            "a":"1200.01","A":"6","o":"1199","h":"1210","l":"1190","v":"5000",
+                            "q":"5995000","O":0,"C":86400000,"F":0,"L":200,"n":201
+                        }
+                    ]"#,
+                    expected: BinanceAllMarketTickers(vec![
+                        BinanceTicker24h {
+                            subscription_id: SubscriptionId::from("!ticker@arr|BTCUSDT"),
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1672515782136,
+                            )),
+                            last_price: 16500.0,
+                            last_amount: 0.1,
+                            best_bid_price: 16499.99,
+                            best_bid_amount: 2.0,
+                            best_ask_price: 16500.01,
+                            best_ask_amount: 3.0,
+                            open: 16485.0,
+                            high: 16600.0,
+                            low: 16400.0,
+                            volume: 1000.0,
+                            vwap: 16490.5,
+                            trade_count: 18151,
+                        },
+                        BinanceTicker24h {
+                            subscription_id: SubscriptionId::from("!ticker@arr|ETHUSDT"),
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1672515782137,
+                            )),
+                            last_price: 1200.0,
+                            last_amount: 1.5,
+                            best_bid_price: 1199.99,
+                            best_bid_amount: 5.0,
+                            best_ask_price: 1200.01,
+                            best_ask_amount: 6.0,
+                            open: 1199.0,
+                            high: 1210.0,
+                            low: 1190.0,
+                            volume: 5000.0,
+                            vwap: 1199.0,
+                            trade_count: 201,
+                        },
+                    ]),
+                },
+                TestCase {
+                    // TC1: valid BinanceFuturesUsd tickers array w/o best bid & ask
+                    input: r#"
+                    [
+                        {
+                            "e":"24hrTicker","E":1672515782136,"s":"BTCUSDT","p":"15","P":"0.09",
+                            "w":"16490.5","c":"16500.00","Q":"0.1","o":"16485","h":"16600",
+                            "l":"16400","v":"1000","q":"16490500","O":0,"C":86400000,"F":0,
+                            "L":18150,"n":18151
+                        }
+                    ]"#,
+                    expected: BinanceAllMarketTickers(vec![BinanceTicker24h {
+                        subscription_id: SubscriptionId::from("!ticker@arr|BTCUSDT"),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672515782136,
+                        )),
+                        last_price: 16500.0,
+                        last_amount: 0.1,
+                        best_bid_price: 0.0,
+                        best_bid_amount: 0.0,
+                        best_ask_price: 0.0,
+                        best_ask_amount: 0.0,
+                        open: 16485.0,
+                        high: 16600.0,
+                        low: 16400.0,
+                        volume: 1000.0,
+                        vwap: 16490.5,
+                        trade_count: 18151,
+                    }]),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BinanceAllMarketTickers>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+
+        #[test]
+        fn test_binance_all_market_order_book_l1() {
+            let input = r#"
+            {
+                "e":"bookTicker","u":400900217,"E":1568014460893,"T":1568014460891,
+                "s":"BNBUSDT","b":"25.35190000","B":"31.21000000","a":"25.36520000",
+                "A":"40.66000000"
+            }"#;
+
+            let actual = serde_json::from_str::<BinanceAllMarketOrderBookL1>(input).unwrap();
+
+            assert_eq!(
+                actual.0.subscription_id,
+                SubscriptionId::from("!bookTicker|BNBUSDT")
+            );
+            assert_eq!(
+                actual.0.time,
+                datetime_utc_from_epoch_duration(Duration::from_millis(1568014460891))
+            );
+            assert_eq!(actual.0.best_bid_price, 25.3519);
+            assert_eq!(actual.0.best_ask_amount, 40.66);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_all_market_tickers_fan_out() {
+        let btc = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let eth = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+        let instrument_map = Map(vec![
+            (SubscriptionId::from("!ticker@arr|BTCUSDT"), btc.clone()),
+            (SubscriptionId::from("!ticker@arr|ETHUSDT"), eth.clone()),
+        ]
+        .into_iter()
+        .collect());
+
+        let (ws_sink_tx, _ws_sink_rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut transformer = <StatelessFanOutTransformer<
+            BinanceSpot,
+            AllMarketTickers,
+            BinanceAllMarketTickers,
+        > as ExchangeTransformer<BinanceSpot, AllMarketTickers>>::new(
+            ws_sink_tx, instrument_map
+        )
+        .await
+        .unwrap();
+
+        let input = r#"
+        [
+            {"E":1,"s":"BTCUSDT","c":"100","Q":"1","o":"90","h":"110","l":"80","v":"10","w":"95","n":5},
+            {"E":1,"s":"XRPUSDT","c":"0.5","Q":"1","o":"0.4","h":"0.6","l":"0.3","v":"10","w":"0.5","n":5},
+            {"E":1,"s":"ETHUSDT","c":"10","Q":"2","o":"9","h":"11","l":"8","v":"20","w":"9.5","n":7}
+        ]
+        "#;
+
+        let actual = transformer
+            .transform(serde_json::from_str(input).unwrap())
+            .into_iter()
+            .map(|event| {
+                let event = event.unwrap();
+                (
+                    event.instrument,
+                    event.kind.last_price,
+                    event.kind.trade_count_24h,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // XRPUSDT is not subscribed to, so it is discarded
+        assert_eq!(actual, vec![(btc, 100.0, Some(5)), (eth, 10.0, Some(7))]);
+    }
+
+    #[tokio::test]
+    async fn test_all_market_order_books_l1_fan_out() {
+        let btc = Instrument::from(("btc", "usdt", InstrumentKind::Perpetual));
+        let instrument_map = Map(
+            vec![(SubscriptionId::from("!bookTicker|BTCUSDT"), btc.clone())]
+                .into_iter()
+                .collect(),
+        );
+
+        let (ws_sink_tx, _ws_sink_rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut transformer = <StatelessFanOutTransformer<
+            BinanceFuturesUsd,
+            AllMarketOrderBooksL1,
+            BinanceAllMarketOrderBookL1,
+        > as ExchangeTransformer<BinanceFuturesUsd, AllMarketOrderBooksL1>>::new(
+            ws_sink_tx,
+            instrument_map,
+        )
+        .await
+        .unwrap();
+
+        let btc_update = r#"{"T":1,"s":"BTCUSDT","b":"100","B":"1","a":"101","A":"2"}"#;
+        let eth_update = r#"{"T":2,"s":"ETHUSDT","b":"10","B":"1","a":"11","A":"2"}"#;
+
+        let actual = transformer.transform(serde_json::from_str(btc_update).unwrap());
+        assert_eq!(actual.len(), 1);
+        let event = actual.into_iter().next().unwrap().unwrap();
+        assert_eq!(event.instrument, btc);
+        assert_eq!(event.kind.best_bid, Level::new(100.0, 1.0));
+        assert_eq!(event.kind.best_ask, Level::new(101.0, 2.0));
+
+        // ETHUSDT is not subscribed to, so it is discarded
+        assert!(transformer
+            .transform(serde_json::from_str(eth_update).unwrap())
+            .is_empty());
+    }
+}
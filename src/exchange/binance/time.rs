@@ -0,0 +1,38 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Binance`](super::Binance) REST server-time response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#check-server-time>
+/// ```json
+/// {
+///   "serverTime": 1499827319559
+/// }
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BinanceServerTime {
+    #[serde(
+        rename = "serverTime",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::de::datetime_utc_from_epoch_duration;
+    use std::time::Duration;
+
+    #[test]
+    fn test_de_binance_server_time() {
+        let input = r#"{"serverTime":1499827319559}"#;
+        assert_eq!(
+            serde_json::from_str::<BinanceServerTime>(input).unwrap(),
+            BinanceServerTime {
+                time: datetime_utc_from_epoch_duration(Duration::from_millis(1499827319559)),
+            }
+        );
+    }
+}
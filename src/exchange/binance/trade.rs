@@ -2,7 +2,7 @@ use super::BinanceChannel;
 use crate::{
     event::{MarketEvent, MarketIter},
     exchange::{ExchangeId, ExchangeSub},
-    subscription::trade::PublicTrade,
+    subscription::trade::{PublicTrade, SideSource, TradeOrderIds, TradeSource},
     Identifier,
 };
 use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
@@ -67,6 +67,10 @@ pub struct BinanceTrade {
     pub amount: f64,
     #[serde(alias = "m", deserialize_with = "de_side_from_buyer_is_maker")]
     pub side: Side,
+    #[serde(alias = "b", default)]
+    pub buyer_order_id: Option<u64>,
+    #[serde(alias = "a", default)]
+    pub seller_order_id: Option<u64>,
 }
 
 impl Identifier<Option<SubscriptionId>> for BinanceTrade {
@@ -80,6 +84,7 @@ impl From<(ExchangeId, Instrument, BinanceTrade)> for MarketIter<PublicTrade> {
         Self(vec![Ok(MarketEvent {
             exchange_time: trade.time,
             received_time: Utc::now(),
+            exchange_time_synthesized: false,
             exchange: Exchange::from(exchange_id),
             instrument,
             kind: PublicTrade {
@@ -87,11 +92,91 @@ impl From<(ExchangeId, Instrument, BinanceTrade)> for MarketIter<PublicTrade> {
                 price: trade.price,
                 amount: trade.amount,
                 side: trade.side,
+                source: TradeSource::Live,
+                side_source: SideSource::Exchange,
+                order_ids: TradeOrderIds {
+                    buyer: trade.buyer_order_id.map(|id| id.to_string()),
+                    seller: trade.seller_order_id.map(|id| id.to_string()),
+                    ..TradeOrderIds::default()
+                },
+                aggregate_count: None,
             },
         })])
     }
 }
 
+/// Binance REST recent trade, used to backfill [`PublicTrade`] history.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#recent-trades-list>
+/// ```json
+/// {
+///     "id": 28457,
+///     "price": "4.00000100",
+///     "qty": "12.00000000",
+///     "quoteQty": "48.000012",
+///     "time": 1499865549590,
+///     "isBuyerMaker": true,
+///     "isBestMatch": true
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceRecentTrade {
+    pub id: u64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(alias = "qty", deserialize_with = "barter_integration::de::de_str")]
+    pub amount: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+    #[serde(
+        alias = "isBuyerMaker",
+        deserialize_with = "de_side_from_buyer_is_maker"
+    )]
+    pub side: Side,
+}
+
+impl From<(ExchangeId, Instrument, Vec<BinanceRecentTrade>)> for MarketIter<PublicTrade> {
+    fn from(
+        (exchange_id, instrument, trades): (ExchangeId, Instrument, Vec<BinanceRecentTrade>),
+    ) -> Self {
+        trades
+            .into_iter()
+            .map(|trade| {
+                Ok(MarketEvent {
+                    exchange_time: trade.time,
+                    received_time: Utc::now(),
+                    exchange_time_synthesized: false,
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: PublicTrade {
+                        id: trade.id.to_string(),
+                        price: trade.price,
+                        amount: trade.amount,
+                        side: trade.side,
+                        source: TradeSource::Historical,
+                        side_source: SideSource::Exchange,
+                        order_ids: TradeOrderIds::default(),
+                        aggregate_count: None,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+/// Construct a Binance REST recent trades request url for the [`Instrument`] from the
+/// `base_url`, requesting at most `limit` trades.
+pub fn recent_trades_url(base_url: &str, instrument: &Instrument, limit: usize) -> String {
+    format!(
+        "{}?symbol={}{}&limit={}",
+        base_url,
+        instrument.base.as_ref().to_uppercase(),
+        instrument.quote.as_ref().to_uppercase(),
+        limit
+    )
+}
+
 /// Deserialize a [`BinanceTrade`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`]
 /// (eg/ "@trade|BTCUSDT").
 pub fn de_trade_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
@@ -156,6 +241,8 @@ mod tests {
                         price: 10000.19,
                         amount: 0.239000,
                         side: Side::Buy,
+                        buyer_order_id: Some(10108767791),
+                        seller_order_id: Some(10108764858),
                     }),
                 },
                 TestCase {
@@ -187,6 +274,8 @@ mod tests {
                         price: 10000.19,
                         amount: 0.239000,
                         side: Side::Sell,
+                        buyer_order_id: None,
+                        seller_order_id: None,
                     }),
                 },
                 TestCase {
@@ -206,6 +295,8 @@ mod tests {
                         price: 10000.19,
                         amount: 0.239000,
                         side: Side::Buy,
+                        buyer_order_id: None,
+                        seller_order_id: None,
                     }),
                 },
                 TestCase {
@@ -223,6 +314,8 @@ mod tests {
                         price: 10000.19,
                         amount: 0.239000,
                         side: Side::Buy,
+                        buyer_order_id: None,
+                        seller_order_id: None,
                     }),
                 },
             ];
@@ -243,5 +336,64 @@ mod tests {
                 }
             }
         }
+
+        #[test]
+        fn test_binance_recent_trades() {
+            let input = r#"
+            [
+                {
+                    "id": 28457,
+                    "price": "4.00000100",
+                    "qty": "12.00000000",
+                    "quoteQty": "48.000012",
+                    "time": 1499865549590,
+                    "isBuyerMaker": true,
+                    "isBestMatch": true
+                },
+                {
+                    "id": 28458,
+                    "price": "4.00000200",
+                    "qty": "1.00000000",
+                    "quoteQty": "4.000002",
+                    "time": 1499865549591,
+                    "isBuyerMaker": false,
+                    "isBestMatch": true
+                }
+            ]
+            "#;
+
+            let actual = serde_json::from_str::<Vec<BinanceRecentTrade>>(input).unwrap();
+            let expected = vec![
+                BinanceRecentTrade {
+                    id: 28457,
+                    price: 4.00000100,
+                    amount: 12.0,
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1499865549590)),
+                    side: Side::Sell,
+                },
+                BinanceRecentTrade {
+                    id: 28458,
+                    price: 4.00000200,
+                    amount: 1.0,
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1499865549591)),
+                    side: Side::Buy,
+                },
+            ];
+            assert_eq!(actual, expected);
+
+            // Backfilled trades are marked as historical
+            let instrument = Instrument::from((
+                "btc",
+                "usdt",
+                barter_integration::model::instrument::kind::InstrumentKind::Spot,
+            ));
+            let sources =
+                MarketIter::<PublicTrade>::from((ExchangeId::BinanceSpot, instrument, actual))
+                    .0
+                    .into_iter()
+                    .map(|trade| trade.unwrap().kind.source)
+                    .collect::<Vec<_>>();
+            assert_eq!(sources, vec![TradeSource::Historical; 2]);
+        }
     }
 }
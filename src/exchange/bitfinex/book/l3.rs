@@ -0,0 +1,526 @@
+use super::super::conf::{extract_message_meta, BitfinexMessageMeta};
+use crate::{
+    error::DataError,
+    subscription::book::{Level, OrderBook, OrderBookSide},
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    de::extract_next,
+    model::{instrument::Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// [`Bitfinex`](super::super::Bitfinex) raw OrderBook (precision "R0") message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+/// #### Heartbeat
+/// ```json
+/// [17082,"hb"]
+/// ```
+///
+/// #### Initial OrderBook Snapshot
+/// Format: \[CHANNEL_ID, \[\[ORDER_ID, PRICE, AMOUNT\], ...\]\]
+/// ```json
+/// [17082,[[34668738065,3829.7,0.5],[34668538815,3829.8,-0.25]]]
+/// ```
+///
+/// #### OrderBook Update
+/// Format: \[CHANNEL_ID, \[ORDER_ID, PRICE, AMOUNT\]\]
+/// ```json
+/// [17082,[34668738065,3829.6,0.4]]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BitfinexOrderBookL3 {
+    pub channel_id: u32,
+    pub payload: BitfinexOrderBookL3Payload,
+    pub meta: BitfinexMessageMeta,
+}
+
+/// [`Bitfinex`](super::super::Bitfinex) raw OrderBook payload variants.
+///
+/// See [`BitfinexOrderBookL3`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub enum BitfinexOrderBookL3Payload {
+    Heartbeat,
+    Snapshot(Vec<BitfinexRawOrder>),
+    Update(BitfinexRawOrder),
+}
+
+/// [`Bitfinex`](super::super::Bitfinex) raw OrderBook order.
+///
+/// ### Notes
+/// - A positive `amount` is a bid, and a negative `amount` is an ask.
+/// - A `price` of 0 indicates the order with `id` has been removed from the OrderBook.
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BitfinexRawOrder {
+    pub id: u64,
+    pub price: f64,
+    pub amount: f64,
+}
+
+impl BitfinexRawOrder {
+    /// Determine if this [`BitfinexRawOrder`] removes the order with the same `id`.
+    pub fn is_removal(&self) -> bool {
+        self.price == 0.0
+    }
+}
+
+impl AsRef<BitfinexMessageMeta> for BitfinexOrderBookL3 {
+    fn as_ref(&self) -> &BitfinexMessageMeta {
+        &self.meta
+    }
+}
+
+impl Identifier<Option<SubscriptionId>> for BitfinexOrderBookL3 {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self.payload {
+            BitfinexOrderBookL3Payload::Heartbeat => None,
+            BitfinexOrderBookL3Payload::Snapshot(_) | BitfinexOrderBookL3Payload::Update(_) => {
+                Some(SubscriptionId::from(self.channel_id.to_string()))
+            }
+        }
+    }
+}
+
+/// [`Bitfinex`](super::super::Bitfinex) raw OrderBook [`OrderBookUpdater`].
+///
+/// Tracks the state of each individual order by order id, generating a non-aggregated
+/// [`OrderBook`] where each [`Level`] is a single order.
+///
+/// 1. Subscribe to the "book" channel with precision "R0".
+/// 2. The first message received is a snapshot of all orders; replace any existing orders.
+/// 3. For each subsequent update, when PRICE = 0 remove the order with ORDER_ID.
+/// 4. When PRICE > 0 add or update the order with ORDER_ID.
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct BitfinexBookUpdaterL3 {
+    pub orders: BTreeMap<u64, BitfinexRawOrderState>,
+}
+
+/// Current state of a [`Bitfinex`](super::super::Bitfinex) raw OrderBook order.
+#[derive(Clone, Copy, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BitfinexRawOrderState {
+    pub side: Side,
+    pub price: f64,
+    pub amount: f64,
+}
+
+impl From<BitfinexRawOrder> for BitfinexRawOrderState {
+    fn from(order: BitfinexRawOrder) -> Self {
+        Self {
+            side: if order.amount.is_sign_positive() {
+                Side::Buy
+            } else {
+                Side::Sell
+            },
+            price: order.price,
+            amount: order.amount.abs(),
+        }
+    }
+}
+
+impl BitfinexBookUpdaterL3 {
+    /// Apply a single [`BitfinexRawOrder`] to the tracked order state.
+    pub fn apply(&mut self, order: BitfinexRawOrder) {
+        if order.is_removal() {
+            if self.orders.remove(&order.id).is_none() {
+                debug!(
+                    order_id = order.id,
+                    "Bitfinex raw order to remove not found"
+                );
+            }
+        } else {
+            self.orders
+                .insert(order.id, BitfinexRawOrderState::from(order));
+        }
+    }
+
+    /// Generate the non-aggregated [`OrderBookSide`] for the provided [`Side`].
+    fn book_side(&self, side: Side) -> OrderBookSide {
+        OrderBookSide::new(
+            side,
+            self.orders
+                .values()
+                .filter(|order| order.side == side)
+                .map(|order| Level::new(order.price, order.amount)),
+        )
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for BitfinexBookUpdaterL3 {
+    type OrderBook = OrderBook;
+    type Update = BitfinexOrderBookL3;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Initial OrderBook snapshot is received over the WebSocket after subscribing
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::default(),
+            book: OrderBook {
+                last_update_time: DateTime::default(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            },
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<Self::OrderBook>, DataError> {
+        match update.payload {
+            BitfinexOrderBookL3Payload::Heartbeat => return Ok(None),
+            BitfinexOrderBookL3Payload::Snapshot(orders) => {
+                self.orders.clear();
+                orders.into_iter().for_each(|order| self.apply(order));
+            }
+            BitfinexOrderBookL3Payload::Update(order) => self.apply(order),
+        }
+
+        // Raw orders do not contain a timestamp, so the conf flag message timestamp is used if
+        // enabled, otherwise the MarketEvent exchange_time is synthesized from the received time
+        book.last_update_time = update.meta.time.unwrap_or_default();
+        book.bids = self.book_side(Side::Buy);
+        book.asks = self.book_side(Side::Sell);
+
+        Ok(Some(book.snapshot()))
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BitfinexOrderBookL3 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = BitfinexOrderBookL3;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("BitfinexOrderBookL3 struct from the Bitfinex WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // Heartbeat: [CHANNEL_ID, "hb"]
+                // Snapshot: [CHANNEL_ID, [[ORDER_ID, PRICE, AMOUNT], ...]]
+                // Update: [CHANNEL_ID, [ORDER_ID, PRICE, AMOUNT]]
+
+                // Extract CHANNEL_ID used to identify SubscriptionId: 1st element of the sequence
+                let channel_id: u32 = extract_next(&mut seq, "channel_id")?;
+
+                // Extract payload: 2nd element of the sequence
+                let payload = match extract_next(&mut seq, "payload")? {
+                    BitfinexBookTagOrPayload::Tag(tag) if tag == "hb" => {
+                        BitfinexOrderBookL3Payload::Heartbeat
+                    }
+                    BitfinexBookTagOrPayload::Tag(other) => {
+                        return Err(serde::de::Error::unknown_variant(
+                            &other,
+                            &["heartbeat (hb)"],
+                        ))
+                    }
+                    BitfinexBookTagOrPayload::Snapshot(orders) => {
+                        BitfinexOrderBookL3Payload::Snapshot(orders)
+                    }
+                    BitfinexBookTagOrPayload::Update(order) => {
+                        BitfinexOrderBookL3Payload::Update(order)
+                    }
+                };
+
+                // Extract any trailing conf flag SEQUENCE & MTS, ignoring additional elements
+                let meta = extract_message_meta(&mut seq)?;
+                Ok(BitfinexOrderBookL3 {
+                    channel_id,
+                    payload,
+                    meta,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the WebSocket BitfinexOrderBookL3
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BitfinexRawOrder {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = BitfinexRawOrder;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("BitfinexRawOrder struct from the Bitfinex WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // Raw Order: [ORDER_ID, PRICE, AMOUNT]
+                let id = extract_next(&mut seq, "id")?;
+                let price = extract_next(&mut seq, "price")?;
+                let amount = extract_next(&mut seq, "amount")?;
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Bitfinex may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(BitfinexRawOrder { id, price, amount })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the BitfinexRawOrder
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+/// Second element of a [`BitfinexOrderBookL3`] sequence, either a message tag (eg/ "hb"), an
+/// initial snapshot of orders, or a single order update.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BitfinexBookTagOrPayload {
+    Tag(String),
+    Snapshot(Vec<BitfinexRawOrder>),
+    Update(BitfinexRawOrder),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+
+        #[test]
+        fn test_bitfinex_order_book_l3() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BitfinexOrderBookL3, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: heartbeat
+                    input: r#"[17082,"hb"]"#,
+                    expected: Ok(BitfinexOrderBookL3 {
+                        channel_id: 17082,
+                        payload: BitfinexOrderBookL3Payload::Heartbeat,
+                        meta: BitfinexMessageMeta::default(),
+                    }),
+                },
+                TestCase {
+                    // TC1: initial snapshot
+                    input: r#"[17082,[[34668738065,3829.7,0.5],[34668538815,3829.8,-0.25]]]"#,
+                    expected: Ok(BitfinexOrderBookL3 {
+                        channel_id: 17082,
+                        payload: BitfinexOrderBookL3Payload::Snapshot(vec![
+                            BitfinexRawOrder {
+                                id: 34668738065,
+                                price: 3829.7,
+                                amount: 0.5,
+                            },
+                            BitfinexRawOrder {
+                                id: 34668538815,
+                                price: 3829.8,
+                                amount: -0.25,
+                            },
+                        ]),
+                        meta: BitfinexMessageMeta::default(),
+                    }),
+                },
+                TestCase {
+                    // TC2: order removal update
+                    input: r#"[17082,[34668738065,0,1]]"#,
+                    expected: Ok(BitfinexOrderBookL3 {
+                        channel_id: 17082,
+                        payload: BitfinexOrderBookL3Payload::Update(BitfinexRawOrder {
+                            id: 34668738065,
+                            price: 0.0,
+                            amount: 1.0,
+                        }),
+                        meta: BitfinexMessageMeta::default(),
+                    }),
+                },
+                TestCase {
+                    // TC3: unknown message tag
+                    input: r#"[17082,"cs",-1234]"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BitfinexOrderBookL3>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_update_bitfinex_order_book_l3() {
+        struct TestCase {
+            input: &'static str,
+            expected_orders: Vec<(u64, BitfinexRawOrderState)>,
+            expected_bids: Vec<Level>,
+            expected_asks: Vec<Level>,
+        }
+
+        let mut updater = BitfinexBookUpdaterL3::default();
+        let mut book = OrderBook {
+            last_update_time: Default::default(),
+            bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+            asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: snapshot w/ two bids at the same price & one ask
+                input: r#"[17082,[[1,100.0,0.5],[2,100.0,1.5],[3,101.0,-2.0]]]"#,
+                expected_orders: vec![
+                    (1, state(Side::Buy, 100.0, 0.5)),
+                    (2, state(Side::Buy, 100.0, 1.5)),
+                    (3, state(Side::Sell, 101.0, 2.0)),
+                ],
+                expected_bids: vec![Level::new(100.0, 1.5), Level::new(100.0, 0.5)],
+                expected_asks: vec![Level::new(101.0, 2.0)],
+            },
+            TestCase {
+                // TC1: new ask order
+                input: r#"[17082,[4,102.0,-1.0]]"#,
+                expected_orders: vec![
+                    (1, state(Side::Buy, 100.0, 0.5)),
+                    (2, state(Side::Buy, 100.0, 1.5)),
+                    (3, state(Side::Sell, 101.0, 2.0)),
+                    (4, state(Side::Sell, 102.0, 1.0)),
+                ],
+                expected_bids: vec![Level::new(100.0, 1.5), Level::new(100.0, 0.5)],
+                expected_asks: vec![Level::new(101.0, 2.0), Level::new(102.0, 1.0)],
+            },
+            TestCase {
+                // TC2: existing bid order amended to a new price & amount
+                input: r#"[17082,[1,99.5,0.75]]"#,
+                expected_orders: vec![
+                    (1, state(Side::Buy, 99.5, 0.75)),
+                    (2, state(Side::Buy, 100.0, 1.5)),
+                    (3, state(Side::Sell, 101.0, 2.0)),
+                    (4, state(Side::Sell, 102.0, 1.0)),
+                ],
+                expected_bids: vec![Level::new(100.0, 1.5), Level::new(99.5, 0.75)],
+                expected_asks: vec![Level::new(101.0, 2.0), Level::new(102.0, 1.0)],
+            },
+            TestCase {
+                // TC3: zero price removes the bid order w/ id 2
+                input: r#"[17082,[2,0,1]]"#,
+                expected_orders: vec![
+                    (1, state(Side::Buy, 99.5, 0.75)),
+                    (3, state(Side::Sell, 101.0, 2.0)),
+                    (4, state(Side::Sell, 102.0, 1.0)),
+                ],
+                expected_bids: vec![Level::new(99.5, 0.75)],
+                expected_asks: vec![Level::new(101.0, 2.0), Level::new(102.0, 1.0)],
+            },
+            TestCase {
+                // TC4: zero price removes the ask order w/ id 3 (amount sign is irrelevant)
+                input: r#"[17082,[3,0,-1]]"#,
+                expected_orders: vec![
+                    (1, state(Side::Buy, 99.5, 0.75)),
+                    (4, state(Side::Sell, 102.0, 1.0)),
+                ],
+                expected_bids: vec![Level::new(99.5, 0.75)],
+                expected_asks: vec![Level::new(102.0, 1.0)],
+            },
+            TestCase {
+                // TC5: removing an unknown order id leaves the state unchanged
+                input: r#"[17082,[999,0,1]]"#,
+                expected_orders: vec![
+                    (1, state(Side::Buy, 99.5, 0.75)),
+                    (4, state(Side::Sell, 102.0, 1.0)),
+                ],
+                expected_bids: vec![Level::new(99.5, 0.75)],
+                expected_asks: vec![Level::new(102.0, 1.0)],
+            },
+            TestCase {
+                // TC6: new snapshot replaces all existing orders
+                input: r#"[17082,[[5,98.0,3.0]]]"#,
+                expected_orders: vec![(5, state(Side::Buy, 98.0, 3.0))],
+                expected_bids: vec![Level::new(98.0, 3.0)],
+                expected_asks: vec![],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let update = serde_json::from_str::<BitfinexOrderBookL3>(test.input).unwrap();
+            let snapshot = updater.update(&mut book, update).unwrap().unwrap();
+
+            let actual_orders = updater
+                .orders
+                .iter()
+                .map(|(id, order)| (*id, *order))
+                .collect::<Vec<_>>();
+            assert_eq!(actual_orders, test.expected_orders, "TC{} failed", index);
+
+            let expected_book = OrderBook {
+                last_update_time: snapshot.last_update_time,
+                bids: OrderBookSide::new(Side::Buy, test.expected_bids),
+                asks: OrderBookSide::new(Side::Sell, test.expected_asks),
+            };
+            assert_eq!(snapshot, expected_book, "TC{} failed", index);
+        }
+    }
+
+    fn state(side: Side, price: f64, amount: f64) -> BitfinexRawOrderState {
+        BitfinexRawOrderState {
+            side,
+            price,
+            amount,
+        }
+    }
+}
@@ -0,0 +1,2 @@
+/// Level 3 OrderBook types (raw, non-aggregated orders).
+pub mod l3;
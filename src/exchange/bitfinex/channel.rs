@@ -1,6 +1,11 @@
 use super::Bitfinex;
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{
+        book::OrderBooksL3,
+        funding::{FundingTickers, FundingTrades},
+        trade::PublicTrades,
+        Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +22,16 @@ impl BitfinexChannel {
     ///
     /// See docs: <https://docs.bitfinex.com/reference/ws-public-trades>
     pub const TRADES: Self = Self("trades");
+
+    /// [`Bitfinex`] real-time ticker channel.
+    ///
+    /// See docs: <https://docs.bitfinex.com/reference/ws-public-ticker>
+    pub const TICKER: Self = Self("ticker");
+
+    /// [`Bitfinex`] real-time raw OrderBook channel, subscribed to with precision "R0".
+    ///
+    /// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+    pub const ORDER_BOOK_L3: Self = Self("book");
 }
 
 impl Identifier<BitfinexChannel> for Subscription<Bitfinex, PublicTrades> {
@@ -25,6 +40,30 @@ impl Identifier<BitfinexChannel> for Subscription<Bitfinex, PublicTrades> {
     }
 }
 
+impl Identifier<BitfinexChannel> for Subscription<Bitfinex, FundingTrades> {
+    fn id(&self) -> BitfinexChannel {
+        BitfinexChannel::TRADES
+    }
+}
+
+impl Identifier<BitfinexChannel> for Subscription<Bitfinex, FundingTickers> {
+    fn id(&self) -> BitfinexChannel {
+        BitfinexChannel::TICKER
+    }
+}
+
+impl Identifier<BitfinexChannel> for Subscription<Bitfinex, OrderBooksL3> {
+    fn id(&self) -> BitfinexChannel {
+        BitfinexChannel::ORDER_BOOK_L3
+    }
+}
+
+impl From<&'static str> for BitfinexChannel {
+    fn from(channel: &'static str) -> Self {
+        Self(channel)
+    }
+}
+
 impl AsRef<str> for BitfinexChannel {
     fn as_ref(&self) -> &str {
         self.0
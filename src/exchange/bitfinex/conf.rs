@@ -0,0 +1,284 @@
+use crate::{
+    error::DataError,
+    subscriber::config::ConnectionConfig,
+    subscription::{Map, SubKind},
+    transformer::ExchangeTransformer,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    de::datetime_utc_from_epoch_duration, model::instrument::Instrument,
+    protocol::websocket::WsMessage, Transformer,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::json;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// [`Bitfinex`](super::Bitfinex) `TIMESTAMP` conf flag, appending the message timestamp (milliseconds)
+/// to every message.
+///
+/// See docs: <https://docs.bitfinex.com/docs/ws-general#configuration>
+pub const CONF_FLAG_TIMESTAMP_BITFINEX: u64 = 32768;
+
+/// [`Bitfinex`](super::Bitfinex) `SEQ_ALL` conf flag, appending a connection-level sequence
+/// number to every message.
+///
+/// See docs: <https://docs.bitfinex.com/docs/ws-general#configuration>
+pub const CONF_FLAG_SEQ_ALL_BITFINEX: u64 = 65536;
+
+/// [`Bitfinex`](super::Bitfinex) conf flags enabled on every connection.
+pub const CONF_FLAGS_BITFINEX: u64 = CONF_FLAG_TIMESTAMP_BITFINEX | CONF_FLAG_SEQ_ALL_BITFINEX;
+
+/// Construct the [`Bitfinex`](super::Bitfinex) "conf" request enabling the provided `flags`.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.bitfinex.com/docs/ws-general#configuration>
+/// ```json
+/// {"event": "conf", "flags": 98304}
+/// ```
+pub fn conf_request(flags: u64) -> WsMessage {
+    WsMessage::Text(json!({ "event": "conf", "flags": flags }).to_string())
+}
+
+/// Connection-level sequence number & message timestamp appended to every
+/// [`Bitfinex`](super::Bitfinex) message when the [`CONF_FLAGS_BITFINEX`] are enabled.
+///
+/// Format: \[CHANNEL_ID, ..., SEQUENCE, MTS\]
+///
+/// Fields are `None` if the associated flag is disabled (eg/ before the "conf" request was
+/// acknowledged).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Serialize)]
+pub struct BitfinexMessageMeta {
+    pub sequence: Option<u64>,
+    pub time: Option<DateTime<Utc>>,
+}
+
+/// Extract the trailing [`BitfinexMessageMeta`] from the remaining elements of a
+/// [`Bitfinex`](super::Bitfinex) message sequence, ignoring any further elements.
+pub fn extract_message_meta<'de, SeqAccessor>(
+    seq: &mut SeqAccessor,
+) -> Result<BitfinexMessageMeta, SeqAccessor::Error>
+where
+    SeqAccessor: serde::de::SeqAccess<'de>,
+{
+    let sequence = seq
+        .next_element::<serde_json::Value>()?
+        .and_then(|sequence| sequence.as_u64());
+
+    let time = seq
+        .next_element::<serde_json::Value>()?
+        .and_then(|time| time.as_u64())
+        .map(|time| datetime_utc_from_epoch_duration(Duration::from_millis(time)));
+
+    // Ignore any additional elements or SerDe will fail
+    //  '--> Bitfinex may add fields without warning
+    while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+    Ok(BitfinexMessageMeta { sequence, time })
+}
+
+/// [`Transformer`] wrapper that validates the connection-level [`BitfinexMessageMeta`] sequence
+/// number of every [`Bitfinex`](super::Bitfinex) message before passing it to the `Inner`
+/// [`Transformer`].
+///
+/// A gap in the sequence yields a terminal [`DataError::InvalidSequence`], so the connection is
+/// re-initialised rather than silently missing messages. Messages without a sequence number are
+/// passed through unvalidated.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct BitfinexSequencer<Inner> {
+    pub inner: Inner,
+    pub last_sequence: Option<u64>,
+}
+
+impl<Inner> BitfinexSequencer<Inner> {
+    /// Construct a new [`BitfinexSequencer`] wrapping the provided `Inner` [`Transformer`].
+    pub fn new(inner: Inner) -> Self {
+        Self {
+            inner,
+            last_sequence: None,
+        }
+    }
+
+    /// Validate the provided sequence number follows on from the last sequence number seen.
+    pub fn validate_sequence(&mut self, sequence: u64) -> Result<(), DataError> {
+        let last_sequence = self.last_sequence.replace(sequence);
+        match last_sequence {
+            Some(last_sequence) if sequence != last_sequence + 1 => {
+                Err(DataError::InvalidSequence {
+                    prev_last_update_id: last_sequence,
+                    first_update_id: sequence,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<Inner> Transformer for BitfinexSequencer<Inner>
+where
+    Inner: Transformer<Error = DataError>,
+    Inner::Input: AsRef<BitfinexMessageMeta>,
+{
+    type Error = DataError;
+    type Input = Inner::Input;
+    type Output = Inner::Output;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        if let Some(sequence) = input.as_ref().sequence {
+            if let Err(error) = self.validate_sequence(sequence) {
+                return vec![Err(error)];
+            }
+        }
+
+        self.inner.transform(input).into_iter().collect()
+    }
+}
+
+#[async_trait]
+impl<Exchange, Kind, Inner> ExchangeTransformer<Exchange, Kind> for BitfinexSequencer<Inner>
+where
+    Exchange: Send,
+    Kind: SubKind + Send,
+    Inner: ExchangeTransformer<Exchange, Kind> + Send,
+    Inner::Input: AsRef<BitfinexMessageMeta>,
+{
+    async fn new(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+    ) -> Result<Self, DataError> {
+        Inner::new(ws_sink_tx, instrument_map).await.map(Self::new)
+    }
+
+    fn configure(&mut self, config: &ConnectionConfig) {
+        self.inner.configure(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{bitfinex::Bitfinex, StreamSelector},
+        subscription::{trade::PublicTrades, Subscription},
+        test_util::{MockExchange, MockScript},
+        MarketStream,
+    };
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+    use futures::StreamExt;
+
+    #[tokio::test]
+    async fn test_bitfinex_conf_flags_sent_on_connect() {
+        let script = MockScript::new()
+            .send_text(r#"{"event":"info","version":2,"serverId":"1","platform":{"status":1}}"#)
+            .receive()
+            .send_text(r#"{"event":"conf","status":"OK","flags":98304}"#)
+            .receive()
+            .send_text(r#"{"event":"subscribed","channel":"trades","chanId":420191,"symbol":"tBTCUSD","pair":"BTCUSD"}"#)
+            .send_text(r#"[420191,"te",[1225484398,1665452200022,0.08980641,19027.02807752],1,1665452200030]"#)
+            .send_text(r#"[420191,"tu",[1225484398,1665452200022,0.08980641,19027.02807752],2,1665452200031]"#)
+            .send_text(r#"[420191,"hb",3,1665452200032]"#)
+            .send_text(r#"[420191,"te",[1225484399,1665452200040,-0.5,19027.0],5,1665452200041]"#)
+            .close();
+        let mut exchange = MockExchange::start([script]).await.unwrap();
+
+        let subscriptions = [Subscription::from((
+            Bitfinex,
+            "btc",
+            "usd",
+            InstrumentKind::Spot,
+            PublicTrades,
+        ))];
+        let config = ConnectionConfig::default().url(exchange.url());
+        let mut stream =
+            <Bitfinex as StreamSelector<PublicTrades>>::Stream::init(&subscriptions, &config)
+                .await
+                .unwrap();
+
+        // Conf request is sent before the subscription request
+        let conf = exchange.next_request().await.unwrap().message;
+        assert_eq!(conf, conf_request(CONF_FLAGS_BITFINEX));
+        let WsMessage::Text(subscribe) = exchange.next_request().await.unwrap().message else {
+            panic!("Bitfinex subscription request is not a text WsMessage")
+        };
+        assert!(subscribe.contains(r#""event":"subscribe""#));
+
+        // Sequenced messages are parsed, including "tu" & heartbeat sequence numbers
+        let event = stream.next().await.unwrap().unwrap();
+        assert_eq!(event.kind.id, "1225484398");
+        assert_eq!(event.kind.side, Side::Buy);
+
+        // Gap between sequence numbers 3 & 5 is a terminal InvalidSequence
+        let error = stream.next().await.unwrap().unwrap_err();
+        assert!(matches!(
+            error,
+            DataError::InvalidSequence {
+                prev_last_update_id: 3,
+                first_update_id: 5,
+            }
+        ));
+        assert!(error.is_terminal());
+    }
+
+    #[test]
+    fn test_bitfinex_sequencer_validate_sequence() {
+        struct TestCase {
+            input: u64,
+            expected: Result<(), DataError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: first sequence number is always valid
+                input: 5,
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC1: next sequence number is valid
+                input: 6,
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC2: gap in sequence is invalid
+                input: 8,
+                expected: Err(DataError::InvalidSequence {
+                    prev_last_update_id: 6,
+                    first_update_id: 8,
+                }),
+            },
+            TestCase {
+                // TC3: sequence continues from the gap
+                input: 9,
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC4: repeated sequence number is invalid
+                input: 9,
+                expected: Err(DataError::InvalidSequence {
+                    prev_last_update_id: 9,
+                    first_update_id: 9,
+                }),
+            },
+        ];
+
+        let mut sequencer = BitfinexSequencer::new(());
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = sequencer.validate_sequence(test.input);
+            match (actual, test.expected) {
+                (Ok(()), Ok(())) => {}
+                (Err(actual), Err(expected)) => {
+                    assert_eq!(
+                        actual.to_string(),
+                        expected.to_string(),
+                        "TC{} failed",
+                        index
+                    )
+                }
+                (actual, expected) => {
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,546 @@
+use super::conf::{extract_message_meta, BitfinexMessageMeta};
+use crate::{
+    event::{exchange_time_or_received, MarketEvent, MarketIter},
+    exchange::{timestamp::EpochUnit, ExchangeId},
+    subscription::{
+        funding::{FundingTicker, FundingTrade},
+        trade::TradeSource,
+    },
+    Identifier,
+};
+use barter_integration::{
+    de::extract_next,
+    model::{instrument::Instrument, Exchange, Side, SubscriptionId},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Bitfinex`](super::Bitfinex) message received over
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) relating to an active funding
+/// market (eg/ "fUSD") [`Subscription`](crate::Subscription).
+///
+/// ### Raw Payload Examples
+/// #### Heartbeat
+/// See docs: <https://docs.bitfinex.com/docs/ws-general#heartbeating>
+/// ```json
+/// [337371,"hb"]
+/// ```
+///
+/// #### Initial Funding Trades Snapshot
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-trades>
+/// ```json
+/// [337371,[[133323543,1574694605000,-59.84,0.00023647,2],[133323542,1574694604000,150.0,0.0002364,30]]]
+/// ```
+///
+/// #### Funding Trade
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-trades>
+/// ```json
+/// [337371,"fte",[133323543,1574694605000,-59.84,0.00023647,2]]
+/// ```
+///
+/// #### Funding Ticker
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-ticker>
+/// ```json
+/// [232591,[0.00034470,0.000316,30,1682003.09,0.00031,2,4450.62,0.00002,0.0697,0.00033,219982320.68,0.00037,0.000177,null,null,16491569.47]]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BitfinexFundingMessage {
+    pub channel_id: u32,
+    pub payload: BitfinexFundingPayload,
+    pub meta: BitfinexMessageMeta,
+}
+
+/// [`Bitfinex`](super::Bitfinex) funding market data variants associated with an active
+/// [`Subscription`](crate::Subscription).
+///
+/// See [`BitfinexFundingMessage`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub enum BitfinexFundingPayload {
+    Heartbeat,
+    Trade(BitfinexFundingTrade),
+    Snapshot(Vec<BitfinexFundingTrade>),
+    Ticker(BitfinexFundingTicker),
+}
+
+/// [`Bitfinex`](super::Bitfinex) real-time funding trade.
+///
+/// Format: \[ID, MTS, AMOUNT, RATE, PERIOD\], <br> where +/- of amount indicates Side
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-trades>
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BitfinexFundingTrade {
+    pub id: u64,
+    pub time: DateTime<Utc>,
+    pub side: Side,
+    pub amount: f64,
+    pub rate: f64,
+    pub period: u32,
+}
+
+/// [`Bitfinex`](super::Bitfinex) real-time funding ticker.
+///
+/// Format: \[FRR, BID, BID_PERIOD, BID_SIZE, ASK, ASK_PERIOD, ASK_SIZE, DAILY_CHANGE,
+/// DAILY_CHANGE_RELATIVE, LAST_PRICE, VOLUME, HIGH, LOW, _PLACEHOLDER, _PLACEHOLDER,
+/// FRR_AMOUNT_AVAILABLE\]
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-ticker>
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BitfinexFundingTicker {
+    pub flash_return_rate: f64,
+    pub bid_rate: f64,
+    pub bid_period: u32,
+    pub bid_amount: f64,
+    pub ask_rate: f64,
+    pub ask_period: u32,
+    pub ask_amount: f64,
+    pub last_rate: f64,
+    pub volume: f64,
+    pub high: f64,
+    pub low: f64,
+}
+
+impl Identifier<Option<SubscriptionId>> for BitfinexFundingMessage {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self.payload {
+            BitfinexFundingPayload::Heartbeat => None,
+            _ => Some(SubscriptionId::from(self.channel_id.to_string())),
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, BitfinexFundingTrade)> for MarketEvent<FundingTrade> {
+    fn from(
+        (exchange_id, instrument, trade): (ExchangeId, Instrument, BitfinexFundingTrade),
+    ) -> Self {
+        Self {
+            exchange_time: trade.time,
+            received_time: Utc::now(),
+            exchange_time_synthesized: false,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: FundingTrade {
+                id: trade.id.to_string(),
+                rate: trade.rate,
+                period: trade.period,
+                amount: trade.amount,
+                side: trade.side,
+                source: TradeSource::Live,
+            },
+        }
+    }
+}
+
+impl AsRef<BitfinexMessageMeta> for BitfinexFundingMessage {
+    fn as_ref(&self) -> &BitfinexMessageMeta {
+        &self.meta
+    }
+}
+
+impl From<(ExchangeId, Instrument, BitfinexFundingMessage)> for MarketIter<FundingTrade> {
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, Instrument, BitfinexFundingMessage),
+    ) -> Self {
+        match message.payload {
+            BitfinexFundingPayload::Trade(trade) => Self(vec![Ok(MarketEvent::from((
+                exchange_id,
+                instrument,
+                trade,
+            )))]),
+            BitfinexFundingPayload::Snapshot(trades) => trades
+                .into_iter()
+                .map(|trade| {
+                    let mut event = MarketEvent::from((exchange_id, instrument.clone(), trade));
+                    event.kind.source = TradeSource::Historical;
+                    Ok(event)
+                })
+                .collect(),
+            BitfinexFundingPayload::Heartbeat | BitfinexFundingPayload::Ticker(_) => Self(vec![]),
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, BitfinexFundingMessage)> for MarketIter<FundingTicker> {
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, Instrument, BitfinexFundingMessage),
+    ) -> Self {
+        match message.payload {
+            // Bitfinex tickers do not contain a timestamp, so the conf flag message timestamp
+            // is used if enabled, otherwise the received time
+            BitfinexFundingPayload::Ticker(ticker) => {
+                let received_time = Utc::now();
+                let (exchange_time, exchange_time_synthesized) =
+                    exchange_time_or_received(message.meta.time, received_time);
+                Self(vec![Ok(MarketEvent {
+                    exchange_time,
+                    received_time,
+                    exchange_time_synthesized,
+                    exchange: Exchange::from(exchange_id),
+                    instrument,
+                    kind: FundingTicker {
+                        flash_return_rate: ticker.flash_return_rate,
+                        bid_rate: ticker.bid_rate,
+                        bid_period: ticker.bid_period,
+                        bid_amount: ticker.bid_amount,
+                        ask_rate: ticker.ask_rate,
+                        ask_period: ticker.ask_period,
+                        ask_amount: ticker.ask_amount,
+                        last_rate: ticker.last_rate,
+                        volume: ticker.volume,
+                        high: ticker.high,
+                        low: ticker.low,
+                    },
+                })])
+            }
+            _ => Self(vec![]),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BitfinexFundingMessage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = BitfinexFundingMessage;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("BitfinexFundingMessage struct from the Bitfinex WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // Trade: [CHANNEL_ID, <"fte", "ftu">, [ID, MTS, AMOUNT, RATE, PERIOD]]
+                // Heartbeat: [ CHANNEL_ID, "hb" ]
+                // Snapshot: [CHANNEL_ID, [[ID, MTS, AMOUNT, RATE, PERIOD], ...]]
+                // Ticker: [CHANNEL_ID, [FRR, BID, BID_PERIOD, BID_SIZE, ASK, ...]]
+
+                // Extract CHANNEL_ID used to identify SubscriptionId: 1st element of the sequence
+                let channel_id: u32 = extract_next(&mut seq, "channel_id")?;
+
+                // Extract message tag or untagged payload: 2nd element of the sequence
+                let payload = match extract_next(&mut seq, "message_tag")? {
+                    BitfinexFundingTagOrPayload::Snapshot(trades) => {
+                        BitfinexFundingPayload::Snapshot(trades)
+                    }
+                    BitfinexFundingTagOrPayload::Ticker(ticker) => {
+                        BitfinexFundingPayload::Ticker(ticker)
+                    }
+                    // Filter "ftu" Trades since they are identical but slower
+                    // '--> use as additional Heartbeat
+                    BitfinexFundingTagOrPayload::Tag(tag) => match tag.as_str() {
+                        "hb" => BitfinexFundingPayload::Heartbeat,
+                        "ftu" => {
+                            let _: serde::de::IgnoredAny =
+                                extract_next(&mut seq, "BitfinexFundingTrade")?;
+                            BitfinexFundingPayload::Heartbeat
+                        }
+                        "fte" => BitfinexFundingPayload::Trade(extract_next(
+                            &mut seq,
+                            "BitfinexFundingTrade",
+                        )?),
+                        other => {
+                            return Err(serde::de::Error::unknown_variant(
+                                other,
+                                &["heartbeat (hb)", "funding trade (fte | ftu)"],
+                            ))
+                        }
+                    },
+                };
+
+                // Extract any trailing conf flag SEQUENCE & MTS, ignoring additional elements
+                let meta = extract_message_meta(&mut seq)?;
+                Ok(BitfinexFundingMessage {
+                    channel_id,
+                    payload,
+                    meta,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the WebSocket BitfinexFundingMessage
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+/// Second element of a [`BitfinexFundingMessage`] sequence, either a message tag (eg/ "fte"),
+/// an initial snapshot of recent funding trades, or a funding ticker.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BitfinexFundingTagOrPayload {
+    Tag(String),
+    Snapshot(Vec<BitfinexFundingTrade>),
+    Ticker(BitfinexFundingTicker),
+}
+
+impl<'de> serde::Deserialize<'de> for BitfinexFundingTrade {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = BitfinexFundingTrade;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("BitfinexFundingTrade struct from the Bitfinex WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // Funding Trade: [ID, MTS, AMOUNT, RATE, PERIOD]
+                let id = extract_next(&mut seq, "id")?;
+                let time: u64 = extract_next(&mut seq, "time")?;
+                let amount: f64 = extract_next(&mut seq, "amount")?;
+                let rate = extract_next(&mut seq, "rate")?;
+                let period = extract_next(&mut seq, "period")?;
+                let side = match amount.is_sign_positive() {
+                    true => Side::Buy,
+                    false => Side::Sell,
+                };
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Bitfinex may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(BitfinexFundingTrade {
+                    id,
+                    // MTS is epoch milliseconds
+                    time: EpochUnit::Milliseconds
+                        .datetime_utc(time)
+                        .map_err(serde::de::Error::custom)?,
+                    side,
+                    amount: amount.abs(),
+                    rate,
+                    period,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the BitfinexFundingTrade message
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BitfinexFundingTicker {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = BitfinexFundingTicker;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("BitfinexFundingTicker struct from the Bitfinex WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // Funding Ticker: [FRR, BID, BID_PERIOD, BID_SIZE, ASK, ASK_PERIOD, ASK_SIZE,
+                //                  DAILY_CHANGE, DAILY_CHANGE_RELATIVE, LAST_PRICE, VOLUME, HIGH,
+                //                  LOW, _PLACEHOLDER, _PLACEHOLDER, FRR_AMOUNT_AVAILABLE]
+                let flash_return_rate = extract_next(&mut seq, "flash_return_rate")?;
+                let bid_rate = extract_next(&mut seq, "bid_rate")?;
+                let bid_period = extract_next(&mut seq, "bid_period")?;
+                let bid_amount = extract_next(&mut seq, "bid_amount")?;
+                let ask_rate = extract_next(&mut seq, "ask_rate")?;
+                let ask_period = extract_next(&mut seq, "ask_period")?;
+                let ask_amount = extract_next(&mut seq, "ask_amount")?;
+                let _: serde::de::IgnoredAny = extract_next(&mut seq, "daily_change")?;
+                let _: serde::de::IgnoredAny = extract_next(&mut seq, "daily_change_relative")?;
+                let last_rate = extract_next(&mut seq, "last_rate")?;
+                let volume = extract_next(&mut seq, "volume")?;
+                let high = extract_next(&mut seq, "high")?;
+                let low = extract_next(&mut seq, "low")?;
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Bitfinex may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(BitfinexFundingTicker {
+                    flash_return_rate,
+                    bid_rate,
+                    bid_period,
+                    bid_amount,
+                    ask_rate,
+                    ask_period,
+                    ask_amount,
+                    last_rate,
+                    volume,
+                    high,
+                    low,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the BitfinexFundingTicker message
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::{
+        de::datetime_utc_from_epoch_duration, error::SocketError,
+        model::instrument::kind::InstrumentKind,
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn test_de_bitfinex_funding_message() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<BitfinexFundingMessage, SocketError>,
+        }
+
+        let funding_trade = BitfinexFundingTrade {
+            id: 133323543,
+            time: datetime_utc_from_epoch_duration(Duration::from_millis(1574694605000)),
+            side: Side::Sell,
+            amount: 59.84,
+            rate: 0.00023647,
+            period: 2,
+        };
+
+        let cases = vec![
+            // TC0: Funding trade message fte
+            TestCase {
+                input: r#"[337371,"fte",[133323543,1574694605000,-59.84,0.00023647,2]]"#,
+                expected: Ok(BitfinexFundingMessage {
+                    channel_id: 337371,
+                    payload: BitfinexFundingPayload::Trade(funding_trade),
+                    meta: BitfinexMessageMeta::default(),
+                }),
+            },
+            // TC1: Funding trade message ftu --> Should be marked as a heartbeat
+            TestCase {
+                input: r#"[337371,"ftu",[133323543,1574694605000,-59.84,0.00023647,2]]"#,
+                expected: Ok(BitfinexFundingMessage {
+                    channel_id: 337371,
+                    payload: BitfinexFundingPayload::Heartbeat,
+                    meta: BitfinexMessageMeta::default(),
+                }),
+            },
+            // TC2: Initial funding trades snapshot
+            TestCase {
+                input: r#"[337371,[[133323543,1574694605000,-59.84,0.00023647,2]]]"#,
+                expected: Ok(BitfinexFundingMessage {
+                    channel_id: 337371,
+                    payload: BitfinexFundingPayload::Snapshot(vec![funding_trade]),
+                    meta: BitfinexMessageMeta::default(),
+                }),
+            },
+            // TC3: Funding ticker
+            TestCase {
+                input: r#"[232591,[0.0003447,0.000316,30,1682003.09,0.00031,2,4450.62,0.00002,0.0697,0.00033,219982320.68,0.00037,0.000177,null,null,16491569.47]]"#,
+                expected: Ok(BitfinexFundingMessage {
+                    channel_id: 232591,
+                    payload: BitfinexFundingPayload::Ticker(BitfinexFundingTicker {
+                        flash_return_rate: 0.0003447,
+                        bid_rate: 0.000316,
+                        bid_period: 30,
+                        bid_amount: 1682003.09,
+                        ask_rate: 0.00031,
+                        ask_period: 2,
+                        ask_amount: 4450.62,
+                        last_rate: 0.00033,
+                        volume: 219982320.68,
+                        high: 0.00037,
+                        low: 0.000177,
+                    }),
+                    meta: BitfinexMessageMeta::default(),
+                }),
+            },
+            // TC4: Heartbeat message
+            TestCase {
+                input: r#"[232591,"hb"]"#,
+                expected: Ok(BitfinexFundingMessage {
+                    channel_id: 232591,
+                    payload: BitfinexFundingPayload::Heartbeat,
+                    meta: BitfinexMessageMeta::default(),
+                }),
+            },
+            // TC5: Trading pair trade message is invalid
+            TestCase {
+                input: r#"[420191,"te",[1225484398,1665452200022,-0.08980641,19027.02807752]]"#,
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<BitfinexFundingMessage>(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bitfinex_funding_message_to_market_events() {
+        let instrument = Instrument::from(("usd", "usd", InstrumentKind::Spot));
+
+        let trade = serde_json::from_str::<BitfinexFundingMessage>(
+            r#"[337371,"fte",[133323543,1574694605000,-59.84,0.00023647,2]]"#,
+        )
+        .unwrap();
+        let trades =
+            MarketIter::<FundingTrade>::from((ExchangeId::Bitfinex, instrument.clone(), trade)).0;
+        assert_eq!(trades.len(), 1);
+        let trade = trades.into_iter().next().unwrap().unwrap();
+        assert_eq!(
+            trade.kind,
+            FundingTrade {
+                id: "133323543".to_string(),
+                rate: 0.00023647,
+                period: 2,
+                amount: 59.84,
+                side: Side::Sell,
+                source: TradeSource::Live,
+            }
+        );
+
+        let ticker = serde_json::from_str::<BitfinexFundingMessage>(
+            r#"[232591,[0.0003447,0.000316,30,1682003.09,0.00031,2,4450.62,0.00002,0.0697,0.00033,219982320.68,0.00037,0.000177,null,null,16491569.47]]"#,
+        )
+        .unwrap();
+        let tickers =
+            MarketIter::<FundingTicker>::from((ExchangeId::Bitfinex, instrument, ticker)).0;
+        assert_eq!(tickers.len(), 1);
+        let ticker = tickers.into_iter().next().unwrap().unwrap();
+        assert_eq!(ticker.kind.flash_return_rate, 0.0003447);
+        assert_eq!(ticker.kind.last_rate, 0.00033);
+    }
+}
@@ -1,22 +1,71 @@
 use super::Bitfinex;
-use crate::{subscription::Subscription, Identifier};
+use crate::{
+    subscription::{
+        book::OrderBooksL3,
+        funding::{FundingTickers, FundingTrades},
+        trade::PublicTrades,
+        Subscription,
+    },
+    Identifier,
+};
+use barter_integration::model::instrument::Instrument;
 use serde::{Deserialize, Serialize};
 
 /// Type that defines how to translate a Barter [`Subscription`] into a
 /// [`Bitfinex`](super::Bitfinex) market that can be subscribed to.
 ///
+/// ### Notes
+/// - Trading pair markets are "t" prefixed (eg/ "tBTCUSD").
+/// - Funding markets are "f" prefixed & only use the [`Instrument`] base currency (eg/ "fUSD").
+///
 /// See docs: <https://docs.bitfinex.com/docs/ws-public>
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct BitfinexMarket(pub String);
 
-impl<Kind> Identifier<BitfinexMarket> for Subscription<Bitfinex, Kind> {
-    fn id(&self) -> BitfinexMarket {
-        BitfinexMarket(format!(
+impl BitfinexMarket {
+    /// Construct a "t" prefixed trading pair [`BitfinexMarket`] from the provided [`Instrument`].
+    pub fn trading(instrument: &Instrument) -> Self {
+        Self(format!(
             "t{}{}",
-            self.instrument.base.to_string().to_uppercase(),
-            self.instrument.quote.to_string().to_uppercase()
+            instrument.base.to_string().to_uppercase(),
+            instrument.quote.to_string().to_uppercase()
         ))
     }
+
+    /// Construct an "f" prefixed funding [`BitfinexMarket`] from the provided [`Instrument`]
+    /// base currency.
+    pub fn funding(instrument: &Instrument) -> Self {
+        Self(format!("f{}", instrument.base.to_string().to_uppercase()))
+    }
+
+    /// Determine if this [`BitfinexMarket`] is an "f" prefixed funding market.
+    pub fn is_funding(&self) -> bool {
+        self.0.starts_with('f')
+    }
+}
+
+impl Identifier<BitfinexMarket> for Subscription<Bitfinex, PublicTrades> {
+    fn id(&self) -> BitfinexMarket {
+        BitfinexMarket::trading(&self.instrument)
+    }
+}
+
+impl Identifier<BitfinexMarket> for Subscription<Bitfinex, OrderBooksL3> {
+    fn id(&self) -> BitfinexMarket {
+        BitfinexMarket::trading(&self.instrument)
+    }
+}
+
+impl Identifier<BitfinexMarket> for Subscription<Bitfinex, FundingTrades> {
+    fn id(&self) -> BitfinexMarket {
+        BitfinexMarket::funding(&self.instrument)
+    }
+}
+
+impl Identifier<BitfinexMarket> for Subscription<Bitfinex, FundingTickers> {
+    fn id(&self) -> BitfinexMarket {
+        BitfinexMarket::funding(&self.instrument)
+    }
 }
 
 impl AsRef<str> for BitfinexMarket {
@@ -24,3 +73,25 @@ impl AsRef<str> for BitfinexMarket {
         &self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    #[test]
+    fn test_bitfinex_market_prefix() {
+        let trades =
+            Subscription::from((Bitfinex, "btc", "usd", InstrumentKind::Spot, PublicTrades));
+        let funding =
+            Subscription::from((Bitfinex, "usd", "usd", InstrumentKind::Spot, FundingTrades));
+
+        let trades_market: BitfinexMarket = trades.id();
+        let funding_market: BitfinexMarket = funding.id();
+
+        assert_eq!(trades_market.as_ref(), "tBTCUSD");
+        assert!(!trades_market.is_funding());
+        assert_eq!(funding_market.as_ref(), "fUSD");
+        assert!(funding_market.is_funding());
+    }
+}
@@ -1,6 +1,12 @@
-use super::trade::BitfinexTrade;
+use super::{
+    conf::{extract_message_meta, BitfinexMessageMeta},
+    trade::BitfinexTrade,
+};
 use crate::{
-    event::MarketIter, exchange::ExchangeId, subscription::trade::PublicTrade, Identifier,
+    event::MarketIter,
+    exchange::ExchangeId,
+    subscription::trade::{PublicTrade, TradeSource},
+    Identifier,
 };
 use barter_integration::{
     de::extract_next,
@@ -15,6 +21,12 @@ use serde::Serialize;
 /// The message is associated with the original [`Subscription`](crate::Subscription) using the
 /// `channel_id` field as the [`SubscriptionId`](barter_integration::model::SubscriptionId).
 ///
+/// ### Notes
+/// Bitfinex publishes every trade twice: first as a "te" (trade executed) message, then as a
+/// "tu" (trade updated) message carrying the same trade id. Only "te" messages are converted
+/// into [`PublicTrade`]s since they arrive first, so "tu" messages are treated as heartbeats
+/// and each trade is emitted exactly once.
+///
 /// ### Raw Payload Examples
 /// #### Heartbeat
 /// See docs: <https://docs.bitfinex.com/docs/ws-general#heartbeating>
@@ -22,6 +34,12 @@ use serde::Serialize;
 /// [420191,"hb"]
 /// ```
 ///
+/// #### Initial Trades Snapshot
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-trades>
+/// ```json
+/// [420191,[[1225484398,1665452200022,0.08980641,19027.02807752],[1225484397,1665452199988,-0.0155,19027.0]]]
+/// ```
+///
 /// #### Side::Buy Trade
 /// See docs: <https://docs.bitfinex.com/reference/ws-public-trades>
 /// ```json
@@ -33,10 +51,17 @@ use serde::Serialize;
 /// ```json
 /// [420191,"te",[1225484398,1665452200022,-0.08980641,19027.02807752]]
 /// ```
-#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+///
+/// #### Trade With Conf Flags SEQ_ALL & TIMESTAMP
+/// See docs: <https://docs.bitfinex.com/docs/ws-general#configuration>
+/// ```json
+/// [420191,"te",[1225484398,1665452200022,0.08980641,19027.02807752],5,1665452200030]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
 pub struct BitfinexMessage {
     pub channel_id: u32,
     pub payload: BitfinexPayload,
+    pub meta: BitfinexMessageMeta,
 }
 
 /// [`Bitfinex`](super::Bitfinex) market data variants associated with an
@@ -45,26 +70,45 @@ pub struct BitfinexMessage {
 /// See [`BitfinexMessage`] for full raw payload examples.
 ///
 /// See docs: <https://docs.bitfinex.com/docs/ws-general>
-#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
 pub enum BitfinexPayload {
     Heartbeat,
     Trade(BitfinexTrade),
+    Snapshot(Vec<BitfinexTrade>),
 }
 
 impl Identifier<Option<SubscriptionId>> for BitfinexMessage {
     fn id(&self) -> Option<SubscriptionId> {
         match self.payload {
             BitfinexPayload::Heartbeat => None,
-            BitfinexPayload::Trade(_) => Some(SubscriptionId::from(self.channel_id.to_string())),
+            BitfinexPayload::Trade(_) | BitfinexPayload::Snapshot(_) => {
+                Some(SubscriptionId::from(self.channel_id.to_string()))
+            }
         }
     }
 }
 
+impl AsRef<BitfinexMessageMeta> for BitfinexMessage {
+    fn as_ref(&self) -> &BitfinexMessageMeta {
+        &self.meta
+    }
+}
+
 impl From<(ExchangeId, Instrument, BitfinexMessage)> for MarketIter<PublicTrade> {
     fn from((exchange_id, instrument, message): (ExchangeId, Instrument, BitfinexMessage)) -> Self {
         match message.payload {
             BitfinexPayload::Heartbeat => Self(vec![]),
             BitfinexPayload::Trade(trade) => Self::from((exchange_id, instrument, trade)),
+            BitfinexPayload::Snapshot(trades) => trades
+                .into_iter()
+                .flat_map(|trade| Self::from((exchange_id, instrument.clone(), trade)).0)
+                .map(|event| {
+                    event.map(|mut event| {
+                        event.kind.source = TradeSource::Historical;
+                        event
+                    })
+                })
+                .collect(),
         }
     }
 }
@@ -92,34 +136,45 @@ impl<'de> serde::Deserialize<'de> for BitfinexMessage {
             {
                 // Trade: [CHANNEL_ID, <"te", "tu">, [ID, TIME, AMOUNT, PRICE]]
                 // Heartbeat: [ CHANNEL_ID, "hb" ]
+                // Snapshot: [CHANNEL_ID, [[ID, TIME, AMOUNT, PRICE], ...]]
                 // Candle: [CHANNEL_ID, [MTS, OPEN, CLOSE, HIGH, LOW, VOLUME]]
 
                 // Extract CHANNEL_ID used to identify SubscriptionId: 1st element of the sequence
                 let channel_id: u32 = extract_next(&mut seq, "channel_id")?;
 
                 // Extract message tag to identify payload type: 2nd element of the sequence
-                let message_tag: String = extract_next(&mut seq, "message_tag")?;
+                // '--> initial trade snapshots have no message tag, only an array of trades
+                let message_tag = match extract_next(&mut seq, "message_tag")? {
+                    BitfinexTagOrSnapshot::Tag(message_tag) => message_tag,
+                    BitfinexTagOrSnapshot::Snapshot(trades) => {
+                        return Ok(BitfinexMessage {
+                            channel_id,
+                            payload: BitfinexPayload::Snapshot(trades),
+                            meta: extract_message_meta(&mut seq)?,
+                        });
+                    }
+                };
 
                 // Use message tag to extract the payload: 3rd element of sequence
-                let payload = match message_tag.as_str() {
+                let payload = match message_tag {
                     // Filter "tu" Trades since they are identical but slower
                     // '--> use as additional Heartbeat
-                    "hb" | "tu" => BitfinexPayload::Heartbeat,
-                    "te" => BitfinexPayload::Trade(extract_next(&mut seq, "BitfinexTrade")?),
-                    other => {
-                        return Err(serde::de::Error::unknown_variant(
-                            other,
-                            &["heartbeat (hb)", "trade (te | tu)"],
-                        ))
+                    BitfinexTag::Heartbeat => BitfinexPayload::Heartbeat,
+                    BitfinexTag::TradeUpdated => {
+                        let _: serde::de::IgnoredAny = extract_next(&mut seq, "BitfinexTrade")?;
+                        BitfinexPayload::Heartbeat
+                    }
+                    BitfinexTag::TradeExecuted => {
+                        BitfinexPayload::Trade(extract_next(&mut seq, "BitfinexTrade")?)
                     }
                 };
 
-                // Ignore any additional elements or SerDe will fail
-                //  '--> Bitfinex may add fields without warning
-                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+                // Extract any trailing conf flag SEQUENCE & MTS, ignoring additional elements
+                let meta = extract_message_meta(&mut seq)?;
                 Ok(BitfinexMessage {
                     channel_id,
                     payload,
+                    meta,
                 })
             }
         }
@@ -129,13 +184,92 @@ impl<'de> serde::Deserialize<'de> for BitfinexMessage {
     }
 }
 
+/// Second element of a [`BitfinexMessage`] sequence, either a message tag (eg/ "te") or an
+/// initial snapshot of recent trades.
+///
+/// Deserialised by a visitor rather than as an untagged enum, so the tag is compared without
+/// being copied into an owned `String`, and a snapshot is deserialised directly rather than
+/// first being buffered into an intermediate representation.
+enum BitfinexTagOrSnapshot {
+    Tag(BitfinexTag),
+    Snapshot(Vec<BitfinexTrade>),
+}
+
+/// [`BitfinexMessage`] tag identifying the payload type.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum BitfinexTag {
+    /// "hb"
+    Heartbeat,
+    /// "te"
+    TradeExecuted,
+    /// "tu"
+    TradeUpdated,
+}
+
+impl<'de> serde::Deserialize<'de> for BitfinexTagOrSnapshot {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct TagOrSnapshotVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for TagOrSnapshotVisitor {
+            type Value = BitfinexTagOrSnapshot;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("Bitfinex message tag or initial trades snapshot")
+            }
+
+            fn visit_str<E>(self, tag: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match tag {
+                    "hb" => Ok(BitfinexTagOrSnapshot::Tag(BitfinexTag::Heartbeat)),
+                    "te" => Ok(BitfinexTagOrSnapshot::Tag(BitfinexTag::TradeExecuted)),
+                    "tu" => Ok(BitfinexTagOrSnapshot::Tag(BitfinexTag::TradeUpdated)),
+                    other => Err(E::unknown_variant(
+                        other,
+                        &["heartbeat (hb)", "trade (te | tu)"],
+                    )),
+                }
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                let mut trades = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+                while let Some(trade) = seq.next_element::<BitfinexTrade>()? {
+                    trades.push(trade);
+                }
+                Ok(BitfinexTagOrSnapshot::Snapshot(trades))
+            }
+        }
+
+        deserializer.deserialize_any(TagOrSnapshotVisitor)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        exchange::bitfinex::Bitfinex,
+        subscription::{trade::PublicTrades, Map},
+        transformer::{stateless::StatelessTransformer, ExchangeTransformer},
+    };
     use barter_integration::{
-        de::datetime_utc_from_epoch_duration, error::SocketError, model::Side,
+        de::datetime_utc_from_epoch_duration,
+        error::SocketError,
+        model::{instrument::kind::InstrumentKind, Side},
+        Transformer,
     };
     use std::time::Duration;
+    use tokio::sync::mpsc;
 
     #[test]
     fn test_de_bitfinex_message() {
@@ -163,6 +297,7 @@ mod tests {
                         price: 19027.02807752,
                         amount: 0.08980641,
                     }),
+                    meta: BitfinexMessageMeta::default(),
                 }),
             },
             // TC1: Trade message te Buy
@@ -179,6 +314,7 @@ mod tests {
                         price: 19027.02807752,
                         amount: 0.08980641,
                     }),
+                    meta: BitfinexMessageMeta::default(),
                 }),
             },
             // TC2: Trade tu --> Should be marked as a heartbeat
@@ -187,6 +323,7 @@ mod tests {
                 expected: Ok(BitfinexMessage {
                     channel_id: 420191,
                     payload: BitfinexPayload::Heartbeat,
+                    meta: BitfinexMessageMeta::default(),
                 }),
             },
             // TC3: Heartbeat message
@@ -195,6 +332,110 @@ mod tests {
                 expected: Ok(BitfinexMessage {
                     channel_id: 420191,
                     payload: BitfinexPayload::Heartbeat,
+                    meta: BitfinexMessageMeta::default(),
+                }),
+            },
+            // TC4: Initial trades snapshot
+            TestCase {
+                input: r#"[420191,[[1225484398,1665452200022,0.08980641,19027.02807752],[1225484397,1665452199988,-0.0155,19027.0]]]"#,
+                expected: Ok(BitfinexMessage {
+                    channel_id: 420191,
+                    payload: BitfinexPayload::Snapshot(vec![
+                        BitfinexTrade {
+                            id: 1225484398,
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1665452200022,
+                            )),
+                            side: Side::Buy,
+                            price: 19027.02807752,
+                            amount: 0.08980641,
+                        },
+                        BitfinexTrade {
+                            id: 1225484397,
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1665452199988,
+                            )),
+                            side: Side::Sell,
+                            price: 19027.0,
+                            amount: 0.0155,
+                        },
+                    ]),
+                    meta: BitfinexMessageMeta::default(),
+                }),
+            },
+            // TC5: Trade message te w/ conf flags SEQ_ALL & TIMESTAMP
+            TestCase {
+                input: r#"[420191,"te",[1225484398,1665452200022,0.08980641,19027.02807752],5,1665452200030]"#,
+                expected: Ok(BitfinexMessage {
+                    channel_id: 420191,
+                    payload: BitfinexPayload::Trade(BitfinexTrade {
+                        id: 1225484398,
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1665452200022,
+                        )),
+                        side: Side::Buy,
+                        price: 19027.02807752,
+                        amount: 0.08980641,
+                    }),
+                    meta: BitfinexMessageMeta {
+                        sequence: Some(5),
+                        time: Some(datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1665452200030,
+                        ))),
+                    },
+                }),
+            },
+            // TC6: Trade tu w/ conf flags --> Should be marked as a sequenced heartbeat
+            TestCase {
+                input: r#"[420191,"tu",[1225484398,1665452200022,0.08980641,19027.02807752],6,1665452200031]"#,
+                expected: Ok(BitfinexMessage {
+                    channel_id: 420191,
+                    payload: BitfinexPayload::Heartbeat,
+                    meta: BitfinexMessageMeta {
+                        sequence: Some(6),
+                        time: Some(datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1665452200031,
+                        ))),
+                    },
+                }),
+            },
+            // TC7: Heartbeat message w/ conf flags
+            TestCase {
+                input: r#"[420191,"hb",7,1665452200032]"#,
+                expected: Ok(BitfinexMessage {
+                    channel_id: 420191,
+                    payload: BitfinexPayload::Heartbeat,
+                    meta: BitfinexMessageMeta {
+                        sequence: Some(7),
+                        time: Some(datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1665452200032,
+                        ))),
+                    },
+                }),
+            },
+            // TC8: Trade message w/ an escaped te tag
+            TestCase {
+                input: r#"[420191,"t\u0065",[1225484398,1665452200022,0.08980641,19027.02807752]]"#,
+                expected: Ok(BitfinexMessage {
+                    channel_id: 420191,
+                    payload: BitfinexPayload::Trade(BitfinexTrade {
+                        id: 1225484398,
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1665452200022,
+                        )),
+                        side: Side::Buy,
+                        price: 19027.02807752,
+                        amount: 0.08980641,
+                    }),
+                    meta: BitfinexMessageMeta::default(),
+                }),
+            },
+            // TC9: Unknown message tag
+            TestCase {
+                input: r#"[420191,"xx",[1225484398,1665452200022,0.08980641,19027.02807752]]"#,
+                expected: Err(SocketError::Unsupported {
+                    entity: "Bitfinex",
+                    item: "xx".to_owned(),
                 }),
             },
         ];
@@ -215,4 +456,59 @@ mod tests {
             }
         }
     }
+
+    #[tokio::test]
+    async fn test_bitfinex_te_tu_pair_emits_single_trade() {
+        let instrument = Instrument::from(("btc", "usd", InstrumentKind::Spot));
+        let instrument_map = Map::from_iter([(SubscriptionId::from("420191"), instrument.clone())]);
+
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+        let mut transformer = <StatelessTransformer<Bitfinex, PublicTrades, BitfinexMessage> as ExchangeTransformer<Bitfinex, PublicTrades>>::new(ws_sink_tx, instrument_map)
+        .await
+        .unwrap();
+
+        let actual = [
+            r#"[420191,"te",[1225484398,1665452200022,-0.08980641,19027.02807752]]"#,
+            r#"[420191,"tu",[1225484398,1665452200022,-0.08980641,19027.02807752]]"#,
+        ]
+        .into_iter()
+        .flat_map(|input| {
+            transformer.transform(serde_json::from_str::<BitfinexMessage>(input).unwrap())
+        })
+        .map(|event| event.unwrap())
+        .collect::<Vec<_>>();
+
+        assert_eq!(actual.len(), 1);
+        assert_eq!(actual[0].instrument, instrument);
+        assert_eq!(actual[0].kind.id, "1225484398");
+        assert_eq!(actual[0].kind.side, Side::Sell);
+    }
+
+    #[test]
+    fn test_bitfinex_snapshot_to_historical_trades() {
+        let message = serde_json::from_str::<BitfinexMessage>(
+            r#"[420191,[[1225484398,1665452200022,0.08980641,19027.02807752],[1225484397,1665452199988,-0.0155,19027.0]]]"#,
+        )
+        .unwrap();
+
+        let live = serde_json::from_str::<BitfinexMessage>(
+            r#"[420191,"te",[1225484399,1665452200030,0.5,19028.0]]"#,
+        )
+        .unwrap();
+
+        let instrument = Instrument::from(("btc", "usd", InstrumentKind::Spot));
+
+        let historical =
+            MarketIter::<PublicTrade>::from((ExchangeId::Bitfinex, instrument.clone(), message)).0;
+        assert_eq!(historical.len(), 2);
+        for event in historical {
+            assert_eq!(event.unwrap().kind.source, TradeSource::Historical);
+        }
+
+        let live = MarketIter::<PublicTrade>::from((ExchangeId::Bitfinex, instrument, live)).0;
+        assert_eq!(live.len(), 1);
+        let live = live.into_iter().next().unwrap().unwrap();
+        assert_eq!(live.kind.id, "1225484399");
+        assert_eq!(live.kind.source, TradeSource::Live);
+    }
 }
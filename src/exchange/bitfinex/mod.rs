@@ -18,16 +18,47 @@
 //! - Bitfinex trades subscriptions results in receiving tag="te" & tag="tu" trades.
 //! - Both appear to be identical payloads, but "te" arriving marginally faster.
 //! - Therefore, tag="tu" trades are filtered out and considered only as additional Heartbeats.
+//! - An initial snapshot of recent trades is sent after each subscription success. These are
+//!   emitted as [`TradeSource::Historical`](crate::subscription::trade::TradeSource) trades.
+//!
+//! #### Funding Markets
+//! - Funding markets are "f" prefixed (eg/ "fUSD"), compared to "t" prefixed trading pairs.
+//! - Funding trades are received with tag="fte" & tag="ftu", where tag="ftu" trades are filtered
+//!   out in the same way as trading pair trades.
+//!
+//! #### Raw OrderBook
+//! - [`OrderBooksL3`] subscriptions use the "book" channel with precision "R0", which provides
+//!   individual orders rather than orders aggregated by price.
+//! - Orders are tracked by order id, and an order with a price of 0 indicates it was removed.
+//!
+//! #### Conf Flags
+//! - Every connection sends a "conf" request enabling the `TIMESTAMP` & `SEQ_ALL` flags before
+//!   subscribing, appending a message timestamp & connection-level sequence number to every
+//!   message.
+//! - Message timestamps are used as the `exchange_time` of payloads without their own timestamp
+//!   (eg/ raw orders & funding tickers), and a gap in the sequence numbers is a terminal
+//!   [`DataError::InvalidSequence`].
 
 use self::{
-    channel::BitfinexChannel, market::BitfinexMarket, message::BitfinexMessage,
-    subscription::BitfinexPlatformEvent, validator::BitfinexWebSocketSubValidator,
+    book::l3::BitfinexBookUpdaterL3,
+    channel::BitfinexChannel,
+    conf::{conf_request, BitfinexSequencer, CONF_FLAGS_BITFINEX},
+    funding::BitfinexFundingMessage,
+    market::BitfinexMarket,
+    message::BitfinexMessage,
+    subscription::{BitfinexInfo, BitfinexPlatformEvent},
+    validator::BitfinexWebSocketSubValidator,
 };
 use crate::{
+    error::DataError,
     exchange::{Connector, ExchangeId, ExchangeSub, StreamSelector},
     subscriber::WebSocketSubscriber,
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
+    subscription::{
+        book::OrderBooksL3,
+        funding::{FundingTickers, FundingTrades},
+        trade::PublicTrades,
+    },
+    transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
     ExchangeWsStream,
 };
 use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
@@ -35,10 +66,21 @@ use barter_macro::{DeExchange, SerExchange};
 use serde_json::json;
 use url::Url;
 
+/// OrderBook types for [`Bitfinex`].
+pub mod book;
+
+/// "conf" flags enabled on every [`Bitfinex`] connection, and the
+/// [`BitfinexSequencer`](conf::BitfinexSequencer) validating the message sequence numbers they
+/// add.
+pub mod conf;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
 
+/// Funding market trade & ticker types for [`Bitfinex`].
+pub mod funding;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
@@ -62,6 +104,20 @@ pub mod validator;
 /// See docs: <https://docs.bitfinex.com/docs/ws-general>
 pub const BASE_URL_BITFINEX: &str = "wss://api-pub.bitfinex.com/ws/2";
 
+/// [`Bitfinex`] number of orders per side requested for a raw [`OrderBooksL3`] subscription.
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+pub const ORDER_BOOK_L3_LEN_BITFINEX: &str = "250";
+
+/// [`Bitfinex`] [`BitfinexInfo`] event codes communicating the server is restarting or entering
+/// maintenance.
+///
+/// - 20051: Stop/Restart WebSocket Server (please reconnect)
+/// - 20060: Entering in Maintenance mode
+///
+/// See docs: <https://docs.bitfinex.com/docs/ws-general#info-messages>
+pub const MAINTENANCE_CODES_BITFINEX: &[u32] = &[20051, 20060];
+
 /// [`Bitfinex`] exchange.
 ///
 /// See docs: <https://docs.bitfinex.com/docs/ws-general>
@@ -82,23 +138,62 @@ impl Connector for Bitfinex {
         Url::parse(BASE_URL_BITFINEX).map_err(SocketError::UrlParse)
     }
 
+    fn connection_requests() -> Vec<WsMessage> {
+        vec![conf_request(CONF_FLAGS_BITFINEX)]
+    }
+
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
         exchange_subs
             .into_iter()
             .map(|ExchangeSub { channel, market }| {
-                WsMessage::Text(
-                    json!({
+                let request = match channel {
+                    BitfinexChannel::ORDER_BOOK_L3 => json!({
                         "event": "subscribe",
                         "channel": channel.as_ref(),
                         "symbol": market.as_ref(),
-                    })
-                    .to_string(),
-                )
+                        "prec": "R0",
+                        "len": ORDER_BOOK_L3_LEN_BITFINEX,
+                    }),
+                    _ => json!({
+                        "event": "subscribe",
+                        "channel": channel.as_ref(),
+                        "symbol": market.as_ref(),
+                    }),
+                };
+
+                WsMessage::Text(request.to_string())
             })
             .collect()
     }
+
+    fn is_maintenance(error: &DataError) -> bool {
+        error.is_maintenance()
+            || error.unexpected_payload::<BitfinexInfo>().is_some_and(
+                |BitfinexInfo::Info { code, .. }| MAINTENANCE_CODES_BITFINEX.contains(&code),
+            )
+    }
 }
 
 impl StreamSelector<PublicTrades> for Bitfinex {
-    type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, BitfinexMessage>>;
+    type Stream = ExchangeWsStream<
+        BitfinexSequencer<StatelessTransformer<Self, PublicTrades, BitfinexMessage>>,
+    >;
+}
+
+impl StreamSelector<OrderBooksL3> for Bitfinex {
+    type Stream = ExchangeWsStream<
+        BitfinexSequencer<MultiBookTransformer<Self, OrderBooksL3, BitfinexBookUpdaterL3>>,
+    >;
+}
+
+impl StreamSelector<FundingTrades> for Bitfinex {
+    type Stream = ExchangeWsStream<
+        BitfinexSequencer<StatelessTransformer<Self, FundingTrades, BitfinexFundingMessage>>,
+    >;
+}
+
+impl StreamSelector<FundingTickers> for Bitfinex {
+    type Stream = ExchangeWsStream<
+        BitfinexSequencer<StatelessTransformer<Self, FundingTickers, BitfinexFundingMessage>>,
+    >;
 }
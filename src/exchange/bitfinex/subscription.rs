@@ -1,4 +1,5 @@
 use barter_integration::{error::SocketError, Validator};
+use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 
 /// [`Bitfinex`](super::Bitfinex) platform event detailing the variants expected to be received
@@ -41,6 +42,7 @@ pub enum BitfinexPlatformEvent {
     #[serde(rename = "info")]
     PlatformStatus(BitfinexPlatformStatus),
     Subscribed(BitfinexSubResponse),
+    Conf(BitfinexConf),
     Error(BitfinexError),
 }
 
@@ -52,12 +54,22 @@ impl Validator for BitfinexPlatformEvent {
         match &self {
             BitfinexPlatformEvent::PlatformStatus(status) => match status.status {
                 Status::Operative => Ok(self),
-                Status::Maintenance => Err(SocketError::Subscribe(format!(
-                    "exchange version: {} with server_id: {} is in maintenance mode",
-                    status.api_version, status.server_id,
-                ))),
+                // Surfaced as the HTTP 503 Service Unavailable Bitfinex responds with while in
+                // maintenance, so it is recognised by DataError::is_maintenance
+                Status::Maintenance => Err(SocketError::HttpResponse(
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    format!(
+                        "exchange version: {} with server_id: {} is in maintenance mode",
+                        status.api_version, status.server_id,
+                    ),
+                )),
             },
             BitfinexPlatformEvent::Subscribed(_) => Ok(self),
+            BitfinexPlatformEvent::Conf(conf) if conf.status == BITFINEX_CONF_STATUS_OK => Ok(self),
+            BitfinexPlatformEvent::Conf(conf) => Err(SocketError::Subscribe(format!(
+                "received failure conf response status: {} for flags: {}",
+                conf.status, conf.flags,
+            ))),
             BitfinexPlatformEvent::Error(error) => Err(SocketError::Subscribe(format!(
                 "received failure subscription response code: {} with message: {}",
                 error.code, error.msg,
@@ -146,6 +158,27 @@ pub struct BitfinexSubResponse {
     pub channel_id: BitfinexChannelId,
 }
 
+/// [`Bitfinex`](super::Bitfinex) "conf" response status of successfully enabled flags.
+pub const BITFINEX_CONF_STATUS_OK: &str = "OK";
+
+/// [`Bitfinex`](super::Bitfinex) response to a "conf" request enabling connection flags (see
+/// [`CONF_FLAGS_BITFINEX`](super::conf::CONF_FLAGS_BITFINEX)).
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.bitfinex.com/docs/ws-general#configuration>
+/// ``` json
+/// {
+///   "event": "conf",
+///   "status": "OK",
+///   "flags": 98304
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BitfinexConf {
+    pub status: String,
+    pub flags: u64,
+}
+
 /// [`Bitfinex`](super::Bitfinex) channel identifier that is used to identify the subscription
 /// associated with incoming events. See the module level "SubscriptionId" documentation notes
 /// for more details.
@@ -171,6 +204,29 @@ pub struct BitfinexError {
     code: u32,
 }
 
+/// [`Bitfinex`](super::Bitfinex) info event communicating a change in server state via a code
+/// (eg/ [`MAINTENANCE_CODES_BITFINEX`](super::MAINTENANCE_CODES_BITFINEX)), which may be received
+/// at any time after subscribing.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.bitfinex.com/docs/ws-general#info-messages>
+/// ``` json
+/// {
+///   "event": "info",
+///   "code": 20051,
+///   "msg": "Stopping. Please try to reconnect"
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(tag = "event", rename_all = "lowercase")]
+pub enum BitfinexInfo {
+    Info {
+        code: u32,
+        #[serde(default)]
+        msg: String,
+    },
+}
+
 impl<'de> Deserialize<'de> for Status {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -293,10 +349,13 @@ mod tests {
                     server_id: "server_id".to_string(),
                     status: Status::Maintenance,
                 }),
-                expected: Err(SocketError::Subscribe(format!(
-                    "exchange version: {} with server_id: {} is in maintenance mode",
-                    2, "server_id",
-                ))),
+                expected: Err(SocketError::HttpResponse(
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    format!(
+                        "exchange version: {} with server_id: {} is in maintenance mode",
+                        2, "server_id",
+                    ),
+                )),
             },
             TestCase {
                 // TC1: bitfinex server is online
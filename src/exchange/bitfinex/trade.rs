@@ -1,10 +1,10 @@
 use crate::{
     event::{MarketEvent, MarketIter},
-    exchange::ExchangeId,
-    subscription::trade::PublicTrade,
+    exchange::{timestamp::EpochUnit, ExchangeId},
+    subscription::trade::{PublicTrade, SideSource, TradeOrderIds, TradeSource},
 };
 use barter_integration::{
-    de::{datetime_utc_from_epoch_duration, extract_next},
+    de::extract_next,
     model::{instrument::Instrument, Exchange, Side},
 };
 use chrono::{DateTime, Utc};
@@ -31,7 +31,7 @@ use serde::Serialize;
 ///
 /// ## Notes:
 /// - [`Bitfinex`](super::Bitfinex) trades subscriptions results in receiving tag="te" & tag="tu"
-/// trades, both of which are identical.
+///   trades, both of which are identical.
 /// - "te" trades arrive marginally faster.
 /// - Therefore, tag="tu" trades are filtered out and considered only as additional Heartbeats.
 ///
@@ -50,6 +50,7 @@ impl From<(ExchangeId, Instrument, BitfinexTrade)> for MarketIter<PublicTrade> {
         Self(vec![Ok(MarketEvent {
             exchange_time: trade.time,
             received_time: Utc::now(),
+            exchange_time_synthesized: false,
             exchange: Exchange::from(exchange_id),
             instrument,
             kind: PublicTrade {
@@ -57,6 +58,10 @@ impl From<(ExchangeId, Instrument, BitfinexTrade)> for MarketIter<PublicTrade> {
                 price: trade.price,
                 amount: trade.amount,
                 side: trade.side,
+                source: TradeSource::Live,
+                side_source: SideSource::Exchange,
+                order_ids: TradeOrderIds::default(),
+                aggregate_count: None,
             },
         })])
     }
@@ -85,7 +90,7 @@ impl<'de> serde::Deserialize<'de> for BitfinexTrade {
             {
                 // Trade: [ID, TIME, AMOUNT,PRICE]
                 let id = extract_next(&mut seq, "id")?;
-                let time_millis = extract_next(&mut seq, "time")?;
+                let time: u64 = extract_next(&mut seq, "time")?;
                 let amount: f64 = extract_next(&mut seq, "amount")?;
                 let price = extract_next(&mut seq, "price")?;
                 let side = match amount.is_sign_positive() {
@@ -99,9 +104,10 @@ impl<'de> serde::Deserialize<'de> for BitfinexTrade {
 
                 Ok(BitfinexTrade {
                     id,
-                    time: datetime_utc_from_epoch_duration(std::time::Duration::from_millis(
-                        time_millis,
-                    )),
+                    // MTS is epoch milliseconds
+                    time: EpochUnit::Milliseconds
+                        .datetime_utc(time)
+                        .map_err(serde::de::Error::custom)?,
                     price,
                     amount: amount.abs(),
                     side,
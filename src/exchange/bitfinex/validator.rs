@@ -1,7 +1,7 @@
 use super::subscription::{BitfinexPlatformEvent, BitfinexSubResponse};
 use crate::{
     exchange::{Connector, ExchangeSub},
-    subscriber::validator::SubscriptionValidator,
+    subscriber::validator::{buffer_frame, SubscriptionValidator},
     subscription::{Map, SubKind},
     Identifier,
 };
@@ -10,7 +10,7 @@ use barter_integration::{
     error::SocketError,
     model::{instrument::Instrument, SubscriptionId},
     protocol::{
-        websocket::{WebSocket, WebSocketParser},
+        websocket::{WebSocketParser, WsMessage, WsStream},
         StreamParser,
     },
     Validator,
@@ -39,8 +39,9 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
 
     async fn validate<Exchange, Kind>(
         mut map: Map<Instrument>,
-        websocket: &mut WebSocket,
-    ) -> Result<Map<Instrument>, SocketError>
+        _: &[u64],
+        websocket: &mut WsStream,
+    ) -> Result<(Map<Instrument>, Vec<WsMessage>), SocketError>
     where
         Exchange: Connector + Send,
         Kind: SubKind + Send,
@@ -50,17 +51,22 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
         let expected_responses = Exchange::expected_responses(&map);
 
         // Parameter to keep track of successful Subscription outcomes
-        // '--> Bitfinex sends snapshots as the first message, so count them also
+        // '--> Bitfinex sends an initial trades snapshot after each subscription success, these
+        //      are left on the WebSocket to be emitted as historical trades
         let mut success_responses = 0usize;
-        let mut init_snapshots_received = 0usize;
+
+        // Data frames (eg/ initial snapshots) received before every Subscription is validated
+        let mut buffered = Vec::new();
 
         loop {
             // Break if all Subscriptions were a success
-            if success_responses == expected_responses
-                && init_snapshots_received == expected_responses
-            {
-                debug!(exchange = %Exchange::ID, "validated exchange WebSocket subscriptions");
-                break Ok(map);
+            if success_responses == expected_responses {
+                debug!(
+                    exchange = %Exchange::ID,
+                    buffered = buffered.len(),
+                    "validated exchange WebSocket subscriptions"
+                );
+                break Ok((map, buffered));
             }
 
             tokio::select! {
@@ -77,6 +83,12 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
                         None => break Err(SocketError::Subscribe("WebSocket stream terminated unexpectedly".to_string()))
                     };
 
+                    // Retain data frames, which are buffered if they are not a platform event
+                    let frame = match &response {
+                        Ok(frame @ (WsMessage::Text(_) | WsMessage::Binary(_))) => Some(frame.clone()),
+                        _ => None,
+                    };
+
                     match Self::Parser::parse::<BitfinexPlatformEvent>(response) {
                         Some(Ok(response)) => match response.validate() {
                             // Bitfinex server is online
@@ -90,6 +102,17 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
                                 );
                             }
 
+                            // Conf flags enabled
+                            Ok(BitfinexPlatformEvent::Conf(conf)) => {
+                                debug!(
+                                    exchange = %Exchange::ID,
+                                    %success_responses,
+                                    %expected_responses,
+                                    payload = ?conf,
+                                    "received Bitfinex conf flags response",
+                                );
+                            }
+
                             // Subscription success
                             Ok(BitfinexPlatformEvent::Subscribed(response)) => {
                                 // Determine SubscriptionId associated with the success response
@@ -117,17 +140,20 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
                             // Not reachable after BitfinexPlatformEvent validate()
                             Ok(BitfinexPlatformEvent::Error(error)) => panic!("{error:?}"),
                         }
-                        Some(Err(SocketError::Deserialise { error, payload })) if success_responses >= 1 => {
-                            // Already active Bitfinex subscriptions will send initial snapshots
-                            init_snapshots_received += 1;
+                        Some(Err(SocketError::Deserialise { error, payload })) => {
+                            // Initial snapshots of already active subscriptions that arrive before
+                            // all subscriptions are validated cannot be routed yet, so buffer them
                             debug!(
                                 exchange = %Exchange::ID,
                                 ?error,
                                 %success_responses,
                                 %expected_responses,
                                 %payload,
-                                "failed to deserialise non SubResponse payload"
+                                "buffering non SubResponse payload received during validation"
                             );
+                            if let Some(frame) = frame {
+                                buffer_frame(Exchange::ID, &mut buffered, frame);
+                            }
                             continue
                         }
                         Some(Err(SocketError::Terminated(close_frame))) => {
@@ -25,6 +25,12 @@ impl Identifier<BitmexChannel> for Subscription<Bitmex, PublicTrades> {
     }
 }
 
+impl From<&'static str> for BitmexChannel {
+    fn from(channel: &'static str) -> Self {
+        Self(channel)
+    }
+}
+
 impl AsRef<str> for BitmexChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -72,8 +72,8 @@ impl Connector for Bitmex {
         )]
     }
 
-    fn expected_responses(_: &Map<Instrument>) -> usize {
-        1
+    fn expected_responses(map: &Map<Instrument>) -> usize {
+        Self::num_batched_requests(map.0.len())
     }
 }
 
@@ -90,7 +90,7 @@ impl<'de> serde::Deserialize<'de> for Bitmex {
         let expected = Self::ID.as_str();
 
         if input == Self::ID.as_str() {
-            Ok(Self::default())
+            Ok(Self)
         } else {
             Err(Error::invalid_value(Unexpected::Str(input), &expected))
         }
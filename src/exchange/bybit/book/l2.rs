@@ -0,0 +1,377 @@
+use super::BybitLevel;
+use crate::{
+    error::DataError,
+    exchange::bybit::{message::BybitPayload, subscription::BybitResponse},
+    subscription::book::{Level, OrderBook, OrderBookSide},
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// [`Bybit`](super::super::Bybit) OrderBook Level2 "snapshot" message type.
+pub const BYBIT_BOOK_TYPE_SNAPSHOT: &str = "snapshot";
+
+/// Terse type alias for a [`Bybit`](super::super::Bybit) OrderBook Level2 snapshot or delta
+/// WebSocket message.
+pub type BybitOrderBookL2 = BybitPayload<BybitOrderBookL2Data>;
+
+/// [`Bybit`](super::super::Bybit) OrderBook Level2 WebSocket message, which may also be a
+/// [`BybitResponse`] (eg/ pong) received over the same connection.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BybitOrderBookL2Message {
+    Response(BybitResponse),
+    Book(BybitOrderBookL2),
+}
+
+/// [`Bybit`](super::super::Bybit) OrderBook Level2 snapshot or delta data.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+/// ```json
+/// {
+///     "topic": "orderbook.50.BTCUSDT",
+///     "type": "snapshot",
+///     "ts": 1672304484978,
+///     "data": {
+///         "s": "BTCUSDT",
+///         "b": [["16493.50", "0.006"], ["16493.00", "0.100"]],
+///         "a": [["16611.00", "0.029"], ["16612.00", "0.213"]],
+///         "u": 18521288,
+///         "seq": 7961638724
+///     },
+///     "cts": 1672304484976
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitOrderBookL2Data {
+    #[serde(rename = "s")]
+    pub market: String,
+    #[serde(rename = "b", default)]
+    pub bids: Vec<BybitLevel>,
+    #[serde(rename = "a", default)]
+    pub asks: Vec<BybitLevel>,
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    #[serde(rename = "seq")]
+    pub sequence: u64,
+}
+
+impl Identifier<Option<SubscriptionId>> for BybitOrderBookL2Message {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            Self::Book(book) => Some(book.subscription_id.clone()),
+            Self::Response(_) => None,
+        }
+    }
+}
+
+/// [`Bybit`](super::super::Bybit) [`OrderBookUpdater`] for the "orderbook.50" channel.
+///
+/// Bybit: How To Maintain A Local OrderBook
+///
+/// 1. Subscribe to the "orderbook.50.{symbol}" channel, after which a "snapshot" is received.
+/// 2. Any "snapshot" (eg/ re-sent by Bybit after a service restart) replaces the local OrderBook.
+/// 3. Drop any "delta" received before the first "snapshot".
+/// 4. Drop any "delta" with a "u" or "seq" not greater than the last applied, since it was
+///    generated earlier than the local OrderBook.
+/// 5. Each "delta" "u" should be equal to the previous "u" + 1, otherwise there is a gap & the
+///    process must re-initialise from step 1.
+/// 6. The data in each "delta" is the absolute quantity for a price level.
+/// 7. If the quantity is 0, remove the price level.
+///
+/// Notes:
+///  - "u" => update_id
+///  - "seq" => cross sequence, comparable across OrderBook depths of the same market.
+///  - A detected gap yields a terminal [`DataError::InvalidSequence`], which causes the
+///    [`MarketStream`](crate::MarketStream) to be re-initialised & re-subscribed, resyncing the
+///    OrderBook from a fresh "snapshot".
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct BybitBookUpdater {
+    pub updates_processed: u64,
+    pub last_update_id: u64,
+    pub last_sequence: u64,
+}
+
+impl BybitBookUpdater {
+    /// Determines if a "snapshot" has been applied, after which "delta"s can be applied.
+    pub fn is_initialised(&self) -> bool {
+        self.updates_processed > 0
+    }
+
+    /// Bybit: How To Maintain A Local OrderBook: Step 5:
+    /// "Each delta u should be equal to the previous u + 1"
+    pub fn validate_next_update(&self, update: &BybitOrderBookL2Data) -> Result<(), DataError> {
+        if update.update_id == self.last_update_id + 1 {
+            Ok(())
+        } else {
+            Err(DataError::InvalidSequence {
+                prev_last_update_id: self.last_update_id,
+                first_update_id: update.update_id,
+            })
+        }
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for BybitBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = BybitOrderBookL2Message;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Initial OrderBook snapshot is received over the WebSocket after subscribing
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::default(),
+            book: OrderBook {
+                last_update_time: DateTime::default(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            },
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<Self::OrderBook>, DataError> {
+        // Bybit: How To Maintain A Local OrderBook
+        // See Self's Rust Docs for more information on each numbered step
+        let BybitOrderBookL2Message::Book(update) = update else {
+            return Ok(None);
+        };
+
+        if update.r#type == BYBIT_BOOK_TYPE_SNAPSHOT {
+            // 2. Any snapshot replaces the local OrderBook:
+            book.bids = OrderBookSide::new(Side::Buy, update.data.bids);
+            book.asks = OrderBookSide::new(Side::Sell, update.data.asks);
+        } else {
+            // 3. Drop any delta received before the first snapshot:
+            if !self.is_initialised() {
+                return Ok(None);
+            }
+
+            // 4. Drop any delta generated earlier than the local OrderBook:
+            if update.data.update_id <= self.last_update_id
+                || update.data.sequence <= self.last_sequence
+            {
+                return Ok(None);
+            }
+
+            // 5. Each delta u should be equal to the previous u + 1:
+            self.validate_next_update(&update.data)?;
+
+            // 6. The data in each delta is the absolute quantity for a price level.
+            // 7. If the quantity is 0, remove the price level.
+            book.bids.upsert(update.data.bids);
+            book.asks.upsert(update.data.asks);
+        }
+
+        // Update OrderBook & OrderBookUpdater metadata
+        book.last_update_time = update.time;
+        self.updates_processed += 1;
+        self.last_update_id = update.data.update_id;
+        self.last_sequence = update.data.sequence;
+
+        Ok(Some(book.snapshot()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_bybit_order_book_l2() {
+            let input = r#"
+            {
+                "topic": "orderbook.50.BTCUSDT",
+                "type": "snapshot",
+                "ts": 1672304484978,
+                "data": {
+                    "s": "BTCUSDT",
+                    "b": [["16493.50", "0.006"], ["16493.00", "0.100"]],
+                    "a": [["16611.00", "0.029"]],
+                    "u": 18521288,
+                    "seq": 7961638724
+                },
+                "cts": 1672304484976
+            }
+            "#;
+
+            let BybitOrderBookL2Message::Book(actual) =
+                serde_json::from_str::<BybitOrderBookL2Message>(input).unwrap()
+            else {
+                panic!("expected BybitOrderBookL2Message::Book");
+            };
+
+            assert_eq!(
+                actual.subscription_id,
+                SubscriptionId::from("orderbook.50|BTCUSDT")
+            );
+            assert_eq!(actual.r#type, BYBIT_BOOK_TYPE_SNAPSHOT);
+            assert_eq!(
+                actual.data,
+                BybitOrderBookL2Data {
+                    market: "BTCUSDT".to_string(),
+                    bids: vec![
+                        BybitLevel {
+                            price: 16493.5,
+                            amount: 0.006
+                        },
+                        BybitLevel {
+                            price: 16493.0,
+                            amount: 0.1
+                        },
+                    ],
+                    asks: vec![BybitLevel {
+                        price: 16611.0,
+                        amount: 0.029
+                    }],
+                    update_id: 18521288,
+                    sequence: 7961638724,
+                }
+            );
+        }
+    }
+
+    mod bybit_book_updater {
+        use super::*;
+
+        fn message(r#type: &str, update_id: u64, sequence: u64, bids: &str, asks: &str) -> String {
+            format!(
+                r#"{{
+                    "topic": "orderbook.50.BTCUSDT",
+                    "type": "{type}",
+                    "ts": 1672304484978,
+                    "data": {{"s": "BTCUSDT", "b": {bids}, "a": {asks}, "u": {update_id}, "seq": {sequence}}},
+                    "cts": 1672304484976
+                }}"#
+            )
+        }
+
+        fn book() -> OrderBook {
+            OrderBook {
+                last_update_time: Default::default(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            }
+        }
+
+        fn apply(
+            updater: &mut BybitBookUpdater,
+            book: &mut OrderBook,
+            input: String,
+        ) -> Result<Option<OrderBook>, DataError> {
+            updater.update(book, serde_json::from_str(&input).unwrap())
+        }
+
+        #[test]
+        fn test_update_replays_snapshot_and_deltas() {
+            let mut updater = BybitBookUpdater::default();
+            let mut book = book();
+
+            // Delta received before the first snapshot is dropped
+            let early = message("delta", 9, 99, r#"[["1.0", "1"]]"#, "[]");
+            assert_eq!(apply(&mut updater, &mut book, early).unwrap(), None);
+
+            let snapshot = message(
+                "snapshot",
+                10,
+                100,
+                r#"[["100.0", "1"], ["99.0", "2"]]"#,
+                r#"[["101.0", "1"], ["102.0", "2"]]"#,
+            );
+            apply(&mut updater, &mut book, snapshot).unwrap().unwrap();
+
+            // Delta removes a zero size bid, updates an ask & inserts a new bid
+            let delta = message(
+                "delta",
+                11,
+                105,
+                r#"[["99.0", "0"], ["98.5", "3"]]"#,
+                r#"[["101.0", "0.5"]]"#,
+            );
+            let actual = apply(&mut updater, &mut book, delta).unwrap().unwrap();
+
+            assert_eq!(
+                actual.bids,
+                OrderBookSide::new(
+                    Side::Buy,
+                    vec![Level::new(100.0, 1.0), Level::new(98.5, 3.0)]
+                )
+            );
+            assert_eq!(
+                actual.asks,
+                OrderBookSide::new(
+                    Side::Sell,
+                    vec![Level::new(101.0, 0.5), Level::new(102.0, 2.0)]
+                )
+            );
+
+            // Delta w/ a stale cross sequence is dropped
+            let stale = message("delta", 12, 104, r#"[["100.0", "0"]]"#, "[]");
+            assert_eq!(apply(&mut updater, &mut book, stale).unwrap(), None);
+            assert_eq!(updater.last_update_id, 11);
+
+            // Re-sent snapshot replaces the OrderBook
+            let snapshot = message(
+                "snapshot",
+                1,
+                200,
+                r#"[["90.0", "1"]]"#,
+                r#"[["91.0", "1"]]"#,
+            );
+            let actual = apply(&mut updater, &mut book, snapshot).unwrap().unwrap();
+            assert_eq!(
+                actual.bids,
+                OrderBookSide::new(Side::Buy, vec![Level::new(90.0, 1.0)])
+            );
+            assert_eq!(updater.last_update_id, 1);
+        }
+
+        #[test]
+        fn test_update_gap_triggers_resync() {
+            let mut updater = BybitBookUpdater::default();
+            let mut book = book();
+
+            let snapshot = message("snapshot", 10, 100, r#"[["100.0", "1"]]"#, "[]");
+            apply(&mut updater, &mut book, snapshot).unwrap();
+
+            // Delta u=12 skips u=11
+            let gap = message("delta", 12, 101, r#"[["100.0", "2"]]"#, "[]");
+            let error = apply(&mut updater, &mut book, gap).unwrap_err();
+
+            assert!(matches!(
+                error,
+                DataError::InvalidSequence {
+                    prev_last_update_id: 10,
+                    first_update_id: 12
+                }
+            ));
+            assert!(error.is_terminal());
+        }
+    }
+}
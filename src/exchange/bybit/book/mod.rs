@@ -0,0 +1,31 @@
+use crate::subscription::book::Level;
+use serde::{Deserialize, Serialize};
+
+/// Level 2 OrderBook types and [`OrderBookUpdater`](crate::transformer::book::OrderBookUpdater)
+/// implementation common to both [`BybitSpot`](super::spot::BybitSpot) and
+/// [`BybitPerpetualsUsd`](super::futures::BybitPerpetualsUsd).
+pub mod l2;
+
+/// [`Bybit`](super::Bybit) OrderBook level.
+///
+/// #### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+/// ```json
+/// ["16493.50", "0.006"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitLevel {
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub amount: f64,
+}
+
+impl From<BybitLevel> for Level {
+    fn from(level: BybitLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
@@ -60,22 +60,22 @@ pub struct BybitPayload<T> {
     pub data: T,
 }
 
-/// Deserialize a [`BybitPayload`] "s" (eg/ "publicTrade.BTCUSDT") as the associated
-/// [`SubscriptionId`].
+/// Deserialize a [`BybitPayload`] "topic" (eg/ "publicTrade.BTCUSDT" or
+/// "orderbook.50.BTCUSDT") as the associated [`SubscriptionId`].
 ///
-/// eg/ "publicTrade|BTCUSDT"
+/// eg/ "publicTrade|BTCUSDT" or "orderbook.50|BTCUSDT"
 pub fn de_message_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
     let input = <&str as serde::Deserialize>::deserialize(deserializer)?;
-    let mut tokens = input.split('.');
 
-    match (tokens.next(), tokens.next(), tokens.next()) {
-        (Some("publicTrade"), Some(market), None) => Ok(SubscriptionId::from(format!(
-            "{}|{market}",
-            BybitChannel::TRADES.0
-        ))),
+    match input.rsplit_once('.') {
+        Some((channel, market))
+            if channel == BybitChannel::TRADES.0 || channel == BybitChannel::ORDER_BOOK_L2.0 =>
+        {
+            Ok(SubscriptionId::from(format!("{channel}|{market}")))
+        }
         _ => Err(Error::invalid_value(
             Unexpected::Str(input),
             &"invalid message type expected pattern: <type>.<symbol>",
@@ -1,15 +1,15 @@
 use crate::{
     exchange::{
         bybit::{
-            channel::BybitChannel, market::BybitMarket, message::BybitMessage,
-            subscription::BybitResponse,
+            book::l2::BybitBookUpdater, channel::BybitChannel, market::BybitMarket,
+            message::BybitMessage, subscription::BybitResponse,
         },
         subscription::ExchangeSub,
         Connector, ExchangeId, ExchangeServer, PingInterval, StreamSelector,
     },
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{trade::PublicTrades, Map},
-    transformer::stateless::StatelessTransformer,
+    subscription::{book::OrderBooksL2, trade::PublicTrades, Map},
+    transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
     ExchangeWsStream,
 };
 use barter_integration::{
@@ -20,6 +20,10 @@ use std::{fmt::Debug, marker::PhantomData, time::Duration};
 use tokio::time;
 use url::Url;
 
+/// OrderBook types common to both [`BybitSpot`](spot::BybitSpot) and
+/// [`BybitFuturesUsd`](futures::BybitPerpetualsUsd).
+pub mod book;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -49,6 +53,11 @@ pub mod subscription;
 /// [`BybitFuturesUsd`](futures::BybitPerpetualsUsd).
 pub mod trade;
 
+/// [`Bybit`] maximum number of args accepted in a single subscribe request.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect#public-channel---args-limits>
+pub const MAX_ARGS_PER_REQUEST_BYBIT: usize = 10;
+
 /// Generic [`Bybit<Server>`](Bybit) exchange.
 ///
 /// ### Notes
@@ -103,8 +112,12 @@ where
         )]
     }
 
-    fn expected_responses(_: &Map<Instrument>) -> usize {
-        1
+    fn max_args_per_request() -> Option<usize> {
+        Some(MAX_ARGS_PER_REQUEST_BYBIT)
+    }
+
+    fn expected_responses(map: &Map<Instrument>) -> usize {
+        Self::num_batched_requests(map.0.len())
     }
 }
 
@@ -115,6 +128,13 @@ where
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, BybitMessage>>;
 }
 
+impl<Server> StreamSelector<OrderBooksL2> for Bybit<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream = ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, BybitBookUpdater>>;
+}
+
 impl<'de, Server> serde::Deserialize<'de> for Bybit<Server>
 where
     Server: ExchangeServer,
@@ -1,7 +1,7 @@
 use crate::{
     event::{MarketEvent, MarketIter},
     exchange::{bybit::message::BybitPayload, ExchangeId},
-    subscription::trade::PublicTrade,
+    subscription::trade::{PublicTrade, SideSource, TradeOrderIds, TradeSource},
 };
 use barter_integration::model::{instrument::Instrument, Exchange, Side};
 use chrono::{DateTime, Utc};
@@ -59,6 +59,7 @@ impl From<(ExchangeId, Instrument, BybitTrade)> for MarketIter<PublicTrade> {
                     Ok(MarketEvent {
                         exchange_time: trade.time,
                         received_time: Utc::now(),
+                        exchange_time_synthesized: false,
                         exchange: Exchange::from(exchange_id),
                         instrument: instrument.clone(),
                         kind: PublicTrade {
@@ -66,6 +67,10 @@ impl From<(ExchangeId, Instrument, BybitTrade)> for MarketIter<PublicTrade> {
                             price: trade.price,
                             amount: trade.amount,
                             side: trade.side,
+                            source: TradeSource::Live,
+                            side_source: SideSource::Exchange,
+                            order_ids: TradeOrderIds::default(),
+                            aggregate_count: None,
                         },
                     })
                 })
@@ -0,0 +1,429 @@
+use super::channel::CoinbaseChannel;
+use crate::{
+    error::DataError,
+    exchange::ExchangeSub,
+    subscription::book::{Level, OrderBook, OrderBookSide},
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+use tokio::sync::mpsc;
+
+/// [`Coinbase`](super::Coinbase) OrderBook Level2 "level2" channel message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#level2-channel>
+/// #### Snapshot
+/// ```json
+/// {
+///     "type": "snapshot",
+///     "product_id": "BTC-USD",
+///     "bids": [["10101.10", "0.45054140"]],
+///     "asks": [["10102.55", "0.57753524"]]
+/// }
+/// ```
+///
+/// #### Update
+/// ```json
+/// {
+///     "type": "l2update",
+///     "product_id": "BTC-USD",
+///     "changes": [["buy", "10101.80000000", "0.162567"]],
+///     "time": "2019-08-14T20:42:27.265Z"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CoinbaseOrderBookL2 {
+    Snapshot {
+        #[serde(alias = "product_id", deserialize_with = "de_book_l2_subscription_id")]
+        subscription_id: SubscriptionId,
+        bids: Vec<CoinbaseLevel>,
+        asks: Vec<CoinbaseLevel>,
+    },
+    #[serde(rename = "l2update")]
+    Update {
+        #[serde(alias = "product_id", deserialize_with = "de_book_l2_subscription_id")]
+        subscription_id: SubscriptionId,
+        time: DateTime<Utc>,
+        changes: Vec<CoinbaseLevelChange>,
+    },
+}
+
+impl CoinbaseOrderBookL2 {
+    fn subscription_id_mut(&mut self) -> &mut SubscriptionId {
+        match self {
+            Self::Snapshot {
+                subscription_id, ..
+            }
+            | Self::Update {
+                subscription_id, ..
+            } => subscription_id,
+        }
+    }
+}
+
+impl Identifier<Option<SubscriptionId>> for CoinbaseOrderBookL2 {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            Self::Snapshot {
+                subscription_id, ..
+            }
+            | Self::Update {
+                subscription_id, ..
+            } => Some(subscription_id.clone()),
+        }
+    }
+}
+
+/// [`Coinbase`](super::Coinbase) OrderBook Level2 "level2_batch" channel message.
+///
+/// Identical in format to a [`CoinbaseOrderBookL2`], but each "l2update" batches the changes
+/// accumulated over ~50ms rather than being sent in real-time. The [`SubscriptionId`] is
+/// replaced with the "level2_batch" equivalent (eg/ "level2_batch|BTC-USD").
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#level2-batch-channel>
+/// ```json
+/// {
+///     "type": "l2update",
+///     "product_id": "BTC-USD",
+///     "changes": [
+///         ["buy", "10101.80000000", "0.162567"],
+///         ["sell", "10102.55000000", "0"]
+///     ],
+///     "time": "2019-08-14T20:42:27.265Z"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct CoinbaseOrderBookL2Batch(pub CoinbaseOrderBookL2);
+
+impl<'de> Deserialize<'de> for CoinbaseOrderBookL2Batch {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let mut book = CoinbaseOrderBookL2::deserialize(deserializer)?;
+
+        // Replace "level2" SubscriptionId (eg/ "level2|BTC-USD")
+        let subscription_id = book.subscription_id_mut();
+        if let Some((_, product_id)) = subscription_id.0.split_once('|') {
+            *subscription_id =
+                ExchangeSub::from((CoinbaseChannel::ORDER_BOOK_L2_BATCH, product_id)).id();
+        }
+
+        Ok(Self(book))
+    }
+}
+
+impl Identifier<Option<SubscriptionId>> for CoinbaseOrderBookL2Batch {
+    fn id(&self) -> Option<SubscriptionId> {
+        self.0.id()
+    }
+}
+
+impl From<CoinbaseOrderBookL2Batch> for CoinbaseOrderBookL2 {
+    fn from(batch: CoinbaseOrderBookL2Batch) -> Self {
+        batch.0
+    }
+}
+
+/// [`Coinbase`](super::Coinbase) OrderBook level.
+///
+/// ### Raw Payload Examples
+/// ```json
+/// ["10101.10", "0.45054140"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseLevel {
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub amount: f64,
+}
+
+impl From<CoinbaseLevel> for Level {
+    fn from(level: CoinbaseLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+/// [`Coinbase`](super::Coinbase) OrderBook level change contained in an "l2update", where the
+/// amount is the new absolute amount of the price level.
+///
+/// ### Raw Payload Examples
+/// ```json
+/// ["buy", "10101.80000000", "0.162567"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseLevelChange {
+    pub side: Side,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub amount: f64,
+}
+
+/// [`Coinbase`](super::Coinbase) [`OrderBookUpdater`] for the "level2" & "level2_batch"
+/// channels.
+///
+/// Coinbase: How To Maintain A Local OrderBook
+///
+/// 1. Subscribe to the "level2" or "level2_batch" channel, after which a "snapshot" is received.
+/// 2. A "snapshot" replaces the local OrderBook.
+/// 3. Drop any "l2update" received before the "snapshot".
+/// 4. Each "l2update" change contains the absolute amount for a price level.
+/// 5. If the amount is 0, remove the price level.
+///
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#level2-channel>
+#[derive(Debug)]
+pub struct CoinbaseBookUpdater<Update = CoinbaseOrderBookL2> {
+    pub updates_processed: u64,
+    phantom: PhantomData<Update>,
+}
+
+impl<Update> CoinbaseBookUpdater<Update> {
+    /// Construct a new [`Self`] that has not yet processed a "snapshot".
+    pub fn new() -> Self {
+        Self {
+            updates_processed: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Determines if a "snapshot" has been applied, after which "l2update"s can be applied.
+    pub fn is_initialised(&self) -> bool {
+        self.updates_processed > 0
+    }
+}
+
+impl<Update> Default for CoinbaseBookUpdater<Update> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl<Update> OrderBookUpdater for CoinbaseBookUpdater<Update>
+where
+    Update: Into<CoinbaseOrderBookL2> + Send,
+{
+    type OrderBook = OrderBook;
+    type Update = Update;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Initial OrderBook snapshot is received over the WebSocket after subscribing
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::new(),
+            book: OrderBook {
+                last_update_time: DateTime::default(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            },
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<Self::OrderBook>, DataError> {
+        // Coinbase: How To Maintain A Local OrderBook
+        // See Self's Rust Docs for more information on each numbered step
+        match update.into() {
+            CoinbaseOrderBookL2::Snapshot { bids, asks, .. } => {
+                // 2. A snapshot replaces the local OrderBook:
+                book.bids = OrderBookSide::new(Side::Buy, bids);
+                book.asks = OrderBookSide::new(Side::Sell, asks);
+                // Snapshots are not timestamped, so leave the last_update_time unset for the
+                // MarketEvent exchange_time to be synthesized from the received time
+                book.last_update_time = DateTime::default();
+            }
+            CoinbaseOrderBookL2::Update { time, changes, .. } => {
+                // 3. Drop any l2update received before the snapshot:
+                if !self.is_initialised() {
+                    return Ok(None);
+                }
+
+                // 4. Each change contains the absolute amount for a price level.
+                // 5. If the amount is 0, remove the price level.
+                for change in changes {
+                    let level = Level::new(change.price, change.amount);
+                    match change.side {
+                        Side::Buy => book.bids.upsert_single(level),
+                        Side::Sell => book.asks.upsert_single(level),
+                    }
+                }
+                book.last_update_time = time;
+            }
+        }
+
+        self.updates_processed += 1;
+        Ok(Some(book.snapshot()))
+    }
+}
+
+/// Deserialize a [`CoinbaseOrderBookL2`] "product_id" (eg/ "BTC-USD") as the associated
+/// [`SubscriptionId`] (eg/ SubscriptionId("level2|BTC-USD")).
+pub fn de_book_l2_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|product_id| ExchangeSub::from((CoinbaseChannel::ORDER_BOOK_L2, product_id)).id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_coinbase_order_book_l2_batch() {
+            struct TestCase {
+                input: &'static str,
+                expected: CoinbaseOrderBookL2Batch,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid snapshot
+                    input: r#"
+                    {
+                        "type": "snapshot",
+                        "product_id": "BTC-USD",
+                        "bids": [["10101.10", "0.45054140"]],
+                        "asks": [["10102.55", "0.57753524"], ["10103.00", "1.0"]]
+                    }
+                    "#,
+                    expected: CoinbaseOrderBookL2Batch(CoinbaseOrderBookL2::Snapshot {
+                        subscription_id: SubscriptionId::from("level2_batch|BTC-USD"),
+                        bids: vec![CoinbaseLevel {
+                            price: 10101.10,
+                            amount: 0.4505414,
+                        }],
+                        asks: vec![
+                            CoinbaseLevel {
+                                price: 10102.55,
+                                amount: 0.57753524,
+                            },
+                            CoinbaseLevel {
+                                price: 10103.0,
+                                amount: 1.0,
+                            },
+                        ],
+                    }),
+                },
+                TestCase {
+                    // TC1: valid batched l2update w/ many changes
+                    input: r#"
+                    {
+                        "type": "l2update",
+                        "product_id": "BTC-USD",
+                        "changes": [
+                            ["buy", "10101.80000000", "0.162567"],
+                            ["buy", "10101.10000000", "0"],
+                            ["sell", "10102.55000000", "0.3"]
+                        ],
+                        "time": "2019-08-14T20:42:27.265Z"
+                    }
+                    "#,
+                    expected: CoinbaseOrderBookL2Batch(CoinbaseOrderBookL2::Update {
+                        subscription_id: SubscriptionId::from("level2_batch|BTC-USD"),
+                        time: Utc.timestamp_millis_opt(1565815347265).unwrap(),
+                        changes: vec![
+                            CoinbaseLevelChange {
+                                side: Side::Buy,
+                                price: 10101.8,
+                                amount: 0.162567,
+                            },
+                            CoinbaseLevelChange {
+                                side: Side::Buy,
+                                price: 10101.1,
+                                amount: 0.0,
+                            },
+                            CoinbaseLevelChange {
+                                side: Side::Sell,
+                                price: 10102.55,
+                                amount: 0.3,
+                            },
+                        ],
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<CoinbaseOrderBookL2Batch>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_coinbase_book_updater_applies_batched_update() {
+        let mut updater = CoinbaseBookUpdater::<CoinbaseOrderBookL2Batch>::new();
+        let mut book = OrderBook {
+            last_update_time: Utc::now(),
+            bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+            asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+        };
+
+        let update = |input: &str| serde_json::from_str::<CoinbaseOrderBookL2Batch>(input).unwrap();
+
+        // l2update received before the snapshot is dropped
+        let early = update(
+            r#"{"type":"l2update","product_id":"BTC-USD","changes":[["buy","1","1"]],"time":"2019-08-14T20:42:27.265Z"}"#,
+        );
+        assert_eq!(updater.update(&mut book, early).unwrap(), None);
+
+        let snapshot = update(
+            r#"{"type":"snapshot","product_id":"BTC-USD","bids":[["100","1"],["99","2"]],"asks":[["101","1"]]}"#,
+        );
+        updater.update(&mut book, snapshot).unwrap();
+
+        let batch = update(
+            r#"{"type":"l2update","product_id":"BTC-USD","changes":[["buy","100","0"],["buy","99.5","3"],["sell","102","4"]],"time":"2019-08-14T20:42:27.265Z"}"#,
+        );
+        let actual = updater.update(&mut book, batch).unwrap().unwrap();
+
+        assert_eq!(
+            actual.bids,
+            OrderBookSide::new(
+                Side::Buy,
+                vec![Level::new(99.5, 3.0), Level::new(99.0, 2.0)]
+            )
+        );
+        assert_eq!(
+            actual.asks,
+            OrderBookSide::new(
+                Side::Sell,
+                vec![Level::new(101.0, 1.0), Level::new(102.0, 4.0)]
+            )
+        );
+        assert_eq!(
+            actual.last_update_time,
+            Utc.timestamp_millis_opt(1565815347265).unwrap()
+        );
+    }
+}
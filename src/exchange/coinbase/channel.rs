@@ -1,6 +1,11 @@
 use super::Coinbase;
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{
+        book::{OrderBooksL2, OrderBooksL2Batched},
+        status::InstrumentStatuses,
+        trade::{PublicTrades, PublicTradesFeed, TradeFeed},
+        Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +22,27 @@ impl CoinbaseChannel {
     ///
     /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#match>
     pub const TRADES: Self = Self("matches");
+
+    /// [`Coinbase`] real-time ticker channel, summarising the last trade after each match.
+    ///
+    /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#ticker-channel>
+    pub const TICKER: Self = Self("ticker");
+
+    /// [`Coinbase`] real-time OrderBook Level2 channel.
+    ///
+    /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#level2-channel>
+    pub const ORDER_BOOK_L2: Self = Self("level2");
+
+    /// [`Coinbase`] OrderBook Level2 channel that batches updates every ~50ms.
+    ///
+    /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#level2-batch-channel>
+    pub const ORDER_BOOK_L2_BATCH: Self = Self("level2_batch");
+
+    /// [`Coinbase`] real-time product status channel, announcing product state changes (eg/
+    /// delistings & trading halts).
+    ///
+    /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#status-channel>
+    pub const STATUS: Self = Self("status");
 }
 
 impl Identifier<CoinbaseChannel> for Subscription<Coinbase, PublicTrades> {
@@ -25,6 +51,40 @@ impl Identifier<CoinbaseChannel> for Subscription<Coinbase, PublicTrades> {
     }
 }
 
+impl Identifier<CoinbaseChannel> for Subscription<Coinbase, PublicTradesFeed> {
+    fn id(&self) -> CoinbaseChannel {
+        match self.kind.feed {
+            // Coinbase "matches" already yields every individual fill
+            TradeFeed::Standard | TradeFeed::AllFills => CoinbaseChannel::TRADES,
+            TradeFeed::Ticker => CoinbaseChannel::TICKER,
+        }
+    }
+}
+
+impl Identifier<CoinbaseChannel> for Subscription<Coinbase, OrderBooksL2> {
+    fn id(&self) -> CoinbaseChannel {
+        CoinbaseChannel::ORDER_BOOK_L2
+    }
+}
+
+impl Identifier<CoinbaseChannel> for Subscription<Coinbase, OrderBooksL2Batched> {
+    fn id(&self) -> CoinbaseChannel {
+        CoinbaseChannel::ORDER_BOOK_L2_BATCH
+    }
+}
+
+impl Identifier<CoinbaseChannel> for Subscription<Coinbase, InstrumentStatuses> {
+    fn id(&self) -> CoinbaseChannel {
+        CoinbaseChannel::STATUS
+    }
+}
+
+impl From<&'static str> for CoinbaseChannel {
+    fn from(channel: &'static str) -> Self {
+        Self(channel)
+    }
+}
+
 impl AsRef<str> for CoinbaseChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -0,0 +1,86 @@
+use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
+use serde::{Deserialize, Serialize};
+
+/// [`Coinbase`](super::Coinbase) product "status" of an actively trading market.
+pub const COINBASE_PRODUCT_STATUS_ONLINE: &str = "online";
+
+/// [`Coinbase`](super::Coinbase) REST product, listed by the products endpoint.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/reference/exchangerestapi_getproducts>
+/// ```json
+/// [
+///   {
+///     "id": "BTC-USD",
+///     "base_currency": "BTC",
+///     "quote_currency": "USD",
+///     "status": "online",
+///     "trading_disabled": false
+///   }
+/// ]
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct CoinbaseProduct {
+    pub base_currency: String,
+    pub quote_currency: String,
+    pub status: String,
+    #[serde(default)]
+    pub trading_disabled: bool,
+}
+
+/// Actively trading spot [`Instrument`]s of the provided [`CoinbaseProduct`]s.
+pub fn online_instruments(products: Vec<CoinbaseProduct>) -> Vec<Instrument> {
+    products
+        .into_iter()
+        .filter(|product| product.status == COINBASE_PRODUCT_STATUS_ONLINE)
+        .filter(|product| !product.trading_disabled)
+        .map(|product| {
+            Instrument::from((
+                product.base_currency.to_lowercase(),
+                product.quote_currency.to_lowercase(),
+                InstrumentKind::Spot,
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coinbase_online_instruments() {
+        let input = r#"
+        [
+            {
+                "id": "BTC-USD",
+                "base_currency": "BTC",
+                "quote_currency": "USD",
+                "status": "online",
+                "trading_disabled": false
+            },
+            {
+                "id": "ETH-EUR",
+                "base_currency": "ETH",
+                "quote_currency": "EUR",
+                "status": "online",
+                "trading_disabled": true
+            },
+            {
+                "id": "XRP-USD",
+                "base_currency": "XRP",
+                "quote_currency": "USD",
+                "status": "delisted",
+                "trading_disabled": false
+            }
+        ]
+        "#;
+
+        let actual = online_instruments(serde_json::from_str(input).unwrap());
+
+        assert_eq!(
+            actual,
+            vec![Instrument::from(("btc", "usd", InstrumentKind::Spot))]
+        );
+    }
+}
@@ -1,27 +1,52 @@
 use self::{
-    channel::CoinbaseChannel, market::CoinbaseMarket, subscription::CoinbaseSubResponse,
-    trade::CoinbaseTrade,
+    book::{CoinbaseBookUpdater, CoinbaseOrderBookL2, CoinbaseOrderBookL2Batch},
+    channel::CoinbaseChannel,
+    instrument::CoinbaseProduct,
+    market::CoinbaseMarket,
+    status::CoinbaseStatus,
+    subscription::CoinbaseSubResponse,
+    trade::{CoinbaseFeedTrade, CoinbaseRecentTrade, CoinbaseTrade},
 };
 use crate::{
     exchange::{Connector, ExchangeId, ExchangeSub, StreamSelector},
+    streams::{backfill::TradeBackfill, discovery::InstrumentDiscovery},
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
+    subscription::{
+        book::{OrderBooksL2, OrderBooksL2Batched},
+        status::InstrumentStatuses,
+        trade::{PublicTrades, PublicTradesFeed},
+    },
+    transformer::{
+        book::MultiBookTransformer,
+        stateless::{StatelessFanOutTransformer, StatelessTransformer},
+    },
     ExchangeWsStream,
 };
-use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_integration::{
+    error::SocketError, model::instrument::Instrument, protocol::websocket::WsMessage,
+};
 use barter_macro::{DeExchange, SerExchange};
 use serde_json::json;
 use url::Url;
 
+/// Level 2 OrderBook types and [`OrderBookUpdater`](crate::transformer::book::OrderBookUpdater)
+/// implementation for the [`Coinbase`] "level2" & "level2_batch" channels.
+pub mod book;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
 
+/// REST product types, used for instrument discovery, for [`Coinbase`].
+pub mod instrument;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
 
+/// Product status types for the [`Coinbase`] "status" channel.
+pub mod status;
+
 /// [`Subscription`](crate::subscription::Subscription) response type and response
 /// [`Validator`](barter_integration::Validator) for [`Coinbase`].
 pub mod subscription;
@@ -34,6 +59,17 @@ pub mod trade;
 /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-overview>
 pub const BASE_URL_COINBASE: &str = "wss://ws-feed.exchange.coinbase.com";
 
+/// [`Coinbase`] sandbox server base url.
+///
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/sandbox>
+pub const BASE_URL_COINBASE_SANDBOX: &str = "wss://ws-feed-public.sandbox.exchange.coinbase.com";
+
+/// [`Coinbase`] REST products url, used for instrument discovery & to construct the recent
+/// trades request url.
+///
+/// See docs: <https://docs.cloud.coinbase.com/exchange/reference/exchangerestapi_getproducts>
+pub const HTTP_PRODUCTS_URL_COINBASE: &str = "https://api.exchange.coinbase.com/products";
+
 /// [`Coinbase`] exchange.
 ///
 /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-overview>
@@ -54,6 +90,10 @@ impl Connector for Coinbase {
         Url::parse(BASE_URL_COINBASE).map_err(SocketError::UrlParse)
     }
 
+    fn sandbox_url() -> Result<Url, SocketError> {
+        Url::parse(BASE_URL_COINBASE_SANDBOX).map_err(SocketError::UrlParse)
+    }
+
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
         exchange_subs
             .into_iter()
@@ -74,3 +114,52 @@ impl Connector for Coinbase {
 impl StreamSelector<PublicTrades> for Coinbase {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, CoinbaseTrade>>;
 }
+
+impl StreamSelector<PublicTradesFeed> for Coinbase {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTradesFeed, CoinbaseFeedTrade>>;
+}
+
+impl StreamSelector<OrderBooksL2> for Coinbase {
+    type Stream = ExchangeWsStream<
+        MultiBookTransformer<Self, OrderBooksL2, CoinbaseBookUpdater<CoinbaseOrderBookL2>>,
+    >;
+}
+
+impl StreamSelector<OrderBooksL2Batched> for Coinbase {
+    type Stream = ExchangeWsStream<
+        MultiBookTransformer<
+            Self,
+            OrderBooksL2Batched,
+            CoinbaseBookUpdater<CoinbaseOrderBookL2Batch>,
+        >,
+    >;
+}
+
+impl StreamSelector<InstrumentStatuses> for Coinbase {
+    type Stream =
+        ExchangeWsStream<StatelessFanOutTransformer<Self, InstrumentStatuses, CoinbaseStatus>>;
+}
+
+impl TradeBackfill for Coinbase {
+    const RECENT_TRADES_URL: &'static str = HTTP_PRODUCTS_URL_COINBASE;
+    type Response = Vec<CoinbaseRecentTrade>;
+
+    fn recent_trades_url(base_url: &str, instrument: &Instrument, limit: usize) -> String {
+        format!(
+            "{}/{}-{}/trades?limit={}",
+            base_url,
+            instrument.base.as_ref().to_uppercase(),
+            instrument.quote.as_ref().to_uppercase(),
+            limit
+        )
+    }
+}
+
+impl InstrumentDiscovery for Coinbase {
+    const INSTRUMENTS_URL: &'static str = HTTP_PRODUCTS_URL_COINBASE;
+    type Response = Vec<CoinbaseProduct>;
+
+    fn instruments(response: Self::Response) -> Vec<Instrument> {
+        instrument::online_instruments(response)
+    }
+}
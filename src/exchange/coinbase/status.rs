@@ -0,0 +1,289 @@
+use super::CoinbaseChannel;
+use crate::{
+    event::{exchange_time_or_received, MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::status::{InstrumentState, InstrumentStatus},
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, SubscriptionId};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// [`Coinbase`](super::Coinbase) product "status" of a permanently removed market.
+pub const COINBASE_PRODUCT_STATUS_DELISTED: &str = "delisted";
+
+/// Coinbase real-time "status" WebSocket message, announcing the current state of every product.
+///
+/// Coinbase sends the state of every product, regardless of the subscribed product_ids, so each
+/// [`CoinbaseProductStatus`] is fanned out & the products of un-subscribed markets discarded.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#status-channel>
+/// ```json
+/// {
+///     "type": "status",
+///     "products": [
+///         {
+///             "id": "BTC-USD",
+///             "base_currency": "BTC",
+///             "quote_currency": "USD",
+///             "display_name": "BTC-USD",
+///             "status": "online",
+///             "status_message": null,
+///             "trading_disabled": false,
+///             "cancel_only": false,
+///             "limit_only": false,
+///             "post_only": false
+///         }
+///     ],
+///     "currencies": []
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct CoinbaseStatus {
+    pub products: Vec<CoinbaseProductStatus>,
+}
+
+impl IntoIterator for CoinbaseStatus {
+    type Item = CoinbaseProductStatus;
+    type IntoIter = std::vec::IntoIter<CoinbaseProductStatus>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.products.into_iter()
+    }
+}
+
+/// Coinbase product state contained in a [`CoinbaseStatus`] message.
+///
+/// See [`CoinbaseStatus`] for full raw payload examples.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct CoinbaseProductStatus {
+    #[serde(alias = "id", deserialize_with = "de_status_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub status: String,
+    #[serde(default)]
+    pub status_message: Option<String>,
+    #[serde(default)]
+    pub trading_disabled: bool,
+    #[serde(default)]
+    pub cancel_only: bool,
+    #[serde(default)]
+    pub limit_only: bool,
+    #[serde(default)]
+    pub post_only: bool,
+}
+
+impl CoinbaseProductStatus {
+    /// Determine the normalised [`InstrumentState`] from the product "status" & trading flags,
+    /// with the most restrictive taking precedence.
+    pub fn state(&self) -> InstrumentState {
+        match self.status.as_str() {
+            COINBASE_PRODUCT_STATUS_DELISTED => InstrumentState::Delisted,
+            status if status != super::instrument::COINBASE_PRODUCT_STATUS_ONLINE => {
+                InstrumentState::Offline
+            }
+            _ if self.trading_disabled => InstrumentState::Halted,
+            _ if self.cancel_only => InstrumentState::CancelOnly,
+            _ if self.post_only => InstrumentState::PostOnly,
+            _ if self.limit_only => InstrumentState::LimitOnly,
+            _ => InstrumentState::Online,
+        }
+    }
+}
+
+impl Identifier<Option<SubscriptionId>> for CoinbaseProductStatus {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl From<(ExchangeId, Instrument, CoinbaseProductStatus)> for MarketIter<InstrumentStatus> {
+    fn from(
+        (exchange_id, instrument, product): (ExchangeId, Instrument, CoinbaseProductStatus),
+    ) -> Self {
+        // Coinbase status messages are not timestamped
+        let received_time = Utc::now();
+        let (exchange_time, exchange_time_synthesized) =
+            exchange_time_or_received(None, received_time);
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time,
+            received_time,
+            exchange_time_synthesized,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: InstrumentStatus {
+                state: product.state(),
+                message: product.status_message.filter(|message| !message.is_empty()),
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`CoinbaseProductStatus`] "id" (eg/ "BTC-USD") as the associated
+/// [`SubscriptionId`] (eg/ SubscriptionId("status|BTC-USD").
+pub fn de_status_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|product_id| ExchangeSub::from((CoinbaseChannel::STATUS, product_id)).id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::model::instrument::kind::InstrumentKind;
+
+        #[test]
+        fn test_coinbase_status() {
+            let input = r#"
+            {
+                "type": "status",
+                "products": [
+                    {
+                        "id": "BTC-USD",
+                        "base_currency": "BTC",
+                        "quote_currency": "USD",
+                        "display_name": "BTC-USD",
+                        "status": "online",
+                        "status_message": "",
+                        "trading_disabled": false,
+                        "cancel_only": false,
+                        "limit_only": true,
+                        "post_only": false
+                    },
+                    {
+                        "id": "XRP-USD",
+                        "base_currency": "XRP",
+                        "quote_currency": "USD",
+                        "display_name": "XRP-USD",
+                        "status": "delisted",
+                        "status_message": "XRP-USD has been delisted",
+                        "trading_disabled": true,
+                        "cancel_only": false,
+                        "limit_only": false,
+                        "post_only": false
+                    }
+                ],
+                "currencies": []
+            }
+            "#;
+
+            let actual = serde_json::from_str::<CoinbaseStatus>(input).unwrap();
+            let expected = CoinbaseStatus {
+                products: vec![
+                    CoinbaseProductStatus {
+                        subscription_id: SubscriptionId::from("status|BTC-USD"),
+                        status: "online".to_string(),
+                        status_message: Some(String::new()),
+                        trading_disabled: false,
+                        cancel_only: false,
+                        limit_only: true,
+                        post_only: false,
+                    },
+                    CoinbaseProductStatus {
+                        subscription_id: SubscriptionId::from("status|XRP-USD"),
+                        status: "delisted".to_string(),
+                        status_message: Some("XRP-USD has been delisted".to_string()),
+                        trading_disabled: true,
+                        cancel_only: false,
+                        limit_only: false,
+                        post_only: false,
+                    },
+                ],
+            };
+            assert_eq!(actual, expected);
+
+            // Product state transitions are captured as normalised InstrumentStatuses
+            let actual = actual
+                .into_iter()
+                .map(|product| {
+                    MarketIter::<InstrumentStatus>::from((
+                        ExchangeId::Coinbase,
+                        Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+                        product,
+                    ))
+                    .0
+                    .remove(0)
+                    .unwrap()
+                })
+                .collect::<Vec<_>>();
+
+            // Coinbase status messages are not timestamped, so the exchange_time is synthesized
+            assert!(actual
+                .iter()
+                .all(|event| event.is_exchange_time_synthesized()
+                    && event.exchange_time == event.received_time));
+
+            let actual = actual
+                .into_iter()
+                .map(|event| event.kind)
+                .collect::<Vec<_>>();
+            assert_eq!(
+                actual,
+                vec![
+                    InstrumentStatus {
+                        state: InstrumentState::LimitOnly,
+                        message: None,
+                    },
+                    InstrumentStatus {
+                        state: InstrumentState::Delisted,
+                        message: Some("XRP-USD has been delisted".to_string()),
+                    },
+                ]
+            );
+        }
+
+        #[test]
+        fn test_coinbase_product_status_state() {
+            struct TestCase {
+                input: &'static str,
+                expected: InstrumentState,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: online product without restrictions
+                    input: r#"{"id": "BTC-USD", "status": "online"}"#,
+                    expected: InstrumentState::Online,
+                },
+                TestCase {
+                    // TC1: online product with trading disabled is halted
+                    input: r#"{"id": "BTC-USD", "status": "online", "trading_disabled": true, "cancel_only": true}"#,
+                    expected: InstrumentState::Halted,
+                },
+                TestCase {
+                    // TC2: cancel only takes precedence over limit only
+                    input: r#"{"id": "BTC-USD", "status": "online", "cancel_only": true, "limit_only": true}"#,
+                    expected: InstrumentState::CancelOnly,
+                },
+                TestCase {
+                    // TC3: post only product
+                    input: r#"{"id": "BTC-USD", "status": "online", "post_only": true}"#,
+                    expected: InstrumentState::PostOnly,
+                },
+                TestCase {
+                    // TC4: non-online status is offline, regardless of trading flags
+                    input: r#"{"id": "BTC-USD", "status": "offline", "limit_only": true}"#,
+                    expected: InstrumentState::Offline,
+                },
+                TestCase {
+                    // TC5: delisted product
+                    input: r#"{"id": "BTC-USD", "status": "delisted", "trading_disabled": true}"#,
+                    expected: InstrumentState::Delisted,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<CoinbaseProductStatus>(test.input)
+                    .unwrap()
+                    .state();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+}
@@ -2,7 +2,7 @@ use super::CoinbaseChannel;
 use crate::{
     event::{MarketEvent, MarketIter},
     exchange::{ExchangeId, ExchangeSub},
-    subscription::trade::PublicTrade,
+    subscription::trade::{PublicTrade, SideSource, TradeOrderIds, TradeSource},
     Identifier,
 };
 use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
@@ -40,6 +40,10 @@ pub struct CoinbaseTrade {
     #[serde(deserialize_with = "barter_integration::de::de_str")]
     pub price: f64,
     pub side: Side,
+    #[serde(default)]
+    pub maker_order_id: Option<String>,
+    #[serde(default)]
+    pub taker_order_id: Option<String>,
 }
 
 impl Identifier<Option<SubscriptionId>> for CoinbaseTrade {
@@ -53,6 +57,7 @@ impl From<(ExchangeId, Instrument, CoinbaseTrade)> for MarketIter<PublicTrade> {
         Self(vec![Ok(MarketEvent {
             exchange_time: trade.time,
             received_time: Utc::now(),
+            exchange_time_synthesized: false,
             exchange: Exchange::from(exchange_id),
             instrument,
             kind: PublicTrade {
@@ -60,11 +65,176 @@ impl From<(ExchangeId, Instrument, CoinbaseTrade)> for MarketIter<PublicTrade> {
                 price: trade.price,
                 amount: trade.amount,
                 side: trade.side,
+                source: TradeSource::Live,
+                side_source: SideSource::Exchange,
+                order_ids: TradeOrderIds {
+                    maker: trade.maker_order_id,
+                    taker: trade.taker_order_id,
+                    ..TradeOrderIds::default()
+                },
+                aggregate_count: None,
             },
         })])
     }
 }
 
+/// Coinbase real-time ticker WebSocket message, used as a lower fidelity source of the last trade.
+///
+/// ### Notes
+/// - The ticker "side" uses the same convention as the [`CoinbaseTrade`] "side", so both map
+///   to an identical [`PublicTrade`].
+/// - Several matches may be conflated into a single ticker message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#ticker-channel>
+/// ```json
+/// {
+///     "type": "ticker",
+///     "sequence": 37475248783,
+///     "product_id": "ETH-USD",
+///     "price": "1285.22",
+///     "open_24h": "1310.79",
+///     "volume_24h": "245532.79269678",
+///     "low_24h": "1280.52",
+///     "high_24h": "1313.8",
+///     "volume_30d": "9788783.60117027",
+///     "best_bid": "1285.04",
+///     "best_bid_size": "0.46688654",
+///     "best_ask": "1285.27",
+///     "best_ask_size": "1.56637040",
+///     "side": "buy",
+///     "time": "2022-10-19T23:28:22.061769Z",
+///     "trade_id": 370843401,
+///     "last_size": "11.4396987"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseTickerTrade {
+    #[serde(alias = "product_id", deserialize_with = "de_ticker_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(alias = "trade_id")]
+    pub id: u64,
+    pub time: DateTime<Utc>,
+    #[serde(
+        alias = "last_size",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub amount: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    pub side: Side,
+}
+
+impl Identifier<Option<SubscriptionId>> for CoinbaseTickerTrade {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl From<(ExchangeId, Instrument, CoinbaseTickerTrade)> for MarketIter<PublicTrade> {
+    fn from(
+        (exchange_id, instrument, trade): (ExchangeId, Instrument, CoinbaseTickerTrade),
+    ) -> Self {
+        Self::from((
+            exchange_id,
+            instrument,
+            CoinbaseTrade {
+                subscription_id: trade.subscription_id,
+                id: trade.id,
+                time: trade.time,
+                amount: trade.amount,
+                price: trade.price,
+                side: trade.side,
+                maker_order_id: None,
+                taker_order_id: None,
+            },
+        ))
+    }
+}
+
+/// Coinbase [`PublicTradesFeed`](crate::subscription::trade::PublicTradesFeed) WebSocket message,
+/// being either a [`CoinbaseTrade`] "match" or a [`CoinbaseTickerTrade`] "ticker", depending on
+/// the [`TradeFeed`](crate::subscription::trade::TradeFeed) subscribed to.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CoinbaseFeedTrade {
+    #[serde(alias = "last_match")]
+    Match(CoinbaseTrade),
+    Ticker(CoinbaseTickerTrade),
+}
+
+impl Identifier<Option<SubscriptionId>> for CoinbaseFeedTrade {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            Self::Match(trade) => trade.id(),
+            Self::Ticker(trade) => trade.id(),
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, CoinbaseFeedTrade)> for MarketIter<PublicTrade> {
+    fn from((exchange_id, instrument, trade): (ExchangeId, Instrument, CoinbaseFeedTrade)) -> Self {
+        match trade {
+            CoinbaseFeedTrade::Match(trade) => Self::from((exchange_id, instrument, trade)),
+            CoinbaseFeedTrade::Ticker(trade) => Self::from((exchange_id, instrument, trade)),
+        }
+    }
+}
+
+/// Coinbase REST recent trade, used to backfill [`PublicTrade`] history.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/reference/exchangerestapi_getproducttrades>
+/// ```json
+/// {
+///     "time": "2014-11-07T22:19:28.578544Z",
+///     "trade_id": 74,
+///     "price": "10.00000000",
+///     "size": "0.01000000",
+///     "side": "buy"
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseRecentTrade {
+    #[serde(alias = "trade_id")]
+    pub id: u64,
+    pub time: DateTime<Utc>,
+    #[serde(alias = "size", deserialize_with = "barter_integration::de::de_str")]
+    pub amount: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    pub side: Side,
+}
+
+impl From<(ExchangeId, Instrument, Vec<CoinbaseRecentTrade>)> for MarketIter<PublicTrade> {
+    fn from(
+        (exchange_id, instrument, trades): (ExchangeId, Instrument, Vec<CoinbaseRecentTrade>),
+    ) -> Self {
+        trades
+            .into_iter()
+            .map(|trade| {
+                Ok(MarketEvent {
+                    exchange_time: trade.time,
+                    received_time: Utc::now(),
+                    exchange_time_synthesized: false,
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: PublicTrade {
+                        id: trade.id.to_string(),
+                        price: trade.price,
+                        amount: trade.amount,
+                        side: trade.side,
+                        source: TradeSource::Historical,
+                        side_source: SideSource::Exchange,
+                        order_ids: TradeOrderIds::default(),
+                        aggregate_count: None,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
 /// Deserialize a [`CoinbaseTrade`] "product_id" (eg/ "BTC-USD") as the associated [`SubscriptionId`]
 /// (eg/ SubscriptionId("matches|BTC-USD").
 pub fn de_trade_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
@@ -75,6 +245,16 @@ where
         .map(|product_id| ExchangeSub::from((CoinbaseChannel::TRADES, product_id)).id())
 }
 
+/// Deserialize a [`CoinbaseTickerTrade`] "product_id" (eg/ "BTC-USD") as the associated
+/// [`SubscriptionId`] (eg/ SubscriptionId("ticker|BTC-USD").
+pub fn de_ticker_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|product_id| ExchangeSub::from((CoinbaseChannel::TICKER, product_id)).id())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -115,10 +295,12 @@ mod tests {
                     price: 400.23,
                     amount: 5.23512,
                     side: Side::Sell,
-                    time: DateTime::from_utc(
+                    time: DateTime::from_naive_utc_and_offset(
                         NaiveDateTime::from_str("2014-11-07T08:19:27.028459").unwrap(),
                         Utc,
                     ),
+                    maker_order_id: Some("ac928c66-ca53-498f-9c13-a110027a60e8".to_string()),
+                    taker_order_id: Some("132fb6ae-456b-4654-b4e0-d681ac05cea1".to_string()),
                 }),
             },
         ];
@@ -139,4 +321,197 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_coinbase_trade_order_ids() {
+        let input = r#"
+        {
+            "type": "match","trade_id": 10,"sequence": 50,
+            "maker_order_id": "ac928c66-ca53-498f-9c13-a110027a60e8",
+            "taker_order_id": "132fb6ae-456b-4654-b4e0-d681ac05cea1",
+            "time": "2014-11-07T08:19:27.028459Z",
+            "product_id": "BTC-USD", "size": "5.23512", "price": "400.23", "side": "sell"
+        }"#;
+        let trade = serde_json::from_str::<CoinbaseTrade>(input).unwrap();
+
+        let MarketIter(events) = MarketIter::<PublicTrade>::from((
+            ExchangeId::Coinbase,
+            Instrument::from((
+                "btc",
+                "usd",
+                barter_integration::model::instrument::kind::InstrumentKind::Spot,
+            )),
+            trade,
+        ));
+        let actual = events.into_iter().next().unwrap().unwrap().kind.order_ids;
+
+        assert_eq!(
+            actual,
+            TradeOrderIds {
+                maker: Some("ac928c66-ca53-498f-9c13-a110027a60e8".to_string()),
+                taker: Some("132fb6ae-456b-4654-b4e0-d681ac05cea1".to_string()),
+                buyer: None,
+                seller: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_de_coinbase_ticker_trade() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<CoinbaseTickerTrade, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: invalid Coinbase ticker w/o a last trade
+                input: r#"{"type": "ticker", "sequence": 50, "product_id": "ETH-USD", "price": "1285.22"}"#,
+                expected: Err(SocketError::Deserialise {
+                    error: serde_json::Error::custom(""),
+                    payload: "".to_owned(),
+                }),
+            },
+            TestCase {
+                // TC1: valid Spot CoinbaseTickerTrade
+                input: r#"
+                {
+                    "type": "ticker", "sequence": 37475248783, "product_id": "ETH-USD",
+                    "price": "1285.22", "open_24h": "1310.79", "volume_24h": "245532.79269678",
+                    "low_24h": "1280.52", "high_24h": "1313.8", "volume_30d": "9788783.60117027",
+                    "best_bid": "1285.04", "best_bid_size": "0.46688654", "best_ask": "1285.27",
+                    "best_ask_size": "1.56637040", "side": "buy",
+                    "time": "2022-10-19T23:28:22.061769Z", "trade_id": 370843401,
+                    "last_size": "11.4396987"
+                }"#,
+                expected: Ok(CoinbaseTickerTrade {
+                    subscription_id: SubscriptionId::from("ticker|ETH-USD"),
+                    id: 370843401,
+                    price: 1285.22,
+                    amount: 11.4396987,
+                    side: Side::Buy,
+                    time: DateTime::from_naive_utc_and_offset(
+                        NaiveDateTime::from_str("2022-10-19T23:28:22.061769").unwrap(),
+                        Utc,
+                    ),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<CoinbaseTickerTrade>(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_coinbase_match_and_ticker_produce_consistent_public_trades() {
+        let time = "2022-10-19T23:28:22.061769Z";
+        let matched = serde_json::from_str::<CoinbaseTrade>(&format!(
+            r#"{{"type":"match","trade_id":370843401,"sequence":1,"time":"{time}","product_id":"ETH-USD","size":"11.4396987","price":"1285.22","side":"buy"}}"#
+        ))
+        .unwrap();
+        let ticker = serde_json::from_str::<CoinbaseTickerTrade>(&format!(
+            r#"{{"type":"ticker","sequence":1,"product_id":"ETH-USD","price":"1285.22","side":"buy","time":"{time}","trade_id":370843401,"last_size":"11.4396987"}}"#
+        ))
+        .unwrap();
+
+        let instrument = Instrument::from((
+            "eth",
+            "usd",
+            barter_integration::model::instrument::kind::InstrumentKind::Spot,
+        ));
+        let matched =
+            MarketIter::<PublicTrade>::from((ExchangeId::Coinbase, instrument.clone(), matched)).0;
+        let ticker = MarketIter::<PublicTrade>::from((ExchangeId::Coinbase, instrument, ticker)).0;
+
+        let matched = matched.into_iter().next().unwrap().unwrap();
+        let ticker = ticker.into_iter().next().unwrap().unwrap();
+        assert_eq!(matched.exchange_time, ticker.exchange_time);
+        assert_eq!(matched.kind, ticker.kind);
+    }
+
+    #[test]
+    fn test_de_coinbase_feed_trade() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<SubscriptionId>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: "matches" channel match
+                input: r#"{"type":"match","trade_id":10,"sequence":50,"time":"2014-11-07T08:19:27.028459Z","product_id":"BTC-USD","size":"5.23512","price":"400.23","side":"sell"}"#,
+                expected: Some(SubscriptionId::from("matches|BTC-USD")),
+            },
+            TestCase {
+                // TC1: "matches" channel last_match sent upon subscribing
+                input: r#"{"type":"last_match","trade_id":9,"sequence":49,"time":"2014-11-07T08:19:26.028459Z","product_id":"BTC-USD","size":"1.0","price":"400.22","side":"buy"}"#,
+                expected: Some(SubscriptionId::from("matches|BTC-USD")),
+            },
+            TestCase {
+                // TC2: "ticker" channel ticker
+                input: r#"{"type":"ticker","sequence":1,"product_id":"ETH-USD","price":"1285.22","side":"buy","time":"2022-10-19T23:28:22.061769Z","trade_id":370843401,"last_size":"11.4396987"}"#,
+                expected: Some(SubscriptionId::from("ticker|ETH-USD")),
+            },
+            TestCase {
+                // TC3: unrelated message type
+                input: r#"{"type":"heartbeat","sequence":1,"product_id":"ETH-USD"}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<CoinbaseFeedTrade>(test.input)
+                .ok()
+                .and_then(|trade| trade.id());
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+    #[test]
+    fn test_de_coinbase_recent_trades() {
+        let input = r#"
+        [
+            {"time":"2014-11-07T22:19:28.578544Z","trade_id":75,"price":"10.00000000","size":"0.01000000","side":"buy"},
+            {"time":"2014-11-07T01:08:43.642366Z","trade_id":74,"price":"100.00000000","size":"0.01000000","side":"sell"}
+        ]
+        "#;
+
+        let actual = serde_json::from_str::<Vec<CoinbaseRecentTrade>>(input).unwrap();
+        let expected = vec![
+            CoinbaseRecentTrade {
+                id: 75,
+                time: DateTime::from_naive_utc_and_offset(
+                    NaiveDateTime::from_str("2014-11-07T22:19:28.578544").unwrap(),
+                    Utc,
+                ),
+                amount: 0.01,
+                price: 10.0,
+                side: Side::Buy,
+            },
+            CoinbaseRecentTrade {
+                id: 74,
+                time: DateTime::from_naive_utc_and_offset(
+                    NaiveDateTime::from_str("2014-11-07T01:08:43.642366").unwrap(),
+                    Utc,
+                ),
+                amount: 0.01,
+                price: 100.0,
+                side: Side::Sell,
+            },
+        ];
+
+        assert_eq!(actual, expected);
+    }
 }
@@ -1,10 +1,27 @@
+use super::perpetual::{GateioPerpetualsBtc, GateioPerpetualsUsd};
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        derivatives::DerivativesStatistics,
+        trade::PublicTrades,
+        Subscription,
+    },
     Identifier,
 };
 use barter_integration::model::instrument::kind::InstrumentKind;
 use serde::Serialize;
 
+/// Update frequency requested for every [`GateioChannel::FUTURE_ORDER_BOOK_L2`] subscription.
+///
+/// Fixed rather than configurable since [`OrderBooksL2`] carries no parameters, and the
+/// [`GateioPerpetualBookUpdater`](super::perpetual::l2::GateioPerpetualBookUpdater) only
+/// requires a consistent delta sequence, whatever the frequency.
+pub const GATEIO_ORDER_BOOK_L2_FREQUENCY: &str = "100ms";
+
+/// Depth (levels per side) requested for every [`GateioChannel::FUTURE_ORDER_BOOK_L2`]
+/// subscription. Must match the depth of the REST snapshot used to initialise the book.
+pub const GATEIO_ORDER_BOOK_L2_DEPTH: &str = "100";
+
 /// Type that defines how to translate a Barter [`Subscription`] into a
 /// [`Gateio`](super::Gateio) channel to be subscribed to.
 ///
@@ -28,6 +45,22 @@ impl GateioChannel {
     ///
     /// See docs: <https://www.gate.io/docs/developers/options/ws/en/#public-contract-trades-channel>
     pub const OPTION_TRADES: Self = Self("options.trades");
+
+    /// Gateio [`InstrumentKind::Spot`] real-time best bid & ask channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#best-bid-or-ask-price>
+    pub const SPOT_ORDER_BOOK_L1: Self = Self("spot.book_ticker");
+
+    /// Gateio [`InstrumentKind::Perpetual`] OrderBook Level2 deltas channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#order-book-update-subscription>
+    pub const FUTURE_ORDER_BOOK_L2: Self = Self("futures.order_book_update");
+
+    /// Gateio [`InstrumentKind::Perpetual`] ticker channel, including the mark price, index
+    /// price & funding rate.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#tickers-subscription>
+    pub const FUTURE_TICKERS: Self = Self("futures.tickers");
 }
 
 impl<GateioExchange> Identifier<GateioChannel> for Subscription<GateioExchange, PublicTrades> {
@@ -40,6 +73,38 @@ impl<GateioExchange> Identifier<GateioChannel> for Subscription<GateioExchange,
     }
 }
 
+impl<GateioExchange> Identifier<GateioChannel> for Subscription<GateioExchange, OrderBooksL1> {
+    fn id(&self) -> GateioChannel {
+        GateioChannel::SPOT_ORDER_BOOK_L1
+    }
+}
+
+impl Identifier<GateioChannel> for Subscription<GateioPerpetualsUsd, OrderBooksL2> {
+    fn id(&self) -> GateioChannel {
+        GateioChannel::FUTURE_ORDER_BOOK_L2
+    }
+}
+
+impl Identifier<GateioChannel> for Subscription<GateioPerpetualsBtc, OrderBooksL2> {
+    fn id(&self) -> GateioChannel {
+        GateioChannel::FUTURE_ORDER_BOOK_L2
+    }
+}
+
+impl<GateioExchange> Identifier<GateioChannel>
+    for Subscription<GateioExchange, DerivativesStatistics>
+{
+    fn id(&self) -> GateioChannel {
+        GateioChannel::FUTURE_TICKERS
+    }
+}
+
+impl From<&'static str> for GateioChannel {
+    fn from(channel: &'static str) -> Self {
+        Self(channel)
+    }
+}
+
 impl AsRef<str> for GateioChannel {
     fn as_ref(&self) -> &str {
         self.0
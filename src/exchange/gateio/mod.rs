@@ -1,4 +1,8 @@
-use self::{channel::GateioChannel, market::GateioMarket, subscription::GateioSubResponse};
+use self::{
+    channel::{GateioChannel, GATEIO_ORDER_BOOK_L2_DEPTH, GATEIO_ORDER_BOOK_L2_FREQUENCY},
+    market::GateioMarket,
+    subscription::GateioSubResponse,
+};
 use crate::{
     exchange::{subscription::ExchangeSub, Connector, ExchangeId, ExchangeServer},
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
@@ -80,7 +84,15 @@ where
                         "time": chrono::Utc::now().timestamp_millis(),
                         "channel": channel.as_ref(),
                         "event": "subscribe",
-                        "payload": [market.as_ref()]
+                        "payload": match channel {
+                            // OrderBook Level2 deltas require an update frequency & depth
+                            GateioChannel::FUTURE_ORDER_BOOK_L2 => json!([
+                                market.as_ref(),
+                                GATEIO_ORDER_BOOK_L2_FREQUENCY,
+                                GATEIO_ORDER_BOOK_L2_DEPTH
+                            ]),
+                            _ => json!([market.as_ref()]),
+                        }
                     })
                     .to_string(),
                 )
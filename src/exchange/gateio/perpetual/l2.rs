@@ -0,0 +1,498 @@
+use super::super::{channel::GATEIO_ORDER_BOOK_L2_DEPTH, message::GateioMessage};
+use crate::{
+    error::DataError,
+    exchange::subscription::ExchangeSub,
+    subscription::book::{Level, OrderBook, OrderBookSide},
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{instrument::Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// [`GateioPerpetualsUsd`](super::GateioPerpetualsUsd) &
+/// [`GateioPerpetualsBtc`](super::GateioPerpetualsBtc) HTTP OrderBook L2 snapshot base url.
+///
+/// The settlement currency path segment (eg/ "/usdt/order_book") is appended per [`Instrument`].
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#futures-order-book>
+pub const HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_PERPETUALS: &str =
+    "https://api.gateio.ws/api/v4/futures";
+
+/// [`Gateio`](super::super::Gateio) perpetual OrderBook [`Level`].
+///
+/// ### Raw Payload Examples
+/// ```json
+/// {"p": "54672.1", "s": 95}
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioLevel {
+    #[serde(rename = "p", deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(rename = "s")]
+    pub amount: f64,
+}
+
+impl From<GateioLevel> for Level {
+    fn from(level: GateioLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+/// [`Gateio`](super::super::Gateio) perpetual OrderBook Level2 snapshot HTTP message.
+///
+/// Used as the starting [`OrderBook`] before OrderBook Level2 delta WebSocket updates are
+/// applied. Must be requested with `with_id=true` so the snapshot `id` is provided.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#futures-order-book>
+/// ```json
+/// {
+///     "id": 123456,
+///     "current": 1623898993.123,
+///     "update": 1623898993.121,
+///     "asks": [{"p": "1.52", "s": 100}],
+///     "bids": [{"p": "1.17", "s": 150}]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioPerpetualOrderBookL2Snapshot {
+    #[serde(rename = "id")]
+    pub last_update_id: u64,
+    pub bids: Vec<GateioLevel>,
+    pub asks: Vec<GateioLevel>,
+}
+
+impl From<GateioPerpetualOrderBookL2Snapshot> for OrderBook {
+    fn from(snapshot: GateioPerpetualOrderBookL2Snapshot) -> Self {
+        Self {
+            last_update_time: DateTime::default(),
+            bids: OrderBookSide::new(Side::Buy, snapshot.bids),
+            asks: OrderBookSide::new(Side::Sell, snapshot.asks),
+        }
+    }
+}
+
+/// Terse type alias for a [`GateioPerpetualsUsd`](super::GateioPerpetualsUsd) &
+/// [`GateioPerpetualsBtc`](super::GateioPerpetualsBtc) OrderBook Level2 deltas WebSocket message.
+pub type GateioPerpetualOrderBookL2Delta = GateioMessage<GateioPerpetualOrderBookL2DeltaInner>;
+
+/// [`GateioPerpetualsUsd`](super::GateioPerpetualsUsd) &
+/// [`GateioPerpetualsBtc`](super::GateioPerpetualsBtc) OrderBook Level2 deltas WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#order-book-update-subscription>
+/// ```json
+/// {
+///     "time": 1615366381,
+///     "time_ms": 1615366381123,
+///     "channel": "futures.order_book_update",
+///     "event": "update",
+///     "error": null,
+///     "result": {
+///         "t": 1615366381417,
+///         "s": "BTC_USD",
+///         "U": 2517661101,
+///         "u": 2517661113,
+///         "b": [{"p": "54672.1", "s": 0}, {"p": "54664.5", "s": 58794}],
+///         "a": [{"p": "54743.6", "s": 0}, {"p": "54742", "s": 95}]
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioPerpetualOrderBookL2DeltaInner {
+    #[serde(rename = "s")]
+    pub market: String,
+    #[serde(rename = "U")]
+    pub first_update_id: u64,
+    #[serde(rename = "u")]
+    pub last_update_id: u64,
+    #[serde(rename = "b", default)]
+    pub bids: Vec<GateioLevel>,
+    #[serde(rename = "a", default)]
+    pub asks: Vec<GateioLevel>,
+}
+
+impl Identifier<Option<SubscriptionId>> for GateioPerpetualOrderBookL2Delta {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(ExchangeSub::from((&self.channel, &self.data.market)).id())
+    }
+}
+
+/// [`Gateio`](super::super::Gateio) perpetual [`OrderBookUpdater`].
+///
+/// Gateio Futures: How To Maintain A Local OrderBook
+///
+/// 1. Subscribe to the "futures.order_book_update" channel & buffer the updates received.
+/// 2. Get a depth snapshot from <https://api.gateio.ws/api/v4/futures/usdt/order_book?contract=BTC_USDT&limit=100&with_id=true>.
+/// 3. Drop any update where u <= id in the snapshot.
+/// 4. The first processed update should have U <= id+1 AND u >= id+1.
+/// 5. While listening to the stream, each new update's U should be equal to the previous
+///    update's u+1, otherwise there is a gap & the process must re-initialise from step 2.
+/// 6. The data in each update is the absolute quantity for a price level.
+/// 7. If the quantity is 0, remove the price level.
+///
+/// Notes:
+///  - Uppercase U => first_update_id
+///  - Lowercase u => last_update_id
+///  - A detected gap yields a terminal [`DataError::InvalidSequence`], which causes the
+///    [`MarketStream`](crate::MarketStream) to be re-initialised with a fresh snapshot.
+///
+/// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#order-book-update-subscription>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct GateioPerpetualBookUpdater {
+    pub updates_processed: u64,
+    pub last_update_id: u64,
+}
+
+impl GateioPerpetualBookUpdater {
+    /// Construct a new Gateio perpetual [`OrderBookUpdater`] using the provided last_update_id
+    /// from a HTTP snapshot.
+    pub fn new(last_update_id: u64) -> Self {
+        Self {
+            updates_processed: 0,
+            last_update_id,
+        }
+    }
+
+    /// Determines if the next update processed will be the first since the HTTP snapshot.
+    pub fn is_first_update(&self) -> bool {
+        self.updates_processed == 0
+    }
+
+    /// Gateio Futures: How To Maintain A Local OrderBook: Step 4:
+    /// "The first processed update should have U <= id+1 AND u >= id+1"
+    pub fn validate_first_update(
+        &self,
+        update: &GateioPerpetualOrderBookL2DeltaInner,
+    ) -> Result<(), DataError> {
+        let expected_next_id = self.last_update_id + 1;
+        if update.first_update_id <= expected_next_id && update.last_update_id >= expected_next_id {
+            Ok(())
+        } else {
+            Err(DataError::InvalidSequence {
+                prev_last_update_id: self.last_update_id,
+                first_update_id: update.first_update_id,
+            })
+        }
+    }
+
+    /// Gateio Futures: How To Maintain A Local OrderBook: Step 5:
+    /// "Each new update's U should be equal to the previous update's u+1"
+    pub fn validate_next_update(
+        &self,
+        update: &GateioPerpetualOrderBookL2DeltaInner,
+    ) -> Result<(), DataError> {
+        if update.first_update_id == self.last_update_id + 1 {
+            Ok(())
+        } else {
+            Err(DataError::InvalidSequence {
+                prev_last_update_id: self.last_update_id,
+                first_update_id: update.first_update_id,
+            })
+        }
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for GateioPerpetualBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = GateioPerpetualOrderBookL2Delta;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Construct initial OrderBook snapshot GET url
+        let snapshot_url = format!(
+            "{}/{}/order_book?contract={}_{}&limit={}&with_id=true",
+            HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_PERPETUALS,
+            settlement_currency(&instrument),
+            instrument.base.as_ref().to_uppercase(),
+            instrument.quote.as_ref().to_uppercase(),
+            GATEIO_ORDER_BOOK_L2_DEPTH,
+        );
+
+        // Fetch initial OrderBook snapshot via HTTP
+        let snapshot = reqwest::get(snapshot_url)
+            .await
+            .map_err(SocketError::Http)?
+            .json::<GateioPerpetualOrderBookL2Snapshot>()
+            .await
+            .map_err(SocketError::Http)?;
+
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::new(snapshot.last_update_id),
+            book: OrderBook::from(snapshot),
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<Self::OrderBook>, DataError> {
+        // Gateio Futures: How To Maintain A Local OrderBook
+        // See Self's Rust Docs for more information on each numbered step
+        let update = update.data;
+
+        // 3. Drop any update where u <= id in the snapshot:
+        if update.last_update_id <= self.last_update_id {
+            return Ok(None);
+        }
+
+        if self.is_first_update() {
+            // 4. The first processed update should have U <= id+1 AND u >= id+1:
+            self.validate_first_update(&update)?;
+        } else {
+            // 5. Each new update's U should be equal to the previous update's u+1:
+            self.validate_next_update(&update)?;
+        }
+
+        // Update OrderBook metadata & Levels:
+        // 6. The data in each update is the absolute quantity for a price level.
+        // 7. If the quantity is 0, remove the price level.
+        // Update times are not deserialised, so leave the last_update_time unset for the
+        // MarketEvent exchange_time to be synthesized from the received time
+        book.last_update_time = DateTime::default();
+        book.bids.upsert(update.bids);
+        book.asks.upsert(update.asks);
+
+        // Update OrderBookUpdater metadata
+        self.updates_processed += 1;
+        self.last_update_id = update.last_update_id;
+
+        Ok(Some(book.snapshot()))
+    }
+}
+
+/// Determine the Gateio perpetual settlement currency path segment for the provided
+/// [`Instrument`].
+///
+/// eg/ BTC_USDT => "usdt", BTC_USD => "btc"
+fn settlement_currency(instrument: &Instrument) -> &'static str {
+    match instrument.quote.as_ref() {
+        "usdt" => "usdt",
+        _ => "btc",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_gateio_perpetual_order_book_l2_delta() {
+            let input = r#"
+            {
+                "time": 1615366381,
+                "time_ms": 1615366381123,
+                "channel": "futures.order_book_update",
+                "event": "update",
+                "error": null,
+                "result": {
+                    "t": 1615366381417,
+                    "s": "BTC_USD",
+                    "U": 2517661101,
+                    "u": 2517661113,
+                    "b": [{"p": "54672.1", "s": 0}, {"p": "54664.5", "s": 58794}],
+                    "a": [{"p": "54743.6", "s": 0}, {"p": "54742", "s": 95}]
+                }
+            }
+            "#;
+
+            let actual = serde_json::from_str::<GateioPerpetualOrderBookL2Delta>(input).unwrap();
+
+            assert_eq!(
+                actual.data,
+                GateioPerpetualOrderBookL2DeltaInner {
+                    market: "BTC_USD".to_string(),
+                    first_update_id: 2517661101,
+                    last_update_id: 2517661113,
+                    bids: vec![
+                        GateioLevel {
+                            price: 54672.1,
+                            amount: 0.0
+                        },
+                        GateioLevel {
+                            price: 54664.5,
+                            amount: 58794.0
+                        },
+                    ],
+                    asks: vec![
+                        GateioLevel {
+                            price: 54743.6,
+                            amount: 0.0
+                        },
+                        GateioLevel {
+                            price: 54742.0,
+                            amount: 95.0
+                        },
+                    ],
+                }
+            );
+            assert_eq!(
+                actual.id(),
+                Some(SubscriptionId::from("futures.order_book_update|BTC_USD"))
+            );
+        }
+
+        #[test]
+        fn test_gateio_perpetual_order_book_l2_snapshot() {
+            let input = r#"
+            {
+                "id": 123456,
+                "current": 1623898993.123,
+                "update": 1623898993.121,
+                "asks": [{"p": "1.52", "s": 100}],
+                "bids": [{"p": "1.17", "s": 150}]
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<GateioPerpetualOrderBookL2Snapshot>(input).unwrap(),
+                GateioPerpetualOrderBookL2Snapshot {
+                    last_update_id: 123456,
+                    bids: vec![GateioLevel {
+                        price: 1.17,
+                        amount: 150.0
+                    }],
+                    asks: vec![GateioLevel {
+                        price: 1.52,
+                        amount: 100.0
+                    }],
+                }
+            );
+        }
+    }
+
+    mod gateio_perpetual_book_updater {
+        use super::*;
+        use crate::exchange::gateio::channel::GateioChannel;
+        use chrono::Utc;
+
+        fn delta(first_update_id: u64, last_update_id: u64) -> GateioPerpetualOrderBookL2Delta {
+            GateioMessage {
+                channel: GateioChannel::FUTURE_ORDER_BOOK_L2.0.to_string(),
+                error: None,
+                data: GateioPerpetualOrderBookL2DeltaInner {
+                    market: "BTC_USDT".to_string(),
+                    first_update_id,
+                    last_update_id,
+                    bids: vec![GateioLevel {
+                        price: 100.0,
+                        amount: last_update_id as f64,
+                    }],
+                    asks: vec![],
+                },
+            }
+        }
+
+        #[test]
+        fn test_validate_first_update() {
+            struct TestCase {
+                updater: GateioPerpetualBookUpdater,
+                input: GateioPerpetualOrderBookL2Delta,
+                expected: Result<(), DataError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid first update w/ U <= id+1 <= u
+                    updater: GateioPerpetualBookUpdater::new(100),
+                    input: delta(95, 105),
+                    expected: Ok(()),
+                },
+                TestCase {
+                    // TC1: valid first update w/ U == id+1
+                    updater: GateioPerpetualBookUpdater::new(100),
+                    input: delta(101, 101),
+                    expected: Ok(()),
+                },
+                TestCase {
+                    // TC2: invalid first update w/ U > id+1
+                    updater: GateioPerpetualBookUpdater::new(100),
+                    input: delta(102, 110),
+                    expected: Err(DataError::InvalidSequence {
+                        prev_last_update_id: 100,
+                        first_update_id: 102,
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = test.updater.validate_first_update(&test.input.data);
+                match (actual, test.expected) {
+                    (Ok(()), Ok(())) => {
+                        // Test passed
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_update_with_gapped_sequence_requests_resync() {
+            let mut updater = GateioPerpetualBookUpdater::new(100);
+            let mut book = OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, vec![Level::new(100, 1)]),
+                asks: OrderBookSide::new(Side::Sell, vec![Level::new(110, 1)]),
+            };
+
+            // Stale update is dropped
+            assert_eq!(updater.update(&mut book, delta(90, 100)).unwrap(), None);
+
+            // Contiguous updates are applied
+            assert!(updater.update(&mut book, delta(99, 102)).unwrap().is_some());
+            assert!(updater
+                .update(&mut book, delta(103, 105))
+                .unwrap()
+                .is_some());
+            let book_before_gap = book.clone();
+
+            // Gapped update (U=107 != 105+1) requests a resync via terminal DataError
+            let actual = updater.update(&mut book, delta(107, 110));
+            match actual {
+                Err(error) => {
+                    assert!(error.is_terminal());
+                    assert!(matches!(
+                        error,
+                        DataError::InvalidSequence {
+                            prev_last_update_id: 105,
+                            first_update_id: 107,
+                        }
+                    ));
+                }
+                Ok(book) => panic!("expected resync request, but corrupted book served: {book:?}"),
+            }
+
+            // OrderBook & OrderBookUpdater state are not mutated by the gapped update
+            assert_eq!(book, book_before_gap);
+            assert_eq!(updater.last_update_id, 105);
+        }
+    }
+}
@@ -1,12 +1,21 @@
-use self::trade::GateioFuturesTrades;
+use self::{
+    l2::GateioPerpetualBookUpdater, ticker::GateioFuturesTickers, trade::GateioFuturesTrades,
+};
 use super::Gateio;
 use crate::{
     exchange::{ExchangeId, ExchangeServer, StreamSelector},
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
+    subscription::{book::OrderBooksL2, derivatives::DerivativesStatistics, trade::PublicTrades},
+    transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
     ExchangeWsStream,
 };
 
+/// Level 2 OrderBook types and perpetual
+/// [`OrderBookUpdater`](crate::transformer::book::OrderBookUpdater) implementation.
+pub mod l2;
+
+/// Mark price, index price & funding rate "futures.tickers" types.
+pub mod ticker;
+
 /// Public trades types.
 pub mod trade;
 
@@ -34,6 +43,16 @@ impl StreamSelector<PublicTrades> for GateioPerpetualsUsd {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, GateioFuturesTrades>>;
 }
 
+impl StreamSelector<OrderBooksL2> for GateioPerpetualsUsd {
+    type Stream =
+        ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, GateioPerpetualBookUpdater>>;
+}
+
+impl StreamSelector<DerivativesStatistics> for GateioPerpetualsUsd {
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, DerivativesStatistics, GateioFuturesTickers>>;
+}
+
 /// [`GateioPerpetualsBtc`] WebSocket server base url.
 ///
 /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/>
@@ -57,3 +76,13 @@ impl ExchangeServer for GateioServerPerpetualsBtc {
 impl StreamSelector<PublicTrades> for GateioPerpetualsBtc {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, GateioFuturesTrades>>;
 }
+
+impl StreamSelector<OrderBooksL2> for GateioPerpetualsBtc {
+    type Stream =
+        ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, GateioPerpetualBookUpdater>>;
+}
+
+impl StreamSelector<DerivativesStatistics> for GateioPerpetualsBtc {
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, DerivativesStatistics, GateioFuturesTickers>>;
+}
@@ -0,0 +1,171 @@
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::derivatives::DerivativesStats,
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`GateioPerpetualUsdt`](super::GateioPerpetualsUsd) and
+/// [`GateioPerpetualBtc`](super::GateioPerpetualsBtc) real-time "futures.tickers" WebSocket
+/// message, delivering the mark price, index price & funding rate of a perpetual.
+///
+/// ### Notes
+/// Unlike the generic [`GateioMessage<T>`](super::super::message::GateioMessage), the message
+/// "time_ms" is retained since the ticker payload itself carries no timestamp.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#tickers-api>
+/// ```json
+/// {
+///     "time": 1541659086,
+///     "time_ms": 1541659086123,
+///     "channel": "futures.tickers",
+///     "event": "update",
+///     "result": [
+///         {
+///             "contract": "BTC_USD",
+///             "last": "118.4",
+///             "change_percentage": "0.77",
+///             "funding_rate": "-0.000114",
+///             "funding_rate_indicative": "0.01875",
+///             "mark_price": "118.35",
+///             "index_price": "118.36",
+///             "total_size": "73648",
+///             "volume_24h": "745487577",
+///             "low_24h": "99.2",
+///             "high_24h": "132.5"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioFuturesTickers {
+    pub channel: String,
+    #[serde(
+        rename = "time_ms",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "result")]
+    pub data: Vec<GateioFuturesTickerInner>,
+}
+
+/// [`GateioFuturesTickers`] statistics of a single perpetual contract.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioFuturesTickerInner {
+    #[serde(rename = "contract")]
+    pub market: String,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub mark_price: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub index_price: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub funding_rate: f64,
+}
+
+impl Identifier<Option<SubscriptionId>> for GateioFuturesTickers {
+    fn id(&self) -> Option<SubscriptionId> {
+        self.data
+            .first()
+            .map(|ticker| ExchangeSub::from((&self.channel, &ticker.market)).id())
+    }
+}
+
+impl From<(ExchangeId, Instrument, GateioFuturesTickers)> for MarketIter<DerivativesStats> {
+    fn from(
+        (exchange_id, instrument, tickers): (ExchangeId, Instrument, GateioFuturesTickers),
+    ) -> Self {
+        tickers
+            .data
+            .into_iter()
+            .map(|ticker| {
+                Ok(MarketEvent {
+                    exchange_time: tickers.time,
+                    received_time: Utc::now(),
+                    exchange_time_synthesized: false,
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: DerivativesStats {
+                        mark_price: Some(ticker.mark_price),
+                        index_price: Some(ticker.index_price),
+                        funding_rate: Some(ticker.funding_rate),
+                        next_funding_time: None,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::{
+            de::datetime_utc_from_epoch_duration, model::instrument::kind::InstrumentKind,
+        };
+        use std::time::Duration;
+
+        #[test]
+        fn test_gateio_futures_tickers_derivatives_stats() {
+            let input = r#"
+            {
+                "time": 1541659086,
+                "time_ms": 1541659086123,
+                "channel": "futures.tickers",
+                "event": "update",
+                "result": [
+                    {
+                        "contract": "BTC_USD",
+                        "last": "118.4",
+                        "change_percentage": "0.77",
+                        "funding_rate": "-0.000114",
+                        "funding_rate_indicative": "0.01875",
+                        "mark_price": "118.35",
+                        "index_price": "118.36",
+                        "total_size": "73648",
+                        "volume_24h": "745487577",
+                        "low_24h": "99.2",
+                        "high_24h": "132.5"
+                    }
+                ]
+            }
+            "#;
+
+            let actual = serde_json::from_str::<GateioFuturesTickers>(input).unwrap();
+            assert_eq!(
+                actual.id(),
+                Some(SubscriptionId::from("futures.tickers|BTC_USD"))
+            );
+
+            // Mark price, index price & funding rate are extracted from the single ticker
+            let event = MarketIter::<DerivativesStats>::from((
+                ExchangeId::GateioPerpetualsBtc,
+                Instrument::from(("btc", "usd", InstrumentKind::Perpetual)),
+                actual,
+            ))
+            .0
+            .remove(0)
+            .unwrap();
+
+            assert_eq!(
+                event.exchange_time,
+                datetime_utc_from_epoch_duration(Duration::from_millis(1541659086123))
+            );
+            assert_eq!(
+                event.kind,
+                DerivativesStats {
+                    mark_price: Some(118.35),
+                    index_price: Some(118.36),
+                    funding_rate: Some(-0.000114),
+                    next_funding_time: None,
+                }
+            );
+        }
+    }
+}
@@ -2,7 +2,7 @@ use super::super::message::GateioMessage;
 use crate::{
     event::{MarketEvent, MarketIter},
     exchange::{ExchangeId, ExchangeSub},
-    subscription::trade::PublicTrade,
+    subscription::trade::{PublicTrade, SideSource, TradeOrderIds, TradeSource},
     Identifier,
 };
 use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
@@ -82,6 +82,7 @@ impl From<(ExchangeId, Instrument, GateioFuturesTrades)> for MarketIter<PublicTr
                 Ok(MarketEvent {
                     exchange_time: trade.time,
                     received_time: Utc::now(),
+                    exchange_time_synthesized: false,
                     exchange: Exchange::from(exchange_id),
                     instrument: instrument.clone(),
                     kind: PublicTrade {
@@ -93,6 +94,10 @@ impl From<(ExchangeId, Instrument, GateioFuturesTrades)> for MarketIter<PublicTr
                         } else {
                             Side::Sell
                         },
+                        source: TradeSource::Live,
+                        side_source: SideSource::Exchange,
+                        order_ids: TradeOrderIds::default(),
+                        aggregate_count: None,
                     },
                 })
             })
@@ -0,0 +1,196 @@
+use super::super::message::GateioMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::book::{Level, OrderBookL1},
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`GateioSpot`](super::GateioSpot) real-time best bid & ask WebSocket
+/// message.
+pub type GateioSpotOrderBookL1 = GateioMessage<GateioSpotOrderBookL1Inner>;
+
+/// [`GateioSpot`](super::GateioSpot) real-time "spot.book_ticker" best bid & ask message.
+///
+/// ### Notes
+/// The "u" update id is carried through so consecutive updates of the same market can be
+/// ordered. Gateio only pushes a "spot.book_ticker" update when the best bid or ask changes, so
+/// the update ids are increasing but not contiguous.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#best-bid-or-ask-price>
+/// ```json
+/// {
+///     "t": 1606293275123,
+///     "u": 48733182,
+///     "s": "BTC_USDT",
+///     "b": "19177.79",
+///     "B": "0.0003341504",
+///     "a": "19179.38",
+///     "A": "0.09"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioSpotOrderBookL1Inner {
+    #[serde(rename = "s")]
+    pub market: String,
+    #[serde(
+        rename = "t",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    #[serde(rename = "b", deserialize_with = "barter_integration::de::de_str")]
+    pub best_bid_price: f64,
+    #[serde(rename = "B", deserialize_with = "barter_integration::de::de_str")]
+    pub best_bid_amount: f64,
+    #[serde(rename = "a", deserialize_with = "barter_integration::de::de_str")]
+    pub best_ask_price: f64,
+    #[serde(rename = "A", deserialize_with = "barter_integration::de::de_str")]
+    pub best_ask_amount: f64,
+}
+
+impl Identifier<Option<SubscriptionId>> for GateioSpotOrderBookL1 {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(ExchangeSub::from((&self.channel, &self.data.market)).id())
+    }
+}
+
+impl From<(ExchangeId, Instrument, GateioSpotOrderBookL1)> for MarketIter<OrderBookL1> {
+    fn from(
+        (exchange_id, instrument, book): (ExchangeId, Instrument, GateioSpotOrderBookL1),
+    ) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: book.data.time,
+            received_time: Utc::now(),
+            exchange_time_synthesized: false,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: OrderBookL1 {
+                last_update_time: book.data.time,
+                sequence: Some(book.data.update_id),
+                best_bid: Level::new(book.data.best_bid_price, book.data.best_bid_amount),
+                best_ask: Level::new(book.data.best_ask_price, book.data.best_ask_amount),
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::{
+        de::datetime_utc_from_epoch_duration, model::instrument::kind::InstrumentKind,
+    };
+    use std::time::Duration;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_gateio_message_spot_book_ticker() {
+            let input = r#"
+            {
+                "time": 1606293275,
+                "time_ms": 1606293275723,
+                "channel": "spot.book_ticker",
+                "event": "update",
+                "result": {
+                    "t": 1606293275123,
+                    "u": 48733182,
+                    "s": "BTC_USDT",
+                    "b": "19177.79",
+                    "B": "0.0003341504",
+                    "a": "19179.38",
+                    "A": "0.09"
+                }
+            }
+            "#;
+
+            let actual = serde_json::from_str::<GateioSpotOrderBookL1>(input).unwrap();
+
+            assert_eq!(
+                actual.id(),
+                Some(SubscriptionId::from("spot.book_ticker|BTC_USDT"))
+            );
+            assert_eq!(
+                actual.data,
+                GateioSpotOrderBookL1Inner {
+                    market: "BTC_USDT".to_string(),
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1606293275123)),
+                    update_id: 48733182,
+                    best_bid_price: 19177.79,
+                    best_bid_amount: 0.0003341504,
+                    best_ask_price: 19179.38,
+                    best_ask_amount: 0.09,
+                }
+            );
+        }
+    }
+    #[test]
+    fn test_gateio_spot_book_ticker_sequence_gap() {
+        fn book_l1(update_id: u64) -> OrderBookL1 {
+            let input = format!(
+                r#"{{"time":1606293275,"time_ms":1606293275723,"channel":"spot.book_ticker","event":"update","result":{{"t":1606293275123,"u":{update_id},"s":"BTC_USDT","b":"19177.79","B":"0.0003341504","a":"19179.38","A":"0.09"}}}}"#
+            );
+            let book = serde_json::from_str::<GateioSpotOrderBookL1>(&input).unwrap();
+
+            let MarketIter(mut events) = MarketIter::<OrderBookL1>::from((
+                ExchangeId::GateioSpot,
+                Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                book,
+            ));
+            events.remove(0).unwrap().kind
+        }
+
+        struct TestCase {
+            previous: u64,
+            next: u64,
+            expected: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: contiguous update id is in sequence
+                previous: 48733182,
+                next: 48733183,
+                expected: false,
+            },
+            TestCase {
+                // TC1: gap in update ids is in sequence, since only best bid & ask changes are pushed
+                previous: 48733182,
+                next: 48733190,
+                expected: false,
+            },
+            TestCase {
+                // TC2: duplicate update id is out of sequence
+                previous: 48733182,
+                next: 48733182,
+                expected: true,
+            },
+            TestCase {
+                // TC3: stale update id is out of sequence
+                previous: 48733182,
+                next: 48733100,
+                expected: true,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let previous = book_l1(test.previous);
+            let next = book_l1(test.next);
+            assert_eq!(previous.sequence, Some(test.previous), "TC{} failed", index);
+            assert_eq!(next.sequence, Some(test.next), "TC{} failed", index);
+            assert_eq!(
+                next.is_out_of_sequence(&previous),
+                test.expected,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
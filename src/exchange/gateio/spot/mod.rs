@@ -1,13 +1,16 @@
-use self::trade::GateioSpotTrade;
+use self::{l1::GateioSpotOrderBookL1, trade::GateioSpotTrade};
 use super::Gateio;
 use crate::{
     exchange::{ExchangeId, ExchangeServer, StreamSelector},
-    subscription::trade::PublicTrades,
+    subscription::{book::OrderBooksL1, trade::PublicTrades},
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream,
 };
 use barter_macro::{DeExchange, SerExchange};
 
+/// Level 1 OrderBook types.
+pub mod l1;
+
 /// Public trades types.
 pub mod trade;
 
@@ -36,3 +39,7 @@ impl ExchangeServer for GateioServerSpot {
 impl StreamSelector<PublicTrades> for GateioSpot {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, GateioSpotTrade>>;
 }
+
+impl StreamSelector<OrderBooksL1> for GateioSpot {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, GateioSpotOrderBookL1>>;
+}
@@ -2,7 +2,7 @@ use super::super::message::GateioMessage;
 use crate::{
     event::{MarketEvent, MarketIter},
     exchange::{ExchangeId, ExchangeSub},
-    subscription::trade::PublicTrade,
+    subscription::trade::{PublicTrade, SideSource, TradeOrderIds, TradeSource},
     Identifier,
 };
 use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
@@ -33,7 +33,7 @@ pub struct GateioSpotTradeInner {
     pub market: String,
     #[serde(
         rename = "create_time_ms",
-        deserialize_with = "barter_integration::de::de_str_f64_epoch_ms_as_datetime_utc"
+        deserialize_with = "crate::exchange::timestamp::de_str_f64_epoch_ms_as_datetime_utc"
     )]
     pub time: DateTime<Utc>,
     pub id: u64,
@@ -58,6 +58,7 @@ impl From<(ExchangeId, Instrument, GateioSpotTrade)> for MarketIter<PublicTrade>
         Self(vec![Ok(MarketEvent {
             exchange_time: trade.data.time,
             received_time: Utc::now(),
+            exchange_time_synthesized: false,
             exchange: Exchange::from(exchange_id),
             instrument,
             kind: PublicTrade {
@@ -65,6 +66,10 @@ impl From<(ExchangeId, Instrument, GateioSpotTrade)> for MarketIter<PublicTrade>
                 price: trade.data.price,
                 amount: trade.data.amount,
                 side: trade.data.side,
+                source: TradeSource::Live,
+                side_source: SideSource::Exchange,
+                order_ids: TradeOrderIds::default(),
+                aggregate_count: None,
             },
         })])
     }
@@ -59,10 +59,12 @@ impl From<(ExchangeId, Instrument, KrakenOrderBookL1)> for MarketIter<OrderBookL
             KrakenOrderBookL1::Data(book) => Self(vec![Ok(MarketEvent {
                 exchange_time: book.spread.time,
                 received_time: Utc::now(),
+                exchange_time_synthesized: false,
                 exchange: Exchange::from(exchange_id),
                 instrument,
                 kind: OrderBookL1 {
                     last_update_time: book.spread.time,
+                    sequence: None,
                     best_bid: Level::new(book.spread.best_bid_price, book.spread.best_bid_amount),
                     best_ask: Level::new(book.spread.best_ask_price, book.spread.best_ask_amount),
                 },
@@ -1,6 +1,6 @@
 use super::Kraken;
 use crate::{
-    subscription::{book::OrderBooksL1, trade::PublicTrades, Subscription},
+    subscription::{book::OrderBooksL1, ticker::Tickers, trade::PublicTrades, Subscription},
     Identifier,
 };
 use serde::Serialize;
@@ -22,6 +22,11 @@ impl KrakenChannel {
     ///
     /// See docs: <https://docs.kraken.com/websockets/#message-subscribe>
     pub const ORDER_BOOK_L1: Self = Self("spread");
+
+    /// [`Kraken`] real-time ticker channel name.
+    ///
+    /// See docs: <https://docs.kraken.com/websockets/#message-subscribe>
+    pub const TICKER: Self = Self("ticker");
 }
 
 impl Identifier<KrakenChannel> for Subscription<Kraken, PublicTrades> {
@@ -36,6 +41,18 @@ impl Identifier<KrakenChannel> for Subscription<Kraken, OrderBooksL1> {
     }
 }
 
+impl Identifier<KrakenChannel> for Subscription<Kraken, Tickers> {
+    fn id(&self) -> KrakenChannel {
+        KrakenChannel::TICKER
+    }
+}
+
+impl From<&'static str> for KrakenChannel {
+    fn from(channel: &'static str) -> Self {
+        Self(channel)
+    }
+}
+
 impl AsRef<str> for KrakenChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -1,4 +1,4 @@
-use super::Kraken;
+use super::{v2::KrakenV2, Kraken};
 use crate::{subscription::Subscription, Identifier};
 use serde::{Deserialize, Serialize};
 
@@ -15,6 +15,12 @@ impl<Kind> Identifier<KrakenMarket> for Subscription<Kraken, Kind> {
     }
 }
 
+impl<Kind> Identifier<KrakenMarket> for Subscription<KrakenV2, Kind> {
+    fn id(&self) -> KrakenMarket {
+        KrakenMarket(format!("{}/{}", self.instrument.base, self.instrument.quote).to_uppercase())
+    }
+}
+
 impl AsRef<str> for KrakenMarket {
     fn as_ref(&self) -> &str {
         &self.0
@@ -1,4 +1,4 @@
-use crate::Identifier;
+use crate::{streams::lifecycle::ExchangeStatus, Identifier};
 use barter_integration::model::SubscriptionId;
 use serde::{Deserialize, Serialize};
 
@@ -61,6 +61,17 @@ use serde::{Deserialize, Serialize};
 /// }
 /// ```
 ///
+/// #### SystemStatus
+/// See docs: <https://docs.kraken.com/websockets/#message-systemStatus>
+/// ```json
+/// {
+///   "connectionID": 8628615390848610000,
+///   "event": "systemStatus",
+///   "status": "online",
+///   "version": "1.0.0"
+/// }
+/// ```
+///
 /// #### KrakenError Generic
 /// See docs: <https://docs.kraken.com/websockets/#errortypes>
 /// ```json
@@ -88,6 +99,16 @@ where
     }
 }
 
+impl<T> KrakenMessage<T> {
+    /// [`KrakenSystemStatus`] of the message, if it is a [`KrakenEvent::SystemStatus`].
+    pub fn system_status(&self) -> Option<&KrakenSystemStatus> {
+        match self {
+            Self::Event(KrakenEvent::SystemStatus(status)) => Some(status),
+            _ => None,
+        }
+    }
+}
+
 /// [`Kraken`](super::Kraken) messages received over the WebSocket which are not subscription data.
 ///
 /// eg/ [`Kraken`](super::Kraken) sends a [`KrakenEvent::Heartbeat`] if no subscription traffic
@@ -100,9 +121,25 @@ where
 #[serde(tag = "event", rename_all = "camelCase")]
 pub enum KrakenEvent {
     Heartbeat,
+    SystemStatus(KrakenSystemStatus),
     Error(KrakenError),
 }
 
+/// [`Kraken`](super::Kraken) system status sent on connect & whenever the status changes (eg/
+/// during maintenance or an incident).
+///
+/// See [`KrakenMessage`] for full raw payload examples.
+///
+/// See docs: <https://docs.kraken.com/websockets/#message-systemStatus>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct KrakenSystemStatus {
+    pub status: ExchangeStatus,
+    #[serde(alias = "connectionID", default)]
+    pub connection_id: Option<u64>,
+    #[serde(default)]
+    pub version: Option<String>,
+}
+
 /// [`Kraken`](super::Kraken) generic error message String received over the WebSocket.
 ///
 /// Note that since the [`KrakenError`] is only made up of a renamed message String field, it can
@@ -147,6 +184,25 @@ mod tests {
                         message: "Malformed request".to_string(),
                     }))),
                 },
+                TestCase {
+                    // TC2: valid KrakenTrades::Event(KrakenEvent::SystemStatus(KrakenSystemStatus))
+                    input: r#"{"connectionID": 8628615390848610000, "event": "systemStatus", "status": "maintenance", "version": "1.0.0"}"#,
+                    expected: Ok(KrakenMessage::Event(KrakenEvent::SystemStatus(
+                        KrakenSystemStatus {
+                            status: ExchangeStatus::Maintenance,
+                            connection_id: Some(8628615390848610000),
+                            version: Some("1.0.0".to_string()),
+                        },
+                    ))),
+                },
+                TestCase {
+                    // TC3: invalid KrakenSystemStatus with unknown status
+                    input: r#"{"event": "systemStatus", "status": "unknown"}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
             ];
 
             for (index, test) in tests.into_iter().enumerate() {
@@ -1,11 +1,12 @@
 use self::{
     book::l1::KrakenOrderBookL1, channel::KrakenChannel, market::KrakenMarket,
-    message::KrakenMessage, subscription::KrakenSubResponse, trade::KrakenTrades,
+    message::KrakenMessage, status::KrakenStatusTransformer, subscription::KrakenSubResponse,
+    ticker::KrakenTicker, trade::KrakenTrades,
 };
 use crate::{
-    exchange::{Connector, ExchangeId, ExchangeSub, StreamSelector},
+    exchange::{next_request_id, Connector, ExchangeId, ExchangeSub, StreamSelector},
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{book::OrderBooksL1, trade::PublicTrades},
+    subscription::{book::OrderBooksL1, ticker::Tickers, trade::PublicTrades},
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream,
 };
@@ -28,18 +29,33 @@ pub mod market;
 /// [`KrakenMessage`](message::KrakenMessage) type for [`Kraken`].
 pub mod message;
 
+/// [`Transformer`](barter_integration::Transformer) wrapper surfacing [`Kraken`] system status
+/// messages.
+pub mod status;
+
 /// [`Subscription`](crate::subscription::Subscription) response type and response
 /// [`Validator`](barter_integration) for [`Kraken`].
 pub mod subscription;
 
+/// Ticker types for [`Kraken`].
+pub mod ticker;
+
 /// Public trade types for [`Kraken`].
 pub mod trade;
 
+/// [`KrakenV2`](v2::KrakenV2) exchange using the Kraken v2 WebSocket API.
+pub mod v2;
+
 /// [`Kraken`] server base url.
 ///
 /// See docs: <https://docs.kraken.com/websockets/#overview>
 pub const BASE_URL_KRAKEN: &str = "wss://ws.kraken.com/";
 
+/// [`Kraken`] beta sandbox server base url.
+///
+/// See docs: <https://docs.kraken.com/websockets/#connectionDetails>
+pub const BASE_URL_KRAKEN_SANDBOX: &str = "wss://beta-ws.kraken.com/";
+
 /// [`Kraken`] exchange.
 ///
 /// See docs: <https://docs.kraken.com/websockets/#overview>
@@ -60,6 +76,10 @@ impl Connector for Kraken {
         Url::parse(BASE_URL_KRAKEN).map_err(SocketError::UrlParse)
     }
 
+    fn sandbox_url() -> Result<Url, SocketError> {
+        Url::parse(BASE_URL_KRAKEN_SANDBOX).map_err(SocketError::UrlParse)
+    }
+
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
         exchange_subs
             .into_iter()
@@ -67,6 +87,7 @@ impl Connector for Kraken {
                 WsMessage::Text(
                     json!({
                         "event": "subscribe",
+                        "reqid": next_request_id(),
                         "pair": [market.as_ref()],
                         "subscription": {
                             "name": channel.as_ref()
@@ -77,12 +98,26 @@ impl Connector for Kraken {
             })
             .collect()
     }
+
+    fn request_id_field() -> Option<&'static str> {
+        Some("reqid")
+    }
 }
 
 impl StreamSelector<PublicTrades> for Kraken {
-    type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, KrakenTrades>>;
+    type Stream = ExchangeWsStream<
+        KrakenStatusTransformer<StatelessTransformer<Self, PublicTrades, KrakenTrades>>,
+    >;
 }
 
 impl StreamSelector<OrderBooksL1> for Kraken {
-    type Stream = ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, KrakenOrderBookL1>>;
+    type Stream = ExchangeWsStream<
+        KrakenStatusTransformer<StatelessTransformer<Self, OrderBooksL1, KrakenOrderBookL1>>,
+    >;
+}
+
+impl StreamSelector<Tickers> for Kraken {
+    type Stream = ExchangeWsStream<
+        KrakenStatusTransformer<StatelessTransformer<Self, Tickers, KrakenTicker>>,
+    >;
 }
@@ -0,0 +1,317 @@
+use super::message::KrakenMessage;
+use crate::{
+    error::DataError,
+    exchange::ExchangeId,
+    subscriber::config::ConnectionConfig,
+    subscription::{Map, SubKind},
+    transformer::ExchangeTransformer,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::instrument::Instrument, protocol::websocket::WsMessage, Transformer,
+};
+use serde::Deserialize;
+use tokio::sync::mpsc;
+
+/// [`Transformer`] wrapper that surfaces every [`Kraken`](super::Kraken)
+/// [`KrakenSystemStatus`](super::message::KrakenSystemStatus) as a
+/// [`DataError::ExchangeStatus`] before passing any other message to the `Inner`
+/// [`Transformer`].
+///
+/// The consumer loop pauses re-connection if the status is
+/// [`ExchangeStatus::Maintenance`](crate::streams::lifecycle::ExchangeStatus::Maintenance), and
+/// otherwise emits a [`LifecycleEvent::Status`](crate::streams::lifecycle::LifecycleEvent::Status).
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct KrakenStatusTransformer<Inner> {
+    pub inner: Inner,
+}
+
+impl<Inner> KrakenStatusTransformer<Inner> {
+    /// Construct a new [`KrakenStatusTransformer`] wrapping the provided `Inner` [`Transformer`].
+    pub fn new(inner: Inner) -> Self {
+        Self { inner }
+    }
+}
+
+impl<Inner, T> Transformer for KrakenStatusTransformer<Inner>
+where
+    Inner: Transformer<Input = KrakenMessage<T>, Error = DataError>,
+    T: for<'de> Deserialize<'de>,
+{
+    type Error = DataError;
+    type Input = Inner::Input;
+    type Output = Inner::Output;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        if let Some(system_status) = input.system_status() {
+            return vec![Err(DataError::ExchangeStatus {
+                exchange: ExchangeId::Kraken,
+                status: system_status.status,
+            })];
+        }
+
+        self.inner.transform(input).into_iter().collect()
+    }
+}
+
+#[async_trait]
+impl<Exchange, Kind, Inner, T> ExchangeTransformer<Exchange, Kind>
+    for KrakenStatusTransformer<Inner>
+where
+    Exchange: Send,
+    Kind: SubKind + Send,
+    Inner: ExchangeTransformer<Exchange, Kind, Input = KrakenMessage<T>> + Send,
+    T: for<'de> Deserialize<'de>,
+{
+    async fn new(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+    ) -> Result<Self, DataError> {
+        Inner::new(ws_sink_tx, instrument_map).await.map(Self::new)
+    }
+
+    fn configure(&mut self, config: &ConnectionConfig) {
+        self.inner.configure(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{kraken::Kraken, Connector},
+        streams::{
+            consumer::{consume, LagPolicy},
+            health::HealthMonitor,
+            lifecycle::{ExchangeStatus, LifecycleEvent},
+            reconnect::ReconnectPolicy,
+        },
+        subscription::{trade::PublicTrades, Subscription},
+        test_util::{MockExchange, MockScript},
+        transformer::stateless::StatelessTransformer,
+    };
+    use barter_integration::model::{instrument::kind::InstrumentKind, SubscriptionId};
+    use std::{collections::HashMap, sync::Arc, time::Duration};
+
+    const ACK: &str = r#"{"channelID":0,"channelName":"trade","event":"subscriptionStatus","pair":"XBT/USD","status":"subscribed","subscription":{"name":"trade"}}"#;
+
+    fn system_status(status: &str) -> String {
+        format!(
+            r#"{{"connectionID":8628615390848610000,"event":"systemStatus","status":"{status}","version":"1.0.0"}}"#
+        )
+    }
+
+    #[tokio::test]
+    async fn test_kraken_status_transformer() {
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+        let mut transformer = <KrakenStatusTransformer<
+            StatelessTransformer<Kraken, PublicTrades, super::super::trade::KrakenTrades>,
+        > as ExchangeTransformer<Kraken, PublicTrades>>::new(
+            ws_sink_tx,
+            Map(HashMap::from([(
+                SubscriptionId::from("trade|XBT/USD"),
+                Instrument::from(("xbt", "usd", InstrumentKind::Spot)),
+            )])),
+        )
+        .await
+        .unwrap();
+
+        struct TestCase {
+            input: String,
+            expected: Option<(ExchangeStatus, bool)>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: maintenance systemStatus is a maintenance signal
+                input: system_status("maintenance"),
+                expected: Some((ExchangeStatus::Maintenance, true)),
+            },
+            TestCase {
+                // TC1: cancel_only systemStatus is captured but is not a maintenance signal
+                input: system_status("cancel_only"),
+                expected: Some((ExchangeStatus::CancelOnly, false)),
+            },
+            TestCase {
+                // TC2: trade is passed to the Inner Transformer
+                input: r#"[0,[["5541.20000","0.15850568","1534614057.321597","s","l",""]],"trade","XBT/USD"]"#.to_string(),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let input = serde_json::from_str(&test.input).unwrap();
+            let mut output = transformer.transform(input);
+            assert_eq!(output.len(), 1, "TC{} failed", index);
+
+            match (output.remove(0), test.expected) {
+                (Err(error), Some((expected_status, expected_maintenance))) => {
+                    assert!(
+                        matches!(
+                            error,
+                            DataError::ExchangeStatus {
+                                exchange: ExchangeId::Kraken,
+                                status,
+                            } if status == expected_status
+                        ),
+                        "TC{} failed",
+                        index
+                    );
+                    assert_eq!(
+                        Kraken::is_maintenance(&error),
+                        expected_maintenance,
+                        "TC{} failed",
+                        index
+                    );
+                }
+                (Ok(trade), None) => assert_eq!(trade.kind.price, 5541.2, "TC{} failed", index),
+                (actual, expected) => {
+                    panic!("TC{index} failed. \nActual: {actual:?}\nExpected: {expected:?}\n")
+                }
+            }
+        }
+    }
+
+    /// [`ReconnectPolicy`] with a short maintenance delay.
+    #[derive(Debug)]
+    struct ShortMaintenance;
+
+    impl ReconnectPolicy for ShortMaintenance {
+        fn next_delay(&self, _: u32, _: Option<&DataError>) -> Option<Duration> {
+            None
+        }
+
+        fn maintenance_delay(&self) -> Duration {
+            Duration::from_millis(10)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_consume_kraken_system_status() {
+        let exchange = MockExchange::start([
+            MockScript::new()
+                .receive()
+                .send_text(ACK)
+                .send_text(system_status("cancel_only"))
+                .send_text(system_status("maintenance")),
+            MockScript::new()
+                .receive()
+                .send_text(ACK)
+                .send_text(system_status("online")),
+        ])
+        .await
+        .unwrap();
+
+        let (lifecycle_tx, mut lifecycle_rx) = mpsc::unbounded_channel();
+        let (exchange_tx, _exchange_rx) = mpsc::channel(1);
+        let consumer = tokio::spawn(consume(
+            vec![Subscription::from((
+                Kraken,
+                "xbt",
+                "usd",
+                InstrumentKind::Spot,
+                PublicTrades,
+            ))],
+            ConnectionConfig::default().url(exchange.url()),
+            Arc::new(ShortMaintenance),
+            Some(lifecycle_tx),
+            HealthMonitor::default().register(ExchangeId::Kraken),
+            false,
+            exchange_tx,
+        ));
+
+        let mut actual = vec![];
+        for _ in 0..3 {
+            actual.push(
+                tokio::time::timeout(Duration::from_secs(5), lifecycle_rx.recv())
+                    .await
+                    .unwrap()
+                    .unwrap(),
+            );
+        }
+
+        // Maintenance status pauses re-connection instead of emitting a LifecycleEvent::Status
+        assert_eq!(
+            actual,
+            vec![
+                LifecycleEvent::Status {
+                    exchange: ExchangeId::Kraken,
+                    status: ExchangeStatus::CancelOnly,
+                },
+                LifecycleEvent::Maintenance {
+                    exchange: ExchangeId::Kraken,
+                },
+                LifecycleEvent::Status {
+                    exchange: ExchangeId::Kraken,
+                    status: ExchangeStatus::Online,
+                },
+            ]
+        );
+        assert_eq!(exchange.connections(), 2);
+
+        consumer.abort();
+    }
+
+    #[tokio::test]
+    async fn test_consume_kraken_system_status_while_receiver_stalled() {
+        let trade =
+            r#"[0,[["5541.20000","0.15850568","1534614057.321597","s","l",""]],"trade","XBT/USD"]"#;
+        let exchange = MockExchange::start([MockScript::new()
+            .receive()
+            .send_text(ACK)
+            .send_text(trade)
+            .send_text(trade)
+            .send_text(trade)
+            .send_text(system_status("cancel_only"))])
+        .await
+        .unwrap();
+
+        // Receiver is never polled, so the ExchangeChannel is full after the first trade
+        let (lifecycle_tx, mut lifecycle_rx) = mpsc::unbounded_channel();
+        let (exchange_tx, _exchange_rx) = mpsc::channel(1);
+        let consumer = tokio::spawn(consume(
+            vec![Subscription::from((
+                Kraken,
+                "xbt",
+                "usd",
+                InstrumentKind::Spot,
+                PublicTrades,
+            ))],
+            ConnectionConfig::default()
+                .url(exchange.url())
+                .lag_policy(LagPolicy::DropNewest),
+            Arc::new(ShortMaintenance),
+            Some(lifecycle_tx),
+            HealthMonitor::default().register(ExchangeId::Kraken),
+            false,
+            exchange_tx,
+        ));
+
+        let mut actual = vec![];
+        for _ in 0..2 {
+            actual.push(
+                tokio::time::timeout(Duration::from_secs(5), lifecycle_rx.recv())
+                    .await
+                    .unwrap()
+                    .unwrap(),
+            );
+        }
+
+        // Lagging is reported once, & the read loop keeps consuming the status feed
+        assert_eq!(
+            actual,
+            vec![
+                LifecycleEvent::Lagged {
+                    exchange: ExchangeId::Kraken,
+                },
+                LifecycleEvent::Status {
+                    exchange: ExchangeId::Kraken,
+                    status: ExchangeStatus::CancelOnly,
+                },
+            ]
+        );
+
+        consumer.abort();
+    }
+}
@@ -0,0 +1,347 @@
+use super::KrakenMessage;
+use crate::{
+    event::{exchange_time_or_received, MarketEvent, MarketIter},
+    exchange::{kraken::channel::KrakenChannel, subscription::ExchangeSub, ExchangeId},
+    subscription::{book::Level, ticker::Ticker},
+    Identifier,
+};
+use barter_integration::{
+    de::extract_next,
+    model::{instrument::Instrument, Exchange, SubscriptionId},
+};
+use chrono::Utc;
+use serde::{de::IgnoredAny, Deserialize, Serialize};
+
+/// Terse type alias for an [`Kraken`](super::Kraken) real-time ticker WebSocket message.
+pub type KrakenTicker = KrakenMessage<KrakenTickerInner>;
+
+/// [`Kraken`](super::Kraken) real-time ticker data and the associated [`SubscriptionId`].
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.kraken.com/websockets/#message-ticker>
+/// ```json
+/// [
+///     0,
+///     {
+///         "a": ["5525.40000", 1, "1.000"],
+///         "b": ["5525.10000", 1, "1.000"],
+///         "c": ["5525.10000", "0.00398963"],
+///         "v": ["2634.11501494", "3591.17907851"],
+///         "p": ["5631.44067", "5653.78939"],
+///         "t": [11493, 16267],
+///         "l": ["5505.00000", "5505.00000"],
+///         "h": ["5783.00000", "5783.00000"],
+///         "o": ["5760.70000", "5763.40000"]
+///     },
+///     "ticker",
+///     "XBT/USD"
+/// ]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct KrakenTickerInner {
+    pub subscription_id: SubscriptionId,
+    pub ticker: KrakenTickerData,
+}
+
+/// [`Kraken`](super::Kraken) ticker fields.
+///
+/// Each statistic is sent as a \[TODAY, LAST_24_HOURS\] pair, of which only the rolling 24 hour
+/// value is used.
+///
+/// See docs: <https://docs.kraken.com/websockets/#message-ticker>
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KrakenTickerData {
+    #[serde(rename = "a", deserialize_with = "de_level_with_whole_lot")]
+    pub best_ask: Level,
+    #[serde(rename = "b", deserialize_with = "de_level_with_whole_lot")]
+    pub best_bid: Level,
+    #[serde(rename = "c", deserialize_with = "de_level")]
+    pub last_trade: Level,
+    #[serde(rename = "v", deserialize_with = "de_last_24h")]
+    pub volume_24h: f64,
+    #[serde(rename = "p", deserialize_with = "de_last_24h")]
+    pub vwap_24h: f64,
+    #[serde(rename = "t", deserialize_with = "de_trade_count_24h")]
+    pub trade_count_24h: u64,
+    #[serde(rename = "l", deserialize_with = "de_last_24h")]
+    pub low_24h: f64,
+    #[serde(rename = "h", deserialize_with = "de_last_24h")]
+    pub high_24h: f64,
+    #[serde(rename = "o", deserialize_with = "de_last_24h")]
+    pub open_24h: f64,
+}
+
+impl Identifier<Option<SubscriptionId>> for KrakenTickerInner {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl From<(ExchangeId, Instrument, KrakenTicker)> for MarketIter<Ticker> {
+    fn from((exchange_id, instrument, ticker): (ExchangeId, Instrument, KrakenTicker)) -> Self {
+        match ticker {
+            // Kraken tickers do not contain a timestamp, so use the time received
+            KrakenTicker::Data(ticker) => {
+                let received_time = Utc::now();
+                let (exchange_time, exchange_time_synthesized) =
+                    exchange_time_or_received(None, received_time);
+                Self(vec![Ok(MarketEvent {
+                    exchange_time,
+                    received_time,
+                    exchange_time_synthesized,
+                    exchange: Exchange::from(exchange_id),
+                    instrument,
+                    kind: Ticker {
+                        best_bid: ticker.ticker.best_bid,
+                        best_ask: ticker.ticker.best_ask,
+                        last_price: ticker.ticker.last_trade.price,
+                        last_amount: ticker.ticker.last_trade.amount,
+                        open_24h: ticker.ticker.open_24h,
+                        high_24h: ticker.ticker.high_24h,
+                        low_24h: ticker.ticker.low_24h,
+                        volume_24h: ticker.ticker.volume_24h,
+                        vwap_24h: Some(ticker.ticker.vwap_24h),
+                        trade_count_24h: Some(ticker.ticker.trade_count_24h),
+                    },
+                })])
+            }
+            KrakenTicker::Event(_) => MarketIter(vec![]),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for KrakenTickerInner {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = KrakenTickerInner;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("KrakenTickerInner struct from the Kraken WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // KrakenTickerInner Sequence Format:
+                // [channelID, {a, b, c, v, p, t, l, h, o}, channelName, pair]
+                // <https://docs.kraken.com/websockets/#message-ticker>
+
+                // Extract deprecated channelID & ignore
+                let _: IgnoredAny = extract_next(&mut seq, "channelID")?;
+
+                // Extract ticker
+                let ticker = extract_next(&mut seq, "ticker")?;
+
+                // Extract channelName (eg/ "ticker") & ignore
+                let _: IgnoredAny = extract_next(&mut seq, "channelName")?;
+
+                // Extract pair (eg/ "XBT/USD") & map to SubscriptionId (ie/ "ticker|{pair}")
+                let subscription_id = extract_next::<SeqAccessor, String>(&mut seq, "pair")
+                    .map(|market| ExchangeSub::from((KrakenChannel::TICKER, market)).id())?;
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Exchange may add fields without warning
+                while seq.next_element::<IgnoredAny>()?.is_some() {}
+
+                Ok(KrakenTickerInner {
+                    subscription_id,
+                    ticker,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialize the KrakenTickerInner
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+/// Parse a Kraken string encoded number.
+fn parse<E>(input: &str) -> Result<f64, E>
+where
+    E: serde::de::Error,
+{
+    input.parse().map_err(E::custom)
+}
+
+/// Deserialize a Kraken \[PRICE, WHOLE_LOT_VOLUME, LOT_VOLUME\] array as a Barter [`Level`].
+fn de_level_with_whole_lot<'de, D>(deserializer: D) -> Result<Level, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let (price, _, amount) = <(&str, IgnoredAny, &str)>::deserialize(deserializer)?;
+    Ok(Level::new(
+        parse::<D::Error>(price)?,
+        parse::<D::Error>(amount)?,
+    ))
+}
+
+/// Deserialize a Kraken \[PRICE, LOT_VOLUME\] array as a Barter [`Level`].
+fn de_level<'de, D>(deserializer: D) -> Result<Level, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let (price, amount) = <(&str, &str)>::deserialize(deserializer)?;
+    Ok(Level::new(
+        parse::<D::Error>(price)?,
+        parse::<D::Error>(amount)?,
+    ))
+}
+
+/// Deserialize a Kraken \[TODAY, LAST_24_HOURS\] string array, keeping the rolling 24 hour value.
+fn de_last_24h<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let (_, last_24h) = <(IgnoredAny, &str)>::deserialize(deserializer)?;
+    parse::<D::Error>(last_24h)
+}
+
+/// Deserialize a Kraken \[TODAY, LAST_24_HOURS\] trade count array, keeping the rolling 24 hour
+/// value.
+fn de_trade_count_24h<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <(IgnoredAny, u64)>::deserialize(deserializer).map(|(_, last_24h)| last_24h)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+
+        #[test]
+        fn test_kraken_message_ticker() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<KrakenTicker, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid KrakenTicker::Data(KrakenTickerInner)
+                    input: r#"
+                    [
+                        0,
+                        {
+                            "a": ["5525.40000", 1, "1.000"],
+                            "b": ["5525.10000", 1, "1.000"],
+                            "c": ["5525.10000", "0.00398963"],
+                            "v": ["2634.11501494", "3591.17907851"],
+                            "p": ["5631.44067", "5653.78939"],
+                            "t": [11493, 16267],
+                            "l": ["5505.00000", "5505.00000"],
+                            "h": ["5783.00000", "5783.00000"],
+                            "o": ["5760.70000", "5763.40000"]
+                        },
+                        "ticker",
+                        "XBT/USD"
+                    ]
+                    "#,
+                    expected: Ok(KrakenTicker::Data(KrakenTickerInner {
+                        subscription_id: SubscriptionId::from("ticker|XBT/USD"),
+                        ticker: KrakenTickerData {
+                            best_ask: Level::new(5525.4, 1.0),
+                            best_bid: Level::new(5525.1, 1.0),
+                            last_trade: Level::new(5525.1, 0.00398963),
+                            volume_24h: 3591.17907851,
+                            vwap_24h: 5653.78939,
+                            trade_count_24h: 16267,
+                            low_24h: 5505.0,
+                            high_24h: 5783.0,
+                            open_24h: 5763.4,
+                        },
+                    })),
+                },
+                TestCase {
+                    // TC1: invalid KrakenTicker w/ non-numeric price
+                    input: r#"
+                    [
+                        0,
+                        {
+                            "a": ["invalid", 1, "1.000"],
+                            "b": ["5525.10000", 1, "1.000"],
+                            "c": ["5525.10000", "0.00398963"],
+                            "v": ["2634.11501494", "3591.17907851"],
+                            "p": ["5631.44067", "5653.78939"],
+                            "t": [11493, 16267],
+                            "l": ["5505.00000", "5505.00000"],
+                            "h": ["5783.00000", "5783.00000"],
+                            "o": ["5760.70000", "5763.40000"]
+                        },
+                        "ticker",
+                        "XBT/USD"
+                    ]
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_owned(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<KrakenTicker>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_kraken_ticker_synthesizes_exchange_time() {
+        use barter_integration::model::instrument::kind::InstrumentKind;
+
+        let input = r#"
+        [
+            0,
+            {
+                "a": ["5525.40000", 1, "1.000"],
+                "b": ["5525.10000", 1, "1.000"],
+                "c": ["5525.10000", "0.00398963"],
+                "v": ["2634.11501494", "3591.17907851"],
+                "p": ["5631.44067", "5653.78939"],
+                "t": [11493, 16267],
+                "l": ["5505.00000", "5505.00000"],
+                "h": ["5783.00000", "5783.00000"],
+                "o": ["5760.70000", "5763.40000"]
+            },
+            "ticker",
+            "XBT/USD"
+        ]
+        "#;
+
+        let ticker = serde_json::from_str::<KrakenTicker>(input).unwrap();
+        let instrument = Instrument::from(("xbt", "usd", InstrumentKind::Spot));
+
+        let event = MarketIter::<Ticker>::from((ExchangeId::Kraken, instrument, ticker))
+            .0
+            .remove(0)
+            .unwrap();
+
+        assert_eq!(event.exchange_time, event.received_time);
+        assert!(event.is_exchange_time_synthesized());
+    }
+}
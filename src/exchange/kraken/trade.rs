@@ -2,7 +2,7 @@ use super::KrakenMessage;
 use crate::{
     event::{MarketEvent, MarketIter},
     exchange::ExchangeId,
-    subscription::trade::PublicTrade,
+    subscription::trade::{PublicTrade, SideSource, TradeOrderIds, TradeSource},
     Identifier,
 };
 use barter_integration::{
@@ -51,7 +51,7 @@ impl Identifier<Option<SubscriptionId>> for KrakenTradesInner {
 fn custom_kraken_trade_id(trade: &KrakenTrade) -> String {
     format!(
         "{}_{}_{}_{}",
-        trade.time.timestamp_nanos(),
+        trade.time.timestamp_nanos_opt().unwrap_or_default(),
         trade.side,
         trade.price,
         trade.amount
@@ -68,6 +68,7 @@ impl From<(ExchangeId, Instrument, KrakenTrades)> for MarketIter<PublicTrade> {
                     Ok(MarketEvent {
                         exchange_time: trade.time,
                         received_time: Utc::now(),
+                        exchange_time_synthesized: false,
                         exchange: Exchange::from(exchange_id),
                         instrument: instrument.clone(),
                         kind: PublicTrade {
@@ -75,6 +76,10 @@ impl From<(ExchangeId, Instrument, KrakenTrades)> for MarketIter<PublicTrade> {
                             price: trade.price,
                             amount: trade.amount,
                             side: trade.side,
+                            source: TradeSource::Live,
+                            side_source: SideSource::Exchange,
+                            order_ids: TradeOrderIds::default(),
+                            aggregate_count: None,
                         },
                     })
                 })
@@ -0,0 +1,375 @@
+use super::message::{KrakenDataTypeV2, KrakenMessageV2};
+use crate::{
+    error::DataError,
+    exchange::kraken::market::KrakenMarket,
+    subscription::book::{Level, OrderBook, OrderBookSide},
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, Side},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// Default depth of a [`KrakenV2`](super::KrakenV2) "book" subscription.
+///
+/// See docs: <https://docs.kraken.com/api/docs/websocket-v2/book>
+pub const KRAKEN_V2_BOOK_DEPTH: usize = 10;
+
+/// Terse type alias for a [`KrakenV2`](super::KrakenV2) OrderBook Level2 snapshot or update
+/// WebSocket message.
+pub type KrakenOrderBookL2V2 = KrakenMessageV2<KrakenBookV2>;
+
+/// [`KrakenV2`](super::KrakenV2) OrderBook Level2 snapshot or update data.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.kraken.com/api/docs/websocket-v2/book>
+/// #### Snapshot
+/// ```json
+/// {
+///     "channel": "book",
+///     "type": "snapshot",
+///     "data": [
+///         {
+///             "symbol": "MATIC/USD",
+///             "bids": [{"price": 0.5666, "qty": 4831.75496}, {"price": 0.5665, "qty": 6658.22734}],
+///             "asks": [{"price": 0.5668, "qty": 4410.79769}, {"price": 0.5669, "qty": 4655.40412}],
+///             "checksum": 2439117997
+///         }
+///     ]
+/// }
+/// ```
+///
+/// #### Update
+/// ```json
+/// {
+///     "channel": "book",
+///     "type": "update",
+///     "data": [
+///         {
+///             "symbol": "MATIC/USD",
+///             "bids": [{"price": 0.5657, "qty": 1098.3947558}],
+///             "asks": [],
+///             "checksum": 2114181697,
+///             "timestamp": "2023-10-06T17:35:55.440295Z"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KrakenBookV2 {
+    pub symbol: String,
+    #[serde(default)]
+    pub bids: Vec<KrakenLevelV2>,
+    #[serde(default)]
+    pub asks: Vec<KrakenLevelV2>,
+    pub checksum: u32,
+    #[serde(rename = "timestamp", default)]
+    pub time: Option<DateTime<Utc>>,
+}
+
+impl Identifier<KrakenMarket> for KrakenBookV2 {
+    fn id(&self) -> KrakenMarket {
+        KrakenMarket(self.symbol.clone())
+    }
+}
+
+/// [`KrakenV2`](super::KrakenV2) OrderBook level.
+///
+/// See [`KrakenBookV2`] for full raw payload examples.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KrakenLevelV2 {
+    pub price: f64,
+    #[serde(rename = "qty")]
+    pub amount: f64,
+}
+
+impl From<KrakenLevelV2> for Level {
+    fn from(level: KrakenLevelV2) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+/// [`KrakenV2`](super::KrakenV2) [`OrderBookUpdater`] for the "book" channel.
+///
+/// Kraken: How To Maintain A Local OrderBook
+///
+/// 1. Subscribe to the "book" channel, after which a "snapshot" is received.
+/// 2. Any "snapshot" replaces the local OrderBook.
+/// 3. Drop any "update" received before the first "snapshot".
+/// 4. The data in each "update" is the absolute quantity for a price level.
+/// 5. If the quantity is 0, remove the price level.
+/// 6. Truncate the OrderBook to the subscribed depth, since levels pushed beyond the depth by
+///    an insert are not explicitly removed.
+///
+/// Notes:
+///  - The "checksum" is not verified since it is computed from the exact decimal strings of
+///    the top 10 levels, which are not preserved once parsed as `f64`.
+///
+/// See docs: <https://docs.kraken.com/api/docs/websocket-v2/book>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct KrakenBookUpdaterV2 {
+    pub updates_processed: u64,
+}
+
+impl KrakenBookUpdaterV2 {
+    /// Determines if a "snapshot" has been applied, after which "update"s can be applied.
+    pub fn is_initialised(&self) -> bool {
+        self.updates_processed > 0
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for KrakenBookUpdaterV2 {
+    type OrderBook = OrderBook;
+    type Update = KrakenOrderBookL2V2;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Initial OrderBook snapshot is received over the WebSocket after subscribing
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::default(),
+            book: OrderBook {
+                last_update_time: DateTime::default(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            },
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<Self::OrderBook>, DataError> {
+        // Kraken: How To Maintain A Local OrderBook
+        // See Self's Rust Docs for more information on each numbered step
+        let KrakenMessageV2::Data(update) = update else {
+            return Ok(None);
+        };
+
+        for data in update.data {
+            match update.r#type {
+                // 2. Any snapshot replaces the local OrderBook:
+                KrakenDataTypeV2::Snapshot => {
+                    book.bids = OrderBookSide::new(Side::Buy, data.bids);
+                    book.asks = OrderBookSide::new(Side::Sell, data.asks);
+                }
+                KrakenDataTypeV2::Update => {
+                    // 3. Drop any update received before the first snapshot:
+                    if !self.is_initialised() {
+                        return Ok(None);
+                    }
+
+                    // 4. The data in each update is the absolute quantity for a price level.
+                    // 5. If the quantity is 0, remove the price level.
+                    book.bids.upsert(data.bids);
+                    book.asks.upsert(data.asks);
+                }
+            }
+
+            // 6. Truncate the OrderBook to the subscribed depth:
+            book.bids.truncate(KRAKEN_V2_BOOK_DEPTH);
+            book.asks.truncate(KRAKEN_V2_BOOK_DEPTH);
+
+            // Update OrderBook & OrderBookUpdater metadata
+            book.last_update_time = data.time.unwrap_or_default();
+            self.updates_processed += 1;
+        }
+
+        Ok(Some(book.snapshot()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::kraken::v2::message::KrakenDataV2;
+    use barter_integration::model::SubscriptionId;
+    use chrono::TimeZone;
+
+    const SNAPSHOT: &str = r#"
+    {
+        "channel": "book",
+        "type": "snapshot",
+        "data": [
+            {
+                "symbol": "MATIC/USD",
+                "bids": [{"price": 0.5666, "qty": 4831.75496}, {"price": 0.5665, "qty": 6658.22734}],
+                "asks": [{"price": 0.5668, "qty": 4410.79769}, {"price": 0.5669, "qty": 4655.40412}],
+                "checksum": 2439117997
+            }
+        ]
+    }
+    "#;
+
+    const UPDATE: &str = r#"
+    {
+        "channel": "book",
+        "type": "update",
+        "data": [
+            {
+                "symbol": "MATIC/USD",
+                "bids": [{"price": 0.5666, "qty": 0.0}, {"price": 0.5657, "qty": 1098.3947558}],
+                "asks": [],
+                "checksum": 2114181697,
+                "timestamp": "2023-10-06T17:35:55.440295Z"
+            }
+        ]
+    }
+    "#;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_kraken_order_book_l2_v2() {
+            struct TestCase {
+                input: &'static str,
+                expected: KrakenOrderBookL2V2,
+            }
+
+            let cases = vec![
+                // TC0: snapshot without a timestamp
+                TestCase {
+                    input: SNAPSHOT,
+                    expected: KrakenMessageV2::Data(KrakenDataV2 {
+                        channel: "book".to_string(),
+                        r#type: KrakenDataTypeV2::Snapshot,
+                        data: vec![KrakenBookV2 {
+                            symbol: "MATIC/USD".to_string(),
+                            bids: vec![
+                                KrakenLevelV2 {
+                                    price: 0.5666,
+                                    amount: 4831.75496,
+                                },
+                                KrakenLevelV2 {
+                                    price: 0.5665,
+                                    amount: 6658.22734,
+                                },
+                            ],
+                            asks: vec![
+                                KrakenLevelV2 {
+                                    price: 0.5668,
+                                    amount: 4410.79769,
+                                },
+                                KrakenLevelV2 {
+                                    price: 0.5669,
+                                    amount: 4655.40412,
+                                },
+                            ],
+                            checksum: 2439117997,
+                            time: None,
+                        }],
+                    }),
+                },
+                // TC1: update with a timestamp
+                TestCase {
+                    input: UPDATE,
+                    expected: KrakenMessageV2::Data(KrakenDataV2 {
+                        channel: "book".to_string(),
+                        r#type: KrakenDataTypeV2::Update,
+                        data: vec![KrakenBookV2 {
+                            symbol: "MATIC/USD".to_string(),
+                            bids: vec![
+                                KrakenLevelV2 {
+                                    price: 0.5666,
+                                    amount: 0.0,
+                                },
+                                KrakenLevelV2 {
+                                    price: 0.5657,
+                                    amount: 1098.3947558,
+                                },
+                            ],
+                            asks: vec![],
+                            checksum: 2114181697,
+                            time: Some(
+                                Utc.with_ymd_and_hms(2023, 10, 6, 17, 35, 55).unwrap()
+                                    + chrono::Duration::microseconds(440295),
+                            ),
+                        }],
+                    }),
+                },
+            ];
+
+            for (index, test) in cases.into_iter().enumerate() {
+                let actual = serde_json::from_str::<KrakenOrderBookL2V2>(test.input).unwrap();
+                assert_eq!(
+                    actual.id(),
+                    Some(SubscriptionId::from("book|MATIC/USD")),
+                    "TC{} failed",
+                    index
+                );
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+
+    mod kraken_book_updater_v2 {
+        use super::*;
+
+        #[test]
+        fn test_update() {
+            let mut updater = KrakenBookUpdaterV2::default();
+            let mut book = OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            };
+            let parse = |input| serde_json::from_str::<KrakenOrderBookL2V2>(input).unwrap();
+
+            // Update received before the first snapshot is dropped
+            assert_eq!(updater.update(&mut book, parse(UPDATE)).unwrap(), None);
+
+            let actual = updater.update(&mut book, parse(SNAPSHOT)).unwrap().unwrap();
+            assert_eq!(
+                actual.bids,
+                OrderBookSide::new(
+                    Side::Buy,
+                    vec![
+                        Level::new(0.5666, 4831.75496),
+                        Level::new(0.5665, 6658.22734)
+                    ]
+                )
+            );
+
+            let actual = updater.update(&mut book, parse(UPDATE)).unwrap().unwrap();
+            assert_eq!(
+                actual.bids,
+                OrderBookSide::new(
+                    Side::Buy,
+                    vec![
+                        Level::new(0.5665, 6658.22734),
+                        Level::new(0.5657, 1098.3947558)
+                    ]
+                )
+            );
+            assert_eq!(
+                actual.asks,
+                OrderBookSide::new(
+                    Side::Sell,
+                    vec![
+                        Level::new(0.5668, 4410.79769),
+                        Level::new(0.5669, 4655.40412)
+                    ]
+                )
+            );
+        }
+    }
+}
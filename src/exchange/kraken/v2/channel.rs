@@ -0,0 +1,49 @@
+use super::KrakenV2;
+use crate::{
+    subscription::{book::OrderBooksL2, trade::PublicTrades, Subscription},
+    Identifier,
+};
+use serde::Serialize;
+
+/// Type that defines how to translate a Barter [`Subscription`] into a
+/// [`KrakenV2`](super::KrakenV2) channel to be subscribed to.
+///
+/// See docs: <https://docs.kraken.com/api/docs/websocket-v2/trade>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct KrakenChannelV2(pub &'static str);
+
+impl KrakenChannelV2 {
+    /// [`KrakenV2`] real-time trades channel name.
+    ///
+    /// See docs: <https://docs.kraken.com/api/docs/websocket-v2/trade>
+    pub const TRADES: Self = Self("trade");
+
+    /// [`KrakenV2`] real-time OrderBook Level2 channel name.
+    ///
+    /// See docs: <https://docs.kraken.com/api/docs/websocket-v2/book>
+    pub const ORDER_BOOK_L2: Self = Self("book");
+}
+
+impl Identifier<KrakenChannelV2> for Subscription<KrakenV2, PublicTrades> {
+    fn id(&self) -> KrakenChannelV2 {
+        KrakenChannelV2::TRADES
+    }
+}
+
+impl Identifier<KrakenChannelV2> for Subscription<KrakenV2, OrderBooksL2> {
+    fn id(&self) -> KrakenChannelV2 {
+        KrakenChannelV2::ORDER_BOOK_L2
+    }
+}
+
+impl From<&'static str> for KrakenChannelV2 {
+    fn from(channel: &'static str) -> Self {
+        Self(channel)
+    }
+}
+
+impl AsRef<str> for KrakenChannelV2 {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{
+    exchange::{kraken::market::KrakenMarket, ExchangeSub},
+    Identifier,
+};
+use barter_integration::model::SubscriptionId;
+use serde::{Deserialize, Serialize};
+
+/// [`KrakenV2`](super::KrakenV2) message variants that can be received over
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket).
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.kraken.com/api/docs/guides/spot-ws-intro>
+///
+/// #### Trades
+/// See docs: <https://docs.kraken.com/api/docs/websocket-v2/trade>
+/// ```json
+/// {
+///     "channel": "trade",
+///     "type": "update",
+///     "data": [
+///         {
+///             "symbol": "MATIC/USD",
+///             "side": "buy",
+///             "price": 0.5147,
+///             "qty": 6423.46326,
+///             "ord_type": "limit",
+///             "trade_id": 4665846,
+///             "timestamp": "2023-09-25T07:48:36.925533Z"
+///         }
+///     ]
+/// }
+/// ```
+///
+/// #### Heartbeat
+/// See docs: <https://docs.kraken.com/api/docs/websocket-v2/heartbeat>
+/// ```json
+/// {
+///     "channel": "heartbeat"
+/// }
+/// ```
+///
+/// #### Status
+/// See docs: <https://docs.kraken.com/api/docs/websocket-v2/status>
+/// ```json
+/// {
+///     "channel": "status",
+///     "type": "update",
+///     "data": [
+///         {
+///             "api_version": "v2",
+///             "connection_id": 12393906104898154338,
+///             "system": "online",
+///             "version": "2.0.0"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum KrakenMessageV2<T> {
+    Data(KrakenDataV2<T>),
+    Event(KrakenEventV2),
+}
+
+/// [`KrakenV2`](super::KrakenV2) subscription data, either an initial "snapshot" or an
+/// "update".
+///
+/// See [`KrakenMessageV2`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KrakenDataV2<T> {
+    pub channel: String,
+    pub r#type: KrakenDataTypeV2,
+    pub data: Vec<T>,
+}
+
+/// [`KrakenV2`](super::KrakenV2) "type" of a [`KrakenDataV2`] message.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KrakenDataTypeV2 {
+    Snapshot,
+    Update,
+}
+
+/// [`KrakenV2`](super::KrakenV2) messages received over the WebSocket which are not
+/// subscription data.
+///
+/// See [`KrakenMessageV2`] for full raw payload examples.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(tag = "channel", rename_all = "snake_case")]
+pub enum KrakenEventV2 {
+    Heartbeat,
+    Status,
+}
+
+impl<T> Identifier<Option<SubscriptionId>> for KrakenMessageV2<T>
+where
+    T: Identifier<KrakenMarket>,
+{
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            // KrakenV2 pushes separate messages for each subscribed symbol
+            Self::Data(message) => message
+                .data
+                .first()
+                .map(|data| ExchangeSub::from((message.channel.as_str(), data.id())).id()),
+            Self::Event(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+
+        #[test]
+        fn test_kraken_message_v2_event() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<KrakenMessageV2<()>, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid KrakenMessageV2::Event(KrakenEventV2::Heartbeat)
+                    input: r#"{"channel": "heartbeat"}"#,
+                    expected: Ok(KrakenMessageV2::Event(KrakenEventV2::Heartbeat)),
+                },
+                TestCase {
+                    // TC1: valid KrakenMessageV2::Event(KrakenEventV2::Status)
+                    input: r#"
+                    {
+                        "channel": "status",
+                        "type": "update",
+                        "data": [
+                            {
+                                "api_version": "v2",
+                                "connection_id": 12393906104898154338,
+                                "system": "online",
+                                "version": "2.0.0"
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Ok(KrakenMessageV2::Event(KrakenEventV2::Status)),
+                },
+                TestCase {
+                    // TC2: invalid unknown channel
+                    input: r#"{"channel": "unknown"}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<KrakenMessageV2<()>>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use self::{
+    book::KrakenBookUpdaterV2, channel::KrakenChannelV2, subscription::KrakenSubResponseV2,
+    trade::KrakenTradesV2,
+};
+use super::market::KrakenMarket;
+use crate::{
+    exchange::{next_request_id, Connector, ExchangeId, ExchangeSub, StreamSelector},
+    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+    subscription::{book::OrderBooksL2, trade::PublicTrades},
+    transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
+    ExchangeWsStream,
+};
+use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_macro::{DeExchange, SerExchange};
+use serde_json::json;
+use url::Url;
+
+/// Level 2 OrderBook types for [`KrakenV2`].
+pub mod book;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into a [`KrakenV2`] specific channel used for generating [`Connector::requests`].
+pub mod channel;
+
+/// [`KrakenMessageV2`](message::KrakenMessageV2) type for [`KrakenV2`].
+pub mod message;
+
+/// [`Subscription`](crate::subscription::Subscription) response type and response
+/// [`Validator`](barter_integration) for [`KrakenV2`].
+pub mod subscription;
+
+/// Public trade types for [`KrakenV2`].
+pub mod trade;
+
+/// [`KrakenV2`] server base url.
+///
+/// See docs: <https://docs.kraken.com/api/docs/guides/spot-ws-intro>
+pub const BASE_URL_KRAKEN_V2: &str = "wss://ws.kraken.com/v2";
+
+/// [`KrakenV2`] beta sandbox server base url.
+///
+/// See docs: <https://docs.kraken.com/api/docs/guides/spot-ws-intro>
+pub const BASE_URL_KRAKEN_V2_SANDBOX: &str = "wss://beta-ws.kraken.com/v2";
+
+/// [`Kraken`](super::Kraken) exchange using the v2 WebSocket API.
+///
+/// The v2 API replaces the array based payloads of the v1 API with JSON objects of the form
+/// `{"channel", "type", "data"}`, where "type" distinguishes an initial "snapshot" from
+/// subsequent "update"s. Kraken plans to deprecate the v1 API served by
+/// [`Kraken`](super::Kraken).
+///
+/// See docs: <https://docs.kraken.com/api/docs/guides/spot-ws-intro>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DeExchange, SerExchange,
+)]
+pub struct KrakenV2;
+
+impl Connector for KrakenV2 {
+    const ID: ExchangeId = ExchangeId::KrakenV2;
+    type Channel = KrakenChannelV2;
+    type Market = KrakenMarket;
+    type Subscriber = WebSocketSubscriber;
+    type SubValidator = WebSocketSubValidator;
+    type SubResponse = KrakenSubResponseV2;
+
+    fn url() -> Result<Url, SocketError> {
+        Url::parse(BASE_URL_KRAKEN_V2).map_err(SocketError::UrlParse)
+    }
+
+    fn sandbox_url() -> Result<Url, SocketError> {
+        Url::parse(BASE_URL_KRAKEN_V2_SANDBOX).map_err(SocketError::UrlParse)
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        exchange_subs
+            .into_iter()
+            .map(|ExchangeSub { channel, market }| {
+                WsMessage::Text(
+                    json!({
+                        "method": "subscribe",
+                        "params": {
+                            "channel": channel.as_ref(),
+                            "symbol": [market.as_ref()],
+                        },
+                        "req_id": next_request_id(),
+                    })
+                    .to_string(),
+                )
+            })
+            .collect()
+    }
+
+    fn request_id_field() -> Option<&'static str> {
+        Some("req_id")
+    }
+}
+
+impl StreamSelector<PublicTrades> for KrakenV2 {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, KrakenTradesV2>>;
+}
+
+impl StreamSelector<OrderBooksL2> for KrakenV2 {
+    type Stream = ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, KrakenBookUpdaterV2>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kraken_v2_requests() {
+        let actual = KrakenV2::requests(vec![ExchangeSub::from((
+            KrakenChannelV2::TRADES,
+            KrakenMarket("BTC/USD".to_string()),
+        ))]);
+
+        let WsMessage::Text(actual) = &actual[0] else {
+            panic!("expected WsMessage::Text subscription request")
+        };
+        let mut actual = serde_json::from_str::<serde_json::Value>(actual).unwrap();
+        assert!(actual["req_id"].is_u64());
+        actual.as_object_mut().unwrap().remove("req_id");
+
+        assert_eq!(
+            actual,
+            json!({
+                "method": "subscribe",
+                "params": {
+                    "channel": "trade",
+                    "symbol": ["BTC/USD"],
+                },
+            })
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use barter_integration::{error::SocketError, Validator};
+use serde::{Deserialize, Serialize};
+
+/// [`KrakenV2`](super::KrakenV2) message received in response to WebSocket subscription
+/// requests.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.kraken.com/api/docs/websocket-v2/trade>
+/// #### Subscription Trade Success
+/// ```json
+/// {
+///     "method": "subscribe",
+///     "req_id": 1,
+///     "result": {
+///         "channel": "trade",
+///         "snapshot": true,
+///         "symbol": "BTC/USD"
+///     },
+///     "success": true,
+///     "time_in": "2023-09-25T09:04:31.742599Z",
+///     "time_out": "2023-09-25T09:04:31.742648Z"
+/// }
+/// ```
+///
+/// #### Subscription Trade Failure
+/// ```json
+/// {
+///     "error": "Currency pair not supported ALGO/USDD",
+///     "method": "subscribe",
+///     "req_id": 1,
+///     "success": false,
+///     "symbol": "ALGO/USDD",
+///     "time_in": "2023-09-25T09:04:31.742599Z",
+///     "time_out": "2023-09-25T09:04:31.742648Z"
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum KrakenSubResponseV2 {
+    Subscribed { result: KrakenSubResultV2 },
+    Error { error: String },
+}
+
+/// [`KrakenV2`](super::KrakenV2) subscription accepted by the server.
+///
+/// See [`KrakenSubResponseV2`] for full raw payload examples.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct KrakenSubResultV2 {
+    pub channel: String,
+    pub symbol: String,
+}
+
+impl Validator for KrakenSubResponseV2 {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match &self {
+            KrakenSubResponseV2::Subscribed { .. } => Ok(self),
+            KrakenSubResponseV2::Error { error } => Err(SocketError::Subscribe(format!(
+                "received failure subscription response: {error}",
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_kraken_sub_response_v2() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<KrakenSubResponseV2, SocketError>,
+            }
+
+            let cases = vec![
+                TestCase {
+                    // TC0: input response is Subscribed
+                    input: r#"
+                    {
+                        "method": "subscribe",
+                        "req_id": 1,
+                        "result": {
+                            "channel": "trade",
+                            "snapshot": true,
+                            "symbol": "BTC/USD"
+                        },
+                        "success": true,
+                        "time_in": "2023-09-25T09:04:31.742599Z",
+                        "time_out": "2023-09-25T09:04:31.742648Z"
+                    }
+                    "#,
+                    expected: Ok(KrakenSubResponseV2::Subscribed {
+                        result: KrakenSubResultV2 {
+                            channel: "trade".to_string(),
+                            symbol: "BTC/USD".to_string(),
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC1: input response is failed subscription
+                    input: r#"
+                    {
+                        "error": "Currency pair not supported ALGO/USDD",
+                        "method": "subscribe",
+                        "req_id": 1,
+                        "success": false,
+                        "symbol": "ALGO/USDD",
+                        "time_in": "2023-09-25T09:04:31.742599Z",
+                        "time_out": "2023-09-25T09:04:31.742648Z"
+                    }
+                    "#,
+                    expected: Ok(KrakenSubResponseV2::Error {
+                        error: "Currency pair not supported ALGO/USDD".to_string(),
+                    }),
+                },
+                TestCase {
+                    // TC2: input is a status message, not a subscription response
+                    input: r#"{"channel": "status", "type": "update", "data": []}"#,
+                    expected: Err(SocketError::Subscribe("".to_string())),
+                },
+            ];
+
+            for (index, test) in cases.into_iter().enumerate() {
+                let actual = serde_json::from_str::<KrakenSubResponseV2>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_kraken_sub_response_v2_validate() {
+        struct TestCase {
+            input_response: KrakenSubResponseV2,
+            is_valid: bool,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input response is successful subscription
+                input_response: KrakenSubResponseV2::Subscribed {
+                    result: KrakenSubResultV2 {
+                        channel: "book".to_string(),
+                        symbol: "BTC/USD".to_string(),
+                    },
+                },
+                is_valid: true,
+            },
+            TestCase {
+                // TC1: input response is failed subscription
+                input_response: KrakenSubResponseV2::Error {
+                    error: "Currency pair not supported ALGO/USDD".to_string(),
+                },
+                is_valid: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test.input_response.validate().is_ok();
+            assert_eq!(actual, test.is_valid, "TestCase {} failed", index);
+        }
+    }
+}
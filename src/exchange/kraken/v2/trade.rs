@@ -0,0 +1,179 @@
+use super::message::{KrakenDataTypeV2, KrakenMessageV2};
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{kraken::market::KrakenMarket, ExchangeId},
+    subscription::trade::{PublicTrade, SideSource, TradeOrderIds, TradeSource},
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, Side};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`KrakenV2`](super::KrakenV2) real-time trades WebSocket message.
+pub type KrakenTradesV2 = KrakenMessageV2<KrakenTradeV2>;
+
+/// [`KrakenV2`](super::KrakenV2) trade.
+///
+/// ### Notes
+/// The trades of the initial "snapshot" sent after subscribing were executed before the
+/// subscription, so they are emitted with a [`TradeSource::Historical`].
+///
+/// See [`KrakenMessageV2`] for full raw payload examples.
+///
+/// See docs: <https://docs.kraken.com/api/docs/websocket-v2/trade>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KrakenTradeV2 {
+    pub symbol: String,
+    pub side: Side,
+    pub price: f64,
+    #[serde(rename = "qty")]
+    pub amount: f64,
+    pub trade_id: u64,
+    #[serde(rename = "timestamp")]
+    pub time: DateTime<Utc>,
+}
+
+impl Identifier<KrakenMarket> for KrakenTradeV2 {
+    fn id(&self) -> KrakenMarket {
+        KrakenMarket(self.symbol.clone())
+    }
+}
+
+impl From<(ExchangeId, Instrument, KrakenTradesV2)> for MarketIter<PublicTrade> {
+    fn from((exchange_id, instrument, trades): (ExchangeId, Instrument, KrakenTradesV2)) -> Self {
+        match trades {
+            KrakenTradesV2::Data(trades) => {
+                let source = match trades.r#type {
+                    KrakenDataTypeV2::Snapshot => TradeSource::Historical,
+                    KrakenDataTypeV2::Update => TradeSource::Live,
+                };
+
+                trades
+                    .data
+                    .into_iter()
+                    .map(|trade| {
+                        Ok(MarketEvent {
+                            exchange_time: trade.time,
+                            received_time: Utc::now(),
+                            exchange_time_synthesized: false,
+                            exchange: Exchange::from(exchange_id),
+                            instrument: instrument.clone(),
+                            kind: PublicTrade {
+                                id: trade.trade_id.to_string(),
+                                price: trade.price,
+                                amount: trade.amount,
+                                side: trade.side,
+                                source,
+                                side_source: SideSource::Exchange,
+                                order_ids: TradeOrderIds::default(),
+                                aggregate_count: None,
+                            },
+                        })
+                    })
+                    .collect()
+            }
+            KrakenTradesV2::Event(_) => Self(vec![]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::kraken::v2::message::KrakenDataV2;
+    use barter_integration::model::{instrument::kind::InstrumentKind, SubscriptionId};
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_kraken_trades_v2() {
+        let input = r#"
+        {
+            "channel": "trade",
+            "type": "update",
+            "data": [
+                {
+                    "symbol": "MATIC/USD",
+                    "side": "buy",
+                    "price": 0.5147,
+                    "qty": 6423.46326,
+                    "ord_type": "limit",
+                    "trade_id": 4665846,
+                    "timestamp": "2023-09-25T07:48:36.925533Z"
+                },
+                {
+                    "symbol": "MATIC/USD",
+                    "side": "sell",
+                    "price": 0.5146,
+                    "qty": 100.0,
+                    "ord_type": "market",
+                    "trade_id": 4665847,
+                    "timestamp": "2023-09-25T07:48:37.000000Z"
+                }
+            ]
+        }
+        "#;
+
+        let actual = serde_json::from_str::<KrakenTradesV2>(input).unwrap();
+        let time = |second, micros| {
+            Utc.with_ymd_and_hms(2023, 9, 25, 7, 48, second).unwrap()
+                + chrono::Duration::microseconds(micros)
+        };
+
+        assert_eq!(actual.id(), Some(SubscriptionId::from("trade|MATIC/USD")));
+        assert_eq!(
+            actual,
+            KrakenTradesV2::Data(KrakenDataV2 {
+                channel: "trade".to_string(),
+                r#type: KrakenDataTypeV2::Update,
+                data: vec![
+                    KrakenTradeV2 {
+                        symbol: "MATIC/USD".to_string(),
+                        side: Side::Buy,
+                        price: 0.5147,
+                        amount: 6423.46326,
+                        trade_id: 4665846,
+                        time: time(36, 925533),
+                    },
+                    KrakenTradeV2 {
+                        symbol: "MATIC/USD".to_string(),
+                        side: Side::Sell,
+                        price: 0.5146,
+                        amount: 100.0,
+                        trade_id: 4665847,
+                        time: time(37, 0),
+                    },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn test_kraken_trades_v2_snapshot_to_historical_trades() {
+        let input = r#"
+        {
+            "channel": "trade",
+            "type": "snapshot",
+            "data": [
+                {
+                    "symbol": "BTC/USD",
+                    "side": "sell",
+                    "price": 26500.0,
+                    "qty": 0.01,
+                    "ord_type": "limit",
+                    "trade_id": 61341711,
+                    "timestamp": "2023-09-25T07:48:30.000000Z"
+                }
+            ]
+        }
+        "#;
+
+        let trades = serde_json::from_str::<KrakenTradesV2>(input).unwrap();
+        let instrument = Instrument::from(("btc", "usd", InstrumentKind::Spot));
+
+        let actual = MarketIter::<PublicTrade>::from((ExchangeId::KrakenV2, instrument, trades)).0;
+        assert_eq!(actual.len(), 1);
+        let actual = actual.into_iter().next().unwrap().unwrap();
+        assert_eq!(actual.kind.id, "61341711");
+        assert_eq!(actual.kind.source, TradeSource::Historical);
+    }
+}
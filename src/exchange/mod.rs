@@ -1,6 +1,13 @@
 use self::subscription::ExchangeSub;
 use crate::{
-    subscriber::{validator::SubscriptionValidator, Subscriber},
+    error::DataError,
+    streams::{ratelimit::RateLimitUsage, reconnect::ErrorClass},
+    subscriber::{
+        config::{ConnectionConfig, Credentials},
+        pacer::RequestRateLimit,
+        validator::{SubscriptionValidator, ValidationStrategy},
+        Subscriber,
+    },
     subscription::{Map, SubKind},
     MarketStream,
 };
@@ -13,6 +20,8 @@ use barter_integration::{
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::{
     fmt::{Debug, Display},
+    str::FromStr,
+    sync::atomic::{AtomicU64, Ordering},
     time::Duration,
 };
 use url::Url;
@@ -46,6 +55,10 @@ pub mod okx;
 /// exchange [`Connector`] to build [`WsMessage`] subscription payloads.
 pub mod subscription;
 
+/// Exchange specific epoch timestamp units, and the deserialisers used to interpret them as a
+/// `DateTime<Utc>`.
+pub mod timestamp;
+
 /// Default [`Duration`] the [`Connector::SubValidator`] will wait to receive all success responses to actioned
 /// [`Subscription`](crate::subscription::Subscription) requests.
 pub const DEFAULT_SUBSCRIPTION_TIMEOUT: Duration = Duration::from_secs(10);
@@ -62,6 +75,43 @@ where
     Kind: SubKind,
 {
     type Stream: MarketStream<Self, Kind>;
+
+    /// Defines if a [`WILDCARD`](crate::subscription::WILDCARD) [`Instrument`] can be used to
+    /// subscribe to every market via the exchange all-symbols channel.
+    const WILDCARD: WildcardSupport = WildcardSupport::Unsupported;
+
+    /// Determine if [`Self`] supports [`Subscription`](crate::subscription::Subscription)s of
+    /// this [`SubKind`] for the provided [`InstrumentKind`].
+    ///
+    /// Defaults to the [`ExchangeId::supports`] capability matrix. An unsupported
+    /// [`SubKind`] cannot be subscribed to at all, since [`Self`] does not implement the
+    /// associated [`StreamSelector`].
+    fn supports(instrument_kind: InstrumentKind) -> bool {
+        Self::ID.supports(instrument_kind)
+    }
+
+    /// Determine if [`Self`] supports the configuration of the provided [`SubKind`] (eg/ the
+    /// update speed of an [`OrderBooksL2Speed`](crate::subscription::book::OrderBooksL2Speed)).
+    ///
+    /// Defaults to `true`, since most [`SubKind`]s are not configurable.
+    fn supports_kind(_: &Kind) -> bool {
+        true
+    }
+}
+
+/// Whether a [`StreamSelector`] supports [`WILDCARD`](crate::subscription::WILDCARD)
+/// [`Subscription`](crate::subscription::Subscription)s.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum WildcardSupport {
+    /// Only [`Subscription`](crate::subscription::Subscription)s for a specific market are
+    /// supported.
+    Unsupported,
+    /// Both wildcard & specific market [`Subscription`](crate::subscription::Subscription)s are
+    /// supported.
+    Supported,
+    /// Only wildcard [`Subscription`](crate::subscription::Subscription)s are supported, since
+    /// the exchange channel cannot be subscribed to for a specific market.
+    Required,
 }
 
 /// Primary exchange abstraction. Defines how to translate Barter types into exchange specific
@@ -83,7 +133,7 @@ where
     /// ### Examples
     /// - [`BinanceChannel("@depth@100ms")`](binance::channel::BinanceChannel)
     /// - [`KrakenChannel("trade")`](kraken::channel::KrakenChannel)
-    type Channel: AsRef<str>;
+    type Channel: AsRef<str> + PartialEq;
 
     /// Type that defines how to translate a Barter
     /// [`Subscription`](crate::subscription::Subscription) into an exchange specific market that
@@ -111,8 +161,22 @@ where
     type SubResponse: Validator + Debug + DeserializeOwned;
 
     /// Base [`Url`] of the exchange server being connected with.
+    #[allow(clippy::result_large_err)]
     fn url() -> Result<Url, SocketError>;
 
+    /// Base [`Url`] of the exchange sandbox server, dialed instead of [`Self::url`] if
+    /// [`ConnectionConfig::sandbox`](crate::subscriber::config::ConnectionConfig::sandbox) is
+    /// enabled, so integrations can be tested without production traffic.
+    ///
+    /// Defaults to an error, since most exchanges provide no public market data sandbox.
+    #[allow(clippy::result_large_err)]
+    fn sandbox_url() -> Result<Url, SocketError> {
+        Err(SocketError::Subscribe(format!(
+            "{} has no sandbox environment",
+            Self::ID
+        )))
+    }
+
     /// Defines [`PingInterval`] of custom application-level
     /// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) pings for the exchange
     /// server being connected with.
@@ -122,10 +186,119 @@ where
         None
     }
 
+    /// Defines the [`WsMessage`]s sent immediately after connecting, before any login or
+    /// subscription requests (eg/ Bitfinex "conf" flags). Any responses are expected to be
+    /// handled by the [`Self::SubValidator`].
+    ///
+    /// Defaults to none.
+    fn connection_requests() -> Vec<WsMessage> {
+        vec![]
+    }
+
+    /// Defines the login [`WsMessage`] that authenticates the connection with the provided
+    /// [`Credentials`], sent before any subscription requests if
+    /// [`ConnectionConfig::credentials`](crate::subscriber::config::ConnectionConfig::credentials)
+    /// are configured. The login response is validated as a [`Self::SubResponse`].
+    ///
+    /// Defaults to `None`, meaning that connections are never logged in.
+    fn login(_credentials: &Credentials) -> Option<WsMessage> {
+        None
+    }
+
+    /// Select the [`Self::Channel`] actually subscribed to in place of the provided default
+    /// channel of a [`Subscription`](crate::subscription::Subscription), given the
+    /// [`ConnectionConfig`] (eg/ upgrading to a faster channel available to the declared
+    /// [`AccountTier`](crate::subscriber::config::AccountTier)).
+    ///
+    /// Returns an error if the configured connection cannot access the channel, so the
+    /// subscription fails before connecting rather than being rejected by the exchange.
+    ///
+    /// Defaults to the provided channel.
+    #[allow(clippy::result_large_err)]
+    fn select_channel(
+        channel: Self::Channel,
+        _config: &ConnectionConfig,
+    ) -> Result<Self::Channel, SocketError> {
+        Ok(channel)
+    }
+
+    /// Defines the [`ExchangeSub`]s subscribed to alongside the provided [`ExchangeSub`] of a
+    /// [`Subscription`](crate::subscription::Subscription), whose data is combined into the same
+    /// stream (eg/ the Okx "index-tickers" & "funding-rate" channels of a "mark-price" market).
+    ///
+    /// Associated [`ExchangeSub`]s are mapped, batched & validated like any other
+    /// [`ExchangeSub`], and may be shared by several
+    /// [`Subscription`](crate::subscription::Subscription)s.
+    ///
+    /// Defaults to none.
+    fn associated_subs(
+        _exchange_sub: &ExchangeSub<Self::Channel, Self::Market>,
+    ) -> Vec<ExchangeSub<Self::Channel, Self::Market>> {
+        vec![]
+    }
+
     /// Defines how to translate a collection of [`ExchangeSub`]s into the [`WsMessage`]
     /// subscription payloads sent to the exchange server.
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage>;
 
+    /// Maximum number of [`ExchangeSub`]s the exchange server accepts in a single subscribe
+    /// request, beyond which the request is rejected as oversized.
+    ///
+    /// Defaults to `None`, meaning that all [`ExchangeSub`]s are passed to [`Self::requests`]
+    /// at once.
+    fn max_args_per_request() -> Option<usize> {
+        None
+    }
+
+    /// Maximum number of [`ExchangeSub`]s the exchange server accepts on a single connection.
+    ///
+    /// Defaults to `None`, meaning that no limit is known.
+    fn max_subscriptions_per_connection() -> Option<usize> {
+        None
+    }
+
+    /// [`RequestRateLimit`] of subscribe requests sent on a single connection, which the
+    /// [`WebSocketSubscriber`](crate::subscriber::WebSocketSubscriber) paces requests to respect.
+    ///
+    /// Defaults to `None`, meaning that all subscribe requests are sent at once.
+    fn subscription_rate_limit() -> Option<RequestRateLimit> {
+        None
+    }
+
+    /// Split a collection of [`ExchangeSub`]s into batches that respect the
+    /// [`Self::max_args_per_request`] cap, translating each batch into [`WsMessage`]
+    /// subscription payloads using [`Self::requests`].
+    fn batched_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        let max_args = match Self::max_args_per_request() {
+            Some(max_args) if max_args > 0 && exchange_subs.len() > max_args => max_args,
+            _ => return Self::requests(exchange_subs),
+        };
+
+        let mut exchange_subs = exchange_subs.into_iter().peekable();
+        let mut requests = Vec::new();
+        while exchange_subs.peek().is_some() {
+            requests.extend(Self::requests(
+                exchange_subs.by_ref().take(max_args).collect(),
+            ));
+        }
+
+        requests
+    }
+
+    /// Number of subscribe requests generated by [`Self::batched_requests`] for the provided
+    /// number of [`ExchangeSub`]s, assuming [`Self::requests`] generates one request per batch.
+    ///
+    /// Useful for implementing [`Self::expected_responses`] for exchanges that send a single
+    /// response per subscribe request.
+    fn num_batched_requests(num_subs: usize) -> usize {
+        match Self::max_args_per_request() {
+            Some(max_args) if max_args > 0 => num_subs.div_ceil(max_args).max(1),
+            _ => 1,
+        }
+    }
+
     /// Number of [`Subscription`](crate::subscription::Subscription) responses expected from the
     /// exchange server in responses to the requests send. Used to validate all
     /// [`Subscription`](crate::subscription::Subscription)s were accepted.
@@ -138,6 +311,56 @@ where
     fn subscription_timeout() -> Duration {
         DEFAULT_SUBSCRIPTION_TIMEOUT
     }
+
+    /// [`ValidationStrategy`] used to determine that the actioned
+    /// [`Subscription`](crate::subscription::Subscription)s were accepted.
+    ///
+    /// Defaults to [`ValidationStrategy::Ack`], which waits for the [`Self::expected_responses`].
+    /// Exchanges that do not acknowledge subscriptions should use
+    /// [`ValidationStrategy::FirstData`] to avoid waiting out the [`Self::subscription_timeout`].
+    fn validation_strategy() -> ValidationStrategy {
+        ValidationStrategy::Ack
+    }
+
+    /// Name of the field containing the client-supplied request id (see [`next_request_id`])
+    /// that is embedded in [`Self::requests`] and echoed back by the exchange server in each
+    /// subscription response.
+    ///
+    /// When defined, the [`SubscriptionValidator`] correlates each subscription response with a
+    /// sent request, ignoring responses to unknown requests & awaiting a success response for
+    /// every sent request. Defaults to `None`.
+    fn request_id_field() -> Option<&'static str> {
+        None
+    }
+
+    /// Determine if the provided [`DataError`] is a known signal that the exchange server is
+    /// closed for maintenance, in which case re-connections are paused for the
+    /// [`ReconnectPolicy::maintenance_delay`](crate::streams::reconnect::ReconnectPolicy::maintenance_delay).
+    ///
+    /// Defaults to recognising the structured signals of [`DataError::is_maintenance`].
+    fn is_maintenance(error: &DataError) -> bool {
+        error.is_maintenance()
+    }
+
+    /// Classify the provided [`DataError`] (eg/ by exchange error code or close frame reason)
+    /// as [`ErrorClass::Retryable`] or [`ErrorClass::Terminal`], overriding how the consumer
+    /// loop would otherwise handle it.
+    ///
+    /// Defaults to `None` for every [`DataError`], so re-connection is only driven by the
+    /// [`ReconnectPolicy`](crate::streams::reconnect::ReconnectPolicy).
+    fn classify_error(_: &DataError) -> Option<ErrorClass> {
+        None
+    }
+
+    /// Determine if the provided [`DataError`] is a known exchange throttle notice (eg/ "Requests
+    /// too frequent"), returning the [`RateLimitUsage`] it reports.
+    ///
+    /// The consumer loop records the [`RateLimitUsage`] in the
+    /// [`ConnectionConfig::rate_limits`] monitor, if any. Defaults to `None` for every
+    /// [`DataError`].
+    fn rate_limit_usage(_: &DataError) -> Option<RateLimitUsage> {
+        None
+    }
 }
 
 /// Used when an exchange has servers different
@@ -152,6 +375,31 @@ pub trait ExchangeServer: Default + Debug + Clone + Send {
     fn websocket_url() -> &'static str;
 }
 
+/// Generate a new process-wide unique client-supplied subscription request id.
+pub fn next_request_id() -> u64 {
+    static REQUEST_ID: AtomicU64 = AtomicU64::new(1);
+    REQUEST_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Extract the request id contained in the `field` of a JSON text [`WsMessage`], if any.
+///
+/// Request ids may be encoded as a JSON number (eg/ Kraken "reqid") or a numeric string
+/// (eg/ Okx "id").
+pub fn extract_request_id(field: &str, message: &WsMessage) -> Option<u64> {
+    let WsMessage::Text(text) = message else {
+        return None;
+    };
+
+    match serde_json::from_str::<serde_json::Value>(text)
+        .ok()?
+        .get(field)?
+    {
+        serde_json::Value::Number(id) => id.as_u64(),
+        serde_json::Value::String(id) => id.parse().ok(),
+        _ => None,
+    }
+}
+
 /// Defines the frequency and construction function for custom
 /// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) pings - used for exchanges
 /// that require additional application-level pings.
@@ -184,6 +432,7 @@ pub enum ExchangeId {
     GateioPerpetualsUsd,
     GateioOptions,
     Kraken,
+    KrakenV2,
     Okx,
 }
 
@@ -199,7 +448,51 @@ impl Display for ExchangeId {
     }
 }
 
+impl FromStr for ExchangeId {
+    type Err = SocketError;
+
+    /// Parse the snake_case &str representation produced by [`ExchangeId::as_str`].
+    fn from_str(exchange: &str) -> Result<Self, Self::Err> {
+        ExchangeId::ALL
+            .iter()
+            .find(|exchange_id| exchange_id.as_str() == exchange)
+            .copied()
+            .ok_or_else(|| SocketError::Unsupported {
+                entity: "ExchangeId",
+                item: exchange.to_string(),
+            })
+    }
+}
+
+impl TryFrom<&str> for ExchangeId {
+    type Error = SocketError;
+
+    fn try_from(exchange: &str) -> Result<Self, Self::Error> {
+        Self::from_str(exchange)
+    }
+}
+
 impl ExchangeId {
+    /// Every [`ExchangeId`] variant.
+    pub const ALL: &'static [ExchangeId] = &[
+        ExchangeId::BinanceFuturesUsd,
+        ExchangeId::BinanceSpot,
+        ExchangeId::Bitfinex,
+        ExchangeId::Bitmex,
+        ExchangeId::BybitSpot,
+        ExchangeId::BybitPerpetualsUsd,
+        ExchangeId::Coinbase,
+        ExchangeId::GateioSpot,
+        ExchangeId::GateioFuturesUsd,
+        ExchangeId::GateioFuturesBtc,
+        ExchangeId::GateioPerpetualsBtc,
+        ExchangeId::GateioPerpetualsUsd,
+        ExchangeId::GateioOptions,
+        ExchangeId::Kraken,
+        ExchangeId::KrakenV2,
+        ExchangeId::Okx,
+    ];
+
     /// Return the &str representation of this [`ExchangeId`]
     pub fn as_str(&self) -> &'static str {
         match self {
@@ -217,6 +510,7 @@ impl ExchangeId {
             ExchangeId::GateioPerpetualsBtc => "gateio_perpetuals_btc",
             ExchangeId::GateioOptions => "gateio_options",
             ExchangeId::Kraken => "kraken",
+            ExchangeId::KrakenV2 => "kraken_v2",
             ExchangeId::Okx => "okx",
         }
     }
@@ -254,4 +548,396 @@ impl ExchangeId {
             (_, Option(_)) => false,
         }
     }
+
+    /// Resolve the [`ExchangeId`] server variant for the provided base exchange name (eg/ "binance")
+    /// that serves market data for the provided [`InstrumentKind`].
+    ///
+    /// eg/ ("binance", [`InstrumentKind::Spot`]) => [`ExchangeId::BinanceSpot`]
+    /// eg/ ("binance", [`InstrumentKind::Perpetual`]) => [`ExchangeId::BinanceFuturesUsd`]
+    ///
+    /// ### Notes
+    /// Gateio futures & perpetuals resolve to the USD settled servers. BTC settled servers must be
+    /// selected explicitly.
+    #[allow(clippy::result_large_err)]
+    pub fn resolve(exchange: &str, instrument_kind: InstrumentKind) -> Result<Self, SocketError> {
+        use ExchangeId::*;
+        use InstrumentKind::*;
+
+        let candidate = match (exchange.to_lowercase().as_str(), instrument_kind) {
+            ("binance", Spot) => Some(BinanceSpot),
+            ("binance", Perpetual) => Some(BinanceFuturesUsd),
+            ("bitfinex", _) => Some(Bitfinex),
+            ("bitmex", _) => Some(Bitmex),
+            ("bybit", Spot) => Some(BybitSpot),
+            ("bybit", Perpetual) => Some(BybitPerpetualsUsd),
+            ("coinbase", _) => Some(Coinbase),
+            ("gateio", Spot) => Some(GateioSpot),
+            ("gateio", Future(_)) => Some(GateioFuturesUsd),
+            ("gateio", Perpetual) => Some(GateioPerpetualsUsd),
+            ("gateio", Option(_)) => Some(GateioOptions),
+            ("kraken", _) => Some(Kraken),
+            ("okx", _) => Some(Okx),
+            _ => None,
+        };
+
+        candidate
+            .filter(|exchange_id| exchange_id.supports(instrument_kind))
+            .ok_or_else(|| SocketError::Unsupported {
+                entity: "ExchangeId::resolve",
+                item: format!("{exchange} {instrument_kind}"),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::kind::{
+        FutureContract, OptionContract, OptionExercise, OptionKind,
+    };
+    use chrono::Utc;
+
+    #[test]
+    fn test_connector_is_maintenance() {
+        struct TestCase {
+            exchange: fn(&DataError) -> bool,
+            payload: &'static str,
+            expected: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Bitfinex info event w/ server restart code
+                exchange: bitfinex::Bitfinex::is_maintenance,
+                payload: r#"{"event":"info","code":20051,"msg":"Stopping. Please try to reconnect"}"#,
+                expected: true,
+            },
+            TestCase {
+                // TC1: Bitfinex info event w/ maintenance ended code
+                exchange: bitfinex::Bitfinex::is_maintenance,
+                payload: r#"{"event":"info","code":20061,"msg":"Maintenance ended"}"#,
+                expected: false,
+            },
+            TestCase {
+                // TC2: Okx service upgrade notice
+                exchange: okx::Okx::is_maintenance,
+                payload: r#"{"event":"notice","code":"64008","msg":"The connection will soon be closed for a service upgrade. Please reconnect.","connId":"a4d3ae55"}"#,
+                expected: true,
+            },
+            TestCase {
+                // TC3: Okx error that only mentions maintenance
+                exchange: okx::Okx::is_maintenance,
+                payload: r#"{"event":"error","code":"60012","msg":"Invalid request: maintenance"}"#,
+                expected: false,
+            },
+            TestCase {
+                // TC4: Binance server shutdown event
+                exchange: binance::spot::BinanceSpot::is_maintenance,
+                payload: r#"{"e":"serverShutdown","E":1730000000000}"#,
+                expected: true,
+            },
+            TestCase {
+                // TC5: Binance unrelated event
+                exchange: binance::spot::BinanceSpot::is_maintenance,
+                payload: r#"{"e":"maintenance","E":1730000000000}"#,
+                expected: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let error = DataError::from(SocketError::Deserialise {
+                error: serde_json::from_str::<u8>(test.payload).unwrap_err(),
+                payload: test.payload.to_string(),
+            });
+            assert_eq!((test.exchange)(&error), test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_connector_rate_limit_usage() {
+        struct TestCase {
+            exchange: fn(&DataError) -> Option<RateLimitUsage>,
+            error: DataError,
+            expected: Option<(ExchangeId, u32, u32)>,
+        }
+
+        let notice = |payload: &str| {
+            DataError::from(SocketError::Deserialise {
+                error: serde_json::from_str::<u8>(payload).unwrap_err(),
+                payload: payload.to_string(),
+            })
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: Okx subscription rejected as too frequent
+                exchange: okx::Okx::rate_limit_usage,
+                error: DataError::from(SocketError::Subscribe(
+                    "received failure subscription response code: 60014 with message: Requests too frequent.".to_owned(),
+                )),
+                expected: Some((ExchangeId::Okx, 3, 3)),
+            },
+            TestCase {
+                // TC1: Okx requests too frequent error consumed mid-stream
+                exchange: okx::Okx::rate_limit_usage,
+                error: notice(r#"{"event":"error","code":"60014","msg":"Requests too frequent."}"#),
+                expected: Some((ExchangeId::Okx, 3, 3)),
+            },
+            TestCase {
+                // TC2: Okx unrelated error
+                exchange: okx::Okx::rate_limit_usage,
+                error: notice(r#"{"event":"error","code":"60018","msg":"Wrong URL or channel doesn't exist."}"#),
+                expected: None,
+            },
+            TestCase {
+                // TC3: exchange without throttle notices
+                exchange: binance::spot::BinanceSpot::rate_limit_usage,
+                error: notice(r#"{"event":"error","code":"60014","msg":"Requests too frequent."}"#),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual =
+                (test.exchange)(&test.error).map(|usage| (usage.exchange, usage.used, usage.limit));
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_connector_batched_requests() {
+        struct TestCase {
+            num_subs: usize,
+            expected: Vec<(usize, usize)>,
+        }
+
+        let max = bybit::MAX_ARGS_PER_REQUEST_BYBIT;
+
+        let tests = vec![
+            TestCase {
+                // TC0: fewer subscriptions than the cap are sent in one request
+                num_subs: 1,
+                expected: vec![(0, 1)],
+            },
+            TestCase {
+                // TC1: subscriptions equal to the cap are sent in one request
+                num_subs: max,
+                expected: vec![(0, max)],
+            },
+            TestCase {
+                // TC2: large subscription is chunked, w/ the remainder in the final request
+                num_subs: max * 2 + 1,
+                expected: vec![(0, max), (max, max * 2), (max * 2, max * 2 + 1)],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let exchange_subs = (0..test.num_subs)
+                .map(|market| ExchangeSub {
+                    channel: bybit::channel::BybitChannel::TRADES,
+                    market: bybit::market::BybitMarket(format!("M{market}")),
+                })
+                .collect::<Vec<_>>();
+
+            let actual = bybit::spot::BybitSpot::batched_requests(exchange_subs);
+            let expected = test
+                .expected
+                .into_iter()
+                .map(|(start, end)| {
+                    let args = (start..end)
+                        .map(|market| format!("publicTrade.M{market}"))
+                        .collect::<Vec<_>>();
+                    WsMessage::Text(
+                        serde_json::json!({ "op": "subscribe", "args": args }).to_string(),
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, expected, "TC{} failed", index);
+            assert_eq!(
+                bybit::spot::BybitSpot::num_batched_requests(test.num_subs),
+                actual.len(),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_exchange_id_from_str_round_trip() {
+        // Exhaustive match without a wildcard, so adding an ExchangeId variant fails to compile
+        // until it is given the next index here, & so must be appended to ExchangeId::ALL
+        fn index(exchange_id: ExchangeId) -> usize {
+            match exchange_id {
+                ExchangeId::BinanceFuturesUsd => 0,
+                ExchangeId::BinanceSpot => 1,
+                ExchangeId::Bitfinex => 2,
+                ExchangeId::Bitmex => 3,
+                ExchangeId::BybitSpot => 4,
+                ExchangeId::BybitPerpetualsUsd => 5,
+                ExchangeId::Coinbase => 6,
+                ExchangeId::GateioSpot => 7,
+                ExchangeId::GateioFuturesUsd => 8,
+                ExchangeId::GateioFuturesBtc => 9,
+                ExchangeId::GateioPerpetualsBtc => 10,
+                ExchangeId::GateioPerpetualsUsd => 11,
+                ExchangeId::GateioOptions => 12,
+                ExchangeId::Kraken => 13,
+                ExchangeId::KrakenV2 => 14,
+                ExchangeId::Okx => 15,
+            }
+        }
+        assert_eq!(ExchangeId::ALL.len(), index(ExchangeId::Okx) + 1);
+        for (expected, exchange_id) in ExchangeId::ALL.iter().enumerate() {
+            assert_eq!(
+                index(*exchange_id),
+                expected,
+                "{exchange_id} is out of place"
+            );
+        }
+
+        for exchange_id in ExchangeId::ALL {
+            assert_eq!(
+                ExchangeId::from_str(exchange_id.as_str()).unwrap(),
+                *exchange_id
+            );
+            assert_eq!(
+                ExchangeId::try_from(exchange_id.as_str()).unwrap(),
+                *exchange_id
+            );
+
+            // as_str is consistent w/ the serde rename
+            assert_eq!(
+                serde_json::to_string(exchange_id).unwrap(),
+                format!("\"{}\"", exchange_id.as_str())
+            );
+        }
+
+        for garbage in ["", "binance", "Okx", "okx ", "not_an_exchange"] {
+            assert!(
+                matches!(
+                    ExchangeId::from_str(garbage),
+                    Err(SocketError::Unsupported {
+                        entity: "ExchangeId",
+                        ..
+                    })
+                ),
+                "{garbage} should fail to parse"
+            );
+        }
+    }
+
+    #[test]
+    fn test_exchange_id_resolve() {
+        struct TestCase {
+            exchange: &'static str,
+            instrument_kind: InstrumentKind,
+            expected: Result<ExchangeId, SocketError>,
+        }
+
+        let future = InstrumentKind::Future(FutureContract { expiry: Utc::now() });
+        let option = InstrumentKind::Option(OptionContract {
+            kind: OptionKind::Call,
+            exercise: OptionExercise::European,
+            expiry: Utc::now(),
+            strike: rust_decimal_macros::dec!(50000),
+        });
+
+        let tests = vec![
+            TestCase {
+                // TC0: Binance Spot
+                exchange: "binance",
+                instrument_kind: InstrumentKind::Spot,
+                expected: Ok(ExchangeId::BinanceSpot),
+            },
+            TestCase {
+                // TC1: Binance Perpetual w/ mixed case exchange name
+                exchange: "Binance",
+                instrument_kind: InstrumentKind::Perpetual,
+                expected: Ok(ExchangeId::BinanceFuturesUsd),
+            },
+            TestCase {
+                // TC2: Bybit Perpetual
+                exchange: "bybit",
+                instrument_kind: InstrumentKind::Perpetual,
+                expected: Ok(ExchangeId::BybitPerpetualsUsd),
+            },
+            TestCase {
+                // TC3: Gateio Future
+                exchange: "gateio",
+                instrument_kind: future,
+                expected: Ok(ExchangeId::GateioFuturesUsd),
+            },
+            TestCase {
+                // TC4: Gateio Option
+                exchange: "gateio",
+                instrument_kind: option,
+                expected: Ok(ExchangeId::GateioOptions),
+            },
+            TestCase {
+                // TC5: Okx Option
+                exchange: "okx",
+                instrument_kind: option,
+                expected: Ok(ExchangeId::Okx),
+            },
+            TestCase {
+                // TC6: Coinbase Spot
+                exchange: "coinbase",
+                instrument_kind: InstrumentKind::Spot,
+                expected: Ok(ExchangeId::Coinbase),
+            },
+            TestCase {
+                // TC7: unsupported Binance Future
+                exchange: "binance",
+                instrument_kind: future,
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+            TestCase {
+                // TC8: unsupported Coinbase Perpetual
+                exchange: "coinbase",
+                instrument_kind: InstrumentKind::Perpetual,
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+            TestCase {
+                // TC9: unsupported Bitmex Spot
+                exchange: "bitmex",
+                instrument_kind: InstrumentKind::Spot,
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+            TestCase {
+                // TC10: unknown exchange
+                exchange: "unknown",
+                instrument_kind: InstrumentKind::Spot,
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = ExchangeId::resolve(test.exchange, test.instrument_kind);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(SocketError::Unsupported { .. }), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
 }
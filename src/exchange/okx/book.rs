@@ -0,0 +1,409 @@
+use super::trade::de_okx_message_arg_as_subscription_id;
+use crate::{
+    error::DataError,
+    subscription::book::{Level, OrderBook, OrderBookSide},
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{de::IgnoredAny, Deserialize, Deserializer};
+use tokio::sync::mpsc;
+
+/// Terse type alias for an [`Okx`](super::Okx) tick-by-tick OrderBook Level2 snapshot or
+/// update WebSocket message.
+pub type OkxOrderBookL2Tbt = OkxBookMessage<OkxBook>;
+
+/// [`Okx`](super::Okx) OrderBook WebSocket message, an
+/// [`OkxMessage`](super::trade::OkxMessage) with an additional "action" distinguishing snapshots
+/// from updates.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+/// #### Snapshot
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "books-l2-tbt",
+///     "instId": "BTC-USDT"
+///   },
+///   "action": "snapshot",
+///   "data": [
+///     {
+///       "asks": [["8476.98", "415", "0", "13"], ["8477", "7", "0", "2"]],
+///       "bids": [["8476.97", "256", "0", "12"], ["8475.55", "101", "0", "1"]],
+///       "ts": "1597026383085",
+///       "checksum": -855196043,
+///       "prevSeqId": -1,
+///       "seqId": 123456
+///     }
+///   ]
+/// }
+/// ```
+///
+/// #### Update
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "books-l2-tbt",
+///     "instId": "BTC-USDT"
+///   },
+///   "action": "update",
+///   "data": [
+///     {
+///       "asks": [["8476.98", "0", "0", "0"]],
+///       "bids": [["8476.5", "12", "0", "1"]],
+///       "ts": "1597026383086",
+///       "checksum": -1200119424,
+///       "prevSeqId": 123456,
+///       "seqId": 123457
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize)]
+pub struct OkxBookMessage<T> {
+    #[serde(
+        rename = "arg",
+        deserialize_with = "de_okx_message_arg_as_subscription_id"
+    )]
+    pub subscription_id: SubscriptionId,
+    pub action: OkxBookAction,
+    pub data: Vec<T>,
+}
+
+/// [`Okx`](super::Okx) OrderBook message "action".
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OkxBookAction {
+    Snapshot,
+    Update,
+}
+
+impl<T> Identifier<Option<SubscriptionId>> for OkxBookMessage<T> {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+/// [`Okx`](super::Okx) OrderBook snapshot or update data.
+///
+/// See [`OkxBookMessage`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxBook {
+    #[serde(default)]
+    pub bids: Vec<OkxLevel>,
+    #[serde(default)]
+    pub asks: Vec<OkxLevel>,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    pub checksum: i64,
+    pub prev_seq_id: i64,
+    pub seq_id: i64,
+}
+
+/// [`Okx`](super::Okx) OrderBook level.
+///
+/// Deserialised from the raw `[price, amount, deprecated, number_of_orders]` array, of which
+/// only the price & amount are used.
+///
+/// See [`OkxBookMessage`] for full raw payload examples.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct OkxLevel {
+    pub price: f64,
+    pub amount: f64,
+}
+
+impl<'de> Deserialize<'de> for OkxLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (price, amount, _, _) =
+            <(&str, &str, IgnoredAny, IgnoredAny)>::deserialize(deserializer)?;
+
+        Ok(Self {
+            price: price.parse().map_err(serde::de::Error::custom)?,
+            amount: amount.parse().map_err(serde::de::Error::custom)?,
+        })
+    }
+}
+
+impl From<OkxLevel> for Level {
+    fn from(level: OkxLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+/// [`Okx`](super::Okx) [`OrderBookUpdater`] for the "books" & tick-by-tick "books-l2-tbt"
+/// channels, which share the same message format.
+///
+/// Okx: How To Maintain A Local OrderBook
+///
+/// 1. Subscribe to the "books" or "books-l2-tbt" channel (requires login), after which a
+///    "snapshot" is received.
+/// 2. Any "snapshot" replaces the local OrderBook.
+/// 3. Drop any "update" received before the first "snapshot".
+/// 4. Each "update" prevSeqId must equal the previous message seqId, otherwise data was missed.
+/// 5. The data in each "update" is the absolute quantity for a price level.
+/// 6. If the quantity is 0, remove the price level.
+///
+/// Notes:
+///  - The "checksum" is not verified since it is computed from the exact decimal strings of
+///    the top 25 levels, which are not preserved once parsed as `f64`.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct OkxBookUpdater {
+    pub last_seq_id: Option<i64>,
+}
+
+#[async_trait]
+impl OrderBookUpdater for OkxBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = OkxOrderBookL2Tbt;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Initial OrderBook snapshot is received over the WebSocket after subscribing
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::default(),
+            book: OrderBook {
+                last_update_time: DateTime::default(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            },
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<Self::OrderBook>, DataError> {
+        // Okx: How To Maintain A Local OrderBook
+        // See Self's Rust Docs for more information on each numbered step
+        for data in update.data {
+            match update.action {
+                // 2. Any snapshot replaces the local OrderBook:
+                OkxBookAction::Snapshot => {
+                    book.bids = OrderBookSide::new(Side::Buy, data.bids);
+                    book.asks = OrderBookSide::new(Side::Sell, data.asks);
+                }
+                OkxBookAction::Update => {
+                    // 3. Drop any update received before the first snapshot:
+                    let Some(last_seq_id) = self.last_seq_id else {
+                        return Ok(None);
+                    };
+
+                    // 4. Each update prevSeqId must equal the previous message seqId:
+                    if data.prev_seq_id != last_seq_id {
+                        return Err(DataError::InvalidSequence {
+                            prev_last_update_id: last_seq_id as u64,
+                            first_update_id: data.prev_seq_id as u64,
+                        });
+                    }
+
+                    // 5. The data in each update is the absolute quantity for a price level.
+                    // 6. If the quantity is 0, remove the price level.
+                    book.bids.upsert(data.bids);
+                    book.asks.upsert(data.asks);
+                }
+            }
+
+            // Update OrderBook & OrderBookUpdater metadata
+            book.last_update_time = data.time;
+            self.last_seq_id = Some(data.seq_id);
+        }
+
+        Ok(Some(book.snapshot()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_okx_order_book_l2_tbt() {
+            struct TestCase {
+                input: &'static str,
+                expected: OkxOrderBookL2Tbt,
+            }
+
+            let cases = vec![
+                // TC0: input snapshot is deserialised
+                TestCase {
+                    input: r#"
+                    {
+                        "arg": {"channel": "books-l2-tbt", "instId": "BTC-USDT"},
+                        "action": "snapshot",
+                        "data": [
+                            {
+                                "asks": [["8476.98", "415", "0", "13"], ["8477", "7", "0", "2"]],
+                                "bids": [["8476.97", "256", "0", "12"]],
+                                "ts": "1597026383085",
+                                "checksum": -855196043,
+                                "prevSeqId": -1,
+                                "seqId": 123456
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: OkxBookMessage {
+                        subscription_id: SubscriptionId::from("books-l2-tbt|BTC-USDT"),
+                        action: OkxBookAction::Snapshot,
+                        data: vec![OkxBook {
+                            bids: vec![OkxLevel {
+                                price: 8476.97,
+                                amount: 256.0,
+                            }],
+                            asks: vec![
+                                OkxLevel {
+                                    price: 8476.98,
+                                    amount: 415.0,
+                                },
+                                OkxLevel {
+                                    price: 8477.0,
+                                    amount: 7.0,
+                                },
+                            ],
+                            time: DateTime::from_timestamp_millis(1597026383085).unwrap(),
+                            checksum: -855196043,
+                            prev_seq_id: -1,
+                            seq_id: 123456,
+                        }],
+                    },
+                },
+                // TC1: input update removing a level is deserialised
+                TestCase {
+                    input: r#"
+                    {
+                        "arg": {"channel": "books-l2-tbt", "instId": "BTC-USDT"},
+                        "action": "update",
+                        "data": [
+                            {
+                                "asks": [["8476.98", "0", "0", "0"]],
+                                "bids": [],
+                                "ts": "1597026383086",
+                                "checksum": -1200119424,
+                                "prevSeqId": 123456,
+                                "seqId": 123457
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: OkxBookMessage {
+                        subscription_id: SubscriptionId::from("books-l2-tbt|BTC-USDT"),
+                        action: OkxBookAction::Update,
+                        data: vec![OkxBook {
+                            bids: vec![],
+                            asks: vec![OkxLevel {
+                                price: 8476.98,
+                                amount: 0.0,
+                            }],
+                            time: DateTime::from_timestamp_millis(1597026383086).unwrap(),
+                            checksum: -1200119424,
+                            prev_seq_id: 123456,
+                            seq_id: 123457,
+                        }],
+                    },
+                },
+            ];
+
+            for (index, test) in cases.into_iter().enumerate() {
+                let actual = serde_json::from_str::<OkxOrderBookL2Tbt>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+
+    mod okx_book_updater {
+        use super::*;
+
+        fn book_update(action: OkxBookAction, prev_seq_id: i64, seq_id: i64) -> OkxOrderBookL2Tbt {
+            OkxBookMessage {
+                subscription_id: SubscriptionId::from("books-l2-tbt|BTC-USDT"),
+                action,
+                data: vec![OkxBook {
+                    bids: vec![OkxLevel {
+                        price: 100.0,
+                        amount: seq_id as f64,
+                    }],
+                    asks: vec![],
+                    time: DateTime::from_timestamp_millis(seq_id).unwrap(),
+                    checksum: 0,
+                    prev_seq_id,
+                    seq_id,
+                }],
+            }
+        }
+
+        #[test]
+        fn test_update() {
+            struct TestCase {
+                input: OkxOrderBookL2Tbt,
+                expected_best_bid_amount: Result<Option<f64>, ()>,
+            }
+
+            let mut updater = OkxBookUpdater::default();
+            let mut book = OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            };
+
+            let cases = vec![
+                // TC0: update before the first snapshot is dropped
+                TestCase {
+                    input: book_update(OkxBookAction::Update, 9, 10),
+                    expected_best_bid_amount: Ok(None),
+                },
+                // TC1: snapshot replaces the OrderBook
+                TestCase {
+                    input: book_update(OkxBookAction::Snapshot, -1, 20),
+                    expected_best_bid_amount: Ok(Some(20.0)),
+                },
+                // TC2: sequenced update is applied
+                TestCase {
+                    input: book_update(OkxBookAction::Update, 20, 21),
+                    expected_best_bid_amount: Ok(Some(21.0)),
+                },
+                // TC3: update with a gap in the sequence is rejected
+                TestCase {
+                    input: book_update(OkxBookAction::Update, 25, 26),
+                    expected_best_bid_amount: Err(()),
+                },
+            ];
+
+            for (index, test) in cases.into_iter().enumerate() {
+                let actual = updater
+                    .update(&mut book, test.input)
+                    .map(|book| book.map(|book| book.bids.levels()[0].amount))
+                    .map_err(|_| ());
+                assert_eq!(actual, test.expected_best_bid_amount, "TC{} failed", index);
+            }
+        }
+    }
+}
@@ -0,0 +1,336 @@
+use super::{channel::OkxChannel, trade::OkxMessage};
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::candle::Candle,
+};
+use barter_integration::{
+    de::{datetime_utc_from_epoch_duration, extract_next},
+    error::SocketError,
+    model::{instrument::Instrument, Exchange},
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// Terse type alias for an [`Okx`](super::Okx) real-time candles WebSocket message.
+pub type OkxCandles = OkxMessage<OkxCandle>;
+
+/// [`Okx`](super::Okx) real-time candle.
+///
+/// ### Raw Payload Examples
+/// Format: \[TS, OPEN, HIGH, LOW, CLOSE, VOL, VOL_CCY, VOL_CCY_QUOTE, CONFIRM\], <br> where
+/// CONFIRM is "0" if the candle is still forming, and "1" if it is closed.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-candlesticks-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "candle1m",
+///     "instId": "BTC-USDT"
+///   },
+///   "data": [
+///     ["1597026383085","8533.02","8553.74","8527.17","8548.26","45247","529.5858061","5289.23","0"]
+///   ]
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct OkxCandle {
+    pub start_time: DateTime<Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+    pub confirm: bool,
+}
+
+impl From<(ExchangeId, Instrument, OkxCandles)> for MarketIter<Candle> {
+    fn from((exchange_id, instrument, candles): (ExchangeId, Instrument, OkxCandles)) -> Self {
+        // Determine the CandleInterval from the subscribed channel (eg/ "candle1m|BTC-USDT")
+        let channel = candles
+            .subscription_id
+            .as_ref()
+            .split_once('|')
+            .map_or(candles.subscription_id.as_ref(), |(channel, _)| channel);
+        let Some(interval) = OkxChannel::candle_interval(channel) else {
+            return Self(vec![Err(DataError::from(SocketError::Unsupported {
+                entity: "Okx candle channel",
+                item: channel.to_owned(),
+            }))]);
+        };
+
+        candles
+            .data
+            .into_iter()
+            .map(|candle| {
+                Ok(MarketEvent {
+                    exchange_time: candle.start_time,
+                    received_time: Utc::now(),
+                    exchange_time_synthesized: false,
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: Candle {
+                        close_time: candle.start_time + interval.duration()
+                            - chrono::Duration::milliseconds(1),
+                        open: candle.open,
+                        high: candle.high,
+                        low: candle.low,
+                        close: candle.close,
+                        volume: candle.volume,
+                        trade_count: None,
+                        is_closed: candle.confirm,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for OkxCandle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = OkxCandle;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("OkxCandle struct from the Okx WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // Candle: [TS, OPEN, HIGH, LOW, CLOSE, VOL, VOL_CCY, VOL_CCY_QUOTE, CONFIRM]
+                let start_time_millis = parse_next::<u64, _>(&mut seq, "ts")?;
+                let open = parse_next(&mut seq, "open")?;
+                let high = parse_next(&mut seq, "high")?;
+                let low = parse_next(&mut seq, "low")?;
+                let close = parse_next(&mut seq, "close")?;
+                let volume = parse_next(&mut seq, "vol")?;
+                let _: serde::de::IgnoredAny = extract_next(&mut seq, "vol_ccy")?;
+                let _: serde::de::IgnoredAny = extract_next(&mut seq, "vol_ccy_quote")?;
+                let confirm =
+                    match extract_next::<SeqAccessor, String>(&mut seq, "confirm")?.as_str() {
+                        "0" => false,
+                        "1" => true,
+                        other => {
+                            return Err(serde::de::Error::invalid_value(
+                                serde::de::Unexpected::Str(other),
+                                &"\"0\" or \"1\"",
+                            ))
+                        }
+                    };
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Okx may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(OkxCandle {
+                    start_time: datetime_utc_from_epoch_duration(std::time::Duration::from_millis(
+                        start_time_millis,
+                    )),
+                    open,
+                    high,
+                    low,
+                    close,
+                    volume,
+                    confirm,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the OkxCandle message
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+/// Extract the next sequence element as a `String`, and parse it into `T`.
+fn parse_next<'de, T, SeqAccessor>(
+    seq: &mut SeqAccessor,
+    field: &'static str,
+) -> Result<T, SeqAccessor::Error>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+    SeqAccessor: serde::de::SeqAccess<'de>,
+{
+    extract_next::<SeqAccessor, String>(seq, field)?
+        .parse()
+        .map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use crate::subscription::candle::ClosedOnly;
+        use barter_integration::{
+            error::SocketError,
+            model::{instrument::kind::InstrumentKind, SubscriptionId},
+        };
+        use std::time::Duration;
+
+        #[test]
+        fn test_okx_message_candles() {
+            let input = r#"
+            {
+                "arg": {
+                    "channel": "candle1m",
+                    "instId": "BTC-USDT"
+                },
+                "data": [
+                    ["1597026383085","8533.02","8553.74","8527.17","8548.26","45247","529.5858061","5289.23","0"],
+                    ["1597026323085","8530.00","8540.00","8520.00","8533.02","1200","14.05","119876.5","1"]
+                ]
+            }
+            "#;
+
+            let actual = serde_json::from_str::<OkxCandles>(input);
+            let expected: Result<OkxCandles, SocketError> = Ok(OkxCandles {
+                subscription_id: SubscriptionId::from("candle1m|BTC-USDT"),
+                data: vec![
+                    OkxCandle {
+                        start_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1597026383085,
+                        )),
+                        open: 8533.02,
+                        high: 8553.74,
+                        low: 8527.17,
+                        close: 8548.26,
+                        volume: 45247.0,
+                        confirm: false,
+                    },
+                    OkxCandle {
+                        start_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1597026323085,
+                        )),
+                        open: 8530.0,
+                        high: 8540.0,
+                        low: 8520.0,
+                        close: 8533.02,
+                        volume: 1200.0,
+                        confirm: true,
+                    },
+                ],
+            });
+
+            match (actual, expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC failed")
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+
+            // Candle is_closed is mapped from the confirm flag
+            let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+            let candles = serde_json::from_str::<OkxCandles>(input).unwrap();
+            let is_closed =
+                <MarketIter<Candle> as From<(ExchangeId, Instrument, OkxCandles)>>::from((
+                    ExchangeId::Okx,
+                    instrument.clone(),
+                    candles,
+                ))
+                .0
+                .into_iter()
+                .map(|event| event.unwrap().kind.is_closed)
+                .collect::<Vec<_>>();
+            assert_eq!(is_closed, vec![false, true]);
+
+            // ClosedOnly filters out the still forming candle
+            let candles = serde_json::from_str::<ClosedOnly<OkxCandles>>(input).unwrap();
+            let closed = <MarketIter<Candle> as From<(
+                ExchangeId,
+                Instrument,
+                ClosedOnly<OkxCandles>,
+            )>>::from((ExchangeId::Okx, instrument, candles))
+            .0;
+            assert_eq!(closed.len(), 1);
+            assert!(closed[0].as_ref().unwrap().kind.is_closed);
+        }
+
+        #[test]
+        fn test_okx_candles_interval_from_channel() {
+            let candles = |channel: &str| {
+                serde_json::from_str::<OkxCandles>(&format!(
+                    r#"{{"arg":{{"channel":"{channel}","instId":"BTC-USDT"}},"data":[["1597026300000","8533.02","8553.74","8527.17","8548.26","45247","529.5858061","5289.23","1"]]}}"#
+                ))
+                .unwrap()
+            };
+
+            struct TestCase {
+                channel: &'static str,
+                expected: Option<chrono::Duration>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: 1 minute candle
+                    channel: "candle1m",
+                    expected: Some(chrono::Duration::minutes(1)),
+                },
+                TestCase {
+                    // TC1: 15 minute candle
+                    channel: "candle15m",
+                    expected: Some(chrono::Duration::minutes(15)),
+                },
+                TestCase {
+                    // TC2: 4 hour candle
+                    channel: "candle4H",
+                    expected: Some(chrono::Duration::hours(4)),
+                },
+                TestCase {
+                    // TC3: unrecognised candle channel
+                    channel: "candle7m",
+                    expected: None,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual =
+                    <MarketIter<Candle> as From<(ExchangeId, Instrument, OkxCandles)>>::from((
+                        ExchangeId::Okx,
+                        Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                        candles(test.channel),
+                    ))
+                    .0
+                    .remove(0);
+
+                match (actual, test.expected) {
+                    (Ok(event), Some(interval)) => {
+                        assert_eq!(
+                            event.kind.close_time,
+                            event.exchange_time + interval - chrono::Duration::milliseconds(1),
+                            "TC{} failed",
+                            index
+                        );
+                        assert_eq!(event.kind.trade_count, None, "TC{} failed", index);
+                    }
+                    (Err(DataError::Socket(error)), None)
+                        if matches!(*error, SocketError::Unsupported { .. }) =>
+                    {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        panic!("TC{index} failed. \nActual: {actual:?}\nExpected: {expected:?}\n")
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,15 @@
 use super::Okx;
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    subscriber::config::AccountTier,
+    subscription::{
+        book::{OrderBooksL2, OrderBooksL2Tbt},
+        candle::{CandleInterval, Candles, ClosedCandles, IntervalCandles},
+        derivatives::DerivativesStatistics,
+        greeks::OptionSummary,
+        liquidation::Liquidations,
+        trade::{PublicTrades, PublicTradesFeed, TradeFeed},
+        Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +26,86 @@ impl OkxChannel {
     ///
     /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-trades-channel>
     pub const TRADES: Self = Self("trades");
+
+    /// [`Okx`] real-time trades channel yielding every individual fill, rather than aggregated
+    /// trades.
+    ///
+    /// Requires VIP5+ account access, otherwise the subscription is rejected.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-all-trades-channel>
+    pub const TRADES_ALL: Self = Self("trades-all");
+
+    /// [`Okx`] real-time 1 minute candles channel.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-candlesticks-channel>
+    pub const CANDLES: Self = Self("candle1m");
+
+    /// [`Okx`] OrderBook Level2 channel, sending the top 400 levels & subsequent updates every
+    /// 100ms.
+    ///
+    /// Upgraded to the [`Self::ORDER_BOOK_L2_TBT`] channel if the connection is logged in & the
+    /// declared [`AccountTier`] allows it (see
+    /// [`Connector::select_channel`](crate::exchange::Connector::select_channel)).
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+    pub const ORDER_BOOK_L2: Self = Self("books");
+
+    /// [`Okx`] tick-by-tick OrderBook Level2 channel, sending every individual update.
+    ///
+    /// Requires a connection logged in with the
+    /// [`Credentials`](crate::subscriber::config::Credentials) (including the passphrase) of a
+    /// VIP4+ account, otherwise the subscription fails before connecting.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+    pub const ORDER_BOOK_L2_TBT: Self = Self("books-l2-tbt");
+
+    /// [`Okx`] real-time liquidation orders channel for every market of an instrument type.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-liquidation-orders-channel>
+    pub const LIQUIDATIONS: Self = Self("liquidation-orders");
+
+    /// [`Okx`] real-time mark price channel.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-mark-price-channel>
+    pub const MARK_PRICE: Self = Self("mark-price");
+
+    /// [`Okx`] real-time index tickers channel, subscribed to by underlying index (eg/
+    /// "BTC-USDT").
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-index-tickers-channel>
+    pub const INDEX_TICKERS: Self = Self("index-tickers");
+
+    /// [`Okx`] real-time perpetual funding rate channel.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-funding-rate-channel>
+    pub const FUNDING_RATE: Self = Self("funding-rate");
+
+    /// [`Okx`] real-time option summary channel, yielding the implied volatility & greeks of
+    /// every option contract of an instrument family (eg/ "BTC-USD").
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-option-summary-channel>
+    pub const OPTION_SUMMARY: Self = Self("opt-summary");
+
+    /// Minimum [`AccountTier`] VIP level required to subscribe to the channel, if any.
+    pub fn required_vip_level(&self) -> Option<u8> {
+        match *self {
+            Self::ORDER_BOOK_L2_TBT => Some(4),
+            Self::TRADES_ALL => Some(5),
+            _ => None,
+        }
+    }
+
+    /// Determine if the channel can only be subscribed to over a connection logged in with
+    /// [`Credentials`](crate::subscriber::config::Credentials).
+    pub fn requires_login(&self) -> bool {
+        *self == Self::ORDER_BOOK_L2_TBT
+    }
+
+    /// Determine if the provided [`AccountTier`] can subscribe to the channel.
+    pub fn is_accessible(&self, tier: AccountTier) -> bool {
+        self.required_vip_level()
+            .is_none_or(|required| tier.vip_level >= required)
+    }
 }
 
 impl Identifier<OkxChannel> for Subscription<Okx, PublicTrades> {
@@ -25,8 +114,341 @@ impl Identifier<OkxChannel> for Subscription<Okx, PublicTrades> {
     }
 }
 
+impl Identifier<OkxChannel> for Subscription<Okx, PublicTradesFeed> {
+    fn id(&self) -> OkxChannel {
+        match self.kind.feed {
+            TradeFeed::AllFills => OkxChannel::TRADES_ALL,
+            // TradeFeed::Ticker is rejected by StreamSelector::supports_kind
+            TradeFeed::Standard | TradeFeed::Ticker => OkxChannel::TRADES,
+        }
+    }
+}
+
+impl OkxChannel {
+    /// [`Okx`] real-time candles channel of the provided [`CandleInterval`].
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-candlesticks-channel>
+    pub fn candles(interval: CandleInterval) -> Self {
+        match interval {
+            CandleInterval::S1 => Self("candle1s"),
+            CandleInterval::M1 => Self::CANDLES,
+            CandleInterval::M3 => Self("candle3m"),
+            CandleInterval::M5 => Self("candle5m"),
+            CandleInterval::M15 => Self("candle15m"),
+            CandleInterval::M30 => Self("candle30m"),
+            CandleInterval::H1 => Self("candle1H"),
+            CandleInterval::H2 => Self("candle2H"),
+            CandleInterval::H4 => Self("candle4H"),
+            CandleInterval::H6 => Self("candle6H"),
+            CandleInterval::H12 => Self("candle12H"),
+            CandleInterval::D1 => Self("candle1D"),
+            CandleInterval::W1 => Self("candle1W"),
+        }
+    }
+
+    /// Determine the [`CandleInterval`] of an [`OkxChannel::candles`] channel name (eg/
+    /// "candle15m"), if it is one.
+    pub fn candle_interval(channel: &str) -> Option<CandleInterval> {
+        let interval = match channel {
+            "candle1s" => CandleInterval::S1,
+            "candle1m" => CandleInterval::M1,
+            "candle3m" => CandleInterval::M3,
+            "candle5m" => CandleInterval::M5,
+            "candle15m" => CandleInterval::M15,
+            "candle30m" => CandleInterval::M30,
+            "candle1H" => CandleInterval::H1,
+            "candle2H" => CandleInterval::H2,
+            "candle4H" => CandleInterval::H4,
+            "candle6H" => CandleInterval::H6,
+            "candle12H" => CandleInterval::H12,
+            "candle1D" => CandleInterval::D1,
+            "candle1W" => CandleInterval::W1,
+            _ => return None,
+        };
+
+        Some(interval)
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, Candles> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::CANDLES
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, ClosedCandles> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::CANDLES
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, IntervalCandles> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::candles(self.kind.interval)
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, OrderBooksL2> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::ORDER_BOOK_L2
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, OrderBooksL2Tbt> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::ORDER_BOOK_L2_TBT
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, Liquidations> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::LIQUIDATIONS
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, DerivativesStatistics> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::MARK_PRICE
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, OptionSummary> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::OPTION_SUMMARY
+    }
+}
+
+impl From<&'static str> for OkxChannel {
+    fn from(channel: &'static str) -> Self {
+        Self(channel)
+    }
+}
+
 impl AsRef<str> for OkxChannel {
     fn as_ref(&self) -> &str {
         self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{Connector, StreamSelector},
+        subscriber::{
+            config::{ConnectionConfig, Credentials},
+            mapper::{SubscriptionMapper, WebSocketSubMapper},
+        },
+    };
+    use barter_integration::{
+        error::SocketError,
+        model::{instrument::kind::InstrumentKind, SubscriptionId},
+    };
+
+    #[test]
+    fn test_okx_channel_for_trades_kind() {
+        let trades = Subscription::from((Okx, "btc", "usdt", InstrumentKind::Spot, PublicTrades));
+        let feed = |feed: TradeFeed| {
+            Subscription::from((
+                Okx,
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                PublicTradesFeed::new(feed),
+            ))
+        };
+
+        assert_eq!(Identifier::<OkxChannel>::id(&trades), OkxChannel::TRADES);
+        assert_eq!(
+            Identifier::<OkxChannel>::id(&feed(TradeFeed::default())),
+            OkxChannel::TRADES
+        );
+        assert_eq!(
+            Identifier::<OkxChannel>::id(&feed(TradeFeed::AllFills)),
+            OkxChannel::TRADES_ALL
+        );
+        assert_eq!(OkxChannel::TRADES_ALL.as_ref(), "trades-all");
+
+        // Okx has no ticker trade source, so it is rejected before connecting
+        assert!(!<Okx as StreamSelector<PublicTradesFeed>>::supports_kind(
+            &PublicTradesFeed::new(TradeFeed::Ticker)
+        ));
+    }
+
+    #[test]
+    fn test_okx_select_channel() {
+        struct TestCase {
+            config: ConnectionConfig,
+            input: OkxChannel,
+            expected: Result<OkxChannel, ()>,
+        }
+
+        let logged_in = ConnectionConfig::default()
+            .credentials(Credentials::new("key", "secret").passphrase("passphrase"));
+
+        let tests = vec![
+            TestCase {
+                // TC0: undeclared AccountTier keeps the default channel
+                config: logged_in.clone(),
+                input: OkxChannel::ORDER_BOOK_L2,
+                expected: Ok(OkxChannel::ORDER_BOOK_L2),
+            },
+            TestCase {
+                // TC1: regular AccountTier keeps the standard OrderBook channel
+                config: logged_in.clone().account_tier(AccountTier::REGULAR),
+                input: OkxChannel::ORDER_BOOK_L2,
+                expected: Ok(OkxChannel::ORDER_BOOK_L2),
+            },
+            TestCase {
+                // TC2: logged in VIP4 AccountTier upgrades to the tick-by-tick OrderBook channel
+                config: logged_in.clone().account_tier(AccountTier::vip(4)),
+                input: OkxChannel::ORDER_BOOK_L2,
+                expected: Ok(OkxChannel::ORDER_BOOK_L2_TBT),
+            },
+            TestCase {
+                // TC3: VIP4 AccountTier without Credentials cannot log in, so is not upgraded
+                config: ConnectionConfig::default().account_tier(AccountTier::vip(4)),
+                input: OkxChannel::ORDER_BOOK_L2,
+                expected: Ok(OkxChannel::ORDER_BOOK_L2),
+            },
+            TestCase {
+                // TC4: tick-by-tick OrderBook channel is denied below VIP4
+                config: logged_in.clone().account_tier(AccountTier::vip(3)),
+                input: OkxChannel::ORDER_BOOK_L2_TBT,
+                expected: Err(()),
+            },
+            TestCase {
+                // TC5: all trades channel is denied below VIP5
+                config: logged_in.clone().account_tier(AccountTier::vip(4)),
+                input: OkxChannel::TRADES_ALL,
+                expected: Err(()),
+            },
+            TestCase {
+                // TC6: ungated channel is accessible at every AccountTier
+                config: logged_in.clone().account_tier(AccountTier::REGULAR),
+                input: OkxChannel::TRADES,
+                expected: Ok(OkxChannel::TRADES),
+            },
+            TestCase {
+                // TC7: VIP4 AccountTier w/ Credentials lacking a passphrase is not upgraded
+                config: ConnectionConfig::default()
+                    .credentials(Credentials::new("key", "secret"))
+                    .account_tier(AccountTier::vip(4)),
+                input: OkxChannel::ORDER_BOOK_L2,
+                expected: Ok(OkxChannel::ORDER_BOOK_L2),
+            },
+            TestCase {
+                // TC8: tick-by-tick OrderBook channel is denied without Credentials
+                config: ConnectionConfig::default(),
+                input: OkxChannel::ORDER_BOOK_L2_TBT,
+                expected: Err(()),
+            },
+            TestCase {
+                // TC9: tick-by-tick OrderBook channel is denied w/ Credentials lacking a passphrase
+                config: ConnectionConfig::default().credentials(Credentials::new("key", "secret")),
+                input: OkxChannel::ORDER_BOOK_L2_TBT,
+                expected: Err(()),
+            },
+            TestCase {
+                // TC10: tick-by-tick OrderBook channel is accessible once logged in, if the
+                // AccountTier is undeclared
+                config: logged_in,
+                input: OkxChannel::ORDER_BOOK_L2_TBT,
+                expected: Ok(OkxChannel::ORDER_BOOK_L2_TBT),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = Okx::select_channel(test.input, &test.config).map_err(|_| ());
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_okx_order_books_l2_mapped_by_account_tier() {
+        let books = [Subscription::from((
+            Okx,
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            OrderBooksL2,
+        ))];
+        let logged_in = ConnectionConfig::default()
+            .credentials(Credentials::new("key", "secret").passphrase("passphrase"));
+
+        let standard = WebSocketSubMapper::map(&books, &logged_in).unwrap();
+        assert!(standard
+            .instrument_map
+            .find(&SubscriptionId::from("books|BTC-USDT"))
+            .is_ok());
+
+        let vip = logged_in.clone().account_tier(AccountTier::vip(5));
+        let tbt = WebSocketSubMapper::map(&books, &vip).unwrap();
+        assert!(tbt
+            .instrument_map
+            .find(&SubscriptionId::from("books-l2-tbt|BTC-USDT"))
+            .is_ok());
+
+        // Denied channel fails with a clear error before connecting
+        let denied = WebSocketSubMapper::map(
+            &[Subscription::from((
+                Okx,
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                PublicTradesFeed::new(TradeFeed::AllFills),
+            ))],
+            &logged_in.account_tier(AccountTier::vip(2)),
+        )
+        .unwrap_err();
+        assert_eq!(
+            denied.to_string(),
+            SocketError::Subscribe(
+                "Okx channel trades-all requires a VIP5+ AccountTier, but VIP2 is declared"
+                    .to_owned()
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_okx_order_books_l2_tbt_requires_login() {
+        let tbt = [Subscription::from((
+            Okx,
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            OrderBooksL2Tbt,
+        ))];
+
+        struct TestCase {
+            config: ConnectionConfig,
+            expected: &'static str,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: missing Credentials
+                config: ConnectionConfig::default(),
+                expected: "Okx channel books-l2-tbt requires a logged in connection, but no \
+                    Credentials are configured",
+            },
+            TestCase {
+                // TC1: Credentials missing the passphrase
+                config: ConnectionConfig::default().credentials(Credentials::new("key", "secret")),
+                expected: "Okx channel books-l2-tbt requires a logged in connection, but the \
+                    configured Credentials have no passphrase",
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = WebSocketSubMapper::map(&tbt, &test.config).unwrap_err();
+            assert_eq!(
+                actual.to_string(),
+                SocketError::Subscribe(test.expected.to_owned()).to_string(),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
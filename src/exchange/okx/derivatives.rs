@@ -0,0 +1,393 @@
+use super::{channel::OkxChannel, market::OkxMarket, Okx};
+use crate::{
+    error::DataError,
+    event::{exchange_time_or_received, MarketEvent},
+    exchange::{Connector, ExchangeSub},
+    subscription::{
+        derivatives::{DerivativesStatistics, DerivativesStats},
+        Map,
+    },
+    transformer::{
+        ticker::{FieldDelta, TickerMerger},
+        ExchangeTransformer,
+    },
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, Exchange, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+
+/// [`Okx`] real-time "mark-price", "index-tickers" or "funding-rate" WebSocket message, each
+/// delivering a subset of the [`DerivativesStats`] of a market.
+///
+/// ### Notes
+/// Messages without any "data" (eg/ the subscription responses of the "index-tickers" &
+/// "funding-rate" channels) yield no [`DerivativesStats`].
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-mark-price-channel>
+/// ```json
+/// {
+///     "arg": {"channel": "mark-price", "instId": "BTC-USDT-SWAP"},
+///     "data": [{"instType": "SWAP", "instId": "BTC-USDT-SWAP", "markPx": "42310.6", "ts": "1630049139746"}]
+/// }
+/// ```
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-index-tickers-channel>
+/// ```json
+/// {
+///     "arg": {"channel": "index-tickers", "instId": "BTC-USDT"},
+///     "data": [{"instId": "BTC-USDT", "idxPx": "42305.2", "open24h": "41000.1", "ts": "1630049139752"}]
+/// }
+/// ```
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-funding-rate-channel>
+/// ```json
+/// {
+///     "arg": {"channel": "funding-rate", "instId": "BTC-USDT-SWAP"},
+///     "data": [{"instType": "SWAP", "instId": "BTC-USDT-SWAP", "fundingRate": "0.0001875", "fundingTime": "1630051200000", "ts": "1630049139760"}]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxDerivativesMessage {
+    pub arg: OkxDerivativesArg,
+    #[serde(default)]
+    pub data: Vec<OkxDerivativesData>,
+}
+
+/// Channel & "instId" of an [`OkxDerivativesMessage`].
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OkxDerivativesArg {
+    pub channel: String,
+    #[serde(rename = "instId")]
+    pub inst_id: String,
+}
+
+/// [`DerivativesStats`] subset contained in an [`OkxDerivativesMessage`].
+///
+/// See [`OkxDerivativesMessage`] for full raw payload examples.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxDerivativesData {
+    #[serde(rename = "markPx", default, deserialize_with = "de_opt_str_f64")]
+    pub mark_price: Option<f64>,
+    #[serde(rename = "idxPx", default, deserialize_with = "de_opt_str_f64")]
+    pub index_price: Option<f64>,
+    #[serde(rename = "fundingRate", default, deserialize_with = "de_opt_str_f64")]
+    pub funding_rate: Option<f64>,
+    #[serde(
+        rename = "fundingTime",
+        default,
+        deserialize_with = "de_opt_str_epoch_ms_as_datetime_utc"
+    )]
+    pub next_funding_time: Option<DateTime<Utc>>,
+    #[serde(
+        rename = "ts",
+        default,
+        deserialize_with = "de_opt_str_epoch_ms_as_datetime_utc"
+    )]
+    pub time: Option<DateTime<Utc>>,
+}
+
+impl FieldDelta<DerivativesStats> for OkxDerivativesData {
+    fn apply(&self, stats: &mut DerivativesStats) {
+        stats.mark_price = self.mark_price.or(stats.mark_price);
+        stats.index_price = self.index_price.or(stats.index_price);
+        stats.funding_rate = self.funding_rate.or(stats.funding_rate);
+        stats.next_funding_time = self.next_funding_time.or(stats.next_funding_time);
+    }
+}
+
+/// "index-tickers" (of the underlying index, eg/ "BTC-USDT") & "funding-rate" [`ExchangeSub`]s
+/// associated with the "mark-price" [`ExchangeSub`] of an [`OkxMarket`] (eg/ "BTC-USDT-SWAP").
+///
+/// See [`Connector::associated_subs`].
+pub fn associated_subs(market: &OkxMarket) -> Vec<ExchangeSub<OkxChannel, OkxMarket>> {
+    vec![
+        ExchangeSub {
+            channel: OkxChannel::INDEX_TICKERS,
+            market: OkxMarket(index_inst_id(&market.0)),
+        },
+        ExchangeSub {
+            channel: OkxChannel::FUNDING_RATE,
+            market: market.clone(),
+        },
+    ]
+}
+
+/// [`Okx`] [`ExchangeTransformer`] that combines the "mark-price", "index-tickers" &
+/// "funding-rate" channels of each subscribed market into a single [`DerivativesStats`] stream.
+///
+/// Each [`DerivativesStatistics`] [`Subscription`](crate::subscription::Subscription) subscribes
+/// to the "mark-price" channel, along with the [`associated_subs`] "index-tickers" &
+/// "funding-rate" channels. Every update yields the merged [`DerivativesStats`] of the market.
+#[derive(Clone, PartialEq, Debug)]
+pub struct OkxDerivativesTransformer {
+    instrument_map: Map<Instrument>,
+    index_markets: HashMap<String, Vec<SubscriptionId>>,
+    merger: TickerMerger<DerivativesStats>,
+}
+
+impl OkxDerivativesTransformer {
+    /// Determine the [`SubscriptionId`]s of the markets updated by an [`OkxDerivativesArg`].
+    fn subscription_ids(&self, arg: &OkxDerivativesArg) -> Vec<SubscriptionId> {
+        match arg.channel.as_str() {
+            channel
+                if channel == OkxChannel::MARK_PRICE.0 || channel == OkxChannel::FUNDING_RATE.0 =>
+            {
+                vec![mark_price_subscription_id(&arg.inst_id)]
+            }
+            channel if channel == OkxChannel::INDEX_TICKERS.0 => self
+                .index_markets
+                .get(&arg.inst_id)
+                .cloned()
+                .unwrap_or_default(),
+            _ => vec![],
+        }
+    }
+}
+
+#[async_trait]
+impl ExchangeTransformer<Okx, DerivativesStatistics> for OkxDerivativesTransformer {
+    async fn new(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+    ) -> Result<Self, DataError> {
+        // Determine the "mark-price" markets updated by each underlying index
+        let mut index_markets = HashMap::<String, Vec<SubscriptionId>>::new();
+        for subscription_id in instrument_map.0.keys() {
+            match subscription_id.as_ref().split_once('|') {
+                Some((channel, inst_id)) if channel == OkxChannel::MARK_PRICE.0 => index_markets
+                    .entry(index_inst_id(inst_id))
+                    .or_default()
+                    .push(subscription_id.clone()),
+                _ => continue,
+            }
+        }
+
+        Ok(Self {
+            instrument_map,
+            index_markets,
+            merger: TickerMerger::default(),
+        })
+    }
+}
+
+impl Transformer for OkxDerivativesTransformer {
+    type Error = DataError;
+    type Input = OkxDerivativesMessage;
+    type Output = MarketEvent<DerivativesStats>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        let subscription_ids = self.subscription_ids(&input.arg);
+
+        let mut events = Vec::new();
+        for data in &input.data {
+            let received_time = Utc::now();
+            let (exchange_time, exchange_time_synthesized) =
+                exchange_time_or_received(data.time, received_time);
+
+            for subscription_id in &subscription_ids {
+                let instrument = match self.instrument_map.find(subscription_id) {
+                    Ok(instrument) => instrument,
+                    Err(unidentifiable) => {
+                        events.push(Err(DataError::from(unidentifiable)));
+                        continue;
+                    }
+                };
+
+                events.push(Ok(MarketEvent {
+                    exchange_time,
+                    received_time,
+                    exchange_time_synthesized,
+                    exchange: Exchange::from(Okx::ID),
+                    instrument,
+                    kind: self.merger.apply(subscription_id, data),
+                }));
+            }
+        }
+
+        events
+    }
+}
+
+/// [`SubscriptionId`] of the "mark-price" channel of the provided "instId".
+///
+/// eg/ "mark-price|BTC-USDT-SWAP"
+fn mark_price_subscription_id(inst_id: &str) -> SubscriptionId {
+    SubscriptionId::from(format!("{}|{}", OkxChannel::MARK_PRICE.0, inst_id))
+}
+
+/// Underlying index "instId" of a derivatives "instId" (eg/ "BTC-USDT-SWAP" -> "BTC-USDT").
+fn index_inst_id(inst_id: &str) -> String {
+    inst_id.splitn(3, '-').take(2).collect::<Vec<_>>().join("-")
+}
+
+/// Deserialize an optional [`Okx`] string float, where an empty string is `None`.
+pub fn de_opt_str_f64<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    match <Option<&str> as Deserialize>::deserialize(deserializer)? {
+        None | Some("") => Ok(None),
+        Some(value) => value.parse().map(Some).map_err(serde::de::Error::custom),
+    }
+}
+
+/// Deserialize an optional [`Okx`] string epoch millisecond timestamp, where an empty string is
+/// `None`.
+fn de_opt_str_epoch_ms_as_datetime_utc<'de, D>(
+    deserializer: D,
+) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    de_opt_str_f64(deserializer).map(|millis| {
+        millis.and_then(|millis| DateTime::<Utc>::from_timestamp_millis(millis as i64))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        subscriber::{
+            config::ConnectionConfig,
+            mapper::{SubscriptionMapper, WebSocketSubMapper},
+        },
+        subscription::{Subscription, SubscriptionMeta},
+    };
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_okx_derivatives_transformer_combines_channels() {
+        let subscription = Subscription::from((
+            Okx,
+            "btc",
+            "usdt",
+            InstrumentKind::Perpetual,
+            DerivativesStatistics,
+        ));
+        let instrument = subscription.instrument.clone();
+
+        // Index & funding channels of the subscribed market are mapped as associated
+        // subscriptions, so they are batched & validated with the mark-price channel
+        let SubscriptionMeta {
+            instrument_map,
+            subscriptions,
+            ..
+        } = WebSocketSubMapper::map(&[subscription], &ConnectionConfig::default()).unwrap();
+        assert_eq!(subscriptions.len(), 1);
+        let WsMessage::Text(request) = &subscriptions[0] else {
+            panic!("Okx subscription request is not a text WsMessage")
+        };
+        let request = serde_json::from_str::<serde_json::Value>(request).unwrap();
+        assert_eq!(
+            request["args"],
+            json!([
+                {"channel": "mark-price", "instId": "BTC-USDT-SWAP"},
+                {"channel": "index-tickers", "instId": "BTC-USDT"},
+                {"channel": "funding-rate", "instId": "BTC-USDT-SWAP"},
+            ])
+        );
+        assert_eq!(Okx::expected_responses(&instrument_map), 3);
+
+        let (ws_sink_tx, mut ws_sink_rx) = mpsc::unbounded_channel();
+        let mut transformer = <OkxDerivativesTransformer as ExchangeTransformer<
+            Okx,
+            DerivativesStatistics,
+        >>::new(ws_sink_tx, instrument_map)
+        .await
+        .unwrap();
+
+        // Transformer sends no subscription requests of its own
+        assert!(ws_sink_rx.try_recv().is_err());
+
+        let inputs = [
+            // Subscription response of the index-tickers channel
+            r#"{"event": "subscribe", "arg": {"channel": "index-tickers", "instId": "BTC-USDT"}, "connId": "a4d3ae55"}"#,
+            r#"{
+                "arg": {"channel": "mark-price", "instId": "BTC-USDT-SWAP"},
+                "data": [{"instType": "SWAP", "instId": "BTC-USDT-SWAP", "markPx": "42310.6", "ts": "1630049139746"}]
+            }"#,
+            r#"{
+                "arg": {"channel": "index-tickers", "instId": "BTC-USDT"},
+                "data": [{"instId": "BTC-USDT", "idxPx": "42305.2", "open24h": "41000.1", "ts": "1630049139752"}]
+            }"#,
+            r#"{
+                "arg": {"channel": "funding-rate", "instId": "BTC-USDT-SWAP"},
+                "data": [{"instType": "SWAP", "instId": "BTC-USDT-SWAP", "fundingRate": "0.0001875", "nextFundingRate": "", "fundingTime": "1630051200000", "ts": "1630049139760"}]
+            }"#,
+        ];
+
+        let events = inputs
+            .into_iter()
+            .flat_map(|input| transformer.transform(serde_json::from_str(input).unwrap()))
+            .map(Result::unwrap)
+            .collect::<Vec<_>>();
+
+        // Each channel update yields the merged DerivativesStats of the market
+        assert_eq!(events.len(), 3);
+        assert!(events.iter().all(|event| event.instrument == instrument));
+        assert_eq!(
+            events[0].kind,
+            DerivativesStats {
+                mark_price: Some(42310.6),
+                ..DerivativesStats::default()
+            }
+        );
+        assert_eq!(
+            events[2].kind,
+            DerivativesStats {
+                mark_price: Some(42310.6),
+                index_price: Some(42305.2),
+                funding_rate: Some(0.0001875),
+                next_funding_time: DateTime::<Utc>::from_timestamp_millis(1630051200000),
+            }
+        );
+        assert_eq!(
+            events[2].exchange_time,
+            DateTime::<Utc>::from_timestamp_millis(1630049139760).unwrap()
+        );
+        assert!(events.iter().all(|event| !event.exchange_time_synthesized));
+    }
+
+    #[tokio::test]
+    async fn test_okx_derivatives_rejected_associated_subscription_fails_init() {
+        use crate::{
+            exchange::StreamSelector,
+            test_util::{MockExchange, MockScript},
+            MarketStream,
+        };
+
+        // Okx accepts the mark-price channel, but rejects the associated index-tickers channel
+        let exchange = MockExchange::start([MockScript::new()
+            .receive()
+            .send_text(r#"{"event":"subscribe","arg":{"channel":"mark-price","instId":"BTC-USDT-SWAP"}}"#)
+            .send_text(r#"{"event":"error","code":"60018","msg":"Wrong URL or channel:index-tickers,instId:BTC-USDT doesn't exist."}"#)])
+        .await
+        .unwrap();
+
+        let subscriptions = [Subscription::from((
+            Okx,
+            "btc",
+            "usdt",
+            InstrumentKind::Perpetual,
+            DerivativesStatistics,
+        ))];
+        let config = ConnectionConfig::default().url(exchange.url());
+
+        let error =
+            <Okx as StreamSelector<DerivativesStatistics>>::Stream::init(&subscriptions, &config)
+                .await
+                .unwrap_err();
+        assert!(error.to_string().contains("code: 60018"), "{error}");
+    }
+}
@@ -0,0 +1,323 @@
+use super::{channel::OkxChannel, derivatives::de_opt_str_f64};
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::greeks::OptionGreeks,
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Okx`](super::Okx) real-time "opt-summary" WebSocket message, summarising the implied
+/// volatility & greeks of every option contract of an instrument family (eg/ "BTC-USD").
+///
+/// ### Notes
+/// The "opt-summary" channel can only be subscribed to by instrument family, so each
+/// [`OkxOptionSummary`] is fanned out & those of un-subscribed option contracts are discarded.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-option-summary-channel>
+/// ```json
+/// {
+///     "arg": {"channel": "opt-summary", "instFamily": "BTC-USD"},
+///     "data": [
+///         {
+///             "instType": "OPTION",
+///             "instId": "BTC-USD-241013-65500-P",
+///             "uly": "BTC-USD",
+///             "delta": "-0.0418095504",
+///             "gamma": "0.0004908686",
+///             "vega": "0.0000039464",
+///             "theta": "-0.0000138392",
+///             "lever": "892.6023590438",
+///             "markVol": "0.4297605125",
+///             "bidVol": "0.4105957031",
+///             "askVol": "0.4511547851",
+///             "realVol": "",
+///             "volLv": "0.3398890982",
+///             "deltaBS": "-0.0429214232",
+///             "gammaBS": "0.0000310367",
+///             "thetaBS": "-50.4342660281",
+///             "vegaBS": "2.4473996836",
+///             "ts": "1728703155650",
+///             "fwdPx": "62650.3168747033"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxOptionSummaries {
+    pub data: Vec<OkxOptionSummary>,
+}
+
+impl IntoIterator for OkxOptionSummaries {
+    type Item = OkxOptionSummary;
+    type IntoIter = std::vec::IntoIter<OkxOptionSummary>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+/// [`Okx`](super::Okx) implied volatility & greeks of a single option contract.
+///
+/// The Black-Scholes greeks (eg/ "deltaBS") are used rather than the coin-denominated greeks
+/// (eg/ "delta"), so they are comparable with the [`OptionGreeks`] of other exchanges.
+///
+/// See [`OkxOptionSummaries`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxOptionSummary {
+    #[serde(
+        rename = "instId",
+        deserialize_with = "de_option_summary_subscription_id"
+    )]
+    pub subscription_id: SubscriptionId,
+    #[serde(rename = "markVol", default, deserialize_with = "de_opt_str_f64")]
+    pub mark_vol: Option<f64>,
+    #[serde(rename = "bidVol", default, deserialize_with = "de_opt_str_f64")]
+    pub bid_vol: Option<f64>,
+    #[serde(rename = "askVol", default, deserialize_with = "de_opt_str_f64")]
+    pub ask_vol: Option<f64>,
+    #[serde(rename = "deltaBS", default, deserialize_with = "de_opt_str_f64")]
+    pub delta: Option<f64>,
+    #[serde(rename = "gammaBS", default, deserialize_with = "de_opt_str_f64")]
+    pub gamma: Option<f64>,
+    #[serde(rename = "thetaBS", default, deserialize_with = "de_opt_str_f64")]
+    pub theta: Option<f64>,
+    #[serde(rename = "vegaBS", default, deserialize_with = "de_opt_str_f64")]
+    pub vega: Option<f64>,
+    #[serde(rename = "fwdPx", default, deserialize_with = "de_opt_str_f64")]
+    pub forward_price: Option<f64>,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl Identifier<Option<SubscriptionId>> for OkxOptionSummary {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl From<(ExchangeId, Instrument, OkxOptionSummary)> for MarketIter<OptionGreeks> {
+    fn from(
+        (exchange_id, instrument, summary): (ExchangeId, Instrument, OkxOptionSummary),
+    ) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: summary.time,
+            received_time: Utc::now(),
+            exchange_time_synthesized: false,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: OptionGreeks {
+                mark_iv: summary.mark_vol,
+                bid_iv: summary.bid_vol,
+                ask_iv: summary.ask_vol,
+                delta: summary.delta,
+                gamma: summary.gamma,
+                theta: summary.theta,
+                vega: summary.vega,
+                forward_price: summary.forward_price,
+            },
+        })])
+    }
+}
+
+/// Deserialize an [`OkxOptionSummary`] "instId" (eg/ "BTC-USD-241013-65500-P") as the associated
+/// [`SubscriptionId`] (eg/ SubscriptionId("opt-summary|BTC-USD-241013-65500-P")).
+pub fn de_option_summary_subscription_id<'de, D>(
+    deserializer: D,
+) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|inst_id| ExchangeSub::from((OkxChannel::OPTION_SUMMARY, inst_id)).id())
+}
+
+/// Extract the [`Okx`](super::Okx) "instFamily" (eg/ "BTC-USD") of an option "instId" (eg/
+/// "BTC-USD-241013-65500-P").
+pub fn inst_family(inst_id: &str) -> &str {
+    inst_id
+        .match_indices('-')
+        .nth(1)
+        .map_or(inst_id, |(index, _)| &inst_id[..index])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inst_family() {
+        struct TestCase {
+            input: &'static str,
+            expected: &'static str,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: option instId
+                input: "BTC-USD-241013-65500-P",
+                expected: "BTC-USD",
+            },
+            TestCase {
+                // TC1: instFamily is unchanged
+                input: "ETH-USD",
+                expected: "ETH-USD",
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(inst_family(test.input), test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_okx_option_summary_subscribed_by_inst_family() {
+        use crate::{
+            exchange::{okx::Okx, Connector},
+            subscriber::{
+                config::ConnectionConfig,
+                mapper::{SubscriptionMapper, WebSocketSubMapper},
+            },
+            subscription::{greeks::OptionSummary, Subscription},
+        };
+        use barter_integration::{
+            model::instrument::kind::{InstrumentKind, OptionContract, OptionExercise, OptionKind},
+            protocol::websocket::WsMessage,
+        };
+        use chrono::TimeZone;
+
+        let option = |kind, strike| {
+            Subscription::from((
+                Okx,
+                "btc",
+                "usd",
+                InstrumentKind::Option(OptionContract {
+                    kind,
+                    exercise: OptionExercise::European,
+                    expiry: Utc.with_ymd_and_hms(2024, 10, 13, 8, 0, 0).unwrap(),
+                    strike: rust_decimal::Decimal::from(strike),
+                }),
+                OptionSummary,
+            ))
+        };
+
+        let meta = WebSocketSubMapper::map::<Okx, OptionSummary>(
+            &[
+                option(OptionKind::Put, 65500),
+                option(OptionKind::Call, 70000),
+            ],
+            &ConnectionConfig::default(),
+        )
+        .unwrap();
+
+        // Option contracts of the same family share a single "instFamily" arg & ack
+        let WsMessage::Text(request) = &meta.subscriptions[0] else {
+            panic!("Okx subscription request is not a text WsMessage")
+        };
+        let request = serde_json::from_str::<serde_json::Value>(request).unwrap();
+        assert_eq!(
+            request["args"],
+            serde_json::json!([{"channel": "opt-summary", "instFamily": "BTC-USD"}])
+        );
+        assert_eq!(Okx::expected_responses(&meta.instrument_map), 1);
+
+        // Each option contract is routed by its own "instId"
+        for inst_id in ["BTC-USD-241013-65500-P", "BTC-USD-241013-70000-C"] {
+            assert!(meta
+                .instrument_map
+                .0
+                .contains_key(&SubscriptionId::from(format!("opt-summary|{inst_id}"))));
+        }
+    }
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_okx_option_summaries() {
+            let input = r#"
+            {
+                "arg": {"channel": "opt-summary", "instFamily": "BTC-USD"},
+                "data": [
+                    {
+                        "instType": "OPTION",
+                        "instId": "BTC-USD-241013-65500-P",
+                        "uly": "BTC-USD",
+                        "delta": "-0.0418095504",
+                        "gamma": "0.0004908686",
+                        "vega": "0.0000039464",
+                        "theta": "-0.0000138392",
+                        "lever": "892.6023590438",
+                        "markVol": "0.4297605125",
+                        "bidVol": "0.4105957031",
+                        "askVol": "0.4511547851",
+                        "realVol": "",
+                        "volLv": "0.3398890982",
+                        "deltaBS": "-0.0429214232",
+                        "gammaBS": "0.0000310367",
+                        "thetaBS": "-50.4342660281",
+                        "vegaBS": "2.4473996836",
+                        "ts": "1728703155650",
+                        "fwdPx": "62650.3168747033"
+                    },
+                    {
+                        "instType": "OPTION",
+                        "instId": "BTC-USD-241013-70000-C",
+                        "uly": "BTC-USD",
+                        "markVol": "0.5112334028",
+                        "bidVol": "",
+                        "askVol": "0.6618774414",
+                        "deltaBS": "0.0000902261",
+                        "gammaBS": "",
+                        "thetaBS": "",
+                        "vegaBS": "",
+                        "ts": "1728703155650",
+                        "fwdPx": "62650.3168747033"
+                    }
+                ]
+            }
+            "#;
+
+            let time = datetime_utc_from_epoch_duration(Duration::from_millis(1728703155650));
+            let expected = OkxOptionSummaries {
+                data: vec![
+                    OkxOptionSummary {
+                        subscription_id: SubscriptionId::from("opt-summary|BTC-USD-241013-65500-P"),
+                        mark_vol: Some(0.4297605125),
+                        bid_vol: Some(0.4105957031),
+                        ask_vol: Some(0.4511547851),
+                        delta: Some(-0.0429214232),
+                        gamma: Some(0.0000310367),
+                        theta: Some(-50.4342660281),
+                        vega: Some(2.4473996836),
+                        forward_price: Some(62650.3168747033),
+                        time,
+                    },
+                    OkxOptionSummary {
+                        subscription_id: SubscriptionId::from("opt-summary|BTC-USD-241013-70000-C"),
+                        mark_vol: Some(0.5112334028),
+                        bid_vol: None,
+                        ask_vol: Some(0.6618774414),
+                        delta: Some(0.0000902261),
+                        gamma: None,
+                        theta: None,
+                        vega: None,
+                        forward_price: Some(62650.3168747033),
+                        time,
+                    },
+                ],
+            };
+
+            let actual = serde_json::from_str::<OkxOptionSummaries>(input).unwrap();
+            assert_eq!(actual, expected);
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use super::market::OkxInstrumentId;
+use barter_integration::model::instrument::Instrument;
+use serde::{Deserialize, Serialize};
+
+/// [`Okx`](super::Okx) instrument "state" of an actively trading market.
+pub const OKX_INSTRUMENT_STATE_LIVE: &str = "live";
+
+/// [`Okx`](super::Okx) "instType"s requested when discovering instruments.
+///
+/// Options are not requested since an [`OkxInstrumentId`] cannot represent them.
+pub const OKX_DISCOVERY_INSTRUMENT_TYPES: [&str; 3] = ["SPOT", "SWAP", "FUTURES"];
+
+/// [`Okx`](super::Okx) REST instruments response for a single "instType".
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-rest-api-get-instruments>
+/// ```json
+/// {
+///   "code": "0",
+///   "msg": "",
+///   "data": [
+///     {
+///       "instType": "SWAP",
+///       "instId": "BTC-USDT-SWAP",
+///       "uly": "BTC-USDT",
+///       "settleCcy": "USDT",
+///       "ctVal": "0.01",
+///       "state": "live"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OkxInstruments {
+    pub data: Vec<OkxInstrument>,
+}
+
+/// [`Okx`](super::Okx) REST instrument.
+///
+/// See [`OkxInstruments`] for full raw payload examples.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OkxInstrument {
+    #[serde(rename = "instId")]
+    pub inst_id: String,
+    pub state: String,
+}
+
+impl OkxInstruments {
+    /// Actively trading [`Instrument`]s, skipping any "instId" that cannot be parsed as an
+    /// [`OkxInstrumentId`].
+    pub fn live_instruments(self) -> Vec<Instrument> {
+        self.data
+            .into_iter()
+            .filter(|instrument| instrument.state == OKX_INSTRUMENT_STATE_LIVE)
+            .filter_map(|instrument| instrument.inst_id.parse::<OkxInstrumentId>().ok())
+            .map(|inst_id| inst_id.instrument)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::kind::{FutureContract, InstrumentKind};
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_okx_instruments_live_instruments() {
+        let input = r#"
+        {
+            "code": "0",
+            "msg": "",
+            "data": [
+                {"instType": "SPOT", "instId": "BTC-USDT", "state": "live"},
+                {"instType": "SWAP", "instId": "ETH-USD-SWAP", "state": "live"},
+                {"instType": "FUTURES", "instId": "BTC-USD-230526", "state": "live"},
+                {"instType": "SPOT", "instId": "LUNA-USDT", "state": "suspend"},
+                {"instType": "OPTION", "instId": "BTC-USD-230526-30000-C", "state": "live"}
+            ]
+        }
+        "#;
+
+        let actual = serde_json::from_str::<OkxInstruments>(input)
+            .unwrap()
+            .live_instruments();
+
+        let expected = vec![
+            Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            Instrument::from(("eth", "usd", InstrumentKind::Perpetual)),
+            Instrument::from((
+                "btc",
+                "usd",
+                InstrumentKind::Future(FutureContract {
+                    expiry: Utc.with_ymd_and_hms(2023, 5, 26, 0, 0, 0).unwrap(),
+                }),
+            )),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
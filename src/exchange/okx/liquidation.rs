@@ -0,0 +1,300 @@
+use super::market::OkxInstrumentId;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::liquidation::Liquidation,
+    Identifier,
+};
+use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Okx`](super::Okx) real-time liquidation orders WebSocket message for every market of an
+/// instrument type (eg/ "SWAP").
+///
+/// ### Notes
+/// The "liquidation-orders" channel can only be subscribed to by instrument type, so it is
+/// subscribed to via a [`WILDCARD`](crate::subscription::WILDCARD) [`Instrument`], and the
+/// [`Instrument`] of each [`OkxLiquidation`] is identified from its "instId".
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-liquidation-orders-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "liquidation-orders",
+///     "instType": "SWAP"
+///   },
+///   "data": [
+///     {
+///       "details": [
+///         {
+///           "bkLoss": "0",
+///           "bkPx": "0.007831",
+///           "ccy": "",
+///           "posSide": "short",
+///           "side": "buy",
+///           "sz": "13",
+///           "ts": "1692266434010"
+///         }
+///       ],
+///       "instFamily": "IOST-USDT",
+///       "instId": "IOST-USDT-SWAP",
+///       "instType": "SWAP",
+///       "uly": "IOST-USDT"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxLiquidations {
+    #[serde(
+        rename = "arg",
+        deserialize_with = "de_okx_liquidations_arg_as_subscription_id"
+    )]
+    pub subscription_id: SubscriptionId,
+    pub data: Vec<OkxLiquidation>,
+}
+
+impl Identifier<Option<SubscriptionId>> for OkxLiquidations {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl IntoIterator for OkxLiquidations {
+    type Item = OkxLiquidation;
+    type IntoIter = std::vec::IntoIter<OkxLiquidation>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+/// [`Okx`](super::Okx) liquidation orders of a single market.
+///
+/// See [`OkxLiquidations`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxLiquidation {
+    #[serde(rename = "instId")]
+    pub inst_id: String,
+    pub details: Vec<OkxLiquidationDetail>,
+}
+
+impl Identifier<Option<Instrument>> for OkxLiquidation {
+    fn id(&self) -> Option<Instrument> {
+        self.inst_id
+            .parse::<OkxInstrumentId>()
+            .ok()
+            .map(|inst_id| inst_id.instrument)
+    }
+}
+
+/// [`Okx`](super::Okx) liquidation order.
+///
+/// ### Notes
+/// The quantity of a derivative liquidation is denominated in contracts.
+///
+/// See [`OkxLiquidations`] for full raw payload examples.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxLiquidationDetail {
+    pub side: Side,
+    #[serde(rename = "bkPx", deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(rename = "sz", deserialize_with = "barter_integration::de::de_str")]
+    pub quantity: f64,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl From<(ExchangeId, Instrument, OkxLiquidation)> for MarketIter<Liquidation> {
+    fn from(
+        (exchange_id, instrument, liquidation): (ExchangeId, Instrument, OkxLiquidation),
+    ) -> Self {
+        liquidation
+            .details
+            .into_iter()
+            .map(|detail| {
+                Ok(MarketEvent {
+                    exchange_time: detail.time,
+                    received_time: Utc::now(),
+                    exchange_time_synthesized: false,
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    kind: Liquidation {
+                        side: detail.side,
+                        price: detail.price,
+                        quantity: detail.quantity,
+                        time: detail.time,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+/// Deserialize an [`OkxLiquidations`] "arg" field as a Barter [`SubscriptionId`].
+fn de_okx_liquidations_arg_as_subscription_id<'de, D>(
+    deserializer: D,
+) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Arg<'a> {
+        channel: &'a str,
+        inst_type: &'a str,
+    }
+
+    Deserialize::deserialize(deserializer)
+        .map(|arg: Arg<'_>| ExchangeSub::from((arg.channel, arg.inst_type)).id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::okx::Okx,
+        subscription::{liquidation::Liquidations, Map, WILDCARD},
+        transformer::{stateless::StatelessWildcardTransformer, ExchangeTransformer},
+    };
+    use barter_integration::{
+        de::datetime_utc_from_epoch_duration, model::instrument::kind::InstrumentKind, Transformer,
+    };
+    use std::time::Duration;
+    use tokio::sync::mpsc;
+
+    const INPUT: &str = r#"
+    {
+        "arg": {
+            "channel": "liquidation-orders",
+            "instType": "SWAP"
+        },
+        "data": [
+            {
+                "details": [
+                    {
+                        "bkLoss": "0",
+                        "bkPx": "0.007831",
+                        "ccy": "",
+                        "posSide": "short",
+                        "side": "buy",
+                        "sz": "13",
+                        "ts": "1692266434010"
+                    }
+                ],
+                "instFamily": "IOST-USDT",
+                "instId": "IOST-USDT-SWAP",
+                "instType": "SWAP",
+                "uly": "IOST-USDT"
+            },
+            {
+                "details": [
+                    {
+                        "bkLoss": "0",
+                        "bkPx": "29000.5",
+                        "ccy": "",
+                        "posSide": "long",
+                        "side": "sell",
+                        "sz": "2",
+                        "ts": "1692266434020"
+                    },
+                    {
+                        "bkLoss": "0",
+                        "bkPx": "28999.5",
+                        "ccy": "",
+                        "posSide": "long",
+                        "side": "sell",
+                        "sz": "1",
+                        "ts": "1692266434030"
+                    }
+                ],
+                "instFamily": "BTC-USD",
+                "instId": "BTC-USD-SWAP",
+                "instType": "SWAP",
+                "uly": "BTC-USD"
+            }
+        ]
+    }
+    "#;
+
+    #[test]
+    fn test_okx_liquidations() {
+        let actual = serde_json::from_str::<OkxLiquidations>(INPUT).unwrap();
+
+        assert_eq!(
+            actual.id(),
+            Some(SubscriptionId::from("liquidation-orders|SWAP"))
+        );
+        assert_eq!(
+            actual.data[0].details,
+            vec![OkxLiquidationDetail {
+                side: Side::Buy,
+                price: 0.007831,
+                quantity: 13.0,
+                time: datetime_utc_from_epoch_duration(Duration::from_millis(1692266434010)),
+            }]
+        );
+        assert_eq!(
+            actual.data[1].id(),
+            Some(Instrument::from(("btc", "usd", InstrumentKind::Perpetual)))
+        );
+    }
+
+    #[test]
+    fn test_okx_wildcard_liquidations_subscription_arg() {
+        let subscription = crate::subscription::Subscription::from((
+            Okx,
+            WILDCARD,
+            WILDCARD,
+            InstrumentKind::Perpetual,
+            Liquidations,
+        ));
+
+        let actual = serde_json::to_value(ExchangeSub::new(&subscription)).unwrap();
+
+        assert_eq!(
+            actual,
+            serde_json::json!({"channel": "liquidation-orders", "instType": "SWAP"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_okx_wildcard_liquidations_routed_per_symbol() {
+        let wildcard = Instrument::from((WILDCARD, WILDCARD, InstrumentKind::Perpetual));
+        let instrument_map =
+            Map::from_iter([(SubscriptionId::from("liquidation-orders|SWAP"), wildcard)]);
+
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+        let mut transformer = <StatelessWildcardTransformer<
+            Okx,
+            Liquidations,
+            OkxLiquidations,
+        > as ExchangeTransformer<Okx, Liquidations>>::new(ws_sink_tx, instrument_map)
+        .await
+        .unwrap();
+
+        let actual = transformer
+            .transform(serde_json::from_str::<OkxLiquidations>(INPUT).unwrap())
+            .into_iter()
+            .map(|event| {
+                let event = event.unwrap();
+                (event.instrument, event.kind.side, event.kind.price)
+            })
+            .collect::<Vec<_>>();
+
+        let iost = Instrument::from(("iost", "usdt", InstrumentKind::Perpetual));
+        let btc = Instrument::from(("btc", "usd", InstrumentKind::Perpetual));
+        let expected = vec![
+            (iost, Side::Buy, 0.007831),
+            (btc.clone(), Side::Sell, 29000.5),
+            (btc, Side::Sell, 28999.5),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
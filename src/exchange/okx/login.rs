@@ -0,0 +1,100 @@
+use crate::subscriber::config::Credentials;
+use barter_integration::protocol::websocket::WsMessage;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use hmac::{Hmac, Mac};
+use serde_json::json;
+use sha2::Sha256;
+
+/// [`Okx`](super::Okx) request path signed alongside the timestamp to generate a WebSocket login
+/// signature.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-websocket-login>
+pub const OKX_LOGIN_REQUEST_PATH: &str = "/users/self/verify";
+
+/// Construct the [`Okx`](super::Okx) WebSocket login request for the provided [`Credentials`],
+/// signed at the provided Unix `timestamp` (seconds).
+///
+/// Returns `None` if the [`Credentials`] have no passphrase, since Okx rejects every login
+/// without one.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-websocket-login>
+/// ```json
+/// {
+///   "op": "login",
+///   "args": [
+///     {
+///       "apiKey": "985d5b66-57ce-40fb-b714-afc0b9787083",
+///       "passphrase": "123456",
+///       "timestamp": "1538054050",
+///       "sign": "7L+zFQ+CEgGu5rzCj4+BdV2/uUHGqddA9pI6ztsRRPs="
+///     }
+///   ]
+/// }
+/// ```
+pub fn okx_login_request(credentials: &Credentials, timestamp: i64) -> Option<WsMessage> {
+    let passphrase = credentials.passphrase.as_deref()?;
+    let timestamp = timestamp.to_string();
+
+    Some(WsMessage::Text(
+        json!({
+            "op": "login",
+            "args": [{
+                "apiKey": credentials.api_key,
+                "passphrase": passphrase,
+                "timestamp": timestamp,
+                "sign": okx_login_signature(&credentials.secret, &timestamp),
+            }],
+        })
+        .to_string(),
+    ))
+}
+
+/// Generate the [`Okx`](super::Okx) WebSocket login signature: the Base64 encoded
+/// HMAC-SHA256 of `timestamp + "GET" + "/users/self/verify"`, keyed by the API secret.
+pub fn okx_login_signature(secret: &str, timestamp: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(timestamp.as_bytes());
+    mac.update(b"GET");
+    mac.update(OKX_LOGIN_REQUEST_PATH.as_bytes());
+
+    STANDARD.encode(mac.finalize().into_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_okx_login_request() {
+        let credentials = Credentials::new(
+            "985d5b66-57ce-40fb-b714-afc0b9787083",
+            "22582BD0CFF14C41EDBF1AB98506286D",
+        )
+        .passphrase("123456");
+
+        // Okx rejects logins without a passphrase, so no login request is constructed
+        assert_eq!(
+            okx_login_request(&Credentials::new("key", "secret"), 1538054050),
+            None
+        );
+
+        let Some(WsMessage::Text(actual)) = okx_login_request(&credentials, 1538054050) else {
+            panic!("Okx login request is not a text WsMessage")
+        };
+
+        let actual = serde_json::from_str::<serde_json::Value>(&actual).unwrap();
+        let expected = json!({
+            "op": "login",
+            "args": [{
+                "apiKey": "985d5b66-57ce-40fb-b714-afc0b9787083",
+                "passphrase": "123456",
+                "timestamp": "1538054050",
+                "sign": "+LdIr8lkkvhr5hoA3g9TMC0+uQJ849ftAcocA/ouu4M=",
+            }],
+        });
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -1,14 +1,31 @@
 use super::Okx;
-use crate::{subscription::Subscription, Identifier};
-use barter_integration::model::instrument::{
-    kind::{InstrumentKind, OptionKind},
-    Instrument,
+use crate::{
+    subscription::{
+        book::{OrderBookDeltas, OrderBooksL2, OrderBooksL2Tbt},
+        candle::{Candles, ClosedCandles},
+        derivatives::DerivativesStatistics,
+        greeks::OptionSummary,
+        liquidation::Liquidations,
+        raw::RawChannel,
+        trade::{FilteredTrades, PublicTrades, PublicTradesFeed},
+        Subscription,
+    },
+    Identifier,
+};
+use barter_integration::{
+    error::SocketError,
+    model::instrument::{
+        kind::{FutureContract, InstrumentKind, OptionKind},
+        symbol::Symbol,
+        Instrument,
+    },
 };
 use chrono::{
     format::{DelayedFormat, StrftimeItems},
-    DateTime, Utc,
+    DateTime, NaiveDate, Utc,
 };
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 /// Type that defines how to translate a Barter [`Subscription`] into a
 /// [`Okx`](super::Okx) market that can be subscribed to.
@@ -17,31 +34,101 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct OkxMarket(pub String);
 
-impl<Kind> Identifier<OkxMarket> for Subscription<Okx, Kind> {
+impl<Kind> Identifier<OkxMarket> for Subscription<Okx, Kind>
+where
+    Kind: OkxMarketKind,
+{
     fn id(&self) -> OkxMarket {
-        use InstrumentKind::*;
-        let Instrument { base, quote, kind } = &self.instrument;
+        Kind::market(&self.instrument)
+    }
+}
 
-        OkxMarket(match kind {
-            Spot => format!("{base}-{quote}").to_uppercase(),
-            Future(future) => {
-                format!("{base}-{quote}-{}", format_expiry(future.expiry)).to_uppercase()
+/// Defines how a Barter [`Subscription`] [`Instrument`] of a
+/// [`SubKind`](crate::subscription::SubKind) is translated into an [`OkxMarket`].
+pub trait OkxMarketKind {
+    fn market(instrument: &Instrument) -> OkxMarket;
+}
+
+macro_rules! impl_okx_market_kind_inst_id {
+    ($($kind:ty),+) => {
+        $(
+            impl OkxMarketKind for $kind {
+                fn market(instrument: &Instrument) -> OkxMarket {
+                    inst_id(instrument)
+                }
             }
-            Perpetual => format!("{base}-{quote}-SWAP").to_uppercase(),
-            Option(option) => format!(
-                "{base}-{quote}-{}-{}-{}",
-                format_expiry(option.expiry),
-                option.strike,
-                match option.kind {
-                    OptionKind::Call => "C",
-                    OptionKind::Put => "P",
-                },
-            )
-            .to_uppercase(),
-        })
+        )+
+    };
+}
+
+impl_okx_market_kind_inst_id!(
+    PublicTrades,
+    PublicTradesFeed,
+    FilteredTrades,
+    Candles,
+    ClosedCandles,
+    OrderBooksL2,
+    OrderBooksL2Tbt,
+    DerivativesStatistics,
+    OptionSummary
+);
+
+impl OkxMarketKind for Liquidations {
+    /// Okx "liquidation-orders" are subscribed to by instrument type (eg/ "SWAP").
+    fn market(instrument: &Instrument) -> OkxMarket {
+        use InstrumentKind::*;
+        OkxMarket(
+            match instrument.kind {
+                Spot => "MARGIN",
+                Future(_) => "FUTURES",
+                Perpetual => "SWAP",
+                Option(_) => "OPTION",
+            }
+            .to_owned(),
+        )
+    }
+}
+
+impl<Kind> OkxMarketKind for RawChannel<Kind>
+where
+    Kind: OkxMarketKind,
+{
+    fn market(instrument: &Instrument) -> OkxMarket {
+        Kind::market(instrument)
     }
 }
 
+impl<Kind> OkxMarketKind for OrderBookDeltas<Kind>
+where
+    Kind: OkxMarketKind,
+{
+    fn market(instrument: &Instrument) -> OkxMarket {
+        Kind::market(instrument)
+    }
+}
+
+/// Translate a Barter [`Instrument`] into an [`OkxMarket`] "instId" (eg/ "BTC-USDT-SWAP").
+fn inst_id(instrument: &Instrument) -> OkxMarket {
+    use InstrumentKind::*;
+    let Instrument { base, quote, kind } = instrument;
+
+    OkxMarket(match kind {
+        Spot => format!("{base}-{quote}").to_uppercase(),
+        Future(future) => format!("{base}-{quote}-{}", format_expiry(future.expiry)).to_uppercase(),
+        Perpetual => format!("{base}-{quote}-SWAP").to_uppercase(),
+        Option(option) => format!(
+            "{base}-{quote}-{}-{}-{}",
+            format_expiry(option.expiry),
+            option.strike,
+            match option.kind {
+                OptionKind::Call => "C",
+                OptionKind::Put => "P",
+            },
+        )
+        .to_uppercase(),
+    })
+}
+
 impl AsRef<str> for OkxMarket {
     fn as_ref(&self) -> &str {
         &self.0
@@ -56,3 +143,238 @@ impl AsRef<str> for OkxMarket {
 fn format_expiry<'a>(expiry: DateTime<Utc>) -> DelayedFormat<StrftimeItems<'a>> {
     expiry.date_naive().format("%g%m%d")
 }
+
+/// Whether an [`Okx`](super::Okx) contract is margined & settled in the quote currency (linear),
+/// or in the base currency (inverse).
+///
+/// Quantities of an inverse contract are denominated in contracts of quote currency value
+/// (eg/ USD) rather than the base currency, so they must be interpreted differently.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OkxContractMargin {
+    Linear,
+    Inverse,
+}
+
+/// Decomposed [`Okx`](super::Okx) instrument id (eg/ "BTC-USD-SWAP").
+///
+/// The Barter [`Instrument`] cannot carry the settlement details of a contract, so they are
+/// stored alongside it.
+///
+/// ### Examples
+/// - "BTC-USDT": spot, linear, settled in "usdt"
+/// - "BTC-USDT-SWAP": perpetual, linear, settled in "usdt"
+/// - "BTC-USD-SWAP": perpetual, inverse, settled in "btc"
+/// - "BTC-USD-230526": future expiring 26th of May 2023, inverse, settled in "btc"
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#rest-api-public-data-get-instruments>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OkxInstrumentId {
+    pub instrument: Instrument,
+    pub settlement: Symbol,
+    pub margin: OkxContractMargin,
+}
+
+impl FromStr for OkxInstrumentId {
+    type Err = SocketError;
+
+    /// Parse an [`Okx`](super::Okx) "instId" into an [`OkxInstrumentId`].
+    ///
+    /// Options (eg/ "BTC-USD-230526-30000-C") are not supported.
+    fn from_str(inst_id: &str) -> Result<Self, Self::Err> {
+        let unsupported = || SocketError::Unsupported {
+            entity: "OkxInstrumentId",
+            item: inst_id.to_owned(),
+        };
+
+        let mut parts = inst_id.split('-');
+        let (Some(base), Some(quote)) = (parts.next(), parts.next()) else {
+            return Err(unsupported());
+        };
+        if base.is_empty() || quote.is_empty() {
+            return Err(unsupported());
+        }
+
+        let kind = match (parts.next(), parts.next()) {
+            (None, None) => InstrumentKind::Spot,
+            (Some(suffix), None) if suffix.eq_ignore_ascii_case("SWAP") => {
+                InstrumentKind::Perpetual
+            }
+            (Some(expiry), None) => InstrumentKind::Future(FutureContract {
+                expiry: parse_expiry(expiry).ok_or_else(unsupported)?,
+            }),
+            _ => return Err(unsupported()),
+        };
+
+        // Okx contracts quoted in USD are inverse (coin-margined), all others are linear
+        let margin = match kind {
+            InstrumentKind::Spot => OkxContractMargin::Linear,
+            _ if quote.eq_ignore_ascii_case("USD") => OkxContractMargin::Inverse,
+            _ => OkxContractMargin::Linear,
+        };
+
+        let settlement = match margin {
+            OkxContractMargin::Linear => Symbol::new(quote),
+            OkxContractMargin::Inverse => Symbol::new(base),
+        };
+
+        Ok(Self {
+            instrument: Instrument::from((base, quote, kind)),
+            settlement,
+            margin,
+        })
+    }
+}
+
+impl TryFrom<&OkxMarket> for OkxInstrumentId {
+    type Error = SocketError;
+
+    fn try_from(market: &OkxMarket) -> Result<Self, Self::Error> {
+        market.as_ref().parse()
+    }
+}
+
+/// Parse an Okx API compatible expiry (eg/ "230526") into a midnight UTC DateTime<Utc>.
+fn parse_expiry(expiry: &str) -> Option<DateTime<Utc>> {
+    if expiry.len() != 6 || !expiry.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    NaiveDate::parse_from_str(&format!("20{expiry}"), "%Y%m%d")
+        .ok()?
+        .and_hms_opt(0, 0, 0)
+        .map(|expiry| expiry.and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_okx_instrument_id_from_str() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<OkxInstrumentId, SocketError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: spot
+                input: "BTC-USDT",
+                expected: Ok(OkxInstrumentId {
+                    instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                    settlement: Symbol::new("usdt"),
+                    margin: OkxContractMargin::Linear,
+                }),
+            },
+            TestCase {
+                // TC1: linear perpetual
+                input: "BTC-USDT-SWAP",
+                expected: Ok(OkxInstrumentId {
+                    instrument: Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+                    settlement: Symbol::new("usdt"),
+                    margin: OkxContractMargin::Linear,
+                }),
+            },
+            TestCase {
+                // TC2: inverse perpetual
+                input: "BTC-USD-SWAP",
+                expected: Ok(OkxInstrumentId {
+                    instrument: Instrument::from(("btc", "usd", InstrumentKind::Perpetual)),
+                    settlement: Symbol::new("btc"),
+                    margin: OkxContractMargin::Inverse,
+                }),
+            },
+            TestCase {
+                // TC3: inverse dated future
+                input: "BTC-USD-230526",
+                expected: Ok(OkxInstrumentId {
+                    instrument: Instrument::from((
+                        "btc",
+                        "usd",
+                        InstrumentKind::Future(FutureContract {
+                            expiry: Utc.with_ymd_and_hms(2023, 5, 26, 0, 0, 0).unwrap(),
+                        }),
+                    )),
+                    settlement: Symbol::new("btc"),
+                    margin: OkxContractMargin::Inverse,
+                }),
+            },
+            TestCase {
+                // TC4: linear dated future
+                input: "ETH-USDT-231229",
+                expected: Ok(OkxInstrumentId {
+                    instrument: Instrument::from((
+                        "eth",
+                        "usdt",
+                        InstrumentKind::Future(FutureContract {
+                            expiry: Utc.with_ymd_and_hms(2023, 12, 29, 0, 0, 0).unwrap(),
+                        }),
+                    )),
+                    settlement: Symbol::new("usdt"),
+                    margin: OkxContractMargin::Linear,
+                }),
+            },
+            TestCase {
+                // TC5: unsupported option
+                input: "BTC-USD-230526-30000-C",
+                expected: Err(SocketError::Unsupported {
+                    entity: "OkxInstrumentId",
+                    item: "BTC-USD-230526-30000-C".to_owned(),
+                }),
+            },
+            TestCase {
+                // TC6: invalid expiry
+                input: "BTC-USD-2305",
+                expected: Err(SocketError::Unsupported {
+                    entity: "OkxInstrumentId",
+                    item: "BTC-USD-2305".to_owned(),
+                }),
+            },
+            TestCase {
+                // TC7: missing quote
+                input: "BTC",
+                expected: Err(SocketError::Unsupported {
+                    entity: "OkxInstrumentId",
+                    item: "BTC".to_owned(),
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = OkxInstrumentId::from_str(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_okx_instrument_id_round_trips_okx_market() {
+        for inst_id in [
+            "BTC-USDT",
+            "BTC-USDT-SWAP",
+            "BTC-USD-SWAP",
+            "BTC-USD-230526",
+        ] {
+            let parsed = OkxInstrumentId::try_from(&OkxMarket(inst_id.to_owned())).unwrap();
+            let subscription =
+                Subscription::<Okx, crate::subscription::trade::PublicTrades>::from((
+                    Okx,
+                    parsed.instrument,
+                    crate::subscription::trade::PublicTrades,
+                ));
+            assert_eq!(Identifier::<OkxMarket>::id(&subscription).0, inst_id);
+        }
+    }
+}
@@ -1,23 +1,89 @@
 use self::{
-    channel::OkxChannel, market::OkxMarket, subscription::OkxSubResponse, trade::OkxTrades,
+    book::OkxBookUpdater,
+    candle::OkxCandles,
+    channel::OkxChannel,
+    derivatives::OkxDerivativesTransformer,
+    greeks::OkxOptionSummaries,
+    instrument::{OkxInstruments, OKX_DISCOVERY_INSTRUMENT_TYPES},
+    liquidation::OkxLiquidations,
+    login::okx_login_request,
+    market::OkxMarket,
+    subscription::{OkxNotice, OkxSubResponse, OKX_SERVICE_UNAVAILABLE_CODE},
+    time::OkxServerTime,
+    trade::OkxTrades,
 };
 use crate::{
-    exchange::{Connector, ExchangeId, ExchangeSub, PingInterval, StreamSelector},
-    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
+    error::DataError,
+    exchange::{
+        next_request_id, Connector, ExchangeId, ExchangeSub, PingInterval, StreamSelector,
+        WildcardSupport,
+    },
+    streams::{
+        clock::ServerTime, discovery::InstrumentDiscovery, ratelimit::RateLimitUsage,
+        reconnect::ErrorClass,
+    },
+    subscriber::{
+        config::{ConnectionConfig, Credentials},
+        pacer::RequestRateLimit,
+        validator::WebSocketSubValidator,
+        WebSocketSubscriber,
+    },
+    subscription::{
+        book::{OrderBooksL2, OrderBooksL2Tbt},
+        candle::{Candles, ClosedCandles, ClosedOnly, IntervalCandles},
+        derivatives::DerivativesStatistics,
+        greeks::OptionSummary,
+        liquidation::Liquidations,
+        trade::{PublicTrades, PublicTradesFeed, TradeFeed},
+        Map,
+    },
+    transformer::{
+        book::MultiBookTransformer,
+        stateless::{
+            StatelessFanOutTransformer, StatelessTransformer, StatelessWildcardTransformer,
+        },
+    },
     ExchangeWsStream,
 };
-use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_integration::{
+    error::SocketError,
+    model::instrument::{kind::InstrumentKind, Instrument},
+    protocol::websocket::WsMessage,
+};
 use barter_macro::{DeExchange, SerExchange};
+use chrono::{DateTime, Utc};
 use serde_json::json;
 use std::time::Duration;
+use tracing::warn;
 use url::Url;
 
+/// OrderBook types for [`Okx`].
+pub mod book;
+
+/// Candle types for [`Okx`].
+pub mod candle;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
 
+/// Derivatives statistics types & [`ExchangeTransformer`](crate::transformer::ExchangeTransformer)
+/// combining the [`Okx`] mark price, index & funding rate channels.
+pub mod derivatives;
+
+/// Option summary (implied volatility & greeks) types for [`Okx`].
+pub mod greeks;
+
+/// REST instruments types, used for instrument discovery, for [`Okx`].
+pub mod instrument;
+
+/// Liquidation types for [`Okx`].
+pub mod liquidation;
+
+/// WebSocket login request for [`Okx`], required by channels gated behind an authenticated
+/// connection (eg/ "books-l2-tbt").
+pub mod login;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
@@ -26,6 +92,9 @@ pub mod market;
 /// [`Validator`](barter_integration::Validator) for [`Okx`].
 pub mod subscription;
 
+/// REST server-time types for [`Okx`].
+pub mod time;
+
 /// Public trade types for [`Okx`].
 pub mod trade;
 
@@ -39,6 +108,68 @@ pub const BASE_URL_OKX: &str = "wss://wsaws.okx.com:8443/ws/v5/public";
 /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-connect>
 pub const PING_INTERVAL_OKX: Duration = Duration::from_secs(29);
 
+/// [`Okx`] REST server-time url.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-rest-api-get-system-time>
+pub const HTTP_SERVER_TIME_URL_OKX: &str = "https://www.okx.com/api/v5/public/time";
+
+/// [`Okx`] REST instruments url.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-rest-api-get-instruments>
+pub const HTTP_INSTRUMENTS_URL_OKX: &str = "https://www.okx.com/api/v5/public/instruments";
+
+/// [`Okx`] [`OkxNotice`] codes communicating the server is closed for maintenance, or is about to
+/// close the connection for a service upgrade.
+///
+/// - 50001: Service temporarily unavailable, please try again later
+/// - 64008: The connection will soon be closed for a service upgrade
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#error-code-websocket-public>
+pub const MAINTENANCE_CODES_OKX: &[&str] = &[OKX_SERVICE_UNAVAILABLE_CODE, "64008"];
+
+/// [`Okx`] error codes of transient errors (eg/ "Requests too frequent" & "Internal error"),
+/// after which the [`MarketStream`](crate::MarketStream) is re-initialised.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#error-code-websocket-public>
+pub const RETRYABLE_SIGNALS_OKX: &[&str] = &["code: 60014", "code: 63999"];
+
+/// [`Okx`] [`OkxNotice`] codes communicating the connection has exceeded the
+/// [`SUBSCRIPTION_RATE_LIMIT_OKX`].
+///
+/// - 60014: Requests too frequent
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#error-code-websocket-public>
+pub const RATE_LIMIT_CODES_OKX: &[&str] = &["60014"];
+
+/// [`Okx`] error codes of unrecoverable errors (eg/ "Invalid OK-ACCESS-KEY", "Login failed",
+/// "Wrong URL or channel doesn't exist" & the permission denied codes), after which the consumer
+/// loop ends.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#error-code-websocket-public>
+pub const TERMINAL_SIGNALS_OKX: &[&str] = &[
+    "code: 60005",
+    "code: 60009",
+    "code: 60011",
+    "code: 60018",
+    "code: 60029",
+];
+
+/// [`Okx`] maximum number of args sent in a single subscribe request.
+///
+/// Okx rejects subscribe requests whose total length exceeds 64 KB, so this conservative cap
+/// keeps every request well within the limit.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-subscribe>
+pub const MAX_ARGS_PER_REQUEST_OKX: usize = 32;
+
+/// [`Okx`] subscribe request rate limit per connection.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-subscribe>
+pub const SUBSCRIPTION_RATE_LIMIT_OKX: RequestRateLimit = RequestRateLimit {
+    requests: 3,
+    interval: Duration::from_secs(1),
+};
+
 /// [`Okx`] exchange.
 ///
 /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api>
@@ -66,17 +197,227 @@ impl Connector for Okx {
         })
     }
 
+    fn login(credentials: &Credentials) -> Option<WsMessage> {
+        let login = okx_login_request(credentials, Utc::now().timestamp());
+        if login.is_none() {
+            warn!(
+                exchange = %Self::ID,
+                "Okx Credentials have no passphrase, so the connection is not logged in"
+            );
+        }
+        login
+    }
+
+    fn select_channel(
+        channel: Self::Channel,
+        config: &ConnectionConfig,
+    ) -> Result<Self::Channel, SocketError> {
+        // Connections are only logged in if the Credentials include the required passphrase
+        let login_error = match &config.credentials {
+            None => Some("no Credentials are configured"),
+            Some(Credentials {
+                passphrase: None, ..
+            }) => Some("the configured Credentials have no passphrase"),
+            Some(_) => None,
+        };
+
+        // Upgrade to the tick-by-tick OrderBook channel if the logged in account can access it
+        let channel = match (channel, config.account_tier) {
+            (OkxChannel::ORDER_BOOK_L2, Some(tier))
+                if login_error.is_none() && OkxChannel::ORDER_BOOK_L2_TBT.is_accessible(tier) =>
+            {
+                OkxChannel::ORDER_BOOK_L2_TBT
+            }
+            (channel, _) => channel,
+        };
+
+        // Fail before connecting, rather than with a subscription rejected by the exchange
+        if let Some(error) = login_error.filter(|_| channel.requires_login()) {
+            return Err(SocketError::Subscribe(format!(
+                "Okx channel {} requires a logged in connection, but {error}",
+                channel.as_ref()
+            )));
+        }
+
+        // Channel access is only known if the AccountTier is declared. Subscriptions are paced
+        // by the SUBSCRIPTION_RATE_LIMIT_OKX of every connection, which applies at every tier.
+        let Some(tier) = config.account_tier else {
+            return Ok(channel);
+        };
+
+        match channel.required_vip_level() {
+            Some(required) if tier.vip_level < required => Err(SocketError::Subscribe(format!(
+                "Okx channel {} requires a VIP{required}+ AccountTier, but VIP{} is declared",
+                channel.as_ref(),
+                tier.vip_level
+            ))),
+            _ => Ok(channel),
+        }
+    }
+
+    fn associated_subs(
+        exchange_sub: &ExchangeSub<Self::Channel, Self::Market>,
+    ) -> Vec<ExchangeSub<Self::Channel, Self::Market>> {
+        // DerivativesStats combine the "mark-price", "index-tickers" & "funding-rate" channels
+        match exchange_sub.channel {
+            OkxChannel::MARK_PRICE => derivatives::associated_subs(&exchange_sub.market),
+            _ => vec![],
+        }
+    }
+
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        // Option contracts of the same instrument family share a single "opt-summary" arg
+        let mut args = Vec::with_capacity(exchange_subs.len());
+        for arg in exchange_subs.iter().map(|sub| json!(sub)) {
+            if !args.contains(&arg) {
+                args.push(arg);
+            }
+        }
+
         vec![WsMessage::Text(
             json!({
+                "id": next_request_id().to_string(),
                 "op": "subscribe",
-                "args": &exchange_subs,
+                "args": args,
             })
             .to_string(),
         )]
     }
+
+    fn expected_responses(map: &Map<Instrument>) -> usize {
+        // Okx acknowledges each unique arg, so an "opt-summary" instrument family is acked once
+        map.0
+            .keys()
+            .map(|subscription_id| {
+                let subscription_id = subscription_id.as_ref();
+                match subscription_id.split_once('|') {
+                    Some((channel, inst_id)) if channel == OkxChannel::OPTION_SUMMARY.0 => {
+                        (channel, greeks::inst_family(inst_id))
+                    }
+                    _ => (subscription_id, ""),
+                }
+            })
+            .collect::<std::collections::HashSet<_>>()
+            .len()
+    }
+
+    fn request_id_field() -> Option<&'static str> {
+        Some("id")
+    }
+
+    fn max_args_per_request() -> Option<usize> {
+        Some(MAX_ARGS_PER_REQUEST_OKX)
+    }
+
+    fn subscription_rate_limit() -> Option<RequestRateLimit> {
+        Some(SUBSCRIPTION_RATE_LIMIT_OKX)
+    }
+
+    fn is_maintenance(error: &DataError) -> bool {
+        error.is_maintenance()
+            || error
+                .unexpected_payload::<OkxNotice>()
+                .is_some_and(|notice| MAINTENANCE_CODES_OKX.contains(&notice.code()))
+    }
+
+    fn classify_error(error: &DataError) -> Option<ErrorClass> {
+        ErrorClass::classify(error, RETRYABLE_SIGNALS_OKX, TERMINAL_SIGNALS_OKX)
+    }
+
+    fn rate_limit_usage(error: &DataError) -> Option<RateLimitUsage> {
+        // Throttle notices are rejected subscription acks or unexpected payloads mid-stream
+        let throttled = RATE_LIMIT_CODES_OKX.iter().any(|code| {
+            error.contains_any(&[&format!("code: {code}")])
+                || error
+                    .unexpected_payload::<OkxNotice>()
+                    .is_some_and(|notice| notice.code() == *code)
+        });
+
+        throttled.then(|| {
+            RateLimitUsage::exhausted(Self::ID, SUBSCRIPTION_RATE_LIMIT_OKX.requests as u32)
+        })
+    }
 }
 
 impl StreamSelector<PublicTrades> for Okx {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, OkxTrades>>;
 }
+
+impl StreamSelector<PublicTradesFeed> for Okx {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTradesFeed, OkxTrades>>;
+
+    fn supports_kind(kind: &PublicTradesFeed) -> bool {
+        // Okx does not offer a ticker trade source
+        kind.feed != TradeFeed::Ticker
+    }
+}
+
+impl StreamSelector<Candles> for Okx {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, Candles, OkxCandles>>;
+}
+
+impl StreamSelector<ClosedCandles> for Okx {
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, ClosedCandles, ClosedOnly<OkxCandles>>>;
+}
+
+impl StreamSelector<IntervalCandles> for Okx {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, IntervalCandles, OkxCandles>>;
+}
+
+impl StreamSelector<OrderBooksL2> for Okx {
+    type Stream = ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, OkxBookUpdater>>;
+}
+
+impl StreamSelector<OrderBooksL2Tbt> for Okx {
+    type Stream = ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2Tbt, OkxBookUpdater>>;
+}
+
+impl StreamSelector<Liquidations> for Okx {
+    type Stream =
+        ExchangeWsStream<StatelessWildcardTransformer<Self, Liquidations, OkxLiquidations>>;
+
+    const WILDCARD: WildcardSupport = WildcardSupport::Required;
+}
+
+impl StreamSelector<DerivativesStatistics> for Okx {
+    type Stream = ExchangeWsStream<OkxDerivativesTransformer>;
+
+    fn supports(instrument_kind: InstrumentKind) -> bool {
+        instrument_kind == InstrumentKind::Perpetual
+    }
+}
+
+impl StreamSelector<OptionSummary> for Okx {
+    type Stream =
+        ExchangeWsStream<StatelessFanOutTransformer<Self, OptionSummary, OkxOptionSummaries>>;
+
+    fn supports(instrument_kind: InstrumentKind) -> bool {
+        matches!(instrument_kind, InstrumentKind::Option(_))
+    }
+}
+
+impl ServerTime for Okx {
+    const SERVER_TIME_URL: &'static str = HTTP_SERVER_TIME_URL_OKX;
+    type Response = OkxServerTime;
+
+    fn server_time(response: Self::Response) -> Result<DateTime<Utc>, SocketError> {
+        DateTime::try_from(response)
+    }
+}
+
+impl InstrumentDiscovery for Okx {
+    const INSTRUMENTS_URL: &'static str = HTTP_INSTRUMENTS_URL_OKX;
+    type Response = OkxInstruments;
+
+    fn instruments_urls(base_url: &str) -> Vec<String> {
+        OKX_DISCOVERY_INSTRUMENT_TYPES
+            .iter()
+            .map(|inst_type| format!("{base_url}?instType={inst_type}"))
+            .collect()
+    }
+
+    fn instruments(response: Self::Response) -> Vec<Instrument> {
+        response.live_instruments()
+    }
+}
@@ -1,6 +1,7 @@
-use super::{channel::OkxChannel, market::OkxMarket};
+use super::{channel::OkxChannel, greeks::inst_family, market::OkxMarket};
 use crate::exchange::subscription::ExchangeSub;
 use barter_integration::{error::SocketError, Validator};
+use reqwest::StatusCode;
 use serde::{ser::SerializeStruct, Deserialize, Serialize, Serializer};
 
 // Implement custom Serialize to assist aesthetics of <Okx as Connector>::requests() function.
@@ -9,13 +10,33 @@ impl Serialize for ExchangeSub<OkxChannel, OkxMarket> {
     where
         S: Serializer,
     {
+        // Okx "liquidation-orders" markets are instrument types (eg/ "SWAP") rather than ids, and
+        // "opt-summary" markets are the instrument family (eg/ "BTC-USD") of the option ids
+        let (market_field, market) = match self.channel {
+            OkxChannel::LIQUIDATIONS => ("instType", self.market.as_ref()),
+            OkxChannel::OPTION_SUMMARY => ("instFamily", inst_family(self.market.as_ref())),
+            _ => ("instId", self.market.as_ref()),
+        };
+
         let mut state = serializer.serialize_struct("OkxSubArg", 2)?;
         state.serialize_field("channel", self.channel.as_ref())?;
-        state.serialize_field("instId", self.market.as_ref())?;
+        state.serialize_field(market_field, market)?;
         state.end()
     }
 }
 
+/// [`Okx`](super::Okx) error codes communicating a subscription was rejected due to insufficient
+/// account access (eg/ "trades-all" requires VIP5+).
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#error-code-websocket-public>
+pub const OKX_PERMISSION_DENIED_CODES: &[&str] = &["60011", "60029"];
+
+/// [`Okx`](super::Okx) error code communicating the service is temporarily unavailable (eg/
+/// closed for maintenance), documented as an HTTP 503 response.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#error-code-rest-api-public>
+pub const OKX_SERVICE_UNAVAILABLE_CODE: &str = "50001";
+
 /// [`Okx`](super::Okx) WebSocket subscription response.
 ///
 /// ### Raw Payload Examples
@@ -39,12 +60,44 @@ impl Serialize for ExchangeSub<OkxChannel, OkxMarket> {
 /// }
 /// ```
 ///
+/// #### Subscription Trades-All Permission Denied Response
+/// ```json
+/// {
+///   "event": "error",
+///   "code": "60029",
+///   "msg": "Only users who are VIP5 and above in trading fee tier are allowed to subscribe to this channel."
+/// }
+/// ```
+///
+/// #### Login Ok Response
+/// Responses to a [`Connector::login`](crate::exchange::Connector::login) request share the same
+/// envelope, so are also validated as an [`OkxSubResponse`].
+/// ```json
+/// {
+///   "event": "login",
+///   "code": "0",
+///   "msg": "",
+///   "connId": "a4d3ae55"
+/// }
+/// ```
+///
+/// #### Login Error Response
+/// ```json
+/// {
+///   "event": "error",
+///   "code": "60009",
+///   "msg": "Login failed."
+/// }
+/// ```
+///
 /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-subscribe>
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 #[serde(tag = "event", rename_all = "lowercase")]
 pub enum OkxSubResponse {
     #[serde(rename = "subscribe")]
     Subscribed,
+    #[serde(rename = "login")]
+    LoggedIn,
     Error {
         code: String,
         #[serde(rename = "msg")]
@@ -58,7 +111,18 @@ impl Validator for OkxSubResponse {
         Self: Sized,
     {
         match self {
-            Self::Subscribed => Ok(self),
+            Self::Subscribed | Self::LoggedIn => Ok(self),
+            Self::Error { code, message } if code == OKX_SERVICE_UNAVAILABLE_CODE => {
+                Err(SocketError::HttpResponse(
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    format!("received failure subscription response code: {code} with message: {message}"),
+                ))
+            }
+            Self::Error { code, message } if OKX_PERMISSION_DENIED_CODES.contains(&code.as_str()) => {
+                Err(SocketError::Subscribe(format!(
+                    "permission denied for subscription (channel may require elevated account access) code: {code} with message: {message}",
+                )))
+            }
             Self::Error { code, message } => Err(SocketError::Subscribe(format!(
                 "received failure subscription response code: {code} with message: {message}",
             ))),
@@ -66,6 +130,45 @@ impl Validator for OkxSubResponse {
     }
 }
 
+/// [`Okx`](super::Okx) error or notice event that may be received at any time after subscribing,
+/// communicating a change in server state via a code.
+///
+/// ### Raw Payload Examples
+/// #### Service Upgrade Notice
+/// ```json
+/// {
+///   "event": "notice",
+///   "code": "64008",
+///   "msg": "The connection will soon be closed for a service upgrade. Please reconnect.",
+///   "connId": "a4d3ae55"
+/// }
+/// ```
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#error-code-websocket-public>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(tag = "event", rename_all = "lowercase")]
+pub enum OkxNotice {
+    Error {
+        code: String,
+        #[serde(rename = "msg")]
+        message: String,
+    },
+    Notice {
+        code: String,
+        #[serde(rename = "msg")]
+        message: String,
+    },
+}
+
+impl OkxNotice {
+    /// Code communicating the change in server state.
+    pub fn code(&self) -> &str {
+        match self {
+            Self::Error { code, .. } | Self::Notice { code, .. } => code,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -105,6 +208,18 @@ mod tests {
                         message: "Invalid request: {\"op\": \"subscribe\", \"args\":[{ \"channel\" : \"trades\", \"instId\" : \"BTC-USD-191227\"}]}".to_string()
                     }),
                 },
+                TestCase {
+                    // TC2: input response is login success
+                    input: r#"
+                {
+                    "event": "login",
+                    "code": "0",
+                    "msg": "",
+                    "connId": "a4d3ae55"
+                }
+                "#,
+                    expected: Ok(OkxSubResponse::LoggedIn),
+                },
             ];
 
             for (index, test) in cases.into_iter().enumerate() {
@@ -153,4 +268,20 @@ mod tests {
             assert_eq!(actual, test.is_valid, "TestCase {} failed", index);
         }
     }
+
+    #[test]
+    fn test_validate_okx_sub_response_permission_denied() {
+        let response = serde_json::from_str::<OkxSubResponse>(
+            r#"{"event": "error", "code": "60029", "msg": "Only users who are VIP5 and above in trading fee tier are allowed to subscribe to this channel."}"#,
+        )
+        .unwrap();
+
+        match response.validate() {
+            Err(SocketError::Subscribe(message)) => {
+                assert!(message.contains("permission denied"), "{message}");
+                assert!(message.contains("60029"), "{message}");
+            }
+            other => panic!("expected permission denied SocketError::Subscribe, got: {other:?}"),
+        }
+    }
 }
@@ -0,0 +1,93 @@
+use barter_integration::{de::datetime_utc_from_epoch_duration, error::SocketError};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Okx`](super::Okx) REST server-time response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-rest-api-get-system-time>
+/// ```json
+/// {
+///   "code": "0",
+///   "msg": "",
+///   "data": [
+///     {
+///       "ts": "1597026383085"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OkxServerTime {
+    pub data: Vec<OkxServerTimeData>,
+}
+
+/// [`Okx`](super::Okx) REST server-time data.
+///
+/// See [`OkxServerTime`] for full raw payload examples.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OkxServerTimeData {
+    #[serde(rename = "ts", deserialize_with = "barter_integration::de::de_str")]
+    pub time_millis: u64,
+}
+
+impl TryFrom<OkxServerTime> for DateTime<Utc> {
+    type Error = SocketError;
+
+    fn try_from(response: OkxServerTime) -> Result<Self, Self::Error> {
+        response
+            .data
+            .first()
+            .map(|data| {
+                datetime_utc_from_epoch_duration(std::time::Duration::from_millis(data.time_millis))
+            })
+            .ok_or_else(|| {
+                SocketError::Exchange("Okx server time response has no data".to_string())
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_okx_server_time() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<DateTime<Utc>, SocketError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: valid server time response
+                input: r#"{"code":"0","msg":"","data":[{"ts":"1597026383085"}]}"#,
+                expected: Ok(datetime_utc_from_epoch_duration(
+                    std::time::Duration::from_millis(1597026383085),
+                )),
+            },
+            TestCase {
+                // TC1: server time response w/ no data
+                input: r#"{"code":"0","msg":"","data":[]}"#,
+                expected: Err(SocketError::Exchange(String::new())),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let response = serde_json::from_str::<OkxServerTime>(test.input).unwrap();
+            let actual = DateTime::<Utc>::try_from(response);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
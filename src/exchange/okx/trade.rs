@@ -1,7 +1,7 @@
 use crate::{
     event::{MarketEvent, MarketIter},
     exchange::{ExchangeId, ExchangeSub},
-    subscription::trade::PublicTrade,
+    subscription::trade::{PublicTrade, SideSource, TradeOrderIds, TradeSource},
     Identifier,
 };
 use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
@@ -29,7 +29,8 @@ pub type OkxTrades = OkxMessage<OkxTrade>;
 ///       "px": "42219.9",
 ///       "sz": "0.12060306",
 ///       "side": "buy",
-///       "ts": "1630048897897"
+///       "ts": "1630048897897",
+///       "count": "3"
 ///     }
 ///   ]
 /// }
@@ -89,6 +90,9 @@ pub struct OkxTrade {
         deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
     )]
     pub time: DateTime<Utc>,
+    /// Number of fills aggregated into the trade, omitted by the "trades-all" channel.
+    #[serde(default, deserialize_with = "de_opt_str_u32")]
+    pub count: Option<u32>,
 }
 
 impl From<(ExchangeId, Instrument, OkxTrades)> for MarketIter<PublicTrade> {
@@ -100,6 +104,7 @@ impl From<(ExchangeId, Instrument, OkxTrades)> for MarketIter<PublicTrade> {
                 Ok(MarketEvent {
                     exchange_time: trade.time,
                     received_time: Utc::now(),
+                    exchange_time_synthesized: false,
                     exchange: Exchange::from(exchange_id),
                     instrument: instrument.clone(),
                     kind: PublicTrade {
@@ -107,6 +112,10 @@ impl From<(ExchangeId, Instrument, OkxTrades)> for MarketIter<PublicTrade> {
                         price: trade.price,
                         amount: trade.amount,
                         side: trade.side,
+                        source: TradeSource::Live,
+                        side_source: SideSource::Exchange,
+                        order_ids: TradeOrderIds::default(),
+                        aggregate_count: trade.count,
                     },
                 })
             })
@@ -115,7 +124,7 @@ impl From<(ExchangeId, Instrument, OkxTrades)> for MarketIter<PublicTrade> {
 }
 
 /// Deserialize an [`OkxMessage`] "arg" field as a Barter [`SubscriptionId`].
-fn de_okx_message_arg_as_subscription_id<'de, D>(
+pub fn de_okx_message_arg_as_subscription_id<'de, D>(
     deserializer: D,
 ) -> Result<SubscriptionId, D::Error>
 where
@@ -132,6 +141,18 @@ where
         .map(|arg: Arg<'_>| ExchangeSub::from((arg.channel, arg.inst_id)).id())
 }
 
+/// Deserialize an optional [`Okx`](super::Okx) string integer (eg/ the trade "count"), where an
+/// empty string is `None`.
+fn de_opt_str_u32<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    match <Option<&str> as Deserialize>::deserialize(deserializer)? {
+        None | Some("") => Ok(None),
+        Some(value) => value.parse().map(Some).map_err(serde::de::Error::custom),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -156,7 +177,8 @@ mod tests {
                         "px": "42219.9",
                         "sz": "0.12060306",
                         "side": "buy",
-                        "ts": "1630048897897"
+                        "ts": "1630048897897",
+                        "count": "3"
                     }
                 ]
             }
@@ -171,6 +193,7 @@ mod tests {
                     amount: 0.12060306,
                     side: Side::Buy,
                     time: datetime_utc_from_epoch_duration(Duration::from_millis(1630048897897)),
+                    count: Some(3),
                 }],
             });
 
@@ -187,5 +210,68 @@ mod tests {
                 }
             }
         }
+
+        #[test]
+        fn test_public_trade_aggregate_count() {
+            use crate::exchange::binance::trade::BinanceTrade;
+            use barter_integration::model::instrument::kind::InstrumentKind;
+
+            struct TestCase {
+                input: MarketIter<PublicTrade>,
+                expected_aggregate_count: Option<u32>,
+                expected_count: u32,
+            }
+
+            let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+            let okx = |input: &str| {
+                MarketIter::<PublicTrade>::from((
+                    ExchangeId::Okx,
+                    instrument.clone(),
+                    serde_json::from_str::<OkxTrades>(input).unwrap(),
+                ))
+            };
+
+            let tests = vec![
+                TestCase {
+                    // TC0: Okx "trades" aggregated tick captures the count
+                    input: okx(
+                        r#"{"arg":{"channel":"trades","instId":"BTC-USDT"},"data":[{"instId":"BTC-USDT","tradeId":"130639474","px":"42219.9","sz":"0.12060306","side":"buy","ts":"1630048897897","count":"3"}]}"#,
+                    ),
+                    expected_aggregate_count: Some(3),
+                    expected_count: 3,
+                },
+                TestCase {
+                    // TC1: Okx "trades-all" individual fill omits the count
+                    input: okx(
+                        r#"{"arg":{"channel":"trades-all","instId":"BTC-USDT"},"data":[{"instId":"BTC-USDT","tradeId":"130639475","px":"42219.9","sz":"0.1","side":"sell","ts":"1630048897898"}]}"#,
+                    ),
+                    expected_aggregate_count: None,
+                    expected_count: 1,
+                },
+                TestCase {
+                    // TC2: Binance single trade defaults to a count of one
+                    input: MarketIter::<PublicTrade>::from((
+                        ExchangeId::BinanceSpot,
+                        instrument.clone(),
+                        serde_json::from_str::<BinanceTrade>(
+                            r#"{"e":"trade","E":1649324825173,"s":"BTCUSDT","t":1000000000,"p":"10000.19","q":"0.239000","b":10,"a":20,"T":1649324825173,"m":false,"M":true}"#,
+                        )
+                        .unwrap(),
+                    )),
+                    expected_aggregate_count: None,
+                    expected_count: 1,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = test.input.0.into_iter().next().unwrap().unwrap().kind;
+                assert_eq!(
+                    actual.aggregate_count, test.expected_aggregate_count,
+                    "TC{} failed",
+                    index
+                );
+                assert_eq!(actual.count(), test.expected_count, "TC{} failed", index);
+            }
+        }
     }
 }
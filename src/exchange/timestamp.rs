@@ -0,0 +1,288 @@
+use barter_integration::error::SocketError;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Unit of an exchange epoch timestamp.
+///
+/// Most exchanges send epoch milliseconds, but some send seconds (eg/ Kraken), microseconds or
+/// nanoseconds. Interpreting a timestamp with the wrong unit does
+/// not fail, it silently produces a wildly wrong `exchange_time`, so each exchange deserialiser
+/// should name its unit explicitly.
+///
+/// Note: Exchanges sending ISO-8601 strings (eg/ Coinbase, Bitmex) use the [`DateTime<Utc>`]
+/// [`Deserialize`] implementation directly.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum EpochUnit {
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+impl EpochUnit {
+    /// Number of this [`EpochUnit`] in one second.
+    pub fn per_second(&self) -> u64 {
+        match self {
+            Self::Seconds => 1,
+            Self::Milliseconds => 1_000,
+            Self::Microseconds => 1_000_000,
+            Self::Nanoseconds => 1_000_000_000,
+        }
+    }
+
+    /// Convert an integer epoch timestamp in this [`EpochUnit`] into a [`DateTime<Utc>`].
+    ///
+    /// Fails if the timestamp is out of the [`DateTime<Utc>`] range.
+    #[allow(clippy::result_large_err)]
+    pub fn datetime_utc(&self, timestamp: u64) -> Result<DateTime<Utc>, SocketError> {
+        let per_second = self.per_second();
+        let nanos = ((timestamp % per_second) * (1_000_000_000 / per_second)) as u32;
+
+        i64::try_from(timestamp / per_second)
+            .ok()
+            .and_then(|secs| DateTime::<Utc>::from_timestamp(secs, nanos))
+            .ok_or_else(|| self.out_of_range(timestamp))
+    }
+
+    /// Convert a fractional epoch timestamp in this [`EpochUnit`] into a [`DateTime<Utc>`].
+    ///
+    /// Fails if the timestamp is not finite, or is out of the [`DateTime<Utc>`] range.
+    #[allow(clippy::result_large_err)]
+    pub fn datetime_utc_f64(&self, timestamp: f64) -> Result<DateTime<Utc>, SocketError> {
+        let secs = timestamp / self.per_second() as f64;
+        let nanos = ((secs.fract() * 1e9).round() as u32).min(999_999_999);
+
+        Some(secs)
+            .filter(|secs| secs.is_finite() && secs.abs() < i64::MAX as f64)
+            .and_then(|secs| DateTime::<Utc>::from_timestamp(secs.trunc() as i64, nanos))
+            .ok_or_else(|| self.out_of_range(timestamp))
+    }
+
+    fn out_of_range<T>(&self, timestamp: T) -> SocketError
+    where
+        T: std::fmt::Display,
+    {
+        SocketError::Unsupported {
+            entity: "DateTime<Utc>",
+            item: format!("out of range epoch {self:?} timestamp: {timestamp}"),
+        }
+    }
+}
+
+/// Deserialize a `String` fractional epoch timestamp in milliseconds (eg/ "1606292218213.4578")
+/// as a [`DateTime<Utc>`], retaining the sub-millisecond precision.
+pub fn de_str_f64_epoch_ms_as_datetime_utc<'de, D>(
+    deserializer: D,
+) -> Result<DateTime<Utc>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let timestamp = barter_integration::de::de_str::<D, f64>(deserializer)?;
+    EpochUnit::Milliseconds
+        .datetime_utc_f64(timestamp)
+        .map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn expected() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2022, 10, 11, 1, 36, 40).unwrap()
+            + chrono::Duration::nanoseconds(22_123_456)
+    }
+
+    #[test]
+    fn test_epoch_unit_datetime_utc() {
+        struct TestCase {
+            unit: EpochUnit,
+            input: u64,
+            expected: DateTime<Utc>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: seconds
+                unit: EpochUnit::Seconds,
+                input: 1665452200,
+                expected: Utc.with_ymd_and_hms(2022, 10, 11, 1, 36, 40).unwrap(),
+            },
+            TestCase {
+                // TC1: milliseconds
+                unit: EpochUnit::Milliseconds,
+                input: 1665452200022,
+                expected: expected() - chrono::Duration::nanoseconds(123_456),
+            },
+            TestCase {
+                // TC2: microseconds
+                unit: EpochUnit::Microseconds,
+                input: 1665452200022123,
+                expected: expected() - chrono::Duration::nanoseconds(456),
+            },
+            TestCase {
+                // TC3: nanoseconds
+                unit: EpochUnit::Nanoseconds,
+                input: 1665452200022123456,
+                expected: expected(),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(
+                test.unit.datetime_utc(test.input).unwrap(),
+                test.expected,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_epoch_unit_datetime_utc_f64() {
+        // Kraken fractional seconds w/ microsecond precision
+        let actual = EpochUnit::Seconds
+            .datetime_utc_f64(1665452200.022123)
+            .unwrap();
+        assert_eq!(actual.timestamp(), 1665452200);
+        assert_eq!(actual.timestamp_subsec_micros(), 22123);
+
+        // Gate.io fractional milliseconds
+        let actual = EpochUnit::Milliseconds
+            .datetime_utc_f64(1606292218213.4578)
+            .unwrap();
+        assert_eq!(actual.timestamp_millis(), 1606292218213);
+    }
+
+    #[test]
+    fn test_epoch_unit_datetime_utc_out_of_range() {
+        assert!(EpochUnit::Seconds.datetime_utc(u64::MAX).is_err());
+        assert!(EpochUnit::Milliseconds.datetime_utc(u64::MAX).is_err());
+        assert!(EpochUnit::Nanoseconds.datetime_utc(u64::MAX).is_ok());
+        assert!(EpochUnit::Seconds.datetime_utc_f64(f64::NAN).is_err());
+        assert!(EpochUnit::Seconds.datetime_utc_f64(f64::INFINITY).is_err());
+        assert!(EpochUnit::Seconds.datetime_utc_f64(1e300).is_err());
+    }
+
+    #[test]
+    fn test_exchange_native_timestamp_units() {
+        use crate::exchange::{
+            binance::trade::BinanceTrade, bitfinex::trade::BitfinexTrade,
+            bitmex::trade::BitmexTradeInner, bybit::trade::BybitTradeInner,
+            coinbase::trade::CoinbaseTrade, gateio::perpetual::trade::GateioFuturesTradeInner,
+            gateio::spot::trade::GateioSpotTradeInner, kraken::trade::KrakenTrade,
+            okx::trade::OkxTrade,
+        };
+
+        let millis = expected() - chrono::Duration::nanoseconds(123_456);
+        let micros = expected() - chrono::Duration::nanoseconds(456);
+
+        struct TestCase {
+            exchange: &'static str,
+            actual: DateTime<Utc>,
+            expected: DateTime<Utc>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Binance epoch milliseconds
+                exchange: "binance",
+                actual: serde_json::from_str::<BinanceTrade>(
+                    r#"{"e":"trade","E":1665452200030,"s":"BTCUSDT","t":1,"p":"1","q":"1","b":1,"a":2,"T":1665452200022,"m":true,"M":true}"#,
+                )
+                .unwrap()
+                .time,
+                expected: millis,
+            },
+            TestCase {
+                // TC1: Bitfinex epoch milliseconds
+                exchange: "bitfinex",
+                actual: serde_json::from_str::<BitfinexTrade>(r#"[1,1665452200022,0.1,19027.0]"#)
+                    .unwrap()
+                    .time,
+                expected: millis,
+            },
+            TestCase {
+                // TC2: Bitmex ISO-8601 string
+                exchange: "bitmex",
+                actual: serde_json::from_str::<BitmexTradeInner>(
+                    r#"{"timestamp":"2022-10-11T01:36:40.022Z","symbol":"XBTUSD","side":"Buy","size":1,"price":1.0,"trdMatchID":"a"}"#,
+                )
+                .unwrap()
+                .timestamp,
+                expected: millis,
+            },
+            TestCase {
+                // TC3: Bybit epoch milliseconds
+                exchange: "bybit",
+                actual: serde_json::from_str::<BybitTradeInner>(
+                    r#"{"T":1665452200022,"s":"BTCUSDT","S":"Buy","v":"1","p":"1","i":"a"}"#,
+                )
+                .unwrap()
+                .time,
+                expected: millis,
+            },
+            TestCase {
+                // TC4: Coinbase ISO-8601 string w/ microseconds
+                exchange: "coinbase",
+                actual: serde_json::from_str::<CoinbaseTrade>(
+                    r#"{"type":"match","trade_id":1,"product_id":"BTC-USD","time":"2022-10-11T01:36:40.022123Z","size":"1","price":"1","side":"buy"}"#,
+                )
+                .unwrap()
+                .time,
+                expected: micros,
+            },
+            TestCase {
+                // TC5: Gate.io spot fractional epoch milliseconds string
+                exchange: "gateio_spot",
+                actual: serde_json::from_str::<GateioSpotTradeInner>(
+                    r#"{"id":1,"create_time":1665452200,"create_time_ms":"1665452200022.1234","side":"sell","currency_pair":"BTC_USDT","amount":"1","price":"1"}"#,
+                )
+                .unwrap()
+                .time,
+                expected: micros,
+            },
+            TestCase {
+                // TC6: Gate.io futures epoch milliseconds
+                exchange: "gateio_futures",
+                actual: serde_json::from_str::<GateioFuturesTradeInner>(
+                    r#"{"id":1,"create_time":1665452200,"create_time_ms":1665452200022,"price":"1","size":1,"contract":"BTC_USDT"}"#,
+                )
+                .unwrap()
+                .time,
+                expected: millis,
+            },
+            TestCase {
+                // TC7: Kraken fractional epoch seconds string
+                exchange: "kraken",
+                actual: serde_json::from_str::<KrakenTrade>(
+                    r#"["1.0","1.0","1665452200.022123","b","l",""]"#,
+                )
+                .unwrap()
+                .time,
+                expected: micros,
+            },
+            TestCase {
+                // TC8: Okx epoch milliseconds string
+                exchange: "okx",
+                actual: serde_json::from_str::<OkxTrade>(
+                    r#"{"instId":"BTC-USDT","tradeId":"1","px":"1","sz":"1","side":"buy","ts":"1665452200022"}"#,
+                )
+                .unwrap()
+                .time,
+                expected: millis,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            // Compare to microsecond precision, since fractional timestamps pass through f64
+            assert_eq!(
+                test.actual.timestamp_micros(),
+                test.expected.timestamp_micros(),
+                "TC{} ({}) failed",
+                index,
+                test.exchange
+            );
+        }
+    }
+}
@@ -72,7 +72,7 @@
 //!     // Join all exchange PublicTrades streams into a single tokio_stream::StreamMap
 //!     // Notes:
 //!     //  - Use `streams.select(ExchangeId)` to interact with the individual exchange streams!
-//!     //  - Use `streams.join()` to join all exchange streams into a single mpsc::UnboundedReceiver!
+//!     //  - Use `streams.join()` to join all exchange streams into a single mpsc::Receiver!
 //!     let mut joined_stream = streams.join_map().await;
 //!
 //!     while let Some((exchange, trade)) = joined_stream.next().await {
@@ -82,21 +82,33 @@
 //! ```
 
 use crate::{
+    dedup::{DedupStream, FrameWindow},
+    dump::{DumpRecorder, DumpStream},
     error::DataError,
     event::MarketEvent,
     exchange::{Connector, ExchangeId, PingInterval},
-    subscriber::Subscriber,
+    liveness::{PongTimeout, PongTimeoutStream},
+    middleware::MiddlewareStream,
+    subscriber::{config::ConnectionConfig, validator::BufferedStream, Subscriber},
     subscription::{SubKind, Subscription},
     transformer::ExchangeTransformer,
 };
 use async_trait::async_trait;
 use barter_integration::{
-    protocol::websocket::{WebSocketParser, WsMessage, WsSink, WsStream},
+    protocol::websocket::{WebSocketParser, WsMessage, WsSink},
     ExchangeStream,
 };
-use futures::{SinkExt, Stream, StreamExt};
+use futures::Stream;
 use tokio::sync::mpsc;
-use tracing::{debug, error};
+use tracing::debug;
+
+/// Optional [`FrameWindow`](dedup::FrameWindow) content-hash de-duplication that drops raw
+/// inbound frames the exchange resent before they are deserialised.
+pub mod dedup;
+
+/// Optional [`DebugDump`](dump::DebugDump) pairing every raw inbound frame with its parsed
+/// outcome, used to troubleshoot missing events.
+pub mod dump;
 
 /// All [`Error`](std::error::Error)s generated in Barter-Data.
 pub mod error;
@@ -107,6 +119,14 @@ pub mod event;
 /// [`Connector`] implementations for each exchange.
 pub mod exchange;
 
+/// Optional [`PongTimeout`](liveness::PongTimeout) enforcement that ends a [`MarketStream`]
+/// connection if application-level pings go unanswered.
+pub mod liveness;
+
+/// Optional [`Middleware`](middleware::Middleware) hook invoked on every raw inbound frame of a
+/// [`MarketStream`] before deserialisation.
+pub mod middleware;
+
 /// High-level API types used for building [`MarketStream`]s from collections
 /// of Barter [`Subscription`]s.
 pub mod streams;
@@ -134,9 +154,30 @@ pub mod subscription;
 ///   [`OrderBooksL3`](crate::subscription::book::OrderBooksL3) streams.
 pub mod transformer;
 
+/// In-process [`MockExchange`](test_util::MockExchange) WebSocket server harness that performs
+/// scripted acks, data, close frames & silences, used to integration test [`MarketStream`]s
+/// without connecting to live exchanges.
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
+
+/// Ordered [`WriteQueue`](writer::WriteQueue) through which a single writer task per connection
+/// sends every outbound message (eg/ subscribes, pings & pongs) to the exchange.
+pub mod writer;
+
 /// Convenient type alias for an [`ExchangeStream`] utilising a tungstenite
-/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket).
-pub type ExchangeWsStream<Transformer> = ExchangeStream<WebSocketParser, WsStream, Transformer>;
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket), with each raw inbound frame
+/// passed through an optional [`Middleware`](middleware::Middleware), optional
+/// [`PongTimeout`](liveness::PongTimeout) enforcement & optional
+/// [`FrameWindow`](dedup::FrameWindow) de-duplication. Data frames received during subscription
+/// validation are yielded first via a [`BufferedStream`], and each is paired with its parsed
+/// outcome by an optional [`DebugDump`](dump::DebugDump).
+pub type ExchangeWsStream<Transformer> = DumpStream<
+    ExchangeStream<
+        WebSocketParser,
+        DumpRecorder<DedupStream<MiddlewareStream<PongTimeoutStream<BufferedStream>>>>,
+        Transformer,
+    >,
+>;
 
 /// Defines a generic identification type for the implementor.
 pub trait Identifier<T> {
@@ -152,7 +193,10 @@ where
     Exchange: Connector,
     Kind: SubKind,
 {
-    async fn init(subscriptions: &[Subscription<Exchange, Kind>]) -> Result<Self, DataError>
+    async fn init(
+        subscriptions: &[Subscription<Exchange, Kind>],
+        config: &ConnectionConfig,
+    ) -> Result<Self, DataError>
     where
         Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>;
 }
@@ -165,65 +209,76 @@ where
     Transformer: ExchangeTransformer<Exchange, Kind> + Send,
     Kind::Event: Send,
 {
-    async fn init(subscriptions: &[Subscription<Exchange, Kind>]) -> Result<Self, DataError>
+    async fn init(
+        subscriptions: &[Subscription<Exchange, Kind>],
+        config: &ConnectionConfig,
+    ) -> Result<Self, DataError>
     where
         Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
     {
-        // Connect & subscribe
-        let (websocket, map) = Exchange::Subscriber::subscribe(subscriptions).await?;
-
-        // Split WebSocket into WsStream & WsSink components
-        let (ws_sink, ws_stream) = websocket.split();
-
-        // Spawn task to distribute Transformer messages (eg/ custom pongs) to the exchange
-        let (ws_sink_tx, ws_sink_rx) = mpsc::unbounded_channel();
-        tokio::spawn(distribute_messages_to_exchange(
-            Exchange::ID,
-            ws_sink,
-            ws_sink_rx,
-        ));
+        // Connect & subscribe, submitting the subscriptions to the single writer task of this
+        // connection, which serialises every outbound message (eg/ subscriptions, custom pings &
+        // Transformer pongs) to the exchange in submission order
+        let (write_queue, ws_stream, map, buffered) =
+            Exchange::Subscriber::subscribe(subscriptions, config).await?;
+        let ws_sink_tx = write_queue.sender();
 
-        // Spawn optional task to distribute custom application-level pings to the exchange
+        // Spawn optional task to distribute custom application-level pings to the exchange,
+        // notifying the optional PongTimeout of each ping sent
+        let mut pong_timeout = None;
         if let Some(ping_interval) = Exchange::ping_interval() {
+            let ping_sent_tx = config.pong_timeout.map(|timeout| {
+                let (ping_sent_tx, ping_sent_rx) = mpsc::unbounded_channel();
+                pong_timeout = Some(PongTimeout::new(timeout, ping_sent_rx));
+                ping_sent_tx
+            });
+
             tokio::spawn(schedule_pings_to_exchange(
                 Exchange::ID,
                 ws_sink_tx.clone(),
                 ping_interval,
+                ping_sent_tx,
             ));
         }
 
         // Construct Transformer associated with this Exchange and SubKind
-        let transformer = Transformer::new(ws_sink_tx, map).await?;
+        let transformer = Transformer::with_config(ws_sink_tx, map, config).await?;
+
+        // Pass raw inbound frames through any configured Middleware, dropping any resent
+        // duplicates, before deserialisation
+        // Replay data frames received during validation ahead of any subsequent frames
+        let ws_stream = BufferedStream::new(buffered, ws_stream);
+        let ws_stream = PongTimeoutStream::new(Exchange::ID, ws_stream, pong_timeout);
+        let ws_stream = MiddlewareStream::new(Exchange::ID, ws_stream, config.middleware.clone());
+        let ws_stream = DedupStream::new(
+            Exchange::ID,
+            ws_stream,
+            config.dedup_window.map(FrameWindow::new),
+        );
 
-        Ok(ExchangeWsStream::new(ws_stream, transformer))
+        Ok(DumpStream::new(
+            Exchange::ID,
+            ws_stream,
+            config.debug_dump.clone(),
+            |ws_stream| ExchangeStream::new(ws_stream, transformer),
+        ))
     }
 }
 
 /// Transmit [`WsMessage`]s sent from the [`ExchangeTransformer`] to the exchange via
 /// the [`WsSink`].
 ///
+/// See [`WriteQueue`](writer::WriteQueue) for the ordered single-writer queue used by every [`MarketStream`].
+///
 /// **Note:**
 /// ExchangeTransformer is operating in a synchronous trait context so we use this separate task
 /// to avoid adding `#[\async_trait\]` to the transformer - this avoids allocations.
 pub async fn distribute_messages_to_exchange(
     exchange: ExchangeId,
-    mut ws_sink: WsSink,
-    mut ws_sink_rx: mpsc::UnboundedReceiver<WsMessage>,
+    ws_sink: WsSink,
+    ws_sink_rx: mpsc::UnboundedReceiver<WsMessage>,
 ) {
-    while let Some(message) = ws_sink_rx.recv().await {
-        if let Err(error) = ws_sink.send(message).await {
-            if barter_integration::protocol::websocket::is_websocket_disconnected(&error) {
-                break;
-            }
-
-            // Log error only if WsMessage failed to send over a connected WebSocket
-            error!(
-                %exchange,
-                %error,
-                "failed to send  output message to the exchange via WsSink"
-            );
-        }
-    }
+    writer::write_messages_to_exchange(exchange, ws_sink, ws_sink_rx).await
 }
 
 /// Schedule the sending of custom application-level ping [`WsMessage`]s to the exchange using
@@ -232,10 +287,13 @@ pub async fn distribute_messages_to_exchange(
 /// **Notes:**
 ///  - This is only used for those exchanges that require custom application-level pings.
 ///  - This is additional to the protocol-level pings already handled by `tokio_tungstenite`.
+///  - The send time of each ping is communicated via the optional `ping_sent_tx`, used to
+///    enforce a [`PongTimeout`].
 pub async fn schedule_pings_to_exchange(
     exchange: ExchangeId,
     ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
     PingInterval { mut interval, ping }: PingInterval,
+    ping_sent_tx: Option<mpsc::UnboundedSender<tokio::time::Instant>>,
 ) {
     loop {
         // Wait for next scheduled ping
@@ -248,5 +306,9 @@ pub async fn schedule_pings_to_exchange(
         if ws_sink_tx.send(payload).is_err() {
             break;
         }
+
+        if let Some(ping_sent_tx) = &ping_sent_tx {
+            let _ = ping_sent_tx.send(tokio::time::Instant::now());
+        }
     }
 }
@@ -0,0 +1,182 @@
+use crate::exchange::ExchangeId;
+use barter_integration::protocol::websocket::{WsError, WsMessage, WsStream};
+use futures::Stream;
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::{
+    sync::mpsc,
+    time::{Instant, Sleep},
+};
+use tracing::warn;
+
+/// Pong timeout enforcement applied to a [`PongTimeoutStream`].
+///
+/// Each [`Instant`] received via `pings` is the time an application-level ping was sent to the
+/// exchange (see [`schedule_pings_to_exchange`](crate::schedule_pings_to_exchange)).
+#[derive(Debug)]
+pub struct PongTimeout {
+    pub timeout: Duration,
+    pings: mpsc::UnboundedReceiver<Instant>,
+}
+
+impl PongTimeout {
+    /// Construct a new [`PongTimeout`] from the provided ping notification receiver.
+    pub fn new(timeout: Duration, pings: mpsc::UnboundedReceiver<Instant>) -> Self {
+        Self { timeout, pings }
+    }
+}
+
+/// [`Stream`] adapter that ends the inner [`Stream`] of raw frames if no frame (pong or data)
+/// arrives within the [`PongTimeout`] of an application-level ping being sent.
+///
+/// Ending the [`Stream`] is treated as a disconnection by the consumer loop, which re-initialises
+/// the connection via the configured
+/// [`ReconnectPolicy`](crate::streams::reconnect::ReconnectPolicy). When no [`PongTimeout`] is
+/// set, frames are forwarded untouched.
+#[derive(Debug)]
+pub struct PongTimeoutStream<St = WsStream> {
+    pub exchange: ExchangeId,
+    stream: St,
+    pong_timeout: Option<PongTimeout>,
+    deadline: Option<Pin<Box<Sleep>>>,
+}
+
+impl<St> PongTimeoutStream<St> {
+    /// Construct a new [`PongTimeoutStream`] wrapping the provided [`Stream`] of raw frames.
+    pub fn new(exchange: ExchangeId, stream: St, pong_timeout: Option<PongTimeout>) -> Self {
+        Self {
+            exchange,
+            stream,
+            pong_timeout,
+            deadline: None,
+        }
+    }
+}
+
+impl<St> Stream for PongTimeoutStream<St>
+where
+    St: Stream<Item = Result<WsMessage, WsError>> + Unpin,
+{
+    type Item = Result<WsMessage, WsError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        // Start the pong deadline from the first ping sent since the last inbound frame
+        if let Some(pong_timeout) = &mut this.pong_timeout {
+            while let Poll::Ready(Some(ping_sent)) = pong_timeout.pings.poll_recv(cx) {
+                if this.deadline.is_none() {
+                    this.deadline = Some(Box::pin(tokio::time::sleep_until(
+                        ping_sent + pong_timeout.timeout,
+                    )));
+                }
+            }
+        }
+
+        match Pin::new(&mut this.stream).poll_next(cx) {
+            Poll::Ready(Some(frame)) => {
+                // Any inbound frame proves the connection is alive
+                this.deadline = None;
+                return Poll::Ready(Some(frame));
+            }
+            Poll::Ready(None) => return Poll::Ready(None),
+            Poll::Pending => {}
+        }
+
+        let expired = this
+            .deadline
+            .as_mut()
+            .is_some_and(|deadline| deadline.as_mut().poll(cx).is_ready());
+
+        match expired {
+            true => {
+                warn!(
+                    exchange = %this.exchange,
+                    timeout = ?this.pong_timeout.as_ref().map(|pong_timeout| pong_timeout.timeout),
+                    action = "ending stream to re-connect",
+                    "no pong received within timeout of application-level ping"
+                );
+                this.deadline = None;
+                this.pong_timeout = None;
+                Poll::Ready(None)
+            }
+            false => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_pong_timeout_ends_stream_when_pings_unanswered() {
+        let timeout = Duration::from_secs(5);
+        let (ping_tx, ping_rx) = mpsc::unbounded_channel();
+
+        // Mock exchange that accepts pings but never responds
+        let mut stream = PongTimeoutStream::new(
+            ExchangeId::Okx,
+            futures::stream::pending::<Result<WsMessage, WsError>>(),
+            Some(PongTimeout::new(timeout, ping_rx)),
+        );
+
+        let start = Instant::now();
+        ping_tx.send(Instant::now()).unwrap();
+
+        assert!(stream.next().await.is_none());
+        assert_eq!(Instant::now() - start, timeout);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_pong_timeout_reset_by_inbound_frame() {
+        let timeout = Duration::from_secs(5);
+        let (ping_tx, ping_rx) = mpsc::unbounded_channel();
+        let (frame_tx, frame_rx) = mpsc::unbounded_channel();
+
+        let mut stream = PongTimeoutStream::new(
+            ExchangeId::Okx,
+            tokio_stream::wrappers::UnboundedReceiverStream::new(frame_rx),
+            Some(PongTimeout::new(timeout, ping_rx)),
+        );
+
+        // Pong arrives within the timeout
+        let start = Instant::now();
+        ping_tx.send(Instant::now()).unwrap();
+        tokio::spawn({
+            let frame_tx = frame_tx.clone();
+            async move {
+                tokio::time::sleep(Duration::from_secs(4)).await;
+                frame_tx.send(Ok(WsMessage::text("pong"))).unwrap();
+            }
+        });
+        assert_eq!(
+            stream.next().await.unwrap().unwrap(),
+            WsMessage::text("pong")
+        );
+
+        // Next ping goes unanswered, so the stream ends after the timeout of that ping
+        tokio::time::sleep(Duration::from_secs(10)).await;
+        ping_tx.send(Instant::now()).unwrap();
+        assert!(stream.next().await.is_none());
+        assert_eq!(Instant::now() - start, Duration::from_secs(19));
+        drop(frame_tx);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_no_pong_timeout_forwards_frames() {
+        let frames = vec![Ok(WsMessage::text("pong")), Ok(WsMessage::text("{}"))];
+
+        let forwarded =
+            PongTimeoutStream::new(ExchangeId::Okx, futures::stream::iter(frames), None)
+                .collect::<Vec<_>>()
+                .await;
+
+        assert_eq!(forwarded.len(), 2);
+    }
+}
@@ -0,0 +1,212 @@
+use crate::exchange::ExchangeId;
+use barter_integration::protocol::websocket::{WsError, WsMessage, WsStream};
+use futures::Stream;
+use std::{
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tracing::trace;
+
+/// Hook invoked with every raw inbound text frame, and the [`ExchangeId`] it was received from,
+/// before the frame is deserialised into an exchange specific type.
+///
+/// Useful for auditing, custom metrics, or tapping the raw exchange feed. Configured via
+/// [`ConnectionConfig::middleware`](crate::subscriber::config::ConnectionConfig::middleware).
+#[derive(Clone)]
+pub struct Middleware(Arc<MiddlewareFn>);
+
+type MiddlewareFn = dyn Fn(&str, ExchangeId) + Send + Sync;
+
+impl Middleware {
+    /// Construct a new [`Middleware`] from the provided raw frame hook.
+    pub fn new<F>(hook: F) -> Self
+    where
+        F: Fn(&str, ExchangeId) + Send + Sync + 'static,
+    {
+        Self(Arc::new(hook))
+    }
+
+    /// Invoke the hook with the provided raw frame.
+    pub fn call(&self, frame: &str, exchange: ExchangeId) {
+        (self.0)(frame, exchange)
+    }
+}
+
+impl Debug for Middleware {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Middleware")
+    }
+}
+
+impl PartialEq for Middleware {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for Middleware {}
+
+/// [`Stream`] adapter sitting between the socket read and the typed conversion of an
+/// [`ExchangeWsStream`](crate::ExchangeWsStream), passing each raw inbound frame to an optional
+/// [`Middleware`].
+///
+/// Non-JSON keepalive text frames (see [`is_keepalive`]) are skipped after being passed to the
+/// [`Middleware`], so they never reach deserialisation & produce spurious errors. All other frames
+/// are forwarded untouched, and no work is done per frame when no [`Middleware`] is set.
+#[derive(Debug)]
+pub struct MiddlewareStream<St = WsStream> {
+    pub exchange: ExchangeId,
+    stream: St,
+    middleware: Option<Middleware>,
+}
+
+impl<St> MiddlewareStream<St> {
+    /// Construct a new [`MiddlewareStream`] wrapping the provided [`Stream`] of raw frames.
+    pub fn new(exchange: ExchangeId, stream: St, middleware: Option<Middleware>) -> Self {
+        Self {
+            exchange,
+            stream,
+            middleware,
+        }
+    }
+}
+
+/// Determine if the raw text frame is a non-JSON control or keepalive message (eg/ a plain
+/// "pong" or "heartbeat"), rather than a JSON object or array data frame.
+pub fn is_keepalive(text: &str) -> bool {
+    !matches!(text.trim_start().as_bytes().first(), Some(b'{' | b'['))
+}
+
+impl<St> Stream for MiddlewareStream<St>
+where
+    St: Stream<Item = Result<WsMessage, WsError>> + Unpin,
+{
+    type Item = Result<WsMessage, WsError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            let poll = Pin::new(&mut self.stream).poll_next(cx);
+
+            let text = match &poll {
+                Poll::Ready(Some(Ok(WsMessage::Text(text)))) => Some(text.as_str()),
+                Poll::Ready(Some(Ok(WsMessage::Binary(binary)))) => {
+                    std::str::from_utf8(binary).ok()
+                }
+                _ => None,
+            };
+
+            if let Some(text) = text {
+                if let Some(middleware) = &self.middleware {
+                    middleware.call(text, self.exchange);
+                }
+
+                if is_keepalive(text) {
+                    trace!(exchange = %self.exchange, frame = text, "skipping keepalive frame");
+                    continue;
+                }
+            }
+
+            return poll;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    };
+
+    #[tokio::test]
+    async fn test_middleware_observes_every_inbound_frame() {
+        let frames = vec![
+            Ok(WsMessage::Text(r#"{"id":1,"result":null}"#.to_owned())),
+            Ok(WsMessage::Text(r#"{"e":"trade","s":"BTCUSDT"}"#.to_owned())),
+            Ok(WsMessage::Binary(
+                br#"{"e":"trade","s":"ETHUSDT"}"#.to_vec(),
+            )),
+            Ok(WsMessage::Ping(vec![])),
+        ];
+
+        let count = Arc::new(AtomicUsize::new(0));
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let middleware = {
+            let count = Arc::clone(&count);
+            let seen = Arc::clone(&seen);
+            Middleware::new(move |frame, exchange| {
+                assert_eq!(exchange, ExchangeId::BinanceSpot);
+                count.fetch_add(1, Ordering::Relaxed);
+                seen.lock().unwrap().push(frame.to_owned());
+            })
+        };
+
+        let forwarded = MiddlewareStream::new(
+            ExchangeId::BinanceSpot,
+            futures::stream::iter(frames),
+            Some(middleware),
+        )
+        .collect::<Vec<_>>()
+        .await;
+
+        // Every data frame is forwarded untouched, but control frames are not passed to the Middleware
+        assert_eq!(forwarded.len(), 4);
+        assert_eq!(count.load(Ordering::Relaxed), 3);
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![
+                r#"{"id":1,"result":null}"#,
+                r#"{"e":"trade","s":"BTCUSDT"}"#,
+                r#"{"e":"trade","s":"ETHUSDT"}"#,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_keepalive_frames_are_skipped() {
+        let frames = vec![
+            Ok(WsMessage::Text("pong".to_owned())),
+            Ok(WsMessage::Text(r#"{"e":"trade","s":"BTCUSDT"}"#.to_owned())),
+            Ok(WsMessage::Text("heartbeat".to_owned())),
+            Ok(WsMessage::Binary(b"pong".to_vec())),
+            Ok(WsMessage::Text(r#" [420191,"te",[1,2,3,4]]"#.to_owned())),
+            Ok(WsMessage::Text(String::new())),
+            Ok(WsMessage::Text(r#"{"e":"trade","s":"ETHUSDT"}"#.to_owned())),
+        ];
+
+        let count = Arc::new(AtomicUsize::new(0));
+        let middleware = {
+            let count = Arc::clone(&count);
+            Middleware::new(move |_, _| {
+                count.fetch_add(1, Ordering::Relaxed);
+            })
+        };
+
+        let forwarded = MiddlewareStream::new(
+            ExchangeId::Okx,
+            futures::stream::iter(frames),
+            Some(middleware),
+        )
+        .map(|frame| match frame.unwrap() {
+            WsMessage::Text(text) => text,
+            frame => panic!("unexpected frame: {frame:?}"),
+        })
+        .collect::<Vec<_>>()
+        .await;
+
+        // Only data frames are forwarded for deserialisation, but the Middleware observes all
+        assert_eq!(
+            forwarded,
+            vec![
+                r#"{"e":"trade","s":"BTCUSDT"}"#,
+                r#" [420191,"te",[1,2,3,4]]"#,
+                r#"{"e":"trade","s":"ETHUSDT"}"#,
+            ]
+        );
+        assert_eq!(count.load(Ordering::Relaxed), 7);
+    }
+}
@@ -0,0 +1,256 @@
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MarketIter},
+    exchange::{Connector, ExchangeId},
+    streams::{ratelimit::RateLimitMonitor, rest::get_json},
+    subscription::trade::PublicTrade,
+};
+use barter_integration::model::instrument::Instrument;
+use futures::Future;
+use serde::de::DeserializeOwned;
+use std::collections::{HashSet, VecDeque};
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// Default number of recent trades fetched per [`Instrument`] by [`backfill_trades`].
+pub const DEFAULT_BACKFILL_TRADES: usize = 100;
+
+/// Implemented by an exchange [`Connector`] that exposes a REST recent trades endpoint usable
+/// for backfilling [`PublicTrade`] history before a live stream starts.
+///
+/// The [`Self::Response`] is converted via the exchange `From<(ExchangeId, Instrument, Response)>`
+/// implementation for [`MarketIter<PublicTrade>`], which marks each trade as
+/// [`TradeSource::Historical`](crate::subscription::trade::TradeSource::Historical).
+pub trait TradeBackfill
+where
+    Self: Connector,
+{
+    /// Base url of the exchange REST recent trades endpoint.
+    const RECENT_TRADES_URL: &'static str;
+
+    /// Deserialisable recent trades endpoint response.
+    type Response: DeserializeOwned;
+
+    /// Construct the recent trades request url for the [`Instrument`] from the `base_url`,
+    /// requesting at most `limit` trades.
+    fn recent_trades_url(base_url: &str, instrument: &Instrument, limit: usize) -> String;
+}
+
+/// Fetch up to `limit` recent [`MarketEvent<PublicTrade>`]s for the [`Instrument`] from the
+/// exchange REST recent trades endpoint, sorted oldest first.
+///
+/// Any rate-limit usage reported by the response is recorded in the provided
+/// [`RateLimitMonitor`].
+pub async fn backfill_trades<Exchange>(
+    instrument: &Instrument,
+    limit: usize,
+    rate_limits: Option<&RateLimitMonitor>,
+) -> Result<Vec<MarketEvent<PublicTrade>>, DataError>
+where
+    Exchange: TradeBackfill,
+    MarketIter<PublicTrade>: From<(ExchangeId, Instrument, Exchange::Response)>,
+{
+    backfill_trades_from::<Exchange>(Exchange::RECENT_TRADES_URL, instrument, limit, rate_limits)
+        .await
+}
+
+/// Fetch up to `limit` recent [`MarketEvent<PublicTrade>`]s for the [`Instrument`] using the
+/// provided recent trades endpoint `base_url`, sorted oldest first.
+pub async fn backfill_trades_from<Exchange>(
+    base_url: &str,
+    instrument: &Instrument,
+    limit: usize,
+    rate_limits: Option<&RateLimitMonitor>,
+) -> Result<Vec<MarketEvent<PublicTrade>>, DataError>
+where
+    Exchange: TradeBackfill,
+    MarketIter<PublicTrade>: From<(ExchangeId, Instrument, Exchange::Response)>,
+{
+    let response = get_json::<Exchange::Response>(
+        &reqwest::Client::new(),
+        Exchange::ID,
+        Exchange::recent_trades_url(base_url, instrument, limit),
+        rate_limits,
+    )
+    .await?;
+
+    let mut trades = MarketIter::<PublicTrade>::from((Exchange::ID, instrument.clone(), response))
+        .0
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Exchanges differ in the order recent trades are returned (eg/ Coinbase is newest first)
+    trades.sort_by_key(|trade| trade.exchange_time);
+
+    Ok(trades)
+}
+
+/// Spawn a task that emits the `historical` [`MarketEvent<PublicTrade>`]s once resolved,
+/// followed by every live [`MarketEvent<PublicTrade>`] from the provided [`mpsc::Receiver`].
+///
+/// Live trades received while the `historical` backfill is pending are buffered, so the live
+/// [`mpsc::Receiver`] is always drained. Live trades that were already emitted as part of the
+/// `historical` backfill (ie/ same [`Instrument`] & trade id) are dropped, so the seam between
+/// backfill and live stream contains no duplicates.
+pub fn bootstrap<Historical>(
+    historical: Historical,
+    mut live_rx: mpsc::Receiver<MarketEvent<PublicTrade>>,
+) -> mpsc::Receiver<MarketEvent<PublicTrade>>
+where
+    Historical: Future<Output = Vec<MarketEvent<PublicTrade>>> + Send + 'static,
+{
+    let (bootstrap_tx, bootstrap_rx) = mpsc::channel(live_rx.max_capacity());
+
+    tokio::spawn(async move {
+        // Buffer live trades until the historical backfill resolves
+        let mut buffered = VecDeque::new();
+        let mut historical = Box::pin(historical);
+        let historical = loop {
+            tokio::select! {
+                historical = &mut historical => break historical,
+                trade = live_rx.recv() => match trade {
+                    Some(trade) => buffered.push_back(trade),
+                    None => break historical.await,
+                },
+            }
+        };
+
+        let mut backfilled = historical
+            .iter()
+            .map(|trade| (trade.instrument.clone(), trade.kind.id.clone()))
+            .collect::<HashSet<_>>();
+
+        for trade in historical {
+            if bootstrap_tx.send(trade).await.is_err() {
+                return;
+            }
+        }
+
+        loop {
+            let trade = match buffered.pop_front() {
+                Some(trade) => trade,
+                None => match live_rx.recv().await {
+                    Some(trade) => trade,
+                    None => break,
+                },
+            };
+
+            // Each backfilled trade id is only expected once from the live stream
+            if !backfilled.is_empty()
+                && backfilled.remove(&(trade.instrument.clone(), trade.kind.id.clone()))
+            {
+                debug!(
+                    exchange = %trade.exchange,
+                    instrument = %trade.instrument,
+                    id = %trade.kind.id,
+                    "dropping live PublicTrade already emitted by backfill"
+                );
+                continue;
+            }
+
+            if bootstrap_tx.send(trade).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    bootstrap_rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::trade::TradeSource;
+    use barter_integration::model::{instrument::kind::InstrumentKind, Exchange, Side};
+    use chrono::{TimeZone, Utc};
+
+    fn trade(id: u64, source: TradeSource) -> MarketEvent<PublicTrade> {
+        MarketEvent {
+            exchange_time: Utc.timestamp_millis_opt(id as i64).unwrap(),
+            received_time: Default::default(),
+            exchange_time_synthesized: false,
+            exchange: Exchange::from(ExchangeId::BinanceSpot),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            kind: PublicTrade {
+                id: id.to_string(),
+                price: 100.0,
+                amount: 1.0,
+                side: Side::Buy,
+                source,
+                side_source: Default::default(),
+                order_ids: Default::default(),
+                aggregate_count: None,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bootstrap_dedupes_overlapping_trades_at_seam() {
+        let historical = (1..=5)
+            .map(|id| trade(id, TradeSource::Historical))
+            .collect::<Vec<_>>();
+
+        // Live stream starts before the backfill request completed, so ids 4 & 5 overlap
+        let (live_tx, live_rx) = mpsc::channel(10);
+        for id in 4..=8 {
+            live_tx.send(trade(id, TradeSource::Live)).await.unwrap();
+        }
+        drop(live_tx);
+
+        let mut bootstrap_rx = bootstrap(async { historical }, live_rx);
+
+        let mut actual = vec![];
+        while let Some(trade) = bootstrap_rx.recv().await {
+            actual.push((trade.kind.id, trade.kind.source));
+        }
+
+        let expected = (1..=5)
+            .map(|id| (id.to_string(), TradeSource::Historical))
+            .chain((6..=8).map(|id| (id.to_string(), TradeSource::Live)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[tokio::test]
+    async fn test_bootstrap_buffers_live_trades_while_backfill_pending() {
+        let (historical_tx, historical_rx) = tokio::sync::oneshot::channel();
+        let (live_tx, live_rx) = mpsc::channel(1);
+        let mut bootstrap_rx = bootstrap(
+            async move { historical_rx.await.unwrap_or_default() },
+            live_rx,
+        );
+
+        // Live receiver keeps being drained while the backfill is in flight
+        for id in 3..=6 {
+            tokio::time::timeout(
+                std::time::Duration::from_secs(5),
+                live_tx.send(trade(id, TradeSource::Live)),
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        }
+        drop(live_tx);
+
+        historical_tx
+            .send(
+                (1..=3)
+                    .map(|id| trade(id, TradeSource::Historical))
+                    .collect(),
+            )
+            .unwrap();
+
+        let mut actual = vec![];
+        while let Some(trade) = bootstrap_rx.recv().await {
+            actual.push((trade.kind.id, trade.kind.source));
+        }
+
+        let expected = (1..=3)
+            .map(|id| (id.to_string(), TradeSource::Historical))
+            .chain((4..=6).map(|id| (id.to_string(), TradeSource::Live)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,142 @@
+use tokio::sync::{broadcast, mpsc};
+use tracing::warn;
+
+/// Default number of events buffered by a [`Broadcast`] for each consumer before the slowest
+/// consumer starts to lag.
+pub const DEFAULT_BROADCAST_CAPACITY: usize = 1024;
+
+/// Fan-out layer that shares a single exchange event stream between many consumers.
+///
+/// ### Notes
+/// - Each consumer has an independent view of the most recent `capacity` events.
+/// - A slow consumer never stalls the other consumers: once it falls more than `capacity`
+///   events behind, the oldest events are discarded & it receives a
+///   [`BroadcastEvent::Lagged`] notification.
+/// - Events received before any consumer has subscribed are not replayed to consumers.
+#[derive(Debug)]
+pub struct Broadcast<T> {
+    rx: broadcast::Receiver<T>,
+}
+
+impl<T> Broadcast<T>
+where
+    T: Clone + Send + 'static,
+{
+    /// Construct a new [`Broadcast`] that forwards every event from the provided source
+    /// [`mpsc::Receiver`] to all subscribed consumers, buffering up to `capacity` events.
+    ///
+    /// Panics if `capacity` is 0.
+    pub fn new(mut source: mpsc::Receiver<T>, capacity: usize) -> Self {
+        let (tx, rx) = broadcast::channel(capacity);
+
+        tokio::spawn(async move {
+            while let Some(event) = source.recv().await {
+                // Error only means no consumers are currently subscribed, so discard the event
+                let _ = tx.send(event);
+            }
+        });
+
+        Self { rx }
+    }
+
+    /// Subscribe a new consumer, which will receive every event forwarded from now on.
+    pub fn subscribe(&self) -> BroadcastReceiver<T> {
+        BroadcastReceiver {
+            rx: self.rx.resubscribe(),
+        }
+    }
+}
+
+/// Event received by a [`BroadcastReceiver`] consumer.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum BroadcastEvent<T> {
+    /// Event forwarded from the source stream.
+    Event(T),
+    /// Consumer fell behind & the contained number of events were dropped.
+    Lagged(u64),
+}
+
+/// Consumer handle of a [`Broadcast`].
+#[derive(Debug)]
+pub struct BroadcastReceiver<T> {
+    rx: broadcast::Receiver<T>,
+}
+
+impl<T> BroadcastReceiver<T>
+where
+    T: Clone,
+{
+    /// Receive the next [`BroadcastEvent`], returning `None` once the source stream has
+    /// terminated and all buffered events have been received.
+    pub async fn recv(&mut self) -> Option<BroadcastEvent<T>> {
+        match self.rx.recv().await {
+            Ok(event) => Some(BroadcastEvent::Event(event)),
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                warn!(skipped, "broadcast consumer lagged and skipped events");
+                Some(BroadcastEvent::Lagged(skipped))
+            }
+            Err(broadcast::error::RecvError::Closed) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_broadcast_fan_out_to_many_consumers() {
+        let (source_tx, source_rx) = mpsc::channel(16);
+        let broadcast = Broadcast::new(source_rx, DEFAULT_BROADCAST_CAPACITY);
+
+        let mut consumers = [broadcast.subscribe(), broadcast.subscribe()];
+
+        for event in 0..10 {
+            source_tx.send(event).await.unwrap();
+        }
+        drop(source_tx);
+
+        let expected = (0..10).map(BroadcastEvent::Event).collect::<Vec<_>>();
+        for (index, consumer) in consumers.iter_mut().enumerate() {
+            let mut actual = vec![];
+            while let Some(event) = consumer.recv().await {
+                actual.push(event);
+            }
+            assert_eq!(actual, expected, "consumer {} failed", index);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_fan_out_with_slow_consumer_lag() {
+        let (source_tx, source_rx) = mpsc::channel(16);
+        let broadcast = Broadcast::new(source_rx, 4);
+
+        let mut fast = broadcast.subscribe();
+        let mut slow = broadcast.subscribe();
+        drop(broadcast);
+
+        // Fast consumer receives every event as it is sent
+        for event in 0..10 {
+            source_tx.send(event).await.unwrap();
+            assert_eq!(fast.recv().await, Some(BroadcastEvent::Event(event)));
+        }
+        drop(source_tx);
+        assert_eq!(fast.recv().await, None);
+
+        // Slow consumer is notified it lagged, then receives the most recent events
+        let mut actual = vec![];
+        while let Some(event) = slow.recv().await {
+            actual.push(event);
+        }
+
+        let expected = vec![
+            BroadcastEvent::Lagged(6),
+            BroadcastEvent::Event(6),
+            BroadcastEvent::Event(7),
+            BroadcastEvent::Event(8),
+            BroadcastEvent::Event(9),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
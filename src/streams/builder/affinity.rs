@@ -0,0 +1,268 @@
+use crate::{exchange::Connector, subscription::Subscription};
+use barter_integration::model::instrument::Instrument;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Connection assignment hint for the [`Subscription`]s of an [`Instrument`].
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum Affinity {
+    /// Action the [`Instrument`] [`Subscription`]s on their own dedicated connection, isolating
+    /// them from the traffic (& disconnections) of every other [`Instrument`].
+    Dedicated,
+    /// Action the [`Instrument`] [`Subscription`]s on a connection shared only with the other
+    /// [`Instrument`]s of the same named group.
+    Group(String),
+}
+
+/// Connection affinity hints used to deterministically assign [`Subscription`]s to WebSocket
+/// connections when partitioning them via [`ConnectionAffinity::partition`].
+///
+/// [`Instrument`]s without an [`Affinity`] share the remaining connections, which are split to
+/// respect the [`Connector::max_subscriptions_per_connection`] cap.
+///
+/// eg/ `ConnectionAffinity::default().dedicated(("btc", "usdt", InstrumentKind::Perpetual))`
+#[derive(Clone, Eq, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct ConnectionAffinity {
+    pub affinities: HashMap<Instrument, Affinity>,
+}
+
+/// Connection a [`Subscription`] is assigned to by [`ConnectionAffinity::partition`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+enum Assignment {
+    Shared,
+    Group(String),
+    Dedicated(Instrument),
+}
+
+impl ConnectionAffinity {
+    /// Action the [`Subscription`]s of the provided [`Instrument`] on a dedicated connection.
+    pub fn dedicated<I>(self, instrument: I) -> Self
+    where
+        I: Into<Instrument>,
+    {
+        self.affinity(instrument, Affinity::Dedicated)
+    }
+
+    /// Action the [`Subscription`]s of the provided [`Instrument`] on the connection of the named
+    /// group.
+    pub fn group<S, I>(self, group: S, instrument: I) -> Self
+    where
+        S: Into<String>,
+        I: Into<Instrument>,
+    {
+        self.affinity(instrument, Affinity::Group(group.into()))
+    }
+
+    /// Set the [`Affinity`] of the provided [`Instrument`], replacing any existing hint.
+    pub fn affinity<I>(mut self, instrument: I, affinity: Affinity) -> Self
+    where
+        I: Into<Instrument>,
+    {
+        self.affinities.insert(instrument.into(), affinity);
+        self
+    }
+
+    /// Partition the provided [`Subscription`]s into the collections that will each be actioned
+    /// on a distinct WebSocket connection.
+    ///
+    /// Connections are ordered by the first [`Subscription`] assigned to them, so the partition is
+    /// deterministic for a given input order. Shared connections are split into chunks of at most
+    /// [`Connector::max_subscriptions_per_connection`] [`Subscription`]s, whereas grouped &
+    /// dedicated connections are never split.
+    ///
+    /// Every [`Subscription`] of an [`Instrument`] is assigned to the same connection, so the
+    /// events of each [`Instrument`] are ordered. A shared chunk only exceeds the cap if a single
+    /// [`Instrument`] has more [`Subscription`]s than the cap allows.
+    pub fn partition<Exchange, Kind>(
+        &self,
+        subscriptions: Vec<Subscription<Exchange, Kind>>,
+    ) -> Vec<Vec<Subscription<Exchange, Kind>>>
+    where
+        Exchange: Connector,
+    {
+        let mut connections: Vec<(Assignment, Vec<Subscription<Exchange, Kind>>)> = Vec::new();
+
+        for subscription in subscriptions {
+            let assignment = match self.affinities.get(&subscription.instrument) {
+                None => Assignment::Shared,
+                Some(Affinity::Group(group)) => Assignment::Group(group.clone()),
+                Some(Affinity::Dedicated) => Assignment::Dedicated(subscription.instrument.clone()),
+            };
+
+            match connections
+                .iter_mut()
+                .find(|(existing, _)| *existing == assignment)
+            {
+                Some((_, connection)) => connection.push(subscription),
+                None => connections.push((assignment, vec![subscription])),
+            }
+        }
+
+        let max_shared = Exchange::max_subscriptions_per_connection()
+            .filter(|max| *max > 0)
+            .unwrap_or(usize::MAX);
+
+        connections
+            .into_iter()
+            .flat_map(|(assignment, connection)| {
+                if assignment != Assignment::Shared || connection.len() <= max_shared {
+                    return vec![connection];
+                }
+
+                // Group the Subscriptions of each Instrument, so an Instrument is never split
+                // across connections & its events flow through a single ordered connection
+                let mut instruments: Vec<Vec<Subscription<Exchange, Kind>>> = Vec::new();
+                for subscription in connection {
+                    match instruments
+                        .iter_mut()
+                        .find(|group| group[0].instrument == subscription.instrument)
+                    {
+                        Some(group) => group.push(subscription),
+                        None => instruments.push(vec![subscription]),
+                    }
+                }
+
+                let mut chunks: Vec<Vec<Subscription<Exchange, Kind>>> = Vec::new();
+                for group in instruments {
+                    match chunks.last_mut() {
+                        Some(chunk) if chunk.len() + group.len() <= max_shared => {
+                            chunk.extend(group)
+                        }
+                        _ => chunks.push(group),
+                    }
+                }
+                chunks
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{binance::futures::BinanceFuturesUsd, okx::Okx},
+        subscription::trade::PublicTrades,
+    };
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    fn okx(base: &str) -> Subscription<Okx, PublicTrades> {
+        Subscription::from((Okx, base, "usdt", InstrumentKind::Spot, PublicTrades))
+    }
+
+    fn bases<Exchange>(
+        partition: &[Vec<Subscription<Exchange, PublicTrades>>],
+    ) -> Vec<Vec<String>> {
+        partition
+            .iter()
+            .map(|connection| {
+                connection
+                    .iter()
+                    .map(|subscription| subscription.instrument.base.to_string())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_connection_affinity_partition() {
+        struct TestCase {
+            affinity: ConnectionAffinity,
+            expected: Vec<Vec<&'static str>>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: no affinity shares a single connection
+                affinity: ConnectionAffinity::default(),
+                expected: vec![vec!["btc", "eth", "sol", "xrp"]],
+            },
+            TestCase {
+                // TC1: dedicated instrument lands on its own connection
+                affinity: ConnectionAffinity::default().dedicated((
+                    "eth",
+                    "usdt",
+                    InstrumentKind::Spot,
+                )),
+                expected: vec![vec!["btc", "sol", "xrp"], vec!["eth"]],
+            },
+            TestCase {
+                // TC2: grouped instruments share a connection, isolated from the rest
+                affinity: ConnectionAffinity::default()
+                    .group("alts", ("sol", "usdt", InstrumentKind::Spot))
+                    .group("alts", ("xrp", "usdt", InstrumentKind::Spot))
+                    .dedicated(("btc", "usdt", InstrumentKind::Spot)),
+                expected: vec![vec!["btc"], vec!["eth"], vec!["sol", "xrp"]],
+            },
+            TestCase {
+                // TC3: affinity for an instrument of a different kind is not applied
+                affinity: ConnectionAffinity::default().dedicated((
+                    "eth",
+                    "usdt",
+                    InstrumentKind::Perpetual,
+                )),
+                expected: vec![vec!["btc", "eth", "sol", "xrp"]],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let partition =
+                test.affinity
+                    .partition(vec![okx("btc"), okx("eth"), okx("sol"), okx("xrp")]);
+            assert_eq!(bases(&partition), test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_connection_affinity_partition_splits_shared_connections() {
+        // BinanceFuturesUsd caps subscriptions per connection, so shared connections are split
+        let max = BinanceFuturesUsd::max_subscriptions_per_connection().unwrap();
+        let subscriptions = (0..max + 2)
+            .map(|index| {
+                Subscription::from((
+                    BinanceFuturesUsd::default(),
+                    format!("base{index}").as_str(),
+                    "usdt",
+                    InstrumentKind::Perpetual,
+                    PublicTrades,
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        let partition = ConnectionAffinity::default()
+            .dedicated(("base0", "usdt", InstrumentKind::Perpetual))
+            .partition(subscriptions);
+
+        let sizes = partition.iter().map(Vec::len).collect::<Vec<_>>();
+        assert_eq!(sizes, vec![1, max, 1]);
+        assert_eq!(bases(&partition[..1]), vec![vec!["base0"]]);
+    }
+
+    #[test]
+    fn test_connection_affinity_partition_never_splits_an_instrument() {
+        let max = BinanceFuturesUsd::max_subscriptions_per_connection().unwrap();
+        let subscription = |index: usize| {
+            Subscription::from((
+                BinanceFuturesUsd::default(),
+                format!("base{index}").as_str(),
+                "usdt",
+                InstrumentKind::Perpetual,
+                PublicTrades,
+            ))
+        };
+
+        // Last Instrument has a second Subscription beyond the cap of the first connection
+        let subscriptions = (0..max)
+            .map(subscription)
+            .chain(std::iter::once(subscription(max - 1)))
+            .collect::<Vec<_>>();
+
+        let partition = ConnectionAffinity::default().partition(subscriptions);
+
+        let sizes = partition.iter().map(Vec::len).collect::<Vec<_>>();
+        assert_eq!(sizes, vec![max - 1, 2]);
+        assert!(partition[1].iter().all(
+            |subscription| subscription.instrument.base.as_ref() == format!("base{}", max - 1)
+        ));
+    }
+}
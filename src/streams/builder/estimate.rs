@@ -0,0 +1,217 @@
+use crate::{
+    exchange::{subscription::ExchangeSub, Connector, ExchangeId},
+    subscription::Subscription,
+    Identifier,
+};
+use barter_integration::model::SubscriptionId;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+
+/// Exchange limit that a [`ConnectionEstimate`] predicts will be violated once connected.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum LimitViolation {
+    /// More subscriptions on a single connection than the
+    /// [`Connector::max_subscriptions_per_connection`] cap.
+    SubscriptionsPerConnection { subscriptions: usize, max: usize },
+}
+
+/// Dry-run estimate of a single WebSocket connection that will be opened for a collection of
+/// [`Subscription`]s, computed without connecting to the exchange.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct ConnectionEstimate {
+    pub exchange: ExchangeId,
+    /// Number of unique exchange subscriptions actioned on the connection.
+    pub subscriptions: usize,
+    /// Number of subscribe messages sent once connected, after batching.
+    pub requests: usize,
+    pub violations: Vec<LimitViolation>,
+}
+
+impl ConnectionEstimate {
+    /// Estimate the WebSocket connection that will be opened for the provided
+    /// [`Subscription`]s, based on the exchange [`Connector`] caps.
+    pub fn new<Exchange, Kind>(subscriptions: &[Subscription<Exchange, Kind>]) -> Self
+    where
+        Exchange: Connector,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        // Subscriptions (& associated ExchangeSubs) colliding on the same SubscriptionId are
+        // only actioned once
+        let mut ids = HashSet::<SubscriptionId>::with_capacity(subscriptions.len());
+        let exchange_subs = subscriptions
+            .iter()
+            .map(ExchangeSub::new)
+            .flat_map(|exchange_sub| {
+                let associated_subs = Exchange::associated_subs(&exchange_sub);
+                std::iter::once(exchange_sub).chain(associated_subs)
+            })
+            .filter(|exchange_sub| ids.insert(exchange_sub.id()))
+            .collect::<Vec<ExchangeSub<Exchange::Channel, Exchange::Market>>>();
+
+        let subscriptions = exchange_subs.len();
+        let requests = Exchange::batched_requests(exchange_subs).len();
+
+        let violations = Exchange::max_subscriptions_per_connection()
+            .filter(|max| subscriptions > *max)
+            .map(|max| LimitViolation::SubscriptionsPerConnection { subscriptions, max })
+            .into_iter()
+            .collect();
+
+        Self {
+            exchange: Exchange::ID,
+            subscriptions,
+            requests,
+            violations,
+        }
+    }
+}
+
+/// Dry-run estimate of every WebSocket connection a
+/// [`StreamBuilder`](super::StreamBuilder) will open once initialised.
+#[derive(Clone, Eq, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct SubscriptionEstimate {
+    pub connections: Vec<ConnectionEstimate>,
+}
+
+impl SubscriptionEstimate {
+    /// Number of WebSocket connections that will be opened to each exchange.
+    pub fn connections_per_exchange(&self) -> BTreeMap<ExchangeId, usize> {
+        self.connections
+            .iter()
+            .fold(BTreeMap::new(), |mut connections, connection| {
+                *connections.entry(connection.exchange).or_default() += 1;
+                connections
+            })
+    }
+
+    /// Total number of unique exchange subscriptions actioned across every connection.
+    pub fn subscriptions(&self) -> usize {
+        self.connections
+            .iter()
+            .map(|connection| connection.subscriptions)
+            .sum()
+    }
+
+    /// Total number of subscribe messages that will be sent across every connection.
+    pub fn requests(&self) -> usize {
+        self.connections
+            .iter()
+            .map(|connection| connection.requests)
+            .sum()
+    }
+
+    /// Every predicted [`LimitViolation`], and the exchange it applies to.
+    pub fn violations(&self) -> impl Iterator<Item = (ExchangeId, LimitViolation)> + '_ {
+        self.connections.iter().flat_map(|connection| {
+            connection
+                .violations
+                .iter()
+                .map(|violation| (connection.exchange, *violation))
+        })
+    }
+
+    /// Determine if no [`LimitViolation`]s are predicted.
+    pub fn is_within_limits(&self) -> bool {
+        self.violations().next().is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{
+            binance::spot::BinanceSpot,
+            okx::{Okx, MAX_ARGS_PER_REQUEST_OKX},
+        },
+        streams::builder::StreamBuilder,
+        subscription::trade::PublicTrades,
+    };
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    fn okx_subs(num: usize) -> Vec<Subscription<Okx, PublicTrades>> {
+        (0..num)
+            .map(|index| {
+                Subscription::from((
+                    Okx,
+                    format!("base{index}").as_str(),
+                    "usdt",
+                    InstrumentKind::Spot,
+                    PublicTrades,
+                ))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_estimate_matches_stream_builder_connections() {
+        let batched = okx_subs(MAX_ARGS_PER_REQUEST_OKX * 2 + 1);
+
+        // Duplicate Subscription is only actioned once
+        let mut duplicated = okx_subs(2);
+        duplicated.push(duplicated[0].clone());
+
+        let builder = StreamBuilder::<PublicTrades>::new()
+            .subscribe(batched.clone())
+            .subscribe(duplicated)
+            .subscribe([(
+                BinanceSpot::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                PublicTrades,
+            )]);
+
+        let estimate = builder.estimate();
+
+        // Estimate matches the number of connections the StreamBuilder will open
+        assert_eq!(estimate.connections.len(), builder.futures.len());
+        assert_eq!(
+            estimate.connections_per_exchange(),
+            BTreeMap::from([(ExchangeId::BinanceSpot, 1), (ExchangeId::Okx, 2)])
+        );
+
+        // Batched connection sends one subscribe message per MAX_ARGS_PER_REQUEST_OKX chunk
+        let expected_requests =
+            Okx::batched_requests(batched.iter().map(ExchangeSub::new).collect());
+        assert_eq!(estimate.connections[0].subscriptions, batched.len());
+        assert_eq!(estimate.connections[0].requests, 3);
+        assert_eq!(estimate.connections[0].requests, expected_requests.len());
+
+        assert_eq!(estimate.connections[1].subscriptions, 2);
+        assert_eq!(estimate.connections[1].requests, 1);
+        assert_eq!(estimate.requests(), 5);
+        assert!(estimate.is_within_limits());
+    }
+
+    #[test]
+    fn test_estimate_predicts_subscriptions_per_connection_violation() {
+        let subscriptions = (0..1025)
+            .map(|index| {
+                Subscription::from((
+                    BinanceSpot::default(),
+                    format!("base{index}").as_str(),
+                    "usdt",
+                    InstrumentKind::Spot,
+                    PublicTrades,
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        let estimate = SubscriptionEstimate {
+            connections: vec![ConnectionEstimate::new(&subscriptions)],
+        };
+
+        assert_eq!(
+            estimate.violations().collect::<Vec<_>>(),
+            vec![(
+                ExchangeId::BinanceSpot,
+                LimitViolation::SubscriptionsPerConnection {
+                    subscriptions: 1025,
+                    max: 1024
+                }
+            )]
+        );
+        assert!(!estimate.is_within_limits());
+    }
+}
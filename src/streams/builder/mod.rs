@@ -1,33 +1,78 @@
-use super::{consumer::consume, Streams};
+use self::{
+    affinity::ConnectionAffinity,
+    estimate::{ConnectionEstimate, SubscriptionEstimate},
+};
+use super::{
+    consumer::consume,
+    health::HealthMonitor,
+    lifecycle::LifecycleEvent,
+    reconnect::{ExponentialBackoff, ReconnectPolicy},
+    sink::{Output, OutputSink},
+    subscriptions::SubscriptionSet,
+    Streams,
+};
 use crate::{
     error::DataError,
     event::MarketEvent,
     exchange::{ExchangeId, StreamSelector},
+    subscriber::config::ConnectionConfig,
     subscription::{SubKind, Subscription},
     Identifier,
 };
 use barter_integration::{error::SocketError, Validator};
-use std::{collections::HashMap, fmt::Debug, future::Future, pin::Pin};
+use std::{collections::HashMap, fmt::Debug, future::Future, pin::Pin, sync::Arc};
 use tokio::sync::mpsc;
 
+/// [`ConnectionAffinity`](affinity::ConnectionAffinity) hints that deterministically assign
+/// [`Subscription`]s to dedicated or grouped WebSocket connections.
+pub mod affinity;
+
+/// Dry-run [`SubscriptionEstimate`](estimate::SubscriptionEstimate) of the connections a
+/// [`StreamBuilder`] will open, and any exchange limits they are predicted to violate.
+pub mod estimate;
+
 /// Defines the [`MultiStreamBuilder`](multi::MultiStreamBuilder) API for ergonomically
 /// initialising a common [`Streams<Output>`](Streams) from multiple
 /// [`StreamBuilder<SubKind>`](StreamBuilder)s.
 pub mod multi;
 
+/// Default capacity of each exchange [`ExchangeChannel`] buffering
+/// [`MarketEvent<T>`](MarketEvent)s between a consumer loop and the [`Streams`] receiver.
+///
+/// Once an [`ExchangeChannel`] is full, the consumer loop applies the
+/// [`LagPolicy`](super::consumer::LagPolicy) of its [`ConnectionConfig`], by default waiting for
+/// capacity so that no events are lost.
+pub const DEFAULT_CHANNEL_CAPACITY: usize = 1024;
+
 /// Communicative type alias representing the [`Future`] result of a [`Subscription`] [`validate`]
 /// call generated whilst executing [`StreamBuilder::subscribe`].
 pub type SubscribeFuture = Pin<Box<dyn Future<Output = Result<(), DataError>>>>;
 
 /// Builder to configure and initialise a [`Streams<MarketEvent<SubKind::Event>`](Streams) instance
 /// for a specific [`SubKind`].
-#[derive(Default)]
 pub struct StreamBuilder<Kind>
 where
     Kind: SubKind,
 {
     pub channels: HashMap<ExchangeId, ExchangeChannel<MarketEvent<Kind::Event>>>,
     pub futures: Vec<SubscribeFuture>,
+    pub estimates: Vec<ConnectionEstimate>,
+    pub config: ConnectionConfig,
+    pub reconnect_policy: Arc<dyn ReconnectPolicy>,
+    pub lifecycle_tx: Option<mpsc::UnboundedSender<LifecycleEvent>>,
+    pub health: HealthMonitor,
+    pub channel_capacity: usize,
+    pub filter_instruments: bool,
+    pub max_subscriptions: Option<usize>,
+}
+
+impl<Kind> Default for StreamBuilder<Kind>
+where
+    Kind: SubKind,
+{
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<Kind> Debug for StreamBuilder<Kind>
@@ -38,6 +83,14 @@ where
         f.debug_struct("StreamBuilder<SubKind>")
             .field("channels", &self.channels)
             .field("num_futures", &self.futures.len())
+            .field("estimates", &self.estimates)
+            .field("config", &self.config)
+            .field("reconnect_policy", &self.reconnect_policy)
+            .field("lifecycle_tx", &self.lifecycle_tx)
+            .field("health", &self.health)
+            .field("channel_capacity", &self.channel_capacity)
+            .field("filter_instruments", &self.filter_instruments)
+            .field("max_subscriptions", &self.max_subscriptions)
             .finish()
     }
 }
@@ -51,15 +104,112 @@ where
         Self {
             channels: HashMap::new(),
             futures: Vec::new(),
+            estimates: Vec::new(),
+            config: ConnectionConfig::default(),
+            reconnect_policy: Arc::new(ExponentialBackoff::default()),
+            lifecycle_tx: None,
+            health: HealthMonitor::default(),
+            channel_capacity: DEFAULT_CHANNEL_CAPACITY,
+            filter_instruments: true,
+            max_subscriptions: None,
         }
     }
 
+    /// Set the capacity of the [`ExchangeChannel`] created for each exchange of the
+    /// [`Subscription`]s added via subsequent [`subscribe()`](StreamBuilder::subscribe()) calls.
+    ///
+    /// High-rate [`SubKind`]s (eg/ OrderBooksL2) may require a larger capacity than low-rate
+    /// [`SubKind`]s (eg/ Liquidations). Defaults to [`DEFAULT_CHANNEL_CAPACITY`].
+    ///
+    /// [`init()`](StreamBuilder::init()) returns an error if the capacity is zero.
+    pub fn channel_capacity(mut self, capacity: usize) -> Self {
+        self.channel_capacity = capacity;
+        self
+    }
+
+    /// Set whether [`MarketEvent<T>`](MarketEvent)s for an
+    /// [`Instrument`](barter_integration::model::instrument::Instrument) that is not part of the
+    /// [`Subscription`]s added via subsequent [`subscribe()`](StreamBuilder::subscribe()) calls
+    /// are dropped before reaching the [`Streams`].
+    ///
+    /// Some exchanges send events for instruments beyond those subscribed to (eg/ array
+    /// channels). Defaults to true, so disable to receive every event.
+    pub fn filter_instruments(mut self, enabled: bool) -> Self {
+        self.filter_instruments = enabled;
+        self
+    }
+
+    /// Set the maximum total number of unique exchange subscriptions across every
+    /// [`Subscription`] added to the [`StreamBuilder`], guarding against accidentally
+    /// subscribing to an excessive number of instruments (eg/ and being banned by the exchange).
+    ///
+    /// [`init()`](StreamBuilder::init()) returns a [`DataError::MaxSubscriptionsExceeded`]
+    /// before connecting to any exchange if the maximum is exceeded. Defaults to unlimited.
+    pub fn max_subscriptions(mut self, max: usize) -> Self {
+        self.max_subscriptions = Some(max);
+        self
+    }
+
+    /// Send the [`LifecycleEvent`]s (eg/ [`LifecycleEvent::Maintenance`]) emitted by the consumer
+    /// loops of all [`Subscription`]s added via subsequent
+    /// [`subscribe()`](StreamBuilder::subscribe()) calls to the provided
+    /// [`mpsc::UnboundedSender`].
+    pub fn lifecycle_events(mut self, lifecycle_tx: mpsc::UnboundedSender<LifecycleEvent>) -> Self {
+        self.lifecycle_tx = Some(lifecycle_tx);
+        self
+    }
+
+    /// Set the [`ReconnectPolicy`] used to re-initialise disconnected WebSocket connections for
+    /// the [`Subscription`]s added via subsequent [`subscribe()`](StreamBuilder::subscribe())
+    /// calls.
+    ///
+    /// Defaults to an [`ExponentialBackoff`].
+    pub fn reconnect_policy<Policy>(mut self, policy: Policy) -> Self
+    where
+        Policy: ReconnectPolicy + 'static,
+    {
+        self.reconnect_policy = Arc::new(policy);
+        self
+    }
+
+    /// Set the [`ConnectionConfig`] (eg/ custom upgrade request headers) applied to every
+    /// WebSocket connection dialed for the [`Subscription`]s added via subsequent
+    /// [`subscribe()`](StreamBuilder::subscribe()) calls.
+    pub fn connection_config(mut self, config: ConnectionConfig) -> Self {
+        self.config = config;
+        self
+    }
+
     /// Add a collection of [`Subscription`]s to the [`StreamBuilder`] that will be actioned on
     /// a distinct [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection.
     ///
     /// Note that [`Subscription`]s are not actioned until the
     /// [`init()`](StreamBuilder::init()) method is invoked.
-    pub fn subscribe<SubIter, Sub, Exchange>(mut self, subscriptions: SubIter) -> Self
+    pub fn subscribe<SubIter, Sub, Exchange>(self, subscriptions: SubIter) -> Self
+    where
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<Exchange, Kind>>,
+        Exchange: StreamSelector<Kind> + Ord + Send + Sync + 'static,
+        Kind: Ord + Send + Sync + 'static,
+        Kind::Event: Send,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        self.subscribe_set(SubscriptionSet::new(
+            subscriptions.into_iter().map(Sub::into),
+        ))
+    }
+
+    /// Add a collection of [`Subscription`]s to the [`StreamBuilder`], partitioned across as many
+    /// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connections as required
+    /// by the [`ConnectionAffinity`] hints (see [`ConnectionAffinity::partition`]).
+    ///
+    /// Useful for isolating a critical instrument on its own connection, so a noisy instrument
+    /// cannot delay or disconnect it.
+    pub fn subscribe_with_affinity<SubIter, Sub, Exchange>(
+        self,
+        subscriptions: SubIter,
+        affinity: &ConnectionAffinity,
+    ) -> Self
     where
         SubIter: IntoIterator<Item = Sub>,
         Sub: Into<Subscription<Exchange, Kind>>,
@@ -68,24 +218,78 @@ where
         Kind::Event: Send,
         Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
     {
-        // Construct Vec<Subscriptions> from input SubIter
-        let mut subscriptions = subscriptions.into_iter().map(Sub::into).collect::<Vec<_>>();
+        affinity
+            .partition(subscriptions.into_iter().map(Sub::into).collect())
+            .into_iter()
+            .fold(self, |builder, connection| {
+                builder.subscribe_set(SubscriptionSet::new(connection))
+            })
+    }
+
+    /// Add a [`SubscriptionSet`] to the [`StreamBuilder`] that will be actioned on a distinct
+    /// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection.
+    ///
+    /// Retain a clone of the [`SubscriptionSet`] to add or remove [`Subscription`]s at runtime,
+    /// which are actioned upon the next re-connection. Validation & the
+    /// [`estimate()`](StreamBuilder::estimate()) only consider the [`Subscription`]s present
+    /// when this method is invoked.
+    pub fn subscribe_set<Exchange>(mut self, subscriptions: SubscriptionSet<Exchange, Kind>) -> Self
+    where
+        Exchange: StreamSelector<Kind> + Ord + Send + Sync + 'static,
+        Kind: Ord + Send + Sync + 'static,
+        Kind::Event: Send,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        // Ensure the ExchangeChannel can buffer at least one MarketEvent<Kind::Event>
+        if self.channel_capacity == 0 {
+            self.futures.push(Box::pin(async {
+                Err(DataError::from(SocketError::Subscribe(
+                    "StreamBuilder channel_capacity must be non-zero".to_owned(),
+                )))
+            }));
+            return self;
+        }
+
+        // Estimate the WebSocket connection these Subscriptions will open
+        self.estimates
+            .push(ConnectionEstimate::new(&subscriptions.snapshot()));
 
         // Acquire channel Sender to send Market<Kind::Event> from consumer loop to user
         // '--> Add ExchangeChannel Entry if this Exchange <--> SubKind combination is new
-        let exchange_tx = self.channels.entry(Exchange::ID).or_default().tx.clone();
+        let capacity = self.channel_capacity;
+        let exchange_tx = self
+            .channels
+            .entry(Exchange::ID)
+            .or_insert_with(|| ExchangeChannel::with_capacity(capacity))
+            .tx
+            .clone();
+
+        // Capture the ConnectionConfig, ReconnectPolicy, LifecycleEvent Sender & Instrument filter
+        // to apply to this WebSocket connection, and register it with the HealthMonitor
+        let config = self.config.clone();
+        let reconnect_policy = Arc::clone(&self.reconnect_policy);
+        let lifecycle_tx = self.lifecycle_tx.clone();
+        let health = self.health.register(Exchange::ID);
+        let filter_instruments = self.filter_instruments;
 
         // Add Future that once awaited will yield the Result<(), SocketError> of subscribing
         self.futures.push(Box::pin(async move {
             // Validate Subscriptions
-            validate(&subscriptions)?;
-
-            // Remove duplicate Subscriptions
-            subscriptions.sort();
-            subscriptions.dedup();
+            if let Err(error) = validate(&subscriptions.snapshot()) {
+                health.dead();
+                return Err(error);
+            }
 
             // Spawn a MarketStream consumer loop with these Subscriptions<Exchange, Kind>
-            tokio::spawn(consume(subscriptions, exchange_tx));
+            tokio::spawn(consume(
+                subscriptions,
+                config,
+                reconnect_policy,
+                lifecycle_tx,
+                health,
+                filter_instruments,
+                exchange_tx,
+            ));
 
             Ok(())
         }));
@@ -93,6 +297,29 @@ where
         self
     }
 
+    /// Dry-run estimate of the WebSocket connections that [`init()`](StreamBuilder::init())
+    /// will open for the [`Subscription`]s added so far, without connecting to any exchange.
+    pub fn estimate(&self) -> SubscriptionEstimate {
+        SubscriptionEstimate {
+            connections: self.estimates.clone(),
+        }
+    }
+
+    /// Validate the total number of unique exchange subscriptions added so far does not exceed
+    /// the configured [`max_subscriptions()`](StreamBuilder::max_subscriptions()).
+    pub fn validate_max_subscriptions(&self) -> Result<(), DataError> {
+        let Some(max) = self.max_subscriptions else {
+            return Ok(());
+        };
+
+        match self.estimate().subscriptions() {
+            subscriptions if subscriptions > max => {
+                Err(DataError::MaxSubscriptionsExceeded { subscriptions, max })
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Spawn a [`MarketEvent<SubKind::Event>`](MarketEvent) consumer loop for each collection of
     /// [`Subscription`]s added to [`StreamBuilder`] via the
     /// [`subscribe()`](StreamBuilder::subscribe()) method.
@@ -100,6 +327,9 @@ where
     /// Each consumer loop distributes consumed [`MarketEvent<SubKind::Event>s`](MarketEvent) to
     /// the [`Streams`] `HashMap` returned by this method.
     pub async fn init(self) -> Result<Streams<MarketEvent<Kind::Event>>, DataError> {
+        // Ensure the maximum number of subscriptions is not exceeded before connecting
+        self.validate_max_subscriptions()?;
+
         // Await Stream initialisation perpetual and ensure success
         futures::future::try_join_all(self.futures).await?;
 
@@ -110,22 +340,44 @@ where
                 .into_iter()
                 .map(|(exchange, channel)| (exchange, channel.rx))
                 .collect(),
+            clock_offsets: HashMap::new(),
+            latency: None,
+            health: self.health,
+            rate_limits: self.config.rate_limits,
         })
     }
+
+    /// [`init()`](StreamBuilder::init()) the [`Streams`], and deliver the
+    /// [`MarketEvent<SubKind::Event>`](MarketEvent)s of every exchange via the provided
+    /// [`OutputSink`] (eg/ a multi-consumer [`OutputSink::Broadcast`]).
+    pub async fn init_sink(
+        self,
+        sink: OutputSink<MarketEvent<Kind::Event>>,
+    ) -> Result<Output<MarketEvent<Kind::Event>>, DataError>
+    where
+        Kind::Event: Clone + Send + 'static,
+    {
+        Ok(self.init().await?.into_sink(sink).await)
+    }
 }
 
-/// Convenient type that holds the [`mpsc::UnboundedSender`] and [`mpsc::UnboundedReceiver`] for a
+/// Convenient type that holds the bounded [`mpsc::Sender`] and [`mpsc::Receiver`] for a
 /// [`MarketEvent<T>`](MarketEvent) channel.
 #[derive(Debug)]
 pub struct ExchangeChannel<T> {
-    tx: mpsc::UnboundedSender<T>,
-    rx: mpsc::UnboundedReceiver<T>,
+    tx: mpsc::Sender<T>,
+    rx: mpsc::Receiver<T>,
 }
 
 impl<T> ExchangeChannel<T> {
-    /// Construct a new [`Self`].
+    /// Construct a new [`Self`] with the [`DEFAULT_CHANNEL_CAPACITY`].
     pub fn new() -> Self {
-        let (tx, rx) = mpsc::unbounded_channel();
+        Self::with_capacity(DEFAULT_CHANNEL_CAPACITY)
+    }
+
+    /// Construct a new [`Self`] with the provided capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let (tx, rx) = mpsc::channel(capacity);
         Self { tx, rx }
     }
 }
@@ -137,7 +389,10 @@ impl<T> Default for ExchangeChannel<T> {
 }
 
 /// Validate the provided collection of [`Subscription`]s, ensuring that the associated exchange
-/// supports every [`Subscription`] [`InstrumentKind`](barter_integration::model::InstrumentKind).
+/// supports every [`Subscription`] [`SubKind`] (including its configuration) &
+/// [`InstrumentKind`](barter_integration::model::instrument::kind::InstrumentKind) pair.
+///
+/// An unsupported pair yields a [`DataError::UnsupportedSubscription`] describing it.
 pub fn validate<Exchange, Kind>(
     subscriptions: &[Subscription<Exchange, Kind>],
 ) -> Result<(), DataError>
@@ -147,16 +402,27 @@ where
 {
     // Ensure at least one Subscription has been provided
     if subscriptions.is_empty() {
-        return Err(DataError::Socket(SocketError::Subscribe(
+        return Err(DataError::from(SocketError::Subscribe(
             "StreamBuilder contains no Subscription to action".to_owned(),
         )));
     }
 
-    // Validate the Exchange supports each Subscription InstrumentKind
-    subscriptions
-        .iter()
-        .map(|subscription| subscription.validate())
-        .collect::<Result<Vec<_>, SocketError>>()?;
+    // Validate the Exchange supports each Subscription SubKind & InstrumentKind pair
+    if let Some(unsupported) = subscriptions.iter().find(|subscription| {
+        !<Exchange as StreamSelector<Kind>>::supports(subscription.instrument.kind)
+            || !<Exchange as StreamSelector<Kind>>::supports_kind(&subscription.kind)
+    }) {
+        return Err(DataError::UnsupportedSubscription {
+            exchange: Exchange::ID,
+            kind: Kind::NAME,
+            instrument_kind: unsupported.instrument.kind,
+        });
+    }
+
+    // Validate each Subscription Instrument
+    for subscription in subscriptions {
+        subscription.validate()?;
+    }
 
     Ok(())
 }
@@ -164,8 +430,121 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{exchange::coinbase::Coinbase, subscription::trade::PublicTrades};
-    use barter_integration::model::instrument::kind::InstrumentKind;
+    use crate::{
+        exchange::{
+            binance::{futures::BinanceFuturesUsd, spot::BinanceSpot},
+            coinbase::Coinbase,
+        },
+        subscription::{
+            book::{Level, OrderBook, OrderBookSide, OrderBooksL2},
+            liquidation::{Liquidation, Liquidations},
+            trade::{FilteredTrades, PublicTrades},
+        },
+    };
+    use barter_integration::model::{
+        instrument::{kind::InstrumentKind, Instrument},
+        Exchange, Side,
+    };
+    use chrono::Utc;
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn test_validate_unsupported_subscription() {
+        fn unsupported<Exchange, Kind>(
+            subscription: Subscription<Exchange, Kind>,
+        ) -> Option<(ExchangeId, &'static str, InstrumentKind)>
+        where
+            Exchange: StreamSelector<Kind>,
+            Kind: SubKind,
+        {
+            match validate(&[subscription]) {
+                Err(DataError::UnsupportedSubscription {
+                    exchange,
+                    kind,
+                    instrument_kind,
+                }) => Some((exchange, kind, instrument_kind)),
+                _ => None,
+            }
+        }
+
+        let cases = vec![
+            // TC0: Coinbase PublicTrades for a Perpetual
+            (
+                unsupported(Subscription::from((
+                    Coinbase,
+                    "btc",
+                    "usd",
+                    InstrumentKind::Perpetual,
+                    PublicTrades,
+                ))),
+                Some((
+                    ExchangeId::Coinbase,
+                    "public_trades",
+                    InstrumentKind::Perpetual,
+                )),
+            ),
+            // TC1: BinanceFuturesUsd Liquidations for a Spot market
+            (
+                unsupported(Subscription::from((
+                    BinanceFuturesUsd::default(),
+                    "btc",
+                    "usdt",
+                    InstrumentKind::Spot,
+                    Liquidations,
+                ))),
+                Some((
+                    ExchangeId::BinanceFuturesUsd,
+                    "liquidations",
+                    InstrumentKind::Spot,
+                )),
+            ),
+            // TC2: BinanceSpot OrderBooksL2 for a Perpetual
+            (
+                unsupported(Subscription::from((
+                    BinanceSpot::default(),
+                    "btc",
+                    "usdt",
+                    InstrumentKind::Perpetual,
+                    OrderBooksL2,
+                ))),
+                Some((
+                    ExchangeId::BinanceSpot,
+                    "order_books_l2",
+                    InstrumentKind::Perpetual,
+                )),
+            ),
+            // TC3: Coinbase FilteredTrades for a Perpetual is named without its parameters
+            (
+                unsupported(Subscription::from((
+                    Coinbase,
+                    "btc",
+                    "usd",
+                    InstrumentKind::Perpetual,
+                    FilteredTrades::default().min_amount(Decimal::ONE),
+                ))),
+                Some((
+                    ExchangeId::Coinbase,
+                    "filtered_trades",
+                    InstrumentKind::Perpetual,
+                )),
+            ),
+            // TC4: supported BinanceSpot OrderBooksL2 for a Spot market
+            (
+                unsupported(Subscription::from((
+                    BinanceSpot::default(),
+                    "btc",
+                    "usdt",
+                    InstrumentKind::Spot,
+                    OrderBooksL2,
+                ))),
+                None,
+            ),
+        ];
+
+        for (index, (actual, expected)) in cases.into_iter().enumerate() {
+            assert_eq!(actual, expected, "TC{} failed", index);
+        }
+    }
 
     #[test]
     fn test_validate() {
@@ -227,4 +606,176 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_subscribe_with_affinity_isolates_dedicated_instrument() {
+        let affinity =
+            ConnectionAffinity::default().dedicated(("btc", "usdt", InstrumentKind::Perpetual));
+
+        let builder = StreamBuilder::<PublicTrades>::new().subscribe_with_affinity(
+            ["btc", "eth", "sol"].map(|base| {
+                (
+                    BinanceFuturesUsd::default(),
+                    base,
+                    "usdt",
+                    InstrumentKind::Perpetual,
+                    PublicTrades,
+                )
+            }),
+            &affinity,
+        );
+
+        // Dedicated instrument lands on its own connection, sharing the exchange channel
+        let estimate = builder.estimate();
+        let subscriptions = estimate
+            .connections
+            .iter()
+            .map(|connection| connection.subscriptions)
+            .collect::<Vec<_>>();
+        assert_eq!(subscriptions, vec![1, 2]);
+        assert_eq!(builder.futures.len(), 2);
+        assert_eq!(builder.channels.len(), 1);
+    }
+
+    #[test]
+    fn test_channel_capacity_per_sub_kind() {
+        // Send clones of the MarketEvent until the ExchangeChannel is full
+        fn fill<T: Clone>(tx: &mpsc::Sender<T>, event: T) -> usize {
+            let mut sent = 0;
+            while tx.try_send(event.clone()).is_ok() {
+                sent += 1;
+            }
+            sent
+        }
+
+        fn market_event<T>(exchange: ExchangeId, kind: T) -> MarketEvent<T> {
+            MarketEvent {
+                exchange_time: Utc::now(),
+                received_time: Utc::now(),
+                exchange_time_synthesized: false,
+                exchange: Exchange::from(exchange),
+                instrument: Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+                kind,
+            }
+        }
+
+        let books = StreamBuilder::<OrderBooksL2>::new()
+            .channel_capacity(64)
+            .subscribe([(
+                BinanceSpot::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                OrderBooksL2,
+            )]);
+
+        let liquidations = StreamBuilder::<Liquidations>::new()
+            .channel_capacity(2)
+            .subscribe([(
+                BinanceFuturesUsd::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::Perpetual,
+                Liquidations,
+            )]);
+
+        let default = StreamBuilder::<PublicTrades>::new().subscribe([(
+            Coinbase,
+            "btc",
+            "usd",
+            InstrumentKind::Spot,
+            PublicTrades,
+        )]);
+
+        let book = OrderBook {
+            last_update_time: Utc::now(),
+            bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+            asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+        };
+        let liquidation = Liquidation {
+            side: Side::Sell,
+            price: 30000.0,
+            quantity: 1.0,
+            time: Utc::now(),
+        };
+
+        assert_eq!(
+            fill(
+                &books.channels[&ExchangeId::BinanceSpot].tx,
+                market_event(ExchangeId::BinanceSpot, book)
+            ),
+            64
+        );
+        assert_eq!(
+            fill(
+                &liquidations.channels[&ExchangeId::BinanceFuturesUsd].tx,
+                market_event(ExchangeId::BinanceFuturesUsd, liquidation)
+            ),
+            2
+        );
+        assert_eq!(
+            default.channels[&ExchangeId::Coinbase].tx.max_capacity(),
+            DEFAULT_CHANNEL_CAPACITY
+        );
+    }
+
+    #[tokio::test]
+    async fn test_max_subscriptions() {
+        fn builder(num: usize) -> StreamBuilder<PublicTrades> {
+            StreamBuilder::<PublicTrades>::new().subscribe((0..num).map(|index| {
+                (
+                    Coinbase,
+                    format!("base{index}"),
+                    "usd".to_string(),
+                    InstrumentKind::Spot,
+                    PublicTrades,
+                )
+            }))
+        }
+
+        // Default is unlimited
+        assert!(builder(100).validate_max_subscriptions().is_ok());
+
+        // Under & at the maximum
+        assert!(builder(9)
+            .max_subscriptions(10)
+            .validate_max_subscriptions()
+            .is_ok());
+        assert!(builder(10)
+            .max_subscriptions(10)
+            .validate_max_subscriptions()
+            .is_ok());
+
+        // Over the maximum, split across several connections, fails before connecting
+        let over = builder(6)
+            .max_subscriptions(10)
+            .subscribe((6..11).map(|index| {
+                (
+                    Coinbase,
+                    format!("base{index}"),
+                    "usd".to_string(),
+                    InstrumentKind::Spot,
+                    PublicTrades,
+                )
+            }));
+        match over.init().await {
+            Err(DataError::MaxSubscriptionsExceeded { subscriptions, max }) => {
+                assert_eq!((subscriptions, max), (11, 10));
+            }
+            other => panic!("expected MaxSubscriptionsExceeded, got: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_init_rejects_zero_channel_capacity() {
+        let builder = StreamBuilder::<PublicTrades>::new()
+            .channel_capacity(0)
+            .subscribe([(Coinbase, "btc", "usd", InstrumentKind::Spot, PublicTrades)]);
+
+        assert!(builder.channels.is_empty());
+        assert!(matches!(
+            builder.init().await,
+            Err(DataError::Socket(error)) if matches!(*error, SocketError::Subscribe(_))
+        ));
+    }
 }
@@ -1,17 +1,125 @@
-use super::{ExchangeChannel, StreamBuilder, Streams};
-use crate::{error::DataError, event::MarketEvent, exchange::ExchangeId, subscription::SubKind};
-use std::{collections::HashMap, fmt::Debug, future::Future, pin::Pin};
+use super::{
+    estimate::{ConnectionEstimate, SubscriptionEstimate},
+    ExchangeChannel, StreamBuilder, Streams,
+};
+use crate::{
+    error::DataError,
+    event::MarketEvent,
+    exchange::{ExchangeId, StreamSelector},
+    streams::{
+        health::HealthMonitor,
+        reconnect::{ExponentialBackoff, ReconnectPolicy},
+    },
+    subscriber::config::ConnectionConfig,
+    subscription::{SubKind, Subscription},
+    Identifier,
+};
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt::Debug,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+};
 
 /// Communicative type alias representing the [`Future`] result of a [`StreamBuilder::init`] call
 /// generated whilst executing [`MultiStreamBuilder::add`].
 pub type BuilderInitFuture = Pin<Box<dyn Future<Output = Result<(), DataError>>>>;
 
+/// Boxed [`Subscription<Exchange, Kind>`](Subscription) with the exchange & [`SubKind`] erased.
+type ErasedSubscription = Box<dyn Any + Send>;
+
+/// Function that adds a group of type erased [`AnySubscription`]s to a [`MultiStreamBuilder`].
+type AddGroupFn<Output> =
+    fn(MultiStreamBuilder<Output>, Vec<ErasedSubscription>) -> MultiStreamBuilder<Output>;
+
+/// Type erased [`Subscription<Exchange, Kind>`](Subscription) that can be combined with
+/// [`Subscription`]s of any other exchange & [`SubKind`] in a single
+/// [`MultiStreamBuilder::subscribe`] call, as long as each [`SubKind::Event`] maps into the
+/// common `Output`.
+pub struct AnySubscription<Output> {
+    pub exchange: ExchangeId,
+    group: TypeId,
+    subscription: ErasedSubscription,
+    add: AddGroupFn<Output>,
+}
+
+impl<Output> Debug for AnySubscription<Output> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AnySubscription<Output>")
+            .field("exchange", &self.exchange)
+            .finish()
+    }
+}
+
+impl<Output, Exchange, Kind> From<Subscription<Exchange, Kind>> for AnySubscription<Output>
+where
+    Output: From<MarketEvent<Kind::Event>> + Send + 'static,
+    Exchange: StreamSelector<Kind> + Ord + Send + Sync + 'static,
+    Kind: SubKind + Ord + Send + Sync + 'static,
+    Kind::Event: Send,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    fn from(subscription: Subscription<Exchange, Kind>) -> Self {
+        Self {
+            exchange: Exchange::ID,
+            group: TypeId::of::<Subscription<Exchange, Kind>>(),
+            subscription: Box::new(subscription),
+            add: add_group::<Output, Exchange, Kind>,
+        }
+    }
+}
+
+/// Add a group of type erased [`Subscription<Exchange, Kind>`](Subscription)s to the
+/// [`MultiStreamBuilder`] via a [`StreamBuilder<Kind>`](StreamBuilder) that actions them on a
+/// distinct WebSocket connection, using the [`ConnectionConfig`] & [`ReconnectPolicy`] of the
+/// [`MultiStreamBuilder`].
+fn add_group<Output, Exchange, Kind>(
+    builder: MultiStreamBuilder<Output>,
+    subscriptions: Vec<ErasedSubscription>,
+) -> MultiStreamBuilder<Output>
+where
+    Output: From<MarketEvent<Kind::Event>> + Send + 'static,
+    Exchange: StreamSelector<Kind> + Ord + Send + Sync + 'static,
+    Kind: SubKind + Ord + Send + Sync + 'static,
+    Kind::Event: Send,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    let subscriptions = subscriptions
+        .into_iter()
+        .map(|subscription| {
+            *subscription
+                .downcast::<Subscription<Exchange, Kind>>()
+                .expect("AnySubscriptions are grouped by Subscription<Exchange, Kind> TypeId")
+        })
+        .collect::<Vec<_>>();
+
+    let stream = StreamBuilder::<Kind> {
+        config: builder.config.clone(),
+        reconnect_policy: Arc::clone(&builder.reconnect_policy),
+        ..StreamBuilder::new()
+    };
+
+    builder.add(stream.subscribe(subscriptions))
+}
+
 /// Builder to configure and initialise a common [`Streams<Output>`](Streams) instance from
 /// multiple [`StreamBuilder<SubKind>`](StreamBuilder)s.
-#[derive(Default)]
 pub struct MultiStreamBuilder<Output> {
     pub channels: HashMap<ExchangeId, ExchangeChannel<Output>>,
     pub futures: Vec<BuilderInitFuture>,
+    pub estimates: Vec<ConnectionEstimate>,
+    pub health: HealthMonitor,
+    pub config: ConnectionConfig,
+    pub reconnect_policy: Arc<dyn ReconnectPolicy>,
+    pub max_subscriptions: Option<usize>,
+}
+
+impl<Output> Default for MultiStreamBuilder<Output> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<Output> Debug for MultiStreamBuilder<Output>
@@ -22,6 +130,11 @@ where
         f.debug_struct("MultiStreamBuilder<Output>")
             .field("channels", &self.channels)
             .field("num_futures", &self.futures.len())
+            .field("estimates", &self.estimates)
+            .field("health", &self.health)
+            .field("config", &self.config)
+            .field("reconnect_policy", &self.reconnect_policy)
+            .field("max_subscriptions", &self.max_subscriptions)
             .finish()
     }
 }
@@ -32,9 +145,48 @@ impl<Output> MultiStreamBuilder<Output> {
         Self {
             channels: HashMap::new(),
             futures: Vec::new(),
+            estimates: Vec::new(),
+            health: HealthMonitor::default(),
+            config: ConnectionConfig::default(),
+            reconnect_policy: Arc::new(ExponentialBackoff::default()),
+            max_subscriptions: None,
         }
     }
 
+    /// Set the maximum total number of unique exchange subscriptions across every
+    /// [`StreamBuilder`] & [`AnySubscription`] added to the [`MultiStreamBuilder`].
+    ///
+    /// [`init()`](MultiStreamBuilder::init()) returns a [`DataError::MaxSubscriptionsExceeded`]
+    /// before connecting to any exchange if the maximum is exceeded. Defaults to unlimited.
+    pub fn max_subscriptions(mut self, max: usize) -> Self {
+        self.max_subscriptions = Some(max);
+        self
+    }
+
+    /// Set the [`ConnectionConfig`] applied to every WebSocket connection dialed for the
+    /// [`AnySubscription`]s added via subsequent [`subscribe()`](MultiStreamBuilder::subscribe())
+    /// calls.
+    ///
+    /// [`StreamBuilder`]s added via [`add()`](MultiStreamBuilder::add()) keep their own
+    /// [`ConnectionConfig`].
+    pub fn connection_config(mut self, config: ConnectionConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Set the [`ReconnectPolicy`] used to re-initialise disconnected WebSocket connections for
+    /// the [`AnySubscription`]s added via subsequent
+    /// [`subscribe()`](MultiStreamBuilder::subscribe()) calls.
+    ///
+    /// Defaults to an [`ExponentialBackoff`].
+    pub fn reconnect_policy<Policy>(mut self, policy: Policy) -> Self
+    where
+        Policy: ReconnectPolicy + 'static,
+    {
+        self.reconnect_policy = Arc::new(policy);
+        self
+    }
+
     /// Add a [`StreamBuilder<SubKind>`](StreamBuilder) to the [`MultiStreamBuilder`]. Creates a
     /// [`Future`] that calls [`StreamBuilder::init`] and maps the [`SubKind::Event`](SubKind)
     /// into a common `Output`.
@@ -48,6 +200,9 @@ impl<Output> MultiStreamBuilder<Output> {
         Kind: SubKind + 'static,
         Kind::Event: Send,
     {
+        // Track the connections the StreamBuilder will open for the max_subscriptions guard
+        self.estimates.extend(builder.estimates.iter().cloned());
+
         // Allocate HashMap to hold the exchange_tx<Output> for each StreamBuilder exchange present
         let mut exchange_txs = HashMap::with_capacity(builder.channels.len());
 
@@ -60,11 +215,13 @@ impl<Output> MultiStreamBuilder<Output> {
             exchange_txs.insert(exchange, exchange_tx);
         }
 
-        // Init Streams<Kind::Event> & send mapped Outputs to the associated exchange_tx
+        // Init Streams<Kind::Event>, track the health of its connections, & send mapped Outputs
+        // to the associated exchange_tx
+        let health = self.health.clone();
         self.futures.push(Box::pin(async move {
-            builder
-                .init()
-                .await?
+            let streams = builder.init().await?;
+            health.extend(&streams.health);
+            streams
                 .streams
                 .into_iter()
                 .for_each(|(exchange, mut exchange_rx)| {
@@ -77,7 +234,9 @@ impl<Output> MultiStreamBuilder<Output> {
                     // Task to receive MarketEvent<SubKind::Event> and send Outputs via exchange_tx
                     tokio::spawn(async move {
                         while let Some(event) = exchange_rx.recv().await {
-                            let _ = exchange_tx.send(Output::from(event));
+                            if exchange_tx.send(Output::from(event)).await.is_err() {
+                                break;
+                            }
                         }
                     });
                 });
@@ -88,10 +247,55 @@ impl<Output> MultiStreamBuilder<Output> {
         self
     }
 
+    /// Add a heterogeneous collection of [`AnySubscription`]s, where each entry names its own
+    /// exchange, [`Instrument`](barter_integration::model::instrument::Instrument) & [`SubKind`].
+    ///
+    /// Entries are grouped by exchange & [`SubKind`], with each group actioned on a distinct
+    /// WebSocket connection by a [`StreamBuilder<SubKind>`](StreamBuilder) configured with the
+    /// [`ConnectionConfig`] & [`ReconnectPolicy`] of the [`MultiStreamBuilder`].
+    ///
+    /// Note that the [`AnySubscription`]s are not actioned until the
+    /// [`MultiStreamBuilder::init`] method is invoked.
+    pub fn subscribe<SubIter>(self, subscriptions: SubIter) -> Self
+    where
+        SubIter: IntoIterator<Item = AnySubscription<Output>>,
+    {
+        group(subscriptions)
+            .into_iter()
+            .fold(self, |builder, (add, group)| add(builder, group))
+    }
+
+    /// Dry-run estimate of the WebSocket connections that
+    /// [`init()`](MultiStreamBuilder::init()) will open for every [`StreamBuilder`] &
+    /// [`AnySubscription`] added so far, without connecting to any exchange.
+    pub fn estimate(&self) -> SubscriptionEstimate {
+        SubscriptionEstimate {
+            connections: self.estimates.clone(),
+        }
+    }
+
+    /// Validate the total number of unique exchange subscriptions added so far does not exceed
+    /// the configured [`max_subscriptions()`](MultiStreamBuilder::max_subscriptions()).
+    pub fn validate_max_subscriptions(&self) -> Result<(), DataError> {
+        let Some(max) = self.max_subscriptions else {
+            return Ok(());
+        };
+
+        match self.estimate().subscriptions() {
+            subscriptions if subscriptions > max => {
+                Err(DataError::MaxSubscriptionsExceeded { subscriptions, max })
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Initialise each [`StreamBuilder<SubKind>`](StreamBuilder) that was added to the
     /// [`MultiStreamBuilder`] and map all [`Streams<SubKind::Event>`](Streams) into a common
     /// [`Streams<Output>`](Streams).
     pub async fn init(self) -> Result<Streams<Output>, DataError> {
+        // Ensure the maximum number of subscriptions is not exceeded before connecting
+        self.validate_max_subscriptions()?;
+
         // Await Stream initialisation perpetual and ensure success
         futures::future::try_join_all(self.futures).await?;
 
@@ -102,6 +306,209 @@ impl<Output> MultiStreamBuilder<Output> {
                 .into_iter()
                 .map(|(exchange, channel)| (exchange, channel.rx))
                 .collect(),
+            clock_offsets: HashMap::new(),
+            latency: None,
+            health: self.health,
+            rate_limits: self.config.rate_limits,
         })
     }
 }
+
+/// Group the [`AnySubscription`]s by exchange & [`SubKind`], preserving the order in which each
+/// group first appears.
+fn group<Output, SubIter>(
+    subscriptions: SubIter,
+) -> Vec<(AddGroupFn<Output>, Vec<ErasedSubscription>)>
+where
+    SubIter: IntoIterator<Item = AnySubscription<Output>>,
+{
+    let mut groups: Vec<(TypeId, _, Vec<_>)> = Vec::new();
+
+    for subscription in subscriptions {
+        match groups
+            .iter_mut()
+            .find(|(group, _, _)| *group == subscription.group)
+        {
+            Some((_, _, group)) => group.push(subscription.subscription),
+            None => groups.push((
+                subscription.group,
+                subscription.add,
+                vec![subscription.subscription],
+            )),
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|(_, add, group)| (add, group))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        event::DataKind,
+        exchange::{binance::spot::BinanceSpot, kraken::Kraken, okx::Okx},
+        streams::reconnect::FixedDelay,
+        subscription::{book::OrderBooksL1, candle::Candles, trade::PublicTrades},
+        test_util::{MockExchange, MockScript},
+    };
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use std::time::Duration;
+
+    #[test]
+    fn test_multi_stream_builder_subscribe_groups_heterogeneous_subscriptions() {
+        let subscriptions: Vec<AnySubscription<MarketEvent<DataKind>>> = vec![
+            Subscription::from((
+                BinanceSpot::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                PublicTrades,
+            ))
+            .into(),
+            Subscription::from((Okx, "btc", "usdt", InstrumentKind::Spot, Candles)).into(),
+            Subscription::from((Kraken, "xbt", "usd", InstrumentKind::Spot, OrderBooksL1)).into(),
+            Subscription::from((
+                BinanceSpot::default(),
+                "eth",
+                "usdt",
+                InstrumentKind::Spot,
+                PublicTrades,
+            ))
+            .into(),
+        ];
+
+        let exchanges = subscriptions
+            .iter()
+            .map(|subscription| subscription.exchange)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            exchanges,
+            vec![
+                ExchangeId::BinanceSpot,
+                ExchangeId::Okx,
+                ExchangeId::Kraken,
+                ExchangeId::BinanceSpot
+            ]
+        );
+
+        // Both Binance trade Subscriptions share a group, each other entry has its own
+        let groups = group(subscriptions);
+        let sizes = groups
+            .iter()
+            .map(|(_, group)| group.len())
+            .collect::<Vec<_>>();
+        assert_eq!(sizes, vec![2, 1, 1]);
+
+        // Each group is actioned on its own connection, with one channel per exchange
+        let builder = groups
+            .into_iter()
+            .fold(MultiStreamBuilder::new(), |builder, (add, group)| {
+                add(builder, group)
+            });
+        assert_eq!(builder.futures.len(), 3);
+
+        let mut channels = builder.channels.keys().copied().collect::<Vec<_>>();
+        channels.sort();
+        let mut expected = vec![ExchangeId::BinanceSpot, ExchangeId::Kraken, ExchangeId::Okx];
+        expected.sort();
+        assert_eq!(channels, expected);
+    }
+
+    #[tokio::test]
+    async fn test_multi_stream_builder_subscribe_applies_config_and_reconnect_policy() {
+        const ACK: &str = r#"{"channelID":0,"channelName":"trade","event":"subscriptionStatus","pair":"XBT/USD","status":"subscribed","subscription":{"name":"trade"}}"#;
+        const TRADE: &str =
+            r#"[0,[["5541.20000","0.15850568","1534614057.321597","s","l",""]],"trade","XBT/USD"]"#;
+
+        let exchange = MockExchange::start([MockScript::new()
+            .receive()
+            .send_text(ACK)
+            .send_text(TRADE)
+            .close()])
+        .await
+        .unwrap();
+
+        // ConnectionConfig dials the MockExchange, & the ReconnectPolicy never re-connects
+        let mut streams = MultiStreamBuilder::<MarketEvent<DataKind>>::new()
+            .connection_config(ConnectionConfig::default().url(exchange.url()))
+            .reconnect_policy(FixedDelay {
+                delay: Duration::from_millis(1),
+                max_attempts: Some(0),
+            })
+            .subscribe([AnySubscription::from(Subscription::from((
+                Kraken,
+                "xbt",
+                "usd",
+                InstrumentKind::Spot,
+                PublicTrades,
+            )))])
+            .init()
+            .await
+            .unwrap();
+
+        let mut kraken = streams.select(ExchangeId::Kraken).unwrap();
+        let trade = tokio::time::timeout(Duration::from_secs(5), kraken.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(matches!(trade.kind, DataKind::Trade(trade) if trade.price == 5541.2));
+
+        // Stream ends once the connection closes, rather than re-connecting
+        let end = tokio::time::timeout(Duration::from_secs(5), kraken.recv())
+            .await
+            .unwrap();
+        assert!(end.is_none());
+        assert_eq!(exchange.connections(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_multi_stream_builder_max_subscriptions() {
+        fn trades(range: std::ops::Range<usize>) -> StreamBuilder<PublicTrades> {
+            StreamBuilder::<PublicTrades>::new().subscribe(range.map(|index| {
+                (
+                    Okx,
+                    format!("base{index}"),
+                    "usdt".to_string(),
+                    InstrumentKind::Spot,
+                    PublicTrades,
+                )
+            }))
+        }
+
+        // Default is unlimited
+        let builder = MultiStreamBuilder::<MarketEvent<DataKind>>::new()
+            .add(trades(0..6))
+            .add(trades(6..11));
+        assert!(builder.validate_max_subscriptions().is_ok());
+
+        // At the maximum across both StreamBuilders
+        let builder = MultiStreamBuilder::<MarketEvent<DataKind>>::new()
+            .max_subscriptions(11)
+            .add(trades(0..6))
+            .add(trades(6..11));
+        assert!(builder.validate_max_subscriptions().is_ok());
+
+        // Over the maximum across the StreamBuilders & AnySubscriptions fails before connecting
+        let builder = MultiStreamBuilder::<MarketEvent<DataKind>>::new()
+            .max_subscriptions(10)
+            .add(trades(0..6))
+            .subscribe((6..11).map(|index| {
+                AnySubscription::from(Subscription::from((
+                    Okx,
+                    format!("base{index}"),
+                    "usdt".to_string(),
+                    InstrumentKind::Spot,
+                    PublicTrades,
+                )))
+            }));
+        match builder.init().await {
+            Err(DataError::MaxSubscriptionsExceeded { subscriptions, max }) => {
+                assert_eq!((subscriptions, max), (11, 10));
+            }
+            other => panic!("expected MaxSubscriptionsExceeded, got: {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use crate::{
+    event::{DataKind, MarketEvent},
+    subscription::{
+        book::{OrderBook, OrderBookL1},
+        candle::Candle,
+        liquidation::Liquidation,
+        ticker::Ticker,
+        trade::PublicTrade,
+    },
+};
+use barter_integration::model::instrument::Instrument;
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+use tokio::sync::mpsc;
+
+/// Latest [`MarketEvent<T>`] of each kind for a single [`Instrument`].
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Snapshot {
+    pub trade: Option<MarketEvent<PublicTrade>>,
+    pub book_l1: Option<MarketEvent<OrderBookL1>>,
+    pub book: Option<MarketEvent<OrderBook>>,
+    pub candle: Option<MarketEvent<Candle>>,
+    pub liquidation: Option<MarketEvent<Liquidation>>,
+    pub ticker: Option<MarketEvent<Ticker>>,
+}
+
+/// [`MarketEvent<T>`] kinds that can be stored in a [`Snapshot`].
+pub trait Cacheable: Clone {
+    /// Replace the [`Snapshot`] entry associated with this [`MarketEvent<T>`] kind.
+    fn store(event: &MarketEvent<Self>, snapshot: &mut Snapshot);
+}
+
+macro_rules! impl_cacheable {
+    ($kind:ty, $field:ident) => {
+        impl Cacheable for $kind {
+            fn store(event: &MarketEvent<Self>, snapshot: &mut Snapshot) {
+                snapshot.$field = Some(event.clone());
+            }
+        }
+    };
+}
+
+impl_cacheable!(PublicTrade, trade);
+impl_cacheable!(OrderBookL1, book_l1);
+impl_cacheable!(OrderBook, book);
+impl_cacheable!(Candle, candle);
+impl_cacheable!(Liquidation, liquidation);
+impl_cacheable!(Ticker, ticker);
+
+impl Cacheable for DataKind {
+    fn store(event: &MarketEvent<Self>, snapshot: &mut Snapshot) {
+        fn with<T>(event: &MarketEvent<DataKind>, kind: T) -> MarketEvent<T> {
+            MarketEvent {
+                exchange_time: event.exchange_time,
+                received_time: event.received_time,
+                exchange_time_synthesized: event.exchange_time_synthesized,
+                exchange: event.exchange.clone(),
+                instrument: event.instrument.clone(),
+                kind,
+            }
+        }
+
+        match &event.kind {
+            DataKind::Trade(kind) => snapshot.trade = Some(with(event, kind.clone())),
+            DataKind::OrderBookL1(kind) => snapshot.book_l1 = Some(with(event, *kind)),
+            DataKind::OrderBook(kind) => snapshot.book = Some(with(event, kind.clone())),
+            DataKind::Candle(kind) => snapshot.candle = Some(with(event, *kind)),
+            DataKind::Liquidation(kind) => snapshot.liquidation = Some(with(event, *kind)),
+            DataKind::Ticker(kind) => snapshot.ticker = Some(with(event, *kind)),
+        }
+    }
+}
+
+/// Opt-in shared cache of the latest [`Snapshot`] of each [`Instrument`], updated by a
+/// [`Streams`](super::Streams) as events are consumed.
+///
+/// Cloning a [`SnapshotCache`] yields another handle to the same underlying cache, so it can be
+/// polled on demand from anywhere (eg/ `latest_trade(instrument)`) rather than every consumer
+/// tracking the latest values itself.
+#[derive(Clone, Debug, Default)]
+pub struct SnapshotCache {
+    snapshots: Arc<RwLock<HashMap<Instrument, Snapshot>>>,
+}
+
+impl SnapshotCache {
+    /// Update the cached [`Snapshot`] of the [`MarketEvent<T>`] [`Instrument`].
+    pub fn update<T>(&self, event: &MarketEvent<T>)
+    where
+        T: Cacheable,
+    {
+        let mut snapshots = self.write();
+        match snapshots.get_mut(&event.instrument) {
+            Some(snapshot) => T::store(event, snapshot),
+            None => {
+                let mut snapshot = Snapshot::default();
+                T::store(event, &mut snapshot);
+                snapshots.insert(event.instrument.clone(), snapshot);
+            }
+        }
+    }
+
+    /// Latest [`Snapshot`] of the [`Instrument`].
+    pub fn snapshot(&self, instrument: &Instrument) -> Option<Snapshot> {
+        self.read().get(instrument).cloned()
+    }
+
+    /// Latest [`MarketEvent<PublicTrade>`] of the [`Instrument`].
+    pub fn latest_trade(&self, instrument: &Instrument) -> Option<MarketEvent<PublicTrade>> {
+        self.read().get(instrument)?.trade.clone()
+    }
+
+    /// Latest [`MarketEvent<OrderBookL1>`] of the [`Instrument`].
+    pub fn latest_book_l1(&self, instrument: &Instrument) -> Option<MarketEvent<OrderBookL1>> {
+        self.read().get(instrument)?.book_l1.clone()
+    }
+
+    /// Latest [`MarketEvent<OrderBook>`] of the [`Instrument`].
+    pub fn latest_book(&self, instrument: &Instrument) -> Option<MarketEvent<OrderBook>> {
+        self.read().get(instrument)?.book.clone()
+    }
+
+    /// Latest [`MarketEvent<Candle>`] of the [`Instrument`].
+    pub fn latest_candle(&self, instrument: &Instrument) -> Option<MarketEvent<Candle>> {
+        self.read().get(instrument)?.candle.clone()
+    }
+
+    /// Latest [`MarketEvent<Liquidation>`] of the [`Instrument`].
+    pub fn latest_liquidation(&self, instrument: &Instrument) -> Option<MarketEvent<Liquidation>> {
+        self.read().get(instrument)?.liquidation.clone()
+    }
+
+    /// Latest [`MarketEvent<Ticker>`] of the [`Instrument`].
+    pub fn latest_ticker(&self, instrument: &Instrument) -> Option<MarketEvent<Ticker>> {
+        self.read().get(instrument)?.ticker.clone()
+    }
+
+    fn read(&self) -> RwLockReadGuard<'_, HashMap<Instrument, Snapshot>> {
+        // A writer panicking mid-update cannot leave a Snapshot partially written
+        self.snapshots
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, HashMap<Instrument, Snapshot>> {
+        self.snapshots
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Forward every [`MarketEvent<T>`] from the provided [`mpsc::Receiver`] to the returned
+/// [`mpsc::Receiver`], updating the [`SnapshotCache`] before each event is forwarded.
+pub fn cached<T>(
+    cache: SnapshotCache,
+    mut rx: mpsc::Receiver<MarketEvent<T>>,
+) -> mpsc::Receiver<MarketEvent<T>>
+where
+    T: Cacheable + Send + Sync + 'static,
+{
+    let (cached_tx, cached_rx) = mpsc::channel(rx.max_capacity());
+
+    tokio::spawn(async move {
+        while let Some(event) = rx.recv().await {
+            cache.update(&event);
+            if cached_tx.send(event).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    cached_rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::ExchangeId,
+        subscription::{
+            book::Level,
+            trade::{SideSource, TradeSource},
+        },
+    };
+    use barter_integration::model::{instrument::kind::InstrumentKind, Exchange, Side};
+    use chrono::{TimeZone, Utc};
+
+    fn event<T>(instrument: &Instrument, kind: T) -> MarketEvent<T> {
+        MarketEvent {
+            exchange_time: Utc.timestamp_millis_opt(1_700_000_000_000).unwrap(),
+            received_time: Utc.timestamp_millis_opt(1_700_000_000_050).unwrap(),
+            exchange_time_synthesized: false,
+            exchange: Exchange::from(ExchangeId::BinanceSpot),
+            instrument: instrument.clone(),
+            kind,
+        }
+    }
+
+    fn trade(id: &str, price: f64) -> PublicTrade {
+        PublicTrade {
+            id: id.to_string(),
+            price,
+            amount: 1.0,
+            side: Side::Buy,
+            source: TradeSource::Live,
+            side_source: SideSource::Exchange,
+            order_ids: Default::default(),
+            aggregate_count: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_cache_reflects_most_recent_event() {
+        let btc = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let eth = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+
+        let (tx, rx) = mpsc::channel(16);
+        let cache = SnapshotCache::default();
+        let mut rx = cached(cache.clone(), rx);
+
+        for (instrument, trade) in [
+            (&btc, trade("1", 100.0)),
+            (&eth, trade("2", 10.0)),
+            (&btc, trade("3", 101.0)),
+            (&btc, trade("4", 102.0)),
+        ] {
+            tx.send(event(instrument, trade)).await.unwrap();
+            rx.recv().await.unwrap();
+        }
+
+        assert_eq!(cache.latest_trade(&btc).unwrap().kind, trade("4", 102.0));
+        assert_eq!(cache.latest_trade(&eth).unwrap().kind, trade("2", 10.0));
+        assert!(cache.latest_book_l1(&btc).is_none());
+
+        // Other kinds are cached independently of the latest trade
+        let book_l1 = OrderBookL1 {
+            last_update_time: Utc::now(),
+            sequence: None,
+            best_bid: Level::new(101.5, 1.0),
+            best_ask: Level::new(102.5, 1.0),
+        };
+        cache.update(&event(&btc, DataKind::OrderBookL1(book_l1)));
+        assert_eq!(cache.latest_book_l1(&btc).unwrap().kind, book_l1);
+        assert_eq!(cache.latest_trade(&btc).unwrap().kind, trade("4", 102.0));
+    }
+}
@@ -0,0 +1,229 @@
+use crate::{error::DataError, event::MarketEvent, exchange::Connector};
+use barter_integration::error::SocketError;
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+
+/// Default number of server-time requests used by [`estimate_clock_offset`]. The sample with the
+/// smallest round-trip is used since it bounds the estimate error most tightly.
+pub const DEFAULT_CLOCK_OFFSET_SAMPLES: usize = 5;
+
+/// Implemented by an exchange [`Connector`] that exposes a REST server-time endpoint usable for
+/// estimating a [`ClockOffset`].
+pub trait ServerTime
+where
+    Self: Connector,
+{
+    /// Url of the exchange REST server-time endpoint.
+    const SERVER_TIME_URL: &'static str;
+
+    /// Deserialisable server-time endpoint response.
+    type Response: DeserializeOwned;
+
+    /// Extract the exchange server time from the [`Self::Response`].
+    #[allow(clippy::result_large_err)]
+    fn server_time(response: Self::Response) -> Result<DateTime<Utc>, SocketError>;
+}
+
+/// Estimated offset of an exchange server clock relative to the local clock.
+///
+/// A positive `offset` means the exchange clock is ahead of the local clock.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct ClockOffset {
+    pub offset: chrono::Duration,
+    pub round_trip: chrono::Duration,
+}
+
+impl ClockOffset {
+    /// Compute a [`ClockOffset`] from a single server-time request sample, assuming the server
+    /// time was sampled half way through the round-trip.
+    pub fn from_sample(
+        sent: DateTime<Utc>,
+        received: DateTime<Utc>,
+        server_time: DateTime<Utc>,
+    ) -> Self {
+        let round_trip = received - sent;
+        let local_midpoint = sent + round_trip / 2;
+
+        Self {
+            offset: server_time - local_midpoint,
+            round_trip,
+        }
+    }
+
+    /// Convert an exchange timestamp into the equivalent local clock timestamp.
+    pub fn to_local(&self, exchange_time: DateTime<Utc>) -> DateTime<Utc> {
+        exchange_time - self.offset
+    }
+
+    /// Normalise the [`MarketEvent`] `exchange_time` into the equivalent local clock timestamp.
+    pub fn normalise<T>(&self, event: &mut MarketEvent<T>) {
+        event.exchange_time = self.to_local(event.exchange_time);
+    }
+}
+
+/// Estimate the [`ClockOffset`] of the exchange server clock relative to the local clock using
+/// the [`DEFAULT_CLOCK_OFFSET_SAMPLES`].
+pub async fn estimate_clock_offset<Exchange>() -> Result<ClockOffset, DataError>
+where
+    Exchange: ServerTime,
+{
+    estimate_clock_offset_from::<Exchange>(Exchange::SERVER_TIME_URL, DEFAULT_CLOCK_OFFSET_SAMPLES)
+        .await
+}
+
+/// Estimate the [`ClockOffset`] using the provided server-time endpoint `url` and number of
+/// `samples`, returning the sample with the smallest round-trip.
+pub async fn estimate_clock_offset_from<Exchange>(
+    url: &str,
+    samples: usize,
+) -> Result<ClockOffset, DataError>
+where
+    Exchange: ServerTime,
+{
+    let mut best: Option<ClockOffset> = None;
+
+    for _ in 0..samples.max(1) {
+        let sent = Utc::now();
+        let response = reqwest::get(url)
+            .await
+            .map_err(SocketError::Http)?
+            .json::<Exchange::Response>()
+            .await
+            .map_err(SocketError::Http)?;
+        let received = Utc::now();
+
+        let sample = ClockOffset::from_sample(sent, received, Exchange::server_time(response)?);
+        match best {
+            Some(current) if current.round_trip <= sample.round_trip => {}
+            _ => best = Some(sample),
+        }
+    }
+
+    Ok(best.expect("at least one clock offset sample is taken"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::{binance::spot::BinanceSpot, okx::Okx};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    #[test]
+    fn test_clock_offset_from_sample() {
+        struct TestCase {
+            sent_ms: i64,
+            received_ms: i64,
+            server_ms: i64,
+            expected: ClockOffset,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: exchange clock ahead w/ 100ms round-trip
+                sent_ms: 1_000,
+                received_ms: 1_100,
+                server_ms: 1_550,
+                expected: ClockOffset {
+                    offset: chrono::Duration::milliseconds(500),
+                    round_trip: chrono::Duration::milliseconds(100),
+                },
+            },
+            TestCase {
+                // TC1: exchange clock behind w/ 40ms round-trip
+                sent_ms: 1_000,
+                received_ms: 1_040,
+                server_ms: 770,
+                expected: ClockOffset {
+                    offset: chrono::Duration::milliseconds(-250),
+                    round_trip: chrono::Duration::milliseconds(40),
+                },
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = ClockOffset::from_sample(
+                DateTime::<Utc>::from_timestamp_millis(test.sent_ms).unwrap(),
+                DateTime::<Utc>::from_timestamp_millis(test.received_ms).unwrap(),
+                DateTime::<Utc>::from_timestamp_millis(test.server_ms).unwrap(),
+            );
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_clock_offset_to_local() {
+        let offset = ClockOffset {
+            offset: chrono::Duration::milliseconds(500),
+            round_trip: chrono::Duration::milliseconds(100),
+        };
+
+        assert_eq!(
+            offset.to_local(DateTime::<Utc>::from_timestamp_millis(2_500).unwrap()),
+            DateTime::<Utc>::from_timestamp_millis(2_000).unwrap()
+        );
+    }
+
+    /// Spawn a mock server-time endpoint that responds to every request with the body produced by
+    /// the provided closure.
+    async fn mock_server_time_endpoint(body: fn() -> String) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/time", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut request = [0u8; 1024];
+                let _ = stream.read(&mut request).await.unwrap();
+
+                let body = body();
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        url
+    }
+
+    #[tokio::test]
+    async fn test_estimate_clock_offset_from_mock_endpoint() {
+        // Mock Binance endpoint w/ clock 5s ahead of the local clock
+        let binance = mock_server_time_endpoint(|| {
+            let server_time = Utc::now() + chrono::Duration::seconds(5);
+            format!(r#"{{"serverTime":{}}}"#, server_time.timestamp_millis())
+        })
+        .await;
+
+        // Mock Okx endpoint w/ clock 2s behind the local clock
+        let okx = mock_server_time_endpoint(|| {
+            let server_time = Utc::now() - chrono::Duration::seconds(2);
+            format!(
+                r#"{{"code":"0","msg":"","data":[{{"ts":"{}"}}]}}"#,
+                server_time.timestamp_millis()
+            )
+        })
+        .await;
+
+        let binance = estimate_clock_offset_from::<BinanceSpot>(&binance, 3)
+            .await
+            .unwrap();
+        let okx = estimate_clock_offset_from::<Okx>(&okx, 3).await.unwrap();
+
+        // Offset error is bounded by half the round-trip & millisecond precision
+        for (actual, expected) in [
+            (binance, chrono::Duration::seconds(5)),
+            (okx, chrono::Duration::seconds(-2)),
+        ] {
+            let tolerance = actual.round_trip / 2 + chrono::Duration::milliseconds(1);
+            let error = (actual.offset - expected).abs();
+            assert!(
+                error <= tolerance,
+                "offset: {:?}, expected: {expected:?}, tolerance: {tolerance:?}",
+                actual.offset
+            );
+        }
+    }
+}
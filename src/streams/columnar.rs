@@ -0,0 +1,491 @@
+use crate::{
+    event::MarketEvent,
+    subscription::{candle::Candle, trade::PublicTrade},
+};
+use arrow::{
+    array::{
+        ArrayBuilder, ArrayRef, BooleanBuilder, Float64Builder, RecordBatch, StringBuilder,
+        TimestampMillisecondBuilder, UInt64Builder,
+    },
+    datatypes::{DataType, Field, Schema, SchemaRef, TimeUnit},
+    error::ArrowError,
+};
+use barter_integration::model::Side;
+use std::{sync::Arc, time::Duration};
+use tokio::sync::mpsc;
+use tracing::error;
+
+/// Default maximum number of rows in a [`ColumnarBatch`] before it is flushed.
+pub const DEFAULT_BATCH_SIZE: usize = 1024;
+
+/// Default maximum duration a non-empty [`ColumnarBatch`] is buffered before it is flushed.
+pub const DEFAULT_BATCH_FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Timezone of every [`ColumnarBatch`] timestamp column.
+const TIMEZONE: &str = "UTC";
+
+/// Arrow [`DataType`] of every [`ColumnarBatch`] timestamp column.
+fn timestamp() -> DataType {
+    DataType::Timestamp(TimeUnit::Millisecond, Some(TIMEZONE.into()))
+}
+
+/// Fixed schema, column-oriented batch of [`MarketEvent<T>`]s that is finished into an Arrow
+/// [`RecordBatch`] (eg/ for writing Parquet).
+pub trait ColumnarBatch
+where
+    Self: Default + Send + 'static,
+{
+    /// [`MarketEvent<T>`] `T` stored in each row.
+    type Kind: Send + 'static;
+
+    /// Arrow [`Schema`] of the [`RecordBatch`]es produced by [`Self::finish`].
+    fn schema() -> SchemaRef;
+
+    /// Append a [`MarketEvent<T>`] as a new row.
+    fn push(&mut self, event: MarketEvent<Self::Kind>);
+
+    /// Number of rows in the batch.
+    fn len(&self) -> usize;
+
+    /// Determine if the batch contains no rows.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Finish every row appended so far into a [`RecordBatch`], leaving the batch empty.
+    fn finish(&mut self) -> Result<RecordBatch, ArrowError>;
+}
+
+/// Arrow column builders shared by every [`ColumnarBatch`], holding the [`MarketEvent<T>`]
+/// metadata of each row.
+#[derive(Debug, Default)]
+struct EventColumns {
+    exchange_time: TimestampMillisecondBuilder,
+    received_time: TimestampMillisecondBuilder,
+    exchange: StringBuilder,
+    base: StringBuilder,
+    quote: StringBuilder,
+    instrument_kind: StringBuilder,
+}
+
+impl EventColumns {
+    fn fields() -> [Field; 6] {
+        [
+            Field::new("exchange_time", timestamp(), false),
+            Field::new("received_time", timestamp(), false),
+            Field::new("exchange", DataType::Utf8, false),
+            Field::new("base", DataType::Utf8, false),
+            Field::new("quote", DataType::Utf8, false),
+            Field::new("instrument_kind", DataType::Utf8, false),
+        ]
+    }
+
+    fn push<T>(&mut self, event: &MarketEvent<T>) {
+        self.exchange_time
+            .append_value(event.exchange_time.timestamp_millis());
+        self.received_time
+            .append_value(event.received_time.timestamp_millis());
+        self.exchange.append_value(event.exchange.to_string());
+        self.base.append_value(&event.instrument.base);
+        self.quote.append_value(&event.instrument.quote);
+        self.instrument_kind
+            .append_value(event.instrument.kind.to_string());
+    }
+
+    fn finish(&mut self) -> [ArrayRef; 6] {
+        [
+            Arc::new(self.exchange_time.finish().with_timezone(TIMEZONE)),
+            Arc::new(self.received_time.finish().with_timezone(TIMEZONE)),
+            Arc::new(self.exchange.finish()),
+            Arc::new(self.base.finish()),
+            Arc::new(self.quote.finish()),
+            Arc::new(self.instrument_kind.finish()),
+        ]
+    }
+}
+
+/// [`ColumnarBatch`] of [`MarketEvent<PublicTrade>`]s.
+#[derive(Debug, Default)]
+pub struct TradeBatch {
+    event: EventColumns,
+    id: StringBuilder,
+    price: Float64Builder,
+    amount: Float64Builder,
+    side: StringBuilder,
+}
+
+impl ColumnarBatch for TradeBatch {
+    type Kind = PublicTrade;
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(
+            EventColumns::fields()
+                .into_iter()
+                .chain([
+                    Field::new("id", DataType::Utf8, false),
+                    Field::new("price", DataType::Float64, false),
+                    Field::new("amount", DataType::Float64, false),
+                    Field::new("side", DataType::Utf8, false),
+                ])
+                .collect::<Vec<_>>(),
+        ))
+    }
+
+    fn push(&mut self, event: MarketEvent<PublicTrade>) {
+        self.event.push(&event);
+        self.id.append_value(&event.kind.id);
+        self.price.append_value(event.kind.price);
+        self.amount.append_value(event.kind.amount);
+        self.side.append_value(match event.kind.side {
+            Side::Buy => "buy",
+            Side::Sell => "sell",
+        });
+    }
+
+    fn len(&self) -> usize {
+        self.id.len()
+    }
+
+    fn finish(&mut self) -> Result<RecordBatch, ArrowError> {
+        let columns = self
+            .event
+            .finish()
+            .into_iter()
+            .chain([
+                Arc::new(self.id.finish()) as ArrayRef,
+                Arc::new(self.price.finish()),
+                Arc::new(self.amount.finish()),
+                Arc::new(self.side.finish()),
+            ])
+            .collect();
+
+        RecordBatch::try_new(Self::schema(), columns)
+    }
+}
+
+/// [`ColumnarBatch`] of [`MarketEvent<Candle>`]s.
+#[derive(Debug, Default)]
+pub struct CandleBatch {
+    event: EventColumns,
+    close_time: TimestampMillisecondBuilder,
+    open: Float64Builder,
+    high: Float64Builder,
+    low: Float64Builder,
+    close: Float64Builder,
+    volume: Float64Builder,
+    trade_count: UInt64Builder,
+    is_closed: BooleanBuilder,
+}
+
+impl ColumnarBatch for CandleBatch {
+    type Kind = Candle;
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(
+            EventColumns::fields()
+                .into_iter()
+                .chain([
+                    Field::new("close_time", timestamp(), false),
+                    Field::new("open", DataType::Float64, false),
+                    Field::new("high", DataType::Float64, false),
+                    Field::new("low", DataType::Float64, false),
+                    Field::new("close", DataType::Float64, false),
+                    Field::new("volume", DataType::Float64, false),
+                    Field::new("trade_count", DataType::UInt64, true),
+                    Field::new("is_closed", DataType::Boolean, false),
+                ])
+                .collect::<Vec<_>>(),
+        ))
+    }
+
+    fn push(&mut self, event: MarketEvent<Candle>) {
+        self.event.push(&event);
+        self.close_time
+            .append_value(event.kind.close_time.timestamp_millis());
+        self.open.append_value(event.kind.open);
+        self.high.append_value(event.kind.high);
+        self.low.append_value(event.kind.low);
+        self.close.append_value(event.kind.close);
+        self.volume.append_value(event.kind.volume);
+        self.trade_count.append_option(event.kind.trade_count);
+        self.is_closed.append_value(event.kind.is_closed);
+    }
+
+    fn len(&self) -> usize {
+        self.close_time.len()
+    }
+
+    fn finish(&mut self) -> Result<RecordBatch, ArrowError> {
+        let columns = self
+            .event
+            .finish()
+            .into_iter()
+            .chain([
+                Arc::new(self.close_time.finish().with_timezone(TIMEZONE)) as ArrayRef,
+                Arc::new(self.open.finish()),
+                Arc::new(self.high.finish()),
+                Arc::new(self.low.finish()),
+                Arc::new(self.close.finish()),
+                Arc::new(self.volume.finish()),
+                Arc::new(self.trade_count.finish()),
+                Arc::new(self.is_closed.finish()),
+            ])
+            .collect();
+
+        RecordBatch::try_new(Self::schema(), columns)
+    }
+}
+
+/// Configuration of when a [`ColumnarBatch`] is flushed by [`batch`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct BatchConfig {
+    /// Maximum number of rows in a batch.
+    pub batch_size: usize,
+    /// Maximum duration a non-empty batch is buffered.
+    pub flush_interval: Duration,
+}
+
+impl Default for BatchConfig {
+    fn default() -> Self {
+        Self {
+            batch_size: DEFAULT_BATCH_SIZE,
+            flush_interval: DEFAULT_BATCH_FLUSH_INTERVAL,
+        }
+    }
+}
+
+/// Spawn a task that accumulates every [`MarketEvent<T>`] from the provided [`mpsc::Receiver`]
+/// into a [`ColumnarBatch`], flushing it as a [`RecordBatch`] once it contains `batch_size` rows
+/// or every `flush_interval`, whichever comes first. Any remaining rows are flushed once the
+/// source stream terminates.
+pub fn batch<Batch>(
+    mut event_rx: mpsc::Receiver<MarketEvent<Batch::Kind>>,
+    config: BatchConfig,
+) -> mpsc::Receiver<RecordBatch>
+where
+    Batch: ColumnarBatch,
+{
+    let (batch_tx, batch_rx) = mpsc::channel(event_rx.max_capacity());
+
+    tokio::spawn(async move {
+        let batch_size = config.batch_size.max(1);
+        let mut current = Batch::default();
+        let mut interval = tokio::time::interval(config.flush_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        // First tick completes immediately, so skip it to allow events to be received
+        interval.tick().await;
+
+        loop {
+            tokio::select! {
+                event = event_rx.recv() => {
+                    let Some(event) = event else {
+                        break;
+                    };
+
+                    current.push(event);
+                    if current.len() >= batch_size && !flush(&mut current, &batch_tx).await {
+                        return;
+                    }
+                }
+                _ = interval.tick() => {
+                    if !current.is_empty() && !flush(&mut current, &batch_tx).await {
+                        return;
+                    }
+                }
+            }
+        }
+
+        if !current.is_empty() {
+            flush(&mut current, &batch_tx).await;
+        }
+    });
+
+    batch_rx
+}
+
+/// Finish the [`ColumnarBatch`] and send the [`RecordBatch`], returning false if the receiver
+/// has been dropped.
+async fn flush<Batch>(current: &mut Batch, batch_tx: &mpsc::Sender<RecordBatch>) -> bool
+where
+    Batch: ColumnarBatch,
+{
+    match current.finish() {
+        Ok(batch) => batch_tx.send(batch).await.is_ok(),
+        Err(error) => {
+            error!(%error, "failed to finish ColumnarBatch into a RecordBatch, dropping rows");
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::ExchangeId,
+        subscription::trade::{SideSource, TradeSource},
+    };
+    use arrow::{
+        array::{Array, AsArray},
+        datatypes::{Float64Type, TimestampMillisecondType, UInt64Type},
+    };
+    use barter_integration::model::{
+        instrument::{kind::InstrumentKind, Instrument},
+        Exchange,
+    };
+    use chrono::{TimeZone, Utc};
+
+    fn event<T>(id: u64, kind: T) -> MarketEvent<T> {
+        MarketEvent {
+            exchange_time: Utc.timestamp_millis_opt(1_000 + id as i64).unwrap(),
+            received_time: Utc.timestamp_millis_opt(2_000 + id as i64).unwrap(),
+            exchange_time_synthesized: false,
+            exchange: Exchange::from(ExchangeId::BinanceSpot),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            kind,
+        }
+    }
+
+    fn trade(id: u64, price: f64, side: Side) -> MarketEvent<PublicTrade> {
+        event(
+            id,
+            PublicTrade {
+                id: id.to_string(),
+                price,
+                amount: 0.5,
+                side,
+                source: TradeSource::Live,
+                side_source: SideSource::Exchange,
+                order_ids: Default::default(),
+                aggregate_count: None,
+            },
+        )
+    }
+
+    fn strings(batch: &RecordBatch, column: &str) -> Vec<String> {
+        batch
+            .column_by_name(column)
+            .unwrap()
+            .as_string::<i32>()
+            .iter()
+            .map(|value| value.unwrap().to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_trade_batch_schema_and_rows() {
+        let schema = TradeBatch::schema();
+        let names = schema
+            .fields()
+            .iter()
+            .map(|field| field.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![
+                "exchange_time",
+                "received_time",
+                "exchange",
+                "base",
+                "quote",
+                "instrument_kind",
+                "id",
+                "price",
+                "amount",
+                "side"
+            ]
+        );
+        assert_eq!(schema.field(0).data_type(), &timestamp());
+        assert_eq!(schema.field(7).data_type(), &DataType::Float64);
+
+        let mut batch = TradeBatch::default();
+        batch.push(trade(1, 100.0, Side::Buy));
+        batch.push(trade(2, 101.0, Side::Sell));
+        assert_eq!(batch.len(), 2);
+
+        let actual = batch.finish().unwrap();
+        assert!(batch.is_empty());
+        assert_eq!(actual.schema(), schema);
+        assert_eq!(actual.num_rows(), 2);
+        assert_eq!(
+            actual
+                .column_by_name("exchange_time")
+                .unwrap()
+                .as_primitive::<TimestampMillisecondType>()
+                .values(),
+            &[1_001, 1_002]
+        );
+        assert_eq!(strings(&actual, "exchange"), vec!["binance_spot"; 2]);
+        assert_eq!(strings(&actual, "instrument_kind"), vec!["spot"; 2]);
+        assert_eq!(strings(&actual, "id"), vec!["1", "2"]);
+        assert_eq!(
+            actual
+                .column_by_name("price")
+                .unwrap()
+                .as_primitive::<Float64Type>()
+                .values(),
+            &[100.0, 101.0]
+        );
+        assert_eq!(strings(&actual, "side"), vec!["buy", "sell"]);
+    }
+
+    #[test]
+    fn test_candle_batch_trade_count_is_nullable() {
+        let candle = |trade_count| Candle {
+            close_time: Utc.timestamp_millis_opt(59_999).unwrap(),
+            open: 100.0,
+            high: 110.0,
+            low: 95.0,
+            close: 105.0,
+            volume: 10.0,
+            trade_count,
+            is_closed: true,
+        };
+
+        let mut batch = CandleBatch::default();
+        batch.push(event(1, candle(Some(42))));
+        batch.push(event(2, candle(None)));
+
+        let actual = batch.finish().unwrap();
+        let trade_count = actual
+            .column_by_name("trade_count")
+            .unwrap()
+            .as_primitive::<UInt64Type>();
+        assert_eq!(actual.num_rows(), 2);
+        assert_eq!(trade_count.value(0), 42);
+        assert!(trade_count.is_null(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_batch_flushes_on_size_and_interval() {
+        let (event_tx, event_rx) = mpsc::channel(10);
+        let mut batch_rx = batch::<TradeBatch>(
+            event_rx,
+            BatchConfig {
+                batch_size: 2,
+                flush_interval: Duration::from_secs(5),
+            },
+        );
+
+        // Full batch is flushed immediately
+        for id in 0..3 {
+            event_tx.send(trade(id, 100.0, Side::Buy)).await.unwrap();
+        }
+        assert_eq!(
+            strings(&batch_rx.recv().await.unwrap(), "id"),
+            vec!["0", "1"]
+        );
+
+        // Partial batch is flushed after the flush_interval
+        let start = tokio::time::Instant::now();
+        assert_eq!(strings(&batch_rx.recv().await.unwrap(), "id"), vec!["2"]);
+        assert!(start.elapsed() >= Duration::from_secs(4));
+
+        // Remaining rows are flushed once the source terminates
+        event_tx.send(trade(3, 100.0, Side::Buy)).await.unwrap();
+        drop(event_tx);
+        assert_eq!(strings(&batch_rx.recv().await.unwrap(), "id"), vec!["3"]);
+        assert!(batch_rx.recv().await.is_none());
+    }
+}
@@ -0,0 +1,329 @@
+use super::warmup::{MarketWarmup, Warmup};
+use crate::{
+    event::MarketEvent,
+    subscription::{candle::Candle, trade::PublicTrade},
+};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Duration, Utc};
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Determines where the boundaries of locally built [`Candle`]s fall.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum CandleAlignment {
+    /// Boundaries are aligned to the wall-clock (eg/ minute candles close at :00 seconds),
+    /// matching exchange-native candles. The first [`Candle`] is partial, covering only the
+    /// time from the first trade up to the next boundary.
+    #[default]
+    WallClock,
+    /// Boundaries are offset from the `exchange_time` of the first trade, so every [`Candle`]
+    /// spans a full interval.
+    FirstTrade,
+}
+
+/// [`Candle`] builder aggregating a single market's [`PublicTrade`]s into fixed interval
+/// [`Candle`]s.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CandleAggregator {
+    pub interval: Duration,
+    pub alignment: CandleAlignment,
+    origin: Option<DateTime<Utc>>,
+    current: Option<Candle>,
+}
+
+impl CandleAggregator {
+    /// Construct a new [`CandleAggregator`] building [`Candle`]s of the provided interval.
+    ///
+    /// Panics if the interval is not positive.
+    pub fn new(interval: Duration, alignment: CandleAlignment) -> Self {
+        assert!(
+            interval > Duration::zero(),
+            "CandleAggregator interval must be positive"
+        );
+
+        Self {
+            interval,
+            alignment,
+            origin: None,
+            current: None,
+        }
+    }
+
+    /// Add a [`PublicTrade`] executed at the provided `time`, returning the previously forming
+    /// [`Candle`] if this trade falls beyond its `close_time`.
+    ///
+    /// ### Notes
+    /// A [`Candle`] is only closed once a trade arrives for a later interval, so intervals
+    /// without any trades are skipped rather than yielding empty [`Candle`]s.
+    pub fn update(&mut self, time: DateTime<Utc>, trade: &PublicTrade) -> Option<Candle> {
+        if let Some(current) = self
+            .current
+            .as_mut()
+            .filter(|current| time < current.close_time)
+        {
+            current.high = current.high.max(trade.price);
+            current.low = current.low.min(trade.price);
+            current.close = trade.price;
+            current.volume += trade.amount;
+            current.trade_count = current.trade_count.map(|count| count + 1);
+            return None;
+        }
+
+        let closed = self.current.take().map(|mut candle| {
+            candle.is_closed = true;
+            candle
+        });
+
+        self.current = Some(Candle {
+            close_time: self.close_time(time),
+            open: trade.price,
+            high: trade.price,
+            low: trade.price,
+            close: trade.price,
+            volume: trade.amount,
+            trade_count: Some(1),
+            is_closed: false,
+        });
+
+        closed
+    }
+
+    /// Currently forming [`Candle`], if any trades have been added.
+    pub fn current(&self) -> Option<&Candle> {
+        self.current.as_ref()
+    }
+
+    /// Determine the `close_time` of the interval containing the provided `time`.
+    fn close_time(&mut self, time: DateTime<Utc>) -> DateTime<Utc> {
+        let origin = *self.origin.get_or_insert(match self.alignment {
+            CandleAlignment::WallClock => DateTime::<Utc>::UNIX_EPOCH,
+            CandleAlignment::FirstTrade => time,
+        });
+
+        let interval = self.interval.num_milliseconds().max(1);
+        let elapsed = (time - origin).num_milliseconds();
+        let intervals = elapsed.div_euclid(interval) + 1;
+
+        origin + Duration::milliseconds(intervals * interval)
+    }
+}
+
+/// [`Stream`] adapter that aggregates every [`MarketEvent<PublicTrade>`] into closed
+/// [`MarketEvent<Candle>`]s.
+///
+/// A separate [`CandleAggregator`] is maintained for each [`Exchange`] & [`Instrument`]
+/// combination. Each [`MarketEvent<Candle>`] has an `exchange_time` of the [`Candle`]
+/// `close_time`, and the `received_time` of the trade that closed it. Any still forming
+/// [`Candle`]s are discarded when the input [`Stream`] ends.
+///
+/// An optional [`Warmup`] suppresses the [`Candle`]s of each market closed during its warmup
+/// period (eg/ the partial first [`Candle`] of [`CandleAlignment::WallClock`]).
+#[derive(Debug)]
+pub struct CandleStream<St> {
+    pub interval: Duration,
+    pub alignment: CandleAlignment,
+    stream: St,
+    aggregators: HashMap<(Exchange, Instrument), CandleAggregator>,
+    warmup: MarketWarmup,
+}
+
+impl<St> CandleStream<St>
+where
+    St: Stream<Item = MarketEvent<PublicTrade>> + Unpin,
+{
+    /// Construct a new [`CandleStream`] building [`Candle`]s of the provided interval from the
+    /// provided [`MarketEvent<PublicTrade>`] [`Stream`].
+    pub fn new(stream: St, interval: Duration, alignment: CandleAlignment) -> Self {
+        Self {
+            interval,
+            alignment,
+            stream,
+            aggregators: HashMap::new(),
+            warmup: MarketWarmup::default(),
+        }
+    }
+
+    /// Suppress the [`Candle`]s of each market closed during the provided [`Warmup`] period,
+    /// observed with every input trade.
+    pub fn warmup(self, warmup: Warmup) -> Self {
+        Self {
+            warmup: MarketWarmup::new(Some(warmup)),
+            ..self
+        }
+    }
+
+    fn update(&mut self, event: MarketEvent<PublicTrade>) -> Option<MarketEvent<Candle>> {
+        let warm = self.warmup.observe(&event);
+        let (interval, alignment) = (self.interval, self.alignment);
+        let candle = self
+            .aggregators
+            .entry((event.exchange.clone(), event.instrument.clone()))
+            .or_insert_with(|| CandleAggregator::new(interval, alignment))
+            .update(event.exchange_time, &event.kind)
+            .filter(|_| warm)?;
+
+        Some(MarketEvent {
+            exchange_time: candle.close_time,
+            received_time: event.received_time,
+            exchange_time_synthesized: event.exchange_time_synthesized,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: candle,
+        })
+    }
+}
+
+impl<St> Stream for CandleStream<St>
+where
+    St: Stream<Item = MarketEvent<PublicTrade>> + Unpin,
+{
+    type Item = MarketEvent<Candle>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match Pin::new(&mut self.stream).poll_next(cx) {
+                Poll::Ready(Some(event)) => {
+                    if let Some(candle) = self.update(event) {
+                        return Poll::Ready(Some(candle));
+                    }
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::ExchangeId;
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+    use chrono::TimeZone;
+    use futures::StreamExt;
+
+    fn trade(
+        instrument: &Instrument,
+        time: DateTime<Utc>,
+        price: f64,
+        amount: f64,
+    ) -> MarketEvent<PublicTrade> {
+        MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange_time_synthesized: false,
+            exchange: Exchange::from(ExchangeId::BinanceSpot),
+            instrument: instrument.clone(),
+            kind: PublicTrade {
+                id: time.timestamp_millis().to_string(),
+                price,
+                amount,
+                side: Side::Buy,
+                source: Default::default(),
+                side_source: Default::default(),
+                order_ids: Default::default(),
+                aggregate_count: None,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_candle_stream_alignment() {
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let time = |minute, second| {
+            Utc.with_ymd_and_hms(2023, 1, 1, 12, minute, second)
+                .unwrap()
+        };
+
+        // First trade arrives mid-minute
+        let trades = || {
+            futures::stream::iter(vec![
+                trade(&instrument, time(0, 30), 100.0, 1.0),
+                trade(&instrument, time(0, 45), 110.0, 2.0),
+                trade(&instrument, time(1, 10), 90.0, 1.0),
+                trade(&instrument, time(1, 40), 95.0, 1.0),
+                trade(&instrument, time(2, 5), 105.0, 1.0),
+                trade(&instrument, time(2, 40), 100.0, 1.0),
+            ])
+        };
+
+        let candle = |close_time, open, high, low, close, volume, trade_count| Candle {
+            close_time,
+            open,
+            high,
+            low,
+            close,
+            volume,
+            trade_count: Some(trade_count),
+            is_closed: true,
+        };
+
+        struct TestCase {
+            alignment: CandleAlignment,
+            expected: Vec<Candle>,
+        }
+
+        let cases = vec![
+            // TC0: partial first candle closes at the next :00 boundary
+            TestCase {
+                alignment: CandleAlignment::WallClock,
+                expected: vec![
+                    candle(time(1, 0), 100.0, 110.0, 100.0, 110.0, 3.0, 2),
+                    candle(time(2, 0), 90.0, 95.0, 90.0, 95.0, 2.0, 2),
+                ],
+            },
+            // TC1: boundaries offset from the first trade
+            TestCase {
+                alignment: CandleAlignment::FirstTrade,
+                expected: vec![
+                    candle(time(1, 30), 100.0, 110.0, 90.0, 90.0, 4.0, 3),
+                    candle(time(2, 30), 95.0, 105.0, 95.0, 105.0, 2.0, 2),
+                ],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = CandleStream::new(trades(), Duration::minutes(1), test.alignment)
+                .map(|event| {
+                    assert_eq!(event.exchange_time, event.kind.close_time);
+                    event.kind
+                })
+                .collect::<Vec<_>>()
+                .await;
+
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_candle_stream_warmup_suppresses_partial_first_candle() {
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let time = |minute, second| {
+            Utc.with_ymd_and_hms(2023, 1, 1, 12, minute, second)
+                .unwrap()
+        };
+
+        let trades = futures::stream::iter(vec![
+            trade(&instrument, time(0, 30), 100.0, 1.0),
+            trade(&instrument, time(1, 10), 90.0, 1.0),
+            trade(&instrument, time(2, 5), 105.0, 1.0),
+            trade(&instrument, time(3, 0), 100.0, 1.0),
+        ]);
+
+        let actual = CandleStream::new(trades, Duration::minutes(1), CandleAlignment::WallClock)
+            .warmup(Warmup::Duration(Duration::minutes(1)))
+            .map(|event| event.kind.close_time)
+            .collect::<Vec<_>>()
+            .await;
+
+        // Partial 12:01 candle is closed 40s into the warmup, so only full candles are emitted
+        assert_eq!(actual, vec![time(2, 0), time(3, 0)]);
+    }
+}
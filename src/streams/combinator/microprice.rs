@@ -0,0 +1,229 @@
+use crate::{
+    event::MarketEvent,
+    subscription::book::{mid_price, volume_weighted_mid_price, OrderBook},
+};
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Volume weighted mid price (micro-price) derived from the top-of-book of an [`OrderBook`].
+///
+/// The micro-price weighs each best price by the amount resting on the opposite side, so it
+/// leans towards the side of the book that is more likely to be consumed next.
+///
+/// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Microprice {
+    /// Volume weighted mid price: (bid * ask_amount + ask * bid_amount) / (bid_amount + ask_amount).
+    pub price: f64,
+    /// Average of the best bid & ask prices.
+    pub mid_price: f64,
+    /// Share of the top-of-book amount resting on the bid, in the range [0, 1].
+    pub imbalance: f64,
+}
+
+impl Microprice {
+    /// Derive the [`Microprice`] from the best bid & ask [`Level`](crate::subscription::book::Level)s
+    /// of a sorted [`OrderBook`].
+    ///
+    /// Returns `None` if the [`OrderBook`] is one-sided (missing a bid or ask), or if there is no
+    /// amount at the top-of-book to weigh the prices with.
+    pub fn from_book(book: &OrderBook) -> Option<Self> {
+        let best_bid = *book.bids.levels().first()?;
+        let best_ask = *book.asks.levels().first()?;
+
+        let total_amount = best_bid.amount + best_ask.amount;
+        if total_amount <= 0.0 {
+            return None;
+        }
+
+        Some(Self {
+            price: volume_weighted_mid_price(best_bid, best_ask),
+            mid_price: mid_price(best_bid.price, best_ask.price),
+            imbalance: best_bid.amount / total_amount,
+        })
+    }
+}
+
+/// [`Stream`] adapter that maps every [`MarketEvent<OrderBook>`] into a
+/// [`MarketEvent<Microprice>`].
+///
+/// [`OrderBook`] updates without a [`Microprice`] (eg/ a one-sided book) are skipped, rather than
+/// emitting a price that is not weighted by both sides.
+#[derive(Debug)]
+pub struct MicropriceStream<St> {
+    stream: St,
+}
+
+impl<St> MicropriceStream<St>
+where
+    St: Stream<Item = MarketEvent<OrderBook>> + Unpin,
+{
+    /// Construct a new [`MicropriceStream`] from the provided [`MarketEvent<OrderBook>`]
+    /// [`Stream`].
+    pub fn new(stream: St) -> Self {
+        Self { stream }
+    }
+}
+
+impl<St> Stream for MicropriceStream<St>
+where
+    St: Stream<Item = MarketEvent<OrderBook>> + Unpin,
+{
+    type Item = MarketEvent<Microprice>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            let event = match Pin::new(&mut self.stream).poll_next(cx) {
+                Poll::Ready(Some(event)) => event,
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            };
+
+            if let Some(microprice) = Microprice::from_book(&event.kind) {
+                return Poll::Ready(Some(MarketEvent {
+                    exchange_time: event.exchange_time,
+                    received_time: event.received_time,
+                    exchange_time_synthesized: event.exchange_time_synthesized,
+                    exchange: event.exchange,
+                    instrument: event.instrument,
+                    kind: microprice,
+                }));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::ExchangeId,
+        subscription::book::{Level, OrderBookSide},
+    };
+    use barter_integration::model::{
+        instrument::{kind::InstrumentKind, Instrument},
+        Exchange, Side,
+    };
+    use chrono::{TimeZone, Utc};
+    use futures::StreamExt;
+
+    fn book(bids: Vec<Level>, asks: Vec<Level>) -> MarketEvent<OrderBook> {
+        MarketEvent {
+            exchange_time: Utc.timestamp_millis_opt(1_700_000_000_000).unwrap(),
+            received_time: Utc.timestamp_millis_opt(1_700_000_000_050).unwrap(),
+            exchange_time_synthesized: false,
+            exchange: Exchange::from(ExchangeId::BinanceSpot),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            kind: OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, bids),
+                asks: OrderBookSide::new(Side::Sell, asks),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_microprice_stream() {
+        struct TestCase {
+            input: MarketEvent<OrderBook>,
+            expected: Option<Microprice>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: heavier bid pulls the micro-price towards the ask
+                // (100 * 1 + 101 * 3) / (3 + 1) = 100.75
+                input: book(
+                    vec![Level::new(100.0, 3.0), Level::new(99.0, 10.0)],
+                    vec![Level::new(101.0, 1.0), Level::new(102.0, 10.0)],
+                ),
+                expected: Some(Microprice {
+                    price: 100.75,
+                    mid_price: 100.5,
+                    imbalance: 0.75,
+                }),
+            },
+            TestCase {
+                // TC1: heavier ask pulls the micro-price towards the bid
+                // (100 * 4 + 101 * 1) / (1 + 4) = 100.2
+                input: book(vec![Level::new(100.0, 1.0)], vec![Level::new(101.0, 4.0)]),
+                expected: Some(Microprice {
+                    price: 100.2,
+                    mid_price: 100.5,
+                    imbalance: 0.2,
+                }),
+            },
+            TestCase {
+                // TC2: balanced top-of-book micro-price equals the mid price
+                input: book(vec![Level::new(100.0, 2.0)], vec![Level::new(101.0, 2.0)]),
+                expected: Some(Microprice {
+                    price: 100.5,
+                    mid_price: 100.5,
+                    imbalance: 0.5,
+                }),
+            },
+            TestCase {
+                // TC3: one-sided book missing an ask is skipped
+                input: book(vec![Level::new(100.0, 2.0)], vec![]),
+                expected: None,
+            },
+            TestCase {
+                // TC4: one-sided book missing a bid is skipped
+                input: book(vec![], vec![Level::new(101.0, 2.0)]),
+                expected: None,
+            },
+            TestCase {
+                // TC5: top-of-book without any amount is skipped
+                input: book(vec![Level::new(100.0, 0.0)], vec![Level::new(101.0, 0.0)]),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.iter().enumerate() {
+            let actual = Microprice::from_book(&test.input.kind);
+            match (actual, test.expected) {
+                (None, None) => {}
+                (Some(actual), Some(expected)) => {
+                    assert!(
+                        (actual.price - expected.price).abs() < 1e-9,
+                        "TC{index} failed: price {} != {}",
+                        actual.price,
+                        expected.price
+                    );
+                    assert!(
+                        (actual.mid_price - expected.mid_price).abs() < 1e-9,
+                        "TC{index} failed: mid_price {} != {}",
+                        actual.mid_price,
+                        expected.mid_price
+                    );
+                    assert!(
+                        (actual.imbalance - expected.imbalance).abs() < 1e-9,
+                        "TC{index} failed: imbalance {} != {}",
+                        actual.imbalance,
+                        expected.imbalance
+                    );
+                }
+                (actual, expected) => {
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+
+        // Stream emits an event per two-sided book update only
+        let (inputs, expected): (Vec<_>, Vec<_>) = tests
+            .into_iter()
+            .map(|test| (test.input, test.expected))
+            .unzip();
+
+        let actual = MicropriceStream::new(futures::stream::iter(inputs))
+            .map(|event| event.kind)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(actual.len(), expected.iter().flatten().count());
+    }
+}
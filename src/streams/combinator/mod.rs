@@ -0,0 +1,37 @@
+/// [`CandleStream`](candle::CandleStream) combinator that aggregates a
+/// [`MarketEvent<PublicTrade>`](crate::event::MarketEvent) stream into locally built
+/// [`Candle`](crate::subscription::candle::Candle)s.
+pub mod candle;
+
+/// [`MicropriceStream`](microprice::MicropriceStream) combinator that derives the volume weighted
+/// mid price from the top-of-book of a [`MarketEvent<OrderBook>`](crate::event::MarketEvent) stream.
+pub mod microprice;
+
+/// [`BookSnapshotStream`](snapshot::BookSnapshotStream) combinator that emits the latest
+/// [`MarketEvent<OrderBook>`](crate::event::MarketEvent) of each market on a fixed cadence.
+pub mod snapshot;
+
+/// [`SpreadStream`](spread::SpreadStream) combinator that derives the bid-ask spread from a
+/// [`MarketEvent<OrderBookL1>`](crate::event::MarketEvent) stream.
+pub mod spread;
+
+/// [`ConsolidatedTape`](tape::ConsolidatedTape) combinator that merges many exchanges'
+/// [`MarketEvent<PublicTrade>`](crate::event::MarketEvent) streams for the same canonical
+/// [`Instrument`](barter_integration::model::instrument::Instrument).
+pub mod tape;
+
+/// [`TickRuleStream`](tick_rule::TickRuleStream) combinator that infers the aggressor side of
+/// [`MarketEvent<PublicTrade>`](crate::event::MarketEvent)s the exchange did not provide it for.
+pub mod tick_rule;
+
+/// [`RealizedVolStream`](volatility::RealizedVolStream) combinator that computes a rolling
+/// realized volatility from a [`MarketEvent<Candle>`](crate::event::MarketEvent) stream.
+pub mod volatility;
+
+/// [`VwapStream`](vwap::VwapStream) combinator that computes a rolling volume weighted average
+/// price from a [`MarketEvent<PublicTrade>`](crate::event::MarketEvent) stream.
+pub mod vwap;
+
+/// [`Warmup`](warmup::Warmup) period during which derived combinators suppress their unreliable
+/// initial output.
+pub mod warmup;
@@ -0,0 +1,212 @@
+use crate::{event::MarketEvent, subscription::book::OrderBook};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, VecDeque},
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::time::{Interval, MissedTickBehavior};
+
+/// Determines whether a [`BookSnapshotStream`] forwards every [`OrderBook`] update in addition
+/// to its periodic snapshots.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum BookSnapshotMode {
+    /// Forward every [`OrderBook`] update, and emit periodic snapshots in addition.
+    #[default]
+    WithUpdates,
+    /// Only emit periodic snapshots, suppressing the [`OrderBook`] updates in between.
+    SnapshotsOnly,
+}
+
+/// [`Stream`] adapter that emits the latest full [`MarketEvent<OrderBook>`] of each
+/// [`Exchange`] & [`Instrument`] combination every `interval`, for consumers that sample or
+/// record books at a fixed cadence (eg/ a recorder writing one book per second).
+///
+/// A periodic snapshot is emitted on every tick even if the [`OrderBook`] was not updated since
+/// the previous tick, in which case it is the unchanged [`MarketEvent<OrderBook>`] last received.
+/// No snapshot is emitted for a market until its first [`OrderBook`] is received, and any
+/// missed ticks (eg/ a slow consumer) are skipped rather than emitted in a burst.
+#[derive(Debug)]
+pub struct BookSnapshotStream<St> {
+    pub interval: Duration,
+    pub mode: BookSnapshotMode,
+    stream: St,
+    ticker: Option<Interval>,
+    latest: HashMap<(Exchange, Instrument), MarketEvent<OrderBook>>,
+    snapshots: VecDeque<MarketEvent<OrderBook>>,
+}
+
+impl<St> BookSnapshotStream<St>
+where
+    St: Stream<Item = MarketEvent<OrderBook>> + Unpin,
+{
+    /// Construct a new [`BookSnapshotStream`] emitting a snapshot of every market of the provided
+    /// [`MarketEvent<OrderBook>`] [`Stream`] each `interval`, in addition to every update.
+    ///
+    /// Panics if the interval is zero.
+    pub fn new(stream: St, interval: Duration) -> Self {
+        assert!(
+            !interval.is_zero(),
+            "BookSnapshotStream interval must be non-zero"
+        );
+
+        Self {
+            interval,
+            mode: BookSnapshotMode::default(),
+            stream,
+            ticker: None,
+            latest: HashMap::new(),
+            snapshots: VecDeque::new(),
+        }
+    }
+
+    /// Set the [`BookSnapshotMode`].
+    pub fn mode(self, mode: BookSnapshotMode) -> Self {
+        Self { mode, ..self }
+    }
+}
+
+impl<St> Stream for BookSnapshotStream<St>
+where
+    St: Stream<Item = MarketEvent<OrderBook>> + Unpin,
+{
+    type Item = MarketEvent<OrderBook>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        // Ticker is started lazily, since it requires the Tokio runtime
+        let period = this.interval;
+        let ticker = this.ticker.get_or_insert_with(|| {
+            let mut ticker = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+            ticker
+        });
+
+        // Poll the ticker before the inner Stream, so a busy Stream cannot delay snapshots
+        while ticker.poll_tick(cx).is_ready() {
+            this.snapshots.extend(this.latest.values().cloned());
+        }
+
+        if let Some(snapshot) = this.snapshots.pop_front() {
+            return Poll::Ready(Some(snapshot));
+        }
+
+        loop {
+            match Pin::new(&mut this.stream).poll_next(cx) {
+                Poll::Ready(Some(event)) => {
+                    let key = (event.exchange.clone(), event.instrument.clone());
+                    match this.mode {
+                        BookSnapshotMode::WithUpdates => {
+                            this.latest.insert(key, event.clone());
+                            return Poll::Ready(Some(event));
+                        }
+                        BookSnapshotMode::SnapshotsOnly => {
+                            this.latest.insert(key, event);
+                        }
+                    }
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::ExchangeId,
+        subscription::book::{Level, OrderBookSide},
+    };
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+    use chrono::{DateTime, Utc};
+    use futures::StreamExt;
+    use tokio::{sync::mpsc, time::Instant};
+    use tokio_stream::wrappers::UnboundedReceiverStream;
+
+    fn book(update: i64) -> MarketEvent<OrderBook> {
+        let time = DateTime::<Utc>::from_timestamp_millis(update).unwrap();
+        MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange_time_synthesized: false,
+            exchange: Exchange::from(ExchangeId::BinanceSpot),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            kind: OrderBook {
+                last_update_time: time,
+                bids: OrderBookSide::new(Side::Buy, vec![Level::new(100.0, update as f64)]),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            },
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_book_snapshot_stream_emits_on_cadence() {
+        struct TestCase {
+            mode: BookSnapshotMode,
+            expected: Vec<(u64, i64)>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: snapshots on every tick in addition to every update
+                mode: BookSnapshotMode::WithUpdates,
+                expected: vec![
+                    (0, 1),
+                    (1000, 1),
+                    (2000, 1),
+                    (2500, 2),
+                    (2500, 3),
+                    (2500, 4),
+                    (3000, 4),
+                    (4000, 4),
+                ],
+            },
+            TestCase {
+                // TC1: only snapshots on every tick, regardless of the update frequency
+                mode: BookSnapshotMode::SnapshotsOnly,
+                expected: vec![(1000, 1), (2000, 1), (3000, 4), (4000, 4), (5000, 4)],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (book_tx, book_rx) = mpsc::unbounded_channel();
+            let start = Instant::now();
+
+            // One update, followed by a burst of updates between ticks, then none at all
+            let updates = tokio::spawn(async move {
+                book_tx.send(book(1)).unwrap();
+                tokio::time::sleep(Duration::from_millis(2500)).await;
+                for update in 2..=4 {
+                    book_tx.send(book(update)).unwrap();
+                }
+                tokio::time::sleep(Duration::from_secs(60)).await;
+            });
+
+            let actual = BookSnapshotStream::new(
+                UnboundedReceiverStream::new(book_rx),
+                Duration::from_secs(1),
+            )
+            .mode(test.mode)
+            .take(test.expected.len())
+            .map(|event| {
+                (
+                    (Instant::now() - start).as_millis() as u64,
+                    event.kind.last_update_time.timestamp_millis(),
+                )
+            })
+            .collect::<Vec<_>>()
+            .await;
+
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+            updates.abort();
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use crate::{event::MarketEvent, subscription::book::OrderBookL1};
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// State of the best bid & ask prices relative to each other.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum SpreadState {
+    /// Best ask is above the best bid (positive spread).
+    Normal,
+    /// Best ask is equal to the best bid (zero spread).
+    Locked,
+    /// Best ask is below the best bid (negative spread).
+    Crossed,
+}
+
+/// Bid-ask spread derived from an [`OrderBookL1`].
+///
+/// Crossed & locked markets are not hidden, the spread is negative or zero respectively and is
+/// flagged via the [`SpreadState`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Spread {
+    /// Best ask price minus best bid price.
+    pub absolute: f64,
+    /// Absolute spread in basis points of the mid price.
+    pub bps: f64,
+    pub state: SpreadState,
+}
+
+impl Spread {
+    /// Determine if the best bid & ask are locked or crossed.
+    pub fn is_locked_or_crossed(&self) -> bool {
+        self.state != SpreadState::Normal
+    }
+}
+
+impl From<&OrderBookL1> for Spread {
+    fn from(book: &OrderBookL1) -> Self {
+        let absolute = book.best_ask.price - book.best_bid.price;
+        let mid_price = book.mid_price();
+        let bps = if mid_price == 0.0 {
+            0.0
+        } else {
+            absolute / mid_price * 10_000.0
+        };
+
+        let state = if absolute > 0.0 {
+            SpreadState::Normal
+        } else if absolute == 0.0 {
+            SpreadState::Locked
+        } else {
+            SpreadState::Crossed
+        };
+
+        Self {
+            absolute,
+            bps,
+            state,
+        }
+    }
+}
+
+/// [`Stream`] adapter that maps every [`MarketEvent<OrderBookL1>`] into a
+/// [`MarketEvent<Spread>`].
+#[derive(Debug)]
+pub struct SpreadStream<St> {
+    stream: St,
+}
+
+impl<St> SpreadStream<St>
+where
+    St: Stream<Item = MarketEvent<OrderBookL1>> + Unpin,
+{
+    /// Construct a new [`SpreadStream`] from the provided [`MarketEvent<OrderBookL1>`]
+    /// [`Stream`].
+    pub fn new(stream: St) -> Self {
+        Self { stream }
+    }
+}
+
+impl<St> Stream for SpreadStream<St>
+where
+    St: Stream<Item = MarketEvent<OrderBookL1>> + Unpin,
+{
+    type Item = MarketEvent<Spread>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.stream).poll_next(cx).map(|event| {
+            event.map(|event| MarketEvent {
+                exchange_time: event.exchange_time,
+                received_time: event.received_time,
+                exchange_time_synthesized: event.exchange_time_synthesized,
+                exchange: event.exchange,
+                instrument: event.instrument,
+                kind: Spread::from(&event.kind),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exchange::ExchangeId, subscription::book::Level};
+    use barter_integration::model::{
+        instrument::{kind::InstrumentKind, Instrument},
+        Exchange,
+    };
+    use chrono::{TimeZone, Utc};
+    use futures::StreamExt;
+
+    fn l1(best_bid: f64, best_ask: f64) -> MarketEvent<OrderBookL1> {
+        MarketEvent {
+            exchange_time: Utc.timestamp_millis_opt(1_700_000_000_000).unwrap(),
+            received_time: Utc.timestamp_millis_opt(1_700_000_000_050).unwrap(),
+            exchange_time_synthesized: false,
+            exchange: Exchange::from(ExchangeId::BinanceSpot),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            kind: OrderBookL1 {
+                last_update_time: Utc::now(),
+                sequence: None,
+                best_bid: Level::new(best_bid, 1.0),
+                best_ask: Level::new(best_ask, 1.0),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_spread_stream() {
+        struct TestCase {
+            input: MarketEvent<OrderBookL1>,
+            expected_absolute: f64,
+            expected_bps: f64,
+            expected_state: SpreadState,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: normal spread, 1.0 / 100.5 * 10_000
+                input: l1(100.0, 101.0),
+                expected_absolute: 1.0,
+                expected_bps: 99.50248756218906,
+                expected_state: SpreadState::Normal,
+            },
+            TestCase {
+                // TC1: locked spread
+                input: l1(100.0, 100.0),
+                expected_absolute: 0.0,
+                expected_bps: 0.0,
+                expected_state: SpreadState::Locked,
+            },
+            TestCase {
+                // TC2: crossed spread is negative & flagged, -0.5 / 100.25 * 10_000
+                input: l1(100.5, 100.0),
+                expected_absolute: -0.5,
+                expected_bps: -49.87531172069825,
+                expected_state: SpreadState::Crossed,
+            },
+        ];
+
+        let (inputs, expected): (Vec<_>, Vec<_>) = tests
+            .into_iter()
+            .map(|test| {
+                (
+                    test.input,
+                    (
+                        test.expected_absolute,
+                        test.expected_bps,
+                        test.expected_state,
+                    ),
+                )
+            })
+            .unzip();
+
+        let actual = SpreadStream::new(futures::stream::iter(inputs))
+            .map(|event| event.kind)
+            .collect::<Vec<_>>()
+            .await;
+
+        for (index, (actual, (absolute, bps, state))) in
+            actual.into_iter().zip(expected).enumerate()
+        {
+            assert!(
+                (actual.absolute - absolute).abs() < 1e-9,
+                "TC{index} failed: absolute {} != {absolute}",
+                actual.absolute
+            );
+            assert!(
+                (actual.bps - bps).abs() < 1e-9,
+                "TC{index} failed: bps {} != {bps}",
+                actual.bps
+            );
+            assert_eq!(actual.state, state, "TC{} failed", index);
+            assert_eq!(
+                actual.is_locked_or_crossed(),
+                state != SpreadState::Normal,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
@@ -0,0 +1,393 @@
+use crate::{event::MarketEvent, exchange::ExchangeId, subscription::trade::PublicTrade};
+use barter_integration::model::instrument::Instrument;
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use std::{
+    collections::{BTreeMap, HashMap},
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::time::{Instant, Sleep};
+use tokio_stream::StreamMap;
+
+/// [`MarketEvent`] timestamp used to order the events merged by a [`ConsolidatedTape`].
+///
+/// ### Tradeoffs
+/// - [`OrderingTime::Received`]: local clock, so timestamps are comparable across exchanges, but
+///   they include network & processing jitter, so trades that happened close together on
+///   different venues may be yielded in the order they arrived rather than the order they
+///   executed.
+/// - [`OrderingTime::Exchange`]: venue execution time, free of local jitter, but each exchange
+///   stamps events with its own clock, so any skew between exchange clocks (see
+///   [`ClockOffset`](crate::streams::clock::ClockOffset)) is carried into the merged ordering.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum OrderingTime {
+    /// Order by [`MarketEvent`] `exchange_time`.
+    Exchange,
+    /// Order by [`MarketEvent`] `received_time`.
+    #[default]
+    Received,
+}
+
+impl OrderingTime {
+    /// Select the configured timestamp of the provided [`MarketEvent`].
+    pub fn time<T>(&self, event: &MarketEvent<T>) -> DateTime<Utc> {
+        match self {
+            Self::Exchange => event.exchange_time,
+            Self::Received => event.received_time,
+        }
+    }
+}
+
+/// Consolidated trade tape merging many exchanges' [`MarketEvent<PublicTrade>`] streams for the
+/// same canonical [`Instrument`] into a single [`Stream`].
+///
+/// ### Notes
+/// - Each [`MarketEvent<PublicTrade>`] retains its source [`Exchange`](barter_integration::model::Exchange)
+///   attribution.
+/// - [`MarketEvent<PublicTrade>`]s for any other [`Instrument`] are discarded.
+/// - Ordering is best-effort by the configured [`OrderingTime`] (default `received_time`): all events ready when the tape is polled are
+///   buffered and yielded oldest first, but an event arriving late from a slow stream can still
+///   be yielded after a newer event that was already yielded.
+/// - Configuring a [`max_hold`](ConsolidatedTape::max_hold()) holds each event in the reorder
+///   buffer until every exchange stream has yielded an event at least as new (assuming each
+///   exchange stream is itself ordered), or until any buffered event has been held for the
+///   `max_hold`, at which point the buffer is flushed in order regardless. This trades perfect
+///   ordering for latency bounded by the `max_hold`, even if an exchange stream is silent.
+#[derive(Debug)]
+pub struct ConsolidatedTape<St> {
+    pub instrument: Instrument,
+    pub ordering: OrderingTime,
+    pub max_hold: Option<Duration>,
+    streams: StreamMap<ExchangeId, St>,
+    buffer: BTreeMap<(DateTime<Utc>, u64), MarketEvent<PublicTrade>>,
+    arrivals: BTreeMap<u64, Instant>,
+    watermarks: HashMap<ExchangeId, DateTime<Utc>>,
+    flush_timer: Option<Pin<Box<Sleep>>>,
+    sequence: u64,
+}
+
+impl<St> ConsolidatedTape<St>
+where
+    St: Stream<Item = MarketEvent<PublicTrade>> + Unpin,
+{
+    /// Construct a new [`ConsolidatedTape`] for the canonical [`Instrument`] from the provided
+    /// exchange streams.
+    pub fn new<I>(instrument: I, streams: StreamMap<ExchangeId, St>) -> Self
+    where
+        I: Into<Instrument>,
+    {
+        Self {
+            instrument: instrument.into(),
+            ordering: OrderingTime::default(),
+            max_hold: None,
+            streams,
+            buffer: BTreeMap::new(),
+            arrivals: BTreeMap::new(),
+            watermarks: HashMap::new(),
+            flush_timer: None,
+            sequence: 0,
+        }
+    }
+
+    /// Configure the [`OrderingTime`] used to order the merged [`MarketEvent<PublicTrade>`]s.
+    pub fn ordering(self, ordering: OrderingTime) -> Self {
+        Self { ordering, ..self }
+    }
+
+    /// Configure the maximum [`Duration`] a [`MarketEvent<PublicTrade>`] is held in the reorder
+    /// buffer waiting for potentially earlier events from slower exchange streams.
+    ///
+    /// Defaults to `None`, meaning events are not held beyond each poll.
+    pub fn max_hold(self, max_hold: Duration) -> Self {
+        Self {
+            max_hold: Some(max_hold),
+            ..self
+        }
+    }
+
+    /// Add an exchange [`Stream`] to the [`ConsolidatedTape`], replacing any existing
+    /// [`Stream`] for the same [`ExchangeId`].
+    pub fn add(&mut self, exchange: ExchangeId, stream: St) {
+        self.streams.insert(exchange, stream);
+    }
+
+    /// Buffer the [`MarketEvent<PublicTrade>`] if it is for the canonical [`Instrument`].
+    ///
+    /// Every event advances the watermark of its exchange stream, even if it is discarded.
+    fn buffer(&mut self, exchange: ExchangeId, event: MarketEvent<PublicTrade>) {
+        let time = self.ordering.time(&event);
+        self.watermarks
+            .entry(exchange)
+            .and_modify(|watermark| *watermark = (*watermark).max(time))
+            .or_insert(time);
+
+        if event.instrument != self.instrument {
+            return;
+        }
+
+        self.buffer.insert((time, self.sequence), event);
+        self.arrivals.insert(self.sequence, Instant::now());
+        self.sequence += 1;
+    }
+
+    /// Determine if the oldest buffered event can be yielded without waiting for the
+    /// `max_hold`, since every active exchange stream has yielded an event at least as new.
+    fn is_ordered(&self, time: DateTime<Utc>) -> bool {
+        self.streams.keys().all(|exchange| {
+            self.watermarks
+                .get(exchange)
+                .is_some_and(|watermark| *watermark >= time)
+        })
+    }
+
+    /// Pop the buffered event with the oldest [`OrderingTime`].
+    fn pop(&mut self) -> Option<MarketEvent<PublicTrade>> {
+        let ((_, sequence), event) = self.buffer.pop_first()?;
+        self.arrivals.remove(&sequence);
+        Some(event)
+    }
+}
+
+impl<St> Stream for ConsolidatedTape<St>
+where
+    St: Stream<Item = MarketEvent<PublicTrade>> + Unpin,
+{
+    type Item = MarketEvent<PublicTrade>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // Buffer every event that is currently ready across all exchange streams
+        let terminated = loop {
+            match Pin::new(&mut self.streams).poll_next(cx) {
+                Poll::Ready(Some((exchange, event))) => self.buffer(exchange, event),
+                Poll::Ready(None) => break true,
+                Poll::Pending => break false,
+            }
+        };
+
+        let Some(((time, _), _)) = self.buffer.first_key_value() else {
+            return if terminated {
+                Poll::Ready(None)
+            } else {
+                Poll::Pending
+            };
+        };
+
+        // Yield the buffered event with the oldest OrderingTime if it can no longer be preceded
+        let Some(max_hold) = self.max_hold else {
+            return Poll::Ready(self.pop());
+        };
+        if terminated || self.is_ordered(*time) {
+            return Poll::Ready(self.pop());
+        }
+
+        // Otherwise, flush in order once the earliest arrival has been held for the max_hold
+        let deadline = match self.arrivals.first_key_value() {
+            Some((_, arrival)) => *arrival + max_hold,
+            None => return Poll::Ready(self.pop()),
+        };
+        let timer = match &mut self.flush_timer {
+            Some(timer) => {
+                if timer.deadline() != deadline {
+                    timer.as_mut().reset(deadline);
+                }
+                timer
+            }
+            none => none.insert(Box::pin(tokio::time::sleep_until(deadline))),
+        };
+
+        match timer.as_mut().poll(cx) {
+            Poll::Ready(()) => Poll::Ready(self.pop()),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::{instrument::kind::InstrumentKind, Exchange, Side};
+    use futures::StreamExt;
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::UnboundedReceiverStream;
+
+    fn trade(
+        exchange: ExchangeId,
+        instrument: Instrument,
+        received_millis: i64,
+        id: &str,
+    ) -> MarketEvent<PublicTrade> {
+        let time = DateTime::<Utc>::from_timestamp_millis(received_millis).unwrap();
+        MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange_time_synthesized: false,
+            exchange: Exchange::from(exchange),
+            instrument,
+            kind: PublicTrade {
+                id: id.to_string(),
+                price: 30000.0,
+                amount: 1.0,
+                side: Side::Buy,
+                source: Default::default(),
+                side_source: Default::default(),
+                order_ids: Default::default(),
+                aggregate_count: None,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_consolidated_tape_merges_exchange_trades() {
+        let btc_usdt = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let eth_usdt = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+
+        let (binance_tx, binance_rx) = mpsc::unbounded_channel();
+        let (okx_tx, okx_rx) = mpsc::unbounded_channel();
+
+        for event in [
+            trade(ExchangeId::BinanceSpot, btc_usdt.clone(), 1, "b1"),
+            trade(ExchangeId::BinanceSpot, eth_usdt.clone(), 2, "b-eth"),
+            trade(ExchangeId::BinanceSpot, btc_usdt.clone(), 4, "b2"),
+        ] {
+            binance_tx.send(event).unwrap();
+        }
+        for event in [
+            trade(ExchangeId::Okx, btc_usdt.clone(), 2, "o1"),
+            trade(ExchangeId::Okx, btc_usdt.clone(), 3, "o2"),
+            trade(ExchangeId::Okx, eth_usdt, 5, "o-eth"),
+        ] {
+            okx_tx.send(event).unwrap();
+        }
+        drop((binance_tx, okx_tx));
+
+        let mut streams = StreamMap::new();
+        streams.insert(
+            ExchangeId::BinanceSpot,
+            UnboundedReceiverStream::new(binance_rx),
+        );
+        streams.insert(ExchangeId::Okx, UnboundedReceiverStream::new(okx_rx));
+
+        let actual = ConsolidatedTape::new(btc_usdt.clone(), streams)
+            .map(|event| {
+                assert_eq!(event.instrument, btc_usdt);
+                (event.exchange, event.kind.id)
+            })
+            .collect::<Vec<_>>()
+            .await;
+
+        let expected = vec![
+            (Exchange::from(ExchangeId::BinanceSpot), "b1".to_string()),
+            (Exchange::from(ExchangeId::Okx), "o1".to_string()),
+            (Exchange::from(ExchangeId::Okx), "o2".to_string()),
+            (Exchange::from(ExchangeId::BinanceSpot), "b2".to_string()),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[tokio::test]
+    async fn test_consolidated_tape_ordering_time() {
+        let btc_usdt = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+
+        // Okx trade executed first, but arrived after the Binance trade
+        let events = || {
+            let mut binance = trade(ExchangeId::BinanceSpot, btc_usdt.clone(), 10, "b1");
+            binance.exchange_time = DateTime::<Utc>::from_timestamp_millis(5).unwrap();
+            let mut okx = trade(ExchangeId::Okx, btc_usdt.clone(), 20, "o1");
+            okx.exchange_time = DateTime::<Utc>::from_timestamp_millis(1).unwrap();
+            [(ExchangeId::BinanceSpot, binance), (ExchangeId::Okx, okx)]
+        };
+
+        struct TestCase {
+            ordering: OrderingTime,
+            expected: Vec<&'static str>,
+        }
+
+        let cases = vec![
+            // TC0: received_time ordering yields trades in arrival order
+            TestCase {
+                ordering: OrderingTime::Received,
+                expected: vec!["b1", "o1"],
+            },
+            // TC1: exchange_time ordering yields trades in execution order
+            TestCase {
+                ordering: OrderingTime::Exchange,
+                expected: vec!["o1", "b1"],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut streams = StreamMap::new();
+            for (exchange, event) in events() {
+                let (tx, rx) = mpsc::unbounded_channel();
+                tx.send(event).unwrap();
+                streams.insert(exchange, UnboundedReceiverStream::new(rx));
+            }
+
+            let actual = ConsolidatedTape::new(btc_usdt.clone(), streams)
+                .ordering(test.ordering)
+                .map(|event| event.kind.id)
+                .collect::<Vec<_>>()
+                .await;
+
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_consolidated_tape_max_hold_flushes_with_silent_stream() {
+        use futures::FutureExt;
+
+        let btc_usdt = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let max_hold = Duration::from_millis(100);
+
+        let (binance_tx, binance_rx) = mpsc::unbounded_channel();
+        let (okx_tx, okx_rx) = mpsc::unbounded_channel();
+
+        let mut streams = StreamMap::new();
+        streams.insert(
+            ExchangeId::BinanceSpot,
+            UnboundedReceiverStream::new(binance_rx),
+        );
+        streams.insert(ExchangeId::Okx, UnboundedReceiverStream::new(okx_rx));
+
+        let mut tape = ConsolidatedTape::new(btc_usdt.clone(), streams).max_hold(max_hold);
+
+        // Okx is silent, so the Binance trades are held waiting for potentially earlier trades
+        binance_tx
+            .send(trade(ExchangeId::BinanceSpot, btc_usdt.clone(), 1, "b1"))
+            .unwrap();
+        binance_tx
+            .send(trade(ExchangeId::BinanceSpot, btc_usdt.clone(), 2, "b2"))
+            .unwrap();
+        let start = Instant::now();
+        assert!(tape.next().now_or_never().is_none());
+
+        // Trades are flushed in order once held for the max_hold, despite Okx remaining silent
+        assert_eq!(tape.next().await.unwrap().kind.id, "b1");
+        assert_eq!(start.elapsed(), max_hold);
+        assert_eq!(tape.next().await.unwrap().kind.id, "b2");
+        assert_eq!(start.elapsed(), max_hold);
+
+        // Once every stream has yielded a newer trade, older trades are yielded without waiting
+        okx_tx
+            .send(trade(ExchangeId::Okx, btc_usdt.clone(), 3, "o1"))
+            .unwrap();
+        binance_tx
+            .send(trade(ExchangeId::BinanceSpot, btc_usdt.clone(), 4, "b3"))
+            .unwrap();
+        assert_eq!(
+            tape.next()
+                .now_or_never()
+                .flatten()
+                .map(|event| event.kind.id),
+            Some("o1".to_string())
+        );
+        assert!(tape.next().now_or_never().is_none());
+        assert_eq!(tape.next().await.unwrap().kind.id, "b3");
+        assert_eq!(start.elapsed(), max_hold * 2);
+    }
+}
@@ -0,0 +1,205 @@
+use crate::{
+    event::MarketEvent,
+    subscription::trade::{PublicTrade, SideSource},
+};
+use barter_integration::model::{instrument::Instrument, Exchange, Side};
+use futures::Stream;
+use std::{
+    collections::HashMap,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Tick rule classifier of the aggressor [`Side`] of [`PublicTrade`]s for a single market.
+///
+/// A trade priced above the previous trade (uptick) is classified as a [`Side::Buy`], and below
+/// (downtick) as a [`Side::Sell`]. A trade at the same price (zero tick) inherits the direction
+/// of the most recent non-zero tick.
+///
+/// ### Notes
+/// Only trades flagged as [`SideSource::Absent`] are classified, but every trade contributes to
+/// the price history. A trade that cannot yet be classified (eg/ the first trade) remains
+/// [`SideSource::Absent`].
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct TickRule {
+    last_price: Option<f64>,
+    last_tick: Option<Side>,
+}
+
+impl TickRule {
+    /// Construct a new [`TickRule`] without any price history.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Classify the [`PublicTrade`] aggressor [`Side`] if it was not provided by the exchange,
+    /// marking it as [`SideSource::Inferred`].
+    pub fn classify(&mut self, trade: &mut PublicTrade) {
+        let tick = match self.last_price {
+            Some(last_price) if trade.price > last_price => Some(Side::Buy),
+            Some(last_price) if trade.price < last_price => Some(Side::Sell),
+            _ => None,
+        };
+
+        self.last_price = Some(trade.price);
+        if tick.is_some() {
+            self.last_tick = tick;
+        }
+
+        if trade.side_source != SideSource::Absent {
+            return;
+        }
+
+        if let Some(side) = self.last_tick {
+            trade.side = side;
+            trade.side_source = SideSource::Inferred;
+        }
+    }
+}
+
+/// [`Stream`] adapter that applies a [`TickRule`] to every [`MarketEvent<PublicTrade>`],
+/// inferring the aggressor [`Side`] of trades the exchange did not provide it for.
+///
+/// A separate [`TickRule`] is maintained for each [`Exchange`] & [`Instrument`] combination,
+/// since price history is only comparable within the same market.
+#[derive(Debug)]
+pub struct TickRuleStream<St> {
+    stream: St,
+    rules: HashMap<(Exchange, Instrument), TickRule>,
+}
+
+impl<St> TickRuleStream<St>
+where
+    St: Stream<Item = MarketEvent<PublicTrade>> + Unpin,
+{
+    /// Construct a new [`TickRuleStream`] from the provided [`MarketEvent<PublicTrade>`]
+    /// [`Stream`].
+    pub fn new(stream: St) -> Self {
+        Self {
+            stream,
+            rules: HashMap::new(),
+        }
+    }
+
+    fn classify(&mut self, mut event: MarketEvent<PublicTrade>) -> MarketEvent<PublicTrade> {
+        self.rules
+            .entry((event.exchange.clone(), event.instrument.clone()))
+            .or_default()
+            .classify(&mut event.kind);
+
+        event
+    }
+}
+
+impl<St> Stream for TickRuleStream<St>
+where
+    St: Stream<Item = MarketEvent<PublicTrade>> + Unpin,
+{
+    type Item = MarketEvent<PublicTrade>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match Pin::new(&mut self.stream).poll_next(cx) {
+            Poll::Ready(Some(event)) => Poll::Ready(Some(self.classify(event))),
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::ExchangeId;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use chrono::{DateTime, Utc};
+    use futures::StreamExt;
+
+    fn trade(price: f64, side: Option<Side>) -> MarketEvent<PublicTrade> {
+        let time = DateTime::<Utc>::from_timestamp_millis(0).unwrap();
+        MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange_time_synthesized: false,
+            exchange: Exchange::from(ExchangeId::BinanceSpot),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            kind: PublicTrade {
+                id: price.to_string(),
+                price,
+                amount: 1.0,
+                side: side.unwrap_or(Side::Buy),
+                source: Default::default(),
+                side_source: match side {
+                    Some(_) => SideSource::Exchange,
+                    None => SideSource::Absent,
+                },
+                order_ids: Default::default(),
+                aggregate_count: None,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tick_rule_stream_classification() {
+        struct TestCase {
+            input: MarketEvent<PublicTrade>,
+            expected: (Side, SideSource),
+        }
+
+        let cases = vec![
+            // TC0: first trade cannot be classified without a previous price
+            TestCase {
+                input: trade(100.0, None),
+                expected: (Side::Buy, SideSource::Absent),
+            },
+            // TC1: downtick is classified as a sell
+            TestCase {
+                input: trade(99.0, None),
+                expected: (Side::Sell, SideSource::Inferred),
+            },
+            // TC2: zero tick inherits the previous downtick
+            TestCase {
+                input: trade(99.0, None),
+                expected: (Side::Sell, SideSource::Inferred),
+            },
+            // TC3: uptick is classified as a buy
+            TestCase {
+                input: trade(101.0, None),
+                expected: (Side::Buy, SideSource::Inferred),
+            },
+            // TC4: provided side is never overridden, even if contradicting a downtick
+            TestCase {
+                input: trade(100.0, Some(Side::Buy)),
+                expected: (Side::Buy, SideSource::Exchange),
+            },
+            // TC5: zero tick inherits the downtick of the trade with a provided side
+            TestCase {
+                input: trade(100.0, None),
+                expected: (Side::Sell, SideSource::Inferred),
+            },
+            // TC6: provided side is never overridden, even if contradicting an uptick
+            TestCase {
+                input: trade(102.0, Some(Side::Sell)),
+                expected: (Side::Sell, SideSource::Exchange),
+            },
+            // TC7: zero tick inherits the uptick of the trade with a provided side
+            TestCase {
+                input: trade(102.0, None),
+                expected: (Side::Buy, SideSource::Inferred),
+            },
+        ];
+
+        let (inputs, expected): (Vec<_>, Vec<_>) = cases
+            .into_iter()
+            .map(|test| (test.input, test.expected))
+            .unzip();
+
+        let actual = TickRuleStream::new(futures::stream::iter(inputs))
+            .map(|event| (event.kind.side, event.kind.side_source))
+            .collect::<Vec<_>>()
+            .await;
+
+        for (index, (actual, expected)) in actual.into_iter().zip(expected).enumerate() {
+            assert_eq!(actual, expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,432 @@
+use crate::{event::MarketEvent, subscription::candle::Candle};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Duration, Utc};
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, VecDeque},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Number of days in a year used to annualise a [`RealizedVol`], since crypto markets trade
+/// every day of the year.
+pub const DAYS_PER_YEAR: f64 = 365.0;
+
+/// Determines how a [`RollingRealizedVol`] handles a gap of one or more missing intervals
+/// between consecutive closed [`Candle`]s (eg/ a locally built [`Candle`] interval without any
+/// trades, or a disconnection).
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum VolGapPolicy {
+    /// Discard the window of returns, restarting the estimate from the [`Candle`] after the gap.
+    #[default]
+    Reset,
+    /// Include the return spanning the gap, scaled by the square root of the number of intervals
+    /// it covers to give an equivalent single interval return.
+    Scale,
+}
+
+/// Rolling realized volatility of the log returns between consecutive closed [`Candle`]s.
+///
+/// `volatility` is the root mean square of the log close-to-close returns within the window,
+/// per interval unless annualised. `gaps` is the number of those returns spanning a gap of
+/// missing intervals (see [`VolGapPolicy::Scale`]).
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct RealizedVol {
+    pub volatility: f64,
+    pub returns: usize,
+    pub gaps: usize,
+    pub window_start: DateTime<Utc>,
+}
+
+/// Rolling [`RealizedVol`] calculator for a single stream of closed [`Candle`]s of a fixed
+/// interval.
+///
+/// A running sum of squared returns is maintained incrementally, so each update is O(1)
+/// amortised regardless of window length.
+#[derive(Clone, PartialEq, Debug)]
+pub struct RollingRealizedVol {
+    pub interval: Duration,
+    pub window: usize,
+    pub gap_policy: VolGapPolicy,
+    pub annualise: bool,
+    returns: VecDeque<WindowReturn>,
+    sum_squares: f64,
+    gaps: usize,
+    last: Option<(DateTime<Utc>, f64)>,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct WindowReturn {
+    start: DateTime<Utc>,
+    value: f64,
+    gap: bool,
+}
+
+impl RollingRealizedVol {
+    /// Construct a new empty [`RollingRealizedVol`] over the most recent `window` returns of
+    /// [`Candle`]s with the provided interval, handling gaps with the [`VolGapPolicy::Reset`].
+    ///
+    /// Panics if the interval is not positive, or the window is zero.
+    pub fn new(interval: Duration, window: usize) -> Self {
+        assert!(
+            interval > Duration::zero(),
+            "RollingRealizedVol interval must be positive"
+        );
+        assert!(window > 0, "RollingRealizedVol window must be non-zero");
+
+        Self {
+            interval,
+            window,
+            gap_policy: VolGapPolicy::default(),
+            annualise: false,
+            returns: VecDeque::with_capacity(window),
+            sum_squares: 0.0,
+            gaps: 0,
+            last: None,
+        }
+    }
+
+    /// Handle gaps of missing intervals with the provided [`VolGapPolicy`].
+    pub fn gap_policy(self, gap_policy: VolGapPolicy) -> Self {
+        Self { gap_policy, ..self }
+    }
+
+    /// Annualise the [`RealizedVol`] by the square root of the number of intervals in a year
+    /// (see [`DAYS_PER_YEAR`]).
+    pub fn annualised(self) -> Self {
+        Self {
+            annualise: true,
+            ..self
+        }
+    }
+
+    /// Add a [`Candle`], evicting any returns that have slid out of the window, and return the
+    /// updated [`RealizedVol`].
+    ///
+    /// Forming [`Candle`]s, [`Candle`]s that do not close after the previous [`Candle`], and
+    /// non-positive closes are ignored. Returns `None` if the window contains no returns.
+    pub fn update(&mut self, candle: &Candle) -> Option<RealizedVol> {
+        if !candle.is_closed || candle.close <= 0.0 {
+            return None;
+        }
+
+        let Some((last_time, last_close)) = self.last else {
+            self.last = Some((candle.close_time, candle.close));
+            return None;
+        };
+
+        let intervals = self.intervals_between(last_time, candle.close_time);
+        if intervals < 1 {
+            return None;
+        }
+        self.last = Some((candle.close_time, candle.close));
+
+        let value = (candle.close / last_close).ln();
+        let gap = intervals > 1;
+        match (gap, self.gap_policy) {
+            (false, _) => self.push(last_time, value, false),
+            (true, VolGapPolicy::Reset) => {
+                self.reset();
+                return None;
+            }
+            (true, VolGapPolicy::Scale) => {
+                self.push(last_time, value / (intervals as f64).sqrt(), true)
+            }
+        }
+
+        self.realized_vol()
+    }
+
+    /// Current [`RealizedVol`] of the returns within the window.
+    pub fn realized_vol(&self) -> Option<RealizedVol> {
+        let window_start = self.returns.front()?.start;
+        let volatility = (self.sum_squares.max(0.0) / self.returns.len() as f64).sqrt();
+
+        Some(RealizedVol {
+            volatility: volatility * self.annualisation_factor(),
+            returns: self.returns.len(),
+            gaps: self.gaps,
+            window_start,
+        })
+    }
+
+    /// Number of whole intervals between two [`Candle`] close times, rounded to the nearest
+    /// interval to tolerate exchange timestamp jitter.
+    fn intervals_between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> i64 {
+        let elapsed = (end - start).num_milliseconds() as f64;
+        (elapsed / self.interval.num_milliseconds() as f64).round() as i64
+    }
+
+    fn annualisation_factor(&self) -> f64 {
+        if self.annualise {
+            (Duration::days(1).num_milliseconds() as f64 * DAYS_PER_YEAR
+                / self.interval.num_milliseconds() as f64)
+                .sqrt()
+        } else {
+            1.0
+        }
+    }
+
+    fn push(&mut self, start: DateTime<Utc>, value: f64, gap: bool) {
+        self.returns.push_back(WindowReturn { start, value, gap });
+        self.sum_squares += value * value;
+        self.gaps += usize::from(gap);
+
+        while self.returns.len() > self.window {
+            if let Some(evicted) = self.returns.pop_front() {
+                self.sum_squares -= evicted.value * evicted.value;
+                self.gaps -= usize::from(evicted.gap);
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        self.returns.clear();
+        self.sum_squares = 0.0;
+        self.gaps = 0;
+    }
+}
+
+/// [`Stream`] adapter that maps every closed [`MarketEvent<Candle>`] into a
+/// [`MarketEvent<RealizedVol>`] over a rolling window of returns.
+///
+/// A separate [`RollingRealizedVol`] is maintained for each [`Exchange`] & [`Instrument`]
+/// combination. Realized volatility of trades can be computed by first aggregating them into
+/// [`Candle`]s with a [`CandleStream`](super::candle::CandleStream), which skips intervals
+/// without trades, so configure the [`VolGapPolicy`] accordingly.
+#[derive(Debug)]
+pub struct RealizedVolStream<St> {
+    pub vol: RollingRealizedVol,
+    stream: St,
+    vols: HashMap<(Exchange, Instrument), RollingRealizedVol>,
+}
+
+impl<St> RealizedVolStream<St>
+where
+    St: Stream<Item = MarketEvent<Candle>> + Unpin,
+{
+    /// Construct a new [`RealizedVolStream`] computing a [`RealizedVol`] for each market of the
+    /// provided [`MarketEvent<Candle>`] [`Stream`], using (a clone of) the template
+    /// [`RollingRealizedVol`] configuration.
+    pub fn new(stream: St, vol: RollingRealizedVol) -> Self {
+        Self {
+            vol,
+            stream,
+            vols: HashMap::new(),
+        }
+    }
+
+    fn update(&mut self, event: MarketEvent<Candle>) -> Option<MarketEvent<RealizedVol>> {
+        let template = &self.vol;
+        let vol = self
+            .vols
+            .entry((event.exchange.clone(), event.instrument.clone()))
+            .or_insert_with(|| template.clone())
+            .update(&event.kind)?;
+
+        Some(MarketEvent {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange_time_synthesized: event.exchange_time_synthesized,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: vol,
+        })
+    }
+}
+
+impl<St> Stream for RealizedVolStream<St>
+where
+    St: Stream<Item = MarketEvent<Candle>> + Unpin,
+{
+    type Item = MarketEvent<RealizedVol>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match Pin::new(&mut self.stream).poll_next(cx) {
+                Poll::Ready(Some(event)) => {
+                    if let Some(vol) = self.update(event) {
+                        return Poll::Ready(Some(vol));
+                    }
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::ExchangeId;
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use futures::StreamExt;
+
+    fn candle(day: i64, close: f64) -> Candle {
+        Candle {
+            close_time: DateTime::<Utc>::from_timestamp_millis(0).unwrap() + Duration::days(day),
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume: 1.0,
+            trade_count: Some(1),
+            is_closed: true,
+        }
+    }
+
+    fn rms(returns: &[f64]) -> f64 {
+        (returns.iter().map(|r| r * r).sum::<f64>() / returns.len() as f64).sqrt()
+    }
+
+    #[test]
+    fn test_rolling_realized_vol_update() {
+        struct TestCase {
+            vol: RollingRealizedVol,
+            input: Vec<Candle>,
+            expected: Option<(f64, usize, usize)>,
+        }
+
+        let daily = RollingRealizedVol::new(Duration::days(1), 3);
+        let up = 1.1_f64.ln();
+        let down = 0.9_f64.ln();
+
+        let tests = vec![
+            TestCase {
+                // TC0: single candle yields no returns
+                vol: daily.clone(),
+                input: vec![candle(0, 100.0)],
+                expected: None,
+            },
+            TestCase {
+                // TC1: known return series of +10%, -10%, +10%
+                vol: daily.clone(),
+                input: vec![
+                    candle(0, 100.0),
+                    candle(1, 110.0),
+                    candle(2, 99.0),
+                    candle(3, 108.9),
+                ],
+                expected: Some((rms(&[up, down, up]), 3, 0)),
+            },
+            TestCase {
+                // TC2: oldest return slides out of the window
+                vol: daily.clone(),
+                input: vec![
+                    candle(0, 100.0),
+                    candle(1, 110.0),
+                    candle(2, 99.0),
+                    candle(3, 108.9),
+                    candle(4, 108.9),
+                ],
+                expected: Some((rms(&[down, up, 0.0]), 3, 0)),
+            },
+            TestCase {
+                // TC3: annualised by the square root of 365 daily intervals
+                vol: daily.clone().annualised(),
+                input: vec![candle(0, 100.0), candle(1, 110.0), candle(2, 99.0)],
+                expected: Some((rms(&[up, down]) * 365.0_f64.sqrt(), 2, 0)),
+            },
+            TestCase {
+                // TC4: gap of a missing interval resets the window
+                vol: daily.clone(),
+                input: vec![
+                    candle(0, 100.0),
+                    candle(1, 110.0),
+                    candle(3, 99.0),
+                    candle(4, 108.9),
+                ],
+                expected: Some((up, 1, 0)),
+            },
+            TestCase {
+                // TC5: return spanning 4 intervals is scaled by the square root of 4
+                vol: daily.clone().gap_policy(VolGapPolicy::Scale),
+                input: vec![candle(0, 100.0), candle(1, 110.0), candle(5, 99.0)],
+                expected: Some((rms(&[up, down / 2.0]), 2, 1)),
+            },
+            TestCase {
+                // TC6: forming, duplicate & non-positive candles are ignored
+                vol: daily,
+                input: vec![
+                    candle(0, 100.0),
+                    Candle {
+                        is_closed: false,
+                        ..candle(1, 500.0)
+                    },
+                    candle(1, 110.0),
+                    candle(1, 120.0),
+                    candle(2, 0.0),
+                ],
+                expected: Some((up, 1, 0)),
+            },
+        ];
+
+        for (index, mut test) in tests.into_iter().enumerate() {
+            for candle in &test.input {
+                test.vol.update(candle);
+            }
+
+            let actual = test
+                .vol
+                .realized_vol()
+                .map(|vol| (vol.volatility, vol.returns, vol.gaps));
+
+            match (actual, test.expected) {
+                (None, None) => {}
+                (Some(actual), Some(expected)) => {
+                    assert!(
+                        (actual.0 - expected.0).abs() < 1e-12,
+                        "TC{index} failed: volatility {} != {}",
+                        actual.0,
+                        expected.0
+                    );
+                    assert_eq!(
+                        (actual.1, actual.2),
+                        (expected.1, expected.2),
+                        "TC{} failed",
+                        index
+                    );
+                }
+                (actual, expected) => {
+                    panic!("TC{index} failed. \nActual: {actual:?}\nExpected: {expected:?}\n")
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_realized_vol_stream_per_instrument() {
+        let btc_usdt = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let eth_usdt = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+
+        let event = |instrument: &Instrument, candle: Candle| MarketEvent {
+            exchange_time: candle.close_time,
+            received_time: candle.close_time,
+            exchange_time_synthesized: false,
+            exchange: Exchange::from(ExchangeId::BinanceSpot),
+            instrument: instrument.clone(),
+            kind: candle,
+        };
+
+        let candles = futures::stream::iter(vec![
+            event(&btc_usdt, candle(0, 100.0)),
+            event(&eth_usdt, candle(0, 10.0)),
+            event(&btc_usdt, candle(1, 110.0)),
+            event(&eth_usdt, candle(1, 9.0)),
+            event(&btc_usdt, candle(2, 99.0)),
+        ]);
+
+        let actual = RealizedVolStream::new(candles, RollingRealizedVol::new(Duration::days(1), 2))
+            .map(|event| (event.instrument, event.kind.returns))
+            .collect::<Vec<_>>()
+            .await;
+
+        let expected = vec![(btc_usdt.clone(), 1), (eth_usdt, 1), (btc_usdt, 2)];
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,370 @@
+use super::warmup::{MarketWarmup, Warmup};
+use crate::{event::MarketEvent, subscription::trade::PublicTrade};
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, VecDeque},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Sliding window over which a [`RollingVwap`] is computed.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum VwapWindow {
+    /// Trades with an `exchange_time` within the [`chrono::Duration`] of the most recent trade.
+    Time(chrono::Duration),
+    /// Most recent trades up to the total traded volume, with the oldest contributing trade
+    /// partially included if required to make up the exact volume.
+    Volume(f64),
+}
+
+/// Volume weighted average price of the [`PublicTrade`]s within a [`VwapWindow`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Vwap {
+    pub price: f64,
+    pub volume: f64,
+    pub trades: usize,
+    pub window_start: DateTime<Utc>,
+}
+
+/// Rolling [`Vwap`] calculator for a single stream of [`PublicTrade`]s.
+///
+/// Running price-volume & volume sums are maintained incrementally, so each update is O(1)
+/// amortised regardless of window size.
+#[derive(Clone, PartialEq, Debug)]
+pub struct RollingVwap {
+    pub window: VwapWindow,
+    trades: VecDeque<WindowTrade>,
+    notional: f64,
+    volume: f64,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct WindowTrade {
+    time: DateTime<Utc>,
+    price: f64,
+    amount: f64,
+}
+
+impl RollingVwap {
+    /// Construct a new empty [`RollingVwap`] over the provided [`VwapWindow`].
+    pub fn new(window: VwapWindow) -> Self {
+        Self {
+            window,
+            trades: VecDeque::new(),
+            notional: 0.0,
+            volume: 0.0,
+        }
+    }
+
+    /// Add a [`PublicTrade`] executed at the provided `time`, evicting any trades that have
+    /// slid out of the [`VwapWindow`], and return the updated [`Vwap`].
+    ///
+    /// Returns `None` if the window contains no volume (eg/ only zero amount trades).
+    pub fn update(&mut self, time: DateTime<Utc>, trade: &PublicTrade) -> Option<Vwap> {
+        self.trades.push_back(WindowTrade {
+            time,
+            price: trade.price,
+            amount: trade.amount,
+        });
+        self.notional += trade.price * trade.amount;
+        self.volume += trade.amount;
+
+        self.evict(time);
+        self.vwap()
+    }
+
+    /// Current [`Vwap`] of the trades within the [`VwapWindow`].
+    pub fn vwap(&self) -> Option<Vwap> {
+        let window_start = self.trades.front()?.time;
+        (self.volume > 0.0).then(|| Vwap {
+            price: self.notional / self.volume,
+            volume: self.volume,
+            trades: self.trades.len(),
+            window_start,
+        })
+    }
+
+    fn evict(&mut self, latest: DateTime<Utc>) {
+        match self.window {
+            VwapWindow::Time(duration) => {
+                while let Some(oldest) = self.trades.front() {
+                    if latest - oldest.time < duration {
+                        break;
+                    }
+                    self.pop_front();
+                }
+            }
+            VwapWindow::Volume(max_volume) => {
+                while let Some(oldest) = self.trades.front() {
+                    if self.volume - oldest.amount < max_volume {
+                        break;
+                    }
+                    self.pop_front();
+                }
+
+                // Partially include the oldest trade to make up the exact window volume
+                let excess = self.volume - max_volume;
+                if let Some(oldest) = self.trades.front_mut().filter(|_| excess > 0.0) {
+                    oldest.amount -= excess;
+                    self.notional -= oldest.price * excess;
+                    self.volume = max_volume;
+                }
+            }
+        }
+
+        // Reset running sums once empty to avoid accumulating floating point error
+        if self.trades.is_empty() {
+            self.notional = 0.0;
+            self.volume = 0.0;
+        }
+    }
+
+    fn pop_front(&mut self) {
+        if let Some(trade) = self.trades.pop_front() {
+            self.notional -= trade.price * trade.amount;
+            self.volume -= trade.amount;
+        }
+    }
+}
+
+/// [`Stream`] adapter that maps every [`MarketEvent<PublicTrade>`] into a
+/// [`MarketEvent<Vwap>`] over a sliding [`VwapWindow`].
+///
+/// A separate [`RollingVwap`] is maintained for each [`Exchange`] & [`Instrument`] combination,
+/// so a stream multiplexing many markets yields an independent [`Vwap`] per market. An optional
+/// [`Warmup`] suppresses the [`Vwap`]s of each market until its window has accumulated enough
+/// trades.
+#[derive(Debug)]
+pub struct VwapStream<St> {
+    pub window: VwapWindow,
+    stream: St,
+    vwaps: HashMap<(Exchange, Instrument), RollingVwap>,
+    warmup: MarketWarmup,
+}
+
+impl<St> VwapStream<St>
+where
+    St: Stream<Item = MarketEvent<PublicTrade>> + Unpin,
+{
+    /// Construct a new [`VwapStream`] computing a [`Vwap`] over the [`VwapWindow`] from the
+    /// provided [`MarketEvent<PublicTrade>`] [`Stream`].
+    pub fn new(stream: St, window: VwapWindow) -> Self {
+        Self {
+            window,
+            stream,
+            vwaps: HashMap::new(),
+            warmup: MarketWarmup::default(),
+        }
+    }
+
+    /// Suppress the [`Vwap`]s of each market during the provided [`Warmup`] period.
+    pub fn warmup(self, warmup: Warmup) -> Self {
+        Self {
+            warmup: MarketWarmup::new(Some(warmup)),
+            ..self
+        }
+    }
+
+    fn update(&mut self, event: MarketEvent<PublicTrade>) -> Option<MarketEvent<Vwap>> {
+        let warm = self.warmup.observe(&event);
+        let window = self.window;
+        let vwap = self
+            .vwaps
+            .entry((event.exchange.clone(), event.instrument.clone()))
+            .or_insert_with(|| RollingVwap::new(window))
+            .update(event.exchange_time, &event.kind)
+            .filter(|_| warm)?;
+
+        Some(MarketEvent {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange_time_synthesized: event.exchange_time_synthesized,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            kind: vwap,
+        })
+    }
+}
+
+impl<St> Stream for VwapStream<St>
+where
+    St: Stream<Item = MarketEvent<PublicTrade>> + Unpin,
+{
+    type Item = MarketEvent<Vwap>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match Pin::new(&mut self.stream).poll_next(cx) {
+                Poll::Ready(Some(event)) => {
+                    if let Some(vwap) = self.update(event) {
+                        return Poll::Ready(Some(vwap));
+                    }
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::ExchangeId;
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+    use futures::StreamExt;
+
+    fn trade(
+        instrument: &Instrument,
+        millis: i64,
+        price: f64,
+        amount: f64,
+    ) -> MarketEvent<PublicTrade> {
+        let time = DateTime::<Utc>::from_timestamp_millis(millis).unwrap();
+        MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange_time_synthesized: false,
+            exchange: Exchange::from(ExchangeId::BinanceSpot),
+            instrument: instrument.clone(),
+            kind: PublicTrade {
+                id: millis.to_string(),
+                price,
+                amount,
+                side: Side::Buy,
+                source: Default::default(),
+                side_source: Default::default(),
+                order_ids: Default::default(),
+                aggregate_count: None,
+            },
+        }
+    }
+
+    fn assert_vwap(actual: Option<Vwap>, price: f64, volume: f64, trades: usize, index: usize) {
+        let actual = actual.unwrap_or_else(|| panic!("TC{index} failed: no Vwap"));
+        assert!(
+            (actual.price - price).abs() < 1e-9,
+            "TC{index} failed: price {} != {price}",
+            actual.price
+        );
+        assert!(
+            (actual.volume - volume).abs() < 1e-9,
+            "TC{index} failed: volume {} != {volume}",
+            actual.volume
+        );
+        assert_eq!(actual.trades, trades, "TC{} failed", index);
+    }
+
+    #[test]
+    fn test_rolling_vwap_time_window() {
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let mut vwap = RollingVwap::new(VwapWindow::Time(chrono::Duration::milliseconds(10)));
+
+        // (millis, price, amount) -> (expected price, volume, trades)
+        let tests = [
+            // TC0: single trade
+            ((0, 100.0, 1.0), (100.0, 1.0, 1)),
+            // TC1: (100*1 + 110*3) / 4
+            ((5, 110.0, 3.0), (107.5, 4.0, 2)),
+            // TC2: trade at 0 slides out, (110*3 + 120*1) / 4
+            ((10, 120.0, 1.0), (112.5, 4.0, 2)),
+            // TC3: trades at 5 & 10 slide out
+            ((25, 90.0, 2.0), (90.0, 2.0, 1)),
+        ];
+
+        for (index, ((millis, price, amount), (exp_price, exp_volume, exp_trades))) in
+            tests.into_iter().enumerate()
+        {
+            let event = trade(&instrument, millis, price, amount);
+            let actual = vwap.update(event.exchange_time, &event.kind);
+            assert_vwap(actual, exp_price, exp_volume, exp_trades, index);
+        }
+    }
+
+    #[test]
+    fn test_rolling_vwap_volume_window() {
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let mut vwap = RollingVwap::new(VwapWindow::Volume(5.0));
+
+        // (millis, price, amount) -> (expected price, volume, trades)
+        let tests = [
+            // TC0: window not yet full
+            ((0, 100.0, 2.0), (100.0, 2.0, 1)),
+            // TC1: (100*2 + 110*2) / 4
+            ((1, 110.0, 2.0), (105.0, 4.0, 2)),
+            // TC2: oldest partially included, (100*1 + 110*2 + 120*2) / 5
+            ((2, 120.0, 2.0), (112.0, 5.0, 3)),
+            // TC3: oldest evicted & next partially included, (110*1 + 120*2 + 130*2) / 5
+            ((3, 130.0, 2.0), (122.0, 5.0, 3)),
+            // TC4: single trade larger than the window
+            ((4, 90.0, 10.0), (90.0, 5.0, 1)),
+        ];
+
+        for (index, ((millis, price, amount), (exp_price, exp_volume, exp_trades))) in
+            tests.into_iter().enumerate()
+        {
+            let event = trade(&instrument, millis, price, amount);
+            let actual = vwap.update(event.exchange_time, &event.kind);
+            assert_vwap(actual, exp_price, exp_volume, exp_trades, index);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_vwap_stream_per_instrument() {
+        let btc_usdt = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let eth_usdt = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+
+        let trades = futures::stream::iter(vec![
+            trade(&btc_usdt, 0, 100.0, 1.0),
+            trade(&eth_usdt, 1, 10.0, 1.0),
+            trade(&btc_usdt, 2, 200.0, 1.0),
+            trade(&eth_usdt, 3, 20.0, 3.0),
+        ]);
+
+        let actual = VwapStream::new(trades, VwapWindow::Volume(10.0))
+            .map(|event| (event.instrument, event.kind.price))
+            .collect::<Vec<_>>()
+            .await;
+
+        let expected = vec![
+            (btc_usdt.clone(), 100.0),
+            (eth_usdt.clone(), 10.0),
+            (btc_usdt, 150.0),
+            (eth_usdt, 17.5),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[tokio::test]
+    async fn test_vwap_stream_warmup() {
+        let btc_usdt = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let eth_usdt = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+
+        let trades = futures::stream::iter(vec![
+            trade(&btc_usdt, 0, 100.0, 1.0),
+            trade(&eth_usdt, 1, 10.0, 1.0),
+            trade(&btc_usdt, 2, 200.0, 1.0),
+            trade(&btc_usdt, 3, 300.0, 2.0),
+            trade(&eth_usdt, 4, 20.0, 3.0),
+        ]);
+
+        let actual = VwapStream::new(trades, VwapWindow::Volume(10.0))
+            .warmup(Warmup::Count(2))
+            .map(|event| (event.instrument, event.kind.price, event.kind.trades))
+            .collect::<Vec<_>>()
+            .await;
+
+        // First trade of each market is suppressed, but still contributes to its window
+        let expected = vec![
+            (btc_usdt.clone(), 150.0, 2),
+            (btc_usdt, 225.0, 3),
+            (eth_usdt, 17.5, 2),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,143 @@
+use crate::event::MarketEvent;
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Period during which a derived combinator suppresses its output, since its values are
+/// unreliable until enough input has accumulated (eg/ a [`Vwap`](super::vwap::Vwap) computed
+/// from a single trade).
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum Warmup {
+    /// Output is suppressed until the provided number of inputs have been observed, including
+    /// the input that ends the warmup.
+    Count(usize),
+    /// Output is suppressed until an input is observed at least the [`chrono::Duration`] after
+    /// the first input.
+    Duration(chrono::Duration),
+}
+
+/// Tracks the [`Warmup`] progress of a single stream of inputs.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct WarmupTracker {
+    pub warmup: Warmup,
+    observed: usize,
+    first: Option<DateTime<Utc>>,
+    warm: bool,
+}
+
+impl WarmupTracker {
+    /// Construct a new [`WarmupTracker`] that has not yet observed any inputs.
+    pub fn new(warmup: Warmup) -> Self {
+        Self {
+            warmup,
+            observed: 0,
+            first: None,
+            warm: false,
+        }
+    }
+
+    /// Observe an input at the provided `time`, returning true if the [`Warmup`] has ended.
+    pub fn observe(&mut self, time: DateTime<Utc>) -> bool {
+        if self.warm {
+            return true;
+        }
+
+        self.observed += 1;
+        let first = *self.first.get_or_insert(time);
+
+        self.warm = match self.warmup {
+            Warmup::Count(count) => self.observed >= count,
+            Warmup::Duration(duration) => time - first >= duration,
+        };
+        self.warm
+    }
+
+    /// Determine if the [`Warmup`] has ended.
+    pub fn is_warm(&self) -> bool {
+        self.warm
+    }
+}
+
+/// Optional [`Warmup`] applied independently to each [`Exchange`] & [`Instrument`] combination
+/// of a stream multiplexing many markets.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct MarketWarmup {
+    pub warmup: Option<Warmup>,
+    trackers: HashMap<(Exchange, Instrument), WarmupTracker>,
+}
+
+impl MarketWarmup {
+    /// Construct a new [`MarketWarmup`] applying the optional [`Warmup`] to every market.
+    pub fn new(warmup: Option<Warmup>) -> Self {
+        Self {
+            warmup,
+            trackers: HashMap::new(),
+        }
+    }
+
+    /// Observe the input [`MarketEvent<T>`] at its `exchange_time`, returning true if output
+    /// for its market should be emitted. Always true if no [`Warmup`] is configured.
+    pub fn observe<T>(&mut self, event: &MarketEvent<T>) -> bool {
+        let Some(warmup) = self.warmup else {
+            return true;
+        };
+
+        self.trackers
+            .entry((event.exchange.clone(), event.instrument.clone()))
+            .or_insert_with(|| WarmupTracker::new(warmup))
+            .observe(event.exchange_time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_warmup_tracker_observe() {
+        struct TestCase {
+            warmup: Warmup,
+            input: Vec<i64>,
+            expected: Vec<bool>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: output suppressed until the third input
+                warmup: Warmup::Count(3),
+                input: vec![0, 1, 2, 3],
+                expected: vec![false, false, true, true],
+            },
+            TestCase {
+                // TC1: zero count is warm from the first input
+                warmup: Warmup::Count(0),
+                input: vec![0],
+                expected: vec![true],
+            },
+            TestCase {
+                // TC2: output suppressed until 10ms after the first input
+                warmup: Warmup::Duration(chrono::Duration::milliseconds(10)),
+                input: vec![5, 10, 15, 16],
+                expected: vec![false, false, true, true],
+            },
+            TestCase {
+                // TC3: once warm, out of order inputs remain warm
+                warmup: Warmup::Duration(chrono::Duration::milliseconds(10)),
+                input: vec![0, 10, 1],
+                expected: vec![false, true, true],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut tracker = WarmupTracker::new(test.warmup);
+            let actual = test
+                .input
+                .into_iter()
+                .map(|millis| tracker.observe(DateTime::from_timestamp_millis(millis).unwrap()))
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
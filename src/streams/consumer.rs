@@ -1,98 +1,430 @@
 use crate::{
     error::DataError,
     event::MarketEvent,
-    exchange::StreamSelector,
-    subscription::{SubKind, Subscription},
+    exchange::{Connector, ExchangeId, StreamSelector},
+    streams::{
+        health::ConnectionTracker,
+        lifecycle::LifecycleEvent,
+        reconnect::{self, ErrorClass, ReconnectPolicy},
+        subscriptions::SubscriptionSet,
+    },
+    subscriber::config::ConnectionConfig,
+    subscription::{is_wildcard, SubKind, Subscription},
     Identifier, MarketStream,
 };
+use barter_integration::error::SocketError;
+use barter_integration::model::instrument::Instrument;
 use futures::StreamExt;
-use std::time::Duration;
-use tokio::sync::mpsc;
-use tracing::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+use tokio::sync::mpsc::{
+    self,
+    error::{SendError, TrySendError},
+};
+use tracing::{debug, error, info, warn};
 
 /// Initial duration that the [`consume`] function should wait after disconnecting before attempting
-/// to re-initialise a [`MarketStream`]. This duration will increase exponentially as a result
-/// of repeated disconnections with re-initialisation failures.
+/// to re-initialise a [`MarketStream`].
+#[deprecated(
+    note = "re-connection backoff is now configured via ReconnectPolicy, see reconnect::STARTING_RECONNECT_BACKOFF"
+)]
 pub const STARTING_RECONNECT_BACKOFF_MS: u64 = 125;
 
+/// Configures how the consumer loop handles an inbound message that fails to deserialise (see
+/// [`DataError::is_deserialise`]), eg/ a malformed or unrecognised exchange payload.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum DeserializeErrorPolicy {
+    /// Log the error & continue consuming the [`MarketStream`].
+    #[default]
+    Skip,
+    /// Send a [`LifecycleEvent::DeserializeError`] via the optional `lifecycle_tx` & continue
+    /// consuming the [`MarketStream`].
+    Emit,
+    /// End the consumer loop, returning the error without attempting re-connection.
+    Fail,
+}
+
+/// Configures how the consumer loop handles a consumed [`MarketEvent<T>`](MarketEvent) when the
+/// exchange receiver is full (ie/ the receiver is lagging behind the exchange).
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum LagPolicy {
+    /// Wait for receiver capacity before consuming the next message, so no
+    /// [`MarketEvent<T>`](MarketEvent) is lost.
+    ///
+    /// Note that a stalled receiver also stalls the [`MarketStream`] being consumed (eg/ delaying
+    /// heartbeats until the exchange disconnects).
+    #[default]
+    Backpressure,
+    /// Drop the newest [`MarketEvent<T>`](MarketEvent) rather than wait for receiver capacity, &
+    /// continue consuming the [`MarketStream`]. Every lag is logged & reported via a
+    /// [`LifecycleEvent::Lagged`] sent to the optional `lifecycle_tx`.
+    DropNewest,
+}
+
 /// Central [`MarketEvent<T>`](MarketEvent) consumer loop.
 ///
-/// Initialises an exchange [`MarketStream`] using a collection of [`Subscription`]s. Consumed
-/// events are distributed downstream via the `exchange_tx mpsc::UnboundedSender`. A re-connection
-/// mechanism with an exponential backoff policy is utilised to ensure maximum up-time.
-pub async fn consume<Exchange, Kind>(
-    subscriptions: Vec<Subscription<Exchange, Kind>>,
-    exchange_tx: mpsc::UnboundedSender<MarketEvent<Kind::Event>>,
+/// Initialises an exchange [`MarketStream`] using a [`SubscriptionSet`]. Consumed
+/// events are distributed downstream via the bounded `exchange_tx` [`mpsc::Sender`], applying
+/// the [`ConnectionConfig::lag_policy`] whenever it is full. A re-connection
+/// mechanism driven by the provided [`ReconnectPolicy`] is utilised to ensure maximum up-time.
+///
+/// If the exchange signals it is closed for maintenance (see
+/// [`Connector::is_maintenance`](crate::exchange::Connector::is_maintenance)), a
+/// [`LifecycleEvent::Maintenance`] is sent via the optional `lifecycle_tx` and re-connection is
+/// paused for the [`ReconnectPolicy::maintenance_delay`]. Any other
+/// [`DataError::ExchangeStatus`] is sent via the `lifecycle_tx` as a [`LifecycleEvent::Status`].
+///
+/// Every (re)initialisation subscribes to a fresh snapshot of the [`SubscriptionSet`], so any
+/// [`Subscription`]s added or removed at runtime are reflected upon re-connection.
+///
+/// Any [`DataError`] classified by
+/// [`Connector::classify_error`](crate::exchange::Connector::classify_error) as
+/// [`ErrorClass::Terminal`] ends the consumer loop, whereas an [`ErrorClass::Retryable`]
+/// [`DataError`] re-initialises the [`MarketStream`] (including a failed first initialisation).
+///
+/// Messages that fail to deserialise are handled according to the
+/// [`ConnectionConfig::deserialize_error_policy`].
+///
+/// The [`ConnectionHealth`](crate::streams::health::ConnectionHealth) of the connection is
+/// recorded via the provided [`ConnectionTracker`].
+///
+/// If `filter_instruments` is true, any consumed [`MarketEvent<T>`](MarketEvent) for an
+/// [`Instrument`](barter_integration::model::instrument::Instrument) that is not in the
+/// current [`Subscription`]s is dropped rather than distributed downstream, unless any
+/// [`WILDCARD`](crate::subscription::WILDCARD) [`Subscription`] exists.
+///
+/// If the [`ConnectionConfig::symbol_filter`] is set, [`Subscription`]s for denied
+/// [`Instrument`](barter_integration::model::instrument::Instrument)s are excluded from every
+/// (re)initialisation, and consumed [`MarketEvent<T>`](MarketEvent)s for denied
+/// [`Instrument`](barter_integration::model::instrument::Instrument)s expanded from a
+/// [`WILDCARD`](crate::subscription::WILDCARD) [`Subscription`] are dropped.
+///
+/// Consumed [`MarketEvent<T>`](MarketEvent)s that are not retained (see [`SubKind::retain`]) by
+/// any [`Subscription`] of their [`Instrument`] are also dropped.
+///
+/// If the [`ConnectionConfig::ordering`] is set, consumed [`MarketEvent<T>`](MarketEvent)s are
+/// distributed via the shared [`InstrumentOrdering`](super::ordering::InstrumentOrdering), which
+/// drops any event older than the last distributed event of the same
+/// [`Instrument`](barter_integration::model::instrument::Instrument).
+///
+/// If the [`ConnectionConfig::rate_limits`] is set, the
+/// [`RateLimitUsage`](super::ratelimit::RateLimitUsage) reported by any exchange throttle notice
+/// (see [`Connector::rate_limit_usage`](crate::exchange::Connector::rate_limit_usage)) is
+/// recorded in the shared [`RateLimitMonitor`](super::ratelimit::RateLimitMonitor).
+pub async fn consume<Exchange, Kind, Subs>(
+    subscriptions: Subs,
+    config: ConnectionConfig,
+    reconnect_policy: Arc<dyn ReconnectPolicy>,
+    lifecycle_tx: Option<mpsc::UnboundedSender<LifecycleEvent>>,
+    health: ConnectionTracker,
+    filter_instruments: bool,
+    exchange_tx: mpsc::Sender<MarketEvent<Kind::Event>>,
 ) -> DataError
 where
     Exchange: StreamSelector<Kind>,
     Kind: SubKind,
-    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    Subs: Into<SubscriptionSet<Exchange, Kind>>,
+    Subscription<Exchange, Kind>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market> + Ord,
 {
     // Determine ExchangeId associated with these Subscriptions
     let exchange = Exchange::ID;
+    let subscriptions = subscriptions.into();
 
     info!(
         %exchange,
         ?subscriptions,
-        policy = "retry connection with exponential backoff",
+        ?reconnect_policy,
         "MarketStream consumer loop running",
     );
 
     // Consumer loop retry parameters
     let mut attempt: u32 = 0;
-    let mut backoff_ms: u64 = STARTING_RECONNECT_BACKOFF_MS;
+    let mut initialised_once = false;
 
     loop {
-        // Increment retry parameters at start of every iteration
-        attempt += 1;
-        backoff_ms *= 2;
         info!(%exchange, attempt, "attempting to initialise MarketStream");
 
+        // Snapshot the current Subscriptions, including any runtime additions or removals,
+        // excluding any for Instruments denied by the SymbolFilter
+        let current = match &config.symbol_filter {
+            Some(symbol_filter) => symbol_filter.retain(subscriptions.snapshot()),
+            None => subscriptions.snapshot(),
+        };
+
+        // Determine the subscribed Instruments used to filter inbound MarketEvents, if enabled
+        // and no wildcard Subscription exists that yields MarketEvents for any Instrument
+        let subscribed_instruments = filter_instruments
+            .then(|| {
+                current
+                    .iter()
+                    .map(|subscription| subscription.instrument.clone())
+                    .collect::<HashSet<_>>()
+            })
+            .filter(|instruments| !instruments.iter().any(is_wildcard));
+
+        // Determine the SubKinds of each Instrument used to filter inbound MarketEvents, if the
+        // SubKind declares parameters that may reject them (eg/ a minimum trade size)
+        let retain_kinds = Kind::FILTERED.then(|| {
+            current.iter().fold(
+                HashMap::<Instrument, Vec<Kind>>::new(),
+                |mut kinds, subscription| {
+                    kinds
+                        .entry(subscription.instrument.clone())
+                        .or_default()
+                        .push(subscription.kind.clone());
+                    kinds
+                },
+            )
+        });
+
         // Attempt to initialise MarketStream: if it fails on first attempt return DataError
-        let mut stream = match Exchange::Stream::init(&subscriptions).await {
+        let mut stream = match Exchange::Stream::init(&current, &config).await {
             Ok(stream) => {
                 info!(%exchange, attempt, "successfully initialised MarketStream");
+                health.validated(current.len());
+                initialised_once = true;
                 attempt = 0;
-                backoff_ms = STARTING_RECONNECT_BACKOFF_MS;
                 stream
             }
+            Err(error) if Exchange::is_maintenance(&error) => {
+                health.reconnecting(attempt);
+                pause_for_maintenance(
+                    exchange,
+                    reconnect_policy.as_ref(),
+                    lifecycle_tx.as_ref(),
+                    &error,
+                )
+                .await;
+                continue;
+            }
             Err(error) => {
                 error!(%exchange, attempt, ?error, "failed to initialise MarketStream");
+                record_rate_limit::<Exchange>(&config, &error);
 
-                // Exit function function if Stream::init failed the first attempt, else retry
-                if attempt == 1 {
+                // Exit function if Stream::init failed with a terminal error, or failed the
+                // first attempt with an error not known to be retryable, else retry
+                let class = Exchange::classify_error(&error);
+                if class == Some(ErrorClass::Terminal)
+                    || (!initialised_once && class != Some(ErrorClass::Retryable))
+                {
+                    health.dead();
                     return error;
-                } else {
+                }
+
+                attempt += 1;
+                health.reconnecting(attempt);
+                if reconnect::wait(reconnect_policy.as_ref(), attempt, Some(&error)).await {
                     continue;
+                } else {
+                    error!(%exchange, attempt, "ReconnectPolicy gave up re-initialising MarketStream");
+                    health.dead();
+                    return error;
                 }
             }
         };
 
         // Consume Result<MarketEvent<T>, DataError> from MarketStream
+        let mut disconnect_error = None;
+        let mut maintenance = false;
+        let mut lagging = false;
         while let Some(event_result) = stream.next().await {
+            // Record the message time, reusing the MarketEvent received_time where available
+            health.message(match &event_result {
+                Ok(market_event) => market_event.received_time,
+                Err(_) => chrono::Utc::now(),
+            });
+            if let Err(error) = &event_result {
+                record_rate_limit::<Exchange>(&config, error);
+            }
             match event_result {
-                // If Ok: send MarketEvent<T> to exchange receiver
+                // If Ok & not subscribed to the MarketEvent<T> Instrument: drop MarketEvent<T>
+                Ok(market_event)
+                    if subscribed_instruments.as_ref().is_some_and(|instruments| {
+                        !instruments.contains(&market_event.instrument)
+                    }) =>
+                {
+                    debug!(
+                        %exchange,
+                        instrument = %market_event.instrument,
+                        action = "dropping event",
+                        "consumed MarketEvent for an Instrument that is not subscribed to",
+                    );
+                    continue;
+                }
+
+                // If Ok & Instrument denied by the SymbolFilter (eg/ expanded from a wildcard
+                // Subscription): drop MarketEvent<T>
+                Ok(market_event)
+                    if config.symbol_filter.as_ref().is_some_and(|symbol_filter| {
+                        !symbol_filter.is_allowed(&market_event.instrument)
+                    }) =>
+                {
+                    debug!(
+                        %exchange,
+                        instrument = %market_event.instrument,
+                        action = "dropping event",
+                        "consumed MarketEvent for an Instrument denied by the SymbolFilter",
+                    );
+                    continue;
+                }
+
+                // If Ok & not retained by the Subscription SubKind: drop MarketEvent<T>
+                Ok(market_event)
+                    if retain_kinds
+                        .as_ref()
+                        .is_some_and(|kinds| !is_retained(kinds, &market_event)) =>
+                {
+                    debug!(
+                        %exchange,
+                        instrument = %market_event.instrument,
+                        action = "dropping event",
+                        "consumed MarketEvent that is not retained by the Subscription SubKind",
+                    );
+                    continue;
+                }
+
+                // If Ok: send MarketEvent<T> to exchange receiver according to the LagPolicy
                 Ok(market_event) => {
-                    let _ = exchange_tx.send(market_event).map_err(|err| {
-                        error!(
-                            payload = ?err.0,
-                            why = "receiver dropped",
-                            "failed to send Event<MarketData> to Exchange receiver"
-                        );
-                    });
+                    let sent = match (config.lag_policy, &config.ordering) {
+                        (LagPolicy::Backpressure, Some(ordering)) => ordering
+                            .send(&exchange_tx, market_event)
+                            .await
+                            .map_err(|SendError(event)| TrySendError::Closed(event)),
+                        (LagPolicy::Backpressure, None) => exchange_tx
+                            .send(market_event)
+                            .await
+                            .map_err(|SendError(event)| TrySendError::Closed(event)),
+                        (LagPolicy::DropNewest, Some(ordering)) => {
+                            ordering.try_send(&exchange_tx, market_event).await
+                        }
+                        (LagPolicy::DropNewest, None) => exchange_tx.try_send(market_event),
+                    };
+                    match sent {
+                        Ok(()) => lagging = false,
+                        // If exchange receiver is full: drop MarketEvent<T> & report the lag
+                        Err(TrySendError::Full(_)) if lagging => {}
+                        Err(TrySendError::Full(_)) => {
+                            lagging = true;
+                            warn!(
+                                %exchange,
+                                policy = ?config.lag_policy,
+                                action = "dropping events until the receiver has capacity",
+                                "exchange receiver is lagging behind the MarketStream",
+                            );
+                            if let Some(lifecycle_tx) = &lifecycle_tx {
+                                let _ = lifecycle_tx.send(LifecycleEvent::Lagged { exchange });
+                            }
+                        }
+                        Err(TrySendError::Closed(event)) => {
+                            error!(
+                                payload = ?event,
+                                why = "receiver dropped",
+                                "failed to send Event<MarketData> to Exchange receiver"
+                            );
+                        }
+                    }
                 }
-                // If terminal DataError: break
-                Err(error) if error.is_terminal() => {
+                // If exchange maintenance signal: break
+                Err(error) if Exchange::is_maintenance(&error) => {
+                    health.reconnecting(attempt);
+                    pause_for_maintenance(
+                        exchange,
+                        reconnect_policy.as_ref(),
+                        lifecycle_tx.as_ref(),
+                        &error,
+                    )
+                    .await;
+                    maintenance = true;
+                    break;
+                }
+
+                // If exchange status signal: notify & continue
+                Err(DataError::ExchangeStatus { status, .. }) => {
+                    warn!(
+                        %exchange,
+                        ?status,
+                        action = "emitting LifecycleEvent",
+                        "exchange signalled a change in status",
+                    );
+                    if let Some(lifecycle_tx) = &lifecycle_tx {
+                        let _ = lifecycle_tx.send(LifecycleEvent::Status { exchange, status });
+                    }
+                    continue;
+                }
+
+                // If DataError classified as terminal by the exchange: end consumer loop
+                Err(error) if Exchange::classify_error(&error) == Some(ErrorClass::Terminal) => {
+                    error!(
+                        %exchange,
+                        %error,
+                        action = "ending MarketStream",
+                        "consumed terminal DataError from MarketStream",
+                    );
+                    health.dead();
+                    return error;
+                }
+
+                // If terminal or retryable DataError: break
+                Err(error)
+                    if error.is_terminal()
+                        || Exchange::classify_error(&error) == Some(ErrorClass::Retryable) =>
+                {
                     error!(
                         %exchange,
                         %error,
                         action = "re-initialising Stream",
                         "consumed DataError from MarketStream",
                     );
+                    disconnect_error = Some(error);
                     break;
                 }
 
+                // If deserialisation DataError: apply the DeserializeErrorPolicy
+                Err(error) if error.is_deserialise() => match config.deserialize_error_policy {
+                    DeserializeErrorPolicy::Skip => {
+                        warn!(
+                            %exchange,
+                            %error,
+                            action = "skipping message",
+                            "consumed deserialisation DataError from MarketStream",
+                        );
+                        continue;
+                    }
+                    DeserializeErrorPolicy::Emit => {
+                        warn!(
+                            %exchange,
+                            %error,
+                            action = "skipping message & emitting LifecycleEvent",
+                            "consumed deserialisation DataError from MarketStream",
+                        );
+                        if let Some(lifecycle_tx) = &lifecycle_tx {
+                            let _ = lifecycle_tx.send(LifecycleEvent::DeserializeError {
+                                exchange,
+                                error: error.to_string(),
+                            });
+                        }
+                        continue;
+                    }
+                    DeserializeErrorPolicy::Fail => {
+                        error!(
+                            %exchange,
+                            %error,
+                            action = "ending MarketStream",
+                            "consumed deserialisation DataError from MarketStream",
+                        );
+                        health.dead();
+                        return error;
+                    }
+                },
+
                 // If non-terminal DataError: log & continue
                 Err(error) => {
                     warn!(
@@ -106,13 +438,720 @@ where
             }
         }
 
-        // If MarketStream ends unexpectedly, attempt re-connection after backoff_ms
+        // If exchange maintenance already paused re-connection, re-initialise immediately
+        if maintenance {
+            continue;
+        }
+
+        // If MarketStream ends unexpectedly, attempt re-connection after ReconnectPolicy delay
+        attempt += 1;
+        health.reconnecting(attempt);
         warn!(
             %exchange,
-            backoff_ms,
-            action = "attempt re-connection after backoff",
+            attempt,
+            action = "attempt re-connection after ReconnectPolicy delay",
             "exchange MarketStream unexpectedly ended"
         );
-        tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
+        if !reconnect::wait(
+            reconnect_policy.as_ref(),
+            attempt,
+            disconnect_error.as_ref(),
+        )
+        .await
+        {
+            error!(%exchange, attempt, "ReconnectPolicy gave up re-initialising MarketStream");
+            health.dead();
+            return disconnect_error.unwrap_or_else(|| {
+                DataError::from(SocketError::Terminated(format!(
+                    "{exchange} MarketStream ended & ReconnectPolicy gave up re-connecting"
+                )))
+            });
+        }
+    }
+}
+
+/// Determine if any [`SubKind`] subscribed to for the [`MarketEvent<T>`](MarketEvent)
+/// [`Instrument`] retains it, falling back to every [`SubKind`] if the [`Instrument`] is not
+/// subscribed to directly (eg/ a wildcard [`Subscription`]).
+fn is_retained<Kind>(
+    kinds: &HashMap<Instrument, Vec<Kind>>,
+    event: &MarketEvent<Kind::Event>,
+) -> bool
+where
+    Kind: SubKind,
+{
+    match kinds.get(&event.instrument) {
+        Some(kinds) => kinds.iter().any(|kind| kind.retain(&event.kind)),
+        None => kinds
+            .values()
+            .flatten()
+            .any(|kind| kind.retain(&event.kind)),
+    }
+}
+
+/// Record the [`RateLimitUsage`](super::ratelimit::RateLimitUsage) reported by the
+/// [`DataError`] in the [`ConnectionConfig::rate_limits`] monitor, if it is an exchange throttle
+/// notice & the monitor is set.
+fn record_rate_limit<Exchange>(config: &ConnectionConfig, error: &DataError)
+where
+    Exchange: Connector,
+{
+    let (Some(rate_limits), Some(usage)) = (&config.rate_limits, Exchange::rate_limit_usage(error))
+    else {
+        return;
+    };
+
+    warn!(
+        exchange = %usage.exchange,
+        %error,
+        "exchange signalled the connection has reached its rate limit",
+    );
+    rate_limits.record(usage);
+}
+
+/// Notify the optional `lifecycle_tx` that the exchange is closed for maintenance, and wait the
+/// [`ReconnectPolicy::maintenance_delay`] before the consumer loop attempts to re-initialise.
+async fn pause_for_maintenance(
+    exchange: ExchangeId,
+    reconnect_policy: &dyn ReconnectPolicy,
+    lifecycle_tx: Option<&mpsc::UnboundedSender<LifecycleEvent>>,
+    error: &DataError,
+) {
+    let delay = reconnect_policy.maintenance_delay();
+    warn!(
+        %exchange,
+        %error,
+        ?delay,
+        action = "pausing re-connection",
+        "exchange signalled it is closed for maintenance",
+    );
+
+    if let Some(lifecycle_tx) = lifecycle_tx {
+        let _ = lifecycle_tx.send(LifecycleEvent::Maintenance { exchange });
+    }
+
+    tokio::time::sleep(delay).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{okx::Okx, subscription::ExchangeSub, Connector, ExchangeId},
+        streams::{
+            health::HealthMonitor,
+            ratelimit::RateLimitMonitor,
+            reconnect::{FixedDelay, MAINTENANCE_RECONNECT_BACKOFF},
+        },
+        subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+        subscription::trade::{PublicTrade, PublicTrades},
+        test_util::{MockExchange, MockScript},
+    };
+    use async_trait::async_trait;
+    use barter_integration::{
+        model::instrument::{kind::InstrumentKind, Instrument},
+        protocol::websocket::WsMessage,
+    };
+    use futures::Stream;
+    use serde::{Deserialize, Serialize};
+    use std::{
+        pin::Pin,
+        task::{Context, Poll},
+        time::Duration,
+    };
+    use url::Url;
+
+    /// [`Okx`] "trades" subscription ack for "BTC-USDT".
+    const OKX_BTC_TRADES_ACK: &str =
+        r#"{"event":"subscribe","arg":{"channel":"trades","instId":"BTC-USDT"}}"#;
+
+    /// [`Okx`] "trades" subscription ack for the provided "instId".
+    fn okx_trades_ack(inst_id: &str) -> String {
+        format!(r#"{{"event":"subscribe","arg":{{"channel":"trades","instId":"{inst_id}"}}}}"#)
+    }
+
+    /// [`Okx`] subscription error response w/ the provided code.
+    fn okx_error(code: &str) -> String {
+        format!(r#"{{"event":"error","code":"{code}","msg":"mock"}}"#)
+    }
+
+    /// [`Okx`] "BTC-USDT" trade w/ the provided trade id.
+    fn okx_btc_trade(id: &str) -> String {
+        format!(
+            r#"{{"arg":{{"channel":"trades","instId":"BTC-USDT"}},"data":[{{"instId":"BTC-USDT","tradeId":"{id}","px":"42219.9","sz":"0.1","side":"buy","ts":"1630048897897"}}]}}"#
+        )
+    }
+
+    fn okx_trades(base: &str) -> Subscription<Okx, PublicTrades> {
+        Subscription::from((Okx, base, "usdt", InstrumentKind::Spot, PublicTrades))
+    }
+
+    /// Okx "trades" "instId"s of each subscription request received by the [`MockExchange`].
+    async fn next_requested_inst_ids(exchange: &mut MockExchange) -> Vec<String> {
+        let WsMessage::Text(request) = exchange.next_request().await.unwrap().message else {
+            panic!("Okx subscription request is not a text WsMessage")
+        };
+        serde_json::from_str::<serde_json::Value>(&request).unwrap()["args"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|arg| arg["instId"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    /// [`ReconnectPolicy`] that never re-connects, but re-initialises after the provided
+    /// maintenance delay.
+    #[derive(Debug)]
+    struct MaintenanceDelay(Duration);
+
+    impl ReconnectPolicy for MaintenanceDelay {
+        fn next_delay(&self, _: u32, _: Option<&DataError>) -> Option<Duration> {
+            None
+        }
+
+        fn maintenance_delay(&self) -> Duration {
+            self.0
+        }
+    }
+
+    #[tokio::test]
+    async fn test_consume_pauses_for_exchange_maintenance() {
+        // Default maintenance delay is longer than any regular re-connection backoff
+        let reconnect_policy = FixedDelay {
+            delay: Duration::from_secs(1),
+            max_attempts: Some(0),
+        };
+        assert_eq!(
+            reconnect_policy.maintenance_delay(),
+            MAINTENANCE_RECONNECT_BACKOFF
+        );
+
+        // Okx rejects every subscription w/ the "Service temporarily unavailable" code
+        let exchange = MockExchange::start(
+            (0..2).map(|_| MockScript::new().receive().send_text(okx_error("50001"))),
+        )
+        .await
+        .unwrap();
+        let (lifecycle_tx, mut lifecycle_rx) = mpsc::unbounded_channel();
+        let (exchange_tx, _exchange_rx) = mpsc::channel(1);
+
+        let delay = Duration::from_millis(200);
+        let consumer = tokio::spawn(consume(
+            vec![okx_trades("btc")],
+            ConnectionConfig::default().url(exchange.url()),
+            Arc::new(MaintenanceDelay(delay)),
+            Some(lifecycle_tx),
+            HealthMonitor::default().register(ExchangeId::Okx),
+            true,
+            exchange_tx,
+        ));
+
+        let first = lifecycle_rx.recv().await.unwrap();
+        let start = tokio::time::Instant::now();
+        let second = lifecycle_rx.recv().await.unwrap();
+
+        // Maintenance re-initialises after the maintenance delay, despite never re-connecting
+        let expected = LifecycleEvent::Maintenance {
+            exchange: ExchangeId::Okx,
+        };
+        assert_eq!(first, expected);
+        assert_eq!(second, expected);
+        assert!(start.elapsed() >= delay);
+        assert_eq!(exchange.connections(), 2);
+        assert!(!consumer.is_finished());
+
+        consumer.abort();
+    }
+
+    /// Mock exchange that yields [`MarketEvent<PublicTrade>`]s for "btc_usdt" & "eth_usdt",
+    /// regardless of the [`Subscription`]s.
+    ///
+    /// Every exchange [`MarketStream`] already discards messages of un-subscribed markets, so the
+    /// consumer loop [`Instrument`] filters can only be exercised by a stub [`MarketStream`].
+    #[derive(
+        Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+    )]
+    struct ArrayChannelExchange;
+
+    impl Connector for ArrayChannelExchange {
+        const ID: ExchangeId = ExchangeId::BinanceSpot;
+        type Channel = String;
+        type Market = String;
+        type Subscriber = WebSocketSubscriber;
+        type SubValidator = WebSocketSubValidator;
+        type SubResponse = crate::exchange::okx::subscription::OkxSubResponse;
+
+        fn url() -> Result<Url, SocketError> {
+            Url::parse("ws://localhost").map_err(SocketError::UrlParse)
+        }
+
+        fn requests(_: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+            vec![]
+        }
+    }
+
+    impl StreamSelector<PublicTrades> for ArrayChannelExchange {
+        type Stream = ArrayChannelStream;
+    }
+
+    impl Identifier<String> for Subscription<ArrayChannelExchange, PublicTrades> {
+        fn id(&self) -> String {
+            String::from("trades")
+        }
+    }
+
+    #[derive(Debug)]
+    struct ArrayChannelStream(Vec<MarketEvent<PublicTrade>>);
+
+    impl Stream for ArrayChannelStream {
+        type Item = Result<MarketEvent<PublicTrade>, DataError>;
+
+        fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            Poll::Ready(self.0.pop().map(Ok))
+        }
+    }
+
+    #[async_trait]
+    impl MarketStream<ArrayChannelExchange, PublicTrades> for ArrayChannelStream {
+        async fn init(
+            _: &[Subscription<ArrayChannelExchange, PublicTrades>],
+            _: &ConnectionConfig,
+        ) -> Result<Self, DataError> {
+            let trade = |base: &str, id: &str| MarketEvent {
+                exchange_time: Default::default(),
+                received_time: Default::default(),
+                exchange_time_synthesized: false,
+                exchange: barter_integration::model::Exchange::from(ExchangeId::BinanceSpot),
+                instrument: Instrument::from((base, "usdt", InstrumentKind::Spot)),
+                kind: PublicTrade {
+                    id: id.to_string(),
+                    price: 1.0,
+                    amount: 1.0,
+                    side: barter_integration::model::Side::Buy,
+                    source: Default::default(),
+                    side_source: Default::default(),
+                    order_ids: Default::default(),
+                    aggregate_count: None,
+                },
+            };
+
+            // Events are popped from the back of the Vec
+            Ok(Self(vec![
+                trade("btc", "2"),
+                trade("eth", "1"),
+                trade("btc", "0"),
+            ]))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_consume_filters_events_for_unsubscribed_instruments() {
+        struct TestCase {
+            filter_instruments: bool,
+            expected: Vec<&'static str>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: default filter drops the off-subscription eth_usdt event
+                filter_instruments: true,
+                expected: vec!["0", "2"],
+            },
+            TestCase {
+                // TC1: opted-out filter yields every event
+                filter_instruments: false,
+                expected: vec!["0", "1", "2"],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (exchange_tx, mut exchange_rx) = mpsc::channel(10);
+
+            // Never re-connect once the mock stream ends
+            let reconnect_policy = FixedDelay {
+                delay: Duration::from_secs(1),
+                max_attempts: Some(0),
+            };
+
+            let _ = consume(
+                vec![Subscription::from((
+                    ArrayChannelExchange,
+                    "btc",
+                    "usdt",
+                    InstrumentKind::Spot,
+                    PublicTrades,
+                ))],
+                ConnectionConfig::default(),
+                Arc::new(reconnect_policy),
+                None,
+                HealthMonitor::default().register(ExchangeId::Okx),
+                test.filter_instruments,
+                exchange_tx,
+            )
+            .await;
+
+            let mut actual = vec![];
+            while let Some(event) = exchange_rx.recv().await {
+                actual.push(event.kind.id);
+            }
+
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_consume_applies_symbol_filter_to_wildcard_expansion() {
+        use crate::subscription::{
+            filter::{SymbolFilter, SymbolMatcher},
+            WILDCARD,
+        };
+
+        struct TestCase {
+            symbol_filter: SymbolFilter,
+            expected: Vec<&'static str>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: denied eth_usdt events expanded from the wildcard are excluded
+                symbol_filter: SymbolFilter::new().deny(SymbolMatcher::substring("eth_")),
+                expected: vec!["0", "2"],
+            },
+            TestCase {
+                // TC1: only allowed eth_usdt events expanded from the wildcard are included
+                symbol_filter: SymbolFilter::new().allow(SymbolMatcher::regex("^eth_").unwrap()),
+                expected: vec!["1"],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (exchange_tx, mut exchange_rx) = mpsc::channel(10);
+
+            // Never re-connect once the mock stream ends
+            let reconnect_policy = FixedDelay {
+                delay: Duration::from_secs(1),
+                max_attempts: Some(0),
+            };
+
+            let _ = consume(
+                vec![Subscription::from((
+                    ArrayChannelExchange,
+                    WILDCARD,
+                    WILDCARD,
+                    InstrumentKind::Spot,
+                    PublicTrades,
+                ))],
+                ConnectionConfig::default().symbol_filter(test.symbol_filter),
+                Arc::new(reconnect_policy),
+                None,
+                HealthMonitor::default().register(ExchangeId::Okx),
+                true,
+                exchange_tx,
+            )
+            .await;
+
+            let mut actual = vec![];
+            while let Some(event) = exchange_rx.recv().await {
+                actual.push(event.kind.id);
+            }
+
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_consume_default_lag_policy_is_lossless() {
+        assert_eq!(
+            ConnectionConfig::default().lag_policy,
+            LagPolicy::Backpressure
+        );
+
+        // Exchange sends more trades than the single capacity receiver can buffer
+        let exchange = MockExchange::start([MockScript::subscribe_then_close(
+            [OKX_BTC_TRADES_ACK.to_string()],
+            ["0", "1", "2"].map(okx_btc_trade),
+        )])
+        .await
+        .unwrap();
+        let (exchange_tx, mut exchange_rx) = mpsc::channel(1);
+
+        let consumer = tokio::spawn(consume(
+            vec![okx_trades("btc")],
+            ConnectionConfig::default().url(exchange.url()),
+            Arc::new(FixedDelay {
+                delay: Duration::from_secs(1),
+                max_attempts: Some(0),
+            }),
+            None,
+            HealthMonitor::default().register(ExchangeId::Okx),
+            true,
+            exchange_tx,
+        ));
+
+        // Receiver lags behind the exchange, but every trade is delivered once polled
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let mut actual = vec![];
+        for _ in 0..3 {
+            let trade = tokio::time::timeout(Duration::from_secs(5), exchange_rx.recv())
+                .await
+                .unwrap()
+                .unwrap();
+            actual.push(trade.kind.id);
+        }
+        assert_eq!(actual, vec!["0", "1", "2"]);
+
+        consumer.abort();
+    }
+
+    #[tokio::test]
+    async fn test_consume_applies_deserialize_error_policy() {
+        struct TestCase {
+            policy: DeserializeErrorPolicy,
+            expected_events: Vec<&'static str>,
+            expected_lifecycle: usize,
+            expected_deserialise_error: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: default Skip policy continues consuming after the malformed message
+                policy: DeserializeErrorPolicy::Skip,
+                expected_events: vec!["0", "1"],
+                expected_lifecycle: 0,
+                expected_deserialise_error: false,
+            },
+            TestCase {
+                // TC1: Emit policy sends a LifecycleEvent & continues consuming
+                policy: DeserializeErrorPolicy::Emit,
+                expected_events: vec!["0", "1"],
+                expected_lifecycle: 1,
+                expected_deserialise_error: false,
+            },
+            TestCase {
+                // TC2: Fail policy ends the consumer loop with the deserialisation error
+                policy: DeserializeErrorPolicy::Fail,
+                expected_events: vec!["0"],
+                expected_lifecycle: 0,
+                expected_deserialise_error: true,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            // Exchange sends a trade, a malformed message, and then another trade
+            let exchange = MockExchange::start([MockScript::subscribe_then_close(
+                [OKX_BTC_TRADES_ACK.to_string()],
+                [okx_btc_trade("0"), "{".to_string(), okx_btc_trade("1")],
+            )])
+            .await
+            .unwrap();
+            let (exchange_tx, mut exchange_rx) = mpsc::channel(10);
+            let (lifecycle_tx, mut lifecycle_rx) = mpsc::unbounded_channel();
+
+            // Never re-connect once the mock exchange closes the connection
+            let reconnect_policy = FixedDelay {
+                delay: Duration::from_secs(1),
+                max_attempts: Some(0),
+            };
+
+            let error = consume(
+                vec![okx_trades("btc")],
+                ConnectionConfig::default()
+                    .url(exchange.url())
+                    .deserialize_error_policy(test.policy),
+                Arc::new(reconnect_policy),
+                Some(lifecycle_tx),
+                HealthMonitor::default().register(ExchangeId::Okx),
+                true,
+                exchange_tx,
+            )
+            .await;
+
+            let mut actual_events = vec![];
+            while let Some(event) = exchange_rx.recv().await {
+                actual_events.push(event.kind.id);
+            }
+            assert_eq!(actual_events, test.expected_events, "TC{} failed", index);
+
+            let mut actual_lifecycle = vec![];
+            while let Some(event) = lifecycle_rx.recv().await {
+                assert!(
+                    matches!(
+                        &event,
+                        LifecycleEvent::DeserializeError {
+                            exchange: ExchangeId::Okx,
+                            error,
+                        } if error.contains("{")
+                    ),
+                    "TC{} failed",
+                    index
+                );
+                actual_lifecycle.push(event);
+            }
+            assert_eq!(
+                actual_lifecycle.len(),
+                test.expected_lifecycle,
+                "TC{} failed",
+                index
+            );
+
+            assert_eq!(
+                error.is_deserialise(),
+                test.expected_deserialise_error,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_consume_fail_deserialize_error_policy_terminates_stream() {
+        // Every connection sends a trade, a malformed message, and then another trade
+        let script = MockScript::new()
+            .receive()
+            .send_text(OKX_BTC_TRADES_ACK)
+            .send_text(okx_btc_trade("0"))
+            .send_text("{")
+            .send_text(okx_btc_trade("1"));
+        let exchange = MockExchange::start([script.clone(), script]).await.unwrap();
+        let (exchange_tx, mut exchange_rx) = mpsc::channel(10);
+
+        // Re-connection is never exhausted, so only the Fail policy can end the consumer loop
+        let consumer = tokio::spawn(consume(
+            vec![okx_trades("btc")],
+            ConnectionConfig::default()
+                .url(exchange.url())
+                .deserialize_error_policy(DeserializeErrorPolicy::Fail),
+            Arc::new(FixedDelay {
+                delay: Duration::ZERO,
+                max_attempts: None,
+            }),
+            None,
+            HealthMonitor::default().register(ExchangeId::Okx),
+            true,
+            exchange_tx,
+        ));
+        let abort = consumer.abort_handle();
+
+        let error = tokio::time::timeout(Duration::from_secs(5), consumer).await;
+        abort.abort();
+        let error = error
+            .expect("Fail policy did not terminate the consumer loop")
+            .unwrap();
+
+        // Stream terminates on the malformed message, without consuming the later trade or
+        // re-connecting
+        assert!(error.is_deserialise(), "{error}");
+        assert_eq!(exchange_rx.recv().await.unwrap().kind.id, "0");
+        assert!(exchange_rx.recv().await.is_none());
+        assert_eq!(exchange.connections(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_consume_resubscribes_to_runtime_subscription_set() {
+        // Initial connection closes once subscribed, forcing a re-connection
+        let mut exchange = MockExchange::start([
+            MockScript::new()
+                .receive()
+                .send_text(okx_trades_ack("BTC-USDT"))
+                .send_text(okx_trades_ack("ETH-USDT"))
+                .close(),
+            MockScript::new()
+                .receive()
+                .send_text(okx_trades_ack("BTC-USDT"))
+                .send_text(okx_trades_ack("SOL-USDT")),
+        ])
+        .await
+        .unwrap();
+        let subscriptions = SubscriptionSet::new([okx_trades("btc"), okx_trades("eth")]);
+        let (exchange_tx, _exchange_rx) = mpsc::channel(1);
+
+        let consumer = tokio::spawn(consume(
+            subscriptions.clone(),
+            ConnectionConfig::default().url(exchange.url()),
+            Arc::new(FixedDelay {
+                delay: Duration::from_millis(500),
+                max_attempts: None,
+            }),
+            None,
+            HealthMonitor::default().register(ExchangeId::Okx),
+            true,
+            exchange_tx,
+        ));
+
+        // Initial connection subscribes to the initial SubscriptionSet
+        assert_eq!(
+            next_requested_inst_ids(&mut exchange).await,
+            vec!["BTC-USDT", "ETH-USDT"]
+        );
+
+        // Modify the SubscriptionSet at runtime, before the re-connection delay elapses
+        assert!(subscriptions.add(okx_trades("sol")));
+        assert!(subscriptions.remove(&okx_trades("eth")));
+
+        // Re-connection re-subscribes to the current SubscriptionSet
+        assert_eq!(
+            next_requested_inst_ids(&mut exchange).await,
+            vec!["BTC-USDT", "SOL-USDT"]
+        );
+        assert_eq!(exchange.connections(), 2);
+
+        consumer.abort();
+    }
+
+    #[tokio::test]
+    async fn test_consume_reconnects_on_retryable_error_code_only() {
+        // Exchange rejects the first subscription w/ the code, then accepts & sends a trade
+        let consume_error_code = |exchange: &MockExchange, rate_limits: &RateLimitMonitor| {
+            let (exchange_tx, exchange_rx) = mpsc::channel(10);
+            let consumer = tokio::spawn(consume(
+                vec![okx_trades("btc")],
+                ConnectionConfig::default()
+                    .url(exchange.url())
+                    .rate_limits(rate_limits.clone()),
+                Arc::new(FixedDelay {
+                    delay: Duration::ZERO,
+                    max_attempts: Some(1),
+                }),
+                None,
+                HealthMonitor::default().register(ExchangeId::Okx),
+                true,
+                exchange_tx,
+            ));
+            (consumer, exchange_rx)
+        };
+        let start_exchange = |code: &str| {
+            MockExchange::start([
+                MockScript::new().receive().send_text(okx_error(code)),
+                MockScript::new()
+                    .receive()
+                    .send_text(OKX_BTC_TRADES_ACK)
+                    .send_text(okx_btc_trade("0")),
+            ])
+        };
+
+        // Retryable "Requests too frequent" code re-connects, yielding the trade, & records the
+        // exhausted subscription rate limit
+        let rate_limits = RateLimitMonitor::default();
+        let exchange = start_exchange("60014").await.unwrap();
+        let (consumer, mut exchange_rx) = consume_error_code(&exchange, &rate_limits);
+        assert_eq!(exchange_rx.recv().await.unwrap().kind.id, "0");
+        assert_eq!(exchange.connections(), 2);
+        assert!(!consumer.is_finished());
+        assert_eq!(
+            rate_limits.usage(ExchangeId::Okx).unwrap().utilisation(),
+            1.0
+        );
+        consumer.abort();
+
+        // Terminal "channel doesn't exist" code ends the stream with the error, without
+        // re-connecting
+        let rate_limits = RateLimitMonitor::default();
+        let exchange = start_exchange("60018").await.unwrap();
+        let (consumer, mut exchange_rx) = consume_error_code(&exchange, &rate_limits);
+        let error = consumer.await.unwrap();
+        assert!(error.to_string().contains("code: 60018"), "{error}");
+        assert!(exchange_rx.recv().await.is_none());
+        assert_eq!(exchange.connections(), 1);
+        assert_eq!(rate_limits.usage(ExchangeId::Okx), None);
     }
 }
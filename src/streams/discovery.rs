@@ -0,0 +1,63 @@
+use crate::{
+    error::DataError,
+    exchange::Connector,
+    streams::{ratelimit::RateLimitMonitor, rest::get_json},
+};
+use barter_integration::model::instrument::Instrument;
+use serde::de::DeserializeOwned;
+
+/// Implemented by an exchange [`Connector`] that exposes a REST endpoint listing its currently
+/// active markets, usable for discovering the [`Instrument`]s that can be subscribed to.
+pub trait InstrumentDiscovery
+where
+    Self: Connector,
+{
+    /// Base url of the exchange REST instruments endpoint.
+    const INSTRUMENTS_URL: &'static str;
+
+    /// Deserialisable instruments endpoint response.
+    type Response: DeserializeOwned;
+
+    /// Construct the instruments request urls from the `base_url`. Exchanges that list each
+    /// instrument type separately (eg/ Okx) require a request per instrument type.
+    fn instruments_urls(base_url: &str) -> Vec<String> {
+        vec![base_url.to_owned()]
+    }
+
+    /// Extract every active [`Instrument`] supported by this [`Connector`] from the
+    /// [`Self::Response`], skipping any markets that are halted or cannot be represented.
+    fn instruments(response: Self::Response) -> Vec<Instrument>;
+}
+
+/// Fetch every currently active [`Instrument`] of the exchange from its REST instruments
+/// endpoint, recording any rate-limit usage reported by the responses in the provided
+/// [`RateLimitMonitor`].
+pub async fn discover_instruments<Exchange>(
+    rate_limits: Option<&RateLimitMonitor>,
+) -> Result<Vec<Instrument>, DataError>
+where
+    Exchange: InstrumentDiscovery,
+{
+    discover_instruments_from::<Exchange>(Exchange::INSTRUMENTS_URL, rate_limits).await
+}
+
+/// Fetch every currently active [`Instrument`] of the exchange using the provided instruments
+/// endpoint `base_url`.
+pub async fn discover_instruments_from<Exchange>(
+    base_url: &str,
+    rate_limits: Option<&RateLimitMonitor>,
+) -> Result<Vec<Instrument>, DataError>
+where
+    Exchange: InstrumentDiscovery,
+{
+    let client = reqwest::Client::new();
+    let mut instruments = Vec::new();
+
+    for url in Exchange::instruments_urls(base_url) {
+        let response =
+            get_json::<Exchange::Response>(&client, Exchange::ID, url, rate_limits).await?;
+        instruments.extend(Exchange::instruments(response));
+    }
+
+    Ok(instruments)
+}
@@ -0,0 +1,273 @@
+use super::latency::LatencyMonitor;
+use crate::exchange::ExchangeId;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::{
+    atomic::{AtomicI64, Ordering},
+    Arc, Mutex, RwLock,
+};
+
+/// [`ConnectionTracker`] last message timestamp sentinel, used before any message is consumed.
+const NO_MESSAGE_TIME: i64 = i64::MIN;
+
+/// Status of a single consumer loop WebSocket connection.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum ConnectionStatus {
+    /// Initialising the first [`MarketStream`](crate::MarketStream) connection.
+    #[default]
+    Connecting,
+    /// Connected with every [`Subscription`](crate::subscription::Subscription) validated.
+    Validated,
+    /// Disconnected (or closed for maintenance) & waiting to re-initialise.
+    Reconnecting,
+    /// Consumer loop has ended, and will not re-connect.
+    Dead,
+}
+
+/// Health of a single consumer loop WebSocket connection.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ConnectionHealth {
+    pub exchange: ExchangeId,
+    pub status: ConnectionStatus,
+    /// Time the last message (event or error) was consumed from the exchange, if any.
+    pub last_message_time: Option<DateTime<Utc>>,
+    /// Number of re-connection attempts since the connection was last validated.
+    pub reconnect_attempt: u32,
+    /// Number of [`Subscription`](crate::subscription::Subscription)s actioned by the most recent
+    /// (re)initialisation.
+    pub subscriptions: usize,
+    /// Smoothed latency of the exchange, if a [`LatencyMonitor`] has been opted-in to.
+    pub latency: Option<Duration>,
+}
+
+impl ConnectionHealth {
+    fn new(exchange: ExchangeId) -> Self {
+        Self {
+            exchange,
+            status: ConnectionStatus::default(),
+            last_message_time: None,
+            reconnect_attempt: 0,
+            subscriptions: 0,
+            latency: None,
+        }
+    }
+}
+
+/// Point in time summary of the [`ConnectionHealth`] of every consumer loop connection, returned
+/// by [`Streams::health`](super::Streams::health).
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct HealthSummary {
+    pub connections: Vec<ConnectionHealth>,
+}
+
+impl HealthSummary {
+    /// True if every connection is [`ConnectionStatus::Validated`].
+    pub fn is_healthy(&self) -> bool {
+        self.connections
+            .iter()
+            .all(|connection| connection.status == ConnectionStatus::Validated)
+    }
+
+    /// Number of connections with the provided [`ConnectionStatus`].
+    pub fn count(&self, status: ConnectionStatus) -> usize {
+        self.connections
+            .iter()
+            .filter(|connection| connection.status == status)
+            .count()
+    }
+
+    /// Total number of [`Subscription`](crate::subscription::Subscription)s across every
+    /// connection.
+    pub fn subscriptions(&self) -> usize {
+        self.connections
+            .iter()
+            .map(|connection| connection.subscriptions)
+            .sum()
+    }
+}
+
+/// Shared registry of the [`ConnectionTracker`] of every consumer loop connection spawned by a
+/// [`StreamBuilder`](super::builder::StreamBuilder).
+#[derive(Clone, Debug, Default)]
+pub struct HealthMonitor {
+    connections: Arc<RwLock<Vec<ConnectionTracker>>>,
+}
+
+impl HealthMonitor {
+    /// Register a new connection to the provided exchange, returning the [`ConnectionTracker`]
+    /// its consumer loop updates.
+    pub fn register(&self, exchange: ExchangeId) -> ConnectionTracker {
+        let tracker = ConnectionTracker {
+            health: Arc::new(Mutex::new(ConnectionHealth::new(exchange))),
+            last_message_nanos: Arc::new(AtomicI64::new(NO_MESSAGE_TIME)),
+        };
+        self.connections
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .push(tracker.clone());
+        tracker
+    }
+
+    /// Add every connection registered with another [`HealthMonitor`] to this one.
+    pub fn extend(&self, other: &HealthMonitor) {
+        let others = other
+            .connections
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone();
+        self.connections
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .extend(others);
+    }
+
+    /// Summarise the current [`ConnectionHealth`] of every registered connection, including the
+    /// smoothed latency of each exchange if a [`LatencyMonitor`] is provided.
+    pub fn summary(&self, latency: Option<&LatencyMonitor>) -> HealthSummary {
+        let connections = self
+            .connections
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .iter()
+            .map(|tracker| {
+                let mut health = tracker.health();
+                health.latency = latency.and_then(|monitor| monitor.latency(health.exchange));
+                health
+            })
+            .collect();
+
+        HealthSummary { connections }
+    }
+}
+
+/// Handle used by a consumer loop to record the [`ConnectionHealth`] of its connection.
+///
+/// The time of the last consumed message is recorded for every message, so is kept in an atomic
+/// rather than behind the [`Mutex`] guarding the rest of the [`ConnectionHealth`].
+#[derive(Clone, Debug)]
+pub struct ConnectionTracker {
+    health: Arc<Mutex<ConnectionHealth>>,
+    last_message_nanos: Arc<AtomicI64>,
+}
+
+impl ConnectionTracker {
+    /// Current [`ConnectionHealth`] of the connection.
+    pub fn health(&self) -> ConnectionHealth {
+        let mut health = *self.lock();
+        health.last_message_time = match self.last_message_nanos.load(Ordering::Relaxed) {
+            NO_MESSAGE_TIME => None,
+            nanos => Some(DateTime::from_timestamp_nanos(nanos)),
+        };
+        health
+    }
+
+    /// Record the connection was (re)initialised with the provided number of validated
+    /// [`Subscription`](crate::subscription::Subscription)s.
+    pub fn validated(&self, subscriptions: usize) {
+        let mut health = self.lock();
+        health.status = ConnectionStatus::Validated;
+        health.reconnect_attempt = 0;
+        health.subscriptions = subscriptions;
+    }
+
+    /// Record the connection is waiting to make the provided re-connection attempt.
+    pub fn reconnecting(&self, attempt: u32) {
+        let mut health = self.lock();
+        health.status = ConnectionStatus::Reconnecting;
+        health.reconnect_attempt = attempt;
+    }
+
+    /// Record the consumer loop has ended.
+    pub fn dead(&self) {
+        self.lock().status = ConnectionStatus::Dead;
+    }
+
+    /// Record a message was consumed from the exchange at the provided time.
+    ///
+    /// Times beyond the nanosecond timestamp range (ie/ after the year 2262) are not recorded.
+    pub fn message(&self, time: DateTime<Utc>) {
+        if let Some(nanos) = time.timestamp_nanos_opt() {
+            self.last_message_nanos.store(nanos, Ordering::Relaxed);
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, ConnectionHealth> {
+        self.health
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_health_summary_mixed_connections() {
+        let monitor = HealthMonitor::default();
+        let healthy = monitor.register(ExchangeId::BinanceSpot);
+        let reconnecting = monitor.register(ExchangeId::Okx);
+        let connecting = monitor.register(ExchangeId::Kraken);
+
+        let time = Utc::now();
+        healthy.validated(3);
+        healthy.message(time);
+        reconnecting.validated(2);
+        reconnecting.reconnecting(1);
+
+        struct TestCase {
+            tracker: ConnectionTracker,
+            expected_status: ConnectionStatus,
+            expected_last_message_time: Option<DateTime<Utc>>,
+            expected_subscriptions: usize,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: validated connection consuming messages
+                tracker: healthy,
+                expected_status: ConnectionStatus::Validated,
+                expected_last_message_time: Some(time),
+                expected_subscriptions: 3,
+            },
+            TestCase {
+                // TC1: disconnected connection retains the Subscriptions of the last connection
+                tracker: reconnecting,
+                expected_status: ConnectionStatus::Reconnecting,
+                expected_last_message_time: None,
+                expected_subscriptions: 2,
+            },
+            TestCase {
+                // TC2: connection that has not yet initialised
+                tracker: connecting,
+                expected_status: ConnectionStatus::Connecting,
+                expected_last_message_time: None,
+                expected_subscriptions: 0,
+            },
+        ];
+
+        let summary = monitor.summary(None);
+        assert_eq!(summary.connections.len(), tests.len());
+        assert!(!summary.is_healthy());
+        assert_eq!(summary.count(ConnectionStatus::Validated), 1);
+        assert_eq!(summary.count(ConnectionStatus::Reconnecting), 1);
+        assert_eq!(summary.subscriptions(), 5);
+
+        for (index, (test, actual)) in tests.into_iter().zip(summary.connections).enumerate() {
+            assert_eq!(actual, test.tracker.health(), "TC{} failed", index);
+            assert_eq!(actual.status, test.expected_status, "TC{} failed", index);
+            assert_eq!(
+                actual.last_message_time, test.expected_last_message_time,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                actual.subscriptions, test.expected_subscriptions,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
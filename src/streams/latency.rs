@@ -0,0 +1,539 @@
+use super::{
+    clock::ClockOffset,
+    combinator::warmup::{Warmup, WarmupTracker},
+};
+use crate::{event::MarketEvent, exchange::ExchangeId};
+use chrono::Duration;
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter},
+    sync::{Arc, RwLock, RwLockWriteGuard},
+};
+use tokio::sync::mpsc;
+
+/// Default smoothing factor of a [`LatencyMonitor`] exponential moving average, weighting each
+/// new latency sample by 10%.
+pub const DEFAULT_LATENCY_EMA_ALPHA: f64 = 0.1;
+
+/// Communicative type alias for the callback fired by a [`LatencyMonitor`] with a
+/// [`LatencyAlert`].
+pub type LatencyAlertFn = dyn Fn(LatencyAlert) + Send + Sync;
+
+/// Fired by a [`LatencyMonitor`] when the smoothed latency of an exchange crosses above the
+/// configured threshold, often signalling a degraded connection before it drops.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct LatencyAlert {
+    pub exchange: ExchangeId,
+    pub smoothed: Duration,
+    pub threshold: Duration,
+}
+
+/// Raw & drift-corrected latency of a [`MarketEvent<T>`].
+///
+/// The `raw` latency (`received_time - exchange_time`) includes any exchange clock skew, so it may
+/// even be negative if the exchange clock is ahead of the local clock. The `corrected` latency
+/// first converts the `exchange_time` into local clock time using the known [`ClockOffset`],
+/// leaving a truer network latency figure.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Latency {
+    pub raw: Duration,
+    pub corrected: Duration,
+}
+
+impl Latency {
+    /// Compute the [`Latency`] of the [`MarketEvent<T>`], correcting the raw latency by the
+    /// exchange [`ClockOffset`], if known.
+    pub fn new<T>(event: &MarketEvent<T>, offset: Option<&ClockOffset>) -> Self {
+        let raw = event.received_time - event.exchange_time;
+        let corrected = match offset {
+            Some(offset) => event.received_time - offset.to_local(event.exchange_time),
+            None => raw,
+        };
+
+        Self { raw, corrected }
+    }
+
+    /// Portion of the raw latency attributable to exchange clock skew rather than network delay.
+    pub fn drift(&self) -> Duration {
+        self.raw - self.corrected
+    }
+}
+
+/// Exponential moving average of the latency between a [`MarketEvent<T>`] `exchange_time` and
+/// `received_time`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct LatencyEma {
+    pub alpha: f64,
+    value: Option<f64>,
+}
+
+impl LatencyEma {
+    /// Construct a new [`LatencyEma`] with the provided smoothing factor.
+    ///
+    /// Panics if alpha is not within (0, 1].
+    pub fn new(alpha: f64) -> Self {
+        assert!(
+            alpha > 0.0 && alpha <= 1.0,
+            "LatencyEma alpha must be within (0, 1]"
+        );
+
+        Self { alpha, value: None }
+    }
+
+    /// Add a latency sample, returning the updated smoothed latency. The first sample seeds the
+    /// average.
+    pub fn update(&mut self, latency: Duration) -> Duration {
+        let sample = latency.num_microseconds().unwrap_or(i64::MAX) as f64;
+        let value = match self.value {
+            Some(value) => value + self.alpha * (sample - value),
+            None => sample,
+        };
+        self.value = Some(value);
+
+        Duration::microseconds(value as i64)
+    }
+
+    /// Current smoothed latency, if any samples have been added.
+    pub fn value(&self) -> Option<Duration> {
+        self.value.map(|value| Duration::microseconds(value as i64))
+    }
+}
+
+/// Raw & drift-corrected [`LatencyEma`]s of an exchange, whether it is currently above the
+/// alert threshold, and the progress of any [`Warmup`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct ExchangeLatency {
+    raw: LatencyEma,
+    corrected: LatencyEma,
+    offset: Option<ClockOffset>,
+    alerting: bool,
+    warmup: Option<WarmupTracker>,
+}
+
+impl ExchangeLatency {
+    fn new(alpha: f64, warmup: Option<Warmup>) -> Self {
+        Self {
+            raw: LatencyEma::new(alpha),
+            corrected: LatencyEma::new(alpha),
+            offset: None,
+            alerting: false,
+            warmup: warmup.map(WarmupTracker::new),
+        }
+    }
+
+    fn is_warm(&self) -> bool {
+        self.warmup.as_ref().is_none_or(WarmupTracker::is_warm)
+    }
+}
+
+/// Opt-in shared monitor of the smoothed latency of each exchange, updated by a
+/// [`Streams`](super::Streams) as events are consumed.
+///
+/// Cloning a [`LatencyMonitor`] yields another handle to the same underlying latencies. An
+/// optional [`LatencyAlertFn`] is fired once each time the smoothed latency of an exchange
+/// crosses above the threshold, and re-armed once it falls back below it.
+///
+/// Once the [`ClockOffset`] of an exchange is provided via
+/// [`set_clock_offset()`](LatencyMonitor::set_clock_offset()), a drift-corrected latency is
+/// tracked alongside the raw latency, and alerts are fired using the drift-corrected latency.
+///
+/// If a [`Warmup`] is configured, the smoothed latencies of an exchange are not reported & no
+/// alerts are fired until its [`LatencyEma`]s have consumed enough events.
+#[derive(Clone)]
+pub struct LatencyMonitor {
+    pub alpha: f64,
+    pub warmup: Option<Warmup>,
+    alert: Option<(Duration, Arc<LatencyAlertFn>)>,
+    latencies: Arc<RwLock<HashMap<ExchangeId, ExchangeLatency>>>,
+}
+
+impl Debug for LatencyMonitor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LatencyMonitor")
+            .field("alpha", &self.alpha)
+            .field("warmup", &self.warmup)
+            .field("threshold", &self.threshold())
+            .field("latencies", &self.latencies)
+            .finish()
+    }
+}
+
+impl Default for LatencyMonitor {
+    fn default() -> Self {
+        Self::new(DEFAULT_LATENCY_EMA_ALPHA)
+    }
+}
+
+impl LatencyMonitor {
+    /// Construct a new [`LatencyMonitor`] smoothing latencies with the provided [`LatencyEma`]
+    /// alpha.
+    ///
+    /// Panics if alpha is not within (0, 1].
+    pub fn new(alpha: f64) -> Self {
+        // Validate alpha upfront rather than upon the first event
+        let _ = LatencyEma::new(alpha);
+
+        Self {
+            alpha,
+            warmup: None,
+            alert: None,
+            latencies: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Fire the provided callback when the smoothed latency of an exchange crosses above the
+    /// `threshold`.
+    pub fn alert<F>(mut self, threshold: Duration, alert: F) -> Self
+    where
+        F: Fn(LatencyAlert) + Send + Sync + 'static,
+    {
+        self.alert = Some((threshold, Arc::new(alert)));
+        self
+    }
+
+    /// Suppress the smoothed latencies & alerts of each exchange during the provided [`Warmup`]
+    /// period, observed with the `exchange_time` of every consumed event.
+    pub fn warmup(mut self, warmup: Warmup) -> Self {
+        self.warmup = Some(warmup);
+        self
+    }
+
+    /// Configured alert threshold, if any.
+    pub fn threshold(&self) -> Option<Duration> {
+        self.alert.as_ref().map(|(threshold, _)| *threshold)
+    }
+
+    /// Provide the estimated [`ClockOffset`] of the exchange, used to drift-correct the latency
+    /// of subsequent events.
+    pub fn set_clock_offset(&self, exchange: ExchangeId, offset: ClockOffset) {
+        self.write()
+            .entry(exchange)
+            .or_insert_with(|| ExchangeLatency::new(self.alpha, self.warmup))
+            .offset = Some(offset);
+    }
+
+    /// Update the smoothed latencies of the exchange with the [`Latency`] of the
+    /// [`MarketEvent<T>`], returning the updated smoothed [`Latency`].
+    pub fn update<T>(&self, exchange: ExchangeId, event: &MarketEvent<T>) -> Latency {
+        let (smoothed, crossed) = {
+            let mut latencies = self.write();
+            let state = latencies
+                .entry(exchange)
+                .or_insert_with(|| ExchangeLatency::new(self.alpha, self.warmup));
+
+            let latency = Latency::new(event, state.offset.as_ref());
+            let smoothed = Latency {
+                raw: state.raw.update(latency.raw),
+                corrected: state.corrected.update(latency.corrected),
+            };
+            if let Some(warmup) = state.warmup.as_mut() {
+                warmup.observe(event.exchange_time);
+            }
+            let above = state.is_warm()
+                && self
+                    .threshold()
+                    .is_some_and(|threshold| smoothed.corrected > threshold);
+            let crossed = above && !state.alerting;
+            state.alerting = above;
+
+            (smoothed, crossed)
+        };
+
+        // Fire outside the lock so the callback may query the LatencyMonitor
+        if let (true, Some((threshold, alert))) = (crossed, &self.alert) {
+            alert(LatencyAlert {
+                exchange,
+                smoothed: smoothed.corrected,
+                threshold: *threshold,
+            });
+        }
+
+        smoothed
+    }
+
+    /// Current smoothed raw latency of the exchange, if any events have been consumed & any
+    /// [`Warmup`] has ended.
+    pub fn latency(&self, exchange: ExchangeId) -> Option<Duration> {
+        self.read(exchange, |state| state.raw.value())
+    }
+
+    /// Current smoothed drift-corrected latency of the exchange, if any events have been
+    /// consumed & any [`Warmup`] has ended. Equal to the raw
+    /// [`latency()`](LatencyMonitor::latency()) if no [`ClockOffset`] has been provided.
+    pub fn corrected_latency(&self, exchange: ExchangeId) -> Option<Duration> {
+        self.read(exchange, |state| state.corrected.value())
+    }
+
+    fn read<F>(&self, exchange: ExchangeId, f: F) -> Option<Duration>
+    where
+        F: FnOnce(&ExchangeLatency) -> Option<Duration>,
+    {
+        self.latencies
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(&exchange)
+            .filter(|state| state.is_warm())
+            .and_then(f)
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, HashMap<ExchangeId, ExchangeLatency>> {
+        // A writer panicking mid-update cannot leave an ExchangeLatency partially written
+        self.latencies
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Forward every [`MarketEvent<T>`] from the provided exchange [`mpsc::Receiver`] to the
+/// returned [`mpsc::Receiver`], updating the [`LatencyMonitor`] before each event is forwarded.
+pub fn monitored<T>(
+    monitor: LatencyMonitor,
+    exchange: ExchangeId,
+    mut rx: mpsc::Receiver<MarketEvent<T>>,
+) -> mpsc::Receiver<MarketEvent<T>>
+where
+    T: Send + 'static,
+{
+    let (monitored_tx, monitored_rx) = mpsc::channel(rx.max_capacity());
+
+    tokio::spawn(async move {
+        while let Some(event) = rx.recv().await {
+            monitor.update(exchange, &event);
+            if monitored_tx.send(event).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    monitored_rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streams::Streams;
+    use barter_integration::model::{
+        instrument::{kind::InstrumentKind, Instrument},
+        Exchange,
+    };
+    use chrono::Utc;
+    use std::sync::Mutex;
+
+    fn event(latency_ms: i64) -> MarketEvent<()> {
+        let received_time = Utc::now();
+        MarketEvent {
+            exchange_time: received_time - Duration::milliseconds(latency_ms),
+            received_time,
+            exchange_time_synthesized: false,
+            exchange: Exchange::from(ExchangeId::BinanceSpot),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            kind: (),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_latency_monitor_alert_on_spike() {
+        let alerts = Arc::new(Mutex::new(Vec::new()));
+        let monitor = LatencyMonitor::new(0.5).alert(Duration::milliseconds(100), {
+            let alerts = Arc::clone(&alerts);
+            move |alert| alerts.lock().unwrap().push(alert)
+        });
+
+        let (tx, rx) = mpsc::channel(16);
+        let mut streams = Streams {
+            streams: HashMap::from([(ExchangeId::BinanceSpot, rx)]),
+            clock_offsets: HashMap::new(),
+            latency: None,
+            health: Default::default(),
+            rate_limits: None,
+        };
+        streams.monitor_latency(monitor);
+        let mut rx = streams.select(ExchangeId::BinanceSpot).unwrap();
+
+        struct TestCase {
+            latency_ms: i64,
+            expected_smoothed_ms: i64,
+            expected_alerts: usize,
+        }
+
+        let cases = vec![
+            // TC0: first sample seeds the EMA below the threshold
+            TestCase {
+                latency_ms: 10,
+                expected_smoothed_ms: 10,
+                expected_alerts: 0,
+            },
+            // TC1: spike crosses the EMA above the threshold, firing an alert
+            TestCase {
+                latency_ms: 500,
+                expected_smoothed_ms: 255,
+                expected_alerts: 1,
+            },
+            // TC2: EMA remains above the threshold, so no further alert
+            TestCase {
+                latency_ms: 500,
+                expected_smoothed_ms: 377,
+                expected_alerts: 1,
+            },
+            // TC3: EMA decays but remains above the threshold
+            TestCase {
+                latency_ms: 10,
+                expected_smoothed_ms: 193,
+                expected_alerts: 1,
+            },
+            // TC4: EMA decays but remains above the threshold
+            TestCase {
+                latency_ms: 10,
+                expected_smoothed_ms: 101,
+                expected_alerts: 1,
+            },
+            // TC5: EMA falls back below the threshold, re-arming the alert
+            TestCase {
+                latency_ms: 10,
+                expected_smoothed_ms: 55,
+                expected_alerts: 1,
+            },
+            // TC6: second spike crosses the threshold again
+            TestCase {
+                latency_ms: 500,
+                expected_smoothed_ms: 277,
+                expected_alerts: 2,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            tx.send(event(test.latency_ms)).await.unwrap();
+            rx.recv().await.unwrap();
+
+            let smoothed = streams
+                .smoothed_latency(ExchangeId::BinanceSpot)
+                .unwrap()
+                .num_milliseconds();
+            assert_eq!(smoothed, test.expected_smoothed_ms, "TC{} failed", index);
+            assert_eq!(
+                alerts.lock().unwrap().len(),
+                test.expected_alerts,
+                "TC{} failed",
+                index
+            );
+        }
+
+        let alert = alerts.lock().unwrap()[0];
+        assert_eq!(alert.exchange, ExchangeId::BinanceSpot);
+        assert_eq!(alert.smoothed.num_milliseconds(), 255);
+        assert_eq!(alert.threshold, Duration::milliseconds(100));
+    }
+
+    #[test]
+    fn test_latency_corrected_by_clock_offset() {
+        struct TestCase {
+            offset_ms: Option<i64>,
+            expected_raw_ms: i64,
+            expected_corrected_ms: i64,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: no known offset, so corrected latency is the raw latency
+                offset_ms: None,
+                expected_raw_ms: 100,
+                expected_corrected_ms: 100,
+            },
+            TestCase {
+                // TC1: exchange clock 500ms ahead understates the raw latency
+                offset_ms: Some(500),
+                expected_raw_ms: 100,
+                expected_corrected_ms: 600,
+            },
+            TestCase {
+                // TC2: exchange clock 80ms behind overstates the raw latency
+                offset_ms: Some(-80),
+                expected_raw_ms: 100,
+                expected_corrected_ms: 20,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let offset = test.offset_ms.map(|offset_ms| ClockOffset {
+                offset: Duration::milliseconds(offset_ms),
+                round_trip: Duration::milliseconds(10),
+            });
+
+            // Free function Latency matches the LatencyMonitor drift-corrected latency
+            let monitor = LatencyMonitor::new(1.0);
+            if let Some(offset) = offset {
+                monitor.set_clock_offset(ExchangeId::BinanceSpot, offset);
+            }
+            let event = event(100);
+            let actual = Latency::new(&event, offset.as_ref());
+            let smoothed = monitor.update(ExchangeId::BinanceSpot, &event);
+
+            assert_eq!(
+                actual.raw.num_milliseconds(),
+                test.expected_raw_ms,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                actual.corrected.num_milliseconds(),
+                test.expected_corrected_ms,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                actual.drift(),
+                -offset.map_or(Duration::zero(), |offset| offset.offset),
+                "TC{} failed",
+                index
+            );
+            assert_eq!(smoothed, actual, "TC{} failed", index);
+            assert_eq!(
+                monitor.corrected_latency(ExchangeId::BinanceSpot),
+                Some(actual.corrected),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_latency_monitor_warmup_suppresses_latency_and_alerts() {
+        let alerts = Arc::new(Mutex::new(Vec::new()));
+        let monitor =
+            LatencyMonitor::new(1.0)
+                .warmup(Warmup::Count(3))
+                .alert(Duration::milliseconds(100), {
+                    let alerts = Arc::clone(&alerts);
+                    move |alert| alerts.lock().unwrap().push(alert)
+                });
+
+        // (latency_ms) -> (expected reported latency ms, expected alerts)
+        let cases = [
+            // TC0: first sample above the threshold during warmup
+            (500, None, 0),
+            // TC1: second sample above the threshold during warmup
+            (500, None, 0),
+            // TC2: third sample ends the warmup, reporting & alerting normally
+            (500, Some(500), 1),
+            // TC3: EMA remains above the threshold, so no further alert
+            (400, Some(400), 1),
+        ];
+
+        for (index, (latency_ms, expected_latency, expected_alerts)) in
+            cases.into_iter().enumerate()
+        {
+            monitor.update(ExchangeId::BinanceSpot, &event(latency_ms));
+
+            let actual = monitor
+                .latency(ExchangeId::BinanceSpot)
+                .map(|latency| latency.num_milliseconds());
+            assert_eq!(actual, expected_latency, "TC{} failed", index);
+            assert_eq!(
+                alerts.lock().unwrap().len(),
+                expected_alerts,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::exchange::ExchangeId;
+use serde::{Deserialize, Serialize};
+
+/// Lifecycle events emitted by the consumer loop driving a re-connecting
+/// [`MarketStream`](crate::MarketStream), distinct from the
+/// [`MarketEvent<T>`](crate::event::MarketEvent)s it yields.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum LifecycleEvent {
+    /// Exchange signalled it is closed for maintenance, so re-connection is paused for the
+    /// [`ReconnectPolicy::maintenance_delay`](super::reconnect::ReconnectPolicy::maintenance_delay).
+    Maintenance { exchange: ExchangeId },
+    /// Exchange signalled a change in its trading [`ExchangeStatus`] (eg/ degraded to
+    /// [`ExchangeStatus::CancelOnly`] during an incident, or back [`ExchangeStatus::Online`]).
+    Status {
+        exchange: ExchangeId,
+        status: ExchangeStatus,
+    },
+    /// Inbound message failed to deserialise & was skipped, emitted when the consumer loop is
+    /// configured with [`DeserializeErrorPolicy::Emit`](super::consumer::DeserializeErrorPolicy::Emit).
+    DeserializeError { exchange: ExchangeId, error: String },
+    /// Exchange receiver is full, so consumed [`MarketEvent<T>`](crate::event::MarketEvent)s are
+    /// being dropped, emitted once per lag when the consumer loop is configured with
+    /// [`LagPolicy::DropNewest`](super::consumer::LagPolicy::DropNewest).
+    Lagged { exchange: ExchangeId },
+}
+
+/// Trading status of an exchange, as signalled over a market data connection.
+///
+/// See docs: <https://docs.kraken.com/websockets/#message-systemStatus>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExchangeStatus {
+    /// Operating normally.
+    Online,
+    /// Closed for maintenance, so the connection will be closed.
+    Maintenance,
+    /// Only order cancellations are accepted.
+    CancelOnly,
+    /// Only limit orders are accepted.
+    LimitOnly,
+    /// Only post-only limit orders are accepted.
+    PostOnly,
+}
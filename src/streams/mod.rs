@@ -1,22 +1,117 @@
-use self::builder::{multi::MultiStreamBuilder, StreamBuilder};
-use crate::{exchange::ExchangeId, subscription::SubKind};
+use self::{
+    backfill::TradeBackfill,
+    broadcast::Broadcast,
+    builder::{multi::MultiStreamBuilder, StreamBuilder, DEFAULT_CHANNEL_CAPACITY},
+    cache::{Cacheable, SnapshotCache},
+    clock::{ClockOffset, ServerTime},
+    combinator::tape::ConsolidatedTape,
+    health::{HealthMonitor, HealthSummary},
+    latency::LatencyMonitor,
+    ratelimit::RateLimitMonitor,
+};
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::{trade::PublicTrade, SubKind},
+};
+use barter_integration::model::instrument::Instrument;
 use std::collections::HashMap;
-use tokio::sync::mpsc;
-use tokio_stream::{wrappers::UnboundedReceiverStream, StreamMap};
+use tokio::sync::{mpsc, oneshot};
+use tokio_stream::{wrappers::ReceiverStream, StreamMap};
+
+/// REST [`TradeBackfill`](backfill::TradeBackfill) of recent
+/// [`PublicTrade`](crate::subscription::trade::PublicTrade)s, emitted as historical events ahead
+/// of the live stream.
+pub mod backfill;
+
+/// [`Broadcast`](broadcast::Broadcast) fan-out layer that lets many consumers share a single
+/// exchange stream.
+pub mod broadcast;
 
 /// Defines the [`StreamBuilder`](builder::StreamBuilder) and
 /// [`MultiStreamBuilder`](builder::multi::MultiStreamBuilder) APIs for ergonomically initialising
 /// [`MarketStream`](super::MarketStream) [`Streams`].
 pub mod builder;
 
+/// Opt-in [`SnapshotCache`](cache::SnapshotCache) of the latest
+/// [`MarketEvent<T>`](crate::event::MarketEvent) of each kind per
+/// [`Instrument`](barter_integration::model::instrument::Instrument), for polling on demand.
+pub mod cache;
+
+/// [`ClockOffset`](clock::ClockOffset) estimation of exchange server clocks relative to the
+/// local clock using exchange REST [`ServerTime`](clock::ServerTime) endpoints.
+pub mod clock;
+
+/// Fixed schema, column-oriented [`ColumnarBatch`](columnar::ColumnarBatch)es of
+/// [`MarketEvent<T>`](crate::event::MarketEvent)s, finished into Arrow `RecordBatch`es for
+/// analytics pipelines (eg/ Parquet).
+#[cfg(feature = "columnar")]
+pub mod columnar;
+
+/// Combinators that consume [`Streams`] of [`MarketEvent<T>`](crate::event::MarketEvent)s to
+/// produce derived streams (eg/ a [`ConsolidatedTape`](combinator::tape::ConsolidatedTape)).
+pub mod combinator;
+
+/// REST [`InstrumentDiscovery`](discovery::InstrumentDiscovery) of the currently active
+/// [`Instrument`](barter_integration::model::instrument::Instrument)s of an exchange.
+pub mod discovery;
+
 /// Central consumer loop functionality used by the [`StreamBuilder`](builder::StreamBuilder) to
 /// to drive a re-connecting [`MarketStream`](super::MarketStream).
 pub mod consumer;
 
+/// [`HealthSummary`](health::HealthSummary) of the status, last message time, latency &
+/// subscription count of every consumer loop connection.
+pub mod health;
+
+/// Exponential moving average [`LatencyMonitor`](latency::LatencyMonitor) of each exchange,
+/// with optional alerts when the smoothed latency exceeds a threshold.
+pub mod latency;
+
+/// [`LifecycleEvent`](lifecycle::LifecycleEvent)s emitted by the consumer loop (eg/ exchange
+/// maintenance notifications).
+pub mod lifecycle;
+
+/// Shared [`InstrumentOrdering`](ordering::InstrumentOrdering) guaranteeing the events of each
+/// instrument are strictly ordered across every consumer loop connection.
+pub mod ordering;
+
+/// Shared [`RateLimitMonitor`](ratelimit::RateLimitMonitor) of the rate-limit usage reported by
+/// exchange REST responses (eg/ Binance used request weight) & WebSocket throttle notices.
+pub mod ratelimit;
+
+/// Shared exchange REST request helper used by the [`TradeBackfill`](backfill::TradeBackfill)
+/// and [`InstrumentDiscovery`](discovery::InstrumentDiscovery) endpoints.
+pub mod rest;
+
+/// [`ReconnectPolicy`](reconnect::ReconnectPolicy) trait defining how the consumer loop
+/// re-initialises a disconnected [`MarketStream`](super::MarketStream), with
+/// [`ExponentialBackoff`](reconnect::ExponentialBackoff) and
+/// [`FixedDelay`](reconnect::FixedDelay) implementations.
+pub mod reconnect;
+
+/// Configurable [`OutputSink`](sink::OutputSink) delivering the events of [`Streams`] via an
+/// mpsc receiver, a multi-consumer broadcast, or a user callback.
+pub mod sink;
+
+/// Runtime modifiable [`SubscriptionSet`](subscriptions::SubscriptionSet) re-subscribed to by
+/// the consumer loop upon every re-connection.
+pub mod subscriptions;
+
+/// Snapshot-consistency self-test mode for managed [`OrderBook`](crate::subscription::book::OrderBook)s,
+/// emitting a [`BookDrift`](verify::BookDrift) when a local book diverges from a reference
+/// snapshot.
+pub mod verify;
+
 /// Ergonomic collection of exchange [`MarketEvent<T>`](crate::event::MarketEvent) receivers.
 #[derive(Debug)]
 pub struct Streams<T> {
-    pub streams: HashMap<ExchangeId, mpsc::UnboundedReceiver<T>>,
+    pub streams: HashMap<ExchangeId, mpsc::Receiver<T>>,
+    pub clock_offsets: HashMap<ExchangeId, ClockOffset>,
+    pub latency: Option<LatencyMonitor>,
+    pub health: HealthMonitor,
+    pub rate_limits: Option<RateLimitMonitor>,
 }
 
 impl<T> Streams<T> {
@@ -35,24 +130,88 @@ impl<T> Streams<T> {
         MultiStreamBuilder::<T>::new()
     }
 
-    /// Remove an exchange [`mpsc::UnboundedReceiver`] from the [`Streams`] `HashMap`.
-    pub fn select(&mut self, exchange: ExchangeId) -> Option<mpsc::UnboundedReceiver<T>> {
+    /// Estimate the [`ClockOffset`] of the exchange server clock relative to the local clock,
+    /// storing it in the [`Streams`] `clock_offsets` `HashMap`.
+    ///
+    /// Use [`ClockOffset::normalise`] to normalise consumed
+    /// [`MarketEvent<T>`](crate::event::MarketEvent) `exchange_time`s into local clock time. If a
+    /// [`LatencyMonitor`] has been opted-in to, it is provided the [`ClockOffset`] to
+    /// drift-correct the latency of the exchange.
+    pub async fn sync_clock<Exchange>(&mut self) -> Result<ClockOffset, DataError>
+    where
+        Exchange: ServerTime,
+    {
+        let offset = clock::estimate_clock_offset::<Exchange>().await?;
+        self.clock_offsets.insert(Exchange::ID, offset);
+        if let Some(latency) = &self.latency {
+            latency.set_clock_offset(Exchange::ID, offset);
+        }
+        Ok(offset)
+    }
+
+    /// Retrieve the most recently estimated [`ClockOffset`] for an exchange, if any.
+    pub fn clock_offset(&self, exchange: ExchangeId) -> Option<ClockOffset> {
+        self.clock_offsets.get(&exchange).copied()
+    }
+
+    /// Summarise the current health of every consumer loop connection (eg/ status & last message
+    /// time), including the smoothed latency of each exchange if a [`LatencyMonitor`] has been
+    /// opted-in to via [`monitor_latency()`](Streams::monitor_latency()).
+    pub fn health(&self) -> HealthSummary {
+        self.health.summary(self.latency.as_ref())
+    }
+
+    /// Current smoothed latency of an exchange, if a [`LatencyMonitor`] has been opted-in to via
+    /// [`monitor_latency()`](Streams::monitor_latency()) and any events have been consumed.
+    pub fn smoothed_latency(&self, exchange: ExchangeId) -> Option<chrono::Duration> {
+        self.latency.as_ref()?.latency(exchange)
+    }
+
+    /// Current smoothed drift-corrected latency of an exchange, accounting for the exchange
+    /// [`ClockOffset`] estimated via [`sync_clock()`](Streams::sync_clock()).
+    pub fn corrected_latency(&self, exchange: ExchangeId) -> Option<chrono::Duration> {
+        self.latency.as_ref()?.corrected_latency(exchange)
+    }
+
+    /// Remove an exchange [`mpsc::Receiver`] from the [`Streams`] `HashMap`.
+    pub fn select(&mut self, exchange: ExchangeId) -> Option<mpsc::Receiver<T>> {
         self.streams.remove(&exchange)
     }
 
-    /// Join all exchange [`mpsc::UnboundedReceiver`] streams into a unified
-    /// [`mpsc::UnboundedReceiver`].
-    pub async fn join(self) -> mpsc::UnboundedReceiver<T>
+    /// Remove an exchange [`mpsc::Receiver`] from the [`Streams`] `HashMap` and wrap it in a
+    /// [`Broadcast`] buffering up to `capacity` events, so many consumers can share it.
+    pub fn broadcast(&mut self, exchange: ExchangeId, capacity: usize) -> Option<Broadcast<T>>
+    where
+        T: Clone + Send + 'static,
+    {
+        self.select(exchange)
+            .map(|exchange_rx| Broadcast::new(exchange_rx, capacity))
+    }
+
+    /// Join all exchange [`mpsc::Receiver`] streams into a unified [`mpsc::Receiver`] with the
+    /// [`DEFAULT_CHANNEL_CAPACITY`].
+    pub async fn join(self) -> mpsc::Receiver<T>
+    where
+        T: Send + 'static,
+    {
+        self.join_with_capacity(DEFAULT_CHANNEL_CAPACITY)
+    }
+
+    /// Join all exchange [`mpsc::Receiver`] streams into a unified [`mpsc::Receiver`] with the
+    /// provided capacity.
+    fn join_with_capacity(self, capacity: usize) -> mpsc::Receiver<T>
     where
         T: Send + 'static,
     {
-        let (joined_tx, joined_rx) = mpsc::unbounded_channel();
+        let (joined_tx, joined_rx) = mpsc::channel(capacity);
 
         for mut exchange_rx in self.streams.into_values() {
             let joined_tx = joined_tx.clone();
             tokio::spawn(async move {
                 while let Some(event) = exchange_rx.recv().await {
-                    let _ = joined_tx.send(event);
+                    if joined_tx.send(event).await.is_err() {
+                        break;
+                    }
                 }
             });
         }
@@ -60,13 +219,123 @@ impl<T> Streams<T> {
         joined_rx
     }
 
-    /// Join all exchange [`mpsc::UnboundedReceiver`] streams into a unified [`StreamMap`].
-    pub async fn join_map(self) -> StreamMap<ExchangeId, UnboundedReceiverStream<T>> {
+    /// Join all exchange [`mpsc::Receiver`] streams into a unified [`StreamMap`].
+    pub async fn join_map(self) -> StreamMap<ExchangeId, ReceiverStream<T>> {
         self.streams
             .into_iter()
             .fold(StreamMap::new(), |mut map, (exchange, rx)| {
-                map.insert(exchange, UnboundedReceiverStream::new(rx));
+                map.insert(exchange, ReceiverStream::new(rx));
                 map
             })
     }
 }
+
+impl<Kind> Streams<MarketEvent<Kind>>
+where
+    Kind: Cacheable + Send + Sync + 'static,
+{
+    /// Opt-in to a [`SnapshotCache`] updated with every exchange
+    /// [`MarketEvent<T>`](crate::event::MarketEvent) before it is received, returning a handle
+    /// that can be polled for the latest values of each
+    /// [`Instrument`](barter_integration::model::instrument::Instrument).
+    pub fn cache(&mut self) -> SnapshotCache {
+        let cache = SnapshotCache::default();
+        self.streams = std::mem::take(&mut self.streams)
+            .into_iter()
+            .map(|(exchange, rx)| (exchange, cache::cached(cache.clone(), rx)))
+            .collect();
+        cache
+    }
+}
+
+impl<Kind> Streams<MarketEvent<Kind>>
+where
+    Kind: Send + 'static,
+{
+    /// Opt-in to the provided [`LatencyMonitor`], updated with the latency of every exchange
+    /// [`MarketEvent<T>`](crate::event::MarketEvent) before it is received. The smoothed latency
+    /// of each exchange can then be polled via
+    /// [`smoothed_latency()`](Streams::smoothed_latency()), and any already estimated
+    /// [`ClockOffset`]s are used to drift-correct the latency.
+    pub fn monitor_latency(&mut self, monitor: LatencyMonitor) {
+        for (exchange, offset) in &self.clock_offsets {
+            monitor.set_clock_offset(*exchange, *offset);
+        }
+        self.streams = std::mem::take(&mut self.streams)
+            .into_iter()
+            .map(|(exchange, rx)| (exchange, latency::monitored(monitor.clone(), exchange, rx)))
+            .collect();
+        self.latency = Some(monitor);
+    }
+}
+
+impl Streams<MarketEvent<PublicTrade>> {
+    /// Consolidate all exchange [`mpsc::Receiver`] streams into a single
+    /// [`ConsolidatedTape`] of [`MarketEvent<PublicTrade>`]s for the canonical [`Instrument`].
+    pub async fn consolidate<I>(
+        self,
+        instrument: I,
+    ) -> ConsolidatedTape<ReceiverStream<MarketEvent<PublicTrade>>>
+    where
+        I: Into<Instrument>,
+    {
+        ConsolidatedTape::new(instrument, self.join_map().await)
+    }
+
+    /// Backfill up to `limit` recent [`PublicTrade`]s of each [`Instrument`] via the exchange
+    /// REST [`TradeBackfill`] endpoint, emitting them ahead of the live exchange
+    /// [`mpsc::Receiver`] with any duplicates at the seam removed.
+    ///
+    /// The [`Instrument`]s are fetched concurrently, and live trades received in the meantime are
+    /// buffered so the consumer loop never lags behind the backfill. If any request fails the
+    /// error is returned, and the buffered live trades are emitted without a backfill.
+    ///
+    /// Any rate-limit usage reported by the responses is recorded in the [`RateLimitMonitor`] of
+    /// the [`ConnectionConfig`](crate::subscriber::config::ConnectionConfig) the [`Streams`] were
+    /// built with, if any.
+    ///
+    /// Does nothing if the [`Streams`] do not contain the exchange.
+    pub async fn backfill<Exchange, I>(
+        &mut self,
+        instruments: impl IntoIterator<Item = I>,
+        limit: usize,
+    ) -> Result<(), DataError>
+    where
+        Exchange: TradeBackfill,
+        MarketIter<PublicTrade>: From<(ExchangeId, Instrument, Exchange::Response)>,
+        I: Into<Instrument>,
+    {
+        let Some(live_rx) = self.select(Exchange::ID) else {
+            return Ok(());
+        };
+
+        // Start buffering live trades before the backfill requests are sent
+        let (historical_tx, historical_rx) = oneshot::channel();
+        self.streams.insert(
+            Exchange::ID,
+            backfill::bootstrap(
+                async move { historical_rx.await.unwrap_or_default() },
+                live_rx,
+            ),
+        );
+
+        let instruments = instruments
+            .into_iter()
+            .map(I::into)
+            .collect::<Vec<Instrument>>();
+
+        let mut historical = futures::future::try_join_all(instruments.iter().map(|instrument| {
+            backfill::backfill_trades::<Exchange>(instrument, limit, self.rate_limits.as_ref())
+        }))
+        .await?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+        historical.sort_by_key(|trade| trade.exchange_time);
+
+        // Bootstrap task only ends early if the Streams receiver was dropped
+        let _ = historical_tx.send(historical);
+
+        Ok(())
+    }
+}
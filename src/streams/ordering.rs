@@ -0,0 +1,269 @@
+use crate::event::MarketEvent;
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Utc};
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter},
+    sync::Arc,
+};
+use tokio::sync::{mpsc, Mutex};
+use tracing::debug;
+
+/// Per [`Exchange`] & [`Instrument`] ordering guarantee shared by every consumer loop configured
+/// with (a clone of) the same [`InstrumentOrdering`].
+///
+/// Every [`MarketEvent<T>`](MarketEvent) is delivered through a single ordered path, so the
+/// events of an [`Instrument`] are strictly ordered by `exchange_time` regardless of which
+/// connection they were consumed from (eg/ after batching across many connections, or once a
+/// connection is rebuilt & replays recent events). Any event older than the last delivered event
+/// of the same [`Instrument`] is dropped.
+///
+/// ### Notes
+/// Ordering across different [`Instrument`]s is best-effort only.
+#[derive(Clone, Default)]
+pub struct InstrumentOrdering {
+    last: Arc<Mutex<LastDelivered>>,
+}
+
+/// `exchange_time` of the last [`MarketEvent<T>`](MarketEvent) delivered for each [`Exchange`] &
+/// [`Instrument`].
+type LastDelivered = HashMap<(Exchange, Instrument), DateTime<Utc>>;
+
+impl InstrumentOrdering {
+    /// Construct a new [`InstrumentOrdering`] that has not yet delivered any events.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send the [`MarketEvent<T>`](MarketEvent) via the provided [`mpsc::Sender`], waiting for
+    /// capacity, unless it is older than the last event delivered for the same [`Exchange`] &
+    /// [`Instrument`].
+    ///
+    /// The ordered path is held until the event is sent, so events are delivered in the order
+    /// they were admitted.
+    pub async fn send<T>(
+        &self,
+        tx: &mpsc::Sender<MarketEvent<T>>,
+        event: MarketEvent<T>,
+    ) -> Result<(), mpsc::error::SendError<MarketEvent<T>>> {
+        let mut last = self.last.lock().await;
+
+        let key = (event.exchange.clone(), event.instrument.clone());
+        if let Some(last_time) = last
+            .get(&key)
+            .filter(|last_time| event.exchange_time < **last_time)
+        {
+            debug!(
+                exchange = %event.exchange,
+                instrument = %event.instrument,
+                exchange_time = %event.exchange_time,
+                last_exchange_time = %last_time,
+                action = "dropping event",
+                "consumed MarketEvent older than the last delivered event of the Instrument",
+            );
+            return Ok(());
+        }
+
+        let exchange_time = event.exchange_time;
+        tx.send(event).await?;
+        last.insert(key, exchange_time);
+        Ok(())
+    }
+
+    /// Try to send the [`MarketEvent<T>`](MarketEvent) via the provided [`mpsc::Sender`] without
+    /// waiting for capacity, unless it is older than the last event delivered for the same
+    /// [`Exchange`] & [`Instrument`].
+    ///
+    /// The ordered path is held until the event is sent, so events are delivered in the order
+    /// they were admitted. An event that is not sent because the receiver is full does not
+    /// advance the last delivered event.
+    pub async fn try_send<T>(
+        &self,
+        tx: &mpsc::Sender<MarketEvent<T>>,
+        event: MarketEvent<T>,
+    ) -> Result<(), mpsc::error::TrySendError<MarketEvent<T>>> {
+        let mut last = self.last.lock().await;
+
+        let key = (event.exchange.clone(), event.instrument.clone());
+        match last.get(&key) {
+            Some(last_time) if event.exchange_time < *last_time => {
+                debug!(
+                    exchange = %event.exchange,
+                    instrument = %event.instrument,
+                    exchange_time = %event.exchange_time,
+                    last_exchange_time = %last_time,
+                    action = "dropping event",
+                    "consumed MarketEvent older than the last delivered event of the Instrument",
+                );
+                Ok(())
+            }
+            _ => {
+                let exchange_time = event.exchange_time;
+                tx.try_send(event)?;
+                last.insert(key, exchange_time);
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Debug for InstrumentOrdering {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InstrumentOrdering")
+            .field("instruments", &self.last.try_lock().map(|last| last.len()))
+            .finish()
+    }
+}
+
+impl PartialEq for InstrumentOrdering {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.last, &other.last)
+    }
+}
+
+impl Eq for InstrumentOrdering {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{kraken::Kraken, ExchangeId},
+        streams::{consumer::consume, health::HealthMonitor, reconnect::FixedDelay},
+        subscriber::config::ConnectionConfig,
+        subscription::{trade::PublicTrades, Subscription},
+        test_util::{MockExchange, MockScript},
+    };
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use std::time::Duration;
+
+    fn event(base: &str, millis: i64) -> MarketEvent<i64> {
+        MarketEvent {
+            exchange_time: DateTime::from_timestamp_millis(millis).unwrap(),
+            received_time: DateTime::from_timestamp_millis(millis).unwrap(),
+            exchange_time_synthesized: false,
+            exchange: Exchange::from(ExchangeId::Kraken),
+            instrument: Instrument::from((base, "usd", InstrumentKind::Spot)),
+            kind: millis,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_instrument_ordering_send() {
+        let (tx, mut rx) = mpsc::channel(8);
+        let ordering = InstrumentOrdering::new();
+
+        // Events of two connections sharing the same InstrumentOrdering
+        let connection_a = ordering.clone();
+        let connection_b = ordering.clone();
+        for (ordering, event) in [
+            (&connection_a, event("xbt", 1)),
+            (&connection_b, event("xbt", 3)),
+            (&connection_a, event("eth", 2)),
+            (&connection_a, event("xbt", 2)),
+            (&connection_b, event("xbt", 3)),
+        ] {
+            ordering.try_send(&tx, event).await.unwrap();
+        }
+        drop(tx);
+
+        let mut actual = vec![];
+        while let Some(event) = rx.recv().await {
+            actual.push((event.instrument.base.to_string(), event.kind));
+        }
+
+        // Stale "xbt" event is dropped, whereas an older "eth" event is delivered
+        assert_eq!(
+            actual,
+            vec![
+                ("xbt".to_string(), 1),
+                ("xbt".to_string(), 3),
+                ("eth".to_string(), 2),
+                ("xbt".to_string(), 3),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_instrument_ordering_send_waits_for_capacity() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let ordering = InstrumentOrdering::new();
+        ordering.send(&tx, event("xbt", 1)).await.unwrap();
+
+        // Receiver is full, so the next event waits for capacity rather than being dropped
+        let sender = tokio::spawn({
+            let ordering = ordering.clone();
+            async move { ordering.send(&tx, event("xbt", 2)).await }
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!sender.is_finished());
+
+        assert_eq!(rx.recv().await.unwrap().kind, 1);
+        sender.await.unwrap().unwrap();
+        assert_eq!(rx.recv().await.unwrap().kind, 2);
+    }
+
+    #[tokio::test]
+    async fn test_consume_preserves_instrument_ordering_across_rebuilt_connection() {
+        const ACK: &str = r#"{"channelID":0,"channelName":"trade","event":"subscriptionStatus","pair":"XBT/USD","status":"subscribed","subscription":{"name":"trade"}}"#;
+        let trade = |time: &str| {
+            format!(r#"[0,[["5541.20000","0.15850568","{time}","s","l",""]],"trade","XBT/USD"]"#)
+        };
+
+        // Rebuilt connection replays a trade already delivered by the first connection
+        let exchange = MockExchange::start([
+            MockScript::new()
+                .receive()
+                .send_text(ACK)
+                .send_text(trade("1534614057.1"))
+                .send_text(trade("1534614057.2"))
+                .close(),
+            MockScript::new()
+                .receive()
+                .send_text(ACK)
+                .send_text(trade("1534614057.1"))
+                .send_text(trade("1534614057.3")),
+        ])
+        .await
+        .unwrap();
+
+        let (exchange_tx, mut exchange_rx) = mpsc::channel(8);
+        let consumer = tokio::spawn(consume(
+            vec![Subscription::from((
+                Kraken,
+                "xbt",
+                "usd",
+                InstrumentKind::Spot,
+                PublicTrades,
+            ))],
+            ConnectionConfig::default()
+                .url(exchange.url())
+                .ordering(InstrumentOrdering::new()),
+            Arc::new(FixedDelay {
+                delay: Duration::from_millis(1),
+                max_attempts: None,
+            }),
+            None,
+            HealthMonitor::default().register(ExchangeId::Kraken),
+            true,
+            exchange_tx,
+        ));
+
+        let mut actual = vec![];
+        for _ in 0..3 {
+            let trade = tokio::time::timeout(Duration::from_secs(5), exchange_rx.recv())
+                .await
+                .unwrap()
+                .unwrap();
+            actual.push(trade.exchange_time);
+        }
+
+        // Replayed trade is dropped, so every delivered trade is newer than the last
+        assert!(
+            actual.windows(2).all(|times| times[0] < times[1]),
+            "{actual:?}"
+        );
+        assert_eq!(exchange.connections(), 2);
+
+        consumer.abort();
+    }
+}
@@ -0,0 +1,226 @@
+use crate::exchange::ExchangeId;
+use chrono::{DateTime, Utc};
+use reqwest::header::HeaderMap;
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+/// [`BinanceSpot`](crate::exchange::binance::spot::BinanceSpot) REST request weight limit per
+/// minute, shared by every request from the same IP.
+pub const REQUEST_WEIGHT_LIMIT_BINANCE_SPOT: u32 = 6000;
+
+/// [`BinanceFuturesUsd`](crate::exchange::binance::futures::BinanceFuturesUsd) REST request
+/// weight limit per minute, shared by every request from the same IP.
+pub const REQUEST_WEIGHT_LIMIT_BINANCE_FUTURES_USD: u32 = 2400;
+
+/// [`Binance`](crate::exchange::binance::Binance) REST response header containing the request
+/// weight used by the IP within the current minute.
+pub const HEADER_USED_WEIGHT_BINANCE: &str = "x-mbx-used-weight-1m";
+
+/// Rate-limit usage of an exchange, as last reported by one of its REST responses or WebSocket
+/// throttle notices.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct RateLimitUsage {
+    pub exchange: ExchangeId,
+    pub used: u32,
+    pub limit: u32,
+    pub time: DateTime<Utc>,
+}
+
+impl RateLimitUsage {
+    /// Parse the [`RateLimitUsage`] reported by the [`HeaderMap`] of an exchange REST response,
+    /// if the exchange reports it & the header is present.
+    ///
+    /// Currently only the [`Binance`](crate::exchange::binance::Binance) request weight header
+    /// is supported.
+    pub fn from_headers(exchange: ExchangeId, headers: &HeaderMap) -> Option<Self> {
+        let (header, limit) = match exchange {
+            ExchangeId::BinanceSpot => (
+                HEADER_USED_WEIGHT_BINANCE,
+                REQUEST_WEIGHT_LIMIT_BINANCE_SPOT,
+            ),
+            ExchangeId::BinanceFuturesUsd => (
+                HEADER_USED_WEIGHT_BINANCE,
+                REQUEST_WEIGHT_LIMIT_BINANCE_FUTURES_USD,
+            ),
+            _ => return None,
+        };
+
+        let used = headers.get(header)?.to_str().ok()?.trim().parse().ok()?;
+
+        Some(Self {
+            exchange,
+            used,
+            limit,
+            time: Utc::now(),
+        })
+    }
+
+    /// Construct a [`RateLimitUsage`] of the exchange that has reached its `limit`, as reported by
+    /// an exchange throttle notice (eg/ Okx WebSocket error code 60014).
+    pub fn exhausted(exchange: ExchangeId, limit: u32) -> Self {
+        Self {
+            exchange,
+            used: limit,
+            limit,
+            time: Utc::now(),
+        }
+    }
+
+    /// Fraction of the rate limit used, where 1.0 means the limit has been reached.
+    pub fn utilisation(&self) -> f64 {
+        if self.limit == 0 {
+            return 1.0;
+        }
+        f64::from(self.used) / f64::from(self.limit)
+    }
+
+    /// Remaining usage before the rate limit is reached.
+    pub fn remaining(&self) -> u32 {
+        self.limit.saturating_sub(self.used)
+    }
+}
+
+/// Registry of the latest [`RateLimitUsage`] of each exchange, allowing users to throttle their
+/// own REST usage (eg/ OrderBook snapshot re-fetches & trade backfills) before being limited.
+///
+/// Cloning a [`RateLimitMonitor`] shares the same registry, so inject (a clone of) one monitor via
+/// the [`ConnectionConfig`](crate::subscriber::config::ConnectionConfig) of every connection that
+/// shares an IP to reflect their combined usage.
+#[derive(Debug, Clone, Default)]
+pub struct RateLimitMonitor {
+    usages: Arc<RwLock<HashMap<ExchangeId, RateLimitUsage>>>,
+}
+
+impl PartialEq for RateLimitMonitor {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.usages, &other.usages)
+    }
+}
+
+impl Eq for RateLimitMonitor {}
+
+impl RateLimitMonitor {
+    /// Record the provided [`RateLimitUsage`], replacing any previous usage of the exchange.
+    pub fn record(&self, usage: RateLimitUsage) {
+        self.usages
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(usage.exchange, usage);
+    }
+
+    /// Record the [`RateLimitUsage`] reported by the [`HeaderMap`] of an exchange REST
+    /// response, if any, returning it.
+    pub fn record_headers(
+        &self,
+        exchange: ExchangeId,
+        headers: &HeaderMap,
+    ) -> Option<RateLimitUsage> {
+        let usage = RateLimitUsage::from_headers(exchange, headers)?;
+        self.record(usage);
+        Some(usage)
+    }
+
+    /// Latest [`RateLimitUsage`] of the exchange, if any has been reported.
+    pub fn usage(&self, exchange: ExchangeId) -> Option<RateLimitUsage> {
+        self.usages
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(&exchange)
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_rate_limit_usage_from_headers() {
+        struct TestCase {
+            exchange: ExchangeId,
+            header: Option<&'static str>,
+            expected: Option<(u32, u32)>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: BinanceSpot used weight
+                exchange: ExchangeId::BinanceSpot,
+                header: Some("1200"),
+                expected: Some((1200, REQUEST_WEIGHT_LIMIT_BINANCE_SPOT)),
+            },
+            TestCase {
+                // TC1: BinanceFuturesUsd used weight
+                exchange: ExchangeId::BinanceFuturesUsd,
+                header: Some("1200"),
+                expected: Some((1200, REQUEST_WEIGHT_LIMIT_BINANCE_FUTURES_USD)),
+            },
+            TestCase {
+                // TC2: response without a used weight header
+                exchange: ExchangeId::BinanceSpot,
+                header: None,
+                expected: None,
+            },
+            TestCase {
+                // TC3: non-numeric used weight header
+                exchange: ExchangeId::BinanceSpot,
+                header: Some("unknown"),
+                expected: None,
+            },
+            TestCase {
+                // TC4: exchange that does not report a used weight
+                exchange: ExchangeId::Okx,
+                header: Some("1200"),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut headers = HeaderMap::new();
+            if let Some(header) = test.header {
+                headers.insert(HEADER_USED_WEIGHT_BINANCE, HeaderValue::from_static(header));
+            }
+
+            let actual = RateLimitUsage::from_headers(test.exchange, &headers)
+                .map(|usage| (usage.used, usage.limit));
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_rate_limit_monitor_records_latest_usage() {
+        let monitor = RateLimitMonitor::default();
+        let mut headers = HeaderMap::new();
+
+        for used in ["600", "1800"] {
+            headers.insert(HEADER_USED_WEIGHT_BINANCE, HeaderValue::from_static(used));
+            monitor.record_headers(ExchangeId::BinanceFuturesUsd, &headers);
+        }
+
+        let usage = monitor.usage(ExchangeId::BinanceFuturesUsd).unwrap();
+        assert_eq!(usage.used, 1800);
+        assert_eq!(usage.remaining(), 600);
+        assert_eq!(usage.utilisation(), 0.75);
+        assert_eq!(monitor.usage(ExchangeId::BinanceSpot), None);
+    }
+
+    #[test]
+    fn test_rate_limit_monitor_clones_share_usage() {
+        let monitor = RateLimitMonitor::default();
+        let other = RateLimitMonitor::default();
+
+        monitor
+            .clone()
+            .record(RateLimitUsage::exhausted(ExchangeId::Okx, 3));
+
+        let usage = monitor.usage(ExchangeId::Okx).unwrap();
+        assert_eq!(usage.utilisation(), 1.0);
+        assert_eq!(usage.remaining(), 0);
+        assert_eq!(other.usage(ExchangeId::Okx), None);
+        assert_eq!(monitor, monitor.clone());
+        assert_ne!(monitor, other);
+    }
+}
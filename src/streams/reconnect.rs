@@ -0,0 +1,220 @@
+use crate::error::DataError;
+use std::{fmt::Debug, time::Duration};
+
+/// Initial [`Duration`] the default [`ExponentialBackoff`] [`ReconnectPolicy`] waits after
+/// disconnecting before attempting to re-initialise a [`MarketStream`](crate::MarketStream).
+pub const STARTING_RECONNECT_BACKOFF: Duration = Duration::from_millis(125);
+
+/// Maximum [`Duration`] the default [`ExponentialBackoff`] [`ReconnectPolicy`] waits between
+/// re-initialisation attempts.
+pub const MAX_RECONNECT_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Default [`Duration`] a [`ReconnectPolicy`] waits before re-initialising a
+/// [`MarketStream`](crate::MarketStream) after the exchange signalled it is closed for
+/// maintenance.
+pub const MAINTENANCE_RECONNECT_BACKOFF: Duration = Duration::from_secs(5 * 60);
+
+/// Classification of a [`DataError`] by
+/// [`Connector::classify_error`](crate::exchange::Connector::classify_error), used by the
+/// consumer loop to decide whether to re-connect.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum ErrorClass {
+    /// Transient error (eg/ rate limited or exchange server error), so the
+    /// [`MarketStream`](crate::MarketStream) is re-initialised using the [`ReconnectPolicy`],
+    /// even if it has never been initialised successfully.
+    Retryable,
+    /// Unrecoverable error (eg/ authentication failure or invalid symbol), so the consumer loop
+    /// ends, returning the error without attempting re-connection.
+    Terminal,
+}
+
+impl ErrorClass {
+    /// Classify the [`DataError`] as [`ErrorClass::Terminal`] or [`ErrorClass::Retryable`] if it
+    /// contains any of the associated signals (see [`DataError::contains_any`]), preferring
+    /// [`ErrorClass::Terminal`].
+    pub fn classify(error: &DataError, retryable: &[&str], terminal: &[&str]) -> Option<Self> {
+        if error.contains_any(terminal) {
+            Some(Self::Terminal)
+        } else if error.contains_any(retryable) {
+            Some(Self::Retryable)
+        } else {
+            None
+        }
+    }
+}
+
+/// Defines how long the consumer loop waits before attempting to re-initialise a disconnected
+/// [`MarketStream`](crate::MarketStream), or if it should give up entirely.
+pub trait ReconnectPolicy
+where
+    Self: Debug + Send + Sync,
+{
+    /// Determine the [`Duration`] to wait before the next re-initialisation `attempt`, where
+    /// `attempt` is the number of consecutive re-initialisation attempts so far (starting at 1).
+    ///
+    /// The `error` that caused the disconnection or the failed re-initialisation is provided if
+    /// one is available (eg/ is `None` if the [`MarketStream`](crate::MarketStream) ended).
+    ///
+    /// Returning `None` gives up re-connecting, terminating the consumer loop.
+    fn next_delay(&self, attempt: u32, error: Option<&DataError>) -> Option<Duration>;
+
+    /// Determine the [`Duration`] to wait before re-initialising after the exchange signalled it
+    /// is closed for maintenance (see [`Connector::is_maintenance`](crate::exchange::Connector::is_maintenance)).
+    ///
+    /// Maintenance waits do not count towards the re-initialisation `attempt`s.
+    ///
+    /// Defaults to [`MAINTENANCE_RECONNECT_BACKOFF`].
+    fn maintenance_delay(&self) -> Duration {
+        MAINTENANCE_RECONNECT_BACKOFF
+    }
+}
+
+/// [`ReconnectPolicy`] that doubles the delay after every consecutive re-initialisation attempt,
+/// up to a maximum delay.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct ExponentialBackoff {
+    pub initial: Duration,
+    pub max: Duration,
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for ExponentialBackoff {
+    fn default() -> Self {
+        Self {
+            initial: STARTING_RECONNECT_BACKOFF,
+            max: MAX_RECONNECT_BACKOFF,
+            max_attempts: None,
+        }
+    }
+}
+
+impl ReconnectPolicy for ExponentialBackoff {
+    fn next_delay(&self, attempt: u32, _: Option<&DataError>) -> Option<Duration> {
+        if matches!(self.max_attempts, Some(max) if attempt > max) {
+            return None;
+        }
+
+        let multiplier = 2u32.saturating_pow(attempt.saturating_sub(1));
+        Some(self.initial.saturating_mul(multiplier).min(self.max))
+    }
+}
+
+/// [`ReconnectPolicy`] that always waits the same delay between re-initialisation attempts.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct FixedDelay {
+    pub delay: Duration,
+    pub max_attempts: Option<u32>,
+}
+
+impl ReconnectPolicy for FixedDelay {
+    fn next_delay(&self, attempt: u32, _: Option<&DataError>) -> Option<Duration> {
+        match self.max_attempts {
+            Some(max) if attempt > max => None,
+            _ => Some(self.delay),
+        }
+    }
+}
+
+/// Wait the [`Duration`] determined by the [`ReconnectPolicy`] for the next re-initialisation
+/// `attempt`. Returns `false` if the [`ReconnectPolicy`] has given up re-connecting.
+pub async fn wait<Policy>(policy: &Policy, attempt: u32, error: Option<&DataError>) -> bool
+where
+    Policy: ReconnectPolicy + ?Sized,
+{
+    match policy.next_delay(attempt, error) {
+        Some(delay) => {
+            tokio::time::sleep(delay).await;
+            true
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exponential_backoff_next_delay() {
+        let policy = ExponentialBackoff {
+            initial: Duration::from_millis(100),
+            max: Duration::from_millis(500),
+            max_attempts: Some(5),
+        };
+
+        let actual = (1..=6)
+            .map(|attempt| policy.next_delay(attempt, None))
+            .collect::<Vec<_>>();
+
+        let expected = vec![
+            Some(Duration::from_millis(100)),
+            Some(Duration::from_millis(200)),
+            Some(Duration::from_millis(400)),
+            Some(Duration::from_millis(500)),
+            Some(Duration::from_millis(500)),
+            None,
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_fixed_delay_next_delay() {
+        let policy = FixedDelay {
+            delay: Duration::from_millis(250),
+            max_attempts: Some(2),
+        };
+
+        let actual = (1..=3)
+            .map(|attempt| policy.next_delay(attempt, None))
+            .collect::<Vec<_>>();
+
+        let expected = vec![
+            Some(Duration::from_millis(250)),
+            Some(Duration::from_millis(250)),
+            None,
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_respects_custom_policy_timing() {
+        #[derive(Debug)]
+        struct Alternating;
+
+        impl ReconnectPolicy for Alternating {
+            fn next_delay(&self, attempt: u32, _: Option<&DataError>) -> Option<Duration> {
+                match attempt {
+                    1..=4 if attempt % 2 == 1 => Some(Duration::from_millis(100)),
+                    1..=4 => Some(Duration::from_millis(1000)),
+                    _ => None,
+                }
+            }
+        }
+
+        let policy = Alternating;
+        let mut elapsed = Vec::new();
+
+        let mut attempt = 1;
+        loop {
+            let start = tokio::time::Instant::now();
+            if !wait(&policy, attempt, None).await {
+                break;
+            }
+            elapsed.push(start.elapsed());
+            attempt += 1;
+        }
+
+        assert_eq!(
+            elapsed,
+            vec![
+                Duration::from_millis(100),
+                Duration::from_millis(1000),
+                Duration::from_millis(100),
+                Duration::from_millis(1000),
+            ]
+        );
+        assert_eq!(attempt, 5);
+    }
+}
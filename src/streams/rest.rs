@@ -0,0 +1,32 @@
+use crate::{
+    exchange::ExchangeId, streams::ratelimit::RateLimitMonitor,
+    subscriber::config::DEFAULT_USER_AGENT,
+};
+use barter_integration::error::SocketError;
+use reqwest::IntoUrl;
+use serde::de::DeserializeOwned;
+
+/// Send a GET request to the exchange REST `url`, recording any rate-limit usage reported by the
+/// response headers in the provided [`RateLimitMonitor`], and deserialise the JSON response body.
+pub async fn get_json<Response>(
+    client: &reqwest::Client,
+    exchange: ExchangeId,
+    url: impl IntoUrl,
+    rate_limits: Option<&RateLimitMonitor>,
+) -> Result<Response, SocketError>
+where
+    Response: DeserializeOwned,
+{
+    let response = client
+        .get(url)
+        .header(reqwest::header::USER_AGENT, DEFAULT_USER_AGENT)
+        .send()
+        .await
+        .map_err(SocketError::Http)?;
+
+    if let Some(rate_limits) = rate_limits {
+        rate_limits.record_headers(exchange, response.headers());
+    }
+
+    response.json::<Response>().await.map_err(SocketError::Http)
+}
@@ -0,0 +1,203 @@
+use super::{
+    broadcast::{Broadcast, BroadcastReceiver},
+    Streams,
+};
+use std::fmt::{Debug, Formatter};
+use tokio::{sync::mpsc, task::JoinHandle};
+
+/// Communicative type alias for the user callback invoked by an [`OutputSink::Callback`] with
+/// every event.
+pub type OutputCallback<T> = Box<dyn FnMut(T) + Send>;
+
+/// Delivery mechanism of the events of every exchange of a set of [`Streams`], selected when they
+/// are initialised (see [`StreamBuilder::init_sink`](super::builder::StreamBuilder::init_sink)).
+pub enum OutputSink<T> {
+    /// Deliver events via a single consumer [`mpsc::Receiver`] buffering up to `capacity`
+    /// events.
+    Mpsc { capacity: usize },
+    /// Deliver events via a multi-consumer [`Broadcast`] buffering up to `capacity` events for
+    /// each consumer.
+    Broadcast { capacity: usize },
+    /// Invoke the user callback with every event on a dedicated task.
+    Callback(OutputCallback<T>),
+}
+
+impl<T> OutputSink<T> {
+    /// Construct an [`OutputSink::Callback`] from the provided user callback.
+    pub fn callback<F>(callback: F) -> Self
+    where
+        F: FnMut(T) + Send + 'static,
+    {
+        Self::Callback(Box::new(callback))
+    }
+}
+
+impl<T> Debug for OutputSink<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Mpsc { capacity } => f.debug_struct("Mpsc").field("capacity", capacity).finish(),
+            Self::Broadcast { capacity } => f
+                .debug_struct("Broadcast")
+                .field("capacity", capacity)
+                .finish(),
+            Self::Callback(_) => f.write_str("Callback"),
+        }
+    }
+}
+
+/// Output handle of [`Streams`] delivered via an [`OutputSink`].
+#[derive(Debug)]
+pub enum Output<T> {
+    Mpsc(mpsc::Receiver<T>),
+    Broadcast(Broadcast<T>),
+    /// [`JoinHandle`] of the task invoking the [`OutputSink::Callback`], which completes once
+    /// every exchange stream has terminated.
+    Callback(JoinHandle<()>),
+}
+
+impl<T> Output<T> {
+    /// Take the [`mpsc::Receiver`] of an [`Output::Mpsc`].
+    pub fn into_mpsc(self) -> Option<mpsc::Receiver<T>> {
+        match self {
+            Self::Mpsc(rx) => Some(rx),
+            _ => None,
+        }
+    }
+
+    /// Subscribe a new consumer to an [`Output::Broadcast`].
+    pub fn subscribe(&self) -> Option<BroadcastReceiver<T>>
+    where
+        T: Clone + Send + 'static,
+    {
+        match self {
+            Self::Broadcast(broadcast) => Some(broadcast.subscribe()),
+            _ => None,
+        }
+    }
+}
+
+impl<T> Streams<T>
+where
+    T: Send + 'static,
+{
+    /// Join all exchange [`mpsc::Receiver`] streams & deliver their events via the provided
+    /// [`OutputSink`].
+    ///
+    /// Panics if the capacity of an [`OutputSink::Mpsc`] or [`OutputSink::Broadcast`] is 0.
+    pub async fn into_sink(self, sink: OutputSink<T>) -> Output<T>
+    where
+        T: Clone,
+    {
+        match sink {
+            OutputSink::Mpsc { capacity } => Output::Mpsc(self.join_with_capacity(capacity)),
+            OutputSink::Broadcast { capacity } => {
+                Output::Broadcast(Broadcast::new(self.join_with_capacity(capacity), capacity))
+            }
+            OutputSink::Callback(mut callback) => {
+                let mut joined_rx = self.join().await;
+                Output::Callback(tokio::spawn(async move {
+                    while let Some(event) = joined_rx.recv().await {
+                        callback(event);
+                    }
+                }))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exchange::ExchangeId, streams::broadcast::BroadcastEvent};
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
+
+    fn streams(events: Vec<u64>) -> Streams<u64> {
+        let (tx, rx) = mpsc::channel(events.len().max(1));
+        for event in events {
+            tx.try_send(event).unwrap();
+        }
+
+        Streams {
+            streams: HashMap::from([(ExchangeId::BinanceSpot, rx)]),
+            clock_offsets: HashMap::new(),
+            latency: None,
+            health: Default::default(),
+            rate_limits: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_output_sink_mpsc() {
+        let mut rx = streams(vec![1, 2, 3])
+            .into_sink(OutputSink::Mpsc { capacity: 8 })
+            .await
+            .into_mpsc()
+            .unwrap();
+
+        let mut actual = vec![];
+        while let Some(event) = rx.recv().await {
+            actual.push(event);
+        }
+        assert_eq!(actual, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_output_sink_broadcast() {
+        let (tx, rx) = mpsc::channel(8);
+        let output = Streams {
+            streams: HashMap::from([(ExchangeId::BinanceSpot, rx)]),
+            clock_offsets: HashMap::new(),
+            latency: None,
+            health: Default::default(),
+            rate_limits: None,
+        }
+        .into_sink(OutputSink::Broadcast { capacity: 8 })
+        .await;
+
+        // Consumers subscribed before the events are sent each receive every event
+        let mut consumers = [output.subscribe().unwrap(), output.subscribe().unwrap()];
+        for event in [1, 2, 3] {
+            tx.send(event).await.unwrap();
+        }
+        drop(tx);
+        drop(output);
+
+        for (index, consumer) in consumers.iter_mut().enumerate() {
+            let mut actual = vec![];
+            while let Some(event) = consumer.recv().await {
+                actual.push(event);
+            }
+            assert_eq!(
+                actual,
+                vec![
+                    BroadcastEvent::Event(1),
+                    BroadcastEvent::Event(2),
+                    BroadcastEvent::Event(3)
+                ],
+                "consumer {} failed",
+                index
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_output_sink_callback() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let output = streams(vec![1, 2, 3])
+            .into_sink(OutputSink::callback({
+                let received = Arc::clone(&received);
+                move |event| received.lock().unwrap().push(event)
+            }))
+            .await;
+
+        let Output::Callback(handle) = output else {
+            panic!("OutputSink::Callback did not yield an Output::Callback")
+        };
+        handle.await.unwrap();
+
+        assert_eq!(*received.lock().unwrap(), vec![1, 2, 3]);
+    }
+}
@@ -0,0 +1,232 @@
+use crate::{error::DataError, subscription::Subscription};
+use barter_integration::error::SocketError;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    fmt::{Debug, Formatter},
+    fs,
+    path::Path,
+    sync::{Arc, RwLock},
+};
+
+/// Shared, runtime modifiable set of the [`Subscription`]s actioned by a consumer loop.
+///
+/// The consumer loop takes a fresh snapshot of the [`SubscriptionSet`] every time it initialises
+/// (or re-initialises) a [`MarketStream`](crate::MarketStream), so [`Subscription`]s added or
+/// removed at runtime are reflected in the subscribe requests sent upon the next re-connection,
+/// rather than the set captured at startup.
+///
+/// Cloning a [`SubscriptionSet`] yields another handle to the same underlying set.
+///
+/// A [`SubscriptionSet`] can be [`save`](Self::save)d to a JSON file & [`restore`](Self::restore)d
+/// on startup, so a restarted long-running collector resumes the same [`Subscription`]s.
+pub struct SubscriptionSet<Exchange, Kind> {
+    subscriptions: Arc<RwLock<Vec<Subscription<Exchange, Kind>>>>,
+}
+
+impl<Exchange, Kind> SubscriptionSet<Exchange, Kind>
+where
+    Subscription<Exchange, Kind>: Ord + Clone,
+{
+    /// Construct a new [`SubscriptionSet`] from the provided [`Subscription`]s, removing any
+    /// duplicates.
+    pub fn new<SubIter>(subscriptions: SubIter) -> Self
+    where
+        SubIter: IntoIterator<Item = Subscription<Exchange, Kind>>,
+    {
+        let mut subscriptions = subscriptions.into_iter().collect::<Vec<_>>();
+        subscriptions.sort();
+        subscriptions.dedup();
+
+        Self {
+            subscriptions: Arc::new(RwLock::new(subscriptions)),
+        }
+    }
+
+    /// Add a [`Subscription`], returning `false` if it was already present.
+    pub fn add(&self, subscription: Subscription<Exchange, Kind>) -> bool {
+        let mut subscriptions = self.write();
+        match subscriptions.binary_search(&subscription) {
+            Ok(_) => false,
+            Err(index) => {
+                subscriptions.insert(index, subscription);
+                true
+            }
+        }
+    }
+
+    /// Remove a [`Subscription`], returning `false` if it was not present.
+    pub fn remove(&self, subscription: &Subscription<Exchange, Kind>) -> bool {
+        let mut subscriptions = self.write();
+        match subscriptions.binary_search(subscription) {
+            Ok(index) => {
+                subscriptions.remove(index);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Snapshot of the current [`Subscription`]s.
+    pub fn snapshot(&self) -> Vec<Subscription<Exchange, Kind>> {
+        self.read().clone()
+    }
+
+    /// Number of current [`Subscription`]s.
+    pub fn len(&self) -> usize {
+        self.read().len()
+    }
+
+    /// Determine if there are no current [`Subscription`]s.
+    pub fn is_empty(&self) -> bool {
+        self.read().is_empty()
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, Vec<Subscription<Exchange, Kind>>> {
+        self.subscriptions
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, Vec<Subscription<Exchange, Kind>>> {
+        self.subscriptions
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<Exchange, Kind> SubscriptionSet<Exchange, Kind>
+where
+    Subscription<Exchange, Kind>: Ord + Clone + Serialize + DeserializeOwned,
+{
+    /// Persist the current [`Subscription`]s as JSON to the file at the provided path.
+    ///
+    /// The JSON is written to a temporary sibling file that then replaces the destination, so an
+    /// interrupted write never leaves a truncated file to be restored.
+    pub fn save<P>(&self, path: P) -> Result<(), DataError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let json = serde_json::to_vec_pretty(&*self.read()).map_err(SocketError::Serialise)?;
+
+        let mut temp = path.as_os_str().to_owned();
+        temp.push(".tmp");
+
+        fs::write(&temp, json)
+            .and_then(|_| fs::rename(&temp, path))
+            .map_err(|error| DataError::Persistence {
+                path: path.to_path_buf(),
+                error,
+            })
+    }
+
+    /// Construct a new [`SubscriptionSet`] from the [`Subscription`]s persisted to the file at
+    /// the provided path by [`save`](Self::save).
+    pub fn restore<P>(path: P) -> Result<Self, DataError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let json = fs::read(path).map_err(|error| DataError::Persistence {
+            path: path.to_path_buf(),
+            error,
+        })?;
+
+        serde_json::from_slice::<Vec<Subscription<Exchange, Kind>>>(&json)
+            .map(Self::new)
+            .map_err(|error| {
+                DataError::from(SocketError::Deserialise {
+                    error,
+                    payload: String::from_utf8_lossy(&json).into_owned(),
+                })
+            })
+    }
+}
+
+impl<Exchange, Kind> Clone for SubscriptionSet<Exchange, Kind> {
+    fn clone(&self) -> Self {
+        Self {
+            subscriptions: Arc::clone(&self.subscriptions),
+        }
+    }
+}
+
+impl<Exchange, Kind> Debug for SubscriptionSet<Exchange, Kind>
+where
+    Subscription<Exchange, Kind>: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.subscriptions.read() {
+            Ok(subscriptions) => f.debug_list().entries(subscriptions.iter()).finish(),
+            Err(_) => f.write_str("SubscriptionSet(<poisoned>)"),
+        }
+    }
+}
+
+impl<Exchange, Kind> From<Vec<Subscription<Exchange, Kind>>> for SubscriptionSet<Exchange, Kind>
+where
+    Subscription<Exchange, Kind>: Ord + Clone,
+{
+    fn from(subscriptions: Vec<Subscription<Exchange, Kind>>) -> Self {
+        Self::new(subscriptions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{binance::spot::BinanceSpot, okx::Okx},
+        subscription::{book::OrderBooksL1, trade::PublicTrades},
+    };
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("barter-data-{}-{name}.json", std::process::id()))
+    }
+
+    #[test]
+    fn test_subscription_set_save_and_restore() {
+        let path = temp_path("subscriptions");
+        let subscriptions = SubscriptionSet::new([
+            Subscription::from((Okx, "eth", "usdt", InstrumentKind::Perpetual, PublicTrades)),
+            Subscription::from((Okx, "btc", "usdt", InstrumentKind::Spot, PublicTrades)),
+        ]);
+        subscriptions.add(Subscription::from((
+            Okx,
+            "sol",
+            "usdt",
+            InstrumentKind::Spot,
+            PublicTrades,
+        )));
+
+        subscriptions.save(&path).unwrap();
+        let restored = SubscriptionSet::<Okx, PublicTrades>::restore(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(restored.snapshot(), subscriptions.snapshot());
+    }
+
+    #[test]
+    fn test_subscription_set_restore_errors() {
+        // Missing file
+        let actual = SubscriptionSet::<BinanceSpot, OrderBooksL1>::restore(temp_path("missing"));
+        assert!(matches!(actual, Err(DataError::Persistence { .. })));
+
+        // Subscriptions persisted for a different SubKind
+        let path = temp_path("mismatched");
+        SubscriptionSet::new([Subscription::from((
+            BinanceSpot::default(),
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            PublicTrades,
+        ))])
+        .save(&path)
+        .unwrap();
+
+        let actual = SubscriptionSet::<BinanceSpot, OrderBooksL1>::restore(&path);
+        fs::remove_file(&path).unwrap();
+        assert!(matches!(actual, Err(error) if error.is_deserialise()));
+    }
+}
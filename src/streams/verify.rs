@@ -0,0 +1,354 @@
+use crate::{
+    error::DataError,
+    event::MarketEvent,
+    subscription::book::{Level, OrderBook, OrderBookSide},
+};
+use async_trait::async_trait;
+use barter_integration::model::{instrument::Instrument, Exchange};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, time::Duration};
+use tokio::sync::mpsc;
+use tracing::{error, warn};
+
+/// Default interval between each [`BookVerifier`] cross-check.
+pub const DEFAULT_BOOK_VERIFY_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Source of reference [`OrderBook`] snapshots (eg/ an exchange REST endpoint) that a
+/// [`BookVerifier`] cross-checks locally maintained [`OrderBook`]s against.
+#[async_trait]
+pub trait BookSnapshotSource
+where
+    Self: Send + Sync,
+{
+    /// Fetch a fresh reference [`OrderBook`] snapshot for the provided [`Instrument`].
+    async fn snapshot(&self, instrument: &Instrument) -> Result<OrderBook, DataError>;
+}
+
+/// Tolerance within which a locally maintained [`OrderBook`] is considered consistent with a
+/// reference snapshot.
+///
+/// Only the best `depth` [`Level`]s of each side are compared, since a reference snapshot is
+/// fetched some time after the local [`OrderBook`] was last updated.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BookDriftTolerance {
+    /// Number of best [`Level`]s compared on each side of the [`OrderBook`].
+    pub depth: usize,
+    /// Maximum relative amount difference before a [`Level`] is considered mismatched.
+    pub amount: f64,
+    /// Maximum number of mismatched [`Level`]s before a [`BookDrift`] is emitted.
+    pub max_mismatched_levels: usize,
+}
+
+impl Default for BookDriftTolerance {
+    fn default() -> Self {
+        Self {
+            depth: 10,
+            amount: 0.01,
+            max_mismatched_levels: 2,
+        }
+    }
+}
+
+/// Emitted by a [`BookVerifier`] when a locally maintained [`OrderBook`] diverges from a
+/// reference snapshot beyond the [`BookDriftTolerance`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BookDrift {
+    pub exchange: Exchange,
+    pub instrument: Instrument,
+    pub time: DateTime<Utc>,
+    pub mismatched_levels: usize,
+    pub local: OrderBook,
+    pub reference: OrderBook,
+}
+
+/// Snapshot-consistency self-test for managed [`OrderBook`]s.
+///
+/// Periodically cross-checks the most recent locally maintained [`OrderBook`] of each
+/// [`Instrument`] against a fresh [`BookSnapshotSource`] snapshot, emitting a [`BookDrift`] if
+/// they diverge. Intended as a developer aid for validating [`OrderBook`] management, for
+/// example by sampling a production stream.
+#[derive(Debug)]
+pub struct BookVerifier<Source> {
+    pub source: Source,
+    pub interval: Duration,
+    pub tolerance: BookDriftTolerance,
+}
+
+impl<Source> BookVerifier<Source>
+where
+    Source: BookSnapshotSource + 'static,
+{
+    /// Construct a new [`BookVerifier`] using the [`DEFAULT_BOOK_VERIFY_INTERVAL`] and
+    /// [`BookDriftTolerance::default`].
+    pub fn new(source: Source) -> Self {
+        Self {
+            source,
+            interval: DEFAULT_BOOK_VERIFY_INTERVAL,
+            tolerance: BookDriftTolerance::default(),
+        }
+    }
+
+    /// Set the interval between each cross-check.
+    pub fn interval(self, interval: Duration) -> Self {
+        Self { interval, ..self }
+    }
+
+    /// Set the [`BookDriftTolerance`].
+    pub fn tolerance(self, tolerance: BookDriftTolerance) -> Self {
+        Self { tolerance, ..self }
+    }
+
+    /// Spawn a task that forwards every [`MarketEvent<OrderBook>`] from the provided
+    /// [`mpsc::Receiver`] to the returned [`mpsc::Receiver`], cross-checking the most recent
+    /// [`OrderBook`] of each [`Instrument`] every `interval` and sending any [`BookDrift`] via
+    /// the `drift_tx`.
+    pub fn spawn(
+        self,
+        mut book_rx: mpsc::Receiver<MarketEvent<OrderBook>>,
+        drift_tx: mpsc::UnboundedSender<BookDrift>,
+    ) -> mpsc::Receiver<MarketEvent<OrderBook>> {
+        let (verified_tx, verified_rx) = mpsc::channel(book_rx.max_capacity());
+
+        tokio::spawn(async move {
+            let mut latest = HashMap::<Instrument, MarketEvent<OrderBook>>::new();
+            let mut interval = tokio::time::interval(self.interval);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+            // First tick completes immediately, so skip it to allow OrderBooks to be received
+            interval.tick().await;
+
+            loop {
+                tokio::select! {
+                    event = book_rx.recv() => {
+                        let Some(event) = event else {
+                            break;
+                        };
+
+                        latest.insert(event.instrument.clone(), event.clone());
+                        if verified_tx.send(event).await.is_err() {
+                            break;
+                        }
+                    }
+                    _ = interval.tick() => {
+                        for event in latest.values() {
+                            if let Some(drift) = self.verify(event).await {
+                                let _ = drift_tx.send(drift);
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        verified_rx
+    }
+
+    /// Cross-check the provided [`MarketEvent<OrderBook>`] against a fresh reference snapshot,
+    /// returning a [`BookDrift`] if they diverge beyond the [`BookDriftTolerance`].
+    pub async fn verify(&self, event: &MarketEvent<OrderBook>) -> Option<BookDrift> {
+        let reference = match self.source.snapshot(&event.instrument).await {
+            Ok(reference) => reference,
+            Err(error) => {
+                error!(
+                    exchange = %event.exchange,
+                    instrument = %event.instrument,
+                    %error,
+                    "failed to fetch reference OrderBook snapshot for verification"
+                );
+                return None;
+            }
+        };
+
+        let mismatched_levels = mismatched_levels(&event.kind, &reference, &self.tolerance);
+        if mismatched_levels <= self.tolerance.max_mismatched_levels {
+            return None;
+        }
+
+        warn!(
+            exchange = %event.exchange,
+            instrument = %event.instrument,
+            mismatched_levels,
+            "locally maintained OrderBook drifted from reference snapshot"
+        );
+
+        Some(BookDrift {
+            exchange: event.exchange.clone(),
+            instrument: event.instrument.clone(),
+            time: Utc::now(),
+            mismatched_levels,
+            local: event.kind.clone(),
+            reference,
+        })
+    }
+}
+
+/// Count the number of best [`Level`]s on each side of the local [`OrderBook`] that are missing
+/// from, or differ in amount beyond the [`BookDriftTolerance`] to, the reference [`OrderBook`].
+pub fn mismatched_levels(
+    local: &OrderBook,
+    reference: &OrderBook,
+    tolerance: &BookDriftTolerance,
+) -> usize {
+    // Sort copies since snapshots are not guaranteed to be sorted
+    let mut local = local.clone();
+    let mut reference = reference.clone();
+    let local = local.snapshot();
+    let reference = reference.snapshot();
+
+    side_mismatches(&local.bids, &reference.bids, tolerance)
+        + side_mismatches(&local.asks, &reference.asks, tolerance)
+}
+
+fn side_mismatches(
+    local: &OrderBookSide,
+    reference: &OrderBookSide,
+    tolerance: &BookDriftTolerance,
+) -> usize {
+    let local = &local.levels()[..local.levels().len().min(tolerance.depth)];
+    let reference = &reference.levels()[..reference.levels().len().min(tolerance.depth)];
+
+    let missing = |levels: &[Level], other: &[Level]| {
+        levels
+            .iter()
+            .filter(|level| {
+                !other.iter().any(|other| {
+                    other.eq_price(level.price)
+                        && (other.amount - level.amount).abs()
+                            <= tolerance.amount * other.amount.abs().max(level.amount.abs())
+                })
+            })
+            .count()
+    };
+
+    // Levels only present in the reference book count as mismatches too
+    missing(local, reference).max(missing(reference, local))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::ExchangeId;
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+
+    struct MockSnapshotSource(OrderBook);
+
+    #[async_trait]
+    impl BookSnapshotSource for MockSnapshotSource {
+        async fn snapshot(&self, _: &Instrument) -> Result<OrderBook, DataError> {
+            Ok(self.0.clone())
+        }
+    }
+
+    fn book(bids: Vec<(f64, f64)>, asks: Vec<(f64, f64)>) -> OrderBook {
+        OrderBook {
+            last_update_time: Default::default(),
+            bids: OrderBookSide::new(Side::Buy, bids),
+            asks: OrderBookSide::new(Side::Sell, asks),
+        }
+    }
+
+    #[test]
+    fn test_mismatched_levels() {
+        struct TestCase {
+            local: OrderBook,
+            reference: OrderBook,
+            expected: usize,
+        }
+
+        let reference = book(
+            vec![(100.0, 1.0), (99.0, 2.0), (98.0, 3.0)],
+            vec![(101.0, 1.0), (102.0, 2.0)],
+        );
+
+        let tests = vec![
+            TestCase {
+                // TC0: identical books
+                local: reference.clone(),
+                reference: reference.clone(),
+                expected: 0,
+            },
+            TestCase {
+                // TC1: amount difference within tolerance
+                local: book(
+                    vec![(100.0, 1.005), (99.0, 2.0), (98.0, 3.0)],
+                    vec![(101.0, 1.0), (102.0, 2.0)],
+                ),
+                reference: reference.clone(),
+                expected: 0,
+            },
+            TestCase {
+                // TC2: amount difference beyond tolerance & missing ask level
+                local: book(
+                    vec![(100.0, 5.0), (99.0, 2.0), (98.0, 3.0)],
+                    vec![(102.0, 2.0)],
+                ),
+                reference: reference.clone(),
+                expected: 2,
+            },
+            TestCase {
+                // TC3: unsorted local book w/ a stale level not present in the reference
+                local: book(
+                    vec![(98.0, 3.0), (100.0, 1.0), (99.0, 2.0), (97.0, 1.0)],
+                    vec![(102.0, 2.0), (101.0, 1.0)],
+                ),
+                reference,
+                expected: 1,
+            },
+        ];
+
+        let tolerance = BookDriftTolerance::default();
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = mismatched_levels(&test.local, &test.reference, &tolerance);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_book_verifier_emits_drift() {
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let reference = book(
+            vec![(100.0, 1.0), (99.0, 2.0), (98.0, 3.0)],
+            vec![(101.0, 1.0), (102.0, 2.0), (103.0, 3.0)],
+        );
+
+        // Local book w/ a deliberate drift on every level
+        let drifted = MarketEvent {
+            exchange_time: Default::default(),
+            received_time: Default::default(),
+            exchange_time_synthesized: false,
+            exchange: Exchange::from(ExchangeId::BinanceSpot),
+            instrument: instrument.clone(),
+            kind: book(
+                vec![(100.0, 9.0), (99.0, 9.0), (98.0, 9.0)],
+                vec![(101.0, 9.0), (102.0, 9.0), (103.0, 9.0)],
+            ),
+        };
+
+        let (book_tx, book_rx) = mpsc::channel(10);
+        let (drift_tx, mut drift_rx) = mpsc::unbounded_channel();
+
+        let mut verified_rx = BookVerifier::new(MockSnapshotSource(reference.clone()))
+            .interval(Duration::from_secs(10))
+            .spawn(book_rx, drift_tx);
+
+        // OrderBook events pass through the verifier untouched
+        book_tx.send(drifted.clone()).await.unwrap();
+        assert_eq!(verified_rx.recv().await.unwrap().kind, drifted.kind);
+
+        let drift = drift_rx.recv().await.unwrap();
+        assert_eq!(drift.instrument, instrument);
+        assert_eq!(drift.mismatched_levels, 6);
+        assert_eq!(drift.local, drifted.kind);
+        assert_eq!(drift.reference, reference);
+
+        // Consistent local book does not emit a BookDrift
+        let mut consistent = drifted;
+        consistent.kind = reference;
+        book_tx.send(consistent).await.unwrap();
+        verified_rx.recv().await.unwrap();
+
+        tokio::time::sleep(Duration::from_secs(30)).await;
+        assert!(drift_rx.try_recv().is_err());
+    }
+}
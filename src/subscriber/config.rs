@@ -0,0 +1,698 @@
+use crate::{
+    dump::DebugDump,
+    exchange::{Connector, ExchangeId},
+    middleware::Middleware,
+    streams::{
+        consumer::{DeserializeErrorPolicy, LagPolicy},
+        ordering::InstrumentOrdering,
+        ratelimit::RateLimitMonitor,
+    },
+    subscription::filter::SymbolFilter,
+    transformer::book::{BookAnomalyPolicy, BookPruning, BookResume, BookSeed},
+};
+use barter_integration::{
+    error::SocketError,
+    protocol::websocket::{connect, WebSocket},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Debug, Formatter},
+    sync::Arc,
+    time::Duration,
+};
+use tokio::sync::Semaphore;
+use tokio_tungstenite::tungstenite::{
+    client::IntoClientRequest,
+    handshake::client::Request,
+    http::{
+        header::{HeaderName, USER_AGENT},
+        HeaderMap, HeaderValue,
+    },
+};
+use url::Url;
+
+/// Default `User-Agent` sent on every WebSocket upgrade request, identifying the crate & version.
+///
+/// eg/ "barter-data/0.7.0"
+pub const DEFAULT_USER_AGENT: &str =
+    concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// Default maximum duration of a WebSocket connect & upgrade handshake before it is abandoned.
+pub const DEFAULT_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Limit on the number of WebSocket connect & upgrade handshakes in flight at once, shared by
+/// every connection dialed with a [`ConnectionConfig`] (and its clones).
+///
+/// Used to establish many connections in controlled waves, rather than all at once, avoiding
+/// tripping network or exchange connection-rate limits.
+#[derive(Clone)]
+pub struct HandshakeLimit(Arc<Semaphore>);
+
+impl HandshakeLimit {
+    /// Construct a new [`HandshakeLimit`] allowing the provided number of concurrent handshakes.
+    ///
+    /// A limit of zero is treated as one, since no connection could otherwise be established.
+    pub fn new(max_concurrent: usize) -> Self {
+        Self(Arc::new(Semaphore::new(max_concurrent.max(1))))
+    }
+
+    /// Number of handshakes that can currently begin without waiting.
+    pub fn available(&self) -> usize {
+        self.0.available_permits()
+    }
+}
+
+impl Debug for HandshakeLimit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("HandshakeLimit")
+            .field(&self.0.available_permits())
+            .finish()
+    }
+}
+
+impl PartialEq for HandshakeLimit {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for HandshakeLimit {}
+
+/// Exchange account fee tier declared by the user, used to select the best channels available to
+/// the account (eg/ Okx "books-l2-tbt" requires VIP4+), and to reject channels the account cannot
+/// access before connecting (see
+/// [`Connector::select_channel`](crate::exchange::Connector::select_channel)).
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct AccountTier {
+    pub vip_level: u8,
+}
+
+impl AccountTier {
+    /// Regular (non-VIP) [`AccountTier`].
+    pub const REGULAR: Self = Self { vip_level: 0 };
+
+    /// Construct a new VIP [`AccountTier`] of the provided level.
+    pub fn vip(vip_level: u8) -> Self {
+        Self { vip_level }
+    }
+}
+
+/// Exchange API key [`Credentials`] used to log in to connections to exchanges that gate some
+/// public channels behind an authenticated connection (eg/ Okx "books-l2-tbt").
+///
+/// The `secret` & `passphrase` are redacted from the [`Debug`] output.
+#[derive(Clone, Eq, PartialEq)]
+pub struct Credentials {
+    pub api_key: String,
+    pub secret: String,
+    pub passphrase: Option<String>,
+}
+
+impl Credentials {
+    /// Construct new [`Credentials`] from the provided API key & secret.
+    pub fn new<S>(api_key: S, secret: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            api_key: api_key.into(),
+            secret: secret.into(),
+            passphrase: None,
+        }
+    }
+
+    /// Set the API key passphrase, required by some exchanges (eg/ Okx).
+    pub fn passphrase<S>(self, passphrase: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            passphrase: Some(passphrase.into()),
+            ..self
+        }
+    }
+}
+
+impl Debug for Credentials {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Credentials")
+            .field("api_key", &self.api_key)
+            .field("secret", &"<redacted>")
+            .field(
+                "passphrase",
+                &self.passphrase.as_ref().map(|_| "<redacted>"),
+            )
+            .finish()
+    }
+}
+
+/// Configuration applied to every WebSocket connection dialed by a
+/// [`Subscriber`](super::Subscriber), including re-connections.
+///
+/// By default only the [`DEFAULT_USER_AGENT`] header is sent on the upgrade request, and the
+/// handshake is abandoned after the [`DEFAULT_HANDSHAKE_TIMEOUT`]. No [`Middleware`] or pong
+/// timeout or [`HandshakeLimit`] is set, crossed or locked OrderBooks are handled with
+/// [`BookAnomalyPolicy::Emit`], seeded with [`BookSeed::Rest`] & are neither pruned nor resumed
+/// after a re-connection, malformed messages are handled with [`DeserializeErrorPolicy::Skip`],
+/// events consumed while the receiver is full wait for capacity (see [`LagPolicy::Backpressure`]),
+/// resent frames are neither de-duplicated nor dumped (see [`DebugDump`]), every symbol is subscribed to, events are only ordered
+/// within a connection (see [`InstrumentOrdering`]), rate-limit usage is not recorded (see
+/// [`RateLimitMonitor`]), connections are not logged in with any [`Credentials`], no
+/// [`AccountTier`] is declared, and the production exchange [`Connector::url`] is dialed.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ConnectionConfig {
+    pub headers: HeaderMap,
+    pub handshake_timeout: Duration,
+    pub middleware: Option<Middleware>,
+    pub pong_timeout: Option<Duration>,
+    pub book_anomaly_policy: BookAnomalyPolicy,
+    pub book_pruning: Option<BookPruning>,
+    pub book_resume: Option<BookResume>,
+    pub book_seed: BookSeed,
+    pub deserialize_error_policy: DeserializeErrorPolicy,
+    pub lag_policy: LagPolicy,
+    pub dedup_window: Option<usize>,
+    pub debug_dump: Option<DebugDump>,
+    pub handshake_limit: Option<HandshakeLimit>,
+    pub credentials: Option<Credentials>,
+    pub account_tier: Option<AccountTier>,
+    pub symbol_filter: Option<SymbolFilter>,
+    pub ordering: Option<InstrumentOrdering>,
+    pub rate_limits: Option<RateLimitMonitor>,
+    pub sandbox: bool,
+    pub url: Option<Url>,
+}
+
+impl Default for ConnectionConfig {
+    fn default() -> Self {
+        let mut headers = HeaderMap::new();
+        headers.insert(USER_AGENT, HeaderValue::from_static(DEFAULT_USER_AGENT));
+        Self {
+            headers,
+            handshake_timeout: DEFAULT_HANDSHAKE_TIMEOUT,
+            middleware: None,
+            pong_timeout: None,
+            book_anomaly_policy: BookAnomalyPolicy::default(),
+            book_pruning: None,
+            book_resume: None,
+            book_seed: BookSeed::default(),
+            deserialize_error_policy: DeserializeErrorPolicy::default(),
+            lag_policy: LagPolicy::default(),
+            dedup_window: None,
+            debug_dump: None,
+            handshake_limit: None,
+            credentials: None,
+            account_tier: None,
+            symbol_filter: None,
+            ordering: None,
+            rate_limits: None,
+            sandbox: false,
+            url: None,
+        }
+    }
+}
+
+impl ConnectionConfig {
+    /// Construct a new [`Self`] using the [`Default`] headers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override the `User-Agent` header sent on the WebSocket upgrade request.
+    #[allow(clippy::result_large_err)]
+    pub fn user_agent(self, user_agent: &str) -> Result<Self, SocketError> {
+        self.header(USER_AGENT.as_str(), user_agent)
+    }
+
+    /// Insert an arbitrary header sent on the WebSocket upgrade request, replacing any existing
+    /// value for the same header name.
+    #[allow(clippy::result_large_err)]
+    pub fn header(mut self, name: &str, value: &str) -> Result<Self, SocketError> {
+        let name = HeaderName::from_bytes(name.as_bytes()).map_err(|error| {
+            SocketError::Subscribe(format!("invalid header name {name}: {error}"))
+        })?;
+        let value = HeaderValue::from_str(value).map_err(|error| {
+            SocketError::Subscribe(format!("invalid header value {value}: {error}"))
+        })?;
+
+        self.headers.insert(name, value);
+        Ok(self)
+    }
+
+    /// Set the maximum duration of the WebSocket connect & upgrade handshake, after which the
+    /// dial fails so the re-connection path is taken rather than hanging indefinitely.
+    pub fn handshake_timeout(self, handshake_timeout: Duration) -> Self {
+        Self {
+            handshake_timeout,
+            ..self
+        }
+    }
+
+    /// Set a [`Middleware`] hook invoked with every raw inbound frame of each connection before
+    /// it is deserialised.
+    pub fn middleware<F>(self, hook: F) -> Self
+    where
+        F: Fn(&str, ExchangeId) + Send + Sync + 'static,
+    {
+        Self {
+            middleware: Some(Middleware::new(hook)),
+            ..self
+        }
+    }
+
+    /// Set the maximum duration to wait for any inbound frame (pong or data) after an
+    /// application-level ping is sent, after which the connection is considered dead &
+    /// re-connected.
+    ///
+    /// Only applies to exchanges that send application-level pings (see
+    /// [`Connector::ping_interval`](crate::exchange::Connector::ping_interval)).
+    pub fn pong_timeout(self, pong_timeout: Duration) -> Self {
+        Self {
+            pong_timeout: Some(pong_timeout),
+            ..self
+        }
+    }
+
+    /// Set the [`BookAnomalyPolicy`] applied when a maintained OrderBook is detected to be
+    /// crossed or locked after an update.
+    pub fn book_anomaly_policy(self, book_anomaly_policy: BookAnomalyPolicy) -> Self {
+        Self {
+            book_anomaly_policy,
+            ..self
+        }
+    }
+
+    /// Set the [`BookPruning`] applied to maintained OrderBooks, bounding the memory of
+    /// full-depth books while preserving the tradeable range.
+    pub fn book_pruning(self, book_pruning: BookPruning) -> Self {
+        Self {
+            book_pruning: Some(book_pruning),
+            ..self
+        }
+    }
+
+    /// Resume maintained OrderBooks after a re-connection within `max_gap` of the disconnection,
+    /// rather than re-initialising them from a fresh snapshot (see [`BookResume`]).
+    pub fn book_resume(self, max_gap: Duration) -> Self {
+        Self {
+            book_resume: Some(BookResume::new(max_gap)),
+            ..self
+        }
+    }
+
+    /// Set the [`BookSeed`] source of the initial OrderBook of every maintained OrderBook
+    /// (eg/ [`BookSeed::PartialDepth`] to avoid consuming REST request weight).
+    pub fn book_seed(self, book_seed: BookSeed) -> Self {
+        Self { book_seed, ..self }
+    }
+
+    /// Set the [`DeserializeErrorPolicy`] applied by the consumer loop when an inbound message
+    /// fails to deserialise.
+    pub fn deserialize_error_policy(
+        self,
+        deserialize_error_policy: DeserializeErrorPolicy,
+    ) -> Self {
+        Self {
+            deserialize_error_policy,
+            ..self
+        }
+    }
+
+    /// Set the [`LagPolicy`] applied by the consumer loop when a consumed event cannot be sent
+    /// because the exchange receiver is full.
+    ///
+    /// Defaults to the lossless [`LagPolicy::Backpressure`], so dropping events is opt-in via
+    /// [`LagPolicy::DropNewest`].
+    pub fn lag_policy(self, lag_policy: LagPolicy) -> Self {
+        Self { lag_policy, ..self }
+    }
+
+    /// Drop raw inbound frames that are byte-identical to any of the previous `window` frames of
+    /// the connection, before they are deserialised (see [`FrameWindow`](crate::dedup::FrameWindow)).
+    ///
+    /// Only enable for exchanges that never legitimately repeat a frame verbatim (eg/ an
+    /// unchanged ticker snapshot), since those repeats would also be dropped.
+    pub fn dedup_frames(self, window: usize) -> Self {
+        Self {
+            dedup_window: Some(window),
+            ..self
+        }
+    }
+
+    /// Pair every raw inbound data frame with its parsed outcome via the provided [`DebugDump`],
+    /// used to troubleshoot events missing from a connection.
+    pub fn debug_dump(self, debug_dump: DebugDump) -> Self {
+        Self {
+            debug_dump: Some(debug_dump),
+            ..self
+        }
+    }
+
+    /// Limit the number of WebSocket handshakes in flight at once to `max_concurrent`, across
+    /// every connection (and re-connection) dialed with this [`ConnectionConfig`] or its clones.
+    pub fn max_concurrent_handshakes(self, max_concurrent: usize) -> Self {
+        Self {
+            handshake_limit: Some(HandshakeLimit::new(max_concurrent)),
+            ..self
+        }
+    }
+
+    /// Log in every connection with the provided [`Credentials`] before subscribing, for
+    /// exchanges that support authenticated connections (see
+    /// [`Connector::login`](crate::exchange::Connector::login)).
+    pub fn credentials(self, credentials: Credentials) -> Self {
+        Self {
+            credentials: Some(credentials),
+            ..self
+        }
+    }
+
+    /// Declare the [`AccountTier`] of the exchange account, so the best channel available to it
+    /// is subscribed to, and any channel it cannot access fails before connecting.
+    pub fn account_tier(self, account_tier: AccountTier) -> Self {
+        Self {
+            account_tier: Some(account_tier),
+            ..self
+        }
+    }
+
+    /// Apply the provided [`SymbolFilter`] allow & deny lists to the [`Subscription`]s actioned
+    /// by every (re)initialisation, and to the markets expanded from any
+    /// [`WILDCARD`](crate::subscription::WILDCARD) [`Subscription`].
+    ///
+    /// [`Subscription`]: crate::subscription::Subscription
+    pub fn symbol_filter(self, symbol_filter: SymbolFilter) -> Self {
+        Self {
+            symbol_filter: Some(symbol_filter),
+            ..self
+        }
+    }
+
+    /// Deliver the events of every connection configured with (a clone of) the same
+    /// [`InstrumentOrdering`] through a single ordered path, so the events of each instrument are
+    /// strictly ordered even when consumed from many (or rebuilt) connections.
+    pub fn ordering(self, ordering: InstrumentOrdering) -> Self {
+        Self {
+            ordering: Some(ordering),
+            ..self
+        }
+    }
+
+    /// Record the rate-limit usage reported by exchange REST responses (eg/ OrderBook snapshots)
+    /// & WebSocket throttle notices of every connection configured with (a clone of) the same
+    /// [`RateLimitMonitor`], which is also used by the [`Streams`](crate::streams::Streams)
+    /// REST backfill.
+    pub fn rate_limits(self, rate_limits: RateLimitMonitor) -> Self {
+        Self {
+            rate_limits: Some(rate_limits),
+            ..self
+        }
+    }
+
+    /// Dial the exchange [`Connector::sandbox_url`] rather than the production
+    /// [`Connector::url`], failing to connect if the exchange has no sandbox environment.
+    pub fn sandbox(self) -> Self {
+        Self {
+            sandbox: true,
+            ..self
+        }
+    }
+
+    /// Dial the provided [`Url`] rather than the exchange [`Connector::url`] (eg/ to connect via
+    /// a proxy, or to a [`MockExchange`](crate::test_util::MockExchange) in integration tests).
+    /// Takes precedence over [`Self::sandbox`].
+    pub fn url(self, url: Url) -> Self {
+        Self {
+            url: Some(url),
+            ..self
+        }
+    }
+
+    /// Determine the [`Url`] dialed for the provided exchange [`Connector`], being the configured
+    /// [`Url`] if any, else the [`Connector::sandbox_url`] in sandbox mode, else the production
+    /// [`Connector::url`].
+    #[allow(clippy::result_large_err)]
+    pub fn exchange_url<Exchange>(&self) -> Result<Url, SocketError>
+    where
+        Exchange: Connector,
+    {
+        match (&self.url, self.sandbox) {
+            (Some(url), _) => Ok(url.clone()),
+            (None, true) => Exchange::sandbox_url(),
+            (None, false) => Exchange::url(),
+        }
+    }
+
+    /// Construct the WebSocket upgrade [`Request`] for the provided [`Url`], applying the
+    /// configured headers.
+    #[allow(clippy::result_large_err)]
+    pub fn request(&self, url: Url) -> Result<Request, SocketError> {
+        let mut request = url.into_client_request().map_err(SocketError::WebSocket)?;
+        request.headers_mut().extend(self.headers.clone());
+        Ok(request)
+    }
+
+    /// Connect to the provided [`Url`] using a WebSocket upgrade [`Request`] with the configured
+    /// headers applied, failing if the handshake does not complete within the
+    /// `handshake_timeout`.
+    ///
+    /// If a [`HandshakeLimit`] is configured, the handshake waits for capacity before starting,
+    /// and the `handshake_timeout` only applies once it has started.
+    pub async fn connect(&self, url: Url) -> Result<WebSocket, SocketError> {
+        let request = self.request(url)?;
+
+        // Hold a HandshakeLimit permit (if any) until the handshake completes or fails
+        let _permit = match &self.handshake_limit {
+            Some(limit) => Some(limit.0.acquire().await.map_err(|_| {
+                SocketError::Subscribe("HandshakeLimit semaphore closed".to_owned())
+            })?),
+            None => None,
+        };
+
+        tokio::time::timeout(self.handshake_timeout, connect(request))
+            .await
+            .map_err(|_| {
+                SocketError::Subscribe(format!(
+                    "WebSocket handshake timeout reached: {:?}",
+                    self.handshake_timeout
+                ))
+            })?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::{coinbase::Coinbase, kraken::v2::KrakenV2, kraken::Kraken, okx::Okx};
+
+    #[test]
+    fn test_connection_config_request() {
+        struct TestCase {
+            input: ConnectionConfig,
+            expected: Vec<(&'static str, &'static str)>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: default config w/ crate User-Agent
+                input: ConnectionConfig::default(),
+                expected: vec![("user-agent", DEFAULT_USER_AGENT)],
+            },
+            TestCase {
+                // TC1: custom User-Agent & additional header
+                input: ConnectionConfig::default()
+                    .user_agent("custom-agent/1.0")
+                    .unwrap()
+                    .header("X-Api-Key", "key")
+                    .unwrap(),
+                expected: vec![("user-agent", "custom-agent/1.0"), ("x-api-key", "key")],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let request = test
+                .input
+                .request(Url::parse("wss://stream.exchange.com/ws").unwrap())
+                .unwrap();
+
+            for (name, value) in test.expected {
+                assert_eq!(
+                    request.headers().get(name).unwrap(),
+                    value,
+                    "TC{} failed",
+                    index
+                );
+            }
+        }
+    }
+
+    #[tokio::test]
+    #[allow(clippy::result_large_err)]
+    async fn test_connection_config_headers_sent_in_upgrade_request() {
+        use tokio_tungstenite::tungstenite::handshake::server::{
+            ErrorResponse, Request as ServerRequest, Response,
+        };
+
+        // Mock exchange server that captures the headers of the upgrade request
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = Url::parse(&format!("ws://{}", listener.local_addr().unwrap())).unwrap();
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut captured = HeaderMap::new();
+            let _websocket = tokio_tungstenite::accept_hdr_async(
+                stream,
+                |request: &ServerRequest, response: Response| -> Result<Response, ErrorResponse> {
+                    captured = request.headers().clone();
+                    Ok(response)
+                },
+            )
+            .await
+            .unwrap();
+            captured
+        });
+
+        let config = ConnectionConfig::default()
+            .user_agent("custom-agent/1.0")
+            .unwrap()
+            .header("X-Api-Key", "key")
+            .unwrap();
+
+        let _websocket = config.connect(url).await.unwrap();
+        let captured = server.await.unwrap();
+
+        assert_eq!(captured.get("user-agent").unwrap(), "custom-agent/1.0");
+        assert_eq!(captured.get("x-api-key").unwrap(), "key");
+    }
+
+    #[tokio::test]
+    async fn test_connection_config_handshake_timeout() {
+        // Non-responsive endpoint that accepts the TCP connection but never upgrades it
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = Url::parse(&format!("ws://{}", listener.local_addr().unwrap())).unwrap();
+        let _server = tokio::spawn(async move {
+            let (_stream, _) = listener.accept().await.unwrap();
+            std::future::pending::<()>().await
+        });
+
+        let timeout = Duration::from_millis(200);
+        let config = ConnectionConfig::default().handshake_timeout(timeout);
+
+        let start = std::time::Instant::now();
+        let result = config.connect(url).await;
+        let elapsed = start.elapsed();
+
+        assert!(matches!(result, Err(SocketError::Subscribe(_))));
+        assert!(elapsed >= timeout);
+        assert!(elapsed < Duration::from_secs(5), "dial took {elapsed:?}");
+    }
+
+    #[tokio::test]
+    async fn test_connection_config_max_concurrent_handshakes() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        const MAX_CONCURRENT: usize = 2;
+        const CONNECTIONS: usize = 8;
+
+        // Mock exchange server that delays each upgrade, tracking the handshakes in flight
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = Url::parse(&format!("ws://{}", listener.local_addr().unwrap())).unwrap();
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+        let _server = tokio::spawn({
+            let (in_flight, max_in_flight) = (Arc::clone(&in_flight), Arc::clone(&max_in_flight));
+            async move {
+                loop {
+                    let (stream, _) = listener.accept().await.unwrap();
+                    let (in_flight, max_in_flight) =
+                        (Arc::clone(&in_flight), Arc::clone(&max_in_flight));
+                    tokio::spawn(async move {
+                        let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                        max_in_flight.fetch_max(current, Ordering::SeqCst);
+                        tokio::time::sleep(Duration::from_millis(50)).await;
+
+                        // Handshake is no longer counted as in flight once the upgrade begins
+                        in_flight.fetch_sub(1, Ordering::SeqCst);
+                        let _websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+                        std::future::pending::<()>().await
+                    });
+                }
+            }
+        });
+
+        let config = ConnectionConfig::default().max_concurrent_handshakes(MAX_CONCURRENT);
+
+        let websockets = futures::future::join_all((0..CONNECTIONS).map(|_| {
+            let (config, url) = (config.clone(), url.clone());
+            async move { config.connect(url).await }
+        }))
+        .await;
+
+        assert!(websockets.iter().all(Result::is_ok));
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), MAX_CONCURRENT);
+        assert_eq!(config.handshake_limit.unwrap().available(), MAX_CONCURRENT);
+    }
+
+    #[test]
+    fn test_connection_config_invalid_header() {
+        assert!(ConnectionConfig::default()
+            .header("invalid header", "value")
+            .is_err());
+        assert!(ConnectionConfig::default()
+            .header("x-valid", "invalid\nvalue")
+            .is_err());
+    }
+
+    #[test]
+    fn test_connection_config_exchange_url() {
+        struct TestCase {
+            input: ConnectionConfig,
+            expected: [&'static str; 3],
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: production servers are dialed by default
+                input: ConnectionConfig::default(),
+                expected: [
+                    "wss://ws-feed.exchange.coinbase.com/",
+                    "wss://ws.kraken.com/",
+                    "wss://ws.kraken.com/v2",
+                ],
+            },
+            TestCase {
+                // TC1: sandbox servers are dialed in sandbox mode
+                input: ConnectionConfig::default().sandbox(),
+                expected: [
+                    "wss://ws-feed-public.sandbox.exchange.coinbase.com/",
+                    "wss://beta-ws.kraken.com/",
+                    "wss://beta-ws.kraken.com/v2",
+                ],
+            },
+            TestCase {
+                // TC2: configured Url takes precedence over sandbox mode
+                input: ConnectionConfig::default()
+                    .sandbox()
+                    .url(Url::parse("ws://127.0.0.1:8080/").unwrap()),
+                expected: ["ws://127.0.0.1:8080/"; 3],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = [
+                test.input.exchange_url::<Coinbase>().unwrap(),
+                test.input.exchange_url::<Kraken>().unwrap(),
+                test.input.exchange_url::<KrakenV2>().unwrap(),
+            ]
+            .map(String::from);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_connection_config_exchange_url_without_sandbox() {
+        let config = ConnectionConfig::default().sandbox();
+        assert!(config.exchange_url::<Okx>().is_err());
+    }
+}
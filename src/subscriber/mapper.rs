@@ -1,16 +1,25 @@
+use super::config::ConnectionConfig;
 use crate::{
-    exchange::{subscription::ExchangeSub, Connector},
+    exchange::{extract_request_id, subscription::ExchangeSub, Connector},
     subscription::{Map, SubKind, Subscription, SubscriptionMeta},
     Identifier,
 };
-use barter_integration::model::SubscriptionId;
+use barter_integration::{error::SocketError, model::SubscriptionId};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use tracing::warn;
 
 /// Defines how to map a collection of Barter [`Subscription`]s into exchange specific
 /// [`SubscriptionMeta`], containing subscription payloads that are sent to the exchange.
+///
+/// Each channel is selected via [`Connector::select_channel`] for the provided
+/// [`ConnectionConfig`], failing if any channel cannot be accessed.
 pub trait SubscriptionMapper {
-    fn map<Exchange, Kind>(subscriptions: &[Subscription<Exchange, Kind>]) -> SubscriptionMeta
+    #[allow(clippy::result_large_err)]
+    fn map<Exchange, Kind>(
+        subscriptions: &[Subscription<Exchange, Kind>],
+        config: &ConnectionConfig,
+    ) -> Result<SubscriptionMeta, SocketError>
     where
         Exchange: Connector,
         Kind: SubKind,
@@ -23,7 +32,10 @@ pub trait SubscriptionMapper {
 pub struct WebSocketSubMapper;
 
 impl SubscriptionMapper for WebSocketSubMapper {
-    fn map<Exchange, Kind>(subscriptions: &[Subscription<Exchange, Kind>]) -> SubscriptionMeta
+    fn map<Exchange, Kind>(
+        subscriptions: &[Subscription<Exchange, Kind>],
+        config: &ConnectionConfig,
+    ) -> Result<SubscriptionMeta, SocketError>
     where
         Exchange: Connector,
         Kind: SubKind,
@@ -34,30 +46,184 @@ impl SubscriptionMapper for WebSocketSubMapper {
         let mut instrument_map = Map(HashMap::with_capacity(subscriptions.len()));
 
         // Map Barter Subscriptions to exchange specific subscriptions
-        let exchange_subs = subscriptions
-            .iter()
-            .map(|subscription| {
-                // Translate Barter Subscription to exchange specific subscription
-                let exchange_sub = ExchangeSub::new(subscription);
+        let mut exchange_subs = Vec::with_capacity(subscriptions.len());
+        for subscription in subscriptions {
+            // Translate Barter Subscription to exchange specific subscription, selecting the
+            // channel available to the configured connection
+            let ExchangeSub { channel, market } = ExchangeSub::new(subscription);
+            let exchange_sub = ExchangeSub {
+                channel: Exchange::select_channel(channel, config)?,
+                market,
+            };
 
-                // Determine the SubscriptionId associated with this exchange specific subscription
-                let subscription_id = exchange_sub.id();
+            // Determine the SubscriptionId associated with this exchange specific subscription
+            let subscription_id = exchange_sub.id();
+
+            // Skip exact duplicate Subscriptions, since a duplicate subscription would skew the
+            // expected number of responses. Distinct Instruments or channels colliding on the
+            // same SubscriptionId cannot be told apart once consumed, so fail instead.
+            if let Some(existing) = instrument_map.0.get(&subscription_id) {
+                if *existing != subscription.instrument {
+                    return Err(SocketError::Subscribe(format!(
+                        "{} Subscriptions for distinct Instruments {existing} & {} collide on \
+                         SubscriptionId: {subscription_id}",
+                        Exchange::ID,
+                        subscription.instrument,
+                    )));
+                }
+
+                if exchange_subs.iter().any(|existing: &ExchangeSub<_, _>| {
+                    existing.id() == subscription_id && existing.channel != exchange_sub.channel
+                }) {
+                    return Err(SocketError::Subscribe(format!(
+                        "{} Subscriptions for {} via distinct channels collide on \
+                         SubscriptionId: {subscription_id}",
+                        Exchange::ID,
+                        subscription.instrument,
+                    )));
+                }
+
+                warn!(
+                    exchange = %Exchange::ID,
+                    %subscription_id,
+                    instrument = %subscription.instrument,
+                    "ignoring duplicate Subscription"
+                );
+                continue;
+            }
+
+            // Determine any associated ExchangeSubs combined into the same stream
+            let associated_subs = Exchange::associated_subs(&exchange_sub);
+
+            // Use ExchangeSub SubscriptionId as the link to this Barter Subscription
+            instrument_map
+                .0
+                .insert(subscription_id, subscription.instrument.clone());
+
+            exchange_subs.push(exchange_sub);
+
+            // Associated ExchangeSubs may be shared by several Subscriptions, so are only
+            // actioned once
+            for associated_sub in associated_subs {
+                let subscription_id = associated_sub.id();
+                if instrument_map.0.contains_key(&subscription_id) {
+                    continue;
+                }
 
-                // Use ExchangeSub SubscriptionId as the link to this Barter Subscription
                 instrument_map
                     .0
                     .insert(subscription_id, subscription.instrument.clone());
+                exchange_subs.push(associated_sub);
+            }
+        }
+
+        // Construct WebSocket message subscriptions requests, respecting any per request cap
+        let subscriptions = Exchange::batched_requests(exchange_subs);
 
-                exchange_sub
+        // Track any client-supplied request ids so responses can be correlated with requests
+        let request_ids = Exchange::request_id_field()
+            .map(|field| {
+                subscriptions
+                    .iter()
+                    .filter_map(|request| extract_request_id(field, request))
+                    .collect()
             })
-            .collect::<Vec<ExchangeSub<Exchange::Channel, Exchange::Market>>>();
+            .unwrap_or_default();
 
-        // Construct WebSocket message subscriptions requests
-        let subscriptions = Exchange::requests(exchange_subs);
+        Ok(SubscriptionMeta {
+            instrument_map,
+            subscriptions,
+            request_ids,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exchange::binance::futures::BinanceFuturesUsd, subscription::trade::PublicTrades};
+    use barter_integration::{
+        model::instrument::{
+            kind::{FutureContract, InstrumentKind},
+            Instrument,
+        },
+        protocol::websocket::WsMessage,
+    };
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_websocket_sub_mapper_dedupes_duplicate_subscriptions() {
+        let perpetual = Instrument::from(("btc", "usdt", InstrumentKind::Perpetual));
 
-        SubscriptionMeta {
+        let subscriptions = vec![
+            Subscription::new(
+                BinanceFuturesUsd::default(),
+                perpetual.clone(),
+                PublicTrades,
+            ),
+            // Exact duplicate
+            Subscription::new(
+                BinanceFuturesUsd::default(),
+                perpetual.clone(),
+                PublicTrades,
+            ),
+            Subscription::new(
+                BinanceFuturesUsd::default(),
+                Instrument::from(("eth", "usdt", InstrumentKind::Perpetual)),
+                PublicTrades,
+            ),
+        ];
+
+        let SubscriptionMeta {
             instrument_map,
             subscriptions,
-        }
+            ..
+        } = WebSocketSubMapper::map(&subscriptions, &ConnectionConfig::default()).unwrap();
+
+        // Exact duplicate is only actioned once
+        assert_eq!(instrument_map.0.len(), 2);
+        assert_eq!(
+            instrument_map
+                .find(&SubscriptionId::from("@trade|BTCUSDT"))
+                .unwrap(),
+            perpetual
+        );
+
+        // Expected responses match the deduplicated subscription requests, so validation does
+        // not wait for responses that never arrive
+        assert_eq!(
+            BinanceFuturesUsd::expected_responses(&instrument_map),
+            subscriptions.len()
+        );
+        let WsMessage::Text(payload) = &subscriptions[0] else {
+            panic!("expected text subscription request");
+        };
+        assert_eq!(payload.matches("@trade").count(), 2);
+    }
+
+    #[test]
+    fn test_websocket_sub_mapper_rejects_colliding_subscription_ids() {
+        let future = Instrument::from((
+            "btc",
+            "usdt",
+            InstrumentKind::Future(FutureContract {
+                expiry: Utc.with_ymd_and_hms(2023, 6, 30, 8, 0, 0).unwrap(),
+            }),
+        ));
+
+        // Distinct Instruments that map to the same "@trade|BTCUSDT" SubscriptionId
+        let subscriptions = vec![
+            Subscription::new(
+                BinanceFuturesUsd::default(),
+                Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+                PublicTrades,
+            ),
+            Subscription::new(BinanceFuturesUsd::default(), future, PublicTrades),
+        ];
+
+        assert!(matches!(
+            WebSocketSubMapper::map(&subscriptions, &ConnectionConfig::default()),
+            Err(SocketError::Subscribe(_))
+        ));
     }
 }
@@ -1,26 +1,37 @@
 use self::{
+    config::ConnectionConfig,
     mapper::{SubscriptionMapper, WebSocketSubMapper},
-    validator::SubscriptionValidator,
+    pacer::RequestPacer,
+    validator::{validate_login, SubscriptionValidator},
 };
 use crate::{
     exchange::Connector,
     subscription::{Map, SubKind, Subscription, SubscriptionMeta},
+    writer::WriteQueue,
     Identifier,
 };
 use async_trait::async_trait;
 use barter_integration::{
     error::SocketError,
     model::instrument::Instrument,
-    protocol::websocket::{connect, WebSocket},
+    protocol::websocket::{WsMessage, WsStream},
 };
-use futures::SinkExt;
+use futures::StreamExt;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info};
 
+/// [`ConnectionConfig`](config::ConnectionConfig) applied to every WebSocket connection dial (eg/
+/// custom upgrade request headers).
+pub mod config;
+
 /// [`SubscriptionMapper`](mapper::SubscriptionMapper) implementations defining how to map a
 /// collection of Barter [`Subscription`]s into exchange specific [`SubscriptionMeta`].
 pub mod mapper;
 
+/// [`RequestPacer`](pacer::RequestPacer) that paces outbound subscribe requests to respect an
+/// exchange [`RequestRateLimit`](pacer::RequestRateLimit).
+pub mod pacer;
+
 /// [`SubscriptionValidator`](validator::SubscriptionValidator) implementations defining how to
 /// validate actioned [`Subscription`]s were successful.
 pub mod validator;
@@ -30,16 +41,25 @@ pub mod validator;
 pub trait Subscriber {
     type SubMapper: SubscriptionMapper;
 
+    /// Connect & subscribe, returning the connection [`WriteQueue`] & [`WsStream`], validated
+    /// [`Map<Instrument>`] & any data frames received before validation completed (see
+    /// [`SubscriptionValidator::validate`]).
+    ///
+    /// Every outbound message, including the connection, login & subscription requests, is
+    /// submitted to the returned [`WriteQueue`], so it is written ahead of any message submitted
+    /// afterwards (eg/ pings & transformer requests).
     async fn subscribe<Exchange, Kind>(
         subscriptions: &[Subscription<Exchange, Kind>],
-    ) -> Result<(WebSocket, Map<Instrument>), SocketError>
+        config: &ConnectionConfig,
+    ) -> Result<(WriteQueue, WsStream, Map<Instrument>, Vec<WsMessage>), SocketError>
     where
         Exchange: Connector + Send + Sync,
         Kind: SubKind + Send + Sync,
         Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>;
 }
 
-/// Standard [`Subscriber`] for [`WebSocket`]s suitable for most exchanges.
+/// Standard [`Subscriber`] for [`WebSocket`](barter_integration::protocol::websocket::WebSocket)s
+/// suitable for most exchanges.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct WebSocketSubscriber;
 
@@ -49,7 +69,8 @@ impl Subscriber for WebSocketSubscriber {
 
     async fn subscribe<Exchange, Kind>(
         subscriptions: &[Subscription<Exchange, Kind>],
-    ) -> Result<(WebSocket, Map<Instrument>), SocketError>
+        config: &ConnectionConfig,
+    ) -> Result<(WriteQueue, WsStream, Map<Instrument>, Vec<WsMessage>), SocketError>
     where
         Exchange: Connector + Send + Sync,
         Kind: SubKind + Send + Sync,
@@ -57,31 +78,109 @@ impl Subscriber for WebSocketSubscriber {
     {
         // Define variables for logging ergonomics
         let exchange = Exchange::ID;
-        let url = Exchange::url()?;
+        let url = config.exchange_url::<Exchange>()?;
         debug!(%exchange, %url, ?subscriptions, "subscribing to WebSocket");
 
         // Connect to exchange
-        let mut websocket = connect(url).await?;
+        let websocket = config.connect(url).await?;
         debug!(%exchange, ?subscriptions, "connected to WebSocket");
 
+        // Split WebSocket into WsSink & WsStream components, spawning the single writer task
+        // that writes every outbound message of this connection in submission order
+        let (ws_sink, mut ws_stream) = websocket.split();
+        let write_queue = WriteQueue::spawn(exchange, ws_sink);
+
+        // Configure the connection before logging in & subscribing
+        for request in Exchange::connection_requests() {
+            debug!(%exchange, payload = ?request, "sending exchange connection request");
+            write_queue.send(request)?;
+        }
+
+        // Log in to the exchange before subscribing, if configured with Credentials
+        if let Some(login) = config.credentials.as_ref().and_then(Exchange::login) {
+            debug!(%exchange, "sending exchange login");
+            write_queue.send(login)?;
+            validate_login::<Exchange>(&mut ws_stream).await?;
+            debug!(%exchange, "logged in to WebSocket");
+        }
+
         // Map &[Subscription<Exchange, Kind>] to SubscriptionMeta
         let SubscriptionMeta {
             instrument_map,
             subscriptions,
-        } = Self::SubMapper::map::<Exchange, Kind>(subscriptions);
+            request_ids,
+        } = Self::SubMapper::map::<Exchange, Kind>(subscriptions, config)?;
 
-        // Send Subscriptions over WebSocket
+        // Send Subscriptions over WebSocket, paced to the exchange subscribe rate limit
+        let mut pacer = RequestPacer::new(Exchange::subscription_rate_limit());
         for subscription in subscriptions {
+            pacer.ready().await;
             debug!(%exchange, payload = ?subscription, "sending exchange subscription");
-            websocket.send(subscription).await?;
+            write_queue.send(subscription)?;
         }
 
         // Validate Subscription responses
-        let map =
-            Exchange::SubValidator::validate::<Exchange, Kind>(instrument_map, &mut websocket)
-                .await?;
+        let (map, buffered) = Exchange::SubValidator::validate::<Exchange, Kind>(
+            instrument_map,
+            &request_ids,
+            &mut ws_stream,
+        )
+        .await?;
 
         info!(%exchange, "subscribed to WebSocket");
-        Ok((websocket, map))
+        Ok((write_queue, ws_stream, map, buffered))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::bitfinex::Bitfinex,
+        subscription::trade::PublicTrades,
+        test_util::{MockExchange, MockScript},
+    };
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    #[tokio::test]
+    async fn test_websocket_subscriber_writes_every_request_via_write_queue_in_order() {
+        let script = MockScript::new()
+            .receive()
+            .send_text(r#"{"event":"conf","status":"OK","flags":98304}"#)
+            .receive()
+            .receive()
+            .send_text(r#"{"event":"subscribed","channel":"trades","chanId":420191,"symbol":"tBTCUSD","pair":"BTCUSD"}"#)
+            .send_text(r#"{"event":"subscribed","channel":"trades","chanId":420192,"symbol":"tETHUSD","pair":"ETHUSD"}"#)
+            .receive();
+        let mut exchange = MockExchange::start([script]).await.unwrap();
+
+        let subscriptions = [
+            Subscription::from((Bitfinex, "btc", "usd", InstrumentKind::Spot, PublicTrades)),
+            Subscription::from((Bitfinex, "eth", "usd", InstrumentKind::Spot, PublicTrades)),
+        ];
+        let config = ConnectionConfig::default().url(exchange.url());
+        let (write_queue, _ws_stream, map, _) =
+            WebSocketSubscriber::subscribe(&subscriptions, &config)
+                .await
+                .unwrap();
+        assert_eq!(map.0.len(), 2);
+
+        // Message submitted after subscribing is written after every subscription request
+        let after = WsMessage::text(r#"{"event":"ping","cid":1}"#);
+        write_queue.send(after.clone()).unwrap();
+
+        let mut requests = Vec::new();
+        for _ in 0..4 {
+            requests.push(exchange.next_request().await.unwrap().message);
+        }
+
+        assert_eq!(requests[0], Bitfinex::connection_requests()[0]);
+        for request in &requests[1..3] {
+            let WsMessage::Text(request) = request else {
+                panic!("Bitfinex subscription request is not a text WsMessage")
+            };
+            assert!(request.contains(r#""event":"subscribe""#));
+        }
+        assert_eq!(requests[3], after);
     }
 }
@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+use std::{collections::VecDeque, time::Duration};
+use tokio::time::Instant;
+
+/// Maximum number of subscribe requests an exchange server accepts within an interval on a
+/// single connection.
+///
+/// eg/ Okx accepts 3 requests per second per connection.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct RequestRateLimit {
+    pub requests: usize,
+    pub interval: Duration,
+}
+
+/// Paces outbound subscribe requests to respect a [`RequestRateLimit`], queuing any request that
+/// would exceed the limit until the oldest request within the interval has expired.
+///
+/// Used by the [`WebSocketSubscriber`](super::WebSocketSubscriber) when sending both initial &
+/// re-connection subscribe requests, so bursting many requests does not get throttled.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct RequestPacer {
+    pub limit: Option<RequestRateLimit>,
+    sent: VecDeque<Instant>,
+}
+
+impl RequestPacer {
+    /// Construct a new [`RequestPacer`] for the optional [`RequestRateLimit`]. When `None`,
+    /// requests are never queued.
+    pub fn new(limit: Option<RequestRateLimit>) -> Self {
+        Self {
+            limit,
+            sent: VecDeque::new(),
+        }
+    }
+
+    /// Wait until another request can be sent without exceeding the [`RequestRateLimit`], and
+    /// record it as sent.
+    pub async fn ready(&mut self) {
+        let Some(limit) = self.limit.filter(|limit| limit.requests > 0) else {
+            return;
+        };
+
+        if self.sent.len() >= limit.requests {
+            if let Some(oldest) = self.sent.pop_front() {
+                tokio::time::sleep_until(oldest + limit.interval).await;
+            }
+        }
+
+        self.sent.push_back(Instant::now());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_request_pacer_respects_rate_limit() {
+        let limit = RequestRateLimit {
+            requests: 3,
+            interval: Duration::from_secs(1),
+        };
+        let mut pacer = RequestPacer::new(Some(limit));
+
+        let start = Instant::now();
+        let mut sent = Vec::new();
+        for _ in 0..10 {
+            pacer.ready().await;
+            sent.push(Instant::now() - start);
+        }
+
+        // No more than limit.requests are sent within any limit.interval window
+        for (index, time) in sent.iter().enumerate().skip(limit.requests) {
+            assert!(
+                *time - sent[index - limit.requests] >= limit.interval,
+                "request {index} sent at {time:?} exceeds the rate limit: {sent:?}"
+            );
+        }
+
+        // First burst is sent immediately, then paced to the rate limit
+        assert_eq!(sent[2], Duration::ZERO);
+        assert_eq!(sent[9], Duration::from_secs(3));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_request_pacer_without_rate_limit() {
+        let mut pacer = RequestPacer::new(None);
+
+        let start = Instant::now();
+        for _ in 0..100 {
+            pacer.ready().await;
+        }
+
+        assert_eq!(Instant::now() - start, Duration::ZERO);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    exchange::Connector,
+    exchange::{extract_request_id, Connector},
     subscription::{Map, SubKind},
 };
 use async_trait::async_trait;
@@ -7,14 +7,43 @@ use barter_integration::{
     error::SocketError,
     model::instrument::Instrument,
     protocol::{
-        websocket::{WebSocket, WebSocketParser},
+        websocket::{WebSocketParser, WsError, WsMessage, WsStream},
         StreamParser,
     },
     Validator,
 };
-use futures::StreamExt;
+use futures::{Stream, StreamExt};
 use serde::{Deserialize, Serialize};
-use tracing::debug;
+use std::{
+    collections::{HashSet, VecDeque},
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tracing::{debug, warn};
+
+/// Maximum number of inbound data frames buffered while validating the subscriptions of a single
+/// connection, after which further early data frames are discarded.
+pub const MAX_BUFFERED_FRAMES: usize = 10_000;
+
+/// Strategy used by the [`WebSocketSubValidator`] to determine that actioned
+/// [`Subscription`](crate::subscription::Subscription)s were accepted by the exchange, configured
+/// per exchange via [`Connector::validation_strategy`].
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum ValidationStrategy {
+    /// Subscriptions are validated once the [`Connector::expected_responses`] success
+    /// [`Connector::SubResponse`]s are received.
+    #[default]
+    Ack,
+    /// Subscriptions are validated once the first data frame arrives, for exchanges that do not
+    /// acknowledge subscriptions. Any success [`Connector::SubResponse`]s received beforehand are
+    /// still counted, and error responses still fail validation.
+    FirstData,
+    /// Subscriptions are assumed valid as soon as the requests are sent, without waiting for any
+    /// response.
+    Immediate,
+}
 
 /// Defines how to validate that actioned market data
 /// [`Subscription`](crate::subscription::Subscription)s were accepted by the exchange.
@@ -22,16 +51,125 @@ use tracing::debug;
 pub trait SubscriptionValidator {
     type Parser: StreamParser;
 
+    /// Validate the subscription responses received over the [`WsStream`], using the
+    /// client-supplied `request_ids` of the sent requests (if any) to correlate responses.
+    ///
+    /// Returns the validated [`Map<Instrument>`] & the data frames received before validation
+    /// completed (eg/ from exchanges that start pushing data before every subscription is
+    /// acknowledged), which are replayed ahead of the [`WsStream`] via a [`BufferedStream`].
     async fn validate<Exchange, Kind>(
         instrument_map: Map<Instrument>,
-        websocket: &mut WebSocket,
-    ) -> Result<Map<Instrument>, SocketError>
+        request_ids: &[u64],
+        websocket: &mut WsStream,
+    ) -> Result<(Map<Instrument>, Vec<WsMessage>), SocketError>
     where
         Exchange: Connector + Send,
         Kind: SubKind + Send;
 }
 
-/// Standard [`SubscriptionValidator`] for [`WebSocket`]s suitable for most exchanges.
+/// Validate the response to a [`Connector::login`] request received over the [`WsStream`],
+/// which is expected to be the first [`Connector::SubResponse`] received.
+pub async fn validate_login<Exchange>(websocket: &mut WsStream) -> Result<(), SocketError>
+where
+    Exchange: Connector,
+{
+    let timeout = Exchange::subscription_timeout();
+
+    loop {
+        tokio::select! {
+            // If timeout reached, return SubscribeError
+            _ = tokio::time::sleep(timeout) => {
+                break Err(SocketError::Subscribe(
+                    format!("login validation timeout reached: {:?}", timeout)
+                ))
+            },
+            // Parse incoming messages until the login outcome is determined
+            message = websocket.next() => {
+                let response = match message {
+                    Some(response) => response,
+                    None => break Err(SocketError::Subscribe("WebSocket stream terminated unexpectedly".to_string()))
+                };
+
+                match WebSocketParser::parse::<Exchange::SubResponse>(response) {
+                    Some(Ok(response)) => match response.validate() {
+                        // Login success
+                        Ok(response) => {
+                            debug!(exchange = %Exchange::ID, payload = ?response, "received valid Ok login response");
+                            break Ok(())
+                        }
+
+                        // Login failure
+                        Err(err) => break Err(err)
+                    }
+                    Some(Err(SocketError::Terminated(close_frame))) => {
+                        break Err(SocketError::Subscribe(
+                            format!("received WebSocket CloseFrame: {close_frame}")
+                        ))
+                    }
+                    _ => {
+                        // Pings, Pongs, Frames, etc.
+                        continue
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Buffer an inbound data frame received before subscription validation completed, up to the
+/// [`MAX_BUFFERED_FRAMES`].
+pub fn buffer_frame(
+    exchange: crate::exchange::ExchangeId,
+    buffered: &mut Vec<WsMessage>,
+    frame: WsMessage,
+) {
+    if buffered.len() < MAX_BUFFERED_FRAMES {
+        buffered.push(frame);
+    } else {
+        warn!(
+            %exchange,
+            max = MAX_BUFFERED_FRAMES,
+            action = "discarding frame",
+            "buffered data frames received before subscription validation reached max"
+        );
+    }
+}
+
+/// [`Stream`] adapter that yields the data frames buffered during subscription validation before
+/// any further frames of the inner [`WsStream`] stream, so early data frames are routed using the
+/// validated [`Map<Instrument>`] rather than being dropped.
+#[derive(Debug)]
+pub struct BufferedStream<St = WsStream> {
+    buffered: VecDeque<WsMessage>,
+    stream: St,
+}
+
+impl<St> BufferedStream<St> {
+    /// Construct a new [`BufferedStream`] that yields the `buffered` frames (in order) before
+    /// those of the inner [`Stream`].
+    pub fn new(buffered: Vec<WsMessage>, stream: St) -> Self {
+        Self {
+            buffered: VecDeque::from(buffered),
+            stream,
+        }
+    }
+}
+
+impl<St> Stream for BufferedStream<St>
+where
+    St: Stream<Item = Result<WsMessage, WsError>> + Unpin,
+{
+    type Item = Result<WsMessage, WsError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.buffered.pop_front() {
+            Some(frame) => Poll::Ready(Some(Ok(frame))),
+            None => Pin::new(&mut self.stream).poll_next(cx),
+        }
+    }
+}
+
+/// Standard [`SubscriptionValidator`] for WebSockets suitable for most exchanges.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct WebSocketSubValidator;
 
@@ -41,24 +179,73 @@ impl SubscriptionValidator for WebSocketSubValidator {
 
     async fn validate<Exchange, Kind>(
         instrument_map: Map<Instrument>,
-        websocket: &mut WebSocket,
-    ) -> Result<Map<Instrument>, SocketError>
+        request_ids: &[u64],
+        websocket: &mut WsStream,
+    ) -> Result<(Map<Instrument>, Vec<WsMessage>), SocketError>
     where
         Exchange: Connector + Send,
         Kind: SubKind + Send,
     {
-        // Establish exchange specific subscription validation parameters
+        Self::validate_with_strategy::<Exchange>(
+            Exchange::validation_strategy(),
+            instrument_map,
+            request_ids,
+            websocket,
+        )
+        .await
+    }
+}
+
+impl WebSocketSubValidator {
+    /// Validate the subscription responses received over the [`WsStream`] using the provided
+    /// [`ValidationStrategy`] rather than the [`Connector::validation_strategy`].
+    pub async fn validate_with_strategy<Exchange>(
+        strategy: ValidationStrategy,
+        instrument_map: Map<Instrument>,
+        request_ids: &[u64],
+        websocket: &mut WsStream,
+    ) -> Result<(Map<Instrument>, Vec<WsMessage>), SocketError>
+    where
+        Exchange: Connector,
+    {
+        if strategy == ValidationStrategy::Immediate {
+            debug!(
+                exchange = %Exchange::ID,
+                ?strategy,
+                "validated exchange WebSocket subscriptions without awaiting responses"
+            );
+            return Ok((instrument_map, Vec::new()));
+        }
+
         let timeout = Exchange::subscription_timeout();
         let expected_responses = Exchange::expected_responses(&instrument_map);
 
         // Parameter to keep track of successful Subscription outcomes
         let mut success_responses = 0usize;
 
+        // Request ids yet to be acknowledged by a correlated success response, since repeated
+        // acks for the same request must not validate the Subscriptions of another request
+        let mut unacked_request_ids = HashSet::<u64>::new();
+        let mut correlated = false;
+        if Exchange::request_id_field().is_some() {
+            unacked_request_ids.extend(request_ids.iter().copied());
+        }
+
+        // Data frames received before every Subscription is validated
+        let mut buffered = Vec::new();
+
         loop {
-            // Break if all Subscriptions were a success
-            if success_responses == expected_responses {
-                debug!(exchange = %Exchange::ID, "validated exchange WebSocket subscriptions");
-                break Ok(instrument_map);
+            // Break if all Subscriptions were a success, including an ack for every request id
+            // if the exchange correlates its responses
+            if success_responses >= expected_responses
+                && (!correlated || unacked_request_ids.is_empty())
+            {
+                debug!(
+                    exchange = %Exchange::ID,
+                    buffered = buffered.len(),
+                    "validated exchange WebSocket subscriptions"
+                );
+                break Ok((instrument_map, buffered));
             }
 
             tokio::select! {
@@ -75,11 +262,36 @@ impl SubscriptionValidator for WebSocketSubValidator {
                         None => break Err(SocketError::Subscribe("WebSocket stream terminated unexpectedly".to_string()))
                     };
 
-                    match Self::Parser::parse::<Exchange::SubResponse>(response) {
+                    // Ignore responses correlated with a request that was not sent by this connection
+                    let request_id = match (Exchange::request_id_field(), &response) {
+                        (Some(field), Ok(message)) => extract_request_id(field, message),
+                        _ => None,
+                    };
+                    if let Some(request_id) = request_id.filter(|id| !request_ids.contains(id)) {
+                        debug!(
+                            exchange = %Exchange::ID,
+                            request_id,
+                            ?request_ids,
+                            "ignoring subscription response with unknown request id"
+                        );
+                        continue
+                    }
+
+                    // Retain data frames, which are buffered if they are not a SubResponse
+                    let frame = match &response {
+                        Ok(frame @ (WsMessage::Text(_) | WsMessage::Binary(_))) => Some(frame.clone()),
+                        _ => None,
+                    };
+
+                    match WebSocketParser::parse::<Exchange::SubResponse>(response) {
                         Some(Ok(response)) => match response.validate() {
                             // Subscription success
                             Ok(response) => {
                                 success_responses += 1;
+                                if let Some(request_id) = request_id {
+                                    correlated = true;
+                                    unacked_request_ids.remove(&request_id);
+                                }
                                 debug!(
                                     exchange = %Exchange::ID,
                                     %success_responses,
@@ -92,16 +304,30 @@ impl SubscriptionValidator for WebSocketSubValidator {
                             // Subscription failure
                             Err(err) => break Err(err)
                         }
-                        Some(Err(SocketError::Deserialise { error, payload })) if success_responses >= 1 => {
-                            // Already active subscription payloads, so skip to next SubResponse
+                        Some(Err(SocketError::Deserialise { error, payload })) => {
+                            // Data payloads received before validation completed, so buffer them
+                            // to be routed once every Subscription is validated
                             debug!(
                                 exchange = %Exchange::ID,
                                 ?error,
                                 %success_responses,
                                 %expected_responses,
                                 %payload,
-                                "failed to deserialise non SubResponse payload"
+                                "buffering non SubResponse payload received during validation"
                             );
+                            if let Some(frame) = frame {
+                                buffer_frame(Exchange::ID, &mut buffered, frame);
+
+                                // Arrival of data confirms the Subscriptions of no-ack exchanges
+                                if strategy == ValidationStrategy::FirstData {
+                                    debug!(
+                                        exchange = %Exchange::ID,
+                                        ?strategy,
+                                        "validated exchange WebSocket subscriptions on first data frame"
+                                    );
+                                    break Ok((instrument_map, buffered));
+                                }
+                            }
                             continue
                         }
                         Some(Err(SocketError::Terminated(close_frame))) => {
@@ -119,3 +345,271 @@ impl SubscriptionValidator for WebSocketSubValidator {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{
+            okx::{subscription::OkxSubResponse, Okx},
+            subscription::ExchangeSub,
+            ExchangeId,
+        },
+        subscriber::{config::ConnectionConfig, WebSocketSubscriber},
+        subscription::trade::PublicTrades,
+        test_util::{MockExchange, MockScript},
+    };
+    use barter_integration::model::{instrument::kind::InstrumentKind, SubscriptionId};
+    use std::{collections::HashMap, time::Duration};
+    use url::Url;
+
+    /// Start a [`MockExchange`] that sends the provided subscription responses once a client
+    /// connects.
+    async fn mock_exchange(responses: Vec<&'static str>) -> MockExchange {
+        MockExchange::start([responses
+            .into_iter()
+            .fold(MockScript::new(), MockScript::send_text)])
+        .await
+        .unwrap()
+    }
+
+    fn instrument_map() -> Map<Instrument> {
+        Map(HashMap::from([
+            (
+                SubscriptionId::from("trades|BTC-USDT"),
+                Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            ),
+            (
+                SubscriptionId::from("trades|ETH-USDT"),
+                Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
+            ),
+        ]))
+    }
+
+    #[tokio::test]
+    async fn test_websocket_sub_validator_correlates_responses_by_request_id() {
+        struct TestCase {
+            responses: Vec<&'static str>,
+            expected_ok: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: out of order responses w/ an unknown request id error that is ignored
+                responses: vec![
+                    r#"{"id":"99","event":"error","code":"60012","msg":"Invalid request"}"#,
+                    r#"{"id":"2","event":"subscribe","arg":{"channel":"trades","instId":"ETH-USDT"}}"#,
+                    r#"{"id":"1","event":"subscribe","arg":{"channel":"trades","instId":"BTC-USDT"}}"#,
+                ],
+                expected_ok: true,
+            },
+            TestCase {
+                // TC1: error response correlated w/ a sent request id
+                responses: vec![
+                    r#"{"id":"2","event":"subscribe","arg":{"channel":"trades","instId":"ETH-USDT"}}"#,
+                    r#"{"id":"1","event":"error","code":"60012","msg":"Invalid request"}"#,
+                ],
+                expected_ok: false,
+            },
+            TestCase {
+                // TC2: duplicated ack for one request id does not validate the other request
+                responses: vec![
+                    r#"{"id":"1","event":"subscribe","arg":{"channel":"trades","instId":"BTC-USDT"}}"#,
+                    r#"{"id":"1","event":"subscribe","arg":{"channel":"trades","instId":"BTC-USDT"}}"#,
+                ],
+                expected_ok: false,
+            },
+            TestCase {
+                // TC3: duplicated ack for one request id followed by the ack of the other request
+                responses: vec![
+                    r#"{"id":"1","event":"subscribe","arg":{"channel":"trades","instId":"BTC-USDT"}}"#,
+                    r#"{"id":"1","event":"subscribe","arg":{"channel":"trades","instId":"BTC-USDT"}}"#,
+                    r#"{"id":"2","event":"subscribe","arg":{"channel":"trades","instId":"ETH-USDT"}}"#,
+                ],
+                expected_ok: true,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            // Connection is closed after the responses, failing any validation still awaiting acks
+            let exchange = MockExchange::start([test
+                .responses
+                .into_iter()
+                .fold(MockScript::new(), MockScript::send_text)
+                .close()])
+            .await
+            .unwrap();
+            let (_, mut websocket) = ConnectionConfig::default()
+                .connect(exchange.url())
+                .await
+                .unwrap()
+                .split();
+
+            let actual = WebSocketSubValidator::validate::<Okx, PublicTrades>(
+                instrument_map(),
+                &[1, 2],
+                &mut websocket,
+            )
+            .await;
+
+            assert_eq!(actual.is_ok(), test.expected_ok, "TC{} failed", index);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_data_received_before_subscription_ack_is_delivered() {
+        use crate::{exchange::StreamSelector, subscription::Subscription, MarketStream};
+
+        // Data for the subscribed market is pushed before the subscription ack
+        let script = MockScript::new()
+            .receive()
+            .send_text(
+                r#"{
+                    "arg": {"channel": "trades", "instId": "BTC-USDT"},
+                    "data": [{"instId": "BTC-USDT", "tradeId": "1", "px": "42219.9", "sz": "0.1", "side": "buy", "ts": "1630048897897"}]
+                }"#,
+            )
+            .send_text(r#"{"event": "subscribe", "arg": {"channel": "trades", "instId": "BTC-USDT"}}"#)
+            .send_text(
+                r#"{
+                    "arg": {"channel": "trades", "instId": "BTC-USDT"},
+                    "data": [{"instId": "BTC-USDT", "tradeId": "2", "px": "42220.1", "sz": "0.2", "side": "sell", "ts": "1630048897898"}]
+                }"#,
+            );
+        let exchange = MockExchange::start([script]).await.unwrap();
+
+        let subscriptions = [Subscription::from((
+            Okx,
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            PublicTrades,
+        ))];
+        let config = ConnectionConfig::default().url(exchange.url());
+        let mut stream =
+            <Okx as StreamSelector<PublicTrades>>::Stream::init(&subscriptions, &config)
+                .await
+                .unwrap();
+
+        // Buffered early event is delivered after validation, ahead of subsequent events
+        let first = stream.next().await.unwrap().unwrap();
+        let second = stream.next().await.unwrap().unwrap();
+        assert_eq!(first.kind.id, "1");
+        assert_eq!(second.kind.id, "2");
+    }
+
+    /// Mock [`Okx`] variant that does not acknowledge subscriptions, validated using the
+    /// [`ValidationStrategy::Immediate`] if `IMMEDIATE`, otherwise the
+    /// [`ValidationStrategy::FirstData`].
+    #[derive(
+        Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+    )]
+    struct NoAckExchange<const IMMEDIATE: bool>;
+
+    impl<const IMMEDIATE: bool> Connector for NoAckExchange<IMMEDIATE> {
+        const ID: ExchangeId = ExchangeId::Okx;
+        type Channel = String;
+        type Market = String;
+        type Subscriber = WebSocketSubscriber;
+        type SubValidator = WebSocketSubValidator;
+        type SubResponse = OkxSubResponse;
+
+        fn url() -> Result<Url, SocketError> {
+            Url::parse("ws://localhost").map_err(SocketError::UrlParse)
+        }
+
+        fn requests(_: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+            vec![]
+        }
+
+        fn validation_strategy() -> ValidationStrategy {
+            if IMMEDIATE {
+                ValidationStrategy::Immediate
+            } else {
+                ValidationStrategy::FirstData
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_no_ack_exchange_validated_on_first_data() {
+        // Default strategy awaits acks
+        assert_eq!(Okx::validation_strategy(), ValidationStrategy::Ack);
+
+        // No-ack exchange only sends data, which must validate well within the default timeout
+        let data = r#"{
+            "arg": {"channel": "trades", "instId": "BTC-USDT"},
+            "data": [{"instId": "BTC-USDT", "tradeId": "1", "px": "42219.9", "sz": "0.1", "side": "buy", "ts": "1630048897897"}]
+        }"#;
+        let exchange = mock_exchange(vec![data]).await;
+        let (_, mut websocket) = ConnectionConfig::default()
+            .connect(exchange.url())
+            .await
+            .unwrap()
+            .split();
+
+        let (validated, buffered) = tokio::time::timeout(
+            std::time::Duration::from_secs(1),
+            WebSocketSubValidator::validate::<NoAckExchange<false>, PublicTrades>(
+                instrument_map(),
+                &[],
+                &mut websocket,
+            ),
+        )
+        .await
+        .expect("validation did not complete on first data")
+        .unwrap();
+
+        // First data frame is retained to be replayed ahead of the WebSocket
+        assert_eq!(validated, instrument_map());
+        assert_eq!(buffered, vec![WsMessage::text(data)]);
+    }
+
+    #[tokio::test]
+    async fn test_no_ack_exchange_validated_immediately() {
+        // Nothing is ever sent by the exchange, so validation must not await any response
+        let exchange = MockExchange::start([MockScript::new().silence(Duration::from_secs(5))])
+            .await
+            .unwrap();
+        let (_, mut websocket) = ConnectionConfig::default()
+            .connect(exchange.url())
+            .await
+            .unwrap()
+            .split();
+
+        let (validated, buffered) = tokio::time::timeout(
+            Duration::from_secs(1),
+            WebSocketSubValidator::validate::<NoAckExchange<true>, PublicTrades>(
+                instrument_map(),
+                &[],
+                &mut websocket,
+            ),
+        )
+        .await
+        .expect("validation did not complete immediately")
+        .unwrap();
+
+        assert_eq!(validated, instrument_map());
+        assert!(buffered.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_buffered_stream_yields_buffered_frames_first() {
+        let inner = futures::stream::iter(vec![Ok(WsMessage::text("inner"))]);
+        let stream = BufferedStream::new(
+            vec![WsMessage::text("first"), WsMessage::text("second")],
+            inner,
+        );
+
+        let actual = stream.map(Result::unwrap).collect::<Vec<_>>().await;
+
+        assert_eq!(
+            actual,
+            vec![
+                WsMessage::text("first"),
+                WsMessage::text("second"),
+                WsMessage::text("inner"),
+            ]
+        );
+    }
+}
@@ -1,9 +1,18 @@
-use super::SubKind;
+use super::{SubKind, Subscription};
 use crate::{
-    event::{MarketEvent, MarketIter},
-    exchange::ExchangeId,
+    error::DataError,
+    event::{exchange_time_or_received, MarketEvent, MarketIter},
+    exchange::{Connector, ExchangeId, StreamSelector, WildcardSupport},
+    transformer::book::BookDeltaTransformer,
+    ExchangeWsStream, Identifier, MarketStream,
+};
+use barter_integration::{
+    model::{
+        instrument::{kind::InstrumentKind, Instrument},
+        Exchange, Side,
+    },
+    Transformer,
 };
-use barter_integration::model::{instrument::Instrument, Exchange, Side};
 use barter_macro::{DeSubKind, SerSubKind};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -20,17 +29,50 @@ pub struct OrderBooksL1;
 
 impl SubKind for OrderBooksL1 {
     type Event = OrderBookL1;
+
+    const NAME: &'static str = "order_books_l1";
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields level 1 [`OrderBook`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events from an exchange all-market top of book
+/// channel, rather than a per-market channel.
+///
+/// ### Notes
+/// Every subscribed [`Instrument`] shares a single exchange channel, and the [`OrderBookL1`]s of
+/// any un-subscribed market are discarded.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct AllMarketOrderBooksL1;
+
+impl SubKind for AllMarketOrderBooksL1 {
+    type Event = OrderBookL1;
+
+    const NAME: &'static str = "all_market_order_books_l1";
 }
 
 /// Normalised Barter [`OrderBookL1`] snapshot containing the latest best bid and ask.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize)]
 pub struct OrderBookL1 {
     pub last_update_time: DateTime<Utc>,
+    /// Exchange update id of this snapshot, if the exchange provides one.
+    #[serde(default)]
+    pub sequence: Option<u64>,
     pub best_bid: Level,
     pub best_ask: Level,
 }
 
 impl OrderBookL1 {
+    /// Determines if this [`OrderBookL1`] is out of sequence with the previous [`OrderBookL1`]
+    /// of the same market, ie/ its update id is not greater than the previous update id.
+    ///
+    /// Update ids are not required to be contiguous, since exchanges typically only push a top of
+    /// book update when the best bid or ask changes. Returns false if either update id is unknown.
+    pub fn is_out_of_sequence(&self, previous: &OrderBookL1) -> bool {
+        match (previous.sequence, self.sequence) {
+            (Some(previous), Some(next)) => next <= previous,
+            _ => false,
+        }
+    }
+
     /// Calculate the mid price by taking the average of the best bid and ask prices.
     ///
     /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
@@ -56,6 +98,67 @@ pub struct OrderBooksL2;
 
 impl SubKind for OrderBooksL2 {
     type Event = OrderBook;
+
+    const NAME: &'static str = "order_books_l2";
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields level 2 [`OrderBook`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events at the selected exchange update speed,
+/// rather than the update speed used for [`OrderBooksL2`].
+///
+/// ### Notes
+/// Faster updates matter for some strategies, but cost more bandwidth. Each exchange only
+/// supports a fixed set of update speeds (eg/ Binance spot 100ms & 1000ms), and unsupported
+/// speeds are rejected by [`StreamSelector::supports_kind`](crate::exchange::StreamSelector::supports_kind).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OrderBooksL2Speed {
+    pub update_speed_ms: u64,
+}
+
+impl OrderBooksL2Speed {
+    /// Construct a new [`OrderBooksL2Speed`] with the provided update speed in milliseconds.
+    pub fn new(update_speed_ms: u64) -> Self {
+        Self { update_speed_ms }
+    }
+}
+
+impl SubKind for OrderBooksL2Speed {
+    type Event = OrderBook;
+
+    const NAME: &'static str = "order_books_l2_speed";
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields level 2 [`OrderBook`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events from an exchange channel that batches
+/// updates, rather than the real-time channel used for [`OrderBooksL2`].
+///
+/// ### Notes
+/// Batched channels send far fewer messages at the cost of latency (eg/ Coinbase "level2_batch"
+/// batches updates every ~50ms).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct OrderBooksL2Batched;
+
+impl SubKind for OrderBooksL2Batched {
+    type Event = OrderBook;
+
+    const NAME: &'static str = "order_books_l2_batched";
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields level 2 [`OrderBook`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events from an exchange channel that sends
+/// every individual (tick-by-tick) update, rather than the channel used for [`OrderBooksL2`].
+///
+/// ### Notes
+/// Tick-by-tick channels often require an authenticated connection with elevated account
+/// access (eg/ Okx "books-l2-tbt" requires login & VIP4+), see
+/// [`Credentials`](crate::subscriber::config::Credentials).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct OrderBooksL2Tbt;
+
+impl SubKind for OrderBooksL2Tbt {
+    type Event = OrderBook;
+
+    const NAME: &'static str = "order_books_l2_tbt";
 }
 
 /// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields level 3 [`OrderBook`]
@@ -68,11 +171,108 @@ pub struct OrderBooksL3;
 
 impl SubKind for OrderBooksL3 {
     type Event = OrderBook;
+
+    const NAME: &'static str = "order_books_l3";
+}
+
+/// Barter [`Subscription`] [`SubKind`] wrapper that yields [`OrderBookDelta`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events containing only the [`Level`]s changed by
+/// each update of the wrapped [`OrderBook`] [`SubKind`] (eg/ [`OrderBooksL2`]), rather than a full
+/// [`OrderBook`] snapshot.
+///
+/// The full [`OrderBook`] is still maintained internally (eg/ to validate exchange checksums),
+/// and consumers tracking their own [`OrderBook`] can apply each [`OrderBookDelta`] via
+/// [`OrderBook::apply_delta`], discarding it upon a gap in the [`OrderBookDelta`] `sequence`
+/// until the next `snapshot`.
+///
+/// eg/ `Subscription::from((BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, OrderBookDeltas::new(OrderBooksL2)))`
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct OrderBookDeltas<Kind> {
+    pub kind: Kind,
+}
+
+impl<Kind> OrderBookDeltas<Kind> {
+    /// Construct a new [`OrderBookDeltas`] yielding the deltas of the provided [`SubKind`].
+    pub fn new(kind: Kind) -> Self {
+        Self { kind }
+    }
+}
+
+impl<Kind> SubKind for OrderBookDeltas<Kind>
+where
+    Kind: SubKind<Event = OrderBook>,
+{
+    type Event = OrderBookDelta;
+
+    const NAME: &'static str = "order_book_deltas";
+}
+
+impl<Exchange, Kind> Identifier<Exchange::Channel> for Subscription<Exchange, OrderBookDeltas<Kind>>
+where
+    Exchange: Connector + Clone,
+    Kind: Clone,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel>,
+{
+    fn id(&self) -> Exchange::Channel {
+        Subscription::new(
+            self.exchange.clone(),
+            self.instrument.clone(),
+            self.kind.kind.clone(),
+        )
+        .id()
+    }
+}
+
+impl<Exchange, Kind, Inner> StreamSelector<OrderBookDeltas<Kind>> for Exchange
+where
+    Exchange: StreamSelector<Kind, Stream = ExchangeWsStream<Inner>>,
+    Kind: SubKind<Event = OrderBook>,
+    Inner: Transformer<Output = MarketEvent<OrderBook>, Error = DataError>,
+    ExchangeWsStream<BookDeltaTransformer<Inner>>: MarketStream<Exchange, OrderBookDeltas<Kind>>,
+{
+    type Stream = ExchangeWsStream<BookDeltaTransformer<Inner>>;
+
+    const WILDCARD: WildcardSupport = <Exchange as StreamSelector<Kind>>::WILDCARD;
+
+    fn supports(instrument_kind: InstrumentKind) -> bool {
+        <Exchange as StreamSelector<Kind>>::supports(instrument_kind)
+    }
+
+    fn supports_kind(kind: &OrderBookDeltas<Kind>) -> bool {
+        <Exchange as StreamSelector<Kind>>::supports_kind(&kind.kind)
+    }
+}
+
+/// Normalised Barter [`OrderBook`] delta containing only the [`Level`]s applied by the latest
+/// [`OrderBook`] update, in the order they were applied, where a [`Level`] with a zero amount
+/// has been removed.
+///
+/// The first [`OrderBookDelta`] of each (re)initialised [`OrderBook`] is a `snapshot` containing
+/// every [`Level`], which replaces any [`OrderBook`] previously tracked by the consumer. A
+/// `snapshot` is also emitted whenever the applied [`Level`]s are unknown (eg/ the exchange sent
+/// a full snapshot).
+///
+/// The `sequence` increments by one with every [`OrderBookDelta`] of an
+/// [`Instrument`](barter_integration::model::instrument::Instrument), and restarts at zero with
+/// the `snapshot` of each (re)initialised [`OrderBook`]. A gap in the `sequence` (eg/ a delta
+/// dropped by the [`LagPolicy::DropNewest`](crate::streams::consumer::LagPolicy::DropNewest))
+/// means the tracked [`OrderBook`] is stale & must be discarded until the next `snapshot`.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize)]
+pub struct OrderBookDelta {
+    pub last_update_time: DateTime<Utc>,
+    pub sequence: u64,
+    pub snapshot: bool,
+    pub bids: Vec<Level>,
+    pub asks: Vec<Level>,
 }
 
 /// Normalised Barter [`OrderBook`] snapshot.
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize)]
 pub struct OrderBook {
+    /// Exchange time of the latest update, or epoch-zero if the exchange update is not
+    /// timestamped (see [`exchange_time_or_received`]).
     pub last_update_time: DateTime<Utc>,
     pub bids: OrderBookSide,
     pub asks: OrderBookSide,
@@ -87,6 +287,100 @@ impl OrderBook {
         self.clone()
     }
 
+    /// Start (or restart) tracking the [`Level`]s applied to each [`OrderBookSide`], discarding
+    /// any previously tracked changes (see [`OrderBook::delta()`]).
+    pub fn track_changes(&mut self) {
+        self.bids.track_changes();
+        self.asks.track_changes();
+    }
+
+    /// Stop tracking the [`Level`]s applied to each [`OrderBookSide`], so the next
+    /// [`OrderBook::delta()`] is a `snapshot`.
+    pub fn untrack_changes(&mut self) {
+        self.bids.untrack_changes();
+        self.asks.untrack_changes();
+    }
+
+    /// Generate the [`OrderBookDelta`] of the [`Level`]s applied since
+    /// [`track_changes()`](OrderBook::track_changes()) was last called, or a `snapshot`
+    /// [`OrderBookDelta`] of every [`Level`] if either [`OrderBookSide`] is not tracked (eg/ it
+    /// was replaced).
+    ///
+    /// A `snapshot` requires [`Self`] to be sorted (see [`OrderBook::snapshot()`]).
+    pub fn delta(&self, sequence: u64) -> OrderBookDelta {
+        match (self.bids.changes(), self.asks.changes()) {
+            (Some(bids), Some(asks)) => OrderBookDelta {
+                last_update_time: self.last_update_time,
+                sequence,
+                snapshot: false,
+                bids: bids.to_vec(),
+                asks: asks.to_vec(),
+            },
+            _ => self.snapshot_delta(sequence),
+        }
+    }
+
+    /// Generate a `snapshot` [`OrderBookDelta`] of every [`Level`].
+    ///
+    /// [`Self`] must be sorted (see [`OrderBook::snapshot()`]).
+    pub fn snapshot_delta(&self, sequence: u64) -> OrderBookDelta {
+        OrderBookDelta {
+            last_update_time: self.last_update_time,
+            sequence,
+            snapshot: true,
+            bids: self.bids.levels.clone(),
+            asks: self.asks.levels.clone(),
+        }
+    }
+
+    /// Apply the provided [`OrderBookDelta`], leaving [`Self`] sorted.
+    pub fn apply_delta(&mut self, delta: &OrderBookDelta) {
+        if delta.snapshot {
+            self.bids = OrderBookSide::new(Side::Buy, delta.bids.iter().copied());
+            self.asks = OrderBookSide::new(Side::Sell, delta.asks.iter().copied());
+        } else {
+            self.bids.upsert(delta.bids.iter().copied());
+            self.asks.upsert(delta.asks.iter().copied());
+        }
+
+        self.last_update_time = delta.last_update_time;
+        self.bids.sort();
+        self.asks.sort();
+    }
+
+    /// Detect if the best bid & ask of a sorted [`OrderBook`] are crossed or locked.
+    pub fn anomaly(&self) -> Option<BookAnomaly> {
+        let best_bid = self.bids.levels.first()?.price;
+        let best_ask = self.asks.levels.first()?.price;
+
+        if best_bid > best_ask {
+            Some(BookAnomaly::Crossed { best_bid, best_ask })
+        } else if best_bid == best_ask {
+            Some(BookAnomaly::Locked { price: best_bid })
+        } else {
+            None
+        }
+    }
+
+    /// Sort the [`OrderBook`] & retain only the [`Level`]s priced within `bps` basis points of
+    /// the mid price (eg/ 200 bps retains levels within ±2% of the mid price).
+    ///
+    /// The [`mid_price()`](OrderBook::mid_price()) of a one-sided [`OrderBook`] is its best price,
+    /// and an empty [`OrderBook`] is left untouched.
+    pub fn prune_price_band(&mut self, bps: u32) {
+        self.bids.sort();
+        self.asks.sort();
+
+        let Some(mid_price) = self.mid_price() else {
+            return;
+        };
+
+        let band = mid_price.abs() * f64::from(bps) / 10_000.0;
+        let (min, max) = (mid_price - band, mid_price + band);
+        self.bids.retain_price_range(min, max);
+        self.asks.retain_price_range(min, max);
+    }
+
     /// Calculate the mid price by taking the average of the best bid and ask prices.
     ///
     /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
@@ -115,11 +409,59 @@ impl OrderBook {
     }
 }
 
+/// Invariant violation detected in a maintained [`OrderBook`], signalling bad data or a bug in
+/// how the [`OrderBook`] is being updated.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub enum BookAnomaly {
+    /// Best bid price is greater than the best ask price.
+    Crossed { best_bid: f64, best_ask: f64 },
+    /// Best bid price is equal to the best ask price.
+    Locked { price: f64 },
+}
+
 /// Normalised Barter [`Level`]s for one [`Side`] of the [`OrderBook`].
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize)]
 pub struct OrderBookSide {
     side: Side,
     levels: Vec<Level>,
+    #[serde(skip)]
+    changes: LevelChanges,
+}
+
+/// [`Level`]s applied to an [`OrderBookSide`] since it started tracking changes, or `None` if it
+/// is not tracking changes.
+///
+/// Transient bookkeeping rather than part of the [`OrderBookSide`] value, so it is ignored by
+/// comparisons.
+#[derive(Clone, Debug, Default)]
+struct LevelChanges(Option<Vec<Level>>);
+
+impl LevelChanges {
+    fn record(&mut self, level: Level) {
+        if let Some(changes) = &mut self.0 {
+            changes.push(level);
+        }
+    }
+}
+
+impl PartialEq for LevelChanges {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for LevelChanges {}
+
+impl PartialOrd for LevelChanges {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for LevelChanges {
+    fn cmp(&self, _: &Self) -> Ordering {
+        Ordering::Equal
+    }
 }
 
 impl OrderBookSide {
@@ -132,9 +474,21 @@ impl OrderBookSide {
         Self {
             side,
             levels: levels.into_iter().map(L::into).collect(),
+            changes: LevelChanges::default(),
         }
     }
 
+    /// [`Side`] of the [`OrderBook`] this [`OrderBookSide`] represents.
+    pub fn side(&self) -> Side {
+        self.side
+    }
+
+    /// [`Level`]s of this [`OrderBookSide`], which are only guaranteed to be sorted after calling
+    /// [`sort()`](OrderBookSide::sort()).
+    pub fn levels(&self) -> &[Level] {
+        &self.levels
+    }
+
     /// Upsert a collection of [`Level`]s into this [`OrderBookSide`].
     pub fn upsert<Iter, L>(&mut self, levels: Iter)
     where
@@ -171,15 +525,20 @@ impl OrderBookSide {
             // Scenario 1a: Level exists & new value is 0 => remove Level
             Some((index, _)) if new_level.amount == 0.0 => {
                 self.levels.remove(index);
+                self.changes.record(new_level);
             }
 
             // Scenario 1b: Level exists & new value is > 0 => replace Level
             Some((_, level)) => {
                 *level = new_level;
+                self.changes.record(new_level);
             }
 
             // Scenario 2a: Level does not exist & new value > 0 => insert new Level
-            None if new_level.amount > 0.0 => self.levels.push(new_level),
+            None if new_level.amount > 0.0 => {
+                self.levels.push(new_level);
+                self.changes.record(new_level);
+            }
 
             // Scenario 2b: Level does not exist & new value is 0 => log error & continue
             _ => {
@@ -192,6 +551,30 @@ impl OrderBookSide {
         };
     }
 
+    /// Start (or restart) tracking the [`Level`]s applied to this [`OrderBookSide`], discarding
+    /// any previously tracked changes.
+    pub fn track_changes(&mut self) {
+        match &mut self.changes.0 {
+            Some(changes) => changes.clear(),
+            None => self.changes.0 = Some(Vec::new()),
+        }
+    }
+
+    /// Stop tracking the [`Level`]s applied to this [`OrderBookSide`].
+    pub fn untrack_changes(&mut self) {
+        self.changes.0 = None;
+    }
+
+    /// [`Level`]s applied to this [`OrderBookSide`] since
+    /// [`track_changes()`](OrderBookSide::track_changes()) was last called, in the order they
+    /// were applied, where a removed [`Level`] has a zero amount.
+    ///
+    /// Returns `None` if this [`OrderBookSide`] is not tracking changes (eg/ it was replaced by a
+    /// new [`OrderBookSide`]).
+    pub fn changes(&self) -> Option<&[Level]> {
+        self.changes.0.as_deref()
+    }
+
     /// Sort this [`OrderBookSide`] (bids are reversed).
     pub fn sort(&mut self) {
         // Sort Levels
@@ -202,6 +585,27 @@ impl OrderBookSide {
             self.levels.reverse();
         }
     }
+
+    /// Sort this [`OrderBookSide`] & retain only the best `depth` [`Level`]s.
+    pub fn truncate(&mut self, depth: usize) {
+        self.sort();
+        for level in self.levels.iter().skip(depth) {
+            self.changes.record(Level::new(level.price, 0.0));
+        }
+        self.levels.truncate(depth);
+    }
+
+    /// Retain only the [`Level`]s priced within the inclusive `min` to `max` price range.
+    pub fn retain_price_range(&mut self, min: f64, max: f64) {
+        let changes = &mut self.changes;
+        self.levels.retain(|level| {
+            let retain = level.price >= min && level.price <= max;
+            if !retain {
+                changes.record(Level::new(level.price, 0.0));
+            }
+            retain
+        });
+    }
 }
 
 /// Normalised Barter OrderBook [`Level`].
@@ -222,17 +626,18 @@ where
 
 impl Ord for Level {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other)
-            .unwrap_or_else(|| panic!("{:?}.partial_cmp({:?}) impossible", self, other))
+        let ordering = match self.price.partial_cmp(&other.price) {
+            Some(Ordering::Equal) => self.amount.partial_cmp(&other.amount),
+            non_equal => non_equal,
+        };
+
+        ordering.unwrap_or_else(|| panic!("{:?}.partial_cmp({:?}) impossible", self, other))
     }
 }
 
 impl PartialOrd for Level {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        match self.price.partial_cmp(&other.price)? {
-            Ordering::Equal => self.amount.partial_cmp(&other.amount),
-            non_equal => Some(non_equal),
-        }
+        Some(self.cmp(other))
     }
 }
 
@@ -274,10 +679,15 @@ pub fn volume_weighted_mid_price(best_bid: Level, best_ask: Level) -> f64 {
 }
 
 impl From<(ExchangeId, Instrument, OrderBook)> for MarketIter<OrderBook> {
-    fn from((exchange_id, instrument, book): (ExchangeId, Instrument, OrderBook)) -> Self {
+    fn from((exchange_id, instrument, mut book): (ExchangeId, Instrument, OrderBook)) -> Self {
+        let received_time = Utc::now();
+        let (exchange_time, exchange_time_synthesized) =
+            exchange_time_or_received(Some(book.last_update_time), received_time);
+        book.last_update_time = exchange_time;
         Self(vec![Ok(MarketEvent {
-            exchange_time: book.last_update_time,
-            received_time: Utc::now(),
+            exchange_time,
+            received_time,
+            exchange_time_synthesized,
             exchange: Exchange::from(exchange_id),
             instrument,
             kind: book,
@@ -304,6 +714,7 @@ mod tests {
                     // TC0
                     input: OrderBookL1 {
                         last_update_time: Default::default(),
+                        sequence: None,
                         best_bid: Level::new(100, 999999),
                         best_ask: Level::new(200, 1),
                     },
@@ -313,6 +724,7 @@ mod tests {
                     // TC1
                     input: OrderBookL1 {
                         last_update_time: Default::default(),
+                        sequence: None,
                         best_bid: Level::new(50, 1),
                         best_ask: Level::new(250, 999999),
                     },
@@ -322,6 +734,7 @@ mod tests {
                     // TC2
                     input: OrderBookL1 {
                         last_update_time: Default::default(),
+                        sequence: None,
                         best_bid: Level::new(10, 999999),
                         best_ask: Level::new(250, 999999),
                     },
@@ -346,6 +759,7 @@ mod tests {
                     // TC0: volume the same so should be equal to non-weighted mid price
                     input: OrderBookL1 {
                         last_update_time: Default::default(),
+                        sequence: None,
                         best_bid: Level::new(100, 100),
                         best_ask: Level::new(200, 100),
                     },
@@ -355,6 +769,7 @@ mod tests {
                     // TC1: volume affects mid-price
                     input: OrderBookL1 {
                         last_update_time: Default::default(),
+                        sequence: None,
                         best_bid: Level::new(100, 600),
                         best_ask: Level::new(200, 1000),
                     },
@@ -364,6 +779,7 @@ mod tests {
                     // TC2: volume the same and price the same
                     input: OrderBookL1 {
                         last_update_time: Default::default(),
+                        sequence: None,
                         best_bid: Level::new(1000, 999999),
                         best_ask: Level::new(1000, 999999),
                     },
@@ -398,10 +814,12 @@ mod tests {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
                             side: Side::Buy,
+                            changes: LevelChanges::default(),
                             levels: vec![],
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
+                            changes: LevelChanges::default(),
                             levels: vec![],
                         },
                     },
@@ -413,10 +831,12 @@ mod tests {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
                             side: Side::Buy,
+                            changes: LevelChanges::default(),
                             levels: vec![Level::new(100.0, 100.0), Level::new(50.0, 100.0)],
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
+                            changes: LevelChanges::default(),
                             levels: vec![],
                         },
                     },
@@ -428,10 +848,12 @@ mod tests {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
                             side: Side::Buy,
+                            changes: LevelChanges::default(),
                             levels: vec![],
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
+                            changes: LevelChanges::default(),
                             levels: vec![Level::new(50.0, 100.0), Level::new(100.0, 100.0)],
                         },
                     },
@@ -443,10 +865,12 @@ mod tests {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
                             side: Side::Buy,
+                            changes: LevelChanges::default(),
                             levels: vec![Level::new(100.0, 100.0), Level::new(50.0, 100.0)],
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
+                            changes: LevelChanges::default(),
                             levels: vec![Level::new(200.0, 100.0), Level::new(300.0, 100.0)],
                         },
                     },
@@ -473,10 +897,12 @@ mod tests {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
                             side: Side::Buy,
+                            changes: LevelChanges::default(),
                             levels: vec![],
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
+                            changes: LevelChanges::default(),
                             levels: vec![],
                         },
                     },
@@ -488,10 +914,12 @@ mod tests {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
                             side: Side::Buy,
+                            changes: LevelChanges::default(),
                             levels: vec![Level::new(100.0, 100.0), Level::new(50.0, 100.0)],
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
+                            changes: LevelChanges::default(),
                             levels: vec![],
                         },
                     },
@@ -503,10 +931,12 @@ mod tests {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
                             side: Side::Buy,
+                            changes: LevelChanges::default(),
                             levels: vec![],
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
+                            changes: LevelChanges::default(),
                             levels: vec![Level::new(50.0, 100.0), Level::new(100.0, 100.0)],
                         },
                     },
@@ -518,10 +948,12 @@ mod tests {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
                             side: Side::Buy,
+                            changes: LevelChanges::default(),
                             levels: vec![Level::new(100.0, 100.0), Level::new(50.0, 100.0)],
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
+                            changes: LevelChanges::default(),
                             levels: vec![Level::new(200.0, 100.0), Level::new(300.0, 100.0)],
                         },
                     },
@@ -533,10 +965,12 @@ mod tests {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
                             side: Side::Buy,
+                            changes: LevelChanges::default(),
                             levels: vec![Level::new(100.0, 3000.0), Level::new(50.0, 100.0)],
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
+                            changes: LevelChanges::default(),
                             levels: vec![Level::new(200.0, 1000.0), Level::new(300.0, 100.0)],
                         },
                     },
@@ -552,6 +986,64 @@ mod tests {
                 )
             }
         }
+
+        #[test]
+        fn test_market_iter_from_order_book() {
+            use barter_integration::model::instrument::kind::InstrumentKind;
+            use chrono::TimeZone;
+
+            struct TestCase {
+                last_update_time: DateTime<Utc>,
+                expected_synthesized: bool,
+            }
+
+            let exchange_time = Utc.timestamp_millis_opt(1649324825173).unwrap();
+
+            let tests = vec![
+                TestCase {
+                    // TC0: timestamped OrderBook uses the exchange time
+                    last_update_time: exchange_time,
+                    expected_synthesized: false,
+                },
+                TestCase {
+                    // TC1: untimestamped OrderBook synthesizes the exchange time
+                    last_update_time: DateTime::default(),
+                    expected_synthesized: true,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let book = OrderBook {
+                    last_update_time: test.last_update_time,
+                    bids: OrderBookSide::new(Side::Buy, vec![Level::new(100, 1)]),
+                    asks: OrderBookSide::new(Side::Sell, vec![Level::new(101, 1)]),
+                };
+
+                let event = MarketIter::<OrderBook>::from((
+                    ExchangeId::BinanceSpot,
+                    Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                    book,
+                ))
+                .0
+                .remove(0)
+                .unwrap();
+
+                assert_eq!(
+                    event.is_exchange_time_synthesized(),
+                    test.expected_synthesized,
+                    "TC{index} failed"
+                );
+                assert_eq!(
+                    event.kind.last_update_time, event.exchange_time,
+                    "TC{index} failed"
+                );
+                if test.expected_synthesized {
+                    assert_eq!(event.exchange_time, event.received_time, "TC{index} failed");
+                } else {
+                    assert_eq!(event.exchange_time, exchange_time, "TC{index} failed");
+                }
+            }
+        }
     }
 
     mod order_book_side {
@@ -1,6 +1,9 @@
 use super::SubKind;
+use crate::{event::MarketIter, exchange::ExchangeId, Identifier};
+use barter_integration::model::{instrument::Instrument, SubscriptionId};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
 
 /// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`Candle`]
 /// [`MarketEvent<T>`](crate::event::MarketEvent) events.
@@ -9,6 +12,124 @@ pub struct Candles;
 
 impl SubKind for Candles {
     type Event = Candle;
+
+    const NAME: &'static str = "candles";
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that only yields finalised
+/// [`Candle`] [`MarketEvent<T>`](crate::event::MarketEvent) events, filtering out any candle
+/// updates that are still forming.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct ClosedCandles;
+
+impl SubKind for ClosedCandles {
+    type Event = Candle;
+
+    const NAME: &'static str = "closed_candles";
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`Candle`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events of the selected [`CandleInterval`],
+/// rather than the 1 minute interval used for [`Candles`].
+///
+/// ### Notes
+/// Each exchange only supports a fixed set of intervals (eg/ of Binance, only spot offers 1
+/// second candles), and unsupported intervals are rejected by
+/// [`StreamSelector::supports_kind`](crate::exchange::StreamSelector::supports_kind).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct IntervalCandles {
+    pub interval: CandleInterval,
+}
+
+impl IntervalCandles {
+    /// Construct a new [`IntervalCandles`] with the provided [`CandleInterval`].
+    pub fn new(interval: CandleInterval) -> Self {
+        Self { interval }
+    }
+}
+
+impl SubKind for IntervalCandles {
+    type Event = Candle;
+
+    const NAME: &'static str = "interval_candles";
+}
+
+/// Interval of the [`Candle`]s yielded by an [`IntervalCandles`]
+/// [`Subscription`](super::Subscription).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum CandleInterval {
+    #[serde(rename = "1s")]
+    S1,
+    #[serde(rename = "1m")]
+    M1,
+    #[serde(rename = "3m")]
+    M3,
+    #[serde(rename = "5m")]
+    M5,
+    #[serde(rename = "15m")]
+    M15,
+    #[serde(rename = "30m")]
+    M30,
+    #[serde(rename = "1h")]
+    H1,
+    #[serde(rename = "2h")]
+    H2,
+    #[serde(rename = "4h")]
+    H4,
+    #[serde(rename = "6h")]
+    H6,
+    #[serde(rename = "12h")]
+    H12,
+    #[serde(rename = "1d")]
+    D1,
+    #[serde(rename = "1w")]
+    W1,
+}
+
+impl CandleInterval {
+    /// Duration spanned by a [`Candle`] of this [`CandleInterval`].
+    pub fn duration(&self) -> chrono::Duration {
+        match self {
+            Self::S1 => chrono::Duration::seconds(1),
+            Self::M1 => chrono::Duration::minutes(1),
+            Self::M3 => chrono::Duration::minutes(3),
+            Self::M5 => chrono::Duration::minutes(5),
+            Self::M15 => chrono::Duration::minutes(15),
+            Self::M30 => chrono::Duration::minutes(30),
+            Self::H1 => chrono::Duration::hours(1),
+            Self::H2 => chrono::Duration::hours(2),
+            Self::H4 => chrono::Duration::hours(4),
+            Self::H6 => chrono::Duration::hours(6),
+            Self::H12 => chrono::Duration::hours(12),
+            Self::D1 => chrono::Duration::days(1),
+            Self::W1 => chrono::Duration::weeks(1),
+        }
+    }
+
+    /// Abbreviated name of the [`CandleInterval`] (eg/ "1s", "15m", "1d").
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::S1 => "1s",
+            Self::M1 => "1m",
+            Self::M3 => "3m",
+            Self::M5 => "5m",
+            Self::M15 => "15m",
+            Self::M30 => "30m",
+            Self::H1 => "1h",
+            Self::H2 => "2h",
+            Self::H4 => "4h",
+            Self::H6 => "6h",
+            Self::H12 => "12h",
+            Self::D1 => "1d",
+            Self::W1 => "1w",
+        }
+    }
+}
+
+impl Display for CandleInterval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 /// Normalised Barter OHLCV [`Candle`] model.
@@ -20,6 +141,42 @@ pub struct Candle {
     pub low: f64,
     pub close: f64,
     pub volume: f64,
-    pub trade_count: u64,
+    /// Number of trades in the [`Candle`], if provided by the exchange.
+    pub trade_count: Option<u64>,
     pub is_closed: bool,
 }
+
+/// Transparent wrapper around an exchange candle message `T` that only yields
+/// [`Candle`]s with `is_closed` set. Used as the input for [`ClosedCandles`] streams.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct ClosedOnly<T>(pub T);
+
+impl<T> Identifier<Option<SubscriptionId>> for ClosedOnly<T>
+where
+    T: Identifier<Option<SubscriptionId>>,
+{
+    fn id(&self) -> Option<SubscriptionId> {
+        self.0.id()
+    }
+}
+
+impl<T> From<(ExchangeId, Instrument, ClosedOnly<T>)> for MarketIter<Candle>
+where
+    MarketIter<Candle>: From<(ExchangeId, Instrument, T)>,
+{
+    fn from((exchange_id, instrument, candles): (ExchangeId, Instrument, ClosedOnly<T>)) -> Self {
+        <MarketIter<Candle> as From<(ExchangeId, Instrument, T)>>::from((
+            exchange_id,
+            instrument,
+            candles.0,
+        ))
+        .0
+        .into_iter()
+        .filter(|event| match event {
+            Ok(event) => event.kind.is_closed,
+            Err(_) => true,
+        })
+        .collect()
+    }
+}
@@ -0,0 +1,29 @@
+use super::SubKind;
+use barter_macro::{DeSubKind, SerSubKind};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`DerivativesStats`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events, combining the mark price, index price &
+/// funding rate of a derivatives market into a single subscription.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct DerivativesStatistics;
+
+impl SubKind for DerivativesStatistics {
+    type Event = DerivativesStats;
+
+    const NAME: &'static str = "derivatives_statistics";
+}
+
+/// Normalised Barter [`DerivativesStats`] model.
+///
+/// Fields are `None` until delivered by the exchange, since some exchanges deliver each statistic
+/// on a separate channel & cadence. The `funding_rate` is the rate of the upcoming funding
+/// settlement at the `next_funding_time`.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct DerivativesStats {
+    pub mark_price: Option<f64>,
+    pub index_price: Option<f64>,
+    pub funding_rate: Option<f64>,
+    pub next_funding_time: Option<DateTime<Utc>>,
+}
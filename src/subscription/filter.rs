@@ -0,0 +1,209 @@
+use super::{Subscription, WILDCARD};
+use barter_integration::{error::SocketError, model::instrument::Instrument};
+use regex::Regex;
+
+/// Matcher of the canonical symbol of an [`Instrument`] (see [`canonical_symbol`]).
+#[derive(Clone, Debug)]
+pub enum SymbolMatcher {
+    /// Matches if the canonical symbol contains the lowercase substring.
+    Substring(String),
+    /// Matches if the canonical symbol matches the [`Regex`].
+    Regex(Regex),
+}
+
+impl SymbolMatcher {
+    /// Construct a [`SymbolMatcher::Substring`], normalising the substring to lowercase.
+    pub fn substring<S>(substring: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        Self::Substring(substring.as_ref().to_lowercase())
+    }
+
+    /// Construct a [`SymbolMatcher::Regex`] from the provided pattern.
+    #[allow(clippy::result_large_err)]
+    pub fn regex(pattern: &str) -> Result<Self, SocketError> {
+        Regex::new(pattern).map(Self::Regex).map_err(|error| {
+            SocketError::Subscribe(format!("invalid symbol regex {pattern}: {error}"))
+        })
+    }
+
+    /// Determine if the provided canonical symbol is matched.
+    pub fn is_match(&self, symbol: &str) -> bool {
+        match self {
+            Self::Substring(substring) => symbol.contains(substring.as_str()),
+            Self::Regex(regex) => regex.is_match(symbol),
+        }
+    }
+}
+
+impl PartialEq for SymbolMatcher {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Substring(lhs), Self::Substring(rhs)) => lhs == rhs,
+            (Self::Regex(lhs), Self::Regex(rhs)) => lhs.as_str() == rhs.as_str(),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for SymbolMatcher {}
+
+/// Allow & deny lists of [`SymbolMatcher`]s determining the [`Instrument`]s that may be
+/// subscribed to (eg/ excluding leveraged tokens or stable-stable pairs from a dynamic
+/// instrument list).
+///
+/// An [`Instrument`] is allowed if it matches any allow [`SymbolMatcher`] (or the allow list is
+/// empty), and matches no deny [`SymbolMatcher`].
+///
+/// ### Notes
+/// [`WILDCARD`] [`Instrument`]s are always allowed at subscribe time, since the markets they
+/// expand to are only known once their events are consumed, where the filter is applied instead.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct SymbolFilter {
+    pub allow: Vec<SymbolMatcher>,
+    pub deny: Vec<SymbolMatcher>,
+}
+
+impl SymbolFilter {
+    /// Construct a new [`SymbolFilter`] allowing every [`Instrument`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a [`SymbolMatcher`] to the allow list.
+    pub fn allow(mut self, matcher: SymbolMatcher) -> Self {
+        self.allow.push(matcher);
+        self
+    }
+
+    /// Add a [`SymbolMatcher`] to the deny list.
+    pub fn deny(mut self, matcher: SymbolMatcher) -> Self {
+        self.deny.push(matcher);
+        self
+    }
+
+    /// Determine if the provided [`Instrument`] is allowed by the [`SymbolFilter`].
+    pub fn is_allowed(&self, instrument: &Instrument) -> bool {
+        if is_partial_wildcard(instrument) {
+            return true;
+        }
+
+        let symbol = canonical_symbol(instrument);
+        let allowed =
+            self.allow.is_empty() || self.allow.iter().any(|matcher| matcher.is_match(&symbol));
+
+        allowed && !self.deny.iter().any(|matcher| matcher.is_match(&symbol))
+    }
+
+    /// Retain only the [`Subscription`]s for [`Instrument`]s allowed by the [`SymbolFilter`].
+    pub fn retain<Exchange, Kind>(
+        &self,
+        mut subscriptions: Vec<Subscription<Exchange, Kind>>,
+    ) -> Vec<Subscription<Exchange, Kind>> {
+        subscriptions.retain(|subscription| self.is_allowed(&subscription.instrument));
+        subscriptions
+    }
+}
+
+/// Canonical symbol of an [`Instrument`] matched by a [`SymbolMatcher`], independent of any
+/// exchange specific market format.
+///
+/// eg/ Instrument { base: "btc", quote: "usdt", ... } => "btc_usdt"
+pub fn canonical_symbol(instrument: &Instrument) -> String {
+    format!("{}_{}", instrument.base, instrument.quote).to_lowercase()
+}
+
+/// Determine if either the base or quote of the provided [`Instrument`] is a [`WILDCARD`].
+fn is_partial_wildcard(instrument: &Instrument) -> bool {
+    instrument.base.as_ref() == WILDCARD || instrument.quote.as_ref() == WILDCARD
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exchange::okx::Okx, subscription::trade::PublicTrades};
+    use barter_integration::model::instrument::kind::InstrumentKind;
+
+    #[test]
+    fn test_symbol_filter_is_allowed() {
+        struct TestCase {
+            filter: SymbolFilter,
+            input: Instrument,
+            expected: bool,
+        }
+
+        let leveraged = SymbolMatcher::regex(r"^\w+(3l|3s|up|down)_").unwrap();
+        let stable_stable = SymbolMatcher::regex(r"^(usdc|usdt|dai)_(usdc|usdt|dai)$").unwrap();
+
+        let tests = vec![
+            TestCase {
+                // TC0: empty SymbolFilter allows every Instrument
+                filter: SymbolFilter::new(),
+                input: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                expected: true,
+            },
+            TestCase {
+                // TC1: leveraged token denied by regex
+                filter: SymbolFilter::new().deny(leveraged.clone()),
+                input: Instrument::from(("btc3l", "usdt", InstrumentKind::Spot)),
+                expected: false,
+            },
+            TestCase {
+                // TC2: stable-stable pair denied by regex
+                filter: SymbolFilter::new().deny(stable_stable),
+                input: Instrument::from(("usdc", "usdt", InstrumentKind::Spot)),
+                expected: false,
+            },
+            TestCase {
+                // TC3: Instrument not matching the allow list substring
+                filter: SymbolFilter::new().allow(SymbolMatcher::substring("_USDT")),
+                input: Instrument::from(("btc", "usd", InstrumentKind::Perpetual)),
+                expected: false,
+            },
+            TestCase {
+                // TC4: deny list takes precedence over the allow list
+                filter: SymbolFilter::new()
+                    .allow(SymbolMatcher::substring("_usdt"))
+                    .deny(leveraged),
+                input: Instrument::from(("ethup", "usdt", InstrumentKind::Spot)),
+                expected: false,
+            },
+            TestCase {
+                // TC5: wildcard Instrument is allowed at subscribe time
+                filter: SymbolFilter::new().allow(SymbolMatcher::substring("btc_")),
+                input: Instrument::from((WILDCARD, WILDCARD, InstrumentKind::Perpetual)),
+                expected: true,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.filter.is_allowed(&test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_symbol_filter_retain_subscriptions() {
+        let filter = SymbolFilter::new().deny(SymbolMatcher::substring("usdc_"));
+
+        let actual = filter.retain(vec![
+            Subscription::from((Okx, "btc", "usdt", InstrumentKind::Spot, PublicTrades)),
+            Subscription::from((Okx, "usdc", "usdt", InstrumentKind::Spot, PublicTrades)),
+            Subscription::from((Okx, WILDCARD, WILDCARD, InstrumentKind::Spot, PublicTrades)),
+        ]);
+
+        assert_eq!(
+            actual,
+            vec![
+                Subscription::from((Okx, "btc", "usdt", InstrumentKind::Spot, PublicTrades)),
+                Subscription::from((Okx, WILDCARD, WILDCARD, InstrumentKind::Spot, PublicTrades)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_symbol_matcher_invalid_regex() {
+        assert!(SymbolMatcher::regex("(unclosed").is_err());
+    }
+}
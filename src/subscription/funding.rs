@@ -0,0 +1,59 @@
+use super::{trade::TradeSource, SubKind};
+use barter_integration::model::Side;
+use barter_macro::{DeSubKind, SerSubKind};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`FundingTrade`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events from a funding (margin lending) market.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct FundingTrades;
+
+impl SubKind for FundingTrades {
+    type Event = FundingTrade;
+
+    const NAME: &'static str = "funding_trades";
+}
+
+/// Normalised Barter [`FundingTrade`] model.
+///
+/// A funding trade matches a lender & a borrower of `amount` at a daily `rate`, for a `period`
+/// measured in days.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct FundingTrade {
+    pub id: String,
+    pub rate: f64,
+    pub period: u32,
+    pub amount: f64,
+    pub side: Side,
+    #[serde(default)]
+    pub source: TradeSource,
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`FundingTicker`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events from a funding (margin lending) market.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct FundingTickers;
+
+impl SubKind for FundingTickers {
+    type Event = FundingTicker;
+
+    const NAME: &'static str = "funding_tickers";
+}
+
+/// Normalised Barter [`FundingTicker`] model.
+///
+/// Rates are daily rates, and periods are measured in days.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct FundingTicker {
+    pub flash_return_rate: f64,
+    pub bid_rate: f64,
+    pub bid_period: u32,
+    pub bid_amount: f64,
+    pub ask_rate: f64,
+    pub ask_period: u32,
+    pub ask_amount: f64,
+    pub last_rate: f64,
+    pub volume: f64,
+    pub high: f64,
+    pub low: f64,
+}
@@ -0,0 +1,33 @@
+use super::SubKind;
+use barter_macro::{DeSubKind, SerSubKind};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`OptionGreeks`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events, summarising the implied volatility &
+/// greeks of an option contract.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct OptionSummary;
+
+impl SubKind for OptionSummary {
+    type Event = OptionGreeks;
+
+    const NAME: &'static str = "option_summary";
+}
+
+/// Normalised Barter [`OptionGreeks`] model.
+///
+/// Implied volatilities are annualised fractions (eg/ 0.45 is 45%), and greeks are Black-Scholes
+/// greeks in units of the quote currency. Fields are `None` if the exchange does not provide
+/// them (eg/ no bid implied volatility when the book has no bids).
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct OptionGreeks {
+    pub mark_iv: Option<f64>,
+    pub bid_iv: Option<f64>,
+    pub ask_iv: Option<f64>,
+    pub delta: Option<f64>,
+    pub gamma: Option<f64>,
+    pub theta: Option<f64>,
+    pub vega: Option<f64>,
+    /// Forward price of the underlying at the option expiry.
+    pub forward_price: Option<f64>,
+}
@@ -10,6 +10,24 @@ pub struct Liquidations;
 
 impl SubKind for Liquidations {
     type Event = Liquidation;
+
+    const NAME: &'static str = "liquidations";
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`Liquidation`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events from an exchange all-market liquidations
+/// channel, rather than a per-market channel.
+///
+/// ### Notes
+/// Every subscribed [`Instrument`](barter_integration::model::instrument::Instrument) shares a
+/// single exchange channel, and the [`Liquidation`]s of any un-subscribed market are discarded.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct AllMarketLiquidations;
+
+impl SubKind for AllMarketLiquidations {
+    type Event = Liquidation;
+
+    const NAME: &'static str = "all_market_liquidations";
 }
 
 /// Normalised Barter [`Liquidation`] model.
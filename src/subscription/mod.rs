@@ -1,4 +1,4 @@
-use crate::exchange::StreamSelector;
+use crate::exchange::{StreamSelector, WildcardSupport};
 use barter_integration::{
     error::SocketError,
     model::{
@@ -20,9 +20,32 @@ pub mod book;
 /// Candle [`SubKind`] and the associated Barter output data model.
 pub mod candle;
 
+/// Derivatives statistics [`SubKind`] and the associated Barter output data model.
+pub mod derivatives;
+
+/// [`SymbolFilter`](filter::SymbolFilter) allow & deny lists of the
+/// [`Instrument`]s that may be subscribed to.
+pub mod filter;
+
+/// Funding market [`SubKind`]s and the associated Barter output data models.
+pub mod funding;
+
+/// Option summary [`SubKind`] and the associated Barter output data model.
+pub mod greeks;
+
 /// Liquidation [`SubKind`] and the associated Barter output data model.
 pub mod liquidation;
 
+/// [`RawChannel`](raw::RawChannel) [`SubKind`] wrapper used to subscribe to a pre-resolved
+/// exchange channel, bypassing the default channel mapping of the wrapped [`SubKind`].
+pub mod raw;
+
+/// Instrument status [`SubKind`] and the associated Barter output data model.
+pub mod status;
+
+/// Ticker [`SubKind`] and the associated Barter output data model.
+pub mod ticker;
+
 /// Public trade [`SubKind`] and the associated Barter output data model.
 pub mod trade;
 
@@ -32,6 +55,31 @@ where
     Self: Debug + Clone,
 {
     type Event: Debug;
+
+    /// Stable snake_case name of this [`SubKind`] (eg/ "public_trades"), independent of any
+    /// parameters it is configured with.
+    const NAME: &'static str;
+
+    /// True if [`retain`](SubKind::retain) may discard events, in which case the consumer loop
+    /// applies it to every consumed [`Self::Event`].
+    const FILTERED: bool = false;
+
+    /// Determine if the provided [`Self::Event`] satisfies the parameters declared by this
+    /// [`SubKind`] (eg/ a minimum trade size), and should be emitted. Defaults to true.
+    fn retain(&self, _event: &Self::Event) -> bool {
+        true
+    }
+}
+
+/// Base & quote [`Symbol`] of a wildcard [`Instrument`], used to subscribe to every market of an
+/// [`InstrumentKind`] where the exchange natively supports it (see [`WildcardSupport`]).
+///
+/// eg/ `Subscription::from((Okx, WILDCARD, WILDCARD, InstrumentKind::Perpetual, Liquidations))`
+pub const WILDCARD: &str = "*";
+
+/// Determine if the provided [`Instrument`] is a [`WILDCARD`] [`Instrument`].
+pub fn is_wildcard(instrument: &Instrument) -> bool {
+    instrument.base.as_ref() == WILDCARD && instrument.quote.as_ref() == WILDCARD
 }
 
 /// Barter [`Subscription`] used to subscribe to a [`SubKind`] for a particular exchange
@@ -81,10 +129,9 @@ impl<Exchange, S, I, Kind> From<(Exchange, S, I, Kind)> for Subscription<Exchang
 where
     I: Into<Instrument>,
 {
-    fn from((exchange, symbol, instrument, kind): (Exchange, S, I, Kind)) -> Self {
+    fn from((exchange, _symbol, instrument, kind): (Exchange, S, I, Kind)) -> Self {
         Self::new(exchange, instrument, kind)
     }
-
 }
 
 impl<Exchange, Kind> Subscription<Exchange, Kind> {
@@ -114,13 +161,30 @@ where
         let exchange = Exchange::ID;
 
         // Validate the Exchange supports the Subscription InstrumentKind
-        if exchange.supports(self.instrument.kind) {
-            Ok(self)
-        } else {
-            Err(SocketError::Unsupported {
+        if !exchange.supports(self.instrument.kind) {
+            return Err(SocketError::Unsupported {
                 entity: exchange.as_str(),
                 item: self.instrument.kind.to_string(),
-            })
+            });
+        }
+
+        // Validate the Exchange supports the Subscription Instrument being a wildcard, or not
+        match (
+            is_wildcard(&self.instrument),
+            <Exchange as StreamSelector<Kind>>::WILDCARD,
+        ) {
+            (true, WildcardSupport::Unsupported) => Err(SocketError::Unsupported {
+                entity: exchange.as_str(),
+                item: format!("wildcard {:?} Instrument", self.kind),
+            }),
+            (false, WildcardSupport::Required) => Err(SocketError::Unsupported {
+                entity: exchange.as_str(),
+                item: format!(
+                    "non-wildcard {:?} Instrument {}",
+                    self.kind, self.instrument
+                ),
+            }),
+            _ => Ok(self),
         }
     }
 }
@@ -134,6 +198,9 @@ pub struct SubscriptionMeta {
     pub instrument_map: Map<Instrument>,
     /// Collection of [`WsMessage`]s containing exchange specific subscription payloads to be sent.
     pub subscriptions: Vec<WsMessage>,
+    /// Client-supplied request ids embedded in the `subscriptions`, if the exchange
+    /// [`Connector`](crate::exchange::Connector) defines a request id field.
+    pub request_ids: Vec<u64>,
 }
 
 /// New type`HashMap` that maps a [`SubscriptionId`] to some associated type `T`.
@@ -154,6 +221,7 @@ impl<T> FromIterator<(SubscriptionId, T)> for Map<T> {
 
 impl<T> Map<T> {
     /// Find the `T` associated with the provided [`SubscriptionId`].
+    #[allow(clippy::result_large_err)]
     pub fn find(&self, id: &SubscriptionId) -> Result<T, SocketError>
     where
         T: Clone,
@@ -165,6 +233,7 @@ impl<T> Map<T> {
     }
 
     /// Find the mutable reference to `T` associated with the provided [`SubscriptionId`].
+    #[allow(clippy::result_large_err)]
     pub fn find_mut(&mut self, id: &SubscriptionId) -> Result<&mut T, SocketError> {
         self.0
             .get_mut(id)
@@ -358,6 +427,20 @@ mod tests {
                         PublicTrades,
                     ))),
                 },
+                TestCase {
+                    // TC2: Invalid Okx PublicTrades subscription w/ unsupported wildcard
+                    input: Subscription::from((
+                        Okx,
+                        WILDCARD,
+                        WILDCARD,
+                        InstrumentKind::Perpetual,
+                        PublicTrades,
+                    )),
+                    expected: Err(SocketError::Unsupported {
+                        entity: "okx",
+                        item: "wildcard PublicTrades Instrument".to_owned(),
+                    }),
+                },
             ];
 
             for (index, test) in tests.into_iter().enumerate() {
@@ -378,6 +461,64 @@ mod tests {
         }
     }
 
+    mod wildcard {
+        use super::*;
+        use crate::{exchange::okx::Okx, subscription::liquidation::Liquidations};
+        use barter_integration::model::instrument::kind::InstrumentKind;
+
+        #[test]
+        fn test_validate_okx_liquidations() {
+            struct TestCase {
+                input: Subscription<Okx, Liquidations>,
+                expected: bool,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: Valid Okx Liquidations subscription w/ required wildcard
+                    input: Subscription::from((
+                        Okx,
+                        WILDCARD,
+                        WILDCARD,
+                        InstrumentKind::Perpetual,
+                        Liquidations,
+                    )),
+                    expected: true,
+                },
+                TestCase {
+                    // TC1: Invalid Okx Liquidations subscription w/ specific market
+                    input: Subscription::from((
+                        Okx,
+                        "btc",
+                        "usdt",
+                        InstrumentKind::Perpetual,
+                        Liquidations,
+                    )),
+                    expected: false,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = test.input.validate().is_ok();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+
+        #[test]
+        fn test_is_wildcard() {
+            assert!(is_wildcard(&Instrument::from((
+                WILDCARD,
+                WILDCARD,
+                InstrumentKind::Spot
+            ))));
+            assert!(!is_wildcard(&Instrument::from((
+                WILDCARD,
+                "usdt",
+                InstrumentKind::Spot
+            ))));
+        }
+    }
+
     mod instrument_map {
         use super::*;
         use barter_integration::model::instrument::{kind::InstrumentKind, Instrument};
@@ -0,0 +1,192 @@
+use super::{SubKind, Subscription};
+use crate::{
+    exchange::{Connector, StreamSelector, WildcardSupport},
+    Identifier,
+};
+use barter_integration::model::instrument::kind::InstrumentKind;
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+
+/// Barter [`Subscription`] [`SubKind`] wrapper that subscribes to the provided pre-resolved
+/// exchange `channel` (eg/ Okx "trades-all"), rather than the channel the wrapped [`SubKind`]
+/// maps to by default.
+///
+/// The subscription is still sent using the exchange [`Connector::requests`], validated, and
+/// yields [`MarketEvent<T>`](crate::event::MarketEvent)s of the wrapped [`SubKind::Event`], so
+/// the raw channel payloads must deserialise as those of the wrapped [`SubKind`].
+///
+/// eg/ `Subscription::from((Okx, "btc", "usdt", InstrumentKind::Spot, RawChannel::new("trades-all", PublicTrades)))`
+///
+/// ### Notes
+/// The `channel` is sent verbatim, so it must be formatted exactly as the exchange expects
+/// (eg/ Binance channels include the "@" prefix).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct RawChannel<Kind> {
+    pub channel: &'static str,
+    pub kind: Kind,
+}
+
+impl<Kind> RawChannel<Kind> {
+    /// Construct a new [`RawChannel`] subscribing to the `channel` & yielding the events of the
+    /// provided [`SubKind`].
+    pub fn new(channel: &'static str, kind: Kind) -> Self {
+        Self { channel, kind }
+    }
+}
+
+impl<Kind> SubKind for RawChannel<Kind>
+where
+    Kind: SubKind,
+{
+    type Event = Kind::Event;
+
+    const NAME: &'static str = "raw_channel";
+
+    const FILTERED: bool = Kind::FILTERED;
+
+    fn retain(&self, event: &Self::Event) -> bool {
+        self.kind.retain(event)
+    }
+}
+
+impl<Kind> Display for RawChannel<Kind>
+where
+    Kind: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.kind, self.channel)
+    }
+}
+
+impl<Exchange, Kind> Identifier<Exchange::Channel> for Subscription<Exchange, RawChannel<Kind>>
+where
+    Exchange: Connector,
+    Exchange::Channel: From<&'static str>,
+{
+    fn id(&self) -> Exchange::Channel {
+        Exchange::Channel::from(self.kind.channel)
+    }
+}
+
+impl<Exchange, Kind> StreamSelector<RawChannel<Kind>> for Exchange
+where
+    Exchange: StreamSelector<Kind>,
+    Kind: SubKind,
+    <Exchange as StreamSelector<Kind>>::Stream: crate::MarketStream<Exchange, RawChannel<Kind>>,
+{
+    type Stream = <Exchange as StreamSelector<Kind>>::Stream;
+
+    const WILDCARD: WildcardSupport = <Exchange as StreamSelector<Kind>>::WILDCARD;
+
+    fn supports(instrument_kind: InstrumentKind) -> bool {
+        <Exchange as StreamSelector<Kind>>::supports(instrument_kind)
+    }
+
+    fn supports_kind(kind: &RawChannel<Kind>) -> bool {
+        <Exchange as StreamSelector<Kind>>::supports_kind(&kind.kind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::okx::{trade::OkxTrades, Okx},
+        subscriber::{
+            config::ConnectionConfig,
+            mapper::{SubscriptionMapper, WebSocketSubMapper},
+        },
+        subscription::trade::{PublicTrade, PublicTrades},
+        transformer::{stateless::StatelessTransformer, ExchangeTransformer},
+    };
+    use barter_integration::{
+        model::{instrument::Instrument, Side, SubscriptionId},
+        protocol::websocket::WsMessage,
+        Transformer,
+    };
+    use tokio::sync::mpsc;
+
+    #[tokio::test]
+    async fn test_raw_channel_subscription_yields_wrapped_kind() {
+        fn assert_stream_selector<Exchange, Kind>()
+        where
+            Exchange: StreamSelector<Kind>,
+            Kind: SubKind,
+        {
+        }
+        assert_stream_selector::<Okx, RawChannel<PublicTrades>>();
+
+        let subscription = Subscription::from((
+            Okx,
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            RawChannel::new("trades-all", PublicTrades),
+        ));
+
+        // Raw channel bypasses the default "trades" channel of PublicTrades
+        let meta = WebSocketSubMapper::map::<Okx, RawChannel<PublicTrades>>(
+            &[subscription],
+            &ConnectionConfig::default(),
+        )
+        .unwrap();
+        let WsMessage::Text(request) = &meta.subscriptions[0] else {
+            panic!("Okx subscription request is not a text WsMessage")
+        };
+        let request = serde_json::from_str::<serde_json::Value>(request).unwrap();
+        assert_eq!(
+            request["args"],
+            serde_json::json!([{"channel": "trades-all", "instId": "BTC-USDT"}])
+        );
+        assert!(meta
+            .instrument_map
+            .0
+            .contains_key(&SubscriptionId::from("trades-all|BTC-USDT")));
+
+        // Raw channel payloads are transformed by the Okx PublicTrades Transformer
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+        let mut transformer =
+            <StatelessTransformer<Okx, PublicTrades, OkxTrades> as ExchangeTransformer<
+                Okx,
+                RawChannel<PublicTrades>,
+            >>::new(ws_sink_tx, meta.instrument_map)
+            .await
+            .unwrap();
+
+        let input = r#"
+        {
+            "arg": {"channel": "trades-all", "instId": "BTC-USDT"},
+            "data": [
+                {
+                    "instId": "BTC-USDT",
+                    "tradeId": "130639474",
+                    "px": "42219.9",
+                    "sz": "0.12060306",
+                    "side": "buy",
+                    "ts": "1630048897897"
+                }
+            ]
+        }
+        "#;
+
+        let events = transformer.transform(serde_json::from_str(input).unwrap());
+        let event = events.into_iter().next().unwrap().unwrap();
+        assert_eq!(
+            event.instrument,
+            Instrument::from(("btc", "usdt", InstrumentKind::Spot))
+        );
+        assert_eq!(
+            event.kind,
+            PublicTrade {
+                id: "130639474".to_string(),
+                price: 42219.9,
+                amount: 0.12060306,
+                side: Side::Buy,
+                source: Default::default(),
+                side_source: Default::default(),
+                order_ids: Default::default(),
+                aggregate_count: None,
+            }
+        );
+    }
+}
@@ -0,0 +1,52 @@
+use super::SubKind;
+use barter_macro::{DeSubKind, SerSubKind};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`InstrumentStatus`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events, announcing the trading state of a
+/// market (eg/ a trading halt or delisting).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct InstrumentStatuses;
+
+impl SubKind for InstrumentStatuses {
+    type Event = InstrumentStatus;
+
+    const NAME: &'static str = "instrument_statuses";
+}
+
+/// Normalised Barter [`InstrumentStatus`] model.
+///
+/// The optional `message` is the exchange provided explanation of the [`InstrumentState`], if
+/// any (eg/ "trading is temporarily halted").
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct InstrumentStatus {
+    pub state: InstrumentState,
+    pub message: Option<String>,
+}
+
+/// Trading state of a market, which determines whether its subscriptions produce data.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InstrumentState {
+    /// Market is trading normally.
+    Online,
+    /// Only limit orders are accepted, so no market orders will match.
+    LimitOnly,
+    /// Only post-only orders are accepted, so no trades will occur.
+    PostOnly,
+    /// Only order cancellations are accepted, so no trades will occur.
+    CancelOnly,
+    /// Trading is temporarily disabled, but the market remains listed.
+    Halted,
+    /// Market is unavailable (eg/ exchange maintenance).
+    Offline,
+    /// Market is permanently removed, so its subscriptions will never produce data again.
+    Delisted,
+}
+
+impl InstrumentState {
+    /// Determine if the market in this [`InstrumentState`] can produce trades.
+    pub fn is_trading(&self) -> bool {
+        matches!(self, InstrumentState::Online | InstrumentState::LimitOnly)
+    }
+}
@@ -0,0 +1,49 @@
+use super::{book::Level, SubKind};
+use barter_macro::{DeSubKind, SerSubKind};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`Ticker`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events, summarising the best bid & ask, last
+/// trade and rolling 24 hour statistics of a market.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct Tickers;
+
+impl SubKind for Tickers {
+    type Event = Ticker;
+
+    const NAME: &'static str = "tickers";
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`Ticker`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events from an exchange all-market ticker
+/// channel, rather than a per-market channel.
+///
+/// ### Notes
+/// Every subscribed [`Instrument`](barter_integration::model::instrument::Instrument) shares a
+/// single exchange channel, and the [`Ticker`]s of any un-subscribed market are discarded.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct AllMarketTickers;
+
+impl SubKind for AllMarketTickers {
+    type Event = Ticker;
+
+    const NAME: &'static str = "all_market_tickers";
+}
+
+/// Normalised Barter [`Ticker`] model.
+///
+/// Statistics are computed by the exchange over a rolling 24 hour window. Exchanges that do not
+/// provide the `vwap_24h` or `trade_count_24h` leave them as `None`.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Ticker {
+    pub best_bid: Level,
+    pub best_ask: Level,
+    pub last_price: f64,
+    pub last_amount: f64,
+    pub open_24h: f64,
+    pub high_24h: f64,
+    pub low_24h: f64,
+    pub volume_24h: f64,
+    pub vwap_24h: Option<f64>,
+    pub trade_count_24h: Option<u64>,
+}
@@ -1,6 +1,11 @@
-use super::SubKind;
-use barter_integration::model::Side;
+use super::{SubKind, Subscription};
+use crate::{
+    exchange::{Connector, StreamSelector, WildcardSupport},
+    Identifier, MarketStream,
+};
+use barter_integration::model::{instrument::kind::InstrumentKind, Side};
 use barter_macro::{DeSubKind, SerSubKind};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use serde::{Deserialize, Serialize};
 
 /// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`PublicTrade`]
@@ -10,6 +15,145 @@ pub struct PublicTrades;
 
 impl SubKind for PublicTrades {
     type Event = PublicTrade;
+
+    const NAME: &'static str = "public_trades";
+}
+
+/// Exchange channel used as the source of [`PublicTrade`]s for a [`PublicTradesFeed`].
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum TradeFeed {
+    /// Standard trades channel used for [`PublicTrades`] (eg/ Okx "trades", Coinbase "matches").
+    #[default]
+    Standard,
+    /// Every individual fill, rather than the trades aggregated by the exchange.
+    ///
+    /// Exchanges may require elevated account access for this data (eg/ Okx "trades-all"
+    /// requires VIP5+).
+    AllFills,
+    /// Last trade summarised by an exchange ticker channel (eg/ Coinbase "ticker").
+    ///
+    /// Lower fidelity than the [`TradeFeed::Standard`] channel since the exchange may conflate
+    /// several fills into a single ticker update.
+    Ticker,
+}
+
+/// Barter [`Subscription`] [`SubKind`] that yields [`PublicTrade`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events from the exchange channel selected by
+/// the [`TradeFeed`] parameter.
+///
+/// A [`TradeFeed`] the exchange does not offer is rejected by
+/// [`StreamSelector::supports_kind`].
+///
+/// eg/ `Subscription::from((Okx, "btc", "usdt", InstrumentKind::Spot, PublicTradesFeed::new(TradeFeed::AllFills)))`
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct PublicTradesFeed {
+    #[serde(default)]
+    pub feed: TradeFeed,
+}
+
+impl PublicTradesFeed {
+    /// Construct a new [`Self`] sourcing [`PublicTrade`]s from the provided [`TradeFeed`].
+    pub fn new(feed: TradeFeed) -> Self {
+        Self { feed }
+    }
+}
+
+impl From<TradeFeed> for PublicTradesFeed {
+    fn from(feed: TradeFeed) -> Self {
+        Self::new(feed)
+    }
+}
+
+impl SubKind for PublicTradesFeed {
+    type Event = PublicTrade;
+
+    const NAME: &'static str = "public_trades_feed";
+}
+
+/// Barter [`Subscription`] [`SubKind`] that yields the [`PublicTrade`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent)s of [`PublicTrades`] which meet a minimum size,
+/// dropping any dust trades before they are emitted.
+///
+/// A [`PublicTrade`] is emitted if its amount is at least the `min_amount`, and its notional
+/// (price * amount) is at least the `min_notional`. Thresholds that are `None` are not applied.
+/// Since the thresholds are declared by the [`Subscription`], they are serialised with it and
+/// applied consistently upon every re-connection.
+///
+/// eg/ `Subscription::from((Okx, "btc", "usdt", InstrumentKind::Spot, FilteredTrades::default().min_notional(dec!(100))))`
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct FilteredTrades {
+    #[serde(default)]
+    pub min_amount: Option<Decimal>,
+    #[serde(default)]
+    pub min_notional: Option<Decimal>,
+}
+
+impl FilteredTrades {
+    /// Only emit [`PublicTrade`]s with an amount of at least `min_amount`.
+    pub fn min_amount(self, min_amount: Decimal) -> Self {
+        Self {
+            min_amount: Some(min_amount),
+            ..self
+        }
+    }
+
+    /// Only emit [`PublicTrade`]s with a notional (price * amount) of at least `min_notional`.
+    pub fn min_notional(self, min_notional: Decimal) -> Self {
+        Self {
+            min_notional: Some(min_notional),
+            ..self
+        }
+    }
+}
+
+impl SubKind for FilteredTrades {
+    type Event = PublicTrade;
+
+    const NAME: &'static str = "filtered_trades";
+
+    const FILTERED: bool = true;
+
+    fn retain(&self, trade: &PublicTrade) -> bool {
+        let at_least = |value: f64, threshold: Option<Decimal>| {
+            threshold
+                .and_then(|threshold| threshold.to_f64())
+                .is_none_or(|threshold| value >= threshold)
+        };
+
+        at_least(trade.amount, self.min_amount)
+            && at_least(trade.price * trade.amount, self.min_notional)
+    }
+}
+
+impl<Exchange> Identifier<Exchange::Channel> for Subscription<Exchange, FilteredTrades>
+where
+    Exchange: Connector + Clone,
+    Subscription<Exchange, PublicTrades>: Identifier<Exchange::Channel>,
+{
+    fn id(&self) -> Exchange::Channel {
+        Subscription::new(self.exchange.clone(), self.instrument.clone(), PublicTrades).id()
+    }
+}
+
+impl<Exchange> StreamSelector<FilteredTrades> for Exchange
+where
+    Exchange: StreamSelector<PublicTrades>,
+    <Exchange as StreamSelector<PublicTrades>>::Stream: MarketStream<Exchange, FilteredTrades>,
+{
+    type Stream = <Exchange as StreamSelector<PublicTrades>>::Stream;
+
+    const WILDCARD: WildcardSupport = <Exchange as StreamSelector<PublicTrades>>::WILDCARD;
+
+    fn supports(instrument_kind: InstrumentKind) -> bool {
+        <Exchange as StreamSelector<PublicTrades>>::supports(instrument_kind)
+    }
 }
 
 /// Normalised Barter [`PublicTrade`] model.
@@ -19,4 +163,191 @@ pub struct PublicTrade {
     pub price: f64,
     pub amount: f64,
     pub side: Side,
+    #[serde(default)]
+    pub source: TradeSource,
+    #[serde(default)]
+    pub side_source: SideSource,
+    #[serde(default)]
+    pub order_ids: TradeOrderIds,
+    /// Number of fills aggregated into this [`PublicTrade`] by the exchange, if the exchange
+    /// channel reports it (eg/ Okx "trades").
+    #[serde(default)]
+    pub aggregate_count: Option<u32>,
+}
+
+impl PublicTrade {
+    /// Number of fills aggregated into this [`PublicTrade`], defaulting to one for exchange
+    /// channels that report every fill individually.
+    pub fn count(&self) -> u32 {
+        self.aggregate_count.unwrap_or(1)
+    }
+
+    /// Determine if this [`PublicTrade`] aggregates more than a single fill.
+    pub fn is_aggregated(&self) -> bool {
+        self.count() > 1
+    }
+}
+
+/// Exchange order ids of the orders matched in a [`PublicTrade`], for microstructure research
+/// beyond the aggressor [`Side`].
+///
+/// Each id is only `Some` if the exchange channel provides it (eg/ Binance Spot "trade" provides
+/// the buyer & seller order ids, Coinbase "matches" provides the maker & taker order ids).
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize)]
+pub struct TradeOrderIds {
+    pub maker: Option<String>,
+    pub taker: Option<String>,
+    pub buyer: Option<String>,
+    pub seller: Option<String>,
+}
+
+/// Origin of a [`PublicTrade`].
+///
+/// Some exchanges deliver a backfill of recent trades upon subscribing, which are flagged as
+/// [`TradeSource::Historical`] so consumers can distinguish seed data from live data.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum TradeSource {
+    /// Trade received in real-time.
+    #[default]
+    Live,
+    /// Trade received as part of an initial snapshot of recent trades.
+    Historical,
+}
+
+/// Origin of a [`PublicTrade`] aggressor [`Side`].
+///
+/// Some exchanges (or channels) omit the aggressor [`Side`], in which case the trade is flagged
+/// as [`SideSource::Absent`] and the `side` is a placeholder, until optionally inferred (see
+/// [`TickRule`](crate::streams::combinator::tick_rule::TickRule)).
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum SideSource {
+    /// Side provided by the exchange.
+    #[default]
+    Exchange,
+    /// Side not provided by the exchange, so the `side` should not be relied upon.
+    Absent,
+    /// Side inferred locally from the price of preceding trades.
+    Inferred,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::okx::{channel::OkxChannel, Okx};
+    use rust_decimal_macros::dec;
+
+    fn trade(price: f64, amount: f64) -> PublicTrade {
+        PublicTrade {
+            id: "1".to_string(),
+            price,
+            amount,
+            side: Side::Buy,
+            source: TradeSource::default(),
+            side_source: SideSource::default(),
+            order_ids: TradeOrderIds::default(),
+            aggregate_count: None,
+        }
+    }
+
+    #[test]
+    fn test_filtered_trades_retain() {
+        struct TestCase {
+            kind: FilteredTrades,
+            input: PublicTrade,
+            expected: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: no thresholds retains dust trades
+                kind: FilteredTrades::default(),
+                input: trade(100.0, 0.0001),
+                expected: true,
+            },
+            TestCase {
+                // TC1: amount below min_amount is filtered
+                kind: FilteredTrades::default().min_amount(dec!(0.01)),
+                input: trade(100.0, 0.001),
+                expected: false,
+            },
+            TestCase {
+                // TC2: amount equal to min_amount passes
+                kind: FilteredTrades::default().min_amount(dec!(0.01)),
+                input: trade(100.0, 0.01),
+                expected: true,
+            },
+            TestCase {
+                // TC3: notional below min_notional is filtered
+                kind: FilteredTrades::default().min_notional(dec!(100)),
+                input: trade(100.0, 0.5),
+                expected: false,
+            },
+            TestCase {
+                // TC4: notional equal to min_notional passes
+                kind: FilteredTrades::default().min_notional(dec!(100)),
+                input: trade(100.0, 1.0),
+                expected: true,
+            },
+            TestCase {
+                // TC5: amount above min_amount, but notional below min_notional is filtered
+                kind: FilteredTrades::default()
+                    .min_amount(dec!(1))
+                    .min_notional(dec!(1000)),
+                input: trade(100.0, 2.0),
+                expected: false,
+            },
+            TestCase {
+                // TC6: both thresholds met passes
+                kind: FilteredTrades::default()
+                    .min_amount(dec!(1))
+                    .min_notional(dec!(1000)),
+                input: trade(100.0, 10.0),
+                expected: true,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(
+                test.kind.retain(&test.input),
+                test.expected,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_filtered_trades_subscription() {
+        fn assert_stream_selector<Exchange, Kind>()
+        where
+            Exchange: StreamSelector<Kind>,
+            Kind: SubKind,
+        {
+        }
+        assert_stream_selector::<Okx, FilteredTrades>();
+
+        let subscription = Subscription::from((
+            Okx,
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            FilteredTrades::default().min_notional(dec!(100)),
+        ));
+
+        // Subscribes to the same channel as PublicTrades
+        let channel: OkxChannel = subscription.id();
+        assert_eq!(channel, OkxChannel::TRADES);
+
+        // Thresholds are serialised with the Subscription
+        let serialised = serde_json::to_string(&subscription).unwrap();
+        let deserialised =
+            serde_json::from_str::<Subscription<Okx, FilteredTrades>>(&serialised).unwrap();
+        assert_eq!(deserialised, subscription);
+    }
 }
@@ -0,0 +1,353 @@
+use barter_integration::protocol::websocket::WsMessage;
+use futures::{SinkExt, StreamExt};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::{net::TcpListener, sync::mpsc, task::JoinHandle};
+use tokio_tungstenite::{accept_async, WebSocketStream};
+use url::Url;
+
+/// Scripted action performed by a [`MockExchange`] on an accepted connection.
+#[derive(Clone, PartialEq, Debug)]
+pub enum MockStep {
+    /// Wait for the next inbound text or binary message (eg/ a subscription request), recording
+    /// it as a [`MockRequest`].
+    Receive,
+    /// Send the [`WsMessage`] to the client (eg/ a subscription ack or market data).
+    Send(WsMessage),
+    /// Send nothing for the [`Duration`] (eg/ going silent to trigger a pong timeout).
+    Silence(Duration),
+    /// Send a close frame & end the connection.
+    Close,
+}
+
+/// Ordered [`MockStep`]s performed by a [`MockExchange`] on a single connection.
+///
+/// A [`MockScript`] that does not end with a [`MockStep::Close`] holds the connection open
+/// (silently) until the client disconnects.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct MockScript {
+    pub steps: Vec<MockStep>,
+}
+
+impl MockScript {
+    /// Construct a new empty [`MockScript`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a [`MockStep::Receive`].
+    pub fn receive(self) -> Self {
+        self.step(MockStep::Receive)
+    }
+
+    /// Append a [`MockStep::Send`] of the provided [`WsMessage`].
+    pub fn send(self, message: WsMessage) -> Self {
+        self.step(MockStep::Send(message))
+    }
+
+    /// Append a [`MockStep::Send`] of the provided text payload.
+    pub fn send_text<S>(self, payload: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.send(WsMessage::Text(payload.into()))
+    }
+
+    /// Append a [`MockStep::Silence`] for the provided [`Duration`].
+    pub fn silence(self, duration: Duration) -> Self {
+        self.step(MockStep::Silence(duration))
+    }
+
+    /// Append a [`MockStep::Close`].
+    pub fn close(self) -> Self {
+        self.step(MockStep::Close)
+    }
+
+    /// Append the provided [`MockStep`].
+    pub fn step(mut self, step: MockStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Receive a subscription request for each of the `acks`, acknowledging each request with
+    /// the associated ack payload.
+    pub fn subscribe_acks<Acks, S>(self, acks: Acks) -> Self
+    where
+        Acks: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        acks.into_iter()
+            .fold(self, |script, ack| script.receive().send_text(ack))
+    }
+
+    /// Scripted connect-validate-data-close sequence: acknowledge the subscription requests,
+    /// send the market `data` payloads, then close the connection.
+    pub fn subscribe_then_close<Acks, Data, S>(acks: Acks, data: Data) -> Self
+    where
+        Acks: IntoIterator<Item = S>,
+        Data: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        data.into_iter()
+            .fold(Self::new().subscribe_acks(acks), |script, payload| {
+                script.send_text(payload)
+            })
+            .close()
+    }
+
+    /// Scripted connect-validate-silent sequence: acknowledge the subscription requests, then send
+    /// nothing for the provided [`Duration`] before closing the connection.
+    pub fn subscribe_then_silence<Acks, S>(acks: Acks, silence: Duration) -> Self
+    where
+        Acks: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::new().subscribe_acks(acks).silence(silence).close()
+    }
+}
+
+/// Inbound message received by a [`MockExchange`] during a [`MockStep::Receive`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct MockRequest {
+    /// Index of the connection (in accept order) the message was received on.
+    pub connection: usize,
+    pub message: WsMessage,
+}
+
+/// In-process WebSocket exchange server that performs a [`MockScript`] on each accepted
+/// connection, used to integration test connection resilience (eg/ re-connection, batching &
+/// subscription validation) without connecting to live exchanges.
+///
+/// The Nth accepted connection performs the Nth [`MockScript`], and any connection accepted
+/// after the scripts are exhausted is dropped immediately. Dial the [`MockExchange`] by
+/// configuring [`ConnectionConfig::url`](crate::subscriber::config::ConnectionConfig::url) with
+/// [`MockExchange::url`].
+///
+/// The server is shut down when the [`MockExchange`] is dropped.
+#[derive(Debug)]
+pub struct MockExchange {
+    url: Url,
+    connections: Arc<AtomicUsize>,
+    requests: mpsc::UnboundedReceiver<MockRequest>,
+    server: JoinHandle<()>,
+}
+
+impl MockExchange {
+    /// Bind a [`MockExchange`] to an ephemeral localhost port & start serving the provided
+    /// [`MockScript`]s.
+    pub async fn start<Scripts>(scripts: Scripts) -> std::io::Result<Self>
+    where
+        Scripts: IntoIterator<Item = MockScript>,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = Url::parse(&format!("ws://{}", listener.local_addr()?))
+            .expect("localhost socket address is a valid Url");
+
+        let connections = Arc::new(AtomicUsize::new(0));
+        let (requests_tx, requests) = mpsc::unbounded_channel();
+
+        let server = tokio::spawn(serve(
+            listener,
+            scripts.into_iter().collect(),
+            Arc::clone(&connections),
+            requests_tx,
+        ));
+
+        Ok(Self {
+            url,
+            connections,
+            requests,
+            server,
+        })
+    }
+
+    /// [`Url`] of the [`MockExchange`] WebSocket server.
+    pub fn url(&self) -> Url {
+        self.url.clone()
+    }
+
+    /// Number of connections accepted so far.
+    pub fn connections(&self) -> usize {
+        self.connections.load(Ordering::SeqCst)
+    }
+
+    /// Wait for the next [`MockRequest`] received by any connection, returning `None` once the
+    /// server has shut down.
+    pub async fn next_request(&mut self) -> Option<MockRequest> {
+        self.requests.recv().await
+    }
+}
+
+impl Drop for MockExchange {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+/// Accept connections, performing the next [`MockScript`] on each in a separate task.
+async fn serve(
+    listener: TcpListener,
+    scripts: Vec<MockScript>,
+    connections: Arc<AtomicUsize>,
+    requests_tx: mpsc::UnboundedSender<MockRequest>,
+) {
+    let mut scripts = scripts.into_iter();
+    loop {
+        let Ok((stream, _)) = listener.accept().await else {
+            continue;
+        };
+        let connection = connections.fetch_add(1, Ordering::SeqCst);
+
+        let Some(script) = scripts.next() else {
+            drop(stream);
+            continue;
+        };
+
+        let requests_tx = requests_tx.clone();
+        tokio::spawn(async move {
+            if let Ok(websocket) = accept_async(stream).await {
+                perform(connection, websocket, script, requests_tx).await;
+            }
+        });
+    }
+}
+
+/// Perform the [`MockScript`] on an accepted connection.
+async fn perform<S>(
+    connection: usize,
+    mut websocket: WebSocketStream<S>,
+    script: MockScript,
+    requests_tx: mpsc::UnboundedSender<MockRequest>,
+) where
+    S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+{
+    for step in script.steps {
+        match step {
+            MockStep::Receive => {
+                let Some(message) = next_data_message(&mut websocket).await else {
+                    return;
+                };
+                let _ = requests_tx.send(MockRequest {
+                    connection,
+                    message,
+                });
+            }
+            MockStep::Send(message) => {
+                if websocket.send(message).await.is_err() {
+                    return;
+                }
+            }
+            MockStep::Silence(duration) => tokio::time::sleep(duration).await,
+            MockStep::Close => {
+                let _ = websocket.close(None).await;
+                return;
+            }
+        }
+    }
+
+    // Hold the connection open until the client disconnects
+    while next_data_message(&mut websocket).await.is_some() {}
+}
+
+/// Wait for the next inbound text or binary message, skipping control frames. Returns `None`
+/// once the client disconnects.
+async fn next_data_message<S>(websocket: &mut WebSocketStream<S>) -> Option<WsMessage>
+where
+    S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+{
+    while let Some(message) = websocket.next().await {
+        match message {
+            Ok(message @ (WsMessage::Text(_) | WsMessage::Binary(_))) => return Some(message),
+            Ok(WsMessage::Close(_)) | Err(_) => return None,
+            Ok(_) => continue,
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        error::DataError,
+        exchange::{okx::Okx, StreamSelector},
+        subscriber::config::ConnectionConfig,
+        subscription::{trade::PublicTrades, Subscription},
+        MarketStream,
+    };
+    use barter_integration::{
+        error::SocketError,
+        model::{
+            instrument::{kind::InstrumentKind, Instrument},
+            Side,
+        },
+    };
+
+    #[tokio::test]
+    async fn test_mock_exchange_connect_validate_data_close() {
+        let mut exchange = MockExchange::start([MockScript::subscribe_then_close(
+            [r#"{"event": "subscribe", "arg": {"channel": "trades", "instId": "BTC-USDT"}}"#],
+            [r#"{
+                "arg": {"channel": "trades", "instId": "BTC-USDT"},
+                "data": [{
+                    "instId": "BTC-USDT",
+                    "tradeId": "130639474",
+                    "px": "42219.9",
+                    "sz": "0.12060306",
+                    "side": "buy",
+                    "ts": "1630048897897"
+                }]
+            }"#],
+        )])
+        .await
+        .unwrap();
+
+        let subscriptions = [Subscription::from((
+            Okx,
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            PublicTrades,
+        ))];
+        let config = ConnectionConfig::default().url(exchange.url());
+
+        // Connect & validate the subscription against the scripted ack
+        let mut stream =
+            <Okx as StreamSelector<PublicTrades>>::Stream::init(&subscriptions, &config)
+                .await
+                .unwrap();
+        assert_eq!(exchange.connections(), 1);
+
+        let request = exchange.next_request().await.unwrap();
+        assert_eq!(request.connection, 0);
+        let WsMessage::Text(request) = request.message else {
+            panic!("Okx subscription request is not a text WsMessage")
+        };
+        let request = serde_json::from_str::<serde_json::Value>(&request).unwrap();
+        assert_eq!(
+            request["args"],
+            serde_json::json!([{"channel": "trades", "instId": "BTC-USDT"}])
+        );
+
+        // Scripted data is transformed into a MarketEvent<PublicTrade>
+        let event = stream.next().await.unwrap().unwrap();
+        assert_eq!(
+            event.instrument,
+            Instrument::from(("btc", "usdt", InstrumentKind::Spot))
+        );
+        assert_eq!(event.kind.id, "130639474");
+        assert_eq!(event.kind.side, Side::Buy);
+
+        // Scripted close frame terminates the stream
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(DataError::Socket(error))) if matches!(*error, SocketError::Terminated(_))
+        ));
+    }
+}
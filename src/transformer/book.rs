@@ -1,8 +1,13 @@
 use crate::{
     error::DataError,
     event::{MarketEvent, MarketIter},
-    exchange::Connector,
-    subscription::{book::OrderBook, Map, SubKind},
+    exchange::{Connector, ExchangeId},
+    subscriber::config::ConnectionConfig,
+    subscription::{
+        book::{OrderBook, OrderBookDelta, OrderBookDeltas},
+        raw::RawChannel,
+        Map, SubKind,
+    },
     transformer::ExchangeTransformer,
     Identifier,
 };
@@ -13,7 +18,14 @@ use barter_integration::{
     Transformer,
 };
 use serde::{Deserialize, Serialize};
-use std::marker::PhantomData;
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt::{Debug, Formatter},
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
 use tokio::sync::mpsc;
 
 /// Defines how to apply a [`Self::Update`] to an [`Self::OrderBook`].
@@ -35,6 +47,40 @@ where
         Exchange: Send,
         Kind: Send;
 
+    /// Initialises the [`InstrumentOrderBook`] for the provided [`Instrument`] using the
+    /// [`ConnectionConfig`] of the connection (eg/ to select the [`BookSeed`]).
+    ///
+    /// Defaults to [`init()`](OrderBookUpdater::init()), ignoring the [`ConnectionConfig`].
+    async fn init_with_config<Exchange, Kind>(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+        _: &ConnectionConfig,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        Self::init::<Exchange, Kind>(ws_sink_tx, instrument).await
+    }
+
+    /// Determine if the [`Self::OrderBook`] must retain every [`Level`](crate::subscription::book::Level)
+    /// to be maintained correctly (eg/ to validate exchange checksums computed over the full
+    /// depth), in which case [`BookPruning`] is only applied to emitted snapshots.
+    ///
+    /// Defaults to false, so the internal [`Self::OrderBook`] is also pruned to bound memory.
+    fn requires_full_depth() -> bool {
+        false
+    }
+
+    /// Determine if the [`InstrumentOrderBook`] maintained before a disconnection can be resumed
+    /// by a re-connection (see [`BookResume`]).
+    ///
+    /// Only true for [`OrderBookUpdater`]s that validate the sequence of every update, & so
+    /// detect any update missed during the disconnection. Defaults to false.
+    fn resumable() -> bool {
+        false
+    }
+
     /// Apply the [`Self::Update`] to the provided mutable [`Self::OrderBook`].
     fn update(
         &mut self,
@@ -52,36 +98,242 @@ pub struct InstrumentOrderBook<Updater> {
     pub book: OrderBook,
 }
 
+/// Configures how a [`MultiBookTransformer`] handles an [`OrderBook`] that is crossed or locked
+/// after an update, which a correctly maintained [`OrderBook`] never is.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum BookAnomalyPolicy {
+    /// Yield the [`OrderBook`] followed by a non-terminal [`DataError::BookAnomaly`].
+    #[default]
+    Emit,
+    /// Discard the [`OrderBook`] & yield a terminal [`DataError::BookAnomaly`], so the
+    /// [`MarketStream`](crate::MarketStream) is re-initialised from a fresh snapshot.
+    Resync,
+}
+
+/// Source of the initial [`OrderBook`] an [`OrderBookUpdater`] applies deltas to.
+///
+/// Only exchanges providing a partial depth stream (eg/
+/// [`BinanceSpot`](crate::exchange::binance::spot::BinanceSpot) "depth20") support
+/// [`BookSeed::PartialDepth`], any others always seed from a REST snapshot.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum BookSeed {
+    /// Seed from a full-depth REST snapshot, consuming exchange request weight.
+    #[default]
+    Rest,
+    /// Seed from the first message of the exchange partial depth WebSocket stream, avoiding
+    /// REST requests. Only the top levels are seeded, so deeper levels are only known once
+    /// updated by a delta, which is acceptable when full depth accuracy is not required.
+    PartialDepth,
+}
+
+/// Configures how a [`MultiBookTransformer`] prunes far-away [`Level`](crate::subscription::book::Level)s
+/// of each [`OrderBook`], bounding the memory of full-depth books (eg/ of illiquid instruments)
+/// while preserving the tradeable range.
+///
+/// Emitted snapshots are always pruned, whereas the internal [`OrderBook`] is only pruned if the
+/// [`OrderBookUpdater`] does not [`require_full_depth()`](OrderBookUpdater::requires_full_depth()).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum BookPruning {
+    /// Retain only the levels priced within `bps` basis points of the mid price.
+    PriceBand { bps: u32 },
+    /// Retain only the best `depth` levels of each side.
+    Depth(usize),
+}
+
+impl BookPruning {
+    /// Prune the provided [`OrderBook`].
+    pub fn apply(&self, book: &mut OrderBook) {
+        match *self {
+            Self::PriceBand { bps } => book.prune_price_band(bps),
+            Self::Depth(depth) => {
+                book.bids.truncate(depth);
+                book.asks.truncate(depth);
+            }
+        }
+    }
+}
+
+/// Store of the [`InstrumentOrderBook`]s of disconnected [`MultiBookTransformer`]s, enabling a
+/// quick re-connection to resume each [`OrderBook`] rather than re-initialising it from a fresh
+/// snapshot, which shortens the gap in which no [`OrderBook`] is available.
+///
+/// Every clone of a [`BookResume`] (eg/ via a cloned [`ConnectionConfig`]) shares the same store.
+/// An [`OrderBook`] is only resumed by a re-connection within `max_gap` of the disconnection, and
+/// only if the [`OrderBookUpdater`] is [`resumable()`](OrderBookUpdater::resumable()). The first
+/// update after resuming must then continue the sequence, otherwise the [`OrderBookUpdater`]
+/// yields a terminal [`DataError::InvalidSequence`] & the [`OrderBook`] is re-initialised.
+#[derive(Clone)]
+pub struct BookResume {
+    pub max_gap: Duration,
+    stashes: Arc<Mutex<HashMap<(ExchangeId, TypeId), AnyBookStash>>>,
+}
+
+impl BookResume {
+    /// Construct a new [`BookResume`] that resumes [`OrderBook`]s after a re-connection gap of at
+    /// most `max_gap`.
+    pub fn new(max_gap: Duration) -> Self {
+        Self {
+            max_gap,
+            stashes: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Retrieve the [`BookStash`] of the provided [`OrderBookUpdater`] for the exchange.
+    pub fn stash<Updater>(&self, exchange: ExchangeId) -> BookStash<Updater>
+    where
+        Updater: Send + 'static,
+    {
+        self.stashes
+            .lock()
+            .unwrap()
+            .entry((exchange, TypeId::of::<Updater>()))
+            .or_insert_with(|| Box::new(BookStash::<Updater>::new(self.max_gap)))
+            .downcast_ref::<BookStash<Updater>>()
+            .expect("BookStash is keyed by the TypeId of its OrderBookUpdater")
+            .clone()
+    }
+}
+
+impl Debug for BookResume {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BookResume")
+            .field("max_gap", &self.max_gap)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for BookResume {
+    fn eq(&self, other: &Self) -> bool {
+        self.max_gap == other.max_gap && Arc::ptr_eq(&self.stashes, &other.stashes)
+    }
+}
+
+impl Eq for BookResume {}
+
+/// Type erased [`BookStash`] of any [`OrderBookUpdater`].
+type AnyBookStash = Box<dyn Any + Send>;
+
+/// [`InstrumentOrderBook`]s of a [`BookStash`], each alongside the time of the disconnection.
+type StashedBooks<Updater> = HashMap<SubscriptionId, (Instant, InstrumentOrderBook<Updater>)>;
+
+/// [`BookResume`] store of the disconnected [`InstrumentOrderBook`]s of a single exchange &
+/// [`OrderBookUpdater`], each stashed alongside the time of the disconnection.
+pub struct BookStash<Updater> {
+    max_gap: Duration,
+    books: Arc<Mutex<StashedBooks<Updater>>>,
+}
+
+impl<Updater> BookStash<Updater> {
+    fn new(max_gap: Duration) -> Self {
+        Self {
+            max_gap,
+            books: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Stash the provided [`InstrumentOrderBook`]s of a disconnected [`MultiBookTransformer`].
+    pub fn stash(&self, book_map: Map<InstrumentOrderBook<Updater>>) {
+        let disconnected = Instant::now();
+        self.books.lock().unwrap().extend(
+            book_map
+                .0
+                .into_iter()
+                .map(|(sub_id, book)| (sub_id, (disconnected, book))),
+        );
+    }
+
+    /// Take the stashed [`InstrumentOrderBook`]s of the provided [`SubscriptionId`]s that were
+    /// disconnected within the `max_gap`, discarding any stashed for longer.
+    pub fn take(&self, instrument_map: &Map<Instrument>) -> Map<InstrumentOrderBook<Updater>> {
+        let mut books = self.books.lock().unwrap();
+        books.retain(|_, (disconnected, _)| disconnected.elapsed() <= self.max_gap);
+
+        instrument_map
+            .0
+            .keys()
+            .filter_map(|sub_id| books.remove_entry(sub_id))
+            .map(|(sub_id, (_, book))| (sub_id, book))
+            .collect()
+    }
+}
+
+impl<Updater> Clone for BookStash<Updater> {
+    fn clone(&self) -> Self {
+        Self {
+            max_gap: self.max_gap,
+            books: Arc::clone(&self.books),
+        }
+    }
+}
+
+impl<Updater> Debug for BookStash<Updater> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BookStash")
+            .field("max_gap", &self.max_gap)
+            .field("books", &self.books.lock().map(|books| books.len()))
+            .finish()
+    }
+}
+
+impl<Updater> PartialEq for BookStash<Updater> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.books, &other.books)
+    }
+}
+
+impl<Updater> Eq for BookStash<Updater> {}
+
 /// Standard generic [`ExchangeTransformer`] to translate exchange specific OrderBook types into
 /// normalised Barter OrderBook types. Requires an exchange specific [`OrderBookUpdater`]
 /// implementation.
+///
+/// If configured with a [`BookResume`], the `book_map` is stashed when the transformer is dropped
+/// (ie/ on disconnection), unless a terminal [`DataError`] indicates it must be re-initialised.
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 pub struct MultiBookTransformer<Exchange, Kind, Updater> {
     pub book_map: Map<InstrumentOrderBook<Updater>>,
+    pub anomaly_policy: BookAnomalyPolicy,
+    pub pruning: Option<BookPruning>,
+    #[serde(skip)]
+    pub resume: Option<BookStash<Updater>>,
     phantom: PhantomData<(Exchange, Kind)>,
 }
 
-#[async_trait]
-impl<Exchange, Kind, Updater> ExchangeTransformer<Exchange, Kind>
-    for MultiBookTransformer<Exchange, Kind, Updater>
+impl<Exchange, Kind, Updater> MultiBookTransformer<Exchange, Kind, Updater>
 where
-    Exchange: Connector + Send,
-    Kind: SubKind<Event = OrderBook> + Send,
-    Updater: OrderBookUpdater<OrderBook = Kind::Event> + Send,
-    Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
+    Updater: OrderBookUpdater + Send,
 {
-    async fn new(
+    /// Construct the `book_map`, initialising an [`InstrumentOrderBook`] for every [`Subscription`]
+    /// that is not already provided by the `resumed` [`InstrumentOrderBook`]s.
+    ///
+    /// [`Subscription`]: crate::subscription::Subscription
+    async fn init_book_map(
         ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
         map: Map<Instrument>,
-    ) -> Result<Self, DataError> {
-        // Initialise InstrumentOrderBooks for all Subscriptions
+        mut resumed: Map<InstrumentOrderBook<Updater>>,
+        config: &ConnectionConfig,
+    ) -> Result<Map<InstrumentOrderBook<Updater>>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Initialise InstrumentOrderBooks for all Subscriptions that were not resumed
         let (sub_ids, init_book_requests): (Vec<_>, Vec<_>) = map
             .0
             .into_iter()
+            .filter(|(sub_id, _)| !resumed.0.contains_key(sub_id))
             .map(|(sub_id, instrument)| {
                 (
                     sub_id,
-                    Updater::init::<Exchange, Kind>(ws_sink_tx.clone(), instrument),
+                    Updater::init_with_config::<Exchange, Kind>(
+                        ws_sink_tx.clone(),
+                        instrument,
+                        config,
+                    ),
                 )
             })
             .unzip();
@@ -93,16 +345,123 @@ where
             .collect::<Result<Vec<InstrumentOrderBook<Updater>>, DataError>>()?;
 
         // Construct OrderBookMap if all requests successful
-        let book_map = sub_ids
-            .into_iter()
-            .zip(init_order_books.into_iter())
-            .collect::<Map<InstrumentOrderBook<Updater>>>();
+        resumed.0.extend(sub_ids.into_iter().zip(init_order_books));
+        Ok(resumed)
+    }
+}
+
+impl<Exchange, Kind, Updater> Drop for MultiBookTransformer<Exchange, Kind, Updater> {
+    fn drop(&mut self) {
+        if let Some(stash) = self.resume.take() {
+            stash.stash(std::mem::replace(&mut self.book_map, Map(HashMap::new())));
+        }
+    }
+}
 
+#[async_trait]
+impl<Exchange, Kind, Updater> ExchangeTransformer<Exchange, Kind>
+    for MultiBookTransformer<Exchange, Kind, Updater>
+where
+    Exchange: Connector + Send,
+    Kind: SubKind<Event = OrderBook> + Send,
+    Updater: OrderBookUpdater<OrderBook = Kind::Event> + Send + 'static,
+    Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
+{
+    async fn new(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        map: Map<Instrument>,
+    ) -> Result<Self, DataError> {
         Ok(Self {
-            book_map,
-            phantom: PhantomData::default(),
+            book_map: Self::init_book_map(
+                ws_sink_tx,
+                map,
+                Map(HashMap::new()),
+                &ConnectionConfig::default(),
+            )
+            .await?,
+            anomaly_policy: BookAnomalyPolicy::default(),
+            pruning: None,
+            resume: None,
+            phantom: PhantomData,
         })
     }
+
+    async fn with_config(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        map: Map<Instrument>,
+        config: &ConnectionConfig,
+    ) -> Result<Self, DataError> {
+        // Resume any OrderBooks stashed by a recently disconnected MultiBookTransformer
+        let resumed = Self::book_stash(config)
+            .map(|stash| stash.take(&map))
+            .unwrap_or_else(|| Map(HashMap::new()));
+
+        let mut transformer = Self {
+            book_map: Self::init_book_map(ws_sink_tx, map, resumed, config).await?,
+            anomaly_policy: BookAnomalyPolicy::default(),
+            pruning: None,
+            resume: None,
+            phantom: PhantomData,
+        };
+        <Self as ExchangeTransformer<Exchange, Kind>>::configure(&mut transformer, config);
+        Ok(transformer)
+    }
+
+    fn configure(&mut self, config: &ConnectionConfig) {
+        self.anomaly_policy = config.book_anomaly_policy;
+        self.pruning = config.book_pruning;
+        self.resume = Self::book_stash(config);
+    }
+}
+
+impl<Exchange, Kind, Updater> MultiBookTransformer<Exchange, Kind, Updater>
+where
+    Exchange: Connector,
+    Updater: OrderBookUpdater + Send + 'static,
+{
+    /// Determine the [`BookStash`] of this exchange & [`OrderBookUpdater`], if the
+    /// [`ConnectionConfig`] has a [`BookResume`] & the [`OrderBookUpdater`] is resumable.
+    fn book_stash(config: &ConnectionConfig) -> Option<BookStash<Updater>> {
+        config
+            .book_resume
+            .as_ref()
+            .filter(|_| Updater::resumable())
+            .map(|resume| resume.stash::<Updater>(Exchange::ID))
+    }
+}
+
+#[async_trait]
+impl<Exchange, Kind, Updater> ExchangeTransformer<Exchange, RawChannel<Kind>>
+    for MultiBookTransformer<Exchange, Kind, Updater>
+where
+    Exchange: Connector + Send,
+    Kind: SubKind<Event = OrderBook> + Send,
+    Updater: OrderBookUpdater<OrderBook = Kind::Event> + Send + 'static,
+    Updater::Update: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
+{
+    async fn new(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+    ) -> Result<Self, DataError> {
+        <Self as ExchangeTransformer<Exchange, Kind>>::new(ws_sink_tx, instrument_map).await
+    }
+
+    async fn with_config(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+        config: &ConnectionConfig,
+    ) -> Result<Self, DataError> {
+        <Self as ExchangeTransformer<Exchange, Kind>>::with_config(
+            ws_sink_tx,
+            instrument_map,
+            config,
+        )
+        .await
+    }
+
+    fn configure(&mut self, config: &ConnectionConfig) {
+        <Self as ExchangeTransformer<Exchange, Kind>>::configure(self, config)
+    }
 }
 
 impl<Exchange, Kind, Updater> Transformer for MultiBookTransformer<Exchange, Kind, Updater>
@@ -127,23 +486,681 @@ where
         // Retrieve the InstrumentOrderBook associated with this update (snapshot or delta)
         let book = match self.book_map.find_mut(&subscription_id) {
             Ok(book) => book,
-            Err(unidentifiable) => return vec![Err(DataError::Socket(unidentifiable))],
+            Err(unidentifiable) => return vec![Err(DataError::from(unidentifiable))],
         };
 
         // De-structure for ease
         let InstrumentOrderBook {
             instrument,
-            book,
+            book: internal,
             updater,
         } = book;
 
-        // Apply update (snapshot or delta) to OrderBook & generate Market<OrderBook> snapshot
-        match updater.update(book, update) {
-            Ok(Some(book)) => {
-                MarketIter::<OrderBook>::from((Exchange::ID, instrument.clone(), book)).0
+        // Apply update (snapshot or delta) to OrderBook & generate Market<OrderBook> snapshot,
+        // tracking the Levels applied by the update (eg/ for an OrderBookDelta)
+        internal.track_changes();
+        let mut book = match updater.update(internal, update) {
+            Ok(Some(book)) => book,
+            Ok(None) => return vec![],
+            Err(error) => {
+                // Terminal errors (eg/ a sequence gap) require every OrderBook be re-initialised
+                if error.is_terminal() {
+                    self.resume = None;
+                }
+                return vec![Err(error)];
+            }
+        };
+
+        // Prune far-away Levels, retaining the full internal OrderBook if the Updater requires it
+        if let Some(pruning) = self.pruning {
+            pruning.apply(&mut book);
+            if Updater::requires_full_depth() {
+                // Levels may re-enter the pruned snapshot without being applied by the update
+                book.untrack_changes();
+            } else {
+                pruning.apply(internal);
             }
-            Ok(None) => vec![],
-            Err(error) => vec![Err(error)],
+        }
+
+        // Verify the updated OrderBook is not crossed or locked
+        let anomaly = book.anomaly().map(|anomaly| DataError::BookAnomaly {
+            instrument: instrument.clone(),
+            anomaly,
+            resync: self.anomaly_policy == BookAnomalyPolicy::Resync,
+        });
+
+        match (anomaly, self.anomaly_policy) {
+            (Some(anomaly), BookAnomalyPolicy::Resync) => {
+                self.resume = None;
+                vec![Err(anomaly)]
+            }
+            (anomaly, _) => {
+                let mut events =
+                    MarketIter::<OrderBook>::from((Exchange::ID, instrument.clone(), book)).0;
+                events.extend(anomaly.map(Err));
+                events
+            }
+        }
+    }
+}
+
+/// [`ExchangeTransformer`] wrapper used for [`OrderBookDeltas`] [`Subscription`]s, converting
+/// each [`OrderBook`] snapshot yielded by the wrapped OrderBook [`ExchangeTransformer`] (eg/ a
+/// [`MultiBookTransformer`]) into an [`OrderBookDelta`] of the
+/// [`Level`](crate::subscription::book::Level)s applied by the update (see
+/// [`OrderBook::track_changes`]).
+///
+/// The first [`OrderBookDelta`] of each [`Instrument`] is a full snapshot, as is any update
+/// whose applied [`Level`](crate::subscription::book::Level)s are unknown (eg/ an exchange
+/// snapshot that replaces the [`OrderBook`]). The `sequence` of each [`Instrument`] restarts at
+/// zero whenever the wrapped transformer is re-initialised (ie/ on re-connection).
+///
+/// [`Subscription`]: crate::subscription::Subscription
+#[derive(Clone, PartialEq, Debug)]
+pub struct BookDeltaTransformer<Transformer> {
+    pub transformer: Transformer,
+    sequences: HashMap<Instrument, u64>,
+}
+
+impl<Transformer> BookDeltaTransformer<Transformer> {
+    /// Construct a new [`BookDeltaTransformer`] wrapping the provided OrderBook
+    /// [`ExchangeTransformer`].
+    pub fn new(transformer: Transformer) -> Self {
+        Self {
+            transformer,
+            sequences: HashMap::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Exchange, Kind, Inner> ExchangeTransformer<Exchange, OrderBookDeltas<Kind>>
+    for BookDeltaTransformer<Inner>
+where
+    Exchange: Send,
+    Kind: SubKind<Event = OrderBook> + Send,
+    Inner: ExchangeTransformer<Exchange, Kind> + Send,
+{
+    async fn new(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+    ) -> Result<Self, DataError> {
+        Inner::new(ws_sink_tx, instrument_map).await.map(Self::new)
+    }
+
+    async fn with_config(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+        config: &ConnectionConfig,
+    ) -> Result<Self, DataError> {
+        Inner::with_config(ws_sink_tx, instrument_map, config)
+            .await
+            .map(Self::new)
+    }
+
+    fn configure(&mut self, config: &ConnectionConfig) {
+        self.transformer.configure(config)
+    }
+}
+
+impl<Inner> Transformer for BookDeltaTransformer<Inner>
+where
+    Inner: Transformer<Output = MarketEvent<OrderBook>, Error = DataError>,
+{
+    type Error = DataError;
+    type Input = Inner::Input;
+    type Output = MarketEvent<OrderBookDelta>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        self.transformer
+            .transform(input)
+            .into_iter()
+            .map(|result| {
+                result.map(|event| {
+                    let MarketEvent {
+                        exchange_time,
+                        received_time,
+                        exchange_time_synthesized,
+                        exchange,
+                        instrument,
+                        kind: mut book,
+                    } = event;
+
+                    // First OrderBookDelta of each Instrument is a snapshot of every Level
+                    let sequence = match self.sequences.get_mut(&instrument) {
+                        Some(sequence) => {
+                            *sequence += 1;
+                            *sequence
+                        }
+                        None => {
+                            book.untrack_changes();
+                            self.sequences.insert(instrument.clone(), 0);
+                            0
+                        }
+                    };
+
+                    // Snapshot OrderBookDeltas require a sorted OrderBook
+                    if book.bids.changes().is_none() || book.asks.changes().is_none() {
+                        book.bids.sort();
+                        book.asks.sort();
+                    }
+                    let delta = book.delta(sequence);
+
+                    MarketEvent {
+                        exchange_time,
+                        received_time,
+                        exchange_time_synthesized,
+                        exchange,
+                        instrument,
+                        kind: delta,
+                    }
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::binance::spot::BinanceSpot,
+        subscription::book::{BookAnomaly, Level, OrderBookSide, OrderBooksL2},
+    };
+    use barter_integration::model::{instrument::kind::InstrumentKind, Side};
+    use chrono::{DateTime, Utc};
+
+    /// Exchange time of every timestamped mock update.
+    fn exchange_time() -> DateTime<Utc> {
+        DateTime::from_timestamp_millis(1649324825173).unwrap()
+    }
+
+    /// Update applied by the [`MockUpdater`], upserting the levels into the [`OrderBook`], or
+    /// replacing the [`OrderBook`] if the update is a `snapshot`.
+    #[derive(Clone, Debug, Default, Deserialize)]
+    struct MockUpdate {
+        seq: Option<u64>,
+        snapshot: bool,
+        bids: Vec<(f64, f64)>,
+        asks: Vec<(f64, f64)>,
+    }
+
+    impl MockUpdate {
+        /// Snapshot update replacing the best bid & ask of the [`OrderBook`].
+        fn best(best_bid: f64, best_ask: f64) -> Self {
+            Self {
+                snapshot: true,
+                bids: vec![(best_bid, 1.0)],
+                asks: vec![(best_ask, 1.0)],
+                ..Self::default()
+            }
+        }
+
+        /// Level-less update with the provided sequence number.
+        fn sequenced(seq: u64) -> Self {
+            Self {
+                seq: Some(seq),
+                ..Self::default()
+            }
+        }
+    }
+
+    impl Identifier<Option<SubscriptionId>> for MockUpdate {
+        fn id(&self) -> Option<SubscriptionId> {
+            Some(SubscriptionId::from("book|BTCUSDT"))
+        }
+    }
+
+    /// Resumable updater applying each [`MockUpdate`], validating that every sequenced update
+    /// follows on from the previous sequence number, which optionally requires the full depth
+    /// to be retained internally.
+    #[derive(
+        Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Deserialize, Serialize,
+    )]
+    struct MockUpdater<const FULL_DEPTH: bool = false> {
+        last_seq: u64,
+    }
+
+    impl<const FULL_DEPTH: bool> MockUpdater<FULL_DEPTH> {
+        fn instrument_book(instrument: Instrument) -> InstrumentOrderBook<Self> {
+            InstrumentOrderBook {
+                instrument,
+                updater: Self::default(),
+                book: OrderBook {
+                    last_update_time: Default::default(),
+                    bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                    asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+                },
+            }
+        }
+    }
+
+    #[async_trait]
+    impl<const FULL_DEPTH: bool> OrderBookUpdater for MockUpdater<FULL_DEPTH> {
+        type OrderBook = OrderBook;
+        type Update = MockUpdate;
+
+        async fn init<Exchange, Kind>(
+            _: mpsc::UnboundedSender<WsMessage>,
+            instrument: Instrument,
+        ) -> Result<InstrumentOrderBook<Self>, DataError>
+        where
+            Exchange: Send,
+            Kind: Send,
+        {
+            Ok(Self::instrument_book(instrument))
+        }
+
+        fn requires_full_depth() -> bool {
+            FULL_DEPTH
+        }
+
+        fn resumable() -> bool {
+            true
+        }
+
+        fn update(
+            &mut self,
+            book: &mut Self::OrderBook,
+            update: Self::Update,
+        ) -> Result<Option<Self::OrderBook>, DataError> {
+            if let Some(seq) = update.seq {
+                if seq != self.last_seq + 1 {
+                    return Err(DataError::InvalidSequence {
+                        prev_last_update_id: self.last_seq,
+                        first_update_id: seq,
+                    });
+                }
+                self.last_seq = seq;
+            }
+
+            if update.snapshot {
+                book.bids = OrderBookSide::new(Side::Buy, update.bids);
+                book.asks = OrderBookSide::new(Side::Sell, update.asks);
+            } else {
+                book.bids.upsert(update.bids);
+                book.asks.upsert(update.asks);
+            }
+            book.last_update_time = exchange_time();
+            Ok(Some(book.snapshot()))
+        }
+    }
+
+    fn transformer<const FULL_DEPTH: bool>(
+        anomaly_policy: BookAnomalyPolicy,
+        pruning: Option<BookPruning>,
+    ) -> MultiBookTransformer<BinanceSpot, OrderBooksL2, MockUpdater<FULL_DEPTH>> {
+        let book =
+            MockUpdater::instrument_book(Instrument::from(("btc", "usdt", InstrumentKind::Spot)));
+
+        MultiBookTransformer {
+            book_map: Map::from_iter([(SubscriptionId::from("book|BTCUSDT"), book)]),
+            anomaly_policy,
+            pruning,
+            resume: None,
+            phantom: PhantomData,
+        }
+    }
+
+    #[test]
+    fn test_multi_book_transformer_book_anomaly() {
+        struct TestCase {
+            policy: BookAnomalyPolicy,
+            update: MockUpdate,
+            expected_book: bool,
+            expected_anomaly: Option<(BookAnomaly, bool)>,
+        }
+
+        let cases = vec![
+            // TC0: valid book is yielded without an anomaly
+            TestCase {
+                policy: BookAnomalyPolicy::Resync,
+                update: MockUpdate::best(100.0, 101.0),
+                expected_book: true,
+                expected_anomaly: None,
+            },
+            // TC1: crossed book is yielded followed by a non-terminal anomaly
+            TestCase {
+                policy: BookAnomalyPolicy::Emit,
+                update: MockUpdate::best(102.0, 101.0),
+                expected_book: true,
+                expected_anomaly: Some((
+                    BookAnomaly::Crossed {
+                        best_bid: 102.0,
+                        best_ask: 101.0,
+                    },
+                    false,
+                )),
+            },
+            // TC2: crossed book is discarded & a terminal anomaly triggers a resync
+            TestCase {
+                policy: BookAnomalyPolicy::Resync,
+                update: MockUpdate::best(102.0, 101.0),
+                expected_book: false,
+                expected_anomaly: Some((
+                    BookAnomaly::Crossed {
+                        best_bid: 102.0,
+                        best_ask: 101.0,
+                    },
+                    true,
+                )),
+            },
+            // TC3: locked book triggers a resync
+            TestCase {
+                policy: BookAnomalyPolicy::Resync,
+                update: MockUpdate::best(101.0, 101.0),
+                expected_book: false,
+                expected_anomaly: Some((BookAnomaly::Locked { price: 101.0 }, true)),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let (best_bid, best_ask) = (test.update.bids[0].0, test.update.asks[0].0);
+            let mut transformer = transformer::<false>(test.policy, None);
+            let mut output = transformer.transform(test.update).into_iter();
+
+            if test.expected_book {
+                let book = output.next().unwrap().unwrap().kind;
+                assert_eq!(book.bids.levels()[0].price, best_bid);
+                assert_eq!(book.asks.levels()[0].price, best_ask);
+            }
+
+            match (output.next(), test.expected_anomaly) {
+                (None, None) => {}
+                (
+                    Some(Err(DataError::BookAnomaly {
+                        anomaly, resync, ..
+                    })),
+                    Some((expected_anomaly, expected_resync)),
+                ) => {
+                    assert_eq!(anomaly, expected_anomaly, "TC{} failed", index);
+                    assert_eq!(resync, expected_resync, "TC{} failed", index);
+                }
+                (actual, expected) => {
+                    panic!("TC{index} failed: actual {actual:?}, expected {expected:?}")
+                }
+            }
+            assert!(output.next().is_none(), "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_multi_book_transformer_book_pruning() {
+        fn prices(side: &OrderBookSide) -> Vec<f64> {
+            side.levels().iter().map(|level| level.price).collect()
+        }
+
+        struct TestCase {
+            pruning: BookPruning,
+            full_depth: bool,
+            expected_bids: Vec<f64>,
+            expected_asks: Vec<f64>,
+            expected_internal_levels: usize,
+        }
+
+        let cases = vec![
+            // TC0: 1% band around the 100.0 mid retains levels within [99.0, 101.0]
+            TestCase {
+                pruning: BookPruning::PriceBand { bps: 100 },
+                full_depth: false,
+                expected_bids: vec![99.5, 99.0],
+                expected_asks: vec![100.5, 101.0],
+                expected_internal_levels: 4,
+            },
+            // TC1: emitted snapshot is pruned, but the full internal OrderBook is retained
+            TestCase {
+                pruning: BookPruning::PriceBand { bps: 100 },
+                full_depth: true,
+                expected_bids: vec![99.5, 99.0],
+                expected_asks: vec![100.5, 101.0],
+                expected_internal_levels: 8,
+            },
+            // TC2: top-N pruning retains the best level of each side
+            TestCase {
+                pruning: BookPruning::Depth(1),
+                full_depth: false,
+                expected_bids: vec![99.5],
+                expected_asks: vec![100.5],
+                expected_internal_levels: 2,
+            },
+        ];
+
+        let update = MockUpdate {
+            snapshot: true,
+            bids: vec![(99.5, 1.0), (99.0, 1.0), (90.0, 1.0), (50.0, 1.0)],
+            asks: vec![(100.5, 1.0), (101.0, 1.0), (110.0, 1.0), (150.0, 1.0)],
+            ..MockUpdate::default()
+        };
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let (book, internal) = if test.full_depth {
+                let mut transformer =
+                    transformer::<true>(BookAnomalyPolicy::default(), Some(test.pruning));
+                let book = transformer.transform(update.clone()).remove(0);
+                let internal = transformer.book_map.0.values().next().unwrap().book.clone();
+                (book, internal)
+            } else {
+                let mut transformer =
+                    transformer::<false>(BookAnomalyPolicy::default(), Some(test.pruning));
+                let book = transformer.transform(update.clone()).remove(0);
+                let internal = transformer.book_map.0.values().next().unwrap().book.clone();
+                (book, internal)
+            };
+
+            let book = book.unwrap().kind;
+            assert_eq!(prices(&book.bids), test.expected_bids, "TC{} failed", index);
+            assert_eq!(prices(&book.asks), test.expected_asks, "TC{} failed", index);
+            assert_eq!(
+                internal.bids.levels().len() + internal.asks.levels().len(),
+                test.expected_internal_levels,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_book_delta_transformer_emits_changed_levels() {
+        fn assert_stream_selector<Exchange, Kind>()
+        where
+            Exchange: crate::exchange::StreamSelector<Kind>,
+            Kind: SubKind,
+        {
+        }
+        assert_stream_selector::<BinanceSpot, OrderBookDeltas<OrderBooksL2>>();
+        assert_stream_selector::<
+            crate::exchange::okx::Okx,
+            OrderBookDeltas<crate::subscription::book::OrderBooksL2Tbt>,
+        >();
+
+        struct TestCase {
+            update: MockUpdate,
+            expected: OrderBookDelta,
+        }
+
+        let update = |snapshot, bids: Vec<(f64, f64)>, asks: Vec<(f64, f64)>| MockUpdate {
+            snapshot,
+            bids,
+            asks,
+            ..MockUpdate::default()
+        };
+        let delta =
+            |sequence, snapshot, bids: Vec<(f64, f64)>, asks: Vec<(f64, f64)>| OrderBookDelta {
+                last_update_time: exchange_time(),
+                sequence,
+                snapshot,
+                bids: bids.into_iter().map(Level::from).collect(),
+                asks: asks.into_iter().map(Level::from).collect(),
+            };
+
+        let tests = vec![
+            TestCase {
+                // TC0: first OrderBook is emitted as a snapshot of every Level
+                update: update(
+                    false,
+                    vec![(98.0, 1.0), (99.0, 1.0)],
+                    vec![(101.0, 1.0), (102.0, 1.0)],
+                ),
+                expected: delta(
+                    0,
+                    true,
+                    vec![(99.0, 1.0), (98.0, 1.0)],
+                    vec![(101.0, 1.0), (102.0, 1.0)],
+                ),
+            },
+            TestCase {
+                // TC1: replaced & inserted bids, removed best ask, in the order applied
+                update: update(false, vec![(99.0, 2.0), (97.0, 1.0)], vec![(101.0, 0.0)]),
+                expected: delta(1, false, vec![(99.0, 2.0), (97.0, 1.0)], vec![(101.0, 0.0)]),
+            },
+            TestCase {
+                // TC2: removed bids, inserted best ask & replaced ask
+                update: update(
+                    false,
+                    vec![(99.0, 0.0), (97.0, 0.0)],
+                    vec![(100.5, 1.0), (102.0, 3.0)],
+                ),
+                expected: delta(
+                    2,
+                    false,
+                    vec![(99.0, 0.0), (97.0, 0.0)],
+                    vec![(100.5, 1.0), (102.0, 3.0)],
+                ),
+            },
+            TestCase {
+                // TC3: removing a Level that does not exist is not applied, so the delta is empty
+                update: update(false, vec![(50.0, 0.0)], vec![]),
+                expected: delta(3, false, vec![], vec![]),
+            },
+            TestCase {
+                // TC4: exchange snapshot replacing the OrderBook is emitted as a snapshot
+                update: update(true, vec![(98.0, 2.0), (97.0, 1.0)], vec![(103.0, 1.0)]),
+                expected: delta(4, true, vec![(98.0, 2.0), (97.0, 1.0)], vec![(103.0, 1.0)]),
+            },
+        ];
+
+        let mut snapshots = transformer::<false>(BookAnomalyPolicy::default(), None);
+        let mut deltas =
+            BookDeltaTransformer::new(transformer::<false>(BookAnomalyPolicy::default(), None));
+        let mut tracked: Option<OrderBook> = None;
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let snapshot = snapshots.transform(test.update.clone()).remove(0).unwrap();
+            let delta = deltas.transform(test.update).remove(0).unwrap();
+            assert_eq!(delta.instrument, snapshot.instrument, "TC{} failed", index);
+            assert_eq!(delta.kind, test.expected, "TC{} failed", index);
+
+            // Applying each delta to the prior snapshot yields the full snapshot path OrderBook
+            let book = tracked.get_or_insert_with(|| snapshot.kind.clone());
+            book.apply_delta(&delta.kind);
+            assert_eq!(*book, snapshot.kind, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_book_delta_transformer_records_pruned_levels() {
+        // Depth pruning of a pruned internal OrderBook removes the Levels beyond the best Level
+        let mut deltas = BookDeltaTransformer::new(transformer::<false>(
+            BookAnomalyPolicy::default(),
+            Some(BookPruning::Depth(1)),
+        ));
+        let update = |bids: Vec<(f64, f64)>| MockUpdate {
+            bids,
+            ..MockUpdate::default()
+        };
+
+        let first = deltas
+            .transform(update(vec![(99.0, 1.0)]))
+            .remove(0)
+            .unwrap();
+        assert!(first.kind.snapshot);
+
+        // Inserting a better bid prunes the previous best bid
+        let delta = deltas
+            .transform(update(vec![(100.0, 1.0)]))
+            .remove(0)
+            .unwrap();
+        assert_eq!(delta.kind.sequence, 1);
+        assert!(!delta.kind.snapshot);
+        assert_eq!(
+            delta.kind.bids,
+            vec![Level::new(100.0, 1.0), Level::new(99.0, 0.0)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_multi_book_transformer_book_resume() {
+        type SequencedTransformer = MultiBookTransformer<BinanceSpot, OrderBooksL2, MockUpdater>;
+
+        struct TestCase {
+            updates: Vec<u64>,
+            reconnect_gap: Duration,
+            expected_last_seq: u64,
+        }
+
+        let cases = vec![
+            // TC0: short re-connection gap resumes the maintained OrderBook
+            TestCase {
+                updates: vec![1, 2, 3],
+                reconnect_gap: Duration::ZERO,
+                expected_last_seq: 3,
+            },
+            // TC1: re-connection gap longer than the max_gap re-initialises the OrderBook
+            TestCase {
+                updates: vec![1, 2, 3],
+                reconnect_gap: Duration::from_millis(100),
+                expected_last_seq: 0,
+            },
+            // TC2: sequence gap before the disconnection re-initialises the OrderBook
+            TestCase {
+                updates: vec![1, 3],
+                reconnect_gap: Duration::ZERO,
+                expected_last_seq: 0,
+            },
+        ];
+
+        let map = Map::from_iter([(
+            SubscriptionId::from("book|BTCUSDT"),
+            Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+        )]);
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let config = ConnectionConfig::default().book_resume(Duration::from_millis(50));
+            let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+
+            // Maintain the OrderBook until the connection drops
+            let mut transformer = <SequencedTransformer as ExchangeTransformer<
+                BinanceSpot,
+                OrderBooksL2,
+            >>::with_config(
+                ws_sink_tx.clone(), map.clone(), &config
+            )
+            .await
+            .unwrap();
+            for seq in test.updates {
+                transformer.transform(MockUpdate::sequenced(seq));
+            }
+            drop(transformer);
+
+            // Re-connect after the gap
+            tokio::time::sleep(test.reconnect_gap).await;
+            let transformer = <SequencedTransformer as ExchangeTransformer<
+                BinanceSpot,
+                OrderBooksL2,
+            >>::with_config(ws_sink_tx, map.clone(), &config)
+            .await
+            .unwrap();
+
+            let actual = transformer
+                .book_map
+                .0
+                .values()
+                .next()
+                .unwrap()
+                .updater
+                .last_seq;
+            assert_eq!(actual, test.expected_last_seq, "TC{} failed", index);
         }
     }
 }
@@ -1,6 +1,7 @@
 use crate::{
     error::DataError,
     event::MarketEvent,
+    subscriber::config::ConnectionConfig,
     subscription::{Map, SubKind},
 };
 use async_trait::async_trait;
@@ -16,6 +17,11 @@ pub mod book;
 /// [`PublicTrades`](crate::subscription::trade::PublicTrades) streams.
 pub mod stateless;
 
+/// Generic [`ExchangeTransformer`] & [`TickerMerger`](ticker::TickerMerger) utility for exchanges
+/// that send a [`Ticker`](crate::subscription::ticker::Ticker) snapshot followed by field-level
+/// deltas.
+pub mod ticker;
+
 /// Defines how to construct a [`Transformer`] used by [`MarketStream`](super::MarketStream)s to
 /// translate exchange specific types to normalised Barter types.
 #[async_trait]
@@ -31,4 +37,22 @@ where
         ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
         instrument_map: Map<Instrument>,
     ) -> Result<Self, DataError>;
+
+    /// Construct a new [`Self`] for a [`MarketStream`](super::MarketStream) being initialised
+    /// with the provided [`ConnectionConfig`].
+    ///
+    /// Defaults to [`Self::new`] followed by [`Self::configure`].
+    async fn with_config(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+        config: &ConnectionConfig,
+    ) -> Result<Self, DataError> {
+        let mut transformer = Self::new(ws_sink_tx, instrument_map).await?;
+        transformer.configure(config);
+        Ok(transformer)
+    }
+
+    /// Apply the [`ConnectionConfig`] the [`MarketStream`](super::MarketStream) was initialised
+    /// with. Defaults to ignoring it.
+    fn configure(&mut self, _config: &ConnectionConfig) {}
 }
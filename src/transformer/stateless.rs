@@ -3,7 +3,13 @@ use crate::{
     error::DataError,
     event::{MarketEvent, MarketIter},
     exchange::{Connector, ExchangeId},
-    subscription::{Map, SubKind},
+    subscriber::config::ConnectionConfig,
+    subscription::{
+        is_wildcard,
+        raw::RawChannel,
+        trade::{FilteredTrades, PublicTrade, PublicTrades},
+        Map, SubKind,
+    },
     Identifier,
 };
 use async_trait::async_trait;
@@ -15,6 +21,7 @@ use barter_integration::{
 use serde::{Deserialize, Serialize};
 use std::marker::PhantomData;
 use tokio::sync::mpsc;
+use tracing::trace;
 
 /// Standard generic stateless [`ExchangeTransformer`] to translate exchange specific types into
 /// normalised Barter types. Often used with
@@ -41,11 +48,52 @@ where
     ) -> Result<Self, DataError> {
         Ok(Self {
             instrument_map,
-            phantom: PhantomData::default(),
+            phantom: PhantomData,
         })
     }
 }
 
+#[async_trait]
+impl<Exchange, Kind, Input> ExchangeTransformer<Exchange, RawChannel<Kind>>
+    for StatelessTransformer<Exchange, Kind, Input>
+where
+    Exchange: Connector + Send,
+    Kind: SubKind + Send,
+    Input: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
+    MarketIter<Kind::Event>: From<(ExchangeId, Instrument, Input)>,
+{
+    async fn new(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+    ) -> Result<Self, DataError> {
+        <Self as ExchangeTransformer<Exchange, Kind>>::new(ws_sink_tx, instrument_map).await
+    }
+
+    fn configure(&mut self, config: &ConnectionConfig) {
+        <Self as ExchangeTransformer<Exchange, Kind>>::configure(self, config)
+    }
+}
+
+#[async_trait]
+impl<Exchange, Input> ExchangeTransformer<Exchange, FilteredTrades>
+    for StatelessTransformer<Exchange, PublicTrades, Input>
+where
+    Exchange: Connector + Send,
+    Input: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
+    MarketIter<PublicTrade>: From<(ExchangeId, Instrument, Input)>,
+{
+    async fn new(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+    ) -> Result<Self, DataError> {
+        <Self as ExchangeTransformer<Exchange, PublicTrades>>::new(ws_sink_tx, instrument_map).await
+    }
+
+    fn configure(&mut self, config: &ConnectionConfig) {
+        <Self as ExchangeTransformer<Exchange, PublicTrades>>::configure(self, config)
+    }
+}
+
 impl<Exchange, Kind, Input> Transformer for StatelessTransformer<Exchange, Kind, Input>
 where
     Exchange: Connector,
@@ -68,7 +116,208 @@ where
         // Find Instrument associated with Input and transform
         match self.instrument_map.find(&subscription_id) {
             Ok(instrument) => MarketIter::<Kind::Event>::from((Exchange::ID, instrument, input)).0,
-            Err(unidentifiable) => vec![Err(DataError::Socket(unidentifiable))],
+            Err(unidentifiable) => vec![Err(DataError::from(unidentifiable))],
         }
     }
 }
+
+/// Generic stateless [`ExchangeTransformer`] for exchange messages that batch updates for many
+/// markets (eg/ an all-market channel). Each `Input` item is identified & transformed
+/// independently into normalised Barter types.
+///
+/// ### Notes
+/// Items for markets without an associated [`Instrument`] are discarded, since an all-market
+/// channel delivers updates for every market regardless of which were subscribed to.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+pub struct StatelessFanOutTransformer<Exchange, Kind, Input> {
+    instrument_map: Map<Instrument>,
+    phantom: PhantomData<(Exchange, Kind, Input)>,
+}
+
+#[async_trait]
+impl<Exchange, Kind, Input> ExchangeTransformer<Exchange, Kind>
+    for StatelessFanOutTransformer<Exchange, Kind, Input>
+where
+    Exchange: Connector + Send,
+    Kind: SubKind + Send,
+    Input: IntoIterator + for<'de> Deserialize<'de>,
+    Input::Item: Identifier<Option<SubscriptionId>>,
+    MarketIter<Kind::Event>: From<(ExchangeId, Instrument, Input::Item)>,
+{
+    async fn new(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+    ) -> Result<Self, DataError> {
+        Ok(Self {
+            instrument_map,
+            phantom: PhantomData,
+        })
+    }
+}
+
+#[async_trait]
+impl<Exchange, Kind, Input> ExchangeTransformer<Exchange, RawChannel<Kind>>
+    for StatelessFanOutTransformer<Exchange, Kind, Input>
+where
+    Exchange: Connector + Send,
+    Kind: SubKind + Send,
+    Input: IntoIterator + for<'de> Deserialize<'de>,
+    Input::Item: Identifier<Option<SubscriptionId>>,
+    MarketIter<Kind::Event>: From<(ExchangeId, Instrument, Input::Item)>,
+{
+    async fn new(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+    ) -> Result<Self, DataError> {
+        <Self as ExchangeTransformer<Exchange, Kind>>::new(ws_sink_tx, instrument_map).await
+    }
+
+    fn configure(&mut self, config: &ConnectionConfig) {
+        <Self as ExchangeTransformer<Exchange, Kind>>::configure(self, config)
+    }
+}
+
+impl<Exchange, Kind, Input> Transformer for StatelessFanOutTransformer<Exchange, Kind, Input>
+where
+    Exchange: Connector,
+    Kind: SubKind,
+    Input: IntoIterator + for<'de> Deserialize<'de>,
+    Input::Item: Identifier<Option<SubscriptionId>>,
+    MarketIter<Kind::Event>: From<(ExchangeId, Instrument, Input::Item)>,
+{
+    type Error = DataError;
+    type Input = Input;
+    type Output = MarketEvent<Kind::Event>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        input
+            .into_iter()
+            .flat_map(|item| {
+                let Some(subscription_id) = item.id() else {
+                    return vec![];
+                };
+
+                match self.instrument_map.find(&subscription_id) {
+                    Ok(instrument) => {
+                        MarketIter::<Kind::Event>::from((Exchange::ID, instrument, item)).0
+                    }
+                    Err(_) => {
+                        trace!(%subscription_id, "discarding update for un-subscribed market");
+                        vec![]
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+/// Generic stateless [`ExchangeTransformer`] for exchange all-symbols channels subscribed to via a
+/// [`WILDCARD`](crate::subscription::WILDCARD) [`Instrument`].
+///
+/// The `Input` message is routed to its wildcard [`Instrument`] by [`SubscriptionId`], then fanned
+/// out into each item, with the [`Instrument`] of every item identified from its own exchange
+/// symbol.
+///
+/// ### Notes
+/// Items for an identified [`Instrument`] that is not of the subscribed
+/// [`InstrumentKind`](barter_integration::model::instrument::kind::InstrumentKind) variant (eg/
+/// any future expiry matches a future wildcard), or whose exchange symbol cannot be identified,
+/// are discarded.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+pub struct StatelessWildcardTransformer<Exchange, Kind, Input> {
+    instrument_map: Map<Instrument>,
+    phantom: PhantomData<(Exchange, Kind, Input)>,
+}
+
+#[async_trait]
+impl<Exchange, Kind, Input> ExchangeTransformer<Exchange, Kind>
+    for StatelessWildcardTransformer<Exchange, Kind, Input>
+where
+    Exchange: Connector + Send,
+    Kind: SubKind + Send,
+    Input: Identifier<Option<SubscriptionId>> + IntoIterator + for<'de> Deserialize<'de>,
+    Input::Item: Identifier<Option<Instrument>>,
+    MarketIter<Kind::Event>: From<(ExchangeId, Instrument, Input::Item)>,
+{
+    async fn new(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+    ) -> Result<Self, DataError> {
+        Ok(Self {
+            instrument_map,
+            phantom: PhantomData,
+        })
+    }
+}
+
+#[async_trait]
+impl<Exchange, Kind, Input> ExchangeTransformer<Exchange, RawChannel<Kind>>
+    for StatelessWildcardTransformer<Exchange, Kind, Input>
+where
+    Exchange: Connector + Send,
+    Kind: SubKind + Send,
+    Input: Identifier<Option<SubscriptionId>> + IntoIterator + for<'de> Deserialize<'de>,
+    Input::Item: Identifier<Option<Instrument>>,
+    MarketIter<Kind::Event>: From<(ExchangeId, Instrument, Input::Item)>,
+{
+    async fn new(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+    ) -> Result<Self, DataError> {
+        <Self as ExchangeTransformer<Exchange, Kind>>::new(ws_sink_tx, instrument_map).await
+    }
+
+    fn configure(&mut self, config: &ConnectionConfig) {
+        <Self as ExchangeTransformer<Exchange, Kind>>::configure(self, config)
+    }
+}
+
+impl<Exchange, Kind, Input> Transformer for StatelessWildcardTransformer<Exchange, Kind, Input>
+where
+    Exchange: Connector,
+    Kind: SubKind,
+    Input: Identifier<Option<SubscriptionId>> + IntoIterator + for<'de> Deserialize<'de>,
+    Input::Item: Identifier<Option<Instrument>>,
+    MarketIter<Kind::Event>: From<(ExchangeId, Instrument, Input::Item)>,
+{
+    type Error = DataError;
+    type Input = Input;
+    type Output = MarketEvent<Kind::Event>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        // Determine if the message has an identifiable SubscriptionId
+        let subscription_id = match input.id() {
+            Some(subscription_id) => subscription_id,
+            None => return vec![],
+        };
+
+        // Find the wildcard Instrument associated with Input
+        let wildcard = match self.instrument_map.find(&subscription_id) {
+            Ok(instrument) if is_wildcard(&instrument) => instrument,
+            Ok(instrument) => {
+                trace!(%subscription_id, %instrument, "discarding update for non-wildcard Instrument");
+                return vec![];
+            }
+            Err(unidentifiable) => return vec![Err(DataError::from(unidentifiable))],
+        };
+
+        // Identify the Instrument of each item & transform
+        input
+            .into_iter()
+            .flat_map(|item| match item.id() {
+                Some(instrument)
+                    if std::mem::discriminant(&instrument.kind)
+                        == std::mem::discriminant(&wildcard.kind) =>
+                {
+                    MarketIter::<Kind::Event>::from((Exchange::ID, instrument, item)).0
+                }
+                _ => {
+                    trace!(%subscription_id, "discarding update for unidentifiable market");
+                    vec![]
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,383 @@
+use super::ExchangeTransformer;
+use crate::{
+    error::DataError,
+    event::{exchange_time_or_received, MarketEvent},
+    exchange::Connector,
+    subscriber::config::ConnectionConfig,
+    subscription::{
+        book::Level,
+        raw::RawChannel,
+        ticker::{Ticker, Tickers},
+        Map,
+    },
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{instrument::Instrument, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, marker::PhantomData};
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// Whether a [`TickerUpdate`] contains the full [`Ticker`] state, or only the fields that have
+/// changed since the previous update.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum TickerUpdateKind {
+    Snapshot,
+    Delta,
+}
+
+/// Field-level [`Ticker`] update, where `None` fields are unspecified by the exchange & retain
+/// their last-known value when merged.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct TickerDelta {
+    pub best_bid_price: Option<f64>,
+    pub best_bid_amount: Option<f64>,
+    pub best_ask_price: Option<f64>,
+    pub best_ask_amount: Option<f64>,
+    pub last_price: Option<f64>,
+    pub last_amount: Option<f64>,
+    pub open_24h: Option<f64>,
+    pub high_24h: Option<f64>,
+    pub low_24h: Option<f64>,
+    pub volume_24h: Option<f64>,
+    pub vwap_24h: Option<f64>,
+    pub trade_count_24h: Option<u64>,
+}
+
+/// Field-level update of a last-known `State`, where unspecified fields retain their last-known
+/// value when applied.
+pub trait FieldDelta<State> {
+    /// Apply the specified fields of this delta to the provided `State`.
+    fn apply(&self, state: &mut State);
+}
+
+impl FieldDelta<Ticker> for TickerDelta {
+    fn apply(&self, ticker: &mut Ticker) {
+        fn set<T: Copy>(field: &mut T, value: Option<T>) {
+            if let Some(value) = value {
+                *field = value;
+            }
+        }
+
+        set(&mut ticker.best_bid.price, self.best_bid_price);
+        set(&mut ticker.best_bid.amount, self.best_bid_amount);
+        set(&mut ticker.best_ask.price, self.best_ask_price);
+        set(&mut ticker.best_ask.amount, self.best_ask_amount);
+        set(&mut ticker.last_price, self.last_price);
+        set(&mut ticker.last_amount, self.last_amount);
+        set(&mut ticker.open_24h, self.open_24h);
+        set(&mut ticker.high_24h, self.high_24h);
+        set(&mut ticker.low_24h, self.low_24h);
+        set(&mut ticker.volume_24h, self.volume_24h);
+
+        if self.vwap_24h.is_some() {
+            ticker.vwap_24h = self.vwap_24h;
+        }
+        if self.trade_count_24h.is_some() {
+            ticker.trade_count_24h = self.trade_count_24h;
+        }
+    }
+}
+
+impl From<TickerDelta> for Ticker {
+    fn from(delta: TickerDelta) -> Self {
+        let mut ticker = Ticker {
+            best_bid: Level::new(0.0, 0.0),
+            best_ask: Level::new(0.0, 0.0),
+            last_price: 0.0,
+            last_amount: 0.0,
+            open_24h: 0.0,
+            high_24h: 0.0,
+            low_24h: 0.0,
+            volume_24h: 0.0,
+            vwap_24h: None,
+            trade_count_24h: None,
+        };
+        delta.apply(&mut ticker);
+        ticker
+    }
+}
+
+/// Exchange ticker message translated into a [`TickerDelta`] & the associated metadata.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct TickerUpdate {
+    pub kind: TickerUpdateKind,
+    /// Exchange timestamp of the update, if provided by the exchange.
+    pub exchange_time: Option<DateTime<Utc>>,
+    pub delta: TickerDelta,
+}
+
+/// Maintains the last-known `State` (defaults to [`Ticker`]) of each [`SubscriptionId`],
+/// reconstructing complete state from field-level deltas (eg/ Bybit "tickers", Okx "tickers" &
+/// the Okx [`DerivativesStats`](crate::subscription::derivatives::DerivativesStats) channels).
+#[derive(Clone, PartialEq, Debug)]
+pub struct TickerMerger<State = Ticker> {
+    tickers: HashMap<SubscriptionId, State>,
+}
+
+impl<State> Default for TickerMerger<State> {
+    fn default() -> Self {
+        Self {
+            tickers: HashMap::new(),
+        }
+    }
+}
+
+impl TickerMerger {
+    /// Merge the [`TickerUpdate`] into the last-known [`Ticker`] state of the
+    /// [`SubscriptionId`], returning the complete [`Ticker`].
+    ///
+    /// A [`TickerUpdateKind::Snapshot`] replaces any last-known state. A
+    /// [`TickerUpdateKind::Delta`] received before any snapshot cannot be reconstructed into a
+    /// complete [`Ticker`], so is discarded & `None` is returned.
+    pub fn merge(
+        &mut self,
+        subscription_id: &SubscriptionId,
+        update: &TickerUpdate,
+    ) -> Option<Ticker> {
+        match update.kind {
+            TickerUpdateKind::Snapshot => {
+                let ticker = Ticker::from(update.delta);
+                self.tickers.insert(subscription_id.clone(), ticker);
+                Some(ticker)
+            }
+            TickerUpdateKind::Delta => {
+                let ticker = self.tickers.get_mut(subscription_id)?;
+                update.delta.apply(ticker);
+                Some(*ticker)
+            }
+        }
+    }
+}
+
+impl<State> TickerMerger<State>
+where
+    State: Copy + Default,
+{
+    /// Apply the field-level `delta` to the last-known `State` of the [`SubscriptionId`],
+    /// starting from the `State::default()` if none is known, returning the merged `State`.
+    ///
+    /// Suitable for `State` whose default is a valid partial state (eg/ every field is an
+    /// `Option`), so no snapshot is required.
+    pub fn apply<Delta>(&mut self, subscription_id: &SubscriptionId, delta: &Delta) -> State
+    where
+        Delta: FieldDelta<State>,
+    {
+        let state = self.tickers.entry(subscription_id.clone()).or_default();
+        delta.apply(state);
+        *state
+    }
+}
+
+impl<State> TickerMerger<State> {
+    /// Last-known `State` of the [`SubscriptionId`].
+    pub fn ticker(&self, subscription_id: &SubscriptionId) -> Option<&State> {
+        self.tickers.get(subscription_id)
+    }
+}
+
+/// [`ExchangeTransformer`] for exchanges that send a [`Ticker`] snapshot followed by field-level
+/// deltas, using a [`TickerMerger`] to yield complete [`Ticker`] events.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TickerDeltaTransformer<Exchange, Input> {
+    instrument_map: Map<Instrument>,
+    merger: TickerMerger,
+    phantom: PhantomData<(Exchange, Input)>,
+}
+
+#[async_trait]
+impl<Exchange, Input> ExchangeTransformer<Exchange, Tickers>
+    for TickerDeltaTransformer<Exchange, Input>
+where
+    Exchange: Connector + Send,
+    Input:
+        Identifier<Option<SubscriptionId>> + Into<TickerUpdate> + for<'de> Deserialize<'de> + Send,
+{
+    async fn new(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+    ) -> Result<Self, DataError> {
+        Ok(Self {
+            instrument_map,
+            merger: TickerMerger::default(),
+            phantom: PhantomData,
+        })
+    }
+}
+
+#[async_trait]
+impl<Exchange, Input> ExchangeTransformer<Exchange, RawChannel<Tickers>>
+    for TickerDeltaTransformer<Exchange, Input>
+where
+    Exchange: Connector + Send,
+    Input:
+        Identifier<Option<SubscriptionId>> + Into<TickerUpdate> + for<'de> Deserialize<'de> + Send,
+{
+    async fn new(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+    ) -> Result<Self, DataError> {
+        <Self as ExchangeTransformer<Exchange, Tickers>>::new(ws_sink_tx, instrument_map).await
+    }
+
+    fn configure(&mut self, config: &ConnectionConfig) {
+        <Self as ExchangeTransformer<Exchange, Tickers>>::configure(self, config)
+    }
+}
+
+impl<Exchange, Input> Transformer for TickerDeltaTransformer<Exchange, Input>
+where
+    Exchange: Connector,
+    Input: Identifier<Option<SubscriptionId>> + Into<TickerUpdate> + for<'de> Deserialize<'de>,
+{
+    type Error = DataError;
+    type Input = Input;
+    type Output = MarketEvent<Ticker>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        // Determine if the message has an identifiable SubscriptionId
+        let subscription_id = match input.id() {
+            Some(subscription_id) => subscription_id,
+            None => return vec![],
+        };
+
+        // Find Instrument associated with Input
+        let instrument = match self.instrument_map.find(&subscription_id) {
+            Ok(instrument) => instrument,
+            Err(unidentifiable) => return vec![Err(DataError::from(unidentifiable))],
+        };
+
+        let update = input.into();
+        let received_time = Utc::now();
+        let (exchange_time, exchange_time_synthesized) =
+            exchange_time_or_received(update.exchange_time, received_time);
+        match self.merger.merge(&subscription_id, &update) {
+            Some(ticker) => vec![Ok(MarketEvent {
+                exchange_time,
+                received_time,
+                exchange_time_synthesized,
+                exchange: barter_integration::model::Exchange::from(Exchange::ID),
+                instrument,
+                kind: ticker,
+            })],
+            None => {
+                debug!(%subscription_id, "discarding ticker delta received before snapshot");
+                vec![]
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn update(kind: TickerUpdateKind, delta: TickerDelta) -> TickerUpdate {
+        TickerUpdate {
+            kind,
+            exchange_time: None,
+            delta,
+        }
+    }
+
+    #[test]
+    fn test_ticker_merger_snapshot_then_deltas() {
+        let mut merger = TickerMerger::default();
+        let subscription_id = SubscriptionId::from("tickers|BTCUSDT");
+
+        // Delta received before any snapshot is discarded
+        let early = update(
+            TickerUpdateKind::Delta,
+            TickerDelta {
+                last_price: Some(1.0),
+                ..Default::default()
+            },
+        );
+        assert_eq!(merger.merge(&subscription_id, &early), None);
+
+        // Partial snapshot w/o vwap_24h or trade_count_24h
+        let snapshot = update(
+            TickerUpdateKind::Snapshot,
+            TickerDelta {
+                best_bid_price: Some(100.0),
+                best_bid_amount: Some(1.0),
+                best_ask_price: Some(101.0),
+                best_ask_amount: Some(2.0),
+                last_price: Some(100.5),
+                last_amount: Some(0.1),
+                open_24h: Some(95.0),
+                high_24h: Some(105.0),
+                low_24h: Some(90.0),
+                volume_24h: Some(1000.0),
+                vwap_24h: None,
+                trade_count_24h: None,
+            },
+        );
+        let expected_snapshot = Ticker {
+            best_bid: Level::new(100.0, 1.0),
+            best_ask: Level::new(101.0, 2.0),
+            last_price: 100.5,
+            last_amount: 0.1,
+            open_24h: 95.0,
+            high_24h: 105.0,
+            low_24h: 90.0,
+            volume_24h: 1000.0,
+            vwap_24h: None,
+            trade_count_24h: None,
+        };
+        assert_eq!(
+            merger.merge(&subscription_id, &snapshot),
+            Some(expected_snapshot)
+        );
+
+        // Delta updating the best bid & last trade only
+        let delta_1 = update(
+            TickerUpdateKind::Delta,
+            TickerDelta {
+                best_bid_price: Some(100.2),
+                last_price: Some(100.2),
+                last_amount: Some(0.5),
+                ..Default::default()
+            },
+        );
+        let expected_1 = Ticker {
+            best_bid: Level::new(100.2, 1.0),
+            last_price: 100.2,
+            last_amount: 0.5,
+            ..expected_snapshot
+        };
+        assert_eq!(merger.merge(&subscription_id, &delta_1), Some(expected_1));
+
+        // Delta updating the 24h statistics only, retaining the first delta's fields
+        let delta_2 = update(
+            TickerUpdateKind::Delta,
+            TickerDelta {
+                high_24h: Some(106.0),
+                volume_24h: Some(1001.5),
+                vwap_24h: Some(99.0),
+                ..Default::default()
+            },
+        );
+        let expected_2 = Ticker {
+            high_24h: 106.0,
+            volume_24h: 1001.5,
+            vwap_24h: Some(99.0),
+            ..expected_1
+        };
+        assert_eq!(merger.merge(&subscription_id, &delta_2), Some(expected_2));
+        assert_eq!(merger.ticker(&subscription_id), Some(&expected_2));
+
+        // Other SubscriptionIds are tracked independently
+        assert_eq!(
+            merger.merge(&SubscriptionId::from("tickers|ETHUSDT"), &delta_2),
+            None
+        );
+    }
+}
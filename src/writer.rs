@@ -0,0 +1,164 @@
+use crate::exchange::ExchangeId;
+use barter_integration::{
+    error::SocketError,
+    protocol::websocket::{is_websocket_disconnected, WsError, WsMessage},
+};
+use futures::{Sink, SinkExt};
+use tokio::sync::mpsc;
+use tracing::error;
+
+/// Cloneable handle to the ordered outbound [`WsMessage`] queue of a single exchange connection.
+///
+/// Every outbound message of a connection (eg/ subscribes, unsubscribes, pings & pongs) is
+/// submitted to the same queue and written to the socket by a single writer task, so messages
+/// are written in submission order, one message at a time, and never interleave. Messages are
+/// only discarded once the underlying socket has disconnected.
+#[derive(Clone, Debug)]
+pub struct WriteQueue {
+    pub exchange: ExchangeId,
+    tx: mpsc::UnboundedSender<WsMessage>,
+}
+
+impl WriteQueue {
+    /// Spawn the single writer task that owns the provided `sink`, returning the [`WriteQueue`]
+    /// handle used to submit outbound messages to it.
+    ///
+    /// The writer task ends once the `sink` disconnects, or every [`WriteQueue`] handle (and
+    /// [`sender`](Self::sender)) has been dropped and the remaining queued messages written.
+    pub fn spawn<S>(exchange: ExchangeId, sink: S) -> Self
+    where
+        S: Sink<WsMessage, Error = WsError> + Send + Unpin + 'static,
+    {
+        let (tx, rx) = mpsc::unbounded_channel();
+        tokio::spawn(write_messages_to_exchange(exchange, sink, rx));
+        Self { exchange, tx }
+    }
+
+    /// Submit a [`WsMessage`] to the back of the queue.
+    ///
+    /// Returns a [`SocketError::Sink`] if the writer task has ended (eg/ due to disconnection).
+    #[allow(clippy::result_large_err)]
+    pub fn send(&self, message: WsMessage) -> Result<(), SocketError> {
+        self.tx.send(message).map_err(|_| SocketError::Sink)
+    }
+
+    /// Sender half of the queue, for components that submit messages via a raw
+    /// [`mpsc::UnboundedSender`] (eg/ [`ExchangeTransformer`](crate::transformer::ExchangeTransformer)s).
+    pub fn sender(&self) -> mpsc::UnboundedSender<WsMessage> {
+        self.tx.clone()
+    }
+
+    /// Determine if the writer task has ended, meaning submitted messages are no longer written.
+    pub fn is_closed(&self) -> bool {
+        self.tx.is_closed()
+    }
+}
+
+/// Write every [`WsMessage`] received via `rx` to the `sink`, in the order they were received.
+///
+/// Each message is sent & flushed before the next is taken from the queue, so the `sink` only
+/// ever has a single writer.
+pub async fn write_messages_to_exchange<S>(
+    exchange: ExchangeId,
+    mut sink: S,
+    mut rx: mpsc::UnboundedReceiver<WsMessage>,
+) where
+    S: Sink<WsMessage, Error = WsError> + Unpin,
+{
+    while let Some(message) = rx.recv().await {
+        if let Err(error) = sink.send(message).await {
+            if is_websocket_disconnected(&error) {
+                break;
+            }
+
+            // Log error only if WsMessage failed to send over a connected WebSocket
+            error!(
+                %exchange,
+                %error,
+                "failed to send output message to the exchange via WsSink"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use std::sync::{Arc, Mutex};
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_write_queue_writes_concurrent_messages_in_submission_order() {
+        const PRODUCERS: usize = 8;
+        const ROUNDS: usize = 50;
+
+        let (record_tx, record_rx) = futures::channel::mpsc::unbounded::<WsMessage>();
+        let sink = record_tx.sink_map_err(|_| WsError::ConnectionClosed);
+        let queue = WriteQueue::spawn(ExchangeId::Okx, sink);
+
+        // Lock is held across each submission so the submission order can be recorded
+        let submitted = Arc::new(Mutex::new(Vec::new()));
+
+        let producers = (0..PRODUCERS)
+            .map(|producer| {
+                let queue = queue.clone();
+                let submitted = Arc::clone(&submitted);
+                tokio::spawn(async move {
+                    for round in 0..ROUNDS {
+                        let messages = [
+                            WsMessage::Text(format!(
+                                r#"{{"op":"subscribe","producer":{producer},"round":{round}}}"#
+                            )),
+                            WsMessage::Text(format!(
+                                r#"{{"op":"unsubscribe","producer":{producer},"round":{round}}}"#
+                            )),
+                            WsMessage::Ping(format!("{producer}|{round}").into_bytes()),
+                            WsMessage::Pong(format!("{producer}|{round}").into_bytes()),
+                        ];
+
+                        for message in messages {
+                            let mut submitted = submitted.lock().unwrap();
+                            queue.send(message.clone()).unwrap();
+                            submitted.push(message);
+                        }
+
+                        tokio::task::yield_now().await;
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for producer in producers {
+            producer.await.unwrap();
+        }
+
+        // Dropping the final handle ends the writer task once the queue has been drained
+        drop(queue);
+        let written = record_rx.collect::<Vec<_>>().await;
+
+        let submitted = submitted.lock().unwrap();
+        assert_eq!(written.len(), PRODUCERS * ROUNDS * 4);
+        assert_eq!(written, *submitted);
+    }
+
+    #[tokio::test]
+    async fn test_write_queue_send_fails_once_writer_has_ended() {
+        let (record_tx, record_rx) = futures::channel::mpsc::unbounded::<WsMessage>();
+        let sink = record_tx.sink_map_err(|_| WsError::ConnectionClosed);
+        let queue = WriteQueue::spawn(ExchangeId::Okx, sink);
+
+        // Writer task ends when the sink reports a disconnection
+        drop(record_rx);
+        queue
+            .send(WsMessage::Text("subscribe".to_string()))
+            .unwrap();
+        while !queue.is_closed() {
+            tokio::task::yield_now().await;
+        }
+
+        assert!(matches!(
+            queue.send(WsMessage::Text("unsubscribe".to_string())),
+            Err(SocketError::Sink)
+        ));
+    }
+}